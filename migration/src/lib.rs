@@ -3,6 +3,15 @@ pub use sea_orm_migration::prelude::*;
 // 在这里添加所有的迁移模块
 // mod m20240101_000001_create_users_table;
 // mod m20240102_000001_create_roles_table;
+mod m20261018_000001_add_plugin_signature_columns;
+mod m20261018_000002_create_oauth2_provider_table;
+mod m20261018_000003_create_oidc_server_tables;
+mod m20261018_000004_create_system_metric_tables;
+mod m20261018_000005_create_alert_tables;
+mod m20261018_000006_create_log_level_table;
+mod m20261018_000007_add_password_policy;
+mod m20261018_000008_add_user_locale;
+mod m20261018_000009_add_gen_business_table_schema;
 
 pub struct Migrator;
 
@@ -13,6 +22,15 @@ impl MigratorTrait for Migrator {
             // 在这里按顺序添加迁移
             // Box::new(m20240101_000001_create_users_table::Migration),
             // Box::new(m20240102_000001_create_roles_table::Migration),
+            Box::new(m20261018_000001_add_plugin_signature_columns::Migration),
+            Box::new(m20261018_000002_create_oauth2_provider_table::Migration),
+            Box::new(m20261018_000003_create_oidc_server_tables::Migration),
+            Box::new(m20261018_000004_create_system_metric_tables::Migration),
+            Box::new(m20261018_000005_create_alert_tables::Migration),
+            Box::new(m20261018_000006_create_log_level_table::Migration),
+            Box::new(m20261018_000007_add_password_policy::Migration),
+            Box::new(m20261018_000008_add_user_locale::Migration),
+            Box::new(m20261018_000009_add_gen_business_table_schema::Migration),
         ]
     }
 }
@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+/// 为 sys_plugin 表增加插件签名校验字段，表不存在时连同签名字段一起创建
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_table("sys_plugin").await? {
+            return create_table(manager).await;
+        }
+
+        let columns = [
+            ColumnDef::new(SysPlugin::SignatureStatus)
+                .integer()
+                .not_null()
+                .default(0)
+                .to_owned(),
+            ColumnDef::new(SysPlugin::SignatureKeyId)
+                .string_len(64)
+                .null()
+                .to_owned(),
+            ColumnDef::new(SysPlugin::ManifestHash)
+                .string_len(64)
+                .null()
+                .to_owned(),
+            ColumnDef::new(SysPlugin::VerifiedTime)
+                .date_time()
+                .null()
+                .to_owned(),
+        ];
+
+        // SQLite 不支持单条语句添加多列，逐列添加
+        for mut column in columns {
+            let name = column.get_column_name();
+            if manager.has_column("sys_plugin", &name).await? {
+                continue;
+            }
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysPlugin::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_table("sys_plugin").await? {
+            return Ok(());
+        }
+
+        for column in [
+            SysPlugin::SignatureStatus,
+            SysPlugin::SignatureKeyId,
+            SysPlugin::ManifestHash,
+            SysPlugin::VerifiedTime,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysPlugin::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+async fn create_table(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    manager
+        .create_table(
+            Table::create()
+                .table(SysPlugin::Table)
+                .if_not_exists()
+                .col(
+                    ColumnDef::new(SysPlugin::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(SysPlugin::Name).string_len(100).not_null())
+                .col(ColumnDef::new(SysPlugin::Code).string_len(100).not_null().unique_key())
+                .col(ColumnDef::new(SysPlugin::Version).string_len(20).not_null())
+                .col(ColumnDef::new(SysPlugin::PluginType).integer().not_null().default(0))
+                .col(ColumnDef::new(SysPlugin::Description).text().null())
+                .col(ColumnDef::new(SysPlugin::Author).string_len(100).null())
+                .col(ColumnDef::new(SysPlugin::Homepage).string_len(255).null())
+                .col(ColumnDef::new(SysPlugin::FilePath).string_len(255).not_null())
+                .col(ColumnDef::new(SysPlugin::ClassName).string_len(255).not_null())
+                .col(ColumnDef::new(SysPlugin::Config).text().null())
+                .col(ColumnDef::new(SysPlugin::Status).integer().not_null().default(1))
+                .col(ColumnDef::new(SysPlugin::SortOrder).integer().not_null().default(0))
+                .col(ColumnDef::new(SysPlugin::IsSystem).integer().not_null().default(0))
+                .col(ColumnDef::new(SysPlugin::Dependencies).text().null())
+                .col(ColumnDef::new(SysPlugin::InstallTime).date_time().null())
+                .col(ColumnDef::new(SysPlugin::UninstallTime).date_time().null())
+                .col(ColumnDef::new(SysPlugin::SignatureStatus).integer().not_null().default(0))
+                .col(ColumnDef::new(SysPlugin::SignatureKeyId).string_len(64).null())
+                .col(ColumnDef::new(SysPlugin::ManifestHash).string_len(64).null())
+                .col(ColumnDef::new(SysPlugin::VerifiedTime).date_time().null())
+                .col(ColumnDef::new(SysPlugin::CreatedTime).date_time().not_null())
+                .col(ColumnDef::new(SysPlugin::UpdatedTime).date_time().not_null())
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum SysPlugin {
+    Table,
+    Id,
+    Name,
+    Code,
+    Version,
+    PluginType,
+    Description,
+    Author,
+    Homepage,
+    FilePath,
+    ClassName,
+    Config,
+    Status,
+    SortOrder,
+    IsSystem,
+    Dependencies,
+    InstallTime,
+    UninstallTime,
+    CreatedTime,
+    UpdatedTime,
+    SignatureStatus,
+    SignatureKeyId,
+    ManifestHash,
+    VerifiedTime,
+}
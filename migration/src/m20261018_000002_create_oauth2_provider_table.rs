@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+/// 创建 OAuth2/OIDC 提供商配置表（oauth2 插件运行时加载）
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysOauth2Provider::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysOauth2Provider::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysOauth2Provider::Name).string_len(50).not_null().unique_key())
+                    .col(ColumnDef::new(SysOauth2Provider::Kind).string_len(20).not_null())
+                    .col(ColumnDef::new(SysOauth2Provider::ClientId).string_len(255).not_null())
+                    .col(ColumnDef::new(SysOauth2Provider::ClientSecret).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::RedirectUri).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::Issuer).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::AuthUrl).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::TokenUrl).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::UserinfoUrl).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::Scopes).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Provider::ClaimMapping).text().null())
+                    .col(ColumnDef::new(SysOauth2Provider::RoleMapping).text().null())
+                    .col(ColumnDef::new(SysOauth2Provider::Status).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysOauth2Provider::CreatedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysOauth2Provider::UpdatedTime).date_time().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysOauth2Provider::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysOauth2Provider {
+    Table,
+    Id,
+    Name,
+    Kind,
+    ClientId,
+    ClientSecret,
+    RedirectUri,
+    Issuer,
+    AuthUrl,
+    TokenUrl,
+    UserinfoUrl,
+    Scopes,
+    ClaimMapping,
+    RoleMapping,
+    Status,
+    CreatedTime,
+    UpdatedTime,
+}
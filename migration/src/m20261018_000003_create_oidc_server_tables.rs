@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+/// 创建 OIDC 授权服务的客户端表和签名密钥表
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysOauth2Client::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysOauth2Client::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysOauth2Client::ClientId).string_len(64).not_null().unique_key())
+                    .col(ColumnDef::new(SysOauth2Client::ClientSecretHash).string_len(128).null())
+                    .col(ColumnDef::new(SysOauth2Client::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(SysOauth2Client::RedirectUris).text().not_null())
+                    .col(ColumnDef::new(SysOauth2Client::GrantTypes).string_len(255).not_null())
+                    .col(ColumnDef::new(SysOauth2Client::Scopes).string_len(500).not_null())
+                    .col(ColumnDef::new(SysOauth2Client::Status).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysOauth2Client::Remark).string_len(500).null())
+                    .col(ColumnDef::new(SysOauth2Client::CreatedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysOauth2Client::UpdatedTime).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysSigningKey::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysSigningKey::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysSigningKey::Kid).string_len(64).not_null().unique_key())
+                    .col(ColumnDef::new(SysSigningKey::Algorithm).string_len(20).not_null())
+                    .col(ColumnDef::new(SysSigningKey::PrivateKey).text().not_null())
+                    .col(ColumnDef::new(SysSigningKey::PublicKey).text().not_null())
+                    .col(ColumnDef::new(SysSigningKey::Status).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysSigningKey::CreatedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysSigningKey::RetiredTime).date_time().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysSigningKey::Table).if_exists().to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SysOauth2Client::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysOauth2Client {
+    Table,
+    Id,
+    ClientId,
+    ClientSecretHash,
+    Name,
+    RedirectUris,
+    GrantTypes,
+    Scopes,
+    Status,
+    Remark,
+    CreatedTime,
+    UpdatedTime,
+}
+
+#[derive(DeriveIden)]
+enum SysSigningKey {
+    Table,
+    Id,
+    Kid,
+    Algorithm,
+    PrivateKey,
+    PublicKey,
+    Status,
+    CreatedTime,
+    RetiredTime,
+}
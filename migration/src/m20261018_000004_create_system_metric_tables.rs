@@ -0,0 +1,138 @@
+use sea_orm_migration::prelude::*;
+
+/// 创建系统监控指标原始采样表和汇总表
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysSystemMetric::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysSystemMetric::MetricId)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysSystemMetric::MetricType).integer().not_null())
+                    .col(ColumnDef::new(SysSystemMetric::MetricName).string_len(100).not_null())
+                    .col(ColumnDef::new(SysSystemMetric::MetricValue).double().not_null())
+                    .col(ColumnDef::new(SysSystemMetric::Unit).string_len(20).not_null())
+                    .col(ColumnDef::new(SysSystemMetric::HostName).string_len(100).not_null())
+                    .col(ColumnDef::new(SysSystemMetric::IpAddress).string_len(50).not_null())
+                    .col(ColumnDef::new(SysSystemMetric::CollectionTime).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(SysSystemMetric::Remark).string_len(500).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_system_metric_time")
+                    .table(SysSystemMetric::Table)
+                    .col(SysSystemMetric::CollectionTime)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_system_metric_host_name_time")
+                    .table(SysSystemMetric::Table)
+                    .col(SysSystemMetric::HostName)
+                    .col(SysSystemMetric::MetricName)
+                    .col(SysSystemMetric::CollectionTime)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysSystemMetricRollup::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysSystemMetricRollup::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysSystemMetricRollup::Period).string_len(10).not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::BucketTime).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::MetricType).integer().not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::MetricName).string_len(100).not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::Unit).string_len(20).not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::HostName).string_len(100).not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::IpAddress).string_len(50).not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::AvgValue).double().not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::MinValue).double().not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::MaxValue).double().not_null())
+                    .col(ColumnDef::new(SysSystemMetricRollup::SampleCount).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一周期同一指标只保留一条汇总，重复汇总时先删除再写入
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_sys_system_metric_rollup_bucket")
+                    .table(SysSystemMetricRollup::Table)
+                    .col(SysSystemMetricRollup::Period)
+                    .col(SysSystemMetricRollup::BucketTime)
+                    .col(SysSystemMetricRollup::HostName)
+                    .col(SysSystemMetricRollup::MetricName)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysSystemMetricRollup::Table).if_exists().to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SysSystemMetric::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysSystemMetric {
+    Table,
+    MetricId,
+    MetricType,
+    MetricName,
+    MetricValue,
+    Unit,
+    HostName,
+    IpAddress,
+    CollectionTime,
+    Remark,
+}
+
+#[derive(DeriveIden)]
+enum SysSystemMetricRollup {
+    Table,
+    Id,
+    Period,
+    BucketTime,
+    MetricType,
+    MetricName,
+    Unit,
+    HostName,
+    IpAddress,
+    AvgValue,
+    MinValue,
+    MaxValue,
+    SampleCount,
+}
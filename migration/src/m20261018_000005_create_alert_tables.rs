@@ -0,0 +1,177 @@
+use sea_orm_migration::prelude::*;
+
+/// 创建告警规则、告警事件和静默规则表
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAlertRule::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAlertRule::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAlertRule::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(SysAlertRule::Source).string_len(32).not_null())
+                    .col(ColumnDef::new(SysAlertRule::MetricType).integer().null())
+                    .col(ColumnDef::new(SysAlertRule::MetricName).string_len(100).null())
+                    .col(ColumnDef::new(SysAlertRule::HostName).string_len(100).null())
+                    .col(ColumnDef::new(SysAlertRule::Comparator).string_len(4).not_null())
+                    .col(ColumnDef::new(SysAlertRule::Threshold).double().not_null())
+                    .col(ColumnDef::new(SysAlertRule::DurationSeconds).integer().not_null())
+                    .col(ColumnDef::new(SysAlertRule::MinSamples).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysAlertRule::Severity).string_len(16).not_null())
+                    .col(ColumnDef::new(SysAlertRule::RepeatMinutes).integer().not_null().default(0))
+                    .col(ColumnDef::new(SysAlertRule::NotifyEmails).text().null())
+                    .col(ColumnDef::new(SysAlertRule::NoticeRoleIds).text().null())
+                    .col(ColumnDef::new(SysAlertRule::WebhookUrl).string_len(500).null())
+                    .col(ColumnDef::new(SysAlertRule::Status).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysAlertRule::Remark).string_len(500).null())
+                    .col(ColumnDef::new(SysAlertRule::CreatedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysAlertRule::UpdatedTime).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAlertEvent::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAlertEvent::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAlertEvent::RuleId).big_integer().not_null())
+                    .col(ColumnDef::new(SysAlertEvent::RuleName).string_len(100).not_null())
+                    .col(ColumnDef::new(SysAlertEvent::Severity).string_len(16).not_null())
+                    .col(ColumnDef::new(SysAlertEvent::Fingerprint).string_len(255).not_null())
+                    .col(ColumnDef::new(SysAlertEvent::HostName).string_len(100).null())
+                    .col(ColumnDef::new(SysAlertEvent::MetricName).string_len(100).null())
+                    .col(ColumnDef::new(SysAlertEvent::Status).string_len(16).not_null())
+                    .col(ColumnDef::new(SysAlertEvent::Value).double().not_null())
+                    .col(ColumnDef::new(SysAlertEvent::Threshold).double().not_null())
+                    .col(ColumnDef::new(SysAlertEvent::StartedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysAlertEvent::ResolvedTime).date_time().null())
+                    .col(ColumnDef::new(SysAlertEvent::LastNotifiedTime).date_time().null())
+                    .col(ColumnDef::new(SysAlertEvent::AckedBy).string_len(64).null())
+                    .col(ColumnDef::new(SysAlertEvent::AckedTime).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_alert_event_rule_status")
+                    .table(SysAlertEvent::Table)
+                    .col(SysAlertEvent::RuleId)
+                    .col(SysAlertEvent::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAlertSilence::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAlertSilence::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAlertSilence::RuleId).big_integer().null())
+                    .col(ColumnDef::new(SysAlertSilence::HostName).string_len(100).null())
+                    .col(ColumnDef::new(SysAlertSilence::StartsTime).date_time().not_null())
+                    .col(ColumnDef::new(SysAlertSilence::EndsTime).date_time().not_null())
+                    .col(ColumnDef::new(SysAlertSilence::Reason).string_len(500).null())
+                    .col(ColumnDef::new(SysAlertSilence::CreatedBy).string_len(64).null())
+                    .col(ColumnDef::new(SysAlertSilence::CreatedTime).date_time().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAlertSilence::Table).if_exists().to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SysAlertEvent::Table).if_exists().to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SysAlertRule::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysAlertRule {
+    Table,
+    Id,
+    Name,
+    Source,
+    MetricType,
+    MetricName,
+    HostName,
+    Comparator,
+    Threshold,
+    DurationSeconds,
+    MinSamples,
+    Severity,
+    RepeatMinutes,
+    NotifyEmails,
+    NoticeRoleIds,
+    WebhookUrl,
+    Status,
+    Remark,
+    CreatedTime,
+    UpdatedTime,
+}
+
+#[derive(DeriveIden)]
+enum SysAlertEvent {
+    Table,
+    Id,
+    RuleId,
+    RuleName,
+    Severity,
+    Fingerprint,
+    HostName,
+    MetricName,
+    Status,
+    Value,
+    Threshold,
+    StartedTime,
+    ResolvedTime,
+    LastNotifiedTime,
+    AckedBy,
+    AckedTime,
+}
+
+#[derive(DeriveIden)]
+enum SysAlertSilence {
+    Table,
+    Id,
+    RuleId,
+    HostName,
+    StartsTime,
+    EndsTime,
+    Reason,
+    CreatedBy,
+    CreatedTime,
+}
@@ -0,0 +1,95 @@
+use sea_orm_migration::prelude::*;
+
+/// 创建运行时日志级别表，并写入默认禁用的内置规则
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 内置规则：（模块，级别，描述），模块为空表示应用自身
+const SYSTEM_LEVELS: &[(Option<&str>, &str, &str)] = &[
+    (None, "trace", "应用日志：TRACE"),
+    (None, "debug", "应用日志：DEBUG"),
+    (None, "info", "应用日志：INFO"),
+    (None, "warn", "应用日志：WARN"),
+    (None, "error", "应用日志：ERROR"),
+    (Some("sea_orm"), "debug", "输出 SQL 语句"),
+    (Some("sqlx"), "debug", "输出数据库驱动日志"),
+    (Some("tower_http"), "debug", "输出 HTTP 请求详情"),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysLogLevel::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysLogLevel::LevelId)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysLogLevel::Target).string_len(255).null())
+                    .col(ColumnDef::new(SysLogLevel::LevelName).string_len(16).not_null())
+                    .col(ColumnDef::new(SysLogLevel::Description).string_len(500).null())
+                    .col(ColumnDef::new(SysLogLevel::IsSystem).integer().not_null().default(0))
+                    .col(ColumnDef::new(SysLogLevel::Status).integer().not_null().default(1))
+                    .col(ColumnDef::new(SysLogLevel::ExpireTime).date_time().null())
+                    .col(ColumnDef::new(SysLogLevel::CreateBy).string_len(64).not_null())
+                    .col(ColumnDef::new(SysLogLevel::CreatedTime).date_time().not_null())
+                    .col(ColumnDef::new(SysLogLevel::UpdateBy).string_len(64).null())
+                    .col(ColumnDef::new(SysLogLevel::UpdatedTime).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let mut insert = Query::insert()
+            .into_table(SysLogLevel::Table)
+            .columns([
+                SysLogLevel::Target,
+                SysLogLevel::LevelName,
+                SysLogLevel::Description,
+                SysLogLevel::IsSystem,
+                SysLogLevel::Status,
+                SysLogLevel::CreateBy,
+                SysLogLevel::CreatedTime,
+            ])
+            .to_owned();
+        for (target, level, description) in SYSTEM_LEVELS {
+            insert.values_panic([
+                target.map(|t| t.to_string()).into(),
+                (*level).into(),
+                (*description).into(),
+                1.into(),
+                1.into(),
+                "system".into(),
+                Expr::current_timestamp().into(),
+            ]);
+        }
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysLogLevel::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysLogLevel {
+    Table,
+    LevelId,
+    Target,
+    LevelName,
+    Description,
+    IsSystem,
+    Status,
+    ExpireTime,
+    CreateBy,
+    CreatedTime,
+    UpdateBy,
+    UpdatedTime,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+/// 为 sys_user 增加密码修改时间，并创建密码历史表
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_table("sys_user").await?
+            && !manager.has_column("sys_user", "password_changed_time").await?
+        {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysUser::Table)
+                        .add_column(ColumnDef::new(SysUser::PasswordChangedTime).date_time().null())
+                        .to_owned(),
+                )
+                .await?;
+
+            // 已有用户从迁移时开始计算密码有效期
+            manager
+                .exec_stmt(
+                    Query::update()
+                        .table(SysUser::Table)
+                        .value(SysUser::PasswordChangedTime, Expr::current_timestamp())
+                        .and_where(Expr::col(SysUser::PasswordChangedTime).is_null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysUserPasswordHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysUserPasswordHistory::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysUserPasswordHistory::UserId).big_integer().not_null())
+                    .col(ColumnDef::new(SysUserPasswordHistory::Password).string_len(255).not_null())
+                    .col(ColumnDef::new(SysUserPasswordHistory::CreatedTime).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_user_password_history_user_id")
+                    .table(SysUserPasswordHistory::Table)
+                    .col(SysUserPasswordHistory::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysUserPasswordHistory::Table).if_exists().to_owned())
+            .await?;
+
+        if manager.has_column("sys_user", "password_changed_time").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysUser::Table)
+                        .drop_column(SysUser::PasswordChangedTime)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    PasswordChangedTime,
+}
+
+#[derive(DeriveIden)]
+enum SysUserPasswordHistory {
+    Table,
+    Id,
+    UserId,
+    Password,
+    CreatedTime,
+}
@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+/// 为 sys_user 增加偏好语言
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_table("sys_user").await? && !manager.has_column("sys_user", "locale").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysUser::Table)
+                        .add_column(ColumnDef::new(SysUser::Locale).string_len(20).null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_column("sys_user", "locale").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysUser::Table)
+                        .drop_column(SysUser::Locale)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    Locale,
+}
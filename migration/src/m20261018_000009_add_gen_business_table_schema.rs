@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+/// 为代码生成业务 gen_business 增加表所在的数据库 schema
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_table("gen_business").await? && !manager.has_column("gen_business", "table_schema").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(GenBusiness::Table)
+                        .add_column(ColumnDef::new(GenBusiness::TableSchema).string_len(64).null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_column("gen_business", "table_schema").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(GenBusiness::Table)
+                        .drop_column(GenBusiness::TableSchema)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum GenBusiness {
+    Table,
+    TableSchema,
+}
@@ -2,6 +2,8 @@
 
 use axum::{
     extract::{Json, Path, Query, State},
+    http::{header, StatusCode},
+    response::Response,
 };
 use validator::Validate;
 
@@ -35,7 +37,7 @@ pub async fn get_businesses(
     Query(params): Query<std::collections::HashMap<String, String>>,
     Query(pagination): Query<PaginationQuery>,
 ) -> Result<Json<ApiResponse<PageData<GenBusinessDetail>>>, CodeGenError> {
-    let table_name = params.get("table_name").map(|s| s.clone());
+    let table_name = params.get("table_name").cloned();
     
     let page_data = BusinessService::get_list(&state.db, table_name, pagination).await?;
     Ok(Json(ApiResponse::success(page_data)))
@@ -116,14 +118,57 @@ pub async fn get_business_paths(
     Ok(Json(ApiResponse::success(paths)))
 }
 
-/// 生成代码到本地文件系统
+/// 预演生成代码到工作区（返回变更集与差异，不写入文件）
+/// GET /codegen/businesses/{id}/plan
+pub async fn plan_business(
+    State(state): State<crate::api::AppState>,
+    Path(id): Path<i64>,
+    Query(param): Query<GenerateWorkspaceParam>,
+) -> Result<Json<ApiResponse<GenChangeset>>, CodeGenError> {
+    let changeset = CodeGenService::plan_by_business_id(&state.db, id, &param).await?;
+    Ok(Json(ApiResponse::success(changeset)))
+}
+
+/// 生成代码到工作区，存在手动修改的文件时需 force=true
 /// POST /codegen/businesses/{id}/generate
 pub async fn generate_to_filesystem(
     State(state): State<crate::api::AppState>,
     Path(id): Path<i64>,
-) -> Result<Json<ApiResponse<Vec<String>>>, CodeGenError> {
-    let created_files = CodeGenService::generate_by_business_id(&state.db, id).await?;
-    Ok(Json(ApiResponse::success(created_files)))
+    Query(param): Query<GenerateWorkspaceParam>,
+) -> Result<Json<ApiResponse<GenChangeset>>, CodeGenError> {
+    let changeset = CodeGenService::generate_by_business_id(&state.db, id, &param).await?;
+    Ok(Json(ApiResponse::success(changeset)))
+}
+
+/// 预览业务生成代码
+/// GET /codegen/businesses/{id}/preview
+pub async fn preview_business(
+    State(state): State<crate::api::AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<CodePreview>>, CodeGenError> {
+    let preview = CodeGenService::preview_business(&state.db, id).await?;
+    Ok(Json(ApiResponse::success(preview)))
+}
+
+/// 下载业务生成代码ZIP
+/// GET /codegen/businesses/{id}/download
+pub async fn download_business(
+    State(state): State<crate::api::AppState>,
+    Path(id): Path<i64>,
+) -> Result<Response, CodeGenError> {
+    let business = BusinessService::get_by_id(&state.db, id).await?;
+    let zip_data = CodeGenService::download_by_business_id(&state.db, id).await?;
+    let filename = business.filename.unwrap_or(business.table_name);
+    
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/zip")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}.zip\"", filename),
+        )
+        .body(axum::body::Body::from(zip_data))
+        .map_err(|e| CodeGenError::GenerateError(e.to_string()))
 }
 
 /// 创建业务模型路由
@@ -140,5 +185,8 @@ pub fn business_routes() -> axum::Router<crate::api::AppState> {
         .route("/{id}", delete(delete_business))
         .route("/{id}/columns", get(get_business_columns))
         .route("/{id}/paths", get(get_business_paths))  // 获取生成路径
+        .route("/{id}/preview", get(preview_business))  // 预览生成代码
+        .route("/{id}/download", get(download_business))  // 下载生成代码
+        .route("/{id}/plan", get(plan_business))  // 预演写入工作区
         .route("/{id}/generate", post(generate_to_filesystem))  // 生成到文件系统 ✨
 }
@@ -24,7 +24,10 @@ pub async fn get_tables(
     State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<Vec<TableInfo>>>, CodeGenError> {
-    let schema = params.get("schema").map(|s| s.as_str()).unwrap_or("fba");
+    let schema = params
+        .get("schema")
+        .map(|s| s.as_str())
+        .unwrap_or_else(|| CodeGenService::default_schema(&state.db));
     
     let tables = CodeGenService::get_tables(&state.db, schema).await?;
     Ok(Json(ApiResponse::success(tables)))
@@ -37,7 +40,10 @@ pub async fn get_columns(
     Path(table_name): Path<String>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<Vec<ColumnInfo>>>, CodeGenError> {
-    let schema = params.get("schema").map(|s| s.as_str()).unwrap_or("fba");
+    let schema = params
+        .get("schema")
+        .map(|s| s.as_str())
+        .unwrap_or_else(|| CodeGenService::default_schema(&state.db));
     
     let columns = CodeGenService::get_columns(&state.db, schema, &table_name).await?;
     Ok(Json(ApiResponse::success(columns)))
@@ -58,7 +64,10 @@ pub async fn preview_code(
     State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<CodePreview>>, CodeGenError> {
-    let schema = params.get("schema").map(|s| s.as_str()).unwrap_or("fba");
+    let schema = params
+        .get("schema")
+        .map(|s| s.as_str())
+        .unwrap_or_else(|| CodeGenService::default_schema(&state.db));
     let table_name = params
         .get("table_name")
         .ok_or(CodeGenError::ValidationError("缺少table_name参数".to_string()))?;
@@ -81,7 +90,7 @@ pub async fn generate_code(
     // 注意：这里只是生成预览，实际写入磁盘需要额外权限
     let preview = CodeGenService::preview_code(
         &state.db,
-        "fba",
+        CodeGenService::default_schema(&state.db),
         &param.table_name,
         &param.module_name,
     ).await?;
@@ -96,7 +105,10 @@ pub async fn download_code(
     State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Response, CodeGenError> {
-    let schema = params.get("schema").map(|s| s.as_str()).unwrap_or("fba");
+    let schema = params
+        .get("schema")
+        .map(|s| s.as_str())
+        .unwrap_or_else(|| CodeGenService::default_schema(&state.db));
     let table_name = params
         .get("table_name")
         .ok_or(CodeGenError::ValidationError("缺少table_name参数".to_string()))?;
@@ -1,11 +1,12 @@
 //! 代码生成器DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use std::collections::HashMap;
 
 /// 表信息
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct TableInfo {
     pub table_name: String,
     pub table_schema: String,
@@ -13,11 +14,13 @@ pub struct TableInfo {
 }
 
 /// 业务详情
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GenBusinessDetail {
     pub id: i64,
     pub app_name: String,
     pub table_name: String,
+    /// 表所在的数据库 schema
+    pub table_schema: Option<String>,
     pub doc_comment: String,
     pub table_comment: Option<String>,
     pub class_name: Option<String>,
@@ -31,7 +34,7 @@ pub struct GenBusinessDetail {
 }
 
 /// 创建业务请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateGenBusinessParam {
     #[validate(length(min = 1, max = 64))]
     pub app_name: String,
@@ -39,6 +42,10 @@ pub struct CreateGenBusinessParam {
     #[validate(length(min = 1, max = 256))]
     pub table_name: String,
     
+    /// 表所在的数据库 schema，为空时使用数据库默认 schema
+    #[validate(length(max = 64))]
+    pub table_schema: Option<String>,
+    
     #[validate(length(min = 1, max = 256))]
     pub doc_comment: String,
     
@@ -53,9 +60,11 @@ pub struct CreateGenBusinessParam {
 }
 
 /// 更新业务请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateGenBusinessParam {
     pub app_name: Option<String>,
+    #[validate(length(max = 64))]
+    pub table_schema: Option<String>,
     pub doc_comment: Option<String>,
     pub table_comment: Option<String>,
     pub class_name: Option<String>,
@@ -68,7 +77,7 @@ pub struct UpdateGenBusinessParam {
 }
 
 /// 列详情
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GenColumnDetail {
     pub id: i64,
     pub business_id: i64,
@@ -89,18 +98,36 @@ pub struct GenColumnDetail {
 }
 
 /// 列信息
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ColumnInfo {
     pub column_name: String,
+    /// 规范化后的类型名（小写，不含长度/精度）
     pub data_type: String,
+    /// 完整类型定义，如 `varchar(64)`、`int unsigned`
     pub column_type: String,
     pub is_nullable: String,
+    /// 键类型：PRI / UNI / MUL（外键）/ 空
     pub column_key: String,
+    pub column_default: Option<String>,
     pub column_comment: Option<String>,
+    /// 是否自增（auto_increment / serial / identity / INTEGER PRIMARY KEY）
+    #[serde(default)]
+    pub is_auto_increment: bool,
+    /// 枚举取值（MySQL enum/set、PostgreSQL enum 类型）
+    #[serde(default)]
+    pub enum_values: Vec<String>,
+    pub foreign_key: Option<ForeignKeyInfo>,
+}
+
+/// 外键引用信息
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ForeignKeyInfo {
+    pub ref_table: String,
+    pub ref_column: Option<String>,
 }
 
 /// 生成代码请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct GenerateCodeParam {
     #[validate(length(min = 1))]
     pub table_name: String,
@@ -112,7 +139,7 @@ pub struct GenerateCodeParam {
 }
 
 /// 导入表参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ImportTableParam {
     #[validate(length(min = 1, max = 64))]
     pub app: String,
@@ -124,7 +151,75 @@ pub struct ImportTableParam {
 }
 
 /// 代码预览响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CodePreview {
     pub files: HashMap<String, String>,
 }
+
+/// 写入工作区参数
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct GenerateWorkspaceParam {
+    /// 是否强制覆盖手动修改过的文件
+    #[serde(default)]
+    pub force: bool,
+    /// 是否自动注册模块（mod.rs、迁移、路由）
+    #[serde(default = "default_register")]
+    pub register: bool,
+}
+
+fn default_register() -> bool {
+    true
+}
+
+/// 文件变更类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    /// 新建文件
+    Create,
+    /// 更新生成的文件
+    Update,
+    /// 修改已有文件以注册模块
+    Patch,
+    /// 内容无变化
+    Unchanged,
+    /// 跳过
+    Skip,
+    /// 文件被手动修改，需要强制覆盖
+    Conflict,
+}
+
+/// 单个文件变更
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GenFileChange {
+    pub path: String,
+    pub action: ChangeAction,
+    /// 是否为手动修改过的文件（强制覆盖时为 true）
+    pub hand_edited: bool,
+    /// 统一差异格式（unified diff）
+    pub diff: String,
+    pub reason: Option<String>,
+    #[serde(skip)]
+    pub content: Option<String>,
+    /// 是否记录到生成清单中
+    #[serde(skip)]
+    pub tracked: bool,
+}
+
+/// 代码生成变更集
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GenChangeset {
+    pub base_path: String,
+    pub applied: bool,
+    pub files: Vec<GenFileChange>,
+}
+
+impl GenChangeset {
+    /// 存在冲突的文件
+    pub fn conflicts(&self) -> Vec<&GenFileChange> {
+        self.files
+            .iter()
+            .filter(|f| f.action == ChangeAction::Conflict)
+            .collect()
+    }
+}
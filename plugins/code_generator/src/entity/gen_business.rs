@@ -19,6 +19,10 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(256))", unique)]
     pub table_name: String,
     
+    /// 表所在的数据库 schema（为空时使用数据库默认 schema）
+    #[sea_orm(column_type = "String(StringLen::N(64))", nullable)]
+    pub table_schema: Option<String>,
+    
     /// 文档注释（用于函数/参数文档）
     #[sea_orm(column_type = "String(StringLen::N(256))")]
     pub doc_comment: String,
@@ -30,6 +30,10 @@ pub enum CodeGenError {
     /// 资源未找到
     #[error("资源未找到: {0}")]
     NotFound(String),
+    
+    /// 文件冲突
+    #[error("文件冲突: {0}")]
+    Conflict(String),
 }
 
 impl IntoResponse for CodeGenError {
@@ -40,6 +44,7 @@ impl IntoResponse for CodeGenError {
             CodeGenError::TemplateError(msg) => (StatusCode::BAD_REQUEST, 400, msg),
             CodeGenError::ValidationError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, 422, msg),
             CodeGenError::NotFound(msg) => (StatusCode::NOT_FOUND, 404, msg),
+            CodeGenError::Conflict(msg) => (StatusCode::CONFLICT, 409, msg),
         };
 
         let body = Json(json!({
@@ -55,8 +60,8 @@ impl From<validator::ValidationErrors> for CodeGenError {
     fn from(errors: validator::ValidationErrors) -> Self {
         let messages: Vec<String> = errors
             .field_errors()
-            .iter()
-            .flat_map(|(_, errs)| {
+            .values()
+            .flat_map(|errs| {
                 errs.iter()
                     .filter_map(|e| e.message.as_ref().map(|m| m.to_string()))
             })
@@ -3,9 +3,10 @@
 //! 
 //! # 功能特性
 //! - 代码生成器（6个API端点，完整实现）
-//! - 数据库表扫描（MySQL/PostgreSQL）
+//! - 数据库表扫描（MySQL/PostgreSQL/SQLite，参数化查询）
 //! - 字段信息自动提取
-//! - 模板代码生成（Entity/DTO/Service/API）
+//! - Tera 模板代码生成（Entity/DTO/Service/API/Router/Migration/TypeScript/菜单SQL），
+//!   可通过 `CODEGEN_TEMPLATE_DIR` 目录覆盖内置模板
 //! - ZIP打包下载
 //! - 类型智能映射
 //! 
@@ -66,7 +67,7 @@ impl CodeGeneratorPlugin {
 
 // 导出公共类型
 pub use dto::{
-    TableInfo, ColumnInfo, GenerateCodeParam, CodePreview, ImportTableParam,
+    TableInfo, ColumnInfo, ForeignKeyInfo, GenerateCodeParam, CodePreview, ImportTableParam,
     GenBusinessDetail, CreateGenBusinessParam, UpdateGenBusinessParam, GenColumnDetail,
     PaginationQuery, PageData, ApiResponse,
 };
@@ -5,7 +5,7 @@ use sea_orm::*;
 use crate::entity::{gen_business, gen_column};
 use crate::dto::*;
 use crate::error::CodeGenError;
-use crate::service::CodeGenService;
+use crate::service::{CodeGenService, TemplateService};
 
 /// 业务模型服务
 pub struct BusinessService;
@@ -77,6 +77,7 @@ impl BusinessService {
         let business = gen_business::ActiveModel {
             app_name: Set(param.app_name),
             table_name: Set(param.table_name),
+            table_schema: Set(param.table_schema),
             doc_comment: Set(param.doc_comment),
             table_comment: Set(param.table_comment),
             class_name: Set(param.class_name),
@@ -116,6 +117,9 @@ impl BusinessService {
         if let Some(app_name) = param.app_name {
             business.app_name = Set(app_name);
         }
+        if let Some(table_schema) = param.table_schema {
+            business.table_schema = Set(Some(table_schema));
+        }
         if let Some(doc_comment) = param.doc_comment {
             business.doc_comment = Set(doc_comment);
         }
@@ -194,6 +198,7 @@ impl BusinessService {
             id: model.id,
             app_name: model.app_name,
             table_name: model.table_name,
+            table_schema: model.table_schema,
             doc_comment: model.doc_comment,
             table_comment: model.table_comment,
             class_name: model.class_name,
@@ -234,7 +239,10 @@ impl BusinessService {
         db: &DatabaseConnection,
         param: ImportTableParam,
     ) -> Result<GenBusinessDetail, CodeGenError> {
-        let schema = param.table_schema.as_deref().unwrap_or("fba");
+        let schema = param
+            .table_schema
+            .as_deref()
+            .unwrap_or_else(|| CodeGenService::default_schema(db));
         
         // 检查表是否存在
         let table = CodeGenService::get_tables(db, schema)
@@ -256,12 +264,13 @@ impl BusinessService {
         let now = chrono::Utc::now().naive_utc();
         let class_name = CodeGenService::to_pascal_case(&param.table_name);
         let doc_comment = table.table_comment.clone()
-            .unwrap_or_else(|| param.table_name.split('_').last().unwrap_or("").to_string());
+            .unwrap_or_else(|| param.table_name.split('_').next_back().unwrap_or("").to_string());
         
         // 创建业务记录
         let business = gen_business::ActiveModel {
             app_name: Set(param.app.clone()),
             table_name: Set(param.table_name.clone()),
+            table_schema: Set(Some(schema.to_string())),
             doc_comment: Set(doc_comment),
             table_comment: Set(table.table_comment),
             class_name: Set(Some(class_name.clone())),
@@ -293,12 +302,12 @@ impl BusinessService {
                 ts_type: Set(Some(Self::map_db_type_to_ts(&col.data_type))),
                 required: Set(col.is_nullable == "NO"),
                 is_pk: Set(col.column_key == "PRI"),
-                is_fk: Set(col.column_key == "MUL"),
+                is_fk: Set(col.foreign_key.is_some()),
                 is_query: Set(true),
                 is_list: Set(true),
                 is_form: Set(col.column_key != "PRI"),
                 query_type: Set(Some("eq".to_string())),
-                form_type: Set(Some("input".to_string())),
+                form_type: Set(Some(Self::default_form_type(col).to_string())),
                 sort: Set(idx as i32),
                 created_time: Set(now),
                 ..Default::default()
@@ -318,30 +327,42 @@ impl BusinessService {
         db: &DatabaseConnection,
         business_id: i64,
     ) -> Result<Vec<String>, CodeGenError> {
-        let business = Self::get_by_id(db, business_id).await?;
-        
-        let base_path = business.gen_path.unwrap_or_else(|| 
-            format!("backend/app/{}", business.app_name)
-        );
+        let ctx = CodeGenService::build_business_context(db, business_id).await?;
         
-        let mut paths = Vec::new();
-        paths.push(format!("{}/api/{}/{}.rs", base_path, business.api_version, business.filename.clone().unwrap_or(business.table_name.clone())));
-        paths.push(format!("{}/model/{}.rs", base_path, business.filename.clone().unwrap_or(business.table_name.clone())));
-        paths.push(format!("{}/schema/{}.rs", base_path, business.filename.clone().unwrap_or(business.table_name.clone())));
-        paths.push(format!("{}/service/{}_service.rs", base_path, business.filename.clone().unwrap_or(business.table_name.clone())));
-        paths.push(format!("{}/crud/crud_{}.rs", base_path, business.filename.clone().unwrap_or(business.table_name.clone())));
+        let mut paths: Vec<String> = TemplateService::template_names()
+            .iter()
+            .map(|name| TemplateService::output_path(name, &ctx))
+            .collect();
+        paths.sort();
         
         Ok(paths)
     }
     
+    /// 根据列信息推断默认表单类型
+    fn default_form_type(col: &ColumnInfo) -> &'static str {
+        if !col.enum_values.is_empty() || col.foreign_key.is_some() {
+            return "select";
+        }
+        match col.data_type.as_str() {
+            "datetime" | "timestamp" | "timestamptz" | "date" => "date",
+            "text" | "longtext" => "textarea",
+            "bool" | "boolean" => "switch",
+            _ => "input",
+        }
+    }
+
     /// 数据库类型映射到Python类型
     fn map_db_type_to_python(db_type: &str) -> String {
         match db_type.to_lowercase().as_str() {
-            "int" | "integer" | "tinyint" | "smallint" | "bigint" => "int",
-            "varchar" | "char" | "text" | "longtext" => "str",
-            "datetime" | "timestamp" => "datetime",
-            "decimal" | "float" | "double" => "float",
+            "int" | "integer" | "tinyint" | "smallint" | "mediumint" | "bigint"
+            | "int2" | "int4" | "int8" => "int",
+            "varchar" | "char" | "text" | "longtext" | "bpchar" | "enum" | "uuid" => "str",
+            "datetime" | "timestamp" | "timestamptz" => "datetime",
+            "date" => "date",
+            "decimal" | "numeric" => "Decimal",
+            "float" | "double" | "real" | "float4" | "float8" => "float",
             "bool" | "boolean" => "bool",
+            "json" | "jsonb" => "dict",
             _ => "str",
         }.to_string()
     }
@@ -349,11 +370,13 @@ impl BusinessService {
     /// 数据库类型映射到TypeScript类型
     fn map_db_type_to_ts(db_type: &str) -> String {
         match db_type.to_lowercase().as_str() {
-            "int" | "integer" | "tinyint" | "smallint" | "bigint" => "number",
-            "varchar" | "char" | "text" | "longtext" => "string",
-            "datetime" | "timestamp" => "string",
-            "decimal" | "float" | "double" => "number",
+            "int" | "integer" | "tinyint" | "smallint" | "mediumint" | "bigint"
+            | "int2" | "int4" | "int8" => "number",
+            "varchar" | "char" | "text" | "longtext" | "bpchar" | "enum" | "uuid" => "string",
+            "datetime" | "timestamp" | "timestamptz" | "date" => "string",
+            "decimal" | "numeric" | "float" | "double" | "real" | "float4" | "float8" => "number",
             "bool" | "boolean" => "boolean",
+            "json" | "jsonb" => "Record<string, any>",
             _ => "string",
         }.to_string()
     }
@@ -1,13 +1,18 @@
 //! 代码生成服务层
 
 use sea_orm::*;
-use std::collections::HashMap;
 use std::io::{Write, Cursor};
 use zip::ZipWriter;
 use zip::write::FileOptions;
 
 use crate::dto::*;
 use crate::error::CodeGenError;
+use crate::entity::{gen_business, gen_column};
+use crate::service::introspection;
+use crate::service::workspace_service::WorkspaceService;
+use crate::service::template_service::{
+    self, ColumnOptions, GenContext, GenMeta, TemplateService,
+};
 
 /// 代码生成服务
 pub struct CodeGenService;
@@ -18,38 +23,7 @@ impl CodeGenService {
         db: &DatabaseConnection,
         schema: &str,
     ) -> Result<Vec<TableInfo>, CodeGenError> {
-        let query = format!(
-            r#"
-            SELECT 
-                table_name,
-                table_schema,
-                table_comment
-            FROM information_schema.tables
-            WHERE table_schema = '{}'
-            AND table_type = 'BASE TABLE'
-            ORDER BY table_name
-            "#,
-            schema
-        );
-
-        let tables = db
-            .query_all(Statement::from_string(
-                DatabaseBackend::MySql,
-                query,
-            ))
-            .await
-            .map_err(|e| CodeGenError::DatabaseError(e.to_string()))?;
-
-        let result = tables
-            .iter()
-            .map(|row| TableInfo {
-                table_name: row.try_get("", "table_name").unwrap_or_default(),
-                table_schema: row.try_get("", "table_schema").unwrap_or_default(),
-                table_comment: row.try_get("", "table_comment").ok(),
-            })
-            .collect();
-
-        Ok(result)
+        introspection::get_tables(db, schema).await
     }
 
     /// 获取表字段信息
@@ -58,308 +32,169 @@ impl CodeGenService {
         schema: &str,
         table_name: &str,
     ) -> Result<Vec<ColumnInfo>, CodeGenError> {
-        let query = format!(
-            r#"
-            SELECT 
-                column_name,
-                data_type,
-                column_type,
-                is_nullable,
-                column_key,
-                column_comment
-            FROM information_schema.columns
-            WHERE table_schema = '{}'
-            AND table_name = '{}'
-            ORDER BY ordinal_position
-            "#,
-            schema, table_name
-        );
-
-        let columns = db
-            .query_all(Statement::from_string(
-                DatabaseBackend::MySql,
-                query,
-            ))
-            .await
-            .map_err(|e| CodeGenError::DatabaseError(e.to_string()))?;
-
-        let result = columns
-            .iter()
-            .map(|row| ColumnInfo {
-                column_name: row.try_get("", "column_name").unwrap_or_default(),
-                data_type: row.try_get("", "data_type").unwrap_or_default(),
-                column_type: row.try_get("", "column_type").unwrap_or_default(),
-                is_nullable: row.try_get("", "is_nullable").unwrap_or_default(),
-                column_key: row.try_get("", "column_key").unwrap_or_default(),
-                column_comment: row.try_get("", "column_comment").ok(),
-            })
-            .collect();
+        introspection::get_columns(db, schema, table_name).await
+    }
 
-        Ok(result)
+    /// 获取当前数据库后端的默认schema
+    pub fn default_schema(db: &DatabaseConnection) -> &'static str {
+        introspection::default_schema(db.get_database_backend())
     }
 
     /// 获取可用模板列表
     pub fn get_templates() -> Vec<String> {
-        vec![
-            "entity".to_string(),
-            "dto".to_string(),
-            "service".to_string(),
-            "api".to_string(),
-            "crud_full".to_string(),
-        ]
+        TemplateService::template_names()
     }
 
-    /// 生成代码预览
+    /// 生成代码预览（直接基于数据库表结构，使用默认生成选项）
     pub async fn preview_code(
         db: &DatabaseConnection,
         schema: &str,
         table_name: &str,
         module_name: &str,
     ) -> Result<CodePreview, CodeGenError> {
-        let columns = Self::get_columns(db, schema, table_name).await?;
-        
-        let mut files = HashMap::new();
-        
-        // 生成Entity代码
-        let entity_code = Self::generate_entity(table_name, module_name, &columns)?;
-        files.insert(format!("entity/{}.rs", module_name), entity_code);
-        
-        // 生成DTO代码
-        let dto_code = Self::generate_dto(table_name, module_name, &columns)?;
-        files.insert(format!("dto/{}_dto.rs", module_name), dto_code);
-        
-        // 生成Service代码
-        let service_code = Self::generate_service(table_name, module_name)?;
-        files.insert(format!("service/{}_service.rs", module_name), service_code);
-        
-        // 生成API代码
-        let api_code = Self::generate_api(table_name, module_name)?;
-        files.insert(format!("api/{}_api.rs", module_name), api_code);
-        
-        Ok(CodePreview { files })
+        let ctx = Self::build_table_context(db, schema, table_name, module_name).await?;
+        Ok(CodePreview {
+            files: TemplateService::render_all(&ctx)?,
+        })
     }
 
-    /// 生成Entity代码
-    fn generate_entity(
+    /// 构建数据库表的生成上下文
+    pub async fn build_table_context(
+        db: &DatabaseConnection,
+        schema: &str,
         table_name: &str,
         module_name: &str,
-        columns: &[ColumnInfo],
-    ) -> Result<String, CodeGenError> {
-        let mut code = String::new();
-        code.push_str(&format!("//! {} Entity\n\n", module_name));
-        code.push_str("use sea_orm::entity::prelude::*;\n");
-        code.push_str("use serde::{Deserialize, Serialize};\n\n");
-        code.push_str("#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]\n");
-        code.push_str(&format!("#[sea_orm(table_name = \"{}\")]\n", table_name));
-        code.push_str("pub struct Model {\n");
-        
-        for col in columns {
-            if let Some(comment) = &col.column_comment {
-                code.push_str(&format!("    /// {}\n", comment));
-            }
-            if col.column_key == "PRI" {
-                code.push_str("    #[sea_orm(primary_key)]\n");
-            }
-            let rust_type = Self::map_db_type_to_rust(&col.data_type, &col.is_nullable);
-            code.push_str(&format!("    pub {}: {},\n", col.column_name, rust_type));
-        }
-        
-        code.push_str("}\n\n");
-        code.push_str("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
-        code.push_str("pub enum Relation {}\n\n");
-        code.push_str("impl ActiveModelBehavior for ActiveModel {}\n");
-        
-        Ok(code)
-    }
-
-    /// 生成DTO代码
-    fn generate_dto(
-        _table_name: &str,
-        module_name: &str,
-        columns: &[ColumnInfo],
-    ) -> Result<String, CodeGenError> {
-        let mut code = String::new();
-        code.push_str(&format!("//! {} DTO\n\n", module_name));
-        code.push_str("use serde::{Deserialize, Serialize};\n");
-        code.push_str("use validator::Validate;\n\n");
-        
-        // 创建请求DTO
-        code.push_str(&format!("#[derive(Debug, Deserialize, Validate)]\n"));
-        code.push_str(&format!("pub struct Create{}Param {{\n", Self::to_pascal_case(module_name)));
-        
-        for col in columns {
-            if col.column_key != "PRI" {
-                let rust_type = Self::map_db_type_to_rust(&col.data_type, &col.is_nullable);
-                code.push_str(&format!("    pub {}: {},\n", col.column_name, rust_type));
-            }
-        }
-        code.push_str("}\n\n");
-        
-        // 更新请求DTO
-        code.push_str(&format!("#[derive(Debug, Deserialize, Validate)]\n"));
-        code.push_str(&format!("pub struct Update{}Param {{\n", Self::to_pascal_case(module_name)));
-        
-        for col in columns {
-            if col.column_key != "PRI" {
-                let rust_type = Self::map_db_type_to_rust(&col.data_type, &col.is_nullable);
-                code.push_str(&format!("    pub {}: {},\n", col.column_name, rust_type));
-            }
-        }
-        code.push_str("}\n");
-        
-        Ok(code)
-    }
-
-    /// 生成Service代码
-    fn generate_service(
-        _table_name: &str,
-        module_name: &str,
-    ) -> Result<String, CodeGenError> {
-        let pascal_name = Self::to_pascal_case(module_name);
-        let code = format!(
-            r#"//! {} Service
-
-use sea_orm::*;
+    ) -> Result<GenContext, CodeGenError> {
+        let tables = Self::get_tables(db, schema).await?;
+        let table = tables
+            .into_iter()
+            .find(|t| t.table_name == table_name)
+            .ok_or(CodeGenError::NotFound("数据库表不存在".to_string()))?;
+        let columns = Self::get_columns(db, schema, table_name).await?;
 
-pub struct {}Service;
+        let doc_comment = table
+            .table_comment
+            .clone()
+            .unwrap_or_else(|| module_name.to_string());
+        let meta = GenMeta {
+            app_name: module_name.to_string(),
+            table_name: table_name.to_string(),
+            module_name: module_name.to_string(),
+            class_name: Self::to_pascal_case(module_name),
+            doc_comment,
+            table_comment: table.table_comment,
+            api_version: "v1".to_string(),
+            default_datetime_column: true,
+        };
+        let columns = columns
+            .into_iter()
+            .map(|col| {
+                let opts = ColumnOptions::defaults(&col);
+                (col, opts)
+            })
+            .collect();
 
-impl {}Service {{
-    /// 创建
-    pub async fn create(db: &DatabaseConnection, param: Create{}Param) -> Result<Model, DbErr> {{
-        // TODO: 实现创建逻辑
-        unimplemented!()
-    }}
-    
-    /// 更新
-    pub async fn update(db: &DatabaseConnection, id: i64, param: Update{}Param) -> Result<Model, DbErr> {{
-        // TODO: 实现更新逻辑
-        unimplemented!()
-    }}
-    
-    /// 删除
-    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), DbErr> {{
-        // TODO: 实现删除逻辑
-        unimplemented!()
-    }}
-    
-    /// 根据ID获取
-    pub async fn get_by_id(db: &DatabaseConnection, id: i64) -> Result<Option<Model>, DbErr> {{
-        // TODO: 实现查询逻辑
-        unimplemented!()
-    }}
-}}
-"#,
-            module_name, pascal_name, pascal_name, pascal_name, pascal_name
-        );
-        
-        Ok(code)
+        TemplateService::build_context(db.get_database_backend(), meta, columns)
     }
 
-    /// 生成API代码
-    fn generate_api(
-        _table_name: &str,
-        module_name: &str,
-    ) -> Result<String, CodeGenError> {
-        let pascal_name = Self::to_pascal_case(module_name);
-        let code = format!(
-            r#"//! {} API
+    /// 基于业务模型生成代码预览（使用 gen_column 中的生成选项）
+    pub async fn preview_business(
+        db: &DatabaseConnection,
+        business_id: i64,
+    ) -> Result<CodePreview, CodeGenError> {
+        let ctx = Self::build_business_context(db, business_id).await?;
+        Ok(CodePreview {
+            files: TemplateService::render_all(&ctx)?,
+        })
+    }
 
-use axum::{{
-    extract::{{Path, State}},
-    response::Json,
-    routing::{{get, post, put, delete}},
-    Router,
-}};
-use sea_orm::DatabaseConnection;
+    /// 构建业务模型的生成上下文
+    pub async fn build_business_context(
+        db: &DatabaseConnection,
+        business_id: i64,
+    ) -> Result<GenContext, CodeGenError> {
+        let business = gen_business::Entity::find_by_id(business_id)
+            .one(db)
+            .await
+            .map_err(|e| CodeGenError::DatabaseError(e.to_string()))?
+            .ok_or(CodeGenError::NotFound("业务不存在".to_string()))?;
+        let gen_columns = gen_column::Entity::find_by_business_id(business_id)
+            .all(db)
+            .await
+            .map_err(|e| CodeGenError::DatabaseError(e.to_string()))?;
+        if gen_columns.is_empty() {
+            return Err(CodeGenError::ValidationError("业务没有列信息".to_string()));
+        }
 
-/// 创建{}
-pub async fn create_{}(
-    State(db): State<DatabaseConnection>,
-    Json(param): Json<Create{}Param>,
-) -> Result<Json<ApiResponse<Model>>, AppError> {{
-    let data = {}Service::create(&db, param).await?;
-    Ok(Json(ApiResponse::success(data)))
-}}
+        // 以当前数据库中的表结构为准补全长度、自增、枚举等信息，表不存在时退回到 gen_column
+        let backend = db.get_database_backend();
+        let schema = business
+            .table_schema
+            .as_deref()
+            .filter(|schema| !schema.is_empty())
+            .unwrap_or_else(|| Self::default_schema(db));
+        let live_columns = Self::get_columns(db, schema, &business.table_name)
+            .await
+            .unwrap_or_default();
 
-/// 更新{}
-pub async fn update_{}(
-    State(db): State<DatabaseConnection>,
-    Path(id): Path<i64>,
-    Json(param): Json<Update{}Param>,
-) -> Result<Json<ApiResponse<Model>>, AppError> {{
-    let data = {}Service::update(&db, id, param).await?;
-    Ok(Json(ApiResponse::success(data)))
-}}
+        let columns = gen_columns
+            .iter()
+            .map(|model| {
+                let col = live_columns
+                    .iter()
+                    .find(|c| c.column_name == model.column_name)
+                    .cloned()
+                    .unwrap_or_else(|| Self::column_from_model(model));
+                (col, ColumnOptions::from(model))
+            })
+            .collect();
 
-/// 删除{}
-pub async fn delete_{}(
-    State(db): State<DatabaseConnection>,
-    Path(id): Path<i64>,
-) -> Result<Json<ApiResponse<()>>, AppError> {{
-    {}Service::delete(&db, id).await?;
-    Ok(Json(ApiResponse::success_msg("删除成功")))
-}}
+        let module_name = business
+            .filename
+            .clone()
+            .unwrap_or_else(|| business.table_name.clone());
+        let meta = GenMeta {
+            class_name: business
+                .class_name
+                .clone()
+                .unwrap_or_else(|| Self::to_pascal_case(&business.table_name)),
+            app_name: business.app_name,
+            table_name: business.table_name,
+            module_name,
+            doc_comment: business.doc_comment,
+            table_comment: business.table_comment,
+            api_version: business.api_version,
+            default_datetime_column: business.default_datetime_column,
+        };
 
-/// 获取{}
-pub async fn get_{}(
-    State(db): State<DatabaseConnection>,
-    Path(id): Path<i64>,
-) -> Result<Json<ApiResponse<Model>>, AppError> {{
-    let data = {}Service::get_by_id(&db, id).await?
-        .ok_or(AppError::NotFound)?;
-    Ok(Json(ApiResponse::success(data)))
-}}
+        TemplateService::build_context(backend, meta, columns)
+    }
 
-/// 创建路由
-pub fn {}_routes() -> Router<DatabaseConnection> {{
-    Router::new()
-        .route("/", post(create_{}))
-        .route("/{{id}}", get(get_{}))
-        .route("/{{id}}", put(update_{}))
-        .route("/{{id}}", delete(delete_{}))
-}}
-"#,
-            module_name,
-            module_name, module_name, pascal_name, pascal_name,
-            module_name, module_name, pascal_name, pascal_name,
-            module_name, module_name, pascal_name,
-            module_name, module_name, pascal_name,
-            module_name, module_name, module_name, module_name, module_name
-        );
-        
-        Ok(code)
+    /// 由 gen_column 记录还原列信息
+    fn column_from_model(model: &gen_column::Model) -> ColumnInfo {
+        let data_type = model.column_type.to_lowercase();
+        ColumnInfo {
+            column_name: model.column_name.clone(),
+            is_auto_increment: model.is_pk && data_type.contains("int"),
+            column_type: model.column_type.clone(),
+            data_type,
+            is_nullable: if model.required { "NO" } else { "YES" }.to_string(),
+            column_key: if model.is_pk { "PRI" } else { "" }.to_string(),
+            column_default: None,
+            column_comment: model.column_comment.clone(),
+            enum_values: Vec::new(),
+            foreign_key: None,
+        }
     }
 
     /// 数据库类型映射到Rust类型
-    fn map_db_type_to_rust(db_type: &str, is_nullable: &str) -> String {
-        let base_type = match db_type {
-            "int" | "integer" | "tinyint" | "smallint" => "i32",
-            "bigint" => "i64",
-            "varchar" | "char" | "text" | "longtext" => "String",
-            "datetime" | "timestamp" => "DateTime",
-            "decimal" | "float" | "double" => "f64",
-            "bool" | "boolean" => "bool",
-            _ => "String",
-        };
-        
-        if is_nullable == "YES" {
-            format!("Option<{}>", base_type)
-        } else {
-            base_type.to_string()
-        }
+    pub fn map_db_type_to_rust(backend: DatabaseBackend, col: &ColumnInfo) -> String {
+        introspection::map_db_type_to_rust(backend, col)
     }
 
     /// 转换为PascalCase
     pub fn to_pascal_case(s: &str) -> String {
-        s.split('_')
-            .map(|word| {
-                let mut c = word.chars();
-                match c.next() {
-                    None => String::new(),
-                    Some(f) => f.to_uppercase().chain(c).collect(),
-                }
-            })
-            .collect()
+        template_service::to_pascal_case(s)
     }
 
     /// 下载代码为ZIP
@@ -370,7 +205,20 @@ pub fn {}_routes() -> Router<DatabaseConnection> {{
         module_name: &str,
     ) -> Result<Vec<u8>, CodeGenError> {
         let preview = Self::preview_code(db, schema, table_name, module_name).await?;
-        
+        Self::zip_preview(preview)
+    }
+
+    /// 基于业务ID下载代码ZIP
+    pub async fn download_by_business_id(
+        db: &DatabaseConnection,
+        business_id: i64,
+    ) -> Result<Vec<u8>, CodeGenError> {
+        let preview = Self::preview_business(db, business_id).await?;
+        Self::zip_preview(preview)
+    }
+
+    /// 将预览结果打包为ZIP
+    fn zip_preview(preview: CodePreview) -> Result<Vec<u8>, CodeGenError> {
         let mut cursor = Cursor::new(Vec::new());
         {
             let mut zip = ZipWriter::new(&mut cursor);
@@ -391,72 +239,50 @@ pub fn {}_routes() -> Router<DatabaseConnection> {{
         Ok(cursor.into_inner())
     }
 
-    /// 生成代码到本地文件系统
+    /// 生成代码到本地文件系统（不覆盖手动修改过的文件，不注册模块）
     pub async fn generate_to_filesystem(
         db: &DatabaseConnection,
         schema: &str,
         table_name: &str,
         module_name: &str,
         base_path: Option<&str>,
-    ) -> Result<Vec<String>, CodeGenError> {
-        use tokio::fs;
-        use std::path::PathBuf;
-        
-        let preview = Self::preview_code(db, schema, table_name, module_name).await?;
-        let base = base_path.unwrap_or("./generated");
-        let base_path = PathBuf::from(base);
-        
-        let mut created_files = Vec::new();
-        
-        for (file_path, content) in preview.files {
-            let full_path = base_path.join(&file_path);
-            
-            // 创建父目录
-            if let Some(parent) = full_path.parent() {
-                fs::create_dir_all(parent)
-                    .await
-                    .map_err(|e| CodeGenError::GenerateError(format!("创建目录失败: {}", e)))?;
-                
-                // 为Rust项目创建mod.rs（相当于Python的__init__.py）
-                let mod_file = parent.join("mod.rs");
-                if !mod_file.exists() {
-                    fs::write(&mod_file, "// Auto-generated module\n")
-                        .await
-                        .map_err(|e| CodeGenError::GenerateError(format!("创建mod.rs失败: {}", e)))?;
-                }
-            }
-            
-            // 写入代码文件
-            fs::write(&full_path, content.as_bytes())
-                .await
-                .map_err(|e| CodeGenError::GenerateError(format!("写入文件失败: {}", e)))?;
-            
-            created_files.push(full_path.display().to_string());
-        }
-        
-        Ok(created_files)
+    ) -> Result<GenChangeset, CodeGenError> {
+        let ctx = Self::build_table_context(db, schema, table_name, module_name).await?;
+        let options = GenerateWorkspaceParam {
+            force: false,
+            register: false,
+        };
+        let changeset = WorkspaceService::plan(
+            &ctx,
+            TemplateService::render_all(&ctx)?,
+            base_path.unwrap_or("./generated"),
+            &options,
+        )?;
+        WorkspaceService::apply(changeset).await
     }
 
-    /// 基于业务ID生成代码到文件系统
-    pub async fn generate_by_business_id(
+    /// 计算业务代码写入工作区的变更集（预演，不写入文件）
+    pub async fn plan_by_business_id(
         db: &DatabaseConnection,
         business_id: i64,
-    ) -> Result<Vec<String>, CodeGenError> {
+        options: &GenerateWorkspaceParam,
+    ) -> Result<GenChangeset, CodeGenError> {
         use crate::service::BusinessService;
-        
-        // 获取业务信息
+
         let business = BusinessService::get_by_id(db, business_id).await?;
-        
-        // 生成代码
         let base_path = business.gen_path.as_deref().unwrap_or("./generated");
-        let schema = "fba"; // 可以从配置读取
-        
-        Self::generate_to_filesystem(
-            db,
-            schema,
-            &business.table_name,
-            business.filename.as_deref().unwrap_or(&business.table_name),
-            Some(base_path),
-        ).await
+
+        let ctx = Self::build_business_context(db, business_id).await?;
+        WorkspaceService::plan(&ctx, TemplateService::render_all(&ctx)?, base_path, options)
+    }
+
+    /// 基于业务ID生成代码到工作区
+    pub async fn generate_by_business_id(
+        db: &DatabaseConnection,
+        business_id: i64,
+        options: &GenerateWorkspaceParam,
+    ) -> Result<GenChangeset, CodeGenError> {
+        let changeset = Self::plan_by_business_id(db, business_id, options).await?;
+        WorkspaceService::apply(changeset).await
     }
 }
@@ -0,0 +1,633 @@
+//! 数据库表结构内省
+//!
+//! 按数据库后端（MySQL / PostgreSQL / SQLite）分别查询系统目录，
+//! 所有用户输入均通过绑定参数传入，不做字符串拼接。
+
+use sea_orm::*;
+use std::collections::HashMap;
+
+use crate::dto::*;
+use crate::error::CodeGenError;
+
+/// 获取后端默认的 schema
+pub fn default_schema(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::MySql => "fba",
+        DatabaseBackend::Postgres => "public",
+        DatabaseBackend::Sqlite => "main",
+    }
+}
+
+/// 获取所有表
+pub async fn get_tables(
+    db: &DatabaseConnection,
+    schema: &str,
+) -> Result<Vec<TableInfo>, CodeGenError> {
+    let backend = db.get_database_backend();
+    let stmt = match backend {
+        DatabaseBackend::MySql => Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                TABLE_NAME AS table_name,
+                TABLE_SCHEMA AS table_schema,
+                CAST(TABLE_COMMENT AS CHAR) AS table_comment
+            FROM information_schema.TABLES
+            WHERE TABLE_SCHEMA = ?
+            AND TABLE_TYPE = 'BASE TABLE'
+            ORDER BY TABLE_NAME
+            "#,
+            [schema.into()],
+        ),
+        DatabaseBackend::Postgres => Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                c.relname::text AS table_name,
+                n.nspname::text AS table_schema,
+                pg_catalog.obj_description(c.oid, 'pg_class') AS table_comment
+            FROM pg_catalog.pg_class c
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1
+            AND c.relkind IN ('r', 'p')
+            ORDER BY c.relname
+            "#,
+            [schema.into()],
+        ),
+        DatabaseBackend::Sqlite => Statement::from_string(
+            backend,
+            r#"
+            SELECT
+                name AS table_name,
+                'main' AS table_schema,
+                NULL AS table_comment
+            FROM sqlite_master
+            WHERE type = 'table'
+            AND name NOT LIKE 'sqlite_%'
+            ORDER BY name
+            "#,
+        ),
+    };
+
+    let rows = db
+        .query_all(stmt)
+        .await
+        .map_err(|e| CodeGenError::DatabaseError(e.to_string()))?;
+
+    let result = rows
+        .iter()
+        .map(|row| TableInfo {
+            table_name: row.try_get("", "table_name").unwrap_or_default(),
+            table_schema: row.try_get("", "table_schema").unwrap_or_default(),
+            table_comment: non_empty(row.try_get("", "table_comment").ok().flatten()),
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// 获取表字段信息
+pub async fn get_columns(
+    db: &DatabaseConnection,
+    schema: &str,
+    table_name: &str,
+) -> Result<Vec<ColumnInfo>, CodeGenError> {
+    match db.get_database_backend() {
+        DatabaseBackend::MySql => get_mysql_columns(db, schema, table_name).await,
+        DatabaseBackend::Postgres => get_postgres_columns(db, schema, table_name).await,
+        DatabaseBackend::Sqlite => get_sqlite_columns(db, table_name).await,
+    }
+}
+
+async fn get_mysql_columns(
+    db: &DatabaseConnection,
+    schema: &str,
+    table_name: &str,
+) -> Result<Vec<ColumnInfo>, CodeGenError> {
+    let backend = DatabaseBackend::MySql;
+    let columns = query_all(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                COLUMN_NAME AS column_name,
+                DATA_TYPE AS data_type,
+                CAST(COLUMN_TYPE AS CHAR) AS column_type,
+                IS_NULLABLE AS is_nullable,
+                COLUMN_KEY AS column_key,
+                CAST(COLUMN_DEFAULT AS CHAR) AS column_default,
+                CAST(COLUMN_COMMENT AS CHAR) AS column_comment,
+                EXTRA AS extra
+            FROM information_schema.COLUMNS
+            WHERE TABLE_SCHEMA = ?
+            AND TABLE_NAME = ?
+            ORDER BY ORDINAL_POSITION
+            "#,
+            [schema.into(), table_name.into()],
+        ),
+    )
+    .await?;
+
+    let foreign_keys = query_foreign_keys(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                COLUMN_NAME AS column_name,
+                REFERENCED_TABLE_NAME AS ref_table,
+                REFERENCED_COLUMN_NAME AS ref_column
+            FROM information_schema.KEY_COLUMN_USAGE
+            WHERE TABLE_SCHEMA = ?
+            AND TABLE_NAME = ?
+            AND REFERENCED_TABLE_NAME IS NOT NULL
+            "#,
+            [schema.into(), table_name.into()],
+        ),
+    )
+    .await?;
+
+    let result = columns
+        .iter()
+        .map(|row| {
+            let column_name: String = row.try_get("", "column_name").unwrap_or_default();
+            let data_type: String = row.try_get("", "data_type").unwrap_or_default();
+            let column_type: String = row.try_get("", "column_type").unwrap_or_default();
+            let extra: String = row.try_get("", "extra").unwrap_or_default();
+            let data_type = data_type.to_lowercase();
+            let enum_values = if data_type == "enum" || data_type == "set" {
+                parse_mysql_enum_values(&column_type)
+            } else {
+                Vec::new()
+            };
+
+            ColumnInfo {
+                foreign_key: foreign_keys.get(&column_name).cloned(),
+                data_type,
+                column_type,
+                is_nullable: row.try_get("", "is_nullable").unwrap_or_default(),
+                column_key: row.try_get("", "column_key").unwrap_or_default(),
+                column_default: row.try_get("", "column_default").ok().flatten(),
+                column_comment: non_empty(row.try_get("", "column_comment").ok().flatten()),
+                is_auto_increment: extra.to_lowercase().contains("auto_increment"),
+                enum_values,
+                column_name,
+            }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+async fn get_postgres_columns(
+    db: &DatabaseConnection,
+    schema: &str,
+    table_name: &str,
+) -> Result<Vec<ColumnInfo>, CodeGenError> {
+    let backend = DatabaseBackend::Postgres;
+    let columns = query_all(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                a.attname::text AS column_name,
+                CASE WHEN t.typtype = 'e' THEN 'enum' ELSE t.typname::text END AS data_type,
+                pg_catalog.format_type(a.atttypid, a.atttypmod) AS column_type,
+                CASE WHEN a.attnotnull THEN 'NO' ELSE 'YES' END AS is_nullable,
+                pg_catalog.pg_get_expr(d.adbin, d.adrelid) AS column_default,
+                pg_catalog.col_description(c.oid, a.attnum) AS column_comment,
+                a.attidentity::text AS identity,
+                (
+                    SELECT array_to_json(array_agg(e.enumlabel ORDER BY e.enumsortorder))::text
+                    FROM pg_catalog.pg_enum e
+                    WHERE e.enumtypid = t.oid
+                ) AS enum_values
+            FROM pg_catalog.pg_attribute a
+            JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+            LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+            WHERE n.nspname = $1
+            AND c.relname = $2
+            AND a.attnum > 0
+            AND NOT a.attisdropped
+            ORDER BY a.attnum
+            "#,
+            [schema.into(), table_name.into()],
+        ),
+    )
+    .await?;
+
+    let constraints = query_all(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                a.attname::text AS column_name,
+                con.contype::text AS constraint_type,
+                rc.relname::text AS ref_table,
+                ra.attname::text AS ref_column
+            FROM pg_catalog.pg_constraint con
+            JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            CROSS JOIN LATERAL unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+            JOIN pg_catalog.pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+            LEFT JOIN pg_catalog.pg_class rc ON rc.oid = con.confrelid
+            LEFT JOIN pg_catalog.pg_attribute ra
+                ON ra.attrelid = con.confrelid AND ra.attnum = con.confkey[k.ord]
+            WHERE n.nspname = $1
+            AND c.relname = $2
+            AND con.contype IN ('p', 'u', 'f')
+            "#,
+            [schema.into(), table_name.into()],
+        ),
+    )
+    .await?;
+
+    let mut keys: HashMap<String, &'static str> = HashMap::new();
+    let mut foreign_keys: HashMap<String, ForeignKeyInfo> = HashMap::new();
+    for row in &constraints {
+        let column_name: String = row.try_get("", "column_name").unwrap_or_default();
+        let constraint_type: String = row.try_get("", "constraint_type").unwrap_or_default();
+        match constraint_type.as_str() {
+            "p" => {
+                keys.insert(column_name, "PRI");
+            }
+            "u" => {
+                keys.entry(column_name).or_insert("UNI");
+            }
+            "f" => {
+                keys.entry(column_name.clone()).or_insert("MUL");
+                foreign_keys.insert(
+                    column_name,
+                    ForeignKeyInfo {
+                        ref_table: row.try_get("", "ref_table").unwrap_or_default(),
+                        ref_column: row.try_get("", "ref_column").ok(),
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let result = columns
+        .iter()
+        .map(|row| {
+            let column_name: String = row.try_get("", "column_name").unwrap_or_default();
+            let column_default: Option<String> = row.try_get("", "column_default").ok().flatten();
+            let identity: String = row.try_get("", "identity").unwrap_or_default();
+            let enum_values: Option<String> = row.try_get("", "enum_values").ok().flatten();
+            let is_auto_increment = !identity.is_empty()
+                || column_default
+                    .as_deref()
+                    .is_some_and(|d| d.starts_with("nextval("));
+
+            ColumnInfo {
+                data_type: row.try_get("", "data_type").unwrap_or_default(),
+                column_type: row.try_get("", "column_type").unwrap_or_default(),
+                is_nullable: row.try_get("", "is_nullable").unwrap_or_default(),
+                column_key: keys.get(&column_name).copied().unwrap_or_default().to_string(),
+                column_comment: non_empty(row.try_get("", "column_comment").ok().flatten()),
+                foreign_key: foreign_keys.get(&column_name).cloned(),
+                enum_values: enum_values
+                    .and_then(|v| serde_json::from_str(&v).ok())
+                    .unwrap_or_default(),
+                column_default,
+                is_auto_increment,
+                column_name,
+            }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+async fn get_sqlite_columns(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<ColumnInfo>, CodeGenError> {
+    let backend = DatabaseBackend::Sqlite;
+    let columns = query_all(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                name AS column_name,
+                type AS column_type,
+                "notnull" AS not_null,
+                dflt_value AS column_default,
+                pk AS pk
+            FROM pragma_table_info(?)
+            ORDER BY cid
+            "#,
+            [table_name.into()],
+        ),
+    )
+    .await?;
+
+    let foreign_keys = query_foreign_keys(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                "from" AS column_name,
+                "table" AS ref_table,
+                "to" AS ref_column
+            FROM pragma_foreign_key_list(?)
+            "#,
+            [table_name.into()],
+        ),
+    )
+    .await?;
+
+    let unique_rows = query_all(
+        db,
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT ii.name AS column_name
+            FROM pragma_index_list(?) il
+            JOIN pragma_index_info(il.name) ii
+            WHERE il."unique" = 1
+            AND il.origin = 'u'
+            "#,
+            [table_name.into()],
+        ),
+    )
+    .await?;
+    let unique_columns: Vec<String> = unique_rows
+        .iter()
+        .filter_map(|row| row.try_get("", "column_name").ok())
+        .collect();
+
+    let pk_count = columns
+        .iter()
+        .filter(|row| row.try_get::<i64>("", "pk").unwrap_or(0) > 0)
+        .count();
+
+    let result = columns
+        .iter()
+        .map(|row| {
+            let column_name: String = row.try_get("", "column_name").unwrap_or_default();
+            let column_type: String = row.try_get("", "column_type").unwrap_or_default();
+            let not_null: i64 = row.try_get("", "not_null").unwrap_or(0);
+            let is_pk = row.try_get::<i64>("", "pk").unwrap_or(0) > 0;
+            let data_type = column_type
+                .split('(')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase();
+            // INTEGER PRIMARY KEY 为 rowid 别名，自动递增
+            let is_auto_increment = is_pk && pk_count == 1 && data_type == "integer";
+
+            let column_key = if is_pk {
+                "PRI"
+            } else if unique_columns.contains(&column_name) {
+                "UNI"
+            } else if foreign_keys.contains_key(&column_name) {
+                "MUL"
+            } else {
+                ""
+            };
+
+            ColumnInfo {
+                is_nullable: if not_null == 1 || is_pk { "NO" } else { "YES" }.to_string(),
+                column_key: column_key.to_string(),
+                column_default: row.try_get("", "column_default").ok().flatten(),
+                column_comment: None,
+                foreign_key: foreign_keys.get(&column_name).cloned(),
+                enum_values: Vec::new(),
+                data_type,
+                column_type,
+                is_auto_increment,
+                column_name,
+            }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+async fn query_all(
+    db: &DatabaseConnection,
+    stmt: Statement,
+) -> Result<Vec<QueryResult>, CodeGenError> {
+    db.query_all(stmt)
+        .await
+        .map_err(|e| CodeGenError::DatabaseError(e.to_string()))
+}
+
+async fn query_foreign_keys(
+    db: &DatabaseConnection,
+    stmt: Statement,
+) -> Result<HashMap<String, ForeignKeyInfo>, CodeGenError> {
+    let rows = query_all(db, stmt).await?;
+
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let column_name: String = row.try_get("", "column_name").ok()?;
+            let info = ForeignKeyInfo {
+                ref_table: row.try_get("", "ref_table").ok()?,
+                ref_column: row.try_get("", "ref_column").ok().flatten(),
+            };
+            Some((column_name, info))
+        })
+        .collect())
+}
+
+/// 空字符串视为无注释
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
+
+/// 解析 MySQL `enum('a','b')` / `set('a','b')` 的取值列表
+pub fn parse_mysql_enum_values(column_type: &str) -> Vec<String> {
+    let Some(start) = column_type.find('(') else {
+        return Vec::new();
+    };
+    let Some(end) = column_type.rfind(')') else {
+        return Vec::new();
+    };
+    if end <= start {
+        return Vec::new();
+    }
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quote = false;
+    let mut chars = column_type[start + 1..end].chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if in_quote => {
+                // 两个连续单引号表示转义
+                if chars.peek() == Some(&'\'') {
+                    current.push('\'');
+                    chars.next();
+                } else {
+                    in_quote = false;
+                    values.push(std::mem::take(&mut current));
+                }
+            }
+            '\'' => in_quote = true,
+            '\\' if in_quote => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            _ if in_quote => current.push(c),
+            _ => {}
+        }
+    }
+
+    values
+}
+
+/// 数据库类型映射到Rust类型（SeaORM 实体字段类型）
+pub fn map_db_type_to_rust(backend: DatabaseBackend, col: &ColumnInfo) -> String {
+    let data_type = col.data_type.to_lowercase();
+    let column_type = col.column_type.to_lowercase();
+
+    let base_type = match backend {
+        DatabaseBackend::MySql => {
+            let unsigned = column_type.contains("unsigned");
+            match data_type.as_str() {
+                "tinyint" if column_type.starts_with("tinyint(1)") => "bool",
+                "tinyint" if unsigned => "u8",
+                "tinyint" => "i8",
+                "smallint" if unsigned => "u16",
+                "smallint" => "i16",
+                "mediumint" | "int" | "integer" if unsigned => "u32",
+                "mediumint" | "int" | "integer" => "i32",
+                "bigint" if unsigned => "u64",
+                "bigint" => "i64",
+                "bit" if column_type == "bit(1)" => "bool",
+                "bool" | "boolean" => "bool",
+                "float" => "f32",
+                "double" | "real" => "f64",
+                "decimal" | "numeric" => "Decimal",
+                "date" => "Date",
+                "time" => "Time",
+                "datetime" => "DateTime",
+                "timestamp" => "DateTimeUtc",
+                "year" => "i16",
+                "json" => "Json",
+                "binary" | "varbinary" | "blob" | "tinyblob" | "mediumblob" | "longblob"
+                | "bit" => "Vec<u8>",
+                _ => "String",
+            }
+        }
+        DatabaseBackend::Postgres => match data_type.as_str() {
+            "int2" | "smallserial" => "i16",
+            "int4" | "serial" => "i32",
+            "int8" | "bigserial" => "i64",
+            "float4" => "f32",
+            "float8" => "f64",
+            "numeric" => "Decimal",
+            "bool" => "bool",
+            "date" => "Date",
+            "time" => "Time",
+            "timestamp" => "DateTime",
+            "timestamptz" => "DateTimeWithTimeZone",
+            "json" | "jsonb" => "Json",
+            "uuid" => "Uuid",
+            "bytea" => "Vec<u8>",
+            "_int2" => "Vec<i16>",
+            "_int4" => "Vec<i32>",
+            "_int8" => "Vec<i64>",
+            "_text" | "_varchar" => "Vec<String>",
+            _ => "String",
+        },
+        DatabaseBackend::Sqlite => {
+            // SQLite 按类型亲和性规则推断
+            if data_type.contains("bool") {
+                "bool"
+            } else if data_type == "datetime" || data_type == "timestamp" {
+                "DateTime"
+            } else if data_type == "date" {
+                "Date"
+            } else if data_type == "time" {
+                "Time"
+            } else if data_type.contains("int") {
+                "i64"
+            } else if data_type.contains("char")
+                || data_type.contains("clob")
+                || data_type.contains("text")
+            {
+                "String"
+            } else if data_type.contains("blob") || data_type.is_empty() {
+                "Vec<u8>"
+            } else if data_type.contains("real")
+                || data_type.contains("floa")
+                || data_type.contains("doub")
+            {
+                "f64"
+            } else if data_type.contains("decimal") || data_type.contains("numeric") {
+                "Decimal"
+            } else if data_type == "json" {
+                "Json"
+            } else {
+                "String"
+            }
+        }
+    };
+
+    if col.is_nullable == "YES" {
+        format!("Option<{}>", base_type)
+    } else {
+        base_type.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(data_type: &str, column_type: &str, is_nullable: &str) -> ColumnInfo {
+        ColumnInfo {
+            column_name: "c".to_string(),
+            data_type: data_type.to_string(),
+            column_type: column_type.to_string(),
+            is_nullable: is_nullable.to_string(),
+            column_key: String::new(),
+            column_default: None,
+            column_comment: None,
+            is_auto_increment: false,
+            enum_values: Vec::new(),
+            foreign_key: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_mysql_enum_values() {
+        assert_eq!(
+            parse_mysql_enum_values("enum('a','b c','it''s')"),
+            vec!["a", "b c", "it's"]
+        );
+        assert!(parse_mysql_enum_values("varchar(64)").is_empty());
+    }
+
+    #[test]
+    fn test_map_db_type_to_rust() {
+        let mysql = DatabaseBackend::MySql;
+        assert_eq!(map_db_type_to_rust(mysql, &column("tinyint", "tinyint(1)", "NO")), "bool");
+        assert_eq!(map_db_type_to_rust(mysql, &column("int", "int unsigned", "NO")), "u32");
+        assert_eq!(map_db_type_to_rust(mysql, &column("decimal", "decimal(10,2)", "YES")), "Option<Decimal>");
+
+        let pg = DatabaseBackend::Postgres;
+        assert_eq!(map_db_type_to_rust(pg, &column("int8", "bigint", "NO")), "i64");
+        assert_eq!(map_db_type_to_rust(pg, &column("timestamptz", "timestamp with time zone", "NO")), "DateTimeWithTimeZone");
+        assert_eq!(map_db_type_to_rust(pg, &column("enum", "status_enum", "NO")), "String");
+
+        let sqlite = DatabaseBackend::Sqlite;
+        assert_eq!(map_db_type_to_rust(sqlite, &column("integer", "INTEGER", "NO")), "i64");
+        assert_eq!(map_db_type_to_rust(sqlite, &column("varchar", "VARCHAR(64)", "YES")), "Option<String>");
+    }
+}
@@ -1,5 +1,10 @@
 pub mod codegen_service;
 pub mod business_service;
+pub mod introspection;
+pub mod template_service;
+pub mod workspace_service;
 
 pub use codegen_service::CodeGenService;
 pub use business_service::BusinessService;
+pub use template_service::{GenContext, TemplateService};
+pub use workspace_service::WorkspaceService;
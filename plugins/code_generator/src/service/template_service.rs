@@ -0,0 +1,655 @@
+//! 模板渲染服务
+//!
+//! 默认模板内置在插件中，可通过环境变量 `CODEGEN_TEMPLATE_DIR` 指定目录覆盖，
+//! 目录中的文件按相对路径（如 `rust/service.rs.tera`）替换同名内置模板。
+
+use sea_orm::DatabaseBackend;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use tera::{Context, Tera};
+
+use crate::dto::*;
+use crate::entity::gen_column;
+use crate::error::CodeGenError;
+use crate::service::introspection;
+
+/// 内置模板（模板名, 内容）
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    ("rust/entity.rs.tera", include_str!("../../templates/rust/entity.rs.tera")),
+    ("rust/mod.rs.tera", include_str!("../../templates/rust/mod.rs.tera")),
+    ("rust/dto.rs.tera", include_str!("../../templates/rust/dto.rs.tera")),
+    ("rust/service.rs.tera", include_str!("../../templates/rust/service.rs.tera")),
+    ("rust/api.rs.tera", include_str!("../../templates/rust/api.rs.tera")),
+    ("rust/router.rs.tera", include_str!("../../templates/rust/router.rs.tera")),
+    ("rust/migration.rs.tera", include_str!("../../templates/rust/migration.rs.tera")),
+    ("typescript/api.ts.tera", include_str!("../../templates/typescript/api.ts.tera")),
+    ("typescript/types.ts.tera", include_str!("../../templates/typescript/types.ts.tera")),
+    ("sql/menu.sql.tera", include_str!("../../templates/sql/menu.sql.tera")),
+];
+
+/// 模板目录环境变量
+pub const TEMPLATE_DIR_ENV: &str = "CODEGEN_TEMPLATE_DIR";
+
+/// Rust 关键字（字段名冲突时需要重命名）
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+/// 代码生成上下文
+#[derive(Debug, Clone, Serialize)]
+pub struct GenContext {
+    pub app_name: String,
+    pub table_name: String,
+    /// 模块名（snake_case，同时作为文件名）
+    pub module_name: String,
+    /// 模块名（kebab-case，用于URL）
+    pub kebab_name: String,
+    pub class_name: String,
+    pub doc_comment: String,
+    pub table_comment: Option<String>,
+    pub api_version: String,
+    pub api_prefix: String,
+    pub permission_prefix: String,
+    pub migration_name: String,
+    pub default_datetime_column: bool,
+    pub pk: GenColumnContext,
+    pub columns: Vec<GenColumnContext>,
+    pub form_columns: Vec<GenColumnContext>,
+    pub list_columns: Vec<GenColumnContext>,
+    pub query_columns: Vec<GenColumnContext>,
+    pub created_time: Option<GenColumnContext>,
+    pub updated_time: Option<GenColumnContext>,
+    /// 创建时是否还有未赋值的字段（需要 `..Default::default()`）
+    pub create_has_rest: bool,
+}
+
+/// 列生成上下文
+#[derive(Debug, Clone, Serialize)]
+pub struct GenColumnContext {
+    pub column_name: String,
+    /// Rust 字段名（关键字会加上模块名前缀）
+    pub field_name: String,
+    /// 实体 Column 枚举成员名
+    pub variant: String,
+    /// 迁移 Iden 枚举成员名
+    pub iden_variant: String,
+    /// 迁移 Iden 是否需要显式指定列名
+    pub iden_rename: bool,
+    pub comment: Option<String>,
+    /// 展示名称（注释或列名）
+    pub label: String,
+    /// 实体字段类型（可空列为 Option<T>）
+    pub rust_type: String,
+    pub base_type: String,
+    /// DTO 中使用的完整路径类型
+    pub dto_type: String,
+    pub dto_base_type: String,
+    pub ts_type: String,
+    pub nullable: bool,
+    pub is_pk: bool,
+    pub auto_increment: bool,
+    pub is_string: bool,
+    pub max_length: Option<u32>,
+    pub enum_values: Vec<String>,
+    pub is_query: bool,
+    pub is_list: bool,
+    pub is_form: bool,
+    /// 生效的查询方式：eq/ne/gt/ge/lt/le/like/between/in
+    pub query_type: String,
+    pub form_type: String,
+    /// 迁移列定义方法，如 `big_integer()`
+    pub migration_type: String,
+    /// 当前时间表达式（仅时间类型列）
+    pub now_expr: String,
+}
+
+/// 列的生成选项（来自 gen_column 或默认值）
+#[derive(Debug, Clone)]
+pub struct ColumnOptions {
+    pub is_query: bool,
+    pub is_list: bool,
+    pub is_form: bool,
+    pub query_type: Option<String>,
+    pub form_type: Option<String>,
+    pub ts_type: Option<String>,
+}
+
+impl ColumnOptions {
+    /// 导入表时使用的默认选项
+    pub fn defaults(col: &ColumnInfo) -> Self {
+        Self {
+            is_query: true,
+            is_list: true,
+            is_form: col.column_key != "PRI",
+            query_type: Some("eq".to_string()),
+            form_type: None,
+            ts_type: None,
+        }
+    }
+}
+
+impl From<&gen_column::Model> for ColumnOptions {
+    fn from(model: &gen_column::Model) -> Self {
+        Self {
+            is_query: model.is_query,
+            is_list: model.is_list,
+            is_form: model.is_form,
+            query_type: model.query_type.clone(),
+            form_type: model.form_type.clone(),
+            ts_type: model.ts_type.clone(),
+        }
+    }
+}
+
+/// 生成业务基础信息
+#[derive(Debug, Clone)]
+pub struct GenMeta {
+    pub app_name: String,
+    pub table_name: String,
+    pub module_name: String,
+    pub class_name: String,
+    pub doc_comment: String,
+    pub table_comment: Option<String>,
+    pub api_version: String,
+    pub default_datetime_column: bool,
+}
+
+/// 模板渲染服务
+pub struct TemplateService;
+
+impl TemplateService {
+    /// 可用模板名列表
+    pub fn template_names() -> Vec<String> {
+        BUILTIN_TEMPLATES.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    /// 构建模板引擎（内置模板 + 目录覆盖）
+    pub fn engine() -> Result<Tera, CodeGenError> {
+        let override_dir = std::env::var(TEMPLATE_DIR_ENV).ok();
+        Self::engine_with_dir(override_dir.as_deref().map(Path::new))
+    }
+
+    /// 使用指定覆盖目录构建模板引擎
+    pub fn engine_with_dir(override_dir: Option<&Path>) -> Result<Tera, CodeGenError> {
+        let mut tera = Tera::default();
+        tera.register_filter("rust_str", rust_str);
+        tera.add_raw_templates(BUILTIN_TEMPLATES.iter().copied())
+            .map_err(|e| CodeGenError::TemplateError(e.to_string()))?;
+
+        if let Some(dir) = override_dir.filter(|d| d.is_dir()) {
+            for (name, _) in BUILTIN_TEMPLATES {
+                let path = dir.join(name);
+                if path.is_file() {
+                    tera.add_template_file(&path, Some(name))
+                        .map_err(|e| CodeGenError::TemplateError(format!("{}: {}", name, e)))?;
+                }
+            }
+        }
+
+        Ok(tera)
+    }
+
+    /// 渲染全部模板，返回 工作区相对路径 -> 文件内容
+    pub fn render_all(ctx: &GenContext) -> Result<HashMap<String, String>, CodeGenError> {
+        let tera = Self::engine()?;
+        let context =
+            Context::from_serialize(ctx).map_err(|e| CodeGenError::TemplateError(e.to_string()))?;
+
+        let mut files = HashMap::new();
+        for (name, _) in BUILTIN_TEMPLATES {
+            let content = tera
+                .render(name, &context)
+                .map_err(|e| CodeGenError::TemplateError(format!("{}: {:?}", name, e)))?;
+            files.insert(Self::output_path(name, ctx), content);
+        }
+
+        Ok(files)
+    }
+
+    /// 模板对应的输出路径（相对工作区根目录）
+    pub fn output_path(template: &str, ctx: &GenContext) -> String {
+        let module = &ctx.module_name;
+        match template {
+            "rust/entity.rs.tera" => format!("src/database/entity/{}.rs", ctx.table_name),
+            "rust/mod.rs.tera" => format!("src/app/{}/mod.rs", module),
+            "rust/dto.rs.tera" => format!("src/app/{}/dto.rs", module),
+            "rust/service.rs.tera" => format!("src/app/{}/service.rs", module),
+            "rust/api.rs.tera" => format!("src/app/{}/api.rs", module),
+            "rust/router.rs.tera" => format!("src/app/{}/router.rs", module),
+            "rust/migration.rs.tera" => format!("migration/src/{}.rs", ctx.migration_name),
+            "typescript/api.ts.tera" => format!("web/src/api/{}/{}.ts", ctx.app_name, module),
+            "typescript/types.ts.tera" => {
+                format!("web/src/api/{}/types/{}.ts", ctx.app_name, module)
+            }
+            "sql/menu.sql.tera" => format!("sql/menu/{}.sql", module),
+            other => format!("generated/{}", other.trim_end_matches(".tera")),
+        }
+    }
+
+    /// 构建生成上下文
+    pub fn build_context(
+        backend: DatabaseBackend,
+        meta: GenMeta,
+        columns: Vec<(ColumnInfo, ColumnOptions)>,
+    ) -> Result<GenContext, CodeGenError> {
+        let columns: Vec<GenColumnContext> = columns
+            .iter()
+            .map(|(col, opts)| Self::build_column(backend, &meta.module_name, col, opts))
+            .collect();
+
+        let mut pks = columns.iter().filter(|c| c.is_pk);
+        let pk = pks
+            .next()
+            .cloned()
+            .ok_or_else(|| CodeGenError::GenerateError(format!("表 {} 缺少主键", meta.table_name)))?;
+        if pks.next().is_some() {
+            return Err(CodeGenError::GenerateError(format!(
+                "表 {} 为联合主键，暂不支持",
+                meta.table_name
+            )));
+        }
+
+        let find_time = |name: &str| {
+            if !meta.default_datetime_column {
+                return None;
+            }
+            columns
+                .iter()
+                .find(|c| c.column_name == name && !c.now_expr.is_empty())
+                .cloned()
+        };
+        let created_time = find_time("created_time");
+        let updated_time = find_time("updated_time");
+        let is_managed = |c: &GenColumnContext| {
+            c.is_pk && c.auto_increment
+                || created_time.as_ref().is_some_and(|t| t.column_name == c.column_name)
+                || updated_time.as_ref().is_some_and(|t| t.column_name == c.column_name)
+        };
+
+        let form_columns: Vec<GenColumnContext> = columns
+            .iter()
+            .filter(|c| c.is_form && !is_managed(c))
+            .cloned()
+            .collect();
+        let list_columns = columns
+            .iter()
+            .filter(|c| c.is_list && !c.is_pk)
+            .cloned()
+            .collect();
+        let query_columns = columns.iter().filter(|c| c.is_query).cloned().collect();
+        let create_has_rest =
+            form_columns.len() + usize::from(created_time.is_some()) < columns.len();
+
+        let kebab_name = meta.module_name.replace('_', "-");
+        Ok(GenContext {
+            api_prefix: format!("/api/{}/{}/{}", meta.api_version, meta.app_name, kebab_name),
+            permission_prefix: format!("{}:{}", meta.app_name, meta.module_name),
+            migration_name: format!(
+                "m{}_000001_create_{}_table",
+                chrono::Utc::now().format("%Y%m%d"),
+                meta.table_name
+            ),
+            app_name: meta.app_name,
+            table_name: meta.table_name,
+            module_name: meta.module_name,
+            kebab_name,
+            class_name: meta.class_name,
+            doc_comment: meta.doc_comment,
+            table_comment: meta.table_comment,
+            api_version: meta.api_version,
+            default_datetime_column: meta.default_datetime_column,
+            pk,
+            columns,
+            form_columns,
+            list_columns,
+            query_columns,
+            created_time,
+            updated_time,
+            create_has_rest,
+        })
+    }
+
+    fn build_column(
+        backend: DatabaseBackend,
+        module_name: &str,
+        col: &ColumnInfo,
+        opts: &ColumnOptions,
+    ) -> GenColumnContext {
+        let nullable = col.is_nullable == "YES";
+        let is_pk = col.column_key == "PRI";
+        let base_type = introspection::map_db_type_to_rust(
+            backend,
+            &ColumnInfo {
+                is_nullable: "NO".to_string(),
+                ..col.clone()
+            },
+        );
+        let rust_type = if nullable {
+            format!("Option<{}>", base_type)
+        } else {
+            base_type.clone()
+        };
+
+        let snake = to_snake_case(&col.column_name);
+        let field_name = if RUST_KEYWORDS.contains(&snake.as_str()) {
+            format!("{}_{}", module_name, snake)
+        } else {
+            snake
+        };
+        let iden_variant = to_pascal_case(&to_snake_case(&col.column_name));
+        let iden_rename = to_snake_case(&iden_variant) != col.column_name;
+
+        let is_string = base_type == "String";
+        let is_scalar = !base_type.starts_with("Vec<") && base_type != "Json";
+        let query_type = match opts.query_type.as_deref().unwrap_or("eq") {
+            "like" if is_string => "like",
+            "like" => "eq",
+            op @ ("eq" | "ne" | "gt" | "ge" | "lt" | "le" | "between" | "in") => op,
+            _ => "eq",
+        };
+
+        let ts_type = opts
+            .ts_type
+            .clone()
+            .filter(|t| !t.is_empty() && col.enum_values.is_empty())
+            .unwrap_or_else(|| ts_type_for(&base_type, &col.enum_values));
+
+        GenColumnContext {
+            field_name,
+            variant: String::new(),
+            iden_variant,
+            iden_rename,
+            comment: col.column_comment.clone(),
+            label: col
+                .column_comment
+                .clone()
+                .unwrap_or_else(|| col.column_name.clone()),
+            dto_type: qualify_type(&rust_type),
+            dto_base_type: qualify_type(&base_type),
+            ts_type,
+            nullable,
+            is_pk,
+            auto_increment: col.is_auto_increment,
+            is_string,
+            max_length: parse_max_length(&col.column_type),
+            enum_values: col.enum_values.clone(),
+            is_query: opts.is_query && is_scalar,
+            is_list: opts.is_list,
+            is_form: opts.is_form,
+            query_type: query_type.to_string(),
+            form_type: opts.form_type.clone().unwrap_or_else(|| "input".to_string()),
+            migration_type: migration_type_for(&base_type, &col.data_type, parse_max_length(&col.column_type)),
+            now_expr: now_expr_for(&base_type),
+            column_name: col.column_name.clone(),
+            rust_type,
+            base_type,
+        }
+        .with_variant()
+    }
+}
+
+impl GenColumnContext {
+    fn with_variant(mut self) -> Self {
+        self.variant = to_pascal_case(&self.field_name);
+        self
+    }
+}
+
+/// 转换为PascalCase
+pub fn to_pascal_case(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut c = word.chars();
+            match c.next() {
+                None => String::new(),
+                Some(f) => f.to_uppercase().chain(c).collect(),
+            }
+        })
+        .collect()
+}
+
+/// 转换为snake_case
+pub fn to_snake_case(s: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in s.chars() {
+        if c.is_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+            prev_lower = false;
+        } else if c.is_alphanumeric() || c == '_' {
+            out.push(c);
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        } else {
+            out.push('_');
+            prev_lower = false;
+        }
+    }
+    out
+}
+
+/// 从完整类型定义中解析字符串长度，如 `varchar(64)` -> 64
+fn parse_max_length(column_type: &str) -> Option<u32> {
+    let lower = column_type.to_lowercase();
+    if !(lower.contains("char") || lower.contains("binary")) {
+        return None;
+    }
+    let start = lower.find('(')?;
+    let end = lower[start..].find(')')? + start;
+    lower[start + 1..end].trim().parse().ok()
+}
+
+/// 为 DTO 中使用的类型补全路径
+fn qualify_type(rust_type: &str) -> String {
+    let mut result = String::new();
+    let mut ident = String::new();
+    for c in rust_type.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
+            continue;
+        }
+        if !ident.is_empty() {
+            let qualified = match ident.as_str() {
+                "Date" | "Time" | "DateTime" | "DateTimeUtc" | "DateTimeWithTimeZone"
+                | "Decimal" | "Json" | "Uuid" => format!("sea_orm::prelude::{}", ident),
+                _ => ident.clone(),
+            };
+            result.push_str(&qualified);
+            ident.clear();
+        }
+        if c != ' ' {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Rust 类型映射到 TypeScript 类型
+fn ts_type_for(base_type: &str, enum_values: &[String]) -> String {
+    if !enum_values.is_empty() {
+        return enum_values
+            .iter()
+            .map(|v| format!("'{}'", v.replace('\'', "\\'")))
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    if let Some(inner) = base_type.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+        if inner == "u8" {
+            return "string".to_string();
+        }
+        return format!("{}[]", ts_type_for(inner, &[]));
+    }
+    match base_type {
+        "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f32" | "f64" => "number",
+        "bool" => "boolean",
+        "Json" => "Record<string, any>",
+        _ => "string",
+    }
+    .to_string()
+}
+
+/// Rust 类型映射到迁移列定义
+fn migration_type_for(base_type: &str, data_type: &str, max_length: Option<u32>) -> String {
+    match base_type {
+        "i8" => "tiny_integer()".to_string(),
+        "u8" => "tiny_unsigned()".to_string(),
+        "i16" => "small_integer()".to_string(),
+        "u16" => "small_unsigned()".to_string(),
+        "i32" => "integer()".to_string(),
+        "u32" => "unsigned()".to_string(),
+        "i64" => "big_integer()".to_string(),
+        "u64" => "big_unsigned()".to_string(),
+        "f32" => "float()".to_string(),
+        "f64" => "double()".to_string(),
+        "bool" => "boolean()".to_string(),
+        "Decimal" => "decimal()".to_string(),
+        "Date" => "date()".to_string(),
+        "Time" => "time()".to_string(),
+        "DateTime" => "date_time()".to_string(),
+        "DateTimeUtc" => "timestamp()".to_string(),
+        "DateTimeWithTimeZone" => "timestamp_with_time_zone()".to_string(),
+        "Json" => "json()".to_string(),
+        "Uuid" => "uuid()".to_string(),
+        "Vec<u8>" => "blob()".to_string(),
+        "Vec<i16>" => "array(ColumnType::SmallInteger)".to_string(),
+        "Vec<i32>" => "array(ColumnType::Integer)".to_string(),
+        "Vec<i64>" => "array(ColumnType::BigInteger)".to_string(),
+        "Vec<String>" => "array(ColumnType::Text)".to_string(),
+        _ => match max_length {
+            Some(len) => format!("string_len({})", len),
+            None if data_type.contains("text") => "text()".to_string(),
+            None => "string()".to_string(),
+        },
+    }
+}
+
+/// 时间类型列的当前时间表达式
+fn now_expr_for(base_type: &str) -> String {
+    match base_type {
+        "DateTime" => "chrono::Utc::now().naive_utc()",
+        "DateTimeUtc" => "chrono::Utc::now()",
+        "DateTimeWithTimeZone" => "chrono::Utc::now().fixed_offset()",
+        _ => "",
+    }
+    .to_string()
+}
+
+/// 模板过滤器：转义为 Rust 字符串字面量的内容（不含两侧引号）
+fn rust_str(value: &tera::Value, _: &HashMap<String, tera::Value>) -> tera::Result<tera::Value> {
+    let s = tera::try_get_value!("rust_str", "value", String, value);
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    Ok(tera::Value::String(escaped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: &str, column_type: &str, key: &str) -> ColumnInfo {
+        ColumnInfo {
+            column_name: name.to_string(),
+            data_type: data_type.to_string(),
+            column_type: column_type.to_string(),
+            is_nullable: "NO".to_string(),
+            column_key: key.to_string(),
+            column_default: None,
+            column_comment: None,
+            is_auto_increment: key == "PRI",
+            enum_values: Vec::new(),
+            foreign_key: None,
+        }
+    }
+
+    fn sample_context() -> GenContext {
+        let columns = vec![
+            column("id", "bigint", "bigint", "PRI"),
+            column("title", "varchar", "varchar(64)", ""),
+            column("type", "int", "int", ""),
+            column("created_time", "datetime", "datetime", ""),
+        ];
+        let meta = GenMeta {
+            app_name: "cms".to_string(),
+            table_name: "cms_article".to_string(),
+            module_name: "article".to_string(),
+            class_name: "Article".to_string(),
+            doc_comment: "文章".to_string(),
+            table_comment: None,
+            api_version: "v1".to_string(),
+            default_datetime_column: true,
+        };
+        let columns = columns
+            .into_iter()
+            .map(|c| {
+                let opts = ColumnOptions::defaults(&c);
+                (c, opts)
+            })
+            .collect();
+        TemplateService::build_context(DatabaseBackend::MySql, meta, columns).unwrap()
+    }
+
+    #[test]
+    fn test_build_context() {
+        let ctx = sample_context();
+        assert_eq!(ctx.pk.field_name, "id");
+        assert_eq!(ctx.columns[2].field_name, "article_type");
+        assert!(!ctx.columns[2].iden_rename);
+        assert_eq!(ctx.columns[1].max_length, Some(64));
+        assert_eq!(ctx.form_columns.len(), 2);
+        assert_eq!(ctx.permission_prefix, "cms:article");
+    }
+
+    #[test]
+    fn test_render_all() {
+        let files = TemplateService::render_all(&sample_context()).unwrap();
+        assert_eq!(files.len(), BUILTIN_TEMPLATES.len());
+        let service = &files["src/app/article/service.rs"];
+        assert!(service.contains("pub struct ArticleService"));
+        assert!(!service.contains("unimplemented!"));
+    }
+
+    #[test]
+    fn test_render_all_rust_files_parse() {
+        let mut ctx = sample_context();
+        ctx.doc_comment = "\"文章\"\\草稿".to_string();
+        let files = TemplateService::render_all(&ctx).unwrap();
+
+        let rust_files: Vec<_> = files.iter().filter(|(path, _)| path.ends_with(".rs")).collect();
+        assert_eq!(rust_files.len(), BUILTIN_TEMPLATES.iter().filter(|(name, _)| name.starts_with("rust/")).count());
+        for (path, content) in rust_files {
+            if let Err(e) = syn::parse_file(content) {
+                panic!("{} 不是合法的 Rust 代码: {}\n{}", path, e, content);
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_escapes_doc_comment_in_strings() {
+        let mut ctx = sample_context();
+        ctx.doc_comment = "\"文章\"\\草稿".to_string();
+        let files = TemplateService::render_all(&ctx).unwrap();
+        assert!(files["src/app/article/service.rs"].contains(r#""\"文章\"\\草稿不存在""#));
+    }
+
+    #[test]
+    fn test_qualify_type() {
+        assert_eq!(qualify_type("Option<DateTime>"), "Option<sea_orm::prelude::DateTime>");
+        assert_eq!(qualify_type("Vec<String>"), "Vec<String>");
+    }
+}
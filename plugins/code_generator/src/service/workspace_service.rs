@@ -0,0 +1,451 @@
+//! 工作区写入服务
+//!
+//! 生成代码写入项目前先计算变更集（新建/更新/冲突/跳过），
+//! 通过 `.codegen/manifest.json` 记录每个文件最近一次生成内容的哈希，
+//! 用于识别被手动修改过的文件；迁移文件只新建不覆盖。
+
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::dto::{ChangeAction, GenChangeset, GenFileChange, GenerateWorkspaceParam};
+use crate::error::CodeGenError;
+use crate::service::template_service::GenContext;
+
+/// 生成清单文件（相对于工作区根目录）
+pub const MANIFEST_PATH: &str = ".codegen/manifest.json";
+
+/// 路由注册标记，生成的路由挂载语句插入在该行之前
+pub const ROUTES_MARKER: &str = "// @codegen:routes";
+
+/// 工作区写入服务
+pub struct WorkspaceService;
+
+impl WorkspaceService {
+    /// 计算变更集（不写入任何文件）
+    pub fn plan(
+        ctx: &GenContext,
+        files: HashMap<String, String>,
+        base_path: &str,
+        options: &GenerateWorkspaceParam,
+    ) -> Result<GenChangeset, CodeGenError> {
+        let base = PathBuf::from(base_path);
+        let manifest = Self::load_manifest(&base)?;
+        let existing_migration = Self::find_migration(&base, &ctx.table_name);
+
+        let mut paths: Vec<_> = files.into_iter().collect();
+        paths.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut changes = Vec::new();
+        for (path, content) in paths {
+            // 迁移文件只新建，已存在建表迁移时跳过
+            if path.starts_with("migration/src/") {
+                if let Some(name) = &existing_migration {
+                    changes.push(GenFileChange {
+                        path: format!("migration/src/{}.rs", name),
+                        action: ChangeAction::Skip,
+                        hand_edited: false,
+                        diff: String::new(),
+                        reason: Some("迁移文件已存在，表结构变更请手动编写变更迁移".to_string()),
+                        content: None,
+                        tracked: false,
+                    });
+                    continue;
+                }
+            }
+            changes.push(Self::plan_file(&base, &manifest, path, content, options.force)?);
+        }
+
+        if options.register {
+            let migration_name = existing_migration.unwrap_or_else(|| ctx.migration_name.clone());
+            changes.extend(Self::plan_registrations(&base, ctx, &migration_name)?);
+        }
+
+        Ok(GenChangeset {
+            base_path: base_path.to_string(),
+            applied: false,
+            files: changes,
+        })
+    }
+
+    /// 应用变更集，存在冲突时拒绝写入
+    pub async fn apply(mut changeset: GenChangeset) -> Result<GenChangeset, CodeGenError> {
+        let conflicts = changeset.conflicts();
+        if !conflicts.is_empty() {
+            let paths: Vec<_> = conflicts.iter().map(|f| f.path.as_str()).collect();
+            return Err(CodeGenError::Conflict(format!(
+                "以下文件已被手动修改，请确认后强制覆盖: {}",
+                paths.join(", ")
+            )));
+        }
+
+        let base = PathBuf::from(&changeset.base_path);
+        let mut manifest = Self::load_manifest(&base)?;
+
+        for change in &changeset.files {
+            let Some(content) = &change.content else {
+                continue;
+            };
+            if matches!(
+                change.action,
+                ChangeAction::Create | ChangeAction::Update | ChangeAction::Patch
+            ) {
+                Self::write_file(&base.join(&change.path), content).await?;
+            }
+            if change.tracked {
+                manifest.insert(change.path.clone(), content_hash(content));
+            }
+        }
+
+        let manifest_json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| CodeGenError::GenerateError(e.to_string()))?;
+        Self::write_file(&base.join(MANIFEST_PATH), &manifest_json).await?;
+
+        changeset.applied = true;
+        Ok(changeset)
+    }
+
+    /// 计算单个生成文件的变更
+    fn plan_file(
+        base: &Path,
+        manifest: &BTreeMap<String, String>,
+        path: String,
+        content: String,
+        force: bool,
+    ) -> Result<GenFileChange, CodeGenError> {
+        let full_path = base.join(&path);
+        let Some(existing) = Self::read_file(&full_path)? else {
+            return Ok(GenFileChange {
+                diff: unified_diff(&path, "", &content),
+                path,
+                action: ChangeAction::Create,
+                hand_edited: false,
+                reason: None,
+                content: Some(content),
+                tracked: true,
+            });
+        };
+
+        if existing == content {
+            return Ok(GenFileChange {
+                path,
+                action: ChangeAction::Unchanged,
+                hand_edited: false,
+                diff: String::new(),
+                reason: None,
+                content: Some(content),
+                tracked: true,
+            });
+        }
+
+        // 文件内容与上次生成一致，说明未被手动修改，可以直接更新
+        let hand_edited = manifest.get(&path) != Some(&content_hash(&existing));
+        let (action, reason) = match (hand_edited, force) {
+            (false, _) => (ChangeAction::Update, None),
+            (true, true) => (ChangeAction::Update, Some("强制覆盖手动修改".to_string())),
+            (true, false) => (
+                ChangeAction::Conflict,
+                Some("文件已被手动修改或非代码生成器创建".to_string()),
+            ),
+        };
+
+        Ok(GenFileChange {
+            diff: unified_diff(&path, &existing, &content),
+            path,
+            action,
+            hand_edited,
+            reason,
+            content: Some(content),
+            tracked: true,
+        })
+    }
+
+    /// 计算模块注册（mod 声明、迁移、路由）的变更
+    fn plan_registrations(
+        base: &Path,
+        ctx: &GenContext,
+        migration_name: &str,
+    ) -> Result<Vec<GenFileChange>, CodeGenError> {
+        let route_line = format!(
+            "api_v1_router = api_v1_router.nest(\"{}\", crate::app::{}::router::{}_routes());",
+            ctx.api_prefix, ctx.module_name, ctx.module_name
+        );
+
+        Ok(vec![
+            Self::plan_patch(base, "src/app/mod.rs", true, |s| {
+                patch_mod_declaration(s, &format!("pub mod {};", ctx.module_name))
+            })?,
+            // 实体模块在 src/database/mod.rs 中以内联 `pub mod entity { ... }` 声明
+            Self::plan_patch(base, "src/database/mod.rs", false, |s| {
+                patch_inline_mod_declaration(s, "entity", &format!("pub mod {};", ctx.table_name))
+            })?,
+            Self::plan_patch(base, "migration/src/lib.rs", false, |s| {
+                patch_migration_lib(s, migration_name)
+            })?,
+            Self::plan_patch(base, "src/core/registrar.rs", false, |s| {
+                patch_routes(s, &route_line)
+            })?,
+        ])
+    }
+
+    /// 计算对已有文件的补丁
+    fn plan_patch(
+        base: &Path,
+        path: &str,
+        create_if_missing: bool,
+        patch: impl Fn(&str) -> Result<Option<String>, String>,
+    ) -> Result<GenFileChange, CodeGenError> {
+        let existing = Self::read_file(&base.join(path))?;
+        let change = |action, diff, reason: Option<&str>, content| GenFileChange {
+            path: path.to_string(),
+            action,
+            hand_edited: false,
+            diff,
+            reason: reason.map(str::to_string),
+            content,
+            tracked: false,
+        };
+
+        let Some(existing) = existing else {
+            if !create_if_missing {
+                return Ok(change(ChangeAction::Skip, String::new(), Some("文件不存在，请手动注册"), None));
+            }
+            let content = patch("").map_err(CodeGenError::GenerateError)?.unwrap_or_default();
+            return Ok(change(
+                ChangeAction::Create,
+                unified_diff(path, "", &content),
+                None,
+                Some(content),
+            ));
+        };
+
+        Ok(match patch(&existing) {
+            Ok(Some(content)) => change(
+                ChangeAction::Patch,
+                unified_diff(path, &existing, &content),
+                None,
+                Some(content),
+            ),
+            Ok(None) => change(ChangeAction::Unchanged, String::new(), None, None),
+            Err(reason) => change(ChangeAction::Skip, String::new(), Some(&reason), None),
+        })
+    }
+
+    /// 查找已存在的建表迁移名称
+    fn find_migration(base: &Path, table_name: &str) -> Option<String> {
+        let suffix = format!("_create_{}_table.rs", table_name);
+        let mut names: Vec<String> = std::fs::read_dir(base.join("migration/src"))
+            .ok()?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.starts_with('m') && name.ends_with(&suffix))
+            .collect();
+        names.sort();
+        names.pop().map(|name| name.trim_end_matches(".rs").to_string())
+    }
+
+    /// 读取生成清单
+    fn load_manifest(base: &Path) -> Result<BTreeMap<String, String>, CodeGenError> {
+        match Self::read_file(&base.join(MANIFEST_PATH))? {
+            Some(content) => serde_json::from_str(&content)
+                .map_err(|e| CodeGenError::GenerateError(format!("生成清单解析失败: {}", e))),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    fn read_file(path: &Path) -> Result<Option<String>, CodeGenError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CodeGenError::GenerateError(format!("读取文件失败: {}", e))),
+        }
+    }
+
+    async fn write_file(path: &Path, content: &str) -> Result<(), CodeGenError> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| CodeGenError::GenerateError(format!("创建目录失败: {}", e)))?;
+        }
+        tokio::fs::write(path, content.as_bytes())
+            .await
+            .map_err(|e| CodeGenError::GenerateError(format!("写入文件失败: {}", e)))
+    }
+}
+
+/// 计算内容哈希
+fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// 生成统一差异格式
+fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+/// 追加 `pub mod xxx;` 声明，已存在时返回 None
+fn patch_mod_declaration(existing: &str, decl: &str) -> Result<Option<String>, String> {
+    if existing.lines().any(|line| line.trim() == decl) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<&str> = existing.lines().collect();
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod "))
+        .map(|i| i + 1)
+        .unwrap_or(lines.len());
+    lines.insert(position, decl);
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// 在内联模块 `pub mod name { ... }` 中追加 `pub mod xxx;` 声明，已存在时返回 None
+fn patch_inline_mod_declaration(existing: &str, module: &str, decl: &str) -> Result<Option<String>, String> {
+    let header = format!("pub mod {} {{", module);
+    let mut lines: Vec<String> = existing.lines().map(str::to_string).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == header)
+        .ok_or_else(|| format!("未找到内联模块 {}", header))?;
+    let indent = lines[start].len() - lines[start].trim_start().len();
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.trim() == "}" && line.len() - line.trim_start().len() == indent)
+        .map(|i| i + start + 1)
+        .ok_or_else(|| format!("未找到内联模块 {} 的结尾", module))?;
+    if lines[start + 1..end].iter().any(|line| line.trim() == decl) {
+        return Ok(None);
+    }
+
+    lines.insert(end, format!("{}{}", " ".repeat(indent + 4), decl));
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// 在迁移 lib.rs 中注册迁移
+fn patch_migration_lib(existing: &str, name: &str) -> Result<Option<String>, String> {
+    let decl = format!("mod {};", name);
+    let entry = format!("Box::new({}::Migration),", name);
+    let has_decl = existing.lines().any(|line| line.trim() == decl);
+    let has_entry = existing.lines().any(|line| line.trim() == entry);
+    if has_decl && has_entry {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = existing.lines().map(str::to_string).collect();
+    if !has_entry {
+        let vec_start = lines
+            .iter()
+            .position(|line| line.contains("vec!["))
+            .ok_or("未找到迁移列表 vec![]")?;
+        let vec_end = lines[vec_start..]
+            .iter()
+            .position(|line| line.trim_start().starts_with(']'))
+            .map(|i| i + vec_start)
+            .ok_or("未找到迁移列表结尾")?;
+        let indent = lines[vec_end].len() - lines[vec_end].trim_start().len();
+        lines.insert(vec_end, format!("{}{}", " ".repeat(indent + 4), entry));
+    }
+    if !has_decl {
+        let position = lines
+            .iter()
+            .rposition(|line| line.starts_with("mod m"))
+            .or_else(|| lines.iter().position(|line| line.starts_with("pub use")))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        lines.insert(position, decl);
+    }
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// 在路由注册标记前插入路由挂载语句
+fn patch_routes(existing: &str, route_line: &str) -> Result<Option<String>, String> {
+    if existing.lines().any(|line| line.trim() == route_line) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = existing.lines().map(str::to_string).collect();
+    let marker = lines
+        .iter()
+        .position(|line| line.trim() == ROUTES_MARKER)
+        .ok_or_else(|| format!("未找到路由注册标记 {}", ROUTES_MARKER))?;
+    let indent = lines[marker].len() - lines[marker].trim_start().len();
+    lines.insert(marker, format!("{}{}", " ".repeat(indent), route_line));
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_mod_declaration() {
+        let patched = patch_mod_declaration("pub mod dept;\npub mod user;\n", "pub mod post;")
+            .unwrap()
+            .unwrap();
+        assert_eq!(patched, "pub mod dept;\npub mod user;\npub mod post;\n");
+        assert!(patch_mod_declaration(&patched, "pub mod post;").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_patch_inline_mod_declaration() {
+        let source = "pub mod entity {\n    pub mod prelude;\n    pub mod user;\n}\n\npub mod redis;\n";
+        let patched = patch_inline_mod_declaration(source, "entity", "pub mod post;")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            patched,
+            "pub mod entity {\n    pub mod prelude;\n    pub mod user;\n    pub mod post;\n}\n\npub mod redis;\n"
+        );
+        assert!(patch_inline_mod_declaration(&patched, "entity", "pub mod post;").unwrap().is_none());
+        // 块外的同名声明不算已注册
+        assert!(patch_inline_mod_declaration(source, "entity", "pub mod redis;").unwrap().is_some());
+        assert!(patch_inline_mod_declaration("pub mod redis;\n", "entity", "pub mod post;").is_err());
+    }
+
+    #[test]
+    fn test_patch_migration_lib() {
+        let lib = "pub use sea_orm_migration::prelude::*;\n\npub struct Migrator;\n\n\
+                   impl MigratorTrait for Migrator {\n    fn migrations() -> Vec<Box<dyn MigrationTrait>> {\n        vec![\n        ]\n    }\n}\n";
+        let patched = patch_migration_lib(lib, "m20260101_000001_create_post_table")
+            .unwrap()
+            .unwrap();
+        assert!(patched.contains("mod m20260101_000001_create_post_table;\n"));
+        assert!(patched.contains("            Box::new(m20260101_000001_create_post_table::Migration),\n        ]"));
+        assert!(patch_migration_lib(&patched, "m20260101_000001_create_post_table")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_patch_routes_requires_marker() {
+        let source = "    let r = 1;\n    // @codegen:routes\n";
+        let patched = patch_routes(source, "api_v1_router = x;").unwrap().unwrap();
+        assert_eq!(patched, "    let r = 1;\n    api_v1_router = x;\n    // @codegen:routes\n");
+        assert!(patch_routes("fn main() {}", "x").is_err());
+    }
+
+    #[test]
+    fn test_plan_file_detects_hand_edits() {
+        let base = std::env::temp_dir().join(format!("codegen_ws_{}", std::process::id()));
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::write(base.join("a.rs"), "edited\n").unwrap();
+
+        let mut manifest = BTreeMap::new();
+        let plan = |manifest: &BTreeMap<String, String>, force| {
+            WorkspaceService::plan_file(&base, manifest, "a.rs".into(), "new\n".into(), force).unwrap()
+        };
+        assert_eq!(plan(&manifest, false).action, ChangeAction::Conflict);
+        assert_eq!(plan(&manifest, true).action, ChangeAction::Update);
+
+        manifest.insert("a.rs".to_string(), content_hash("edited\n"));
+        let change = plan(&manifest, false);
+        assert_eq!(change.action, ChangeAction::Update);
+        assert!(change.diff.contains("-edited\n+new\n"));
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
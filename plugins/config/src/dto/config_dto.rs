@@ -1,6 +1,7 @@
 //! 系统配置DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use chrono::NaiveDateTime;
 
@@ -10,7 +11,7 @@ lazy_static::lazy_static! {
 }
 
 /// 配置详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ConfigDetail {
     pub id: i64,
     pub name: String,
@@ -41,7 +42,7 @@ impl From<crate::entity::config::Model> for ConfigDetail {
 }
 
 /// 创建配置请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateConfigParam {
     /// 配置名称
     #[validate(length(min = 1, max = 64, message = "配置名称长度必须在1-64之间"))]
@@ -70,7 +71,7 @@ pub struct CreateConfigParam {
 }
 
 /// 更新配置请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateConfigParam {
     /// 配置名称
     #[validate(length(min = 1, max = 64, message = "配置名称长度必须在1-64之间"))]
@@ -92,7 +93,7 @@ pub struct UpdateConfigParam {
 }
 
 /// 获取所有配置查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct GetAllConfigQuery {
     /// 配置类型（可选，用于过滤）
     #[serde(rename = "type")]
@@ -100,7 +101,7 @@ pub struct GetAllConfigQuery {
 }
 
 /// 配置查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct ConfigQuery {
     /// 配置名称（模糊查询）
     pub name: Option<String>,
@@ -1,9 +1,10 @@
 //! 通用DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 分页查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct PaginationQuery {
     /// 页码（从1开始）
     #[serde(default = "default_page")]
@@ -35,12 +36,13 @@ impl PaginationQuery {
 }
 
 /// 分页响应数据
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PageData<T> {
     /// 总记录数
     pub total: u64,
     
     /// 当前页数据
+    #[schema(inline)]
     pub items: Vec<T>,
     
     /// 当前页码
@@ -58,7 +60,7 @@ impl<T> PageData<T> {
         let pages = if total == 0 {
             0
         } else {
-            (total + size - 1) / size
+            total.div_ceil(size)
         };
         
         Self {
@@ -122,7 +124,7 @@ impl ApiResponse<()> {
 }
 
 /// 批量删除请求参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DeleteBatchParam {
     /// ID列表
     pub ids: Vec<i64>,
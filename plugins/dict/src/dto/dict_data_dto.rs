@@ -1,11 +1,12 @@
 //! 字典数据DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use chrono::NaiveDateTime;
 
 /// 字典数据详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DictDataDetail {
     pub id: i64,
     pub label: String,
@@ -39,7 +40,7 @@ impl From<crate::entity::dict_data::Model> for DictDataDetail {
 }
 
 /// 创建字典数据请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateDictDataParam {
     /// 显示标签
     #[validate(length(min = 1, max = 64, message = "标签长度必须在1-64之间"))]
@@ -75,7 +76,7 @@ pub struct CreateDictDataParam {
 }
 
 /// 更新字典数据请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateDictDataParam {
     /// 显示标签
     #[validate(length(min = 1, max = 64, message = "标签长度必须在1-64之间"))]
@@ -100,7 +101,7 @@ pub struct UpdateDictDataParam {
 }
 
 /// 字典数据查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct DictDataQuery {
     /// 类型编码
     pub type_code: Option<String>,
@@ -1,11 +1,12 @@
 //! 字典类型DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use chrono::NaiveDateTime;
 
 /// 字典类型详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DictTypeDetail {
     pub id: i64,
     pub name: String,
@@ -31,7 +32,7 @@ impl From<crate::entity::dict_type::Model> for DictTypeDetail {
 }
 
 /// 创建字典类型请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateDictTypeParam {
     /// 字典名称
     #[validate(length(min = 1, max = 32, message = "字典名称长度必须在1-32之间"))]
@@ -51,7 +52,7 @@ pub struct CreateDictTypeParam {
 }
 
 /// 更新字典类型请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateDictTypeParam {
     /// 字典名称
     #[validate(length(min = 1, max = 32, message = "字典名称长度必须在1-32之间"))]
@@ -65,7 +66,7 @@ pub struct UpdateDictTypeParam {
 }
 
 /// 字典类型查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct DictTypeQuery {
     /// 字典名称（模糊查询）
     pub name: Option<String>,
@@ -0,0 +1,18 @@
+-- 邮件发送队列：重试调度字段
+ALTER TABLE `sys_email_record`
+  MODIFY COLUMN `status` smallint NOT NULL DEFAULT '0' COMMENT '发送状态（0待发送/1发送成功/2发送失败/3发送中/4已抑制）',
+  ADD COLUMN `retry_count` int NOT NULL DEFAULT '0' COMMENT '已重试次数' AFTER `error_msg`,
+  ADD COLUMN `next_retry_time` datetime DEFAULT NULL COMMENT '下次发送时间' AFTER `retry_count`,
+  ADD COLUMN `updated_time` datetime DEFAULT NULL COMMENT '更新时间' AFTER `created_time`,
+  ADD KEY `idx_status_next_retry_time` (`status`, `next_retry_time`);
+
+-- 邮件抑制名单（硬退信、投诉或手动添加的收件人不再发送）
+CREATE TABLE IF NOT EXISTS `sys_email_suppression` (
+  `id` bigint NOT NULL AUTO_INCREMENT COMMENT '主键ID',
+  `email` varchar(255) NOT NULL COMMENT '收件人邮箱（小写）',
+  `reason` varchar(20) NOT NULL COMMENT '原因（bounce退信/complaint投诉/manual手动）',
+  `detail` text COMMENT '详情（如SMTP响应）',
+  `created_time` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '创建时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_email` (`email`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='邮件抑制名单表';
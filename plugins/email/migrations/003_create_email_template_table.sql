@@ -0,0 +1,88 @@
+-- 邮件模板表（按 编码 + 语言 + 版本 存储，每个 编码 + 语言 有且只有一个当前版本）
+CREATE TABLE IF NOT EXISTS `sys_email_template` (
+  `id` bigint NOT NULL AUTO_INCREMENT COMMENT '主键ID',
+  `code` varchar(50) NOT NULL COMMENT '模板编码',
+  `locale` varchar(20) NOT NULL COMMENT '语言（如 zh-CN、en-US）',
+  `version` int NOT NULL DEFAULT '1' COMMENT '版本号',
+  `subject` varchar(255) NOT NULL COMMENT '主题模板',
+  `html_body` mediumtext NOT NULL COMMENT 'HTML正文模板',
+  `text_body` mediumtext COMMENT '纯文本正文模板',
+  `sample_data` text COMMENT '预览用示例数据（JSON）',
+  `is_current` smallint NOT NULL DEFAULT '0' COMMENT '是否当前版本（1是/0否）',
+  `remark` varchar(500) DEFAULT NULL COMMENT '备注',
+  `created_time` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '创建时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_code_locale_version` (`code`, `locale`, `version`),
+  KEY `idx_code_current` (`code`, `is_current`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='邮件模板表';
+
+-- 邮件记录保存纯文本正文，与 HTML 正文一起作为 multipart/alternative 发送
+ALTER TABLE `sys_email_record`
+  ADD COLUMN `text_content` text COMMENT '纯文本正文（HTML邮件的替代内容）' AFTER `content`;
+
+-- 内置欢迎邮件模板（原 templates/welcome.html）
+INSERT INTO `sys_email_template` (`code`, `locale`, `version`, `subject`, `html_body`, `text_body`, `sample_data`, `is_current`, `remark`)
+VALUES ('welcome', 'zh-CN', 1, '欢迎加入，{{ username }}',
+'<!DOCTYPE html>
+<html lang="zh-CN">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>欢迎</title>
+    <style>
+        body {
+            font-family: Arial, sans-serif;
+            line-height: 1.6;
+            color: #333;
+            max-width: 600px;
+            margin: 0 auto;
+            padding: 20px;
+        }
+        .header {
+            background-color: #4CAF50;
+            color: white;
+            padding: 20px;
+            text-align: center;
+        }
+        .content {
+            padding: 20px;
+            background-color: #f9f9f9;
+        }
+        .button {
+            display: inline-block;
+            padding: 10px 20px;
+            margin: 20px 0;
+            background-color: #4CAF50;
+            color: white;
+            text-decoration: none;
+            border-radius: 5px;
+        }
+        .footer {
+            text-align: center;
+            padding: 20px;
+            font-size: 12px;
+            color: #777;
+        }
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>欢迎加入！</h1>
+    </div>
+    <div class="content">
+        <p>亲爱的 {{ username }}，</p>
+        <p>欢迎您注册我们的系统！</p>
+        <p>请点击下面的按钮激活您的账户：</p>
+        <a href="{{ link }}" class="button">激活账户</a>
+        <p>如果按钮无法点击，请复制以下链接到浏览器打开：</p>
+        <p>{{ link }}</p>
+    </div>
+    <div class="footer">
+        <p>此邮件由系统自动发送，请勿直接回复。</p>
+        <p>&copy; 2024 FastAPI Best Architecture. All rights reserved.</p>
+    </div>
+</body>
+</html>',
+'亲爱的 {{ username }}，\n\n欢迎您注册我们的系统！请打开以下链接激活您的账户：\n{{ link }}\n\n此邮件由系统自动发送，请勿直接回复。',
+'{"username": "张三", "link": "https://example.com/activate?token=demo"}',
+1, '注册欢迎邮件');
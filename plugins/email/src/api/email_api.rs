@@ -2,6 +2,7 @@
 
 use axum::{
     extract::{Path, Query, State},
+    http::{header, HeaderMap},
     response::Json,
 };
 use sea_orm::DatabaseConnection;
@@ -9,13 +10,13 @@ use validator::Validate;
 
 use crate::dto::*;
 use crate::error::EmailError;
-use crate::service::{EmailService, SmtpConfig};
+use crate::entity::email_suppression::REASON_MANUAL;
+use crate::service::{EmailService, SuppressionService, TemplateService};
 
 /// 应用状态
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
-    pub smtp_config: SmtpConfig,
 }
 
 /// 发送邮件
@@ -27,21 +28,32 @@ pub async fn send_email(
     // 验证参数
     param.validate()?;
     
-    let data = EmailService::send_email(&state.db, &state.smtp_config, param).await?;
-    Ok(Json(ApiResponse::success_with_msg("邮件发送请求已提交", data)))
+    let data = EmailService::send_email(&state.db, param).await?;
+    Ok(Json(ApiResponse::success_with_msg("邮件已加入发送队列", data)))
 }
 
 /// 发送模板邮件
 /// POST /api/v1/email/send-template
 pub async fn send_template_email(
     State(state): State<AppState>,
-    Json(param): Json<SendTemplateEmailParam>,
+    headers: HeaderMap,
+    Json(mut param): Json<SendTemplateEmailParam>,
 ) -> Result<Json<ApiResponse<EmailRecordDetail>>, EmailError> {
     // 验证参数
     param.validate()?;
     
-    let data = EmailService::send_template_email(&state.db, &state.smtp_config, param).await?;
-    Ok(Json(ApiResponse::success_with_msg("模板邮件发送请求已提交", data)))
+    // 未指定语言时使用请求语言（主程序的国际化中间件已将 Accept-Language 改写为协商结果）
+    if param.locale.is_none() {
+        param.locale = headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split([',', ';']).next())
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty() && tag != "*");
+    }
+    
+    let data = EmailService::send_template_email(&state.db, param).await?;
+    Ok(Json(ApiResponse::success_with_msg("模板邮件已加入发送队列", data)))
 }
 
 /// 测试SMTP配置
@@ -77,14 +89,157 @@ pub async fn get_email_record(
     Ok(Json(ApiResponse::success(data)))
 }
 
+/// 重新发送失败的邮件
+/// POST /api/v1/email/records/{id}/retry
+pub async fn retry_email_record(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<EmailRecordDetail>>, EmailError> {
+    let data = EmailService::retry_email(&state.db, id).await?;
+    Ok(Json(ApiResponse::success_with_msg("邮件已重新加入发送队列", data)))
+}
+
+/// 查询抑制名单（分页）
+/// GET /api/v1/email/suppressions
+pub async fn get_suppressions(
+    State(state): State<AppState>,
+    Query(query): Query<SuppressionQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<PageData<EmailSuppressionDetail>>>, EmailError> {
+    let page_data = SuppressionService::get_list(&state.db, query, pagination).await?;
+    Ok(Json(ApiResponse::success(page_data)))
+}
+
+/// 添加抑制名单（如外部退信、投诉回调）
+/// POST /api/v1/email/suppressions
+pub async fn add_suppression(
+    State(state): State<AppState>,
+    Json(param): Json<AddSuppressionParam>,
+) -> Result<Json<ApiResponse<EmailSuppressionDetail>>, EmailError> {
+    param.validate()?;
+    
+    let reason = param.reason.as_deref().unwrap_or(REASON_MANUAL);
+    let data = SuppressionService::add(&state.db, &param.email, reason, param.detail).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 移出抑制名单
+/// DELETE /api/v1/email/suppressions/{id}
+pub async fn delete_suppression(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<()>>, EmailError> {
+    SuppressionService::delete(&state.db, id).await?;
+    Ok(Json(ApiResponse::success_msg("已移出抑制名单")))
+}
+
+/// 查询邮件模板（分页，仅当前版本）
+/// GET /api/v1/email/templates
+pub async fn get_templates(
+    State(state): State<AppState>,
+    Query(query): Query<EmailTemplateQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<PageData<EmailTemplateDetail>>>, EmailError> {
+    let page_data = TemplateService::get_list(&state.db, query, pagination).await?;
+    Ok(Json(ApiResponse::success(page_data)))
+}
+
+/// 创建邮件模板
+/// POST /api/v1/email/templates
+pub async fn create_template(
+    State(state): State<AppState>,
+    Json(param): Json<CreateEmailTemplateParam>,
+) -> Result<Json<ApiResponse<EmailTemplateDetail>>, EmailError> {
+    param.validate()?;
+    
+    let data = TemplateService::create(&state.db, param).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 获取邮件模板详情
+/// GET /api/v1/email/templates/{id}
+pub async fn get_template(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<EmailTemplateDetail>>, EmailError> {
+    let data = TemplateService::get_by_id(&state.db, id).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 修改邮件模板（保存为新版本）
+/// PUT /api/v1/email/templates/{id}
+pub async fn update_template(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(param): Json<EmailTemplateContent>,
+) -> Result<Json<ApiResponse<EmailTemplateDetail>>, EmailError> {
+    param.validate()?;
+    
+    let data = TemplateService::update(&state.db, id, param).await?;
+    Ok(Json(ApiResponse::success_with_msg("已保存为新版本", data)))
+}
+
+/// 删除邮件模板（含全部版本）
+/// DELETE /api/v1/email/templates/{id}
+pub async fn delete_template(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<()>>, EmailError> {
+    TemplateService::delete(&state.db, id).await?;
+    Ok(Json(ApiResponse::success_msg("删除成功")))
+}
+
+/// 查询邮件模板的历史版本
+/// GET /api/v1/email/templates/{id}/versions
+pub async fn get_template_versions(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<Vec<EmailTemplateDetail>>>, EmailError> {
+    let data = TemplateService::get_versions(&state.db, id).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 将指定版本设为当前版本
+/// POST /api/v1/email/templates/{id}/activate
+pub async fn activate_template(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<EmailTemplateDetail>>, EmailError> {
+    let data = TemplateService::activate(&state.db, id).await?;
+    Ok(Json(ApiResponse::success_with_msg("已切换到该版本", data)))
+}
+
+/// 预览邮件模板
+/// POST /api/v1/email/templates/{id}/preview
+pub async fn preview_template(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    param: Option<Json<PreviewEmailTemplateParam>>,
+) -> Result<Json<ApiResponse<EmailTemplatePreview>>, EmailError> {
+    let param = param.map(|Json(p)| p).unwrap_or_default();
+    let data = TemplateService::preview(&state.db, id, param.data).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
 /// 创建邮件路由
 pub fn email_routes() -> axum::Router<AppState> {
-    use axum::routing::{get, post};
+    use axum::routing::{delete, get, post};
     
     axum::Router::new()
         .route("/send", post(send_email))
         .route("/send-template", post(send_template_email))
         .route("/test-smtp", post(test_smtp))
         .route("/records", get(get_email_records))
-        .route("/records/:id", get(get_email_record))
+        .route("/records/{id}", get(get_email_record))
+        .route("/records/{id}/retry", post(retry_email_record))
+        .route("/suppressions", get(get_suppressions).post(add_suppression))
+        .route("/suppressions/{id}", delete(delete_suppression))
+        .route("/templates", get(get_templates).post(create_template))
+        .route(
+            "/templates/{id}",
+            get(get_template).put(update_template).delete(delete_template),
+        )
+        .route("/templates/{id}/versions", get(get_template_versions))
+        .route("/templates/{id}/activate", post(activate_template))
+        .route("/templates/{id}/preview", post(preview_template))
 }
@@ -1,22 +1,26 @@
 //! 邮件发送DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use chrono::NaiveDateTime;
-use std::collections::HashMap;
 
 /// 邮件发送记录详情
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct EmailRecordDetail {
     pub id: i64,
     pub to_email: String,
     pub subject: String,
     pub content: String,
+    pub text_content: Option<String>,
     pub is_html: i16,
     pub status: i16,
     pub error_msg: Option<String>,
+    pub retry_count: i32,
+    pub next_retry_time: Option<NaiveDateTime>,
     pub send_time: Option<NaiveDateTime>,
     pub created_time: NaiveDateTime,
+    pub updated_time: Option<NaiveDateTime>,
 }
 
 impl From<crate::entity::email_record::Model> for EmailRecordDetail {
@@ -26,17 +30,21 @@ impl From<crate::entity::email_record::Model> for EmailRecordDetail {
             to_email: model.to_email,
             subject: model.subject,
             content: model.content,
+            text_content: model.text_content,
             is_html: model.is_html,
             status: model.status,
             error_msg: model.error_msg,
+            retry_count: model.retry_count,
+            next_retry_time: model.next_retry_time,
             send_time: model.send_time,
             created_time: model.created_time,
+            updated_time: model.updated_time,
         }
     }
 }
 
 /// 发送邮件请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct SendEmailParam {
     /// 收件人邮箱
     #[validate(email(message = "收件人邮箱格式不正确"))]
@@ -53,25 +61,33 @@ pub struct SendEmailParam {
     /// 是否HTML格式
     #[serde(default)]
     pub is_html: bool,
+    
+    /// 纯文本正文（HTML邮件的替代内容，可选）
+    #[validate(length(max = 100000, message = "纯文本内容长度不能超过100000"))]
+    pub text_content: Option<String>,
 }
 
 /// 发送模板邮件请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct SendTemplateEmailParam {
     /// 收件人邮箱
     #[validate(email(message = "收件人邮箱格式不正确"))]
     pub to: String,
     
-    /// 模板名称
-    #[validate(length(min = 1, max = 50, message = "模板名称长度必须在1-50之间"))]
+    /// 模板编码
+    #[validate(length(min = 1, max = 50, message = "模板编码长度必须在1-50之间"))]
     pub template: String,
     
-    /// 模板数据
-    pub data: HashMap<String, String>,
+    /// 语言（如 en-US，缺省时使用请求的 Accept-Language，不存在时回退到默认语言）
+    pub locale: Option<String>,
+    
+    /// 模板数据（JSON对象，支持嵌套）
+    #[serde(default)]
+    pub data: serde_json::Value,
 }
 
 /// 测试SMTP配置请求参数
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct TestSmtpParam {
     /// SMTP服务器
     #[validate(length(min = 1, max = 255, message = "SMTP服务器不能为空"))]
@@ -92,10 +108,14 @@ pub struct TestSmtpParam {
     /// 测试收件人
     #[validate(email(message = "测试收件人邮箱格式不正确"))]
     pub test_to: String,
+    
+    /// 发件人（默认test@example.com，多数SMTP服务要求与账号一致）
+    #[validate(email(message = "发件人邮箱格式不正确"))]
+    pub from: Option<String>,
 }
 
 /// 邮件记录查询参数
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct EmailRecordQuery {
     /// 收件人邮箱（模糊查询）
     pub to_email: Option<String>,
@@ -103,3 +123,160 @@ pub struct EmailRecordQuery {
     /// 发送状态
     pub status: Option<i16>,
 }
+
+/// 抑制名单详情
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct EmailSuppressionDetail {
+    pub id: i64,
+    pub email: String,
+    pub reason: String,
+    pub detail: Option<String>,
+    pub created_time: NaiveDateTime,
+}
+
+impl From<crate::entity::email_suppression::Model> for EmailSuppressionDetail {
+    fn from(model: crate::entity::email_suppression::Model) -> Self {
+        Self {
+            id: model.id,
+            email: model.email,
+            reason: model.reason,
+            detail: model.detail,
+            created_time: model.created_time,
+        }
+    }
+}
+
+/// 添加抑制名单请求参数
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AddSuppressionParam {
+    /// 收件人邮箱
+    #[validate(email(message = "邮箱格式不正确"))]
+    pub email: String,
+    
+    /// 原因（bounce/complaint/manual，默认manual）
+    #[validate(custom(function = "validate_suppression_reason"))]
+    pub reason: Option<String>,
+    
+    /// 详情
+    pub detail: Option<String>,
+}
+
+fn validate_suppression_reason(reason: &str) -> Result<(), validator::ValidationError> {
+    use crate::entity::email_suppression::{REASON_BOUNCE, REASON_COMPLAINT, REASON_MANUAL};
+
+    if [REASON_BOUNCE, REASON_COMPLAINT, REASON_MANUAL].contains(&reason) {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("reason");
+        error.message = Some("原因必须是 bounce、complaint 或 manual".into());
+        Err(error)
+    }
+}
+
+/// 抑制名单查询参数
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct SuppressionQuery {
+    /// 邮箱（模糊查询）
+    pub email: Option<String>,
+    
+    /// 原因
+    pub reason: Option<String>,
+}
+
+/// 邮件模板详情
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct EmailTemplateDetail {
+    pub id: i64,
+    pub code: String,
+    pub locale: String,
+    pub version: i32,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: Option<String>,
+    pub sample_data: Option<serde_json::Value>,
+    pub is_current: i16,
+    pub remark: Option<String>,
+    pub created_time: NaiveDateTime,
+}
+
+impl From<crate::entity::email_template::Model> for EmailTemplateDetail {
+    fn from(model: crate::entity::email_template::Model) -> Self {
+        Self {
+            id: model.id,
+            code: model.code,
+            locale: model.locale,
+            version: model.version,
+            subject: model.subject,
+            html_body: model.html_body,
+            text_body: model.text_body,
+            sample_data: model.sample_data.and_then(|s| serde_json::from_str(&s).ok()),
+            is_current: model.is_current,
+            remark: model.remark,
+            created_time: model.created_time,
+        }
+    }
+}
+
+/// 邮件模板内容（修改时生成新版本）
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct EmailTemplateContent {
+    /// 主题模板
+    #[validate(length(min = 1, max = 255, message = "主题长度必须在1-255之间"))]
+    pub subject: String,
+    
+    /// HTML正文模板
+    #[validate(length(min = 1, max = 1000000, message = "HTML正文长度必须在1-1000000之间"))]
+    pub html_body: String,
+    
+    /// 纯文本正文模板
+    #[validate(length(max = 100000, message = "纯文本正文长度不能超过100000"))]
+    pub text_body: Option<String>,
+    
+    /// 预览用示例数据
+    pub sample_data: Option<serde_json::Value>,
+    
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过500"))]
+    pub remark: Option<String>,
+}
+
+/// 创建邮件模板请求参数
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateEmailTemplateParam {
+    /// 模板编码
+    #[validate(length(min = 1, max = 50, message = "模板编码长度必须在1-50之间"))]
+    pub code: String,
+    
+    /// 语言
+    #[validate(length(min = 1, max = 20, message = "语言长度必须在1-20之间"))]
+    pub locale: String,
+    
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub content: EmailTemplateContent,
+}
+
+/// 邮件模板查询参数
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct EmailTemplateQuery {
+    /// 模板编码（模糊查询）
+    pub code: Option<String>,
+    
+    /// 语言
+    pub locale: Option<String>,
+}
+
+/// 模板预览请求参数
+#[derive(Debug, Deserialize, Default, ToSchema)]
+pub struct PreviewEmailTemplateParam {
+    /// 预览数据，缺省时使用模板的示例数据
+    pub data: Option<serde_json::Value>,
+}
+
+/// 模板预览结果
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmailTemplatePreview {
+    pub subject: String,
+    pub html: String,
+    pub text: Option<String>,
+}
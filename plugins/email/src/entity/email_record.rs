@@ -1,8 +1,20 @@
 //! 邮件发送记录实体
 
 use sea_orm::entity::prelude::*;
+use sea_orm::{Condition, QueryOrder};
 use serde::{Deserialize, Serialize};
 
+/// 待发送（排队中或等待重试）
+pub const STATUS_PENDING: i16 = 0;
+/// 发送成功
+pub const STATUS_SUCCESS: i16 = 1;
+/// 发送失败（永久失败或重试次数用尽）
+pub const STATUS_FAILED: i16 = 2;
+/// 发送中（已被队列领取）
+pub const STATUS_SENDING: i16 = 3;
+/// 已抑制（收件人在抑制名单中）
+pub const STATUS_SUPPRESSED: i16 = 4;
+
 /// 邮件发送记录实体模型
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "sys_email_record")]
@@ -23,22 +35,37 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub content: String,
     
+    /// 纯文本正文（HTML邮件的替代内容）
+    #[sea_orm(column_type = "Text", nullable)]
+    pub text_content: Option<String>,
+    
     /// 是否HTML格式（1是/0否）
     pub is_html: i16,
     
-    /// 发送状态（0待发送/1发送成功/2发送失败）
+    /// 发送状态（0待发送/1发送成功/2发送失败/3发送中/4已抑制）
     pub status: i16,
     
     /// 错误信息
     #[sea_orm(column_type = "Text", nullable)]
     pub error_msg: Option<String>,
     
+    /// 已重试次数
+    pub retry_count: i32,
+    
+    /// 下次发送时间（为空表示立即发送）
+    #[sea_orm(nullable)]
+    pub next_retry_time: Option<DateTime>,
+    
     /// 发送时间
     #[sea_orm(nullable)]
     pub send_time: Option<DateTime>,
     
     /// 创建时间
     pub created_time: DateTime,
+    
+    /// 更新时间
+    #[sea_orm(nullable)]
+    pub updated_time: Option<DateTime>,
 }
 
 /// 关系定义
@@ -52,16 +79,27 @@ impl ActiveModelBehavior for ActiveModel {}
 impl Entity {
     /// 查找待发送的邮件
     pub fn find_pending() -> Select<Self> {
-        Self::find().filter(Column::Status.eq(0))
+        Self::find().filter(Column::Status.eq(STATUS_PENDING))
+    }
+    
+    /// 查找已到发送时间的待发送邮件
+    pub fn find_due(now: DateTime) -> Select<Self> {
+        Self::find_pending()
+            .filter(
+                Condition::any()
+                    .add(Column::NextRetryTime.is_null())
+                    .add(Column::NextRetryTime.lte(now)),
+            )
+            .order_by_asc(Column::Id)
     }
     
     /// 查找发送成功的邮件
     pub fn find_success() -> Select<Self> {
-        Self::find().filter(Column::Status.eq(1))
+        Self::find().filter(Column::Status.eq(STATUS_SUCCESS))
     }
     
     /// 查找发送失败的邮件
     pub fn find_failed() -> Select<Self> {
-        Self::find().filter(Column::Status.eq(2))
+        Self::find().filter(Column::Status.eq(STATUS_FAILED))
     }
 }
@@ -0,0 +1,42 @@
+//! 邮件抑制名单实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 硬退信
+pub const REASON_BOUNCE: &str = "bounce";
+/// 投诉
+pub const REASON_COMPLAINT: &str = "complaint";
+/// 手动添加
+pub const REASON_MANUAL: &str = "manual";
+
+/// 邮件抑制名单实体模型
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_email_suppression")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    
+    /// 收件人邮箱（小写）
+    #[sea_orm(column_type = "String(StringLen::N(255))", unique)]
+    pub email: String,
+    
+    /// 原因（bounce/complaint/manual）
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub reason: String,
+    
+    /// 详情
+    #[sea_orm(column_type = "Text", nullable)]
+    pub detail: Option<String>,
+    
+    /// 创建时间
+    pub created_time: DateTime,
+}
+
+/// 关系定义
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// ActiveModel行为实现
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,57 @@
+//! 邮件模板实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 邮件模板实体模型
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_email_template")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    
+    /// 模板编码
+    #[sea_orm(column_type = "String(StringLen::N(50))")]
+    pub code: String,
+    
+    /// 语言（如 zh-CN、en-US）
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub locale: String,
+    
+    /// 版本号
+    pub version: i32,
+    
+    /// 主题模板
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub subject: String,
+    
+    /// HTML正文模板
+    #[sea_orm(column_type = "Text")]
+    pub html_body: String,
+    
+    /// 纯文本正文模板
+    #[sea_orm(column_type = "Text", nullable)]
+    pub text_body: Option<String>,
+    
+    /// 预览用示例数据（JSON）
+    #[sea_orm(column_type = "Text", nullable)]
+    pub sample_data: Option<String>,
+    
+    /// 是否当前版本（1是/0否）
+    pub is_current: i16,
+    
+    /// 备注
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub remark: Option<String>,
+    
+    /// 创建时间
+    pub created_time: DateTime,
+}
+
+/// 关系定义
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// ActiveModel行为实现
+impl ActiveModelBehavior for ActiveModel {}
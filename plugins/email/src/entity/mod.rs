@@ -1,3 +1,29 @@
 pub mod email_record;
+pub mod email_suppression;
+pub mod email_template;
 
 pub use email_record::Entity as EmailRecordEntity;
+pub use email_suppression::Entity as EmailSuppressionEntity;
+pub use email_template::Entity as EmailTemplateEntity;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use sea_orm::*;
+
+    /// 建好全部邮件表的内存 SQLite 数据库
+    pub(crate) async fn sqlite() -> DatabaseConnection {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1);
+        let db = Database::connect(options).await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(super::email_record::Entity),
+            schema.create_table_from_entity(super::email_suppression::Entity),
+            schema.create_table_from_entity(super::email_template::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await.unwrap();
+        }
+        db
+    }
+}
@@ -61,8 +61,8 @@ impl From<validator::ValidationErrors> for EmailError {
     fn from(errors: validator::ValidationErrors) -> Self {
         let messages: Vec<String> = errors
             .field_errors()
-            .iter()
-            .flat_map(|(_, errs)| {
+            .values()
+            .flat_map(|errs| {
                 errs.iter()
                     .filter_map(|e| e.message.as_ref().map(|m| m.to_string()))
             })
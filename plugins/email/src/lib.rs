@@ -2,11 +2,11 @@
 //! 提供SMTP邮件发送、模板邮件、发送记录管理功能，与Python版本完全对齐
 //! 
 //! # 功能特性
-//! - 邮件发送系统（5个API端点）
-//! - SMTP配置管理
-//! - 邮件模板系统
-//! - 异步发送队列
+//! - SMTP配置管理（连接池、STARTTLS/TLS、可选DKIM签名）
+//! - 数据库管理的邮件模板（多语言、版本管理、预览，编译结果缓存）
+//! - 异步发送队列（指数退避重试、按收件域名限速）
 //! - 发送记录管理
+//! - 退信抑制名单（硬退信自动加入）
 //! 
 //! # API端点
 //! - POST /send - 发送邮件
@@ -14,6 +14,14 @@
 //! - POST /test-smtp - 测试SMTP配置
 //! - GET /records - 查询发送记录
 //! - GET /records/{id} - 获取记录详情
+//! - POST /records/{id}/retry - 重新发送失败的邮件
+//! - GET/POST /suppressions - 查询/添加抑制名单
+//! - DELETE /suppressions/{id} - 移出抑制名单
+//! - GET/POST /templates - 查询/创建邮件模板
+//! - GET/PUT/DELETE /templates/{id} - 模板详情/修改（生成新版本）/删除
+//! - GET /templates/{id}/versions - 模板历史版本
+//! - POST /templates/{id}/activate - 切换到指定版本
+//! - POST /templates/{id}/preview - 使用示例数据预览模板
 
 pub mod entity;
 pub mod dto;
@@ -52,13 +60,22 @@ impl EmailPlugin {
     /// 创建插件路由
     /// 
     /// 注意：这是一个独立路由插件 (router = ['v1'])
-    pub fn create_router(db: DatabaseConnection, smtp_config: SmtpConfig) -> Router {
-        let state = api::AppState { db, smtp_config };
+    pub fn create_router(db: DatabaseConnection) -> Router {
+        let state = api::AppState { db };
         
         Router::new()
             .nest("/email", api::email_routes())
             .with_state(state)
     }
+
+    /// 启动发送队列（进程内只启动一次）
+    pub fn start_queue(
+        db: DatabaseConnection,
+        smtp_config: &SmtpConfig,
+        queue_config: QueueConfig,
+    ) -> Result<(), EmailError> {
+        EmailQueue::start(db, smtp_config, queue_config).map(|_| ())
+    }
 }
 
 // 导出公共类型
@@ -66,8 +83,13 @@ pub use dto::{
     EmailRecordDetail,
     SendEmailParam, SendTemplateEmailParam, TestSmtpParam,
     EmailRecordQuery,
+    EmailSuppressionDetail, AddSuppressionParam, SuppressionQuery,
+    EmailTemplateDetail, EmailTemplateContent, CreateEmailTemplateParam,
+    EmailTemplateQuery, PreviewEmailTemplateParam, EmailTemplatePreview,
     PaginationQuery, PageData, ApiResponse,
 };
 pub use error::EmailError;
-pub use service::{EmailService, SmtpConfig};
+pub use service::{
+    EmailQueue, EmailService, QueueConfig, SmtpConfig, SuppressionService, TemplateService,
+};
 pub use api::AppState;
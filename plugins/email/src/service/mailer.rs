@@ -0,0 +1,340 @@
+//! SMTP 发送器
+//!
+//! 基于连接池的异步 SMTP 传输，可选 DKIM 签名；发送失败按 SMTP 响应码分类，
+//! 供发送队列决定重试、放弃或加入抑制名单。
+
+use lettre::{
+    message::{header::ContentType, Mailbox, MultiPart, SinglePart},
+    transport::smtp::{
+        authentication::Credentials,
+        client::Tls,
+        PoolConfig,
+    },
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use lettre::message::dkim::{DkimConfig, DkimSigningAlgorithm, DkimSigningKey};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::EmailError;
+
+/// SMTP 连接加密方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// 明文连接后升级（587 端口）
+    StartTls,
+    /// 隐式 TLS（465 端口）
+    Tls,
+    /// 不加密（仅用于本地 SMTP 测试服务）
+    None,
+}
+
+impl SmtpTls {
+    fn parse(value: &str) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "tls" | "ssl" => Self::Tls,
+            "none" | "plain" => Self::None,
+            _ => Self::StartTls,
+        }
+    }
+}
+
+/// DKIM 签名配置
+#[derive(Clone)]
+pub struct DkimOptions {
+    /// DNS 中发布公钥的选择器
+    pub selector: String,
+    /// 签名域名
+    pub domain: String,
+    /// 私钥：RSA 为 PKCS#1 PEM，Ed25519 为 Base64 编码的 32 字节私钥
+    pub private_key: String,
+    /// 签名算法（rsa / ed25519）
+    pub algorithm: String,
+}
+
+impl DkimOptions {
+    /// 从环境变量读取，未配置选择器或域名时不签名
+    fn from_env() -> Option<Self> {
+        let selector = std::env::var("EMAIL_DKIM_SELECTOR").ok().filter(|s| !s.is_empty())?;
+        let domain = std::env::var("EMAIL_DKIM_DOMAIN").ok().filter(|s| !s.is_empty())?;
+        let private_key = match std::env::var("EMAIL_DKIM_PRIVATE_KEY_FILE") {
+            Ok(path) if !path.is_empty() => std::fs::read_to_string(&path)
+                .map_err(|e| tracing::error!("读取 DKIM 私钥文件 {} 失败: {}", path, e))
+                .ok()?,
+            _ => std::env::var("EMAIL_DKIM_PRIVATE_KEY").ok()?,
+        };
+
+        Some(Self {
+            selector,
+            domain,
+            private_key,
+            algorithm: std::env::var("EMAIL_DKIM_ALGORITHM").unwrap_or_else(|_| "rsa".to_string()),
+        })
+    }
+
+    fn build(&self) -> Result<DkimConfig, EmailError> {
+        let algorithm = match self.algorithm.to_ascii_lowercase().as_str() {
+            "rsa" => DkimSigningAlgorithm::Rsa,
+            "ed25519" => DkimSigningAlgorithm::Ed25519,
+            other => return Err(EmailError::SmtpError(format!("不支持的 DKIM 算法: {}", other))),
+        };
+        let key = DkimSigningKey::new(self.private_key.trim(), algorithm)
+            .map_err(|e| EmailError::SmtpError(format!("DKIM 私钥无效: {:?}", e)))?;
+
+        Ok(DkimConfig::default_config(self.selector.clone(), self.domain.clone(), key))
+    }
+}
+
+/// SMTP配置
+#[derive(Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    /// 连接加密方式
+    pub tls: SmtpTls,
+    /// 连接池最大连接数
+    pub pool_size: u32,
+    /// 单次操作超时（秒）
+    pub timeout_seconds: u64,
+    /// DKIM 签名，为空时不签名
+    pub dkim: Option<DkimOptions>,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: std::env::var("SMTP_HOST").unwrap_or_else(|_| "smtp.gmail.com".to_string()),
+            port: std::env::var("SMTP_PORT")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(587),
+            username: std::env::var("SMTP_USERNAME").unwrap_or_default(),
+            password: std::env::var("SMTP_PASSWORD").unwrap_or_default(),
+            from: std::env::var("SMTP_FROM").unwrap_or_else(|_| "noreply@example.com".to_string()),
+            tls: SmtpTls::parse(&std::env::var("SMTP_TLS").unwrap_or_default()),
+            pool_size: std::env::var("SMTP_POOL_SIZE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(4),
+            timeout_seconds: std::env::var("SMTP_TIMEOUT_SECONDS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(30),
+            dkim: DkimOptions::from_env(),
+        }
+    }
+}
+
+/// 发送失败类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// 临时失败（4xx、网络或超时），可重试
+    Transient,
+    /// 永久失败（5xx、地址或配置错误），不再重试
+    Permanent,
+    /// 硬退信（收件人邮箱不存在或被拒收），加入抑制名单
+    Bounce,
+}
+
+/// 发送失败
+#[derive(Debug, Clone)]
+pub struct SendError {
+    pub kind: FailureKind,
+    pub message: String,
+}
+
+impl SendError {
+    fn permanent(message: impl Into<String>) -> Self {
+        Self { kind: FailureKind::Permanent, message: message.into() }
+    }
+}
+
+impl From<lettre::transport::smtp::Error> for SendError {
+    fn from(e: lettre::transport::smtp::Error) -> Self {
+        Self { kind: classify(&e), message: format!("邮件发送失败: {}", e) }
+    }
+}
+
+/// 按 SMTP 响应码分类：550/551/553 表示收件人邮箱不可用，属于硬退信
+fn classify(e: &lettre::transport::smtp::Error) -> FailureKind {
+    match e.status() {
+        Some(code) if e.is_permanent() => match code.to_string().as_str() {
+            "550" | "551" | "553" => FailureKind::Bounce,
+            _ => FailureKind::Permanent,
+        },
+        Some(_) => FailureKind::Transient,
+        None if e.is_client() => FailureKind::Permanent,
+        None => FailureKind::Transient,
+    }
+}
+
+/// 邮件发送器
+#[derive(Clone)]
+pub struct Mailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    dkim: Option<Arc<DkimConfig>>,
+}
+
+impl Mailer {
+    /// 创建发送器（连接在首次发送时建立并进入连接池）
+    pub fn new(config: &SmtpConfig) -> Result<Self, EmailError> {
+        let builder = match config.tls {
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host),
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host),
+            SmtpTls::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+                .tls(Tls::None)),
+        }
+        .map_err(|e| EmailError::SmtpError(format!("SMTP配置无效: {}", e)))?;
+
+        let mut builder = builder
+            .port(config.port)
+            .timeout(Some(Duration::from_secs(config.timeout_seconds)))
+            .pool_config(PoolConfig::new().max_size(config.pool_size.max(1)));
+        if !config.username.is_empty() {
+            builder = builder.credentials(Credentials::new(config.username.clone(), config.password.clone()));
+        }
+
+        let from = config
+            .from
+            .parse()
+            .map_err(|e| EmailError::SmtpError(format!("From地址错误: {}", e)))?;
+        let dkim = config.dkim.as_ref().map(DkimOptions::build).transpose()?.map(Arc::new);
+
+        Ok(Self { transport: builder.build(), from, dkim })
+    }
+
+    /// 发送邮件，HTML 邮件附带纯文本正文时以 multipart/alternative 发送
+    pub async fn send(
+        &self,
+        to: &str,
+        subject: &str,
+        content: &str,
+        is_html: bool,
+        text_content: Option<&str>,
+    ) -> Result<(), SendError> {
+        let to: Mailbox = to
+            .parse()
+            .map_err(|e| SendError::permanent(format!("To地址错误: {}", e)))?;
+
+        let builder = Message::builder().from(self.from.clone()).to(to).subject(subject);
+        let message = match (is_html, text_content) {
+            (true, Some(text)) => builder.multipart(MultiPart::alternative_plain_html(
+                text.to_string(),
+                content.to_string(),
+            )),
+            (true, None) => builder.singlepart(SinglePart::html(content.to_string())),
+            (false, _) => builder.header(ContentType::TEXT_PLAIN).body(content.to_string()),
+        };
+        let mut message = message.map_err(|e| SendError::permanent(format!("邮件构建失败: {}", e)))?;
+        if let Some(dkim) = &self.dkim {
+            message.sign(dkim);
+        }
+
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    /// 本地 SMTP 测试服务：记录收到的邮件；收件人为 bounce@ 时返回 550，busy@ 时返回 451
+    pub(crate) async fn smtp_sink() -> (u16, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let store = received.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else { break };
+                let store = store.clone();
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut lines = BufReader::new(reader).lines();
+                    writer.write_all(b"220 sink ready\r\n").await.unwrap();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        let command = line.to_ascii_uppercase();
+                        let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                            b"250 sink\r\n"
+                        } else if command.starts_with("RCPT") && command.contains("BOUNCE@") {
+                            b"550 5.1.1 mailbox unavailable\r\n"
+                        } else if command.starts_with("RCPT") && command.contains("BUSY@") {
+                            b"451 4.3.0 try again later\r\n"
+                        } else if command.starts_with("DATA") {
+                            writer.write_all(b"354 go ahead\r\n").await.unwrap();
+                            let mut data = String::new();
+                            while let Ok(Some(line)) = lines.next_line().await {
+                                if line == "." {
+                                    break;
+                                }
+                                data.push_str(&line);
+                                data.push('\n');
+                            }
+                            store.lock().unwrap().push(data);
+                            b"250 queued\r\n"
+                        } else if command.starts_with("QUIT") {
+                            let _ = writer.write_all(b"221 bye\r\n").await;
+                            break;
+                        } else {
+                            b"250 ok\r\n"
+                        };
+                        if writer.write_all(reply).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        (port, received)
+    }
+
+    pub(crate) fn sink_config(port: u16) -> SmtpConfig {
+        SmtpConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            username: String::new(),
+            password: String::new(),
+            from: "noreply@example.com".to_string(),
+            tls: SmtpTls::None,
+            pool_size: 2,
+            timeout_seconds: 5,
+            dkim: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_to_sink() {
+        let (port, received) = smtp_sink().await;
+        let mailer = Mailer::new(&sink_config(port)).unwrap();
+
+        mailer.send("user@example.com", "hello", "<p>hi</p>", true, None).await.unwrap();
+        mailer.send("user@example.com", "hello", "<p>hi</p>", true, Some("hi")).await.unwrap();
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received.len(), 2);
+        assert!(received[0].contains("Content-Type: text/html"));
+        assert!(received[1].contains("multipart/alternative") && received[1].contains("text/plain"));
+    }
+
+    #[tokio::test]
+    async fn test_classify_failures() {
+        let (port, _) = smtp_sink().await;
+        let mailer = Mailer::new(&sink_config(port)).unwrap();
+
+        let bounce = mailer.send("bounce@example.com", "s", "c", false, None).await.unwrap_err();
+        assert_eq!(bounce.kind, FailureKind::Bounce);
+        let busy = mailer.send("busy@example.com", "s", "c", false, None).await.unwrap_err();
+        assert_eq!(busy.kind, FailureKind::Transient);
+        let invalid = mailer.send("not-an-address", "s", "c", false, None).await.unwrap_err();
+        assert_eq!(invalid.kind, FailureKind::Permanent);
+    }
+}
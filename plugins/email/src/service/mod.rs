@@ -1,3 +1,13 @@
 pub mod email_service;
+pub mod mailer;
+pub mod queue;
+pub mod rate_limit;
+pub mod suppression_service;
+pub mod template_service;
 
-pub use email_service::{EmailService, SmtpConfig};
+pub use email_service::EmailService;
+pub use mailer::{DkimOptions, FailureKind, Mailer, SendError, SmtpConfig, SmtpTls};
+pub use queue::{EmailQueue, QueueConfig};
+pub use rate_limit::DomainRateLimiter;
+pub use suppression_service::SuppressionService;
+pub use template_service::{RenderedEmail, TemplateService};
@@ -0,0 +1,374 @@
+//! 邮件发送队列
+//!
+//! 以 `sys_email_record.status` 作为状态机：
+//! 待发送(0) -> 发送中(3) -> 发送成功(1) / 待发送(0，等待重试) / 发送失败(2) / 已抑制(4)。
+//! 领取记录使用条件更新，多实例同时轮询时同一封邮件只会被一个实例发送；
+//! 实例中断后停留在“发送中”的记录超时后重新排队。
+
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use crate::entity::email_record::{
+    self, STATUS_FAILED, STATUS_PENDING, STATUS_SENDING, STATUS_SUCCESS, STATUS_SUPPRESSED,
+};
+use crate::entity::email_suppression::REASON_BOUNCE;
+use crate::error::EmailError;
+use crate::service::mailer::{FailureKind, Mailer, SendError, SmtpConfig};
+use crate::service::rate_limit::{domain_of, DomainRateLimiter};
+use crate::service::suppression_service::SuppressionService;
+
+/// 全局发送队列
+static QUEUE: OnceLock<Arc<EmailQueue>> = OnceLock::new();
+
+/// 队列配置
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    /// 每轮最多领取的邮件数
+    pub batch_size: u64,
+    /// 轮询间隔（秒），新邮件入队时会立即唤醒
+    pub poll_interval_seconds: u64,
+    /// 临时失败的最大重试次数
+    pub max_retries: i32,
+    /// 首次重试延迟（秒），之后按 2 的指数增长
+    pub retry_base_seconds: u64,
+    /// 最大重试延迟（秒）
+    pub retry_max_seconds: u64,
+    /// 发送中记录的超时（秒），超时后重新排队
+    pub sending_timeout_seconds: u64,
+    /// 每个收件域名每分钟的默认发送上限，0 表示不限速
+    pub domain_rate_limit: u32,
+    /// 按域名覆盖的每分钟发送上限
+    pub domain_rate_limits: HashMap<String, u32>,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        let env = |key: &str| std::env::var(key).ok();
+        Self {
+            batch_size: env("EMAIL_QUEUE_BATCH_SIZE").and_then(|s| s.parse().ok()).unwrap_or(20),
+            poll_interval_seconds: env("EMAIL_QUEUE_POLL_INTERVAL_SECONDS")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(10),
+            max_retries: env("EMAIL_QUEUE_MAX_RETRIES").and_then(|s| s.parse().ok()).unwrap_or(5),
+            retry_base_seconds: env("EMAIL_QUEUE_RETRY_BASE_SECONDS")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(60),
+            retry_max_seconds: env("EMAIL_QUEUE_RETRY_MAX_SECONDS")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3600),
+            sending_timeout_seconds: env("EMAIL_QUEUE_SENDING_TIMEOUT_SECONDS")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(600),
+            domain_rate_limit: env("EMAIL_DOMAIN_RATE_LIMIT").and_then(|s| s.parse().ok()).unwrap_or(60),
+            domain_rate_limits: env("EMAIL_DOMAIN_RATE_LIMITS")
+                .map(|s| DomainRateLimiter::parse_overrides(&s))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl QueueConfig {
+    /// 第 n 次重试前的等待时间
+    pub fn backoff(&self, retry_count: i32) -> Duration {
+        let factor = 2u64.saturating_pow(retry_count.clamp(0, 32) as u32);
+        Duration::from_secs(
+            self.retry_base_seconds
+                .saturating_mul(factor)
+                .min(self.retry_max_seconds),
+        )
+    }
+}
+
+/// 邮件发送队列
+pub struct EmailQueue {
+    db: DatabaseConnection,
+    mailer: Mailer,
+    config: QueueConfig,
+    limiter: DomainRateLimiter,
+    notify: Notify,
+}
+
+impl EmailQueue {
+    pub fn new(db: DatabaseConnection, mailer: Mailer, config: QueueConfig) -> Self {
+        let limiter = DomainRateLimiter::new(
+            config.domain_rate_limit,
+            config.domain_rate_limits.clone(),
+            Duration::from_secs(60),
+        );
+        Self {
+            db,
+            mailer,
+            config,
+            limiter,
+            notify: Notify::new(),
+        }
+    }
+
+    /// 启动全局发送队列，重复调用返回已启动的队列
+    pub fn start(
+        db: DatabaseConnection,
+        smtp_config: &SmtpConfig,
+        config: QueueConfig,
+    ) -> Result<Arc<Self>, EmailError> {
+        if let Some(queue) = QUEUE.get() {
+            return Ok(queue.clone());
+        }
+
+        let queue = Arc::new(Self::new(db, Mailer::new(smtp_config)?, config));
+        if QUEUE.set(queue.clone()).is_err() {
+            return Ok(QUEUE.get().cloned().unwrap_or(queue));
+        }
+        tokio::spawn(queue.clone().run());
+        tracing::info!("邮件发送队列已启动");
+
+        Ok(queue)
+    }
+
+    /// 唤醒发送队列（新邮件入队后调用，队列未启动时等待下次启动后轮询）
+    pub fn wake() {
+        if let Some(queue) = QUEUE.get() {
+            queue.notify.notify_one();
+        }
+    }
+
+    async fn run(self: Arc<Self>) {
+        let interval = Duration::from_secs(self.config.poll_interval_seconds.max(1));
+        loop {
+            match self.process_batch().await {
+                // 满批说明可能还有到期邮件，立即继续
+                Ok(processed) if processed as u64 >= self.config.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!("邮件发送队列处理失败: {}", e),
+            }
+            tokio::select! {
+                _ = self.notify.notified() => {}
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    /// 处理一批到期邮件，返回领取的数量
+    pub async fn process_batch(&self) -> Result<usize, EmailError> {
+        self.requeue_stale().await?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let records = email_record::Entity::find_due(now)
+            .limit(self.config.batch_size)
+            .all(&self.db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        let mut processed = 0;
+        for record in records {
+            if self.claim(record.id).await? {
+                self.deliver(record).await?;
+                processed += 1;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    /// 领取待发送记录，已被其他实例领取时返回 false
+    async fn claim(&self, id: i64) -> Result<bool, EmailError> {
+        let result = email_record::Entity::update_many()
+            .col_expr(email_record::Column::Status, Expr::value(STATUS_SENDING))
+            .col_expr(email_record::Column::UpdatedTime, Expr::value(chrono::Utc::now().naive_utc()))
+            .filter(email_record::Column::Id.eq(id))
+            .filter(email_record::Column::Status.eq(STATUS_PENDING))
+            .exec(&self.db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 超时未完成的发送中记录重新排队
+    async fn requeue_stale(&self) -> Result<(), EmailError> {
+        let deadline = chrono::Utc::now().naive_utc()
+            - chrono::Duration::seconds(self.config.sending_timeout_seconds as i64);
+        email_record::Entity::update_many()
+            .col_expr(email_record::Column::Status, Expr::value(STATUS_PENDING))
+            .filter(email_record::Column::Status.eq(STATUS_SENDING))
+            .filter(email_record::Column::UpdatedTime.lt(deadline))
+            .exec(&self.db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn deliver(&self, record: email_record::Model) -> Result<(), EmailError> {
+        if SuppressionService::is_suppressed(&self.db, &record.to_email).await? {
+            return self
+                .finish(record, STATUS_SUPPRESSED, Some("收件人在抑制名单中".to_string()))
+                .await;
+        }
+
+        // 超出域名限额：延后发送，不计入重试次数
+        if let Err(wait) = self.limiter.acquire(domain_of(&record.to_email)) {
+            return self.schedule(record, wait, false, None).await;
+        }
+
+        let result = self
+            .mailer
+            .send(
+                &record.to_email,
+                &record.subject,
+                &record.content,
+                record.is_html == 1,
+                record.text_content.as_deref(),
+            )
+            .await;
+        match result {
+            Ok(()) => self.finish(record, STATUS_SUCCESS, None).await,
+            Err(SendError { kind: FailureKind::Transient, message })
+                if record.retry_count < self.config.max_retries =>
+            {
+                let wait = self.config.backoff(record.retry_count);
+                tracing::warn!("邮件 {} 发送失败，{} 秒后重试: {}", record.id, wait.as_secs(), message);
+                self.schedule(record, wait, true, Some(message)).await
+            }
+            Err(SendError { kind, message }) => {
+                tracing::error!("邮件 {} 发送失败: {}", record.id, message);
+                if kind == FailureKind::Bounce {
+                    SuppressionService::add(&self.db, &record.to_email, REASON_BOUNCE, Some(message.clone()))
+                        .await?;
+                }
+                self.finish(record, STATUS_FAILED, Some(message)).await
+            }
+        }
+    }
+
+    /// 重新排队
+    async fn schedule(
+        &self,
+        record: email_record::Model,
+        wait: Duration,
+        count_retry: bool,
+        error_msg: Option<String>,
+    ) -> Result<(), EmailError> {
+        let now = chrono::Utc::now().naive_utc();
+        let retry_count = record.retry_count + i32::from(count_retry);
+        let mut record: email_record::ActiveModel = record.into();
+        record.status = Set(STATUS_PENDING);
+        record.retry_count = Set(retry_count);
+        record.next_retry_time = Set(Some(now + chrono::Duration::from_std(wait).unwrap_or_default()));
+        if error_msg.is_some() {
+            record.error_msg = Set(error_msg);
+        }
+        record.updated_time = Set(Some(now));
+        record
+            .update(&self.db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// 记录最终状态
+    async fn finish(
+        &self,
+        record: email_record::Model,
+        status: i16,
+        error_msg: Option<String>,
+    ) -> Result<(), EmailError> {
+        let now = chrono::Utc::now().naive_utc();
+        let mut record: email_record::ActiveModel = record.into();
+        record.status = Set(status);
+        record.next_retry_time = Set(None);
+        if status == STATUS_SUCCESS {
+            record.send_time = Set(Some(now));
+            record.error_msg = Set(None);
+        } else {
+            record.error_msg = Set(error_msg);
+        }
+        record.updated_time = Set(Some(now));
+        record
+            .update(&self.db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::SendEmailParam;
+    use crate::entity::tests::sqlite;
+    use crate::service::mailer::tests::{sink_config, smtp_sink};
+    use crate::service::EmailService;
+
+    async fn enqueue(db: &DatabaseConnection, to: &str) -> i64 {
+        let param = SendEmailParam {
+            to: to.to_string(),
+            subject: "subject".to_string(),
+            content: "content".to_string(),
+            is_html: false,
+            text_content: None,
+        };
+        EmailService::send_email(db, param).await.unwrap().id
+    }
+
+    async fn record(db: &DatabaseConnection, id: i64) -> email_record::Model {
+        email_record::Entity::find_by_id(id).one(db).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_queue_against_sink() {
+        let db = sqlite().await;
+        let (port, received) = smtp_sink().await;
+        let config = QueueConfig {
+            domain_rate_limit: 0,
+            domain_rate_limits: DomainRateLimiter::parse_overrides("limited.com=1"),
+            ..QueueConfig::default()
+        };
+        let queue = EmailQueue::new(db.clone(), Mailer::new(&sink_config(port)).unwrap(), config);
+
+        let ok = enqueue(&db, "user@example.com").await;
+        let bounce = enqueue(&db, "bounce@example.com").await;
+        let busy = enqueue(&db, "busy@example.com").await;
+        let limited = [enqueue(&db, "a@limited.com").await, enqueue(&db, "b@limited.com").await];
+        assert_eq!(queue.process_batch().await.unwrap(), 5);
+
+        assert_eq!(record(&db, ok).await.status, STATUS_SUCCESS);
+        assert_eq!(received.lock().unwrap().len(), 2);
+
+        // 硬退信：失败并加入抑制名单，之后发往该地址的邮件直接抑制
+        assert_eq!(record(&db, bounce).await.status, STATUS_FAILED);
+        assert!(SuppressionService::is_suppressed(&db, "Bounce@example.com").await.unwrap());
+        let suppressed = enqueue(&db, "bounce@example.com").await;
+        queue.process_batch().await.unwrap();
+        assert_eq!(record(&db, suppressed).await.status, STATUS_SUPPRESSED);
+
+        // 临时失败：计入重试并延后
+        let busy = record(&db, busy).await;
+        assert_eq!((busy.status, busy.retry_count), (STATUS_PENDING, 1));
+        assert!(busy.next_retry_time.is_some());
+
+        // 超出域名限额：延后但不计入重试
+        assert_eq!(record(&db, limited[0]).await.status, STATUS_SUCCESS);
+        let deferred = record(&db, limited[1]).await;
+        assert_eq!((deferred.status, deferred.retry_count), (STATUS_PENDING, 0));
+        assert!(deferred.next_retry_time.is_some());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let config = QueueConfig {
+            retry_base_seconds: 60,
+            retry_max_seconds: 600,
+            ..QueueConfig::default()
+        };
+        assert_eq!(config.backoff(0), Duration::from_secs(60));
+        assert_eq!(config.backoff(2), Duration::from_secs(240));
+        assert_eq!(config.backoff(10), Duration::from_secs(600));
+        assert_eq!(config.backoff(i32::MAX), Duration::from_secs(600));
+    }
+}
@@ -0,0 +1,88 @@
+//! 按收件域名限速
+//!
+//! 滑动窗口计数，超出限额的邮件延后发送而不计入重试次数。
+//! 计数保存在进程内，多实例部署时每个实例各自限速。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 域名限速器
+pub struct DomainRateLimiter {
+    /// 默认每个窗口允许的发送数，0 表示不限速
+    default_limit: u32,
+    /// 按域名覆盖的限额
+    overrides: HashMap<String, u32>,
+    window: Duration,
+    sent: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl DomainRateLimiter {
+    pub fn new(default_limit: u32, overrides: HashMap<String, u32>, window: Duration) -> Self {
+        Self {
+            default_limit,
+            overrides,
+            window,
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 解析域名限额配置，格式：`gmail.com=20,qq.com=10`
+    pub fn parse_overrides(value: &str) -> HashMap<String, u32> {
+        value
+            .split(',')
+            .filter_map(|item| {
+                let (domain, limit) = item.split_once('=')?;
+                Some((domain.trim().to_ascii_lowercase(), limit.trim().parse().ok()?))
+            })
+            .collect()
+    }
+
+    /// 申请一次发送额度，超出限额时返回需要等待的时长
+    pub fn acquire(&self, domain: &str) -> Result<(), Duration> {
+        let domain = domain.to_ascii_lowercase();
+        let limit = self.overrides.get(&domain).copied().unwrap_or(self.default_limit);
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+        let history = sent.entry(domain).or_default();
+        while history.front().is_some_and(|t| now.duration_since(*t) >= self.window) {
+            history.pop_front();
+        }
+
+        if history.len() >= limit as usize {
+            let oldest = history.front().copied().unwrap_or(now);
+            return Err(self.window.saturating_sub(now.duration_since(oldest)));
+        }
+        history.push_back(now);
+        Ok(())
+    }
+}
+
+/// 收件人邮箱的域名
+pub fn domain_of(email: &str) -> &str {
+    email.rsplit_once('@').map(|(_, domain)| domain).unwrap_or(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_rate_limit() {
+        let overrides = DomainRateLimiter::parse_overrides("gmail.com=1, bad, qq.com=x");
+        assert_eq!(overrides.len(), 1);
+
+        let limiter = DomainRateLimiter::new(2, overrides, Duration::from_secs(60));
+        assert!(limiter.acquire(domain_of("a@Gmail.com")).is_ok());
+        let wait = limiter.acquire("gmail.com").unwrap_err();
+        assert!(wait <= Duration::from_secs(60) && wait > Duration::from_secs(55));
+
+        assert!(limiter.acquire("example.com").is_ok());
+        assert!(limiter.acquire("example.com").is_ok());
+        assert!(limiter.acquire("example.com").is_err());
+    }
+}
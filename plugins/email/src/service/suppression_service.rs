@@ -0,0 +1,107 @@
+//! 邮件抑制名单服务
+
+use sea_orm::*;
+
+use crate::dto::*;
+use crate::entity::email_suppression;
+use crate::error::EmailError;
+
+/// 抑制名单服务
+pub struct SuppressionService;
+
+impl SuppressionService {
+    /// 收件人是否在抑制名单中
+    pub async fn is_suppressed(db: &DatabaseConnection, email: &str) -> Result<bool, EmailError> {
+        let count = email_suppression::Entity::find()
+            .filter(email_suppression::Column::Email.eq(email.trim().to_ascii_lowercase()))
+            .count(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(count > 0)
+    }
+
+    /// 加入抑制名单，已存在时更新原因和详情
+    pub async fn add(
+        db: &DatabaseConnection,
+        email: &str,
+        reason: &str,
+        detail: Option<String>,
+    ) -> Result<EmailSuppressionDetail, EmailError> {
+        let email = email.trim().to_ascii_lowercase();
+        let existing = email_suppression::Entity::find()
+            .filter(email_suppression::Column::Email.eq(&email))
+            .one(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        let model = match existing {
+            Some(model) => {
+                let mut model: email_suppression::ActiveModel = model.into();
+                model.reason = Set(reason.to_string());
+                model.detail = Set(detail);
+                model.update(db).await
+            }
+            None => {
+                email_suppression::ActiveModel {
+                    email: Set(email),
+                    reason: Set(reason.to_string()),
+                    detail: Set(detail),
+                    created_time: Set(chrono::Utc::now().naive_utc()),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await
+            }
+        }
+        .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        Ok(EmailSuppressionDetail::from(model))
+    }
+
+    /// 查询抑制名单
+    pub async fn get_list(
+        db: &DatabaseConnection,
+        query: SuppressionQuery,
+        pagination: PaginationQuery,
+    ) -> Result<PageData<EmailSuppressionDetail>, EmailError> {
+        let mut select = email_suppression::Entity::find();
+        if let Some(email) = &query.email {
+            select = select.filter(email_suppression::Column::Email.contains(email.to_ascii_lowercase()));
+        }
+        if let Some(reason) = &query.reason {
+            select = select.filter(email_suppression::Column::Reason.eq(reason));
+        }
+
+        let total = select
+            .clone()
+            .count(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        let items = select
+            .order_by_desc(email_suppression::Column::CreatedTime)
+            .offset(pagination.offset())
+            .limit(pagination.limit())
+            .all(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(EmailSuppressionDetail::from)
+            .collect();
+
+        Ok(PageData::new(items, total, pagination.page, pagination.size))
+    }
+
+    /// 移出抑制名单
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), EmailError> {
+        let result = email_suppression::Entity::delete_by_id(id)
+            .exec(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        if result.rows_affected == 0 {
+            return Err(EmailError::NotFound("记录不存在".to_string()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,497 @@
+//! 邮件模板服务
+//!
+//! 模板保存在数据库中，按 (编码, 语言) 区分，每次修改生成新版本，可回滚到历史版本。
+//! 所有当前版本编译为一个 Tera 集合缓存在进程内，本实例修改模板时立即失效，
+//! 其他实例的修改在缓存过期（`CACHE_TTL`）后生效。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use sea_orm::*;
+use serde_json::Value;
+use tera::{Context, Tera};
+
+use crate::dto::*;
+use crate::entity::email_template;
+use crate::error::EmailError;
+
+/// 编译缓存有效期
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// 已编译的当前版本模板集合
+static CACHE: RwLock<Option<Arc<CompiledTemplates>>> = RwLock::new(None);
+
+/// 渲染结果
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub html: String,
+    pub text: Option<String>,
+}
+
+struct CompiledTemplates {
+    tera: Tera,
+    /// 模板编码 -> 可用语言
+    locales: HashMap<String, HashSet<String>>,
+    /// 含纯文本正文的 (编码, 语言)
+    with_text: HashSet<(String, String)>,
+    loaded_at: Instant,
+}
+
+/// Tera 内部模板名，`.html` 后缀的正文开启自动转义
+fn template_name(code: &str, locale: &str, part: &str) -> String {
+    format!("{}/{}/{}", code, locale, part)
+}
+
+/// 默认语言，请求的语言没有对应模板时使用
+fn default_locale() -> String {
+    std::env::var("EMAIL_TEMPLATE_DEFAULT_LOCALE").unwrap_or_else(|_| "zh-CN".to_string())
+}
+
+/// 编译单个模板，返回只含该模板的 Tera 实例
+fn compile(model: &email_template::Model) -> Result<Tera, EmailError> {
+    let mut tera = Tera::default();
+    add_to(&mut tera, model)?;
+    Ok(tera)
+}
+
+fn add_to(tera: &mut Tera, model: &email_template::Model) -> Result<(), EmailError> {
+    let mut parts = vec![
+        (template_name(&model.code, &model.locale, "subject.txt"), model.subject.as_str()),
+        (template_name(&model.code, &model.locale, "body.html"), model.html_body.as_str()),
+    ];
+    if let Some(text) = &model.text_body {
+        parts.push((template_name(&model.code, &model.locale, "body.txt"), text.as_str()));
+    }
+
+    tera.add_raw_templates(parts)
+        .map_err(|e| EmailError::TemplateError(format!("模板编译失败: {}", tera_error(&e))))
+}
+
+/// Tera 的错误信息在 source 链中，逐层拼接便于定位语法错误
+fn tera_error(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(e) = source {
+        message.push_str(": ");
+        message.push_str(&e.to_string());
+        source = e.source();
+    }
+    message
+}
+
+fn context_of(data: &Value) -> Result<Context, EmailError> {
+    match data {
+        Value::Null => Ok(Context::new()),
+        Value::Object(_) => Context::from_value(data.clone())
+            .map_err(|e| EmailError::TemplateError(format!("模板数据无效: {}", e))),
+        _ => Err(EmailError::ValidationError("模板数据必须是JSON对象".to_string())),
+    }
+}
+
+fn render_with(
+    tera: &Tera,
+    code: &str,
+    locale: &str,
+    has_text: bool,
+    data: &Value,
+) -> Result<RenderedEmail, EmailError> {
+    let context = context_of(data)?;
+    let render = |part: &str| {
+        tera.render(&template_name(code, locale, part), &context)
+            .map_err(|e| EmailError::TemplateError(format!("模板渲染失败: {}", tera_error(&e))))
+    };
+
+    Ok(RenderedEmail {
+        subject: render("subject.txt")?.trim().to_string(),
+        html: render("body.html")?,
+        text: if has_text { Some(render("body.txt")?) } else { None },
+    })
+}
+
+impl CompiledTemplates {
+    async fn load(db: &DatabaseConnection) -> Result<Self, EmailError> {
+        let models = email_template::Entity::find()
+            .filter(email_template::Column::IsCurrent.eq(1))
+            .all(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        let mut compiled = Self {
+            tera: Tera::default(),
+            locales: HashMap::new(),
+            with_text: HashSet::new(),
+            loaded_at: Instant::now(),
+        };
+        for model in models {
+            // 保存时已校验，这里只跳过直接改库导致无法编译的模板
+            if let Err(e) = add_to(&mut compiled.tera, &model) {
+                tracing::warn!("跳过邮件模板 {}/{}: {}", model.code, model.locale, e);
+                continue;
+            }
+            if model.text_body.is_some() {
+                compiled.with_text.insert((model.code.clone(), model.locale.clone()));
+            }
+            compiled.locales.entry(model.code).or_default().insert(model.locale);
+        }
+
+        Ok(compiled)
+    }
+
+    /// 语言回退：完整匹配 -> 同语种 -> 默认语言 -> 任意可用语言
+    fn resolve_locale(&self, code: &str, locale: Option<&str>) -> Option<String> {
+        let available = self.locales.get(code)?;
+        let find = |wanted: &str| {
+            available.iter().find(|l| l.eq_ignore_ascii_case(wanted)).cloned()
+        };
+
+        if let Some(locale) = locale {
+            if let Some(found) = find(locale) {
+                return Some(found);
+            }
+            let language = locale.split(['-', '_']).next().unwrap_or(locale);
+            let mut same_language: Vec<_> = available
+                .iter()
+                .filter(|l| l.split(['-', '_']).next().is_some_and(|p| p.eq_ignore_ascii_case(language)))
+                .collect();
+            same_language.sort();
+            if let Some(found) = same_language.first() {
+                return Some((*found).clone());
+            }
+        }
+
+        find(&default_locale()).or_else(|| {
+            let mut all: Vec<_> = available.iter().collect();
+            all.sort();
+            all.first().map(|l| (*l).clone())
+        })
+    }
+}
+
+/// 邮件模板服务
+pub struct TemplateService;
+
+impl TemplateService {
+    /// 使缓存失效，下次渲染时重新编译
+    pub fn invalidate_cache() {
+        *CACHE.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    async fn compiled(db: &DatabaseConnection) -> Result<Arc<CompiledTemplates>, EmailError> {
+        if let Some(cached) = CACHE.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            if cached.loaded_at.elapsed() < CACHE_TTL {
+                return Ok(cached.clone());
+            }
+        }
+
+        let compiled = Arc::new(CompiledTemplates::load(db).await?);
+        *CACHE.write().unwrap_or_else(|e| e.into_inner()) = Some(compiled.clone());
+        Ok(compiled)
+    }
+
+    /// 按模板编码和语言渲染当前版本
+    pub async fn render(
+        db: &DatabaseConnection,
+        code: &str,
+        locale: Option<&str>,
+        data: &Value,
+    ) -> Result<RenderedEmail, EmailError> {
+        let compiled = Self::compiled(db).await?;
+        let locale = compiled
+            .resolve_locale(code, locale)
+            .ok_or_else(|| EmailError::NotFound(format!("邮件模板不存在: {}", code)))?;
+        let has_text = compiled.with_text.contains(&(code.to_string(), locale.clone()));
+
+        render_with(&compiled.tera, code, &locale, has_text, data)
+    }
+
+    /// 查询模板列表（仅当前版本）
+    pub async fn get_list(
+        db: &DatabaseConnection,
+        query: EmailTemplateQuery,
+        pagination: PaginationQuery,
+    ) -> Result<PageData<EmailTemplateDetail>, EmailError> {
+        let mut select = email_template::Entity::find()
+            .filter(email_template::Column::IsCurrent.eq(1));
+        if let Some(code) = &query.code {
+            select = select.filter(email_template::Column::Code.contains(code));
+        }
+        if let Some(locale) = &query.locale {
+            select = select.filter(email_template::Column::Locale.eq(locale));
+        }
+
+        let total = select
+            .clone()
+            .count(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        let items = select
+            .order_by_asc(email_template::Column::Code)
+            .order_by_asc(email_template::Column::Locale)
+            .offset(pagination.offset())
+            .limit(pagination.limit())
+            .all(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(EmailTemplateDetail::from)
+            .collect();
+
+        Ok(PageData::new(items, total, pagination.page, pagination.size))
+    }
+
+    async fn find(db: &DatabaseConnection, id: i64) -> Result<email_template::Model, EmailError> {
+        email_template::Entity::find_by_id(id)
+            .one(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?
+            .ok_or(EmailError::NotFound("模板不存在".to_string()))
+    }
+
+    /// 获取模板详情
+    pub async fn get_by_id(db: &DatabaseConnection, id: i64) -> Result<EmailTemplateDetail, EmailError> {
+        Ok(EmailTemplateDetail::from(Self::find(db, id).await?))
+    }
+
+    /// 创建模板（同一编码的新语言也通过创建添加）
+    pub async fn create(
+        db: &DatabaseConnection,
+        param: CreateEmailTemplateParam,
+    ) -> Result<EmailTemplateDetail, EmailError> {
+        let exists = email_template::Entity::find()
+            .filter(email_template::Column::Code.eq(&param.code))
+            .filter(email_template::Column::Locale.eq(&param.locale))
+            .count(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        if exists > 0 {
+            return Err(EmailError::OperationFailed(format!(
+                "模板 {}/{} 已存在",
+                param.code, param.locale
+            )));
+        }
+
+        let model = email_template::Model {
+            id: 0,
+            code: param.code,
+            locale: param.locale,
+            version: 1,
+            subject: param.content.subject,
+            html_body: param.content.html_body,
+            text_body: param.content.text_body,
+            sample_data: param.content.sample_data.map(|v| v.to_string()),
+            is_current: 1,
+            remark: param.content.remark,
+            created_time: chrono::Utc::now().naive_utc(),
+        };
+        compile(&model)?;
+
+        let mut active: email_template::ActiveModel = model.into();
+        active.id = NotSet;
+        let model = active
+            .insert(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Self::invalidate_cache();
+
+        Ok(EmailTemplateDetail::from(model))
+    }
+
+    /// 修改模板：保存为新版本并设为当前版本
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        param: EmailTemplateContent,
+    ) -> Result<EmailTemplateDetail, EmailError> {
+        let base = Self::find(db, id).await?;
+        let txn = db.begin().await.map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+
+        let latest = email_template::Entity::find()
+            .filter(email_template::Column::Code.eq(&base.code))
+            .filter(email_template::Column::Locale.eq(&base.locale))
+            .order_by_desc(email_template::Column::Version)
+            .one(&txn)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?
+            .map(|m| m.version)
+            .unwrap_or(0);
+
+        let model = email_template::Model {
+            id: 0,
+            code: base.code,
+            locale: base.locale,
+            version: latest + 1,
+            subject: param.subject,
+            html_body: param.html_body,
+            text_body: param.text_body,
+            sample_data: param.sample_data.map(|v| v.to_string()),
+            is_current: 1,
+            remark: param.remark,
+            created_time: chrono::Utc::now().naive_utc(),
+        };
+        compile(&model)?;
+
+        Self::clear_current(&txn, &model.code, &model.locale).await?;
+        let mut active: email_template::ActiveModel = model.into();
+        active.id = NotSet;
+        let model = active
+            .insert(&txn)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        txn.commit().await.map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Self::invalidate_cache();
+
+        Ok(EmailTemplateDetail::from(model))
+    }
+
+    async fn clear_current<C: ConnectionTrait>(db: &C, code: &str, locale: &str) -> Result<(), EmailError> {
+        email_template::Entity::update_many()
+            .col_expr(email_template::Column::IsCurrent, sea_query::Expr::value(0))
+            .filter(email_template::Column::Code.eq(code))
+            .filter(email_template::Column::Locale.eq(locale))
+            .exec(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 查询模板的全部版本
+    pub async fn get_versions(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Vec<EmailTemplateDetail>, EmailError> {
+        let base = Self::find(db, id).await?;
+        let versions = email_template::Entity::find()
+            .filter(email_template::Column::Code.eq(&base.code))
+            .filter(email_template::Column::Locale.eq(&base.locale))
+            .order_by_desc(email_template::Column::Version)
+            .all(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(EmailTemplateDetail::from)
+            .collect();
+
+        Ok(versions)
+    }
+
+    /// 将指定版本设为当前版本（回滚）
+    pub async fn activate(db: &DatabaseConnection, id: i64) -> Result<EmailTemplateDetail, EmailError> {
+        let model = Self::find(db, id).await?;
+        compile(&model)?;
+
+        let txn = db.begin().await.map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Self::clear_current(&txn, &model.code, &model.locale).await?;
+        let mut active: email_template::ActiveModel = model.into();
+        active.is_current = Set(1);
+        let model = active
+            .update(&txn)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        txn.commit().await.map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Self::invalidate_cache();
+
+        Ok(EmailTemplateDetail::from(model))
+    }
+
+    /// 删除模板（该编码和语言的全部版本）
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), EmailError> {
+        let model = Self::find(db, id).await?;
+        email_template::Entity::delete_many()
+            .filter(email_template::Column::Code.eq(&model.code))
+            .filter(email_template::Column::Locale.eq(&model.locale))
+            .exec(db)
+            .await
+            .map_err(|e| EmailError::DatabaseError(e.to_string()))?;
+        Self::invalidate_cache();
+
+        Ok(())
+    }
+
+    /// 预览指定版本，未传数据时使用模板的示例数据
+    pub async fn preview(
+        db: &DatabaseConnection,
+        id: i64,
+        data: Option<Value>,
+    ) -> Result<EmailTemplatePreview, EmailError> {
+        let model = Self::find(db, id).await?;
+        let data = match data {
+            Some(data) => data,
+            None => model
+                .sample_data
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()
+                .map_err(|e| EmailError::TemplateError(format!("示例数据无效: {}", e)))?
+                .unwrap_or(Value::Null),
+        };
+
+        let tera = compile(&model)?;
+        let rendered = render_with(&tera, &model.code, &model.locale, model.text_body.is_some(), &data)?;
+        Ok(EmailTemplatePreview {
+            subject: rendered.subject,
+            html: rendered.html,
+            text: rendered.text,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::entity::tests::sqlite;
+
+    fn content(subject: &str, html: &str, text: Option<&str>) -> EmailTemplateContent {
+        EmailTemplateContent {
+            subject: subject.to_string(),
+            html_body: html.to_string(),
+            text_body: text.map(str::to_string),
+            sample_data: Some(json!({ "user": { "name": "示例" } })),
+            remark: None,
+        }
+    }
+
+    async fn create(db: &DatabaseConnection, locale: &str, content: EmailTemplateContent) -> EmailTemplateDetail {
+        let param = CreateEmailTemplateParam {
+            code: "welcome".to_string(),
+            locale: locale.to_string(),
+            content,
+        };
+        TemplateService::create(db, param).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_render_versions_and_locales() {
+        let db = sqlite().await;
+        let zh = create(&db, "zh-CN", content("欢迎 {{ user.name }}", "<p>{{ user.name }}</p>", None)).await;
+        create(&db, "en-US", content("Welcome {{ user.name }}", "<p>{{ user.name }}</p>", Some("Hi {{ user.name }}"))).await;
+
+        // 嵌套数据、HTML 自动转义、主题和纯文本不转义
+        let data = json!({ "user": { "name": "<Tom>" } });
+        let en = TemplateService::render(&db, "welcome", Some("en-GB"), &data).await.unwrap();
+        assert_eq!(en.subject, "Welcome <Tom>");
+        assert_eq!(en.html, "<p>&lt;Tom&gt;</p>");
+        assert_eq!(en.text.as_deref(), Some("Hi <Tom>"));
+        let fallback = TemplateService::render(&db, "welcome", Some("fr"), &data).await.unwrap();
+        assert!(fallback.subject.starts_with("欢迎") && fallback.text.is_none());
+
+        // 修改生成新版本并立即生效，可回滚
+        let v2 = TemplateService::update(&db, zh.id, content("你好 {{ user.name }}", "<b/>", None)).await.unwrap();
+        assert_eq!(v2.version, 2);
+        let rendered = TemplateService::render(&db, "welcome", None, &data).await.unwrap();
+        assert_eq!(rendered.subject, "你好 <Tom>");
+        TemplateService::activate(&db, zh.id).await.unwrap();
+        let rendered = TemplateService::render(&db, "welcome", Some("zh-CN"), &data).await.unwrap();
+        assert_eq!(rendered.subject, "欢迎 <Tom>");
+        assert_eq!(TemplateService::get_versions(&db, v2.id).await.unwrap().len(), 2);
+
+        let preview = TemplateService::preview(&db, v2.id, None).await.unwrap();
+        assert_eq!(preview.subject, "你好 示例");
+
+        let invalid = TemplateService::update(&db, zh.id, content("{{ user.name", "", None)).await;
+        assert!(matches!(invalid, Err(EmailError::TemplateError(_))));
+        assert!(TemplateService::render(&db, "missing", None, &data).await.is_err());
+    }
+}
@@ -0,0 +1,29 @@
+-- 通知中心：定向投放、定时发布/过期、已读状态
+
+ALTER TABLE `sys_notice`
+  ADD COLUMN `target_type` smallint NOT NULL DEFAULT '0' COMMENT '投放范围（0全部用户/1角色/2部门/3指定用户）' AFTER `status`,
+  ADD COLUMN `expire_time` datetime DEFAULT NULL COMMENT '过期时间（为空表示不过期）' AFTER `publish_time`,
+  ADD COLUMN `email_fallback` smallint NOT NULL DEFAULT '0' COMMENT '重要通知是否对不在线的用户发送邮件（1是/0否）' AFTER `expire_time`,
+  ADD COLUMN `dispatched` smallint NOT NULL DEFAULT '0' COMMENT '是否已推送（1是/0否）' AFTER `email_fallback`,
+  ADD KEY `idx_dispatch` (`status`, `dispatched`, `publish_time`);
+
+-- 通知投放对象表（target_type 为角色/部门/用户时记录对应ID）
+CREATE TABLE IF NOT EXISTS `sys_notice_target` (
+  `id` bigint NOT NULL AUTO_INCREMENT COMMENT '主键ID',
+  `notice_id` bigint NOT NULL COMMENT '通知ID',
+  `target_id` bigint NOT NULL COMMENT '角色/部门/用户ID',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_notice_target` (`notice_id`, `target_id`),
+  KEY `idx_target_id` (`target_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='通知投放对象表';
+
+-- 通知已读记录表
+CREATE TABLE IF NOT EXISTS `sys_notice_read` (
+  `id` bigint NOT NULL AUTO_INCREMENT COMMENT '主键ID',
+  `notice_id` bigint NOT NULL COMMENT '通知ID',
+  `user_id` bigint NOT NULL COMMENT '用户ID',
+  `read_time` datetime NOT NULL COMMENT '阅读时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_notice_user` (`notice_id`, `user_id`),
+  KEY `idx_user_id` (`user_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='通知已读记录表';
@@ -2,78 +2,137 @@
 
 use axum::{
     extract::{Path, Query, State},
+    http::{header, HeaderMap},
     response::Json,
 };
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
 use validator::Validate;
 
 use crate::dto::*;
 use crate::error::NoticeError;
-use crate::service::NoticeService;
+use crate::service::{NoticeBackend, NoticeService, Recipient};
+
+/// 应用状态
+#[derive(Clone)]
+pub struct AppState {
+    pub db: DatabaseConnection,
+    pub backend: Arc<dyn NoticeBackend>,
+}
+
+/// 解析请求携带的系统 token，获取当前用户
+async fn current_recipient(state: &AppState, headers: &HeaderMap) -> Result<Recipient, NoticeError> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| NoticeError::Unauthorized("缺少访问令牌".to_string()))?;
+
+    let user_id = state.backend.authenticate(token).await?;
+    state.backend.recipient(user_id).await
+}
 
 /// 获取所有通知公告
 /// GET /api/v1/sys/notices/all
 pub async fn get_all_notices(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
 ) -> Result<Json<ApiResponse<Vec<NoticeDetail>>>, NoticeError> {
-    let data = NoticeService::get_all(&db).await?;
+    let data = NoticeService::get_all(&state.db).await?;
     Ok(Json(ApiResponse::success(data)))
 }
 
 /// 获取通知公告详情
 /// GET /api/v1/sys/notices/{pk}
 pub async fn get_notice(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
     Path(pk): Path<i64>,
 ) -> Result<Json<ApiResponse<NoticeDetail>>, NoticeError> {
-    let data = NoticeService::get_by_id(&db, pk).await?;
+    let data = NoticeService::get_by_id(&state.db, pk).await?;
     Ok(Json(ApiResponse::success(data)))
 }
 
-/// 获取显示的通知公告列表（公开接口）
+/// 获取当前用户可见的通知公告（含已读状态）
 /// GET /api/v1/sys/notices/visible
 pub async fn get_visible_notices(
-    State(db): State<DatabaseConnection>,
-) -> Result<Json<ApiResponse<Vec<NoticeDetail>>>, NoticeError> {
-    let data = NoticeService::get_visible(&db).await?;
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<Vec<UserNoticeDetail>>>, NoticeError> {
+    let recipient = current_recipient(&state, &headers).await?;
+    let data = NoticeService::get_visible(&state.db, &recipient).await?;
     Ok(Json(ApiResponse::success(data)))
 }
 
+/// 获取当前用户的未读通知数量
+/// GET /api/v1/sys/notices/unread-count
+pub async fn get_unread_count(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<UnreadCount>>, NoticeError> {
+    let recipient = current_recipient(&state, &headers).await?;
+    let count = NoticeService::unread_count(&state.db, &recipient).await?;
+    Ok(Json(ApiResponse::success(UnreadCount { count })))
+}
+
+/// 标记通知为已读
+/// PUT /api/v1/sys/notices/{pk}/read
+pub async fn mark_notice_read(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(pk): Path<i64>,
+) -> Result<Json<ApiResponse<()>>, NoticeError> {
+    let recipient = current_recipient(&state, &headers).await?;
+    NoticeService::mark_read(&state.db, &recipient, pk).await?;
+    Ok(Json(ApiResponse::success_msg("已读")))
+}
+
+/// 全部标记为已读
+/// PUT /api/v1/sys/notices/read-all
+pub async fn mark_all_notices_read(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<()>>, NoticeError> {
+    let recipient = current_recipient(&state, &headers).await?;
+    NoticeService::mark_all_read(&state.db, &recipient).await?;
+    Ok(Json(ApiResponse::success_msg("已全部标记为已读")))
+}
+
 /// 分页获取所有通知公告
 /// GET /api/v1/sys/notices
 pub async fn get_notices_paginated(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
     Query(query): Query<NoticeQuery>,
     Query(pagination): Query<PaginationQuery>,
 ) -> Result<Json<ApiResponse<PageData<NoticeDetail>>>, NoticeError> {
-    let page_data = NoticeService::get_list(&db, query, pagination).await?;
+    let page_data = NoticeService::get_list(&state.db, query, pagination).await?;
     Ok(Json(ApiResponse::success(page_data)))
 }
 
 /// 创建通知公告
 /// POST /api/v1/sys/notices
 pub async fn create_notice(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
     Json(param): Json<CreateNoticeParam>,
 ) -> Result<Json<ApiResponse<NoticeDetail>>, NoticeError> {
     // 验证参数
     param.validate()?;
     
-    let data = NoticeService::create(&db, param).await?;
+    let data = NoticeService::create(&state.db, param).await?;
     Ok(Json(ApiResponse::success_with_msg("创建成功", data)))
 }
 
 /// 更新通知公告
 /// PUT /api/v1/sys/notices/{pk}
 pub async fn update_notice(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
     Path(pk): Path<i64>,
     Json(param): Json<UpdateNoticeParam>,
 ) -> Result<Json<ApiResponse<()>>, NoticeError> {
     // 验证参数
     param.validate()?;
     
-    let count = NoticeService::update(&db, pk, param).await?;
+    let count = NoticeService::update(&state.db, pk, param).await?;
     if count > 0 {
         Ok(Json(ApiResponse::success_msg("更新成功")))
     } else {
@@ -84,10 +143,10 @@ pub async fn update_notice(
 /// 批量删除通知公告
 /// DELETE /api/v1/sys/notices
 pub async fn delete_notices(
-    State(db): State<DatabaseConnection>,
+    State(state): State<AppState>,
     Json(param): Json<DeleteBatchParam>,
 ) -> Result<Json<ApiResponse<()>>, NoticeError> {
-    let count = NoticeService::delete_batch(&db, param.ids).await?;
+    let count = NoticeService::delete_batch(&state.db, param.ids).await?;
     if count > 0 {
         Ok(Json(ApiResponse::success_msg("删除成功")))
     } else {
@@ -96,13 +155,16 @@ pub async fn delete_notices(
 }
 
 /// 创建通知公告路由
-pub fn notice_routes() -> axum::Router<DatabaseConnection> {
+pub fn notice_routes() -> axum::Router<AppState> {
     use axum::routing::{delete, get, post, put};
     
     axum::Router::new()
         .route("/all", get(get_all_notices))
         .route("/{pk}", get(get_notice))
         .route("/visible", get(get_visible_notices))
+        .route("/unread-count", get(get_unread_count))
+        .route("/read-all", put(mark_all_notices_read))
+        .route("/{pk}/read", put(mark_notice_read))
         .route("/", get(get_notices_paginated))
         .route("/", post(create_notice))
         .route("/{pk}", put(update_notice))
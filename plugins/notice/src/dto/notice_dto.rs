@@ -1,10 +1,14 @@
 //! 通知公告DTO定义
 
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::entity::notice::LEVEL_NORMAL;
+
 /// 通知公告详情响应 - 匹配Python版本
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct NoticeDetail {
     pub id: i64,
     pub title: String,
@@ -12,6 +16,12 @@ pub struct NoticeDetail {
     pub type_: i32,
     pub status: i32,
     pub content: String,
+    pub level: i16,
+    pub target_type: i16,
+    pub target_ids: Vec<i64>,
+    pub publish_time: Option<NaiveDateTime>,
+    pub expire_time: Option<NaiveDateTime>,
+    pub email_fallback: bool,
 }
 
 impl From<crate::entity::notice::Model> for NoticeDetail {
@@ -22,12 +32,53 @@ impl From<crate::entity::notice::Model> for NoticeDetail {
             type_: model.type_,
             status: model.status,
             content: model.content,
+            level: model.level,
+            target_type: model.target_type,
+            target_ids: Vec::new(),
+            publish_time: model.publish_time,
+            expire_time: model.expire_time,
+            email_fallback: model.email_fallback == 1,
+        }
+    }
+}
+
+/// 当前用户可见的通知（含已读状态）
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct UserNoticeDetail {
+    pub id: i64,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub type_: i32,
+    pub content: String,
+    pub level: i16,
+    pub publish_time: Option<NaiveDateTime>,
+    pub expire_time: Option<NaiveDateTime>,
+    pub is_read: bool,
+}
+
+impl UserNoticeDetail {
+    pub fn new(model: crate::entity::notice::Model, is_read: bool) -> Self {
+        Self {
+            id: model.id,
+            title: model.title,
+            type_: model.type_,
+            content: model.content,
+            level: model.level,
+            publish_time: model.publish_time,
+            expire_time: model.expire_time,
+            is_read,
         }
     }
 }
 
+/// 未读通知数量
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct UnreadCount {
+    pub count: u64,
+}
+
 /// 创建通知公告请求参数 - 匹配Python版本
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateNoticeParam {
     /// 公告标题
     #[validate(length(min = 1, max = 64, message = "标题长度必须在1-64之间"))]
@@ -46,10 +97,34 @@ pub struct CreateNoticeParam {
     /// 公告内容
     #[validate(length(min = 1, max = 50000, message = "内容长度必须在1-50000之间"))]
     pub content: String,
+    
+    /// 重要程度（1普通/2重要/3紧急）
+    #[serde(default = "default_level")]
+    #[validate(range(min = 1, max = 3, message = "重要程度必须是1、2或3"))]
+    pub level: i16,
+    
+    /// 投放范围（0全部用户/1角色/2部门/3指定用户）
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "投放范围必须是0-3"))]
+    pub target_type: i16,
+    
+    /// 投放对象ID（角色/部门/用户ID，投放范围为全部用户时忽略）
+    #[serde(default)]
+    pub target_ids: Vec<i64>,
+    
+    /// 发布时间（为空表示立即发布）
+    pub publish_time: Option<NaiveDateTime>,
+    
+    /// 过期时间（为空表示不过期）
+    pub expire_time: Option<NaiveDateTime>,
+    
+    /// 重要/紧急通知是否对不在线的用户发送邮件
+    #[serde(default)]
+    pub email_fallback: bool,
 }
 
 /// 更新通知公告请求参数 - 匹配Python版本
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateNoticeParam {
     /// 公告标题
     #[validate(length(min = 1, max = 64, message = "标题长度必须在1-64之间"))]
@@ -67,10 +142,34 @@ pub struct UpdateNoticeParam {
     /// 公告内容
     #[validate(length(min = 1, max = 50000, message = "内容长度必须在1-50000之间"))]
     pub content: String,
+    
+    /// 重要程度（1普通/2重要/3紧急）
+    #[serde(default = "default_level")]
+    #[validate(range(min = 1, max = 3, message = "重要程度必须是1、2或3"))]
+    pub level: i16,
+    
+    /// 投放范围（0全部用户/1角色/2部门/3指定用户）
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "投放范围必须是0-3"))]
+    pub target_type: i16,
+    
+    /// 投放对象ID（角色/部门/用户ID，投放范围为全部用户时忽略）
+    #[serde(default)]
+    pub target_ids: Vec<i64>,
+    
+    /// 发布时间（为空表示立即发布）
+    pub publish_time: Option<NaiveDateTime>,
+    
+    /// 过期时间（为空表示不过期）
+    pub expire_time: Option<NaiveDateTime>,
+    
+    /// 重要/紧急通知是否对不在线的用户发送邮件
+    #[serde(default)]
+    pub email_fallback: bool,
 }
 
 /// 通知公告查询参数 - 匹配Python版本
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct NoticeQuery {
     /// 标题（模糊查询）
     pub title: Option<String>,
@@ -86,3 +185,7 @@ pub struct NoticeQuery {
 fn default_type() -> i32 {
     0 // 默认为通知
 }
+
+fn default_level() -> i16 {
+    LEVEL_NORMAL
+}
@@ -1,3 +1,7 @@
 pub mod notice;
+pub mod notice_read;
+pub mod notice_target;
 
 pub use notice::Entity as NoticeEntity;
+pub use notice_read::Entity as NoticeReadEntity;
+pub use notice_target::Entity as NoticeTargetEntity;
@@ -27,8 +27,42 @@ pub struct Model {
     /// 公告内容（富文本）
     #[sea_orm(column_type = "Text")]
     pub content: String,
+    
+    /// 重要程度（1普通/2重要/3紧急）
+    pub level: i16,
+    
+    /// 投放范围（0全部用户/1角色/2部门/3指定用户）
+    pub target_type: i16,
+    
+    /// 发布时间（晚于当前时间表示定时发布）
+    pub publish_time: Option<DateTime>,
+    
+    /// 过期时间（为空表示不过期）
+    pub expire_time: Option<DateTime>,
+    
+    /// 重要通知是否对不在线的用户发送邮件
+    pub email_fallback: i16,
+    
+    /// 是否已推送
+    pub dispatched: i16,
 }
 
+/// 重要程度：普通
+pub const LEVEL_NORMAL: i16 = 1;
+/// 重要程度：重要
+pub const LEVEL_IMPORTANT: i16 = 2;
+/// 重要程度：紧急
+pub const LEVEL_URGENT: i16 = 3;
+
+/// 投放范围：全部用户
+pub const TARGET_ALL: i16 = 0;
+/// 投放范围：角色
+pub const TARGET_ROLE: i16 = 1;
+/// 投放范围：部门
+pub const TARGET_DEPT: i16 = 2;
+/// 投放范围：指定用户
+pub const TARGET_USER: i16 = 3;
+
 /// 关系定义
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
@@ -45,6 +79,16 @@ impl Entity {
             .order_by_desc(Column::Id)
     }
     
+    /// 查找到期待推送的通知（已发布、未推送、发布时间已到且未过期）
+    pub fn find_due(now: DateTime) -> Select<Self> {
+        Self::find()
+            .filter(Column::Status.eq(1))
+            .filter(Column::Dispatched.eq(0))
+            .filter(Column::PublishTime.is_null().or(Column::PublishTime.lte(now)))
+            .filter(Column::ExpireTime.is_null().or(Column::ExpireTime.gt(now)))
+            .order_by_asc(Column::Id)
+    }
+    
     /// 查找隐藏的通知
     pub fn find_hidden() -> Select<Self> {
         Self::find().filter(Column::Status.eq(0))
@@ -71,6 +115,11 @@ impl Model {
         }
     }
     
+    /// 是否需要对不在线的用户发送邮件
+    pub fn needs_email_fallback(&self) -> bool {
+        self.email_fallback == 1 && self.level >= LEVEL_IMPORTANT
+    }
+    
     /// 获取类型文本
     pub fn type_text(&self) -> &str {
         match self.type_ {
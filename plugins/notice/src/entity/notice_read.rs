@@ -0,0 +1,30 @@
+//! 通知已读记录实体
+//! 对应数据库表：sys_notice_read
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 通知已读记录实体模型
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_notice_read")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    
+    /// 通知ID
+    pub notice_id: i64,
+    
+    /// 用户ID
+    pub user_id: i64,
+    
+    /// 阅读时间
+    pub read_time: DateTime,
+}
+
+/// 关系定义
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// ActiveModel行为实现
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,27 @@
+//! 通知投放对象实体
+//! 对应数据库表：sys_notice_target
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 通知投放对象实体模型
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_notice_target")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    
+    /// 通知ID
+    pub notice_id: i64,
+    
+    /// 角色/部门/用户ID（由通知的投放范围决定）
+    pub target_id: i64,
+}
+
+/// 关系定义
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// ActiveModel行为实现
+impl ActiveModelBehavior for ActiveModel {}
@@ -31,6 +31,10 @@ pub enum NoticeError {
     #[error("验证错误: {0}")]
     ValidationError(String),
     
+    /// 未登录或登录已失效
+    #[error("未授权: {0}")]
+    Unauthorized(String),
+    
     /// 权限不足
     #[error("权限不足")]
     PermissionDenied,
@@ -44,6 +48,7 @@ impl IntoResponse for NoticeError {
             NoticeError::AlreadyExists(msg) => (StatusCode::CONFLICT, 409, msg),
             NoticeError::OperationFailed(msg) => (StatusCode::BAD_REQUEST, 400, msg),
             NoticeError::ValidationError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, 422, msg),
+            NoticeError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, 401, msg),
             NoticeError::PermissionDenied => (
                 StatusCode::FORBIDDEN,
                 403,
@@ -65,8 +70,8 @@ impl From<validator::ValidationErrors> for NoticeError {
     fn from(errors: validator::ValidationErrors) -> Self {
         let messages: Vec<String> = errors
             .field_errors()
-            .iter()
-            .flat_map(|(_, errs)| {
+            .values()
+            .flat_map(|errs| {
                 errs.iter()
                     .filter_map(|e| e.message.as_ref().map(|m| m.to_string()))
             })
@@ -8,11 +8,17 @@
 //! - 置顶功能
 //! - 数据验证
 //! - 分页查询
+//! - 定向投放（全部用户/角色/部门/指定用户）、定时发布和过期
+//! - 按用户记录已读状态
+//! - WebSocket 实时推送，重要通知可对不在线的用户发送邮件
 //! 
 //! # API端点
 //! - GET /all - 获取所有通知
 //! - GET /{pk} - 获取单个通知
-//! - GET /published - 获取已发布通知（公开）
+//! - GET /visible - 获取当前用户可见的通知（含已读状态）
+//! - GET /unread-count - 获取当前用户的未读数量
+//! - PUT /{pk}/read - 标记已读
+//! - PUT /read-all - 全部标记已读
 //! - GET / - 分页查询
 //! - POST / - 创建通知
 //! - PUT /{pk} - 更新通知
@@ -28,6 +34,7 @@ pub mod error;
 
 use axum::Router;
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
 
 /// 插件信息
 pub const PLUGIN_INFO: PluginInfo = PluginInfo {
@@ -59,17 +66,25 @@ impl NoticePlugin {
     /// 注意：这个路由会被注入到 /api/v1/sys/notices 路径下
     /// 因为这是一个 extend='admin' 类型的插件
     /// 所以这里直接返回notice_routes，不需要再包一层/notices前缀
-    pub fn create_router(db: DatabaseConnection) -> Router {
-        api::notice_routes().with_state(db)
+    pub fn create_router(db: DatabaseConnection, backend: Arc<dyn NoticeBackend>) -> Router {
+        api::notice_routes().with_state(api::AppState { db, backend })
+    }
+
+    /// 启动通知推送（进程内只启动一次）
+    pub fn start_dispatcher(db: DatabaseConnection, backend: Arc<dyn NoticeBackend>) {
+        NoticeDispatcher::start(db, backend, DispatcherConfig::default());
     }
 }
 
 // 导出公共类型
 pub use dto::{
-    NoticeDetail,
+    NoticeDetail, UserNoticeDetail, UnreadCount,
     CreateNoticeParam, UpdateNoticeParam,
     NoticeQuery,
     PaginationQuery, PageData, ApiResponse,
 };
 pub use error::NoticeError;
-pub use service::NoticeService;
+pub use service::{
+    DispatcherConfig, NoticeBackend, NoticeDispatcher, NoticeService, Recipient, NOTICE_EVENT,
+};
+pub use api::AppState;
@@ -0,0 +1,41 @@
+//! 系统对接
+//!
+//! 插件不依赖主应用，用户认证、收件人解析、WebSocket 推送和邮件发送由主应用实现
+//! [`NoticeBackend`] 后注入。
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::NoticeError;
+
+/// 通知接收人
+#[derive(Debug, Clone, Default)]
+pub struct Recipient {
+    pub user_id: i64,
+    pub dept_id: Option<i64>,
+    pub role_ids: Vec<i64>,
+    pub email: Option<String>,
+}
+
+/// 通知中心后端
+#[async_trait]
+pub trait NoticeBackend: Send + Sync {
+    /// 解析系统 access_token，返回用户ID
+    async fn authenticate(&self, token: &str) -> Result<i64, NoticeError>;
+
+    /// 获取用户的部门、角色和邮箱
+    async fn recipient(&self, user_id: i64) -> Result<Recipient, NoticeError>;
+
+    /// 按投放范围解析全部接收人（只需填充用户ID和邮箱）
+    async fn resolve_recipients(
+        &self,
+        target_type: i16,
+        target_ids: &[i64],
+    ) -> Result<Vec<Recipient>, NoticeError>;
+
+    /// 推送到用户的在线会话，返回是否至少有一个会话在线
+    async fn push(&self, user_id: i64, event: &str, payload: &Value) -> bool;
+
+    /// 发送 HTML 邮件
+    async fn send_email(&self, to: &str, subject: &str, content: &str) -> Result<(), NoticeError>;
+}
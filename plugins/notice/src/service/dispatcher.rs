@@ -0,0 +1,321 @@
+//! 通知推送
+//!
+//! 后台轮询到期的已发布通知（含定时发布），通过 WebSocket 推送给在线用户；
+//! 重要/紧急通知开启邮件兜底时，对不在线的用户发送邮件。
+//! 推送前以条件更新领取通知，多实例部署时每条通知只推送一次；推送失败时释放领取，下一轮重试。
+
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use serde_json::json;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use crate::entity::notice;
+use crate::entity::notice_target;
+use crate::error::NoticeError;
+use crate::service::backend::NoticeBackend;
+
+/// 推送到客户端的事件名
+pub const NOTICE_EVENT: &str = "notice";
+
+static DISPATCHER: OnceLock<Arc<NoticeDispatcher>> = OnceLock::new();
+
+/// 推送配置
+#[derive(Debug, Clone)]
+pub struct DispatcherConfig {
+    /// 轮询间隔（秒），决定定时发布的精度
+    pub poll_interval_seconds: u64,
+}
+
+impl Default for DispatcherConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_seconds: std::env::var("NOTICE_DISPATCH_INTERVAL_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+        }
+    }
+}
+
+/// 通知推送器
+pub struct NoticeDispatcher {
+    db: DatabaseConnection,
+    backend: Arc<dyn NoticeBackend>,
+    config: DispatcherConfig,
+    notify: Notify,
+}
+
+impl NoticeDispatcher {
+    pub fn new(db: DatabaseConnection, backend: Arc<dyn NoticeBackend>, config: DispatcherConfig) -> Self {
+        Self {
+            db,
+            backend,
+            config,
+            notify: Notify::new(),
+        }
+    }
+
+    /// 启动全局推送器，重复调用返回已启动的推送器
+    pub fn start(
+        db: DatabaseConnection,
+        backend: Arc<dyn NoticeBackend>,
+        config: DispatcherConfig,
+    ) -> Arc<Self> {
+        DISPATCHER
+            .get_or_init(|| {
+                let dispatcher = Arc::new(Self::new(db, backend, config));
+                tokio::spawn(dispatcher.clone().run());
+                tracing::info!("通知推送已启动");
+                dispatcher
+            })
+            .clone()
+    }
+
+    /// 唤醒推送器（发布通知后调用）
+    pub fn wake() {
+        if let Some(dispatcher) = DISPATCHER.get() {
+            dispatcher.notify.notify_one();
+        }
+    }
+
+    async fn run(self: Arc<Self>) {
+        let interval = Duration::from_secs(self.config.poll_interval_seconds.max(1));
+        loop {
+            if let Err(e) = self.process_due().await {
+                tracing::error!("通知推送失败: {}", e);
+            }
+            tokio::select! {
+                _ = self.notify.notified() => {}
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    /// 推送全部到期通知，返回推送的通知数量
+    pub async fn process_due(&self) -> Result<usize, NoticeError> {
+        let notices = notice::Entity::find_due(chrono::Utc::now().naive_utc())
+            .all(&self.db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        let mut dispatched = 0;
+        for notice in notices {
+            let id = notice.id;
+            if !self.claim(id).await? {
+                continue;
+            }
+            // 推送失败（如接收人解析时数据库暂时不可用）时释放领取，下一轮重试
+            if let Err(e) = self.dispatch(notice).await {
+                tracing::warn!("通知 {} 推送失败，等待重试: {}", id, e);
+                self.release(id).await?;
+                continue;
+            }
+            dispatched += 1;
+        }
+
+        Ok(dispatched)
+    }
+
+    /// 领取待推送通知，已被其他实例领取时返回 false
+    async fn claim(&self, id: i64) -> Result<bool, NoticeError> {
+        let result = notice::Entity::update_many()
+            .col_expr(notice::Column::Dispatched, Expr::value(1))
+            .filter(notice::Column::Id.eq(id))
+            .filter(notice::Column::Dispatched.eq(0))
+            .exec(&self.db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 释放领取，使通知重新进入待推送状态
+    async fn release(&self, id: i64) -> Result<(), NoticeError> {
+        notice::Entity::update_many()
+            .col_expr(notice::Column::Dispatched, Expr::value(0))
+            .filter(notice::Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn dispatch(&self, notice: notice::Model) -> Result<(), NoticeError> {
+        let target_ids: Vec<i64> = notice_target::Entity::find()
+            .filter(notice_target::Column::NoticeId.eq(notice.id))
+            .all(&self.db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|t| t.target_id)
+            .collect();
+        let recipients = self
+            .backend
+            .resolve_recipients(notice.target_type, &target_ids)
+            .await?;
+
+        let payload = json!({
+            "id": notice.id,
+            "title": notice.title,
+            "type": notice.type_,
+            "level": notice.level,
+            "publish_time": notice.publish_time,
+        });
+        let mut emailed = 0;
+        for recipient in &recipients {
+            if self.backend.push(recipient.user_id, NOTICE_EVENT, &payload).await {
+                continue;
+            }
+            let Some(email) = recipient.email.as_deref().filter(|_| notice.needs_email_fallback()) else {
+                continue;
+            };
+            match self.backend.send_email(email, &notice.title, &notice.content).await {
+                Ok(()) => emailed += 1,
+                Err(e) => tracing::warn!("通知 {} 邮件发送失败: {} ({})", notice.id, e, email),
+            }
+        }
+        tracing::info!(
+            "通知 {} 已推送: 接收人 {}，邮件 {}",
+            notice.id,
+            recipients.len(),
+            emailed
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::dto::CreateNoticeParam;
+    use crate::entity::notice::{LEVEL_NORMAL, LEVEL_URGENT, TARGET_ALL, TARGET_ROLE, TARGET_USER};
+    use crate::entity::notice_read;
+    use crate::service::{NoticeService, Recipient};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    pub(crate) async fn sqlite() -> DatabaseConnection {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1);
+        let db = Database::connect(options).await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(notice::Entity))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(notice_target::Entity))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(notice_read::Entity))).await.unwrap();
+        // 与迁移脚本一致的唯一约束
+        db.execute_unprepared("CREATE UNIQUE INDEX uk_notice_user ON sys_notice_read (notice_id, user_id)")
+            .await
+            .unwrap();
+        db
+    }
+
+    pub(crate) fn param(level: i16, target_type: i16, target_ids: Vec<i64>) -> CreateNoticeParam {
+        CreateNoticeParam {
+            title: "维护通知".to_string(),
+            type_: 0,
+            status: 1,
+            content: "<p>今晚维护</p>".to_string(),
+            level,
+            target_type,
+            target_ids,
+            publish_time: None,
+            expire_time: None,
+            email_fallback: true,
+        }
+    }
+
+    /// 用户1在线，用户2不在线；用户1、2属于角色10
+    #[derive(Default)]
+    struct FakeBackend {
+        pushed: Mutex<Vec<i64>>,
+        emailed: Mutex<Vec<String>>,
+        /// 为 true 时解析接收人失败
+        unavailable: AtomicBool,
+    }
+
+    #[async_trait]
+    impl NoticeBackend for FakeBackend {
+        async fn authenticate(&self, _token: &str) -> Result<i64, NoticeError> {
+            Ok(1)
+        }
+
+        async fn recipient(&self, user_id: i64) -> Result<Recipient, NoticeError> {
+            Ok(Recipient { user_id, ..Default::default() })
+        }
+
+        async fn resolve_recipients(&self, target_type: i16, target_ids: &[i64]) -> Result<Vec<Recipient>, NoticeError> {
+            if self.unavailable.load(Ordering::Relaxed) {
+                return Err(NoticeError::DatabaseError("connection refused".to_string()));
+            }
+            let users = match target_type {
+                TARGET_ALL => vec![1, 2, 3],
+                TARGET_ROLE if target_ids.contains(&10) => vec![1, 2],
+                TARGET_USER => target_ids.to_vec(),
+                _ => vec![],
+            };
+            Ok(users
+                .into_iter()
+                .map(|user_id| Recipient {
+                    user_id,
+                    email: Some(format!("u{}@example.com", user_id)),
+                    ..Default::default()
+                })
+                .collect())
+        }
+
+        async fn push(&self, user_id: i64, _event: &str, _payload: &Value) -> bool {
+            self.pushed.lock().unwrap().push(user_id);
+            user_id == 1
+        }
+
+        async fn send_email(&self, to: &str, _subject: &str, _content: &str) -> Result<(), NoticeError> {
+            self.emailed.lock().unwrap().push(to.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_with_email_fallback() {
+        let db = sqlite().await;
+        let backend = Arc::new(FakeBackend::default());
+        let dispatcher = NoticeDispatcher::new(db.clone(), backend.clone(), DispatcherConfig { poll_interval_seconds: 1 });
+
+        NoticeService::create(&db, param(LEVEL_URGENT, TARGET_ROLE, vec![10])).await.unwrap();
+        // 普通通知即使开启邮件兜底也不发送邮件
+        NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_USER, vec![3])).await.unwrap();
+        // 定时发布的通知未到时间不推送
+        let mut scheduled = param(LEVEL_URGENT, TARGET_ALL, vec![]);
+        scheduled.publish_time = Some(chrono::Utc::now().naive_utc() + chrono::Duration::hours(1));
+        NoticeService::create(&db, scheduled).await.unwrap();
+
+        assert_eq!(dispatcher.process_due().await.unwrap(), 2);
+        assert_eq!(*backend.pushed.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(*backend.emailed.lock().unwrap(), vec!["u2@example.com".to_string()]);
+
+        // 已推送的通知不会重复推送
+        assert_eq!(dispatcher.process_due().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_dispatch_is_retried() {
+        let db = sqlite().await;
+        let backend = Arc::new(FakeBackend::default());
+        let dispatcher = NoticeDispatcher::new(db.clone(), backend.clone(), DispatcherConfig { poll_interval_seconds: 1 });
+        NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_USER, vec![1])).await.unwrap();
+
+        backend.unavailable.store(true, Ordering::Relaxed);
+        assert_eq!(dispatcher.process_due().await.unwrap(), 0);
+        assert!(backend.pushed.lock().unwrap().is_empty());
+
+        backend.unavailable.store(false, Ordering::Relaxed);
+        assert_eq!(dispatcher.process_due().await.unwrap(), 1);
+        assert_eq!(*backend.pushed.lock().unwrap(), vec![1]);
+    }
+}
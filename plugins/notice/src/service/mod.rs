@@ -1,3 +1,7 @@
+pub mod backend;
+pub mod dispatcher;
 pub mod notice_service;
 
+pub use backend::{NoticeBackend, Recipient};
+pub use dispatcher::{DispatcherConfig, NoticeDispatcher, NOTICE_EVENT};
 pub use notice_service::NoticeService;
@@ -1,13 +1,77 @@
 //! 通知公告服务层 - 匹配Python版本
 
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::{Expr, Query};
 use sea_orm::*;
-use crate::entity::notice;
+use crate::entity::{notice, notice_read, notice_target};
+use crate::entity::notice::{TARGET_ALL, TARGET_DEPT, TARGET_ROLE, TARGET_USER};
 use crate::dto::*;
 use crate::error::NoticeError;
+use crate::service::backend::Recipient;
+use crate::service::dispatcher::NoticeDispatcher;
 
 /// 通知公告服务
 pub struct NoticeService;
 
+/// 校验投放范围和有效期
+fn check_schedule(
+    target_type: i16,
+    target_ids: &[i64],
+    publish_time: Option<NaiveDateTime>,
+    expire_time: Option<NaiveDateTime>,
+) -> Result<(), NoticeError> {
+    if target_type != TARGET_ALL && target_ids.is_empty() {
+        return Err(NoticeError::ValidationError("请选择投放对象".to_string()));
+    }
+    if let (Some(publish), Some(expire)) = (publish_time, expire_time) {
+        if expire <= publish {
+            return Err(NoticeError::ValidationError("过期时间必须晚于发布时间".to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// 用户可见的通知：已发布、在有效期内且投放范围包含该用户
+fn visible_to(recipient: &Recipient) -> Condition {
+    let now = chrono::Utc::now().naive_utc();
+    let targeted = |target_type: i16, ids: Vec<i64>| {
+        Condition::all()
+            .add(notice::Column::TargetType.eq(target_type))
+            .add(
+                notice::Column::Id.in_subquery(
+                    Query::select()
+                        .column(notice_target::Column::NoticeId)
+                        .from(notice_target::Entity)
+                        .and_where(Expr::col(notice_target::Column::TargetId).is_in(ids))
+                        .to_owned(),
+                ),
+            )
+    };
+
+    Condition::all()
+        .add(notice::Column::Status.eq(1))
+        .add(notice::Column::PublishTime.is_null().or(notice::Column::PublishTime.lte(now)))
+        .add(notice::Column::ExpireTime.is_null().or(notice::Column::ExpireTime.gt(now)))
+        .add(
+            Condition::any()
+                .add(notice::Column::TargetType.eq(TARGET_ALL))
+                .add(targeted(TARGET_ROLE, recipient.role_ids.clone()))
+                .add(targeted(TARGET_DEPT, recipient.dept_id.into_iter().collect()))
+                .add(targeted(TARGET_USER, vec![recipient.user_id])),
+        )
+}
+
+/// 用户已读的通知ID子查询
+fn read_by(user_id: i64) -> sea_orm::sea_query::SelectStatement {
+    Query::select()
+        .column(notice_read::Column::NoticeId)
+        .from(notice_read::Entity)
+        .and_where(Expr::col(notice_read::Column::UserId).eq(user_id))
+        .to_owned()
+}
+
 impl NoticeService {
     /// 获取所有通知公告
     pub async fn get_all(db: &DatabaseConnection) -> Result<Vec<NoticeDetail>, NoticeError> {
@@ -16,10 +80,10 @@ impl NoticeService {
             .all(db)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
-        Ok(notices.into_iter().map(NoticeDetail::from).collect())
+
+        Self::with_targets(db, notices).await
     }
-    
+
     /// 根据ID获取通知公告
     pub async fn get_by_id(
         db: &DatabaseConnection,
@@ -30,20 +94,160 @@ impl NoticeService {
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
             .ok_or(NoticeError::NotFound("通知公告不存在".to_string()))?;
-        
-        Ok(NoticeDetail::from(notice))
+
+        let mut details = Self::with_targets(db, vec![notice]).await?;
+        Ok(details.remove(0))
+    }
+
+    /// 补充投放对象ID
+    async fn with_targets(
+        db: &DatabaseConnection,
+        notices: Vec<notice::Model>,
+    ) -> Result<Vec<NoticeDetail>, NoticeError> {
+        let ids: Vec<i64> = notices
+            .iter()
+            .filter(|n| n.target_type != TARGET_ALL)
+            .map(|n| n.id)
+            .collect();
+        let mut targets: HashMap<i64, Vec<i64>> = HashMap::new();
+        if !ids.is_empty() {
+            for target in notice_target::Entity::find()
+                .filter(notice_target::Column::NoticeId.is_in(ids))
+                .order_by_asc(notice_target::Column::Id)
+                .all(db)
+                .await
+                .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
+            {
+                targets.entry(target.notice_id).or_default().push(target.target_id);
+            }
+        }
+
+        Ok(notices
+            .into_iter()
+            .map(|n| {
+                let target_ids = targets.remove(&n.id).unwrap_or_default();
+                NoticeDetail { target_ids, ..NoticeDetail::from(n) }
+            })
+            .collect())
+    }
+
+    /// 获取当前用户可见的通知公告（含已读状态）
+    pub async fn get_visible(
+        db: &DatabaseConnection,
+        recipient: &Recipient,
+    ) -> Result<Vec<UserNoticeDetail>, NoticeError> {
+        let notices = notice::Entity::find()
+            .filter(visible_to(recipient))
+            .order_by_desc(notice::Column::Level)
+            .order_by_desc(notice::Column::Id)
+            .all(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        if notices.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let read: HashSet<i64> = notice_read::Entity::find()
+            .filter(notice_read::Column::UserId.eq(recipient.user_id))
+            .filter(notice_read::Column::NoticeId.is_in(notices.iter().map(|n| n.id)))
+            .all(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|r| r.notice_id)
+            .collect();
+
+        Ok(notices
+            .into_iter()
+            .map(|n| {
+                let is_read = read.contains(&n.id);
+                UserNoticeDetail::new(n, is_read)
+            })
+            .collect())
+    }
+
+    /// 当前用户的未读通知数量
+    pub async fn unread_count(
+        db: &DatabaseConnection,
+        recipient: &Recipient,
+    ) -> Result<u64, NoticeError> {
+        notice::Entity::find()
+            .filter(visible_to(recipient))
+            .filter(notice::Column::Id.not_in_subquery(read_by(recipient.user_id)))
+            .count(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))
     }
-    
-    /// 获取显示的通知公告列表（公开接口）
-    pub async fn get_visible(db: &DatabaseConnection) -> Result<Vec<NoticeDetail>, NoticeError> {
-        let notices = notice::Entity::find_visible()
+
+    /// 标记通知为已读
+    pub async fn mark_read(
+        db: &DatabaseConnection,
+        recipient: &Recipient,
+        id: i64,
+    ) -> Result<(), NoticeError> {
+        let visible = notice::Entity::find_by_id(id)
+            .filter(visible_to(recipient))
+            .count(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        if visible == 0 {
+            return Err(NoticeError::NotFound("通知公告不存在".to_string()));
+        }
+
+        Self::insert_reads(db, recipient.user_id, vec![id]).await
+    }
+
+    /// 将当前用户的全部可见通知标记为已读，返回新标记的数量
+    pub async fn mark_all_read(
+        db: &DatabaseConnection,
+        recipient: &Recipient,
+    ) -> Result<u64, NoticeError> {
+        let ids: Vec<i64> = notice::Entity::find()
+            .select_only()
+            .column(notice::Column::Id)
+            .filter(visible_to(recipient))
+            .filter(notice::Column::Id.not_in_subquery(read_by(recipient.user_id)))
+            .into_tuple()
             .all(db)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
-        Ok(notices.into_iter().map(NoticeDetail::from).collect())
+        let count = ids.len() as u64;
+
+        Self::insert_reads(db, recipient.user_id, ids).await?;
+        Ok(count)
+    }
+
+    /// 写入已读记录，已存在的记录忽略
+    async fn insert_reads(
+        db: &DatabaseConnection,
+        user_id: i64,
+        notice_ids: Vec<i64>,
+    ) -> Result<(), NoticeError> {
+        if notice_ids.is_empty() {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        let models = notice_ids.into_iter().map(|notice_id| notice_read::ActiveModel {
+            notice_id: Set(notice_id),
+            user_id: Set(user_id),
+            read_time: Set(now),
+            ..Default::default()
+        });
+        notice_read::Entity::insert_many(models)
+            .on_conflict(
+                sea_query::OnConflict::columns([notice_read::Column::NoticeId, notice_read::Column::UserId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        Ok(())
     }
-    
+
     /// 分页查询通知公告
     pub async fn get_list(
         db: &DatabaseConnection,
@@ -51,7 +255,7 @@ impl NoticeService {
         pagination: PaginationQuery,
     ) -> Result<PageData<NoticeDetail>, NoticeError> {
         let mut select = notice::Entity::find();
-        
+
         // 构建查询条件
         if let Some(title) = &query.title {
             select = select.filter(notice::Column::Title.contains(title));
@@ -62,76 +266,138 @@ impl NoticeService {
         if let Some(status) = query.status {
             select = select.filter(notice::Column::Status.eq(status));
         }
-        
+
         // 查询总数
         let total = select
             .clone()
             .count(db)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
+
         // 分页查询（按ID倒序）
-        let items = select
+        let notices = select
             .order_by_desc(notice::Column::Id)
             .offset(pagination.offset())
             .limit(pagination.limit())
             .all(db)
             .await
-            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
-            .into_iter()
-            .map(NoticeDetail::from)
-            .collect();
-        
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        let items = Self::with_targets(db, notices).await?;
+
         Ok(PageData::new(items, total, pagination.page, pagination.size))
     }
-    
+
+    /// 替换投放对象
+    async fn save_targets<C: ConnectionTrait>(
+        db: &C,
+        notice_id: i64,
+        target_type: i16,
+        target_ids: Vec<i64>,
+    ) -> Result<(), NoticeError> {
+        notice_target::Entity::delete_many()
+            .filter(notice_target::Column::NoticeId.eq(notice_id))
+            .exec(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        if target_type == TARGET_ALL {
+            return Ok(());
+        }
+
+        let unique: HashSet<i64> = target_ids.into_iter().collect();
+        let models = unique.into_iter().map(|target_id| notice_target::ActiveModel {
+            notice_id: Set(notice_id),
+            target_id: Set(target_id),
+            ..Default::default()
+        });
+        notice_target::Entity::insert_many(models)
+            .exec(db)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// 创建通知公告
     pub async fn create(
         db: &DatabaseConnection,
         param: CreateNoticeParam,
     ) -> Result<NoticeDetail, NoticeError> {
+        check_schedule(param.target_type, &param.target_ids, param.publish_time, param.expire_time)?;
+
         let notice = notice::ActiveModel {
             title: Set(param.title),
             type_: Set(param.type_),
             status: Set(param.status),
             content: Set(param.content),
+            level: Set(param.level),
+            target_type: Set(param.target_type),
+            publish_time: Set(param.publish_time),
+            expire_time: Set(param.expire_time),
+            email_fallback: Set(if param.email_fallback { 1 } else { 0 }),
+            dispatched: Set(0),
             ..Default::default()
         };
-        
+
+        let txn = db.begin().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
         let result = notice
-            .insert(db)
+            .insert(&txn)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
-        Ok(NoticeDetail::from(result))
+        Self::save_targets(&txn, result.id, param.target_type, param.target_ids.clone()).await?;
+        txn.commit().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        if result.is_visible() {
+            NoticeDispatcher::wake();
+        }
+        let target_ids = if result.target_type == TARGET_ALL { Vec::new() } else { param.target_ids };
+        Ok(NoticeDetail { target_ids, ..NoticeDetail::from(result) })
     }
-    
+
     /// 更新通知公告
+    ///
+    /// 从隐藏改为显示时重新推送
     pub async fn update(
         db: &DatabaseConnection,
         id: i64,
         param: UpdateNoticeParam,
     ) -> Result<u64, NoticeError> {
+        check_schedule(param.target_type, &param.target_ids, param.publish_time, param.expire_time)?;
+
         let notice = notice::Entity::find_by_id(id)
             .one(db)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?
             .ok_or(NoticeError::NotFound("通知公告不存在".to_string()))?;
-        
+        let republish = !notice.is_visible() && param.status == 1;
+
         let mut notice: notice::ActiveModel = notice.into();
         notice.title = Set(param.title);
         notice.type_ = Set(param.type_);
         notice.status = Set(param.status);
         notice.content = Set(param.content);
-        
+        notice.level = Set(param.level);
+        notice.target_type = Set(param.target_type);
+        notice.publish_time = Set(param.publish_time);
+        notice.expire_time = Set(param.expire_time);
+        notice.email_fallback = Set(if param.email_fallback { 1 } else { 0 });
+        if republish {
+            notice.dispatched = Set(0);
+        }
+
+        let txn = db.begin().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
         notice
-            .update(db)
+            .update(&txn)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
+        Self::save_targets(&txn, id, param.target_type, param.target_ids).await?;
+        txn.commit().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
+        if republish {
+            NoticeDispatcher::wake();
+        }
         Ok(1)
     }
-    
+
     /// 批量删除通知公告
     pub async fn delete_batch(
         db: &DatabaseConnection,
@@ -140,13 +406,69 @@ impl NoticeService {
         if ids.is_empty() {
             return Ok(0);
         }
-        
+
+        let txn = db.begin().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        notice_target::Entity::delete_many()
+            .filter(notice_target::Column::NoticeId.is_in(ids.clone()))
+            .exec(&txn)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+        notice_read::Entity::delete_many()
+            .filter(notice_read::Column::NoticeId.is_in(ids.clone()))
+            .exec(&txn)
+            .await
+            .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
         let result = notice::Entity::delete_many()
             .filter(notice::Column::Id.is_in(ids))
-            .exec(db)
+            .exec(&txn)
             .await
             .map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
-        
+        txn.commit().await.map_err(|e| NoticeError::DatabaseError(e.to_string()))?;
+
         Ok(result.rows_affected)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::notice::{LEVEL_NORMAL, LEVEL_URGENT};
+    use crate::service::dispatcher::tests::{param, sqlite};
+
+    #[tokio::test]
+    async fn test_targeting_and_read_state() {
+        let db = sqlite().await;
+        let all = NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_ALL, vec![])).await.unwrap();
+        let role = NoticeService::create(&db, param(LEVEL_URGENT, TARGET_ROLE, vec![10, 11])).await.unwrap();
+        NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_DEPT, vec![5])).await.unwrap();
+        NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_USER, vec![2])).await.unwrap();
+        let mut expired = param(LEVEL_NORMAL, TARGET_ALL, vec![]);
+        expired.publish_time = Some(chrono::Utc::now().naive_utc() - chrono::Duration::hours(2));
+        expired.expire_time = Some(chrono::Utc::now().naive_utc() - chrono::Duration::hours(1));
+        NoticeService::create(&db, expired).await.unwrap();
+        assert!(NoticeService::create(&db, param(LEVEL_NORMAL, TARGET_USER, vec![])).await.is_err());
+
+        let user = Recipient { user_id: 1, dept_id: Some(6), role_ids: vec![11], email: None };
+        let visible = NoticeService::get_visible(&db, &user).await.unwrap();
+        let ids: Vec<i64> = visible.iter().map(|n| n.id).collect();
+        // 紧急通知排在前面
+        assert_eq!(ids, vec![role.id, all.id]);
+        assert_eq!(NoticeService::unread_count(&db, &user).await.unwrap(), 2);
+
+        NoticeService::mark_read(&db, &user, all.id).await.unwrap();
+        NoticeService::mark_read(&db, &user, all.id).await.unwrap();
+        assert_eq!(NoticeService::unread_count(&db, &user).await.unwrap(), 1);
+        assert!(NoticeService::get_visible(&db, &user).await.unwrap()[1].is_read);
+
+        assert_eq!(NoticeService::mark_all_read(&db, &user).await.unwrap(), 1);
+        assert_eq!(NoticeService::unread_count(&db, &user).await.unwrap(), 0);
+        // 其他用户的已读状态独立
+        let other = Recipient { user_id: 2, dept_id: Some(5), ..Default::default() };
+        assert_eq!(NoticeService::unread_count(&db, &other).await.unwrap(), 3);
+
+        let detail = NoticeService::get_by_id(&db, role.id).await.unwrap();
+        let mut target_ids = detail.target_ids;
+        target_ids.sort();
+        assert_eq!(target_ids, vec![10, 11]);
+    }
+}
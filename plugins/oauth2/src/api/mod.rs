@@ -1,3 +1,5 @@
 pub mod oauth2_api;
+pub mod provider_api;
 
 pub use oauth2_api::*;
+pub use provider_api::*;
@@ -1,156 +1,186 @@
 //! OAuth2 API处理器
 
 use axum::{
-    extract::{Json, Query, State},
+    extract::{Json, Path, Query, State},
+    http::{header, HeaderMap},
     response::Redirect,
 };
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
 use validator::Validate;
-use std::collections::HashMap;
 
 use crate::dto::*;
 use crate::error::OAuth2Error;
-use crate::service::{OAuth2Config, OAuth2Service};
+use crate::service::{
+    OAuth2AccountBackend, OAuth2Config, OAuth2Service, OAuthAction, ProviderRegistry, StateStore,
+};
 
 /// 应用状态
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
     pub oauth2_config: OAuth2Config,
+    pub providers: ProviderRegistry,
+    pub store: StateStore,
+    pub accounts: Arc<dyn OAuth2AccountBackend>,
+}
+
+/// 从请求头解析当前登录用户
+pub(crate) async fn current_user(state: &AppState, headers: &HeaderMap) -> Result<i64, OAuth2Error> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim_start_matches("Bearer ").trim())
+        .filter(|v| !v.is_empty())
+        .ok_or(OAuth2Error::Unauthorized("缺少认证信息".to_string()))?;
+
+    state.accounts.authenticate(token).await
 }
 
-/// GitHub授权
-/// GET /oauth2/github/authorize
-pub async fn github_authorize(
+/// 第三方授权
+/// GET /oauth2/{provider}/authorize
+pub async fn authorize(
     State(state): State<AppState>,
+    Path(provider): Path<String>,
 ) -> Result<Redirect, OAuth2Error> {
-    let auth_url = OAuth2Service::get_github_auth_url(&state.oauth2_config)?;
+    let provider = state.providers.get(&provider)?;
+    let auth_url = OAuth2Service::authorize_url(
+        &state.providers,
+        &provider,
+        &state.store,
+        OAuthAction::Login,
+        None,
+    )
+    .await?;
     Ok(Redirect::to(&auth_url))
 }
 
-/// GitHub回调
-/// GET /oauth2/github/callback
-pub async fn github_callback(
+/// 第三方回调
+/// GET /oauth2/{provider}/callback
+pub async fn callback(
     State(state): State<AppState>,
-    Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<ApiResponse<OAuthCallbackResponse>>, OAuth2Error> {
+    Path(provider): Path<String>,
+    Query(params): Query<OAuthCallbackQuery>,
+) -> Result<Json<ApiResponse<OAuthLoginResult>>, OAuth2Error> {
+    if let Some(error) = params.error {
+        let detail = params.error_description.unwrap_or_default();
+        return Err(OAuth2Error::TokenExchangeError(format!("{} {}", error, detail).trim().to_string()));
+    }
     let code = params
-        .get("code")
-        .ok_or(OAuth2Error::ValidationError("缺少code参数".to_string()))?
-        .clone();
+        .code
+        .ok_or(OAuth2Error::ValidationError("缺少code参数".to_string()))?;
+    let csrf_state = params
+        .state
+        .ok_or(OAuth2Error::ValidationError("缺少state参数".to_string()))?;
 
-    let callback_data = OAuth2Service::github_callback(&state.oauth2_config, code).await?;
-    
-    Ok(Json(ApiResponse::success(callback_data)))
-}
+    let provider = state.providers.get(&provider)?;
+    let auth_state = OAuth2Service::verify_state(&provider, &state.store, &csrf_state).await?;
+    let (token, user_info) =
+        OAuth2Service::exchange_code(&state.providers, &provider, code, &auth_state).await?;
 
-/// Google授权
-/// GET /oauth2/google/authorize
-pub async fn google_authorize(
-    State(state): State<AppState>,
-) -> Result<Redirect, OAuth2Error> {
-    let auth_url = OAuth2Service::get_google_auth_url(&state.oauth2_config)?;
-    Ok(Redirect::to(&auth_url))
+    let result = match auth_state.action {
+        OAuthAction::Login => {
+            OAuth2Service::login_or_link(
+                &state.db,
+                &state.oauth2_config,
+                &state.store,
+                state.accounts.as_ref(),
+                &provider,
+                user_info,
+                token,
+            )
+            .await?
+        }
+        OAuthAction::Bind => {
+            let user_id = auth_state
+                .user_id
+                .ok_or(OAuth2Error::InvalidState("绑定请求缺少用户".to_string()))?;
+            let token = token.encrypt(&state.oauth2_config.token_cipher()?)?;
+            let bind = OAuth2Service::bind_oauth(&state.db, user_id, &user_info, token).await?;
+            OAuthLoginResult::Bound { bind }
+        }
+    };
+
+    Ok(Json(ApiResponse::success(result)))
 }
 
-/// Google回调
-/// GET /oauth2/google/callback
-pub async fn google_callback(
+/// 关联已有账号
+/// POST /oauth2/link
+pub async fn link_account(
     State(state): State<AppState>,
-    Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<ApiResponse<OAuthCallbackResponse>>, OAuth2Error> {
-    let code = params
-        .get("code")
-        .ok_or(OAuth2Error::ValidationError("缺少code参数".to_string()))?
-        .clone();
+    Json(param): Json<LinkOAuthParam>,
+) -> Result<Json<ApiResponse<OAuthLoginResult>>, OAuth2Error> {
+    param.validate()?;
 
-    let callback_data = OAuth2Service::google_callback(&state.oauth2_config, code).await?;
-    
-    Ok(Json(ApiResponse::success(callback_data)))
+    let result = OAuth2Service::link_account(
+        &state.db,
+        &state.providers,
+        &state.store,
+        state.accounts.as_ref(),
+        &param,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(result)))
 }
 
 /// 绑定OAuth账号
 /// POST /oauth2/bind
 pub async fn bind_oauth(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(param): Json<BindOAuthParam>,
-) -> Result<Json<ApiResponse<OAuthBindInfo>>, OAuth2Error> {
+) -> Result<Json<ApiResponse<OAuthAuthorizeUrl>>, OAuth2Error> {
     param.validate()?;
-    
-    // 注意：这里需要从JWT中获取user_id
-    // 暂时使用占位值，实际应用中需要实现JWT认证
-    let user_id = 1i64; // TODO: 从JWT获取
-    
-    // 根据provider处理不同的OAuth流程
-    let callback_data = match param.provider.as_str() {
-        "github" => OAuth2Service::github_callback(&state.oauth2_config, param.code).await?,
-        "google" => OAuth2Service::google_callback(&state.oauth2_config, param.code).await?,
-        _ => return Err(OAuth2Error::ValidationError("不支持的OAuth提供商".to_string())),
-    };
-    
-    let bind_info = OAuth2Service::bind_oauth(
-        &state.db,
-        user_id,
-        &param.provider,
-        &callback_data.user_info.provider_user_id,
-        &callback_data.access_token,
-    ).await?;
-    
-    Ok(Json(ApiResponse::success(bind_info)))
+
+    let user_id = current_user(&state, &headers).await?;
+    let provider = state.providers.get(&param.provider)?;
+    let authorize_url = OAuth2Service::authorize_url(
+        &state.providers,
+        &provider,
+        &state.store,
+        OAuthAction::Bind,
+        Some(user_id),
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(OAuthAuthorizeUrl { authorize_url })))
 }
 
 /// 解绑OAuth账号
 /// DELETE /oauth2/unbind
 pub async fn unbind_oauth(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(param): Json<UnbindOAuthParam>,
 ) -> Result<Json<ApiResponse<()>>, OAuth2Error> {
     param.validate()?;
-    
-    // 注意：这里需要从JWT中获取user_id
-    let user_id = 1i64; // TODO: 从JWT获取
-    
-    OAuth2Service::unbind_oauth(&state.db, user_id, &param.provider).await?;
-    
-    Ok(Json(ApiResponse::success_msg("解绑成功")))
-}
-
-/// LinuxDo授权
-/// GET /oauth2/linux-do/authorize
-pub async fn linux_do_authorize(
-    State(state): State<AppState>,
-) -> Result<Redirect, OAuth2Error> {
-    let auth_url = OAuth2Service::get_linux_do_auth_url(&state.oauth2_config)?;
-    Ok(Redirect::to(&auth_url))
-}
 
-/// LinuxDo回调
-/// GET /oauth2/linux-do/callback
-pub async fn linux_do_callback(
-    State(state): State<AppState>,
-    Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<ApiResponse<OAuthCallbackResponse>>, OAuth2Error> {
-    let code = params
-        .get("code")
-        .ok_or(OAuth2Error::ValidationError("缺少code参数".to_string()))?
-        .clone();
+    let user_id = current_user(&state, &headers).await?;
+    let provider = state.providers.get(&param.provider)?;
+    OAuth2Service::unbind_oauth(&state.db, user_id, &provider.name).await?;
 
-    let callback_data = OAuth2Service::linux_do_callback(&state.oauth2_config, code).await?;
-    
-    Ok(Json(ApiResponse::success(callback_data)))
+    Ok(Json(ApiResponse::success_msg("解绑成功")))
 }
 
 /// 创建OAuth2路由
 pub fn oauth2_routes() -> axum::Router<AppState> {
     use axum::routing::{delete, get, post};
-    
+
     axum::Router::new()
-        .route("/github/authorize", get(github_authorize))
-        .route("/github/callback", get(github_callback))
-        .route("/google/authorize", get(google_authorize))
-        .route("/google/callback", get(google_callback))
-        .route("/linux-do/authorize", get(linux_do_authorize))
-        .route("/linux-do/callback", get(linux_do_callback))
+        .route("/{provider}/authorize", get(authorize))
+        .route("/{provider}/callback", get(callback))
+        .route("/link", post(link_account))
+        .route("/providers", get(super::provider_api::list_enabled_providers))
+        .route(
+            "/admin/providers",
+            get(super::provider_api::get_all_providers).post(super::provider_api::create_provider),
+        )
+        .route(
+            "/admin/providers/{pk}",
+            axum::routing::put(super::provider_api::update_provider)
+                .delete(super::provider_api::delete_provider),
+        )
         .route("/bind", post(bind_oauth))
         .route("/unbind", delete(unbind_oauth))
 }
@@ -0,0 +1,100 @@
+//! OAuth2 提供商管理API处理器
+
+use axum::{
+    extract::{Json, Path, State},
+    http::HeaderMap,
+};
+use validator::Validate;
+
+use crate::api::oauth2_api::{current_user, AppState};
+use crate::dto::*;
+use crate::error::OAuth2Error;
+use crate::service::ProviderService;
+
+/// 提供商配置决定谁能登录以及获得哪些角色，仅超级用户可管理
+async fn require_superuser(state: &AppState, headers: &HeaderMap) -> Result<i64, OAuth2Error> {
+    let user_id = current_user(state, headers).await?;
+    if !state.accounts.is_superuser(user_id).await? {
+        return Err(OAuth2Error::Forbidden("仅超级用户可管理第三方登录提供商".to_string()));
+    }
+    Ok(user_id)
+}
+
+/// 已启用的提供商（登录页展示）
+/// GET /oauth2/providers
+pub async fn list_enabled_providers(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<OAuthProviderSummary>>>, OAuth2Error> {
+    let data = state
+        .providers
+        .list()
+        .into_iter()
+        .filter(|p| !p.client_id.is_empty())
+        .map(|p| OAuthProviderSummary { name: p.name, kind: p.kind })
+        .collect();
+
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 获取所有提供商配置
+/// GET /oauth2/admin/providers
+pub async fn get_all_providers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<Vec<OAuthProviderDetail>>>, OAuth2Error> {
+    require_superuser(&state, &headers).await?;
+
+    let data = ProviderService::get_all(&state.db).await?;
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 创建提供商
+/// POST /oauth2/admin/providers
+pub async fn create_provider(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(param): Json<OAuthProviderParam>,
+) -> Result<Json<ApiResponse<OAuthProviderDetail>>, OAuth2Error> {
+    param.validate()?;
+    require_superuser(&state, &headers).await?;
+
+    let cipher = state.oauth2_config.token_cipher()?;
+    let data = ProviderService::create(&state.db, &cipher, param).await?;
+    state.providers.reload(&state.db, &cipher).await?;
+
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 更新提供商
+/// PUT /oauth2/admin/providers/{pk}
+pub async fn update_provider(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(pk): Path<i64>,
+    Json(param): Json<OAuthProviderParam>,
+) -> Result<Json<ApiResponse<OAuthProviderDetail>>, OAuth2Error> {
+    param.validate()?;
+    require_superuser(&state, &headers).await?;
+
+    let cipher = state.oauth2_config.token_cipher()?;
+    let data = ProviderService::update(&state.db, &cipher, pk, param).await?;
+    state.providers.reload(&state.db, &cipher).await?;
+
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// 删除提供商
+/// DELETE /oauth2/admin/providers/{pk}
+pub async fn delete_provider(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(pk): Path<i64>,
+) -> Result<Json<ApiResponse<()>>, OAuth2Error> {
+    require_superuser(&state, &headers).await?;
+
+    let cipher = state.oauth2_config.token_cipher()?;
+    ProviderService::delete(&state.db, pk).await?;
+    state.providers.reload(&state.db, &cipher).await?;
+
+    Ok(Json(ApiResponse::success_msg("删除成功")))
+}
@@ -1,10 +1,14 @@
 //! OAuth2 DTO定义
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::collections::BTreeMap;
 use validator::Validate;
 
+use crate::service::provider::{ClaimMapping, ProviderKind};
+
 /// OAuth绑定信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OAuthBindInfo {
     pub id: i64,
     pub user_id: i64,
@@ -14,39 +18,135 @@ pub struct OAuthBindInfo {
 }
 
 /// 绑定OAuth请求
-#[derive(Debug, Deserialize, Validate)]
+///
+/// 返回提供商授权地址，用户授权后在回调中完成绑定
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct BindOAuthParam {
     #[validate(length(min = 1, max = 50))]
     pub provider: String,
-    
-    #[validate(length(min = 1))]
-    pub code: String,
-    
-    pub redirect_uri: Option<String>,
 }
 
 /// 解绑OAuth请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UnbindOAuthParam {
     #[validate(length(min = 1, max = 50))]
     pub provider: String,
 }
 
-/// OAuth回调响应
-#[derive(Debug, Serialize, Deserialize)]
-pub struct OAuthCallbackResponse {
-    pub access_token: String,
-    pub user_info: OAuthUserInfo,
+/// OAuth回调参数
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OAuthCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// 授权地址
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OAuthAuthorizeUrl {
+    pub authorize_url: String,
+}
+
+/// 关联已有账号请求
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct LinkOAuthParam {
+    #[validate(length(min = 1))]
+    pub link_ticket: String,
+
+    #[validate(length(min = 1, max = 64))]
+    pub username: String,
+
+    #[validate(length(min = 1))]
+    pub password: String,
+}
+
+/// OAuth回调结果
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OAuthLoginResult {
+    /// 已登录，session 与账号密码登录返回结构一致
+    LoggedIn {
+        session: serde_json::Value,
+        /// 是否为本次自动创建的用户
+        provisioned: bool,
+    },
+    /// 第三方身份未绑定，需要使用 link_ticket 关联已有账号
+    LinkRequired {
+        link_ticket: String,
+        expires_in: u64,
+        user_info: OAuthUserInfo,
+    },
+    /// 已登录用户绑定成功
+    Bound { bind: OAuthBindInfo },
 }
 
 /// OAuth用户信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OAuthUserInfo {
     pub provider: String,
     pub provider_user_id: String,
     pub username: String,
     pub email: Option<String>,
     pub avatar_url: Option<String>,
+    /// 分组（来自声明映射中的 groups）
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
+
+/// 提供商（登录页展示）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OAuthProviderSummary {
+    pub name: String,
+    pub kind: ProviderKind,
+}
+
+/// 创建/更新提供商请求
+///
+/// 更新时 client_secret 为空表示保持原值
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct OAuthProviderParam {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+    pub kind: ProviderKind,
+    #[validate(length(min = 1, max = 255))]
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    #[validate(url)]
+    pub redirect_uri: Option<String>,
+    #[validate(url)]
+    pub issuer: Option<String>,
+    #[validate(url)]
+    pub auth_url: Option<String>,
+    #[validate(url)]
+    pub token_url: Option<String>,
+    #[validate(url)]
+    pub userinfo_url: Option<String>,
+    pub scopes: Option<String>,
+    pub claim_mapping: Option<ClaimMapping>,
+    pub role_mapping: Option<BTreeMap<String, String>>,
+    pub status: Option<i32>,
+}
+
+/// 提供商详情（不返回密钥）
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OAuthProviderDetail {
+    pub id: i64,
+    pub name: String,
+    pub kind: String,
+    pub client_id: String,
+    pub has_client_secret: bool,
+    pub redirect_uri: Option<String>,
+    pub issuer: Option<String>,
+    pub auth_url: Option<String>,
+    pub token_url: Option<String>,
+    pub userinfo_url: Option<String>,
+    pub scopes: Option<String>,
+    pub claim_mapping: Option<serde_json::Value>,
+    pub role_mapping: Option<serde_json::Value>,
+    pub status: i32,
+    pub created_time: String,
+    pub updated_time: Option<String>,
 }
 
 /// API响应
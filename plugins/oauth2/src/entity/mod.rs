@@ -1,3 +1,5 @@
 pub mod oauth_bind;
+pub mod oauth_provider;
 
 pub use oauth_bind::Entity as OAuthBindEntity;
+pub use oauth_provider::Entity as OAuthProviderEntity;
@@ -0,0 +1,79 @@
+//! OAuth2/OIDC 提供商配置实体
+//! 对应数据库表：sys_oauth2_provider
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 提供商配置实体模型
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_oauth2_provider")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    /// 提供商标识（路由中使用，如 keycloak）
+    #[sea_orm(column_type = "String(StringLen::N(50))", unique)]
+    pub name: String,
+
+    /// 类型（oauth2 / oidc）
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub kind: String,
+
+    /// 客户端ID
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub client_id: String,
+
+    /// 客户端密钥（加密存储）
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub client_secret: Option<String>,
+
+    /// 回调地址
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub redirect_uri: Option<String>,
+
+    /// OIDC Issuer
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub issuer: Option<String>,
+
+    /// 授权端点（OIDC 可留空，由发现文档提供）
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub auth_url: Option<String>,
+
+    /// 令牌端点
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub token_url: Option<String>,
+
+    /// 用户信息端点
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub userinfo_url: Option<String>,
+
+    /// 授权范围（逗号或空格分隔）
+    #[sea_orm(column_type = "String(StringLen::N(500))", nullable)]
+    pub scopes: Option<String>,
+
+    /// 声明映射JSON
+    #[sea_orm(column_type = "Text", nullable)]
+    pub claim_mapping: Option<String>,
+
+    /// 分组到角色名称的映射JSON
+    #[sea_orm(column_type = "Text", nullable)]
+    pub role_mapping: Option<String>,
+
+    /// 状态（0停用 1启用）
+    pub status: i32,
+
+    /// 创建时间
+    pub created_time: DateTime,
+
+    /// 更新时间
+    #[sea_orm(nullable)]
+    pub updated_time: Option<DateTime>,
+}
+
+/// 关系定义
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// ActiveModel行为实现
+impl ActiveModelBehavior for ActiveModel {}
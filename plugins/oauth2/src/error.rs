@@ -34,6 +34,30 @@ pub enum OAuth2Error {
     /// 验证错误
     #[error("验证错误: {0}")]
     ValidationError(String),
+
+    /// 授权状态无效（state 不存在、已过期或与提供商不匹配）
+    #[error("授权状态无效: {0}")]
+    InvalidState(String),
+
+    /// 未认证
+    #[error("未认证: {0}")]
+    Unauthorized(String),
+
+    /// 无权限
+    #[error("无权限: {0}")]
+    Forbidden(String),
+
+    /// 缓存错误
+    #[error("缓存错误: {0}")]
+    CacheError(String),
+
+    /// 第三方令牌无效（ID Token 校验失败等）
+    #[error("令牌无效: {0}")]
+    InvalidToken(String),
+
+    /// 令牌加解密错误
+    #[error("令牌加解密失败: {0}")]
+    CryptoError(String),
 }
 
 impl IntoResponse for OAuth2Error {
@@ -45,6 +69,12 @@ impl IntoResponse for OAuth2Error {
             OAuth2Error::DatabaseError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, 500, msg),
             OAuth2Error::NotFound(msg) => (StatusCode::NOT_FOUND, 404, msg),
             OAuth2Error::ValidationError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, 422, msg),
+            OAuth2Error::InvalidState(msg) => (StatusCode::BAD_REQUEST, 400, msg),
+            OAuth2Error::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, 401, msg),
+            OAuth2Error::Forbidden(msg) => (StatusCode::FORBIDDEN, 403, msg),
+            OAuth2Error::InvalidToken(msg) => (StatusCode::UNAUTHORIZED, 401, msg),
+            OAuth2Error::CacheError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, 500, msg),
+            OAuth2Error::CryptoError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, 500, msg),
         };
 
         let body = Json(json!({
@@ -60,8 +90,8 @@ impl From<validator::ValidationErrors> for OAuth2Error {
     fn from(errors: validator::ValidationErrors) -> Self {
         let messages: Vec<String> = errors
             .field_errors()
-            .iter()
-            .flat_map(|(_, errs)| {
+            .values()
+            .flat_map(|errs| {
                 errs.iter()
                     .filter_map(|e| e.message.as_ref().map(|m| m.to_string()))
             })
@@ -70,3 +100,15 @@ impl From<validator::ValidationErrors> for OAuth2Error {
         OAuth2Error::ValidationError(messages.join(", "))
     }
 }
+
+impl From<redis::RedisError> for OAuth2Error {
+    fn from(e: redis::RedisError) -> Self {
+        OAuth2Error::CacheError(e.to_string())
+    }
+}
+
+impl From<sea_orm::DbErr> for OAuth2Error {
+    fn from(e: sea_orm::DbErr) -> Self {
+        OAuth2Error::DatabaseError(e.to_string())
+    }
+}
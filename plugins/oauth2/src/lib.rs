@@ -1,5 +1,5 @@
 //! OAuth2认证插件
-//! 提供GitHub/Google/LinuxDo第三方登录功能，与Python版本完全对齐
+//! 提供GitHub/Google/LinuxDo及任意 OAuth2 / OpenID Connect 提供商的第三方登录功能
 //! 
 //! # 功能特性
 //! - OAuth2第三方登录（8个API端点）
@@ -7,17 +7,21 @@
 //! - Google OAuth2集成（完整实现）
 //! - LinuxDo OAuth2集成（完整实现）✨
 //! - 用户绑定/解绑管理
-//! - Token自动管理
-//! - 数据库持久化
+//! - state + PKCE 校验（Redis 一次性存储）
+//! - 登录签发系统会话，未绑定身份自动创建用户或关联已有账号
+//! - 提供商端点可配置（便于对接本地模拟服务）
+//! - 通用 OIDC 提供商：发现文档、JWKS 校验 ID Token（支持密钥轮换）
+//! - 声明映射可配置，分组声明可映射为系统角色
+//! - 提供商存储在数据库中，运行时增删改即时生效
+//! - 第三方令牌 AES-GCM 加密落库
 //! 
 //! # API端点
-//! - GET /github/authorize - GitHub授权重定向
-//! - GET /github/callback - GitHub回调处理
-//! - GET /google/authorize - Google授权重定向
-//! - GET /google/callback - Google回调处理
-//! - GET /linux-do/authorize - LinuxDo授权重定向 ✨
-//! - GET /linux-do/callback - LinuxDo回调处理 ✨
-//! - POST /bind - 绑定第三方账号
+//! - GET /providers - 已启用的提供商
+//! - GET|POST /admin/providers、PUT|DELETE /admin/providers/{pk} - 提供商管理
+//! - GET /{provider}/authorize - 授权重定向（github / google / linux-do / 自定义）
+//! - GET /{provider}/callback - 回调处理
+//! - POST /link - 使用关联凭证绑定已有账号并登录
+//! - POST /bind - 已登录用户获取绑定授权地址
 //! - DELETE /unbind - 解绑第三方账号
 
 pub mod entity;
@@ -27,7 +31,9 @@ pub mod api;
 pub mod error;
 
 use axum::Router;
+use redis::aio::ConnectionManager;
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
 
 /// 插件信息
 pub const PLUGIN_INFO: PluginInfo = PluginInfo {
@@ -56,8 +62,30 @@ impl OAuth2Plugin {
 
     /// 创建插件路由
     /// 注意：这是一个独立路由插件 (router = ['v1'])
-    pub fn create_router(db: DatabaseConnection, oauth2_config: OAuth2Config) -> Router {
-        let state = api::AppState { db, oauth2_config };
+    pub fn create_router(
+        db: DatabaseConnection,
+        redis: ConnectionManager,
+        oauth2_config: OAuth2Config,
+        accounts: Arc<dyn OAuth2AccountBackend>,
+    ) -> Router {
+        let store = StateStore::new(
+            redis,
+            oauth2_config.redis_prefix.clone(),
+            oauth2_config.state_expire_seconds,
+        );
+        let providers = ProviderRegistry::new(ProviderConfig::builtin());
+
+        // 加载数据库中的提供商
+        if let Ok(cipher) = oauth2_config.token_cipher() {
+            let (db, providers) = (db.clone(), providers.clone());
+            tokio::spawn(async move {
+                if let Err(e) = providers.reload(&db, &cipher).await {
+                    tracing::warn!("加载 OAuth2 提供商失败: {}", e);
+                }
+            });
+        }
+
+        let state = api::AppState { db, oauth2_config, providers, store, accounts };
         
         Router::new()
             .nest("/oauth2", api::oauth2_routes())
@@ -67,9 +95,12 @@ impl OAuth2Plugin {
 
 // 导出公共类型
 pub use dto::{
-    OAuthBindInfo, BindOAuthParam, UnbindOAuthParam,
-    OAuthCallbackResponse, OAuthUserInfo, ApiResponse,
+    OAuthBindInfo, BindOAuthParam, UnbindOAuthParam, LinkOAuthParam,
+    OAuthLoginResult, OAuthUserInfo, ApiResponse,
 };
 pub use error::OAuth2Error;
-pub use service::{OAuth2Service, OAuth2Config};
+pub use service::{
+    ClaimMapping, OAuth2AccountBackend, OAuth2Service, OAuth2Config, ProviderConfig, ProviderKind,
+    ProviderRegistry, StateStore,
+};
 pub use api::AppState;
@@ -0,0 +1,35 @@
+//! 系统账号对接
+//!
+//! 插件不依赖主应用，会话签发、用户创建和账号密码校验由主应用实现
+//! [`OAuth2AccountBackend`] 后注入。
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::dto::OAuthUserInfo;
+use crate::error::OAuth2Error;
+
+/// 系统账号后端
+#[async_trait]
+pub trait OAuth2AccountBackend: Send + Sync {
+    /// 解析系统 access_token，返回用户ID
+    async fn authenticate(&self, token: &str) -> Result<i64, OAuth2Error>;
+
+    /// 为用户签发系统会话（与账号密码登录返回结构一致）
+    async fn issue_session(&self, user_id: i64) -> Result<Value, OAuth2Error>;
+
+    /// 根据第三方用户信息自动创建系统用户，返回用户ID
+    async fn provision_user(&self, user_info: &OAuthUserInfo) -> Result<i64, OAuth2Error>;
+
+    /// 校验系统账号密码，返回用户ID
+    async fn verify_credentials(&self, username: &str, password: &str) -> Result<i64, OAuth2Error>;
+
+    /// 用户是否为超级用户（提供商管理仅超级用户可用）
+    async fn is_superuser(&self, user_id: i64) -> Result<bool, OAuth2Error>;
+
+    /// 同步来自第三方分组映射的角色
+    ///
+    /// `managed` 为提供商角色映射中出现的全部系统角色，只在这些角色范围内增删；
+    /// `granted` 为用户本次应拥有的角色，系统内另行分配的其他角色保持不变
+    async fn sync_roles(&self, user_id: i64, managed: &[String], granted: &[String]) -> Result<(), OAuth2Error>;
+}
@@ -1,3 +1,14 @@
+pub mod account;
 pub mod oauth2_service;
+pub mod oidc;
+pub mod provider;
+pub mod provider_service;
+pub mod state_store;
+pub mod token_cipher;
 
-pub use oauth2_service::{OAuth2Service, OAuth2Config};
+pub use account::OAuth2AccountBackend;
+pub use oauth2_service::{ExchangedToken, OAuth2Config, OAuth2Service};
+pub use provider::{ClaimMapping, ProviderConfig, ProviderKind, ProviderRegistry};
+pub use provider_service::ProviderService;
+pub use state_store::{OAuthAction, StateStore};
+pub use token_cipher::TokenCipher;
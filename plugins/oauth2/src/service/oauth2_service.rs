@@ -2,330 +2,394 @@
 
 use sea_orm::*;
 use oauth2::{
-    AuthorizationCode, AuthUrl, ClientId, ClientSecret, CsrfToken,
-    RedirectUrl, Scope, TokenResponse, TokenUrl,
-    basic::BasicClient,
+    AuthorizationCode, AuthUrl, Client, ClientId, ClientSecret, CsrfToken, ExtraTokenFields,
+    PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, StandardRevocableToken,
+    StandardTokenResponse, TokenResponse, TokenUrl,
+    basic::{
+        BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
+        BasicTokenType,
+    },
     reqwest::async_http_client,
 };
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::entity::oauth_bind;
 use crate::dto::*;
 use crate::error::OAuth2Error;
+use crate::service::account::OAuth2AccountBackend;
+use crate::service::provider::{ProviderConfig, ProviderKind, ProviderRegistry};
+use crate::service::state_store::{AuthorizationState, OAuthAction, PendingLink, ProviderToken, StateStore};
+use crate::service::token_cipher::TokenCipher;
+
+/// 令牌响应中的 ID Token（OIDC）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdTokenFields {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+}
+
+impl ExtraTokenFields for IdTokenFields {}
+
+type OAuthTokenResponse = StandardTokenResponse<IdTokenFields, BasicTokenType>;
+
+type OAuthClient = Client<
+    BasicErrorResponse,
+    OAuthTokenResponse,
+    BasicTokenType,
+    BasicTokenIntrospectionResponse,
+    StandardRevocableToken,
+    BasicRevocationErrorResponse,
+>;
 
 /// OAuth2配置
 #[derive(Clone)]
 pub struct OAuth2Config {
-    pub github_client_id: String,
-    pub github_client_secret: String,
-    pub github_redirect_uri: String,
-    pub google_client_id: String,
-    pub google_client_secret: String,
-    pub google_redirect_uri: String,
-    pub linux_do_client_id: String,
-    pub linux_do_client_secret: String,
-    pub linux_do_redirect_uri: String,
+    /// Redis 键前缀
+    pub redis_prefix: String,
+    /// 授权状态 / 关联凭证有效期（秒）
+    pub state_expire_seconds: u64,
+    /// 未绑定的第三方身份是否自动创建系统用户
+    pub auto_provision: bool,
+    /// 第三方令牌及提供商密钥的加密密钥
+    pub token_encrypt_key: String,
 }
 
 impl Default for OAuth2Config {
     fn default() -> Self {
         Self {
-            github_client_id: std::env::var("OAUTH2_GITHUB_CLIENT_ID").unwrap_or_default(),
-            github_client_secret: std::env::var("OAUTH2_GITHUB_CLIENT_SECRET").unwrap_or_default(),
-            github_redirect_uri: std::env::var("OAUTH2_GITHUB_REDIRECT_URI")
-                .unwrap_or_else(|_| "http://localhost:8000/api/v1/oauth2/github/callback".to_string()),
-            google_client_id: std::env::var("OAUTH2_GOOGLE_CLIENT_ID").unwrap_or_default(),
-            google_client_secret: std::env::var("OAUTH2_GOOGLE_CLIENT_SECRET").unwrap_or_default(),
-            google_redirect_uri: std::env::var("OAUTH2_GOOGLE_REDIRECT_URI")
-                .unwrap_or_else(|_| "http://localhost:8000/api/v1/oauth2/google/callback".to_string()),
-            linux_do_client_id: std::env::var("OAUTH2_LINUX_DO_CLIENT_ID").unwrap_or_default(),
-            linux_do_client_secret: std::env::var("OAUTH2_LINUX_DO_CLIENT_SECRET").unwrap_or_default(),
-            linux_do_redirect_uri: std::env::var("OAUTH2_LINUX_DO_REDIRECT_URI")
-                .unwrap_or_else(|_| "http://localhost:8000/api/v1/oauth2/linux-do/callback".to_string()),
+            redis_prefix: std::env::var("OAUTH2_REDIS_PREFIX")
+                .unwrap_or_else(|_| "fba:oauth2".to_string()),
+            state_expire_seconds: std::env::var("OAUTH2_STATE_EXPIRE_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(600),
+            auto_provision: std::env::var("OAUTH2_AUTO_PROVISION")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            token_encrypt_key: std::env::var("OAUTH2_TOKEN_ENCRYPT_KEY")
+                .or_else(|_| std::env::var("TOKEN_SECRET_KEY"))
+                .unwrap_or_default(),
         }
     }
 }
 
+impl OAuth2Config {
+    /// 令牌加解密器
+    pub fn token_cipher(&self) -> Result<TokenCipher, OAuth2Error> {
+        if self.token_encrypt_key.is_empty() {
+            return Err(OAuth2Error::ConfigError("未配置 OAUTH2_TOKEN_ENCRYPT_KEY".to_string()));
+        }
+        Ok(TokenCipher::new(&self.token_encrypt_key))
+    }
+}
+
+/// 提供商换取的令牌（明文，仅在内存中流转）
+#[derive(Debug, Clone)]
+pub struct ExchangedToken {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+}
+
+impl ExchangedToken {
+    /// 加密为可存储的令牌
+    pub fn encrypt(&self, cipher: &TokenCipher) -> Result<ProviderToken, OAuth2Error> {
+        Ok(ProviderToken {
+            access_token: Some(cipher.encrypt(&self.access_token)?),
+            refresh_token: self
+                .refresh_token
+                .as_deref()
+                .map(|t| cipher.encrypt(t))
+                .transpose()?,
+            expires_at: self.expires_at,
+        })
+    }
+}
+
 /// OAuth2服务
 pub struct OAuth2Service;
 
 impl OAuth2Service {
-    /// 获取GitHub授权URL
-    pub fn get_github_auth_url(config: &OAuth2Config) -> Result<String, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.github_client_id.clone()),
-            Some(ClientSecret::new(config.github_client_secret.clone())),
-            AuthUrl::new("https://github.com/login/oauth/authorize".to_string())
+    /// 构建OAuth2客户端
+    fn client(provider: &ProviderConfig, auth_url: &str, token_url: &str) -> Result<OAuthClient, OAuth2Error> {
+        let client_secret = Some(provider.client_secret.clone())
+            .filter(|s| !s.is_empty())
+            .map(ClientSecret::new);
+
+        Ok(OAuthClient::new(
+            ClientId::new(provider.client_id.clone()),
+            client_secret,
+            AuthUrl::new(auth_url.to_string())
                 .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://github.com/login/oauth/access_token".to_string())
+            Some(TokenUrl::new(token_url.to_string())
                 .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
         )
         .set_redirect_uri(
-            RedirectUrl::new(config.github_redirect_uri.clone())
+            RedirectUrl::new(provider.redirect_uri.clone())
                 .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
-
-        let (auth_url, _csrf_token) = client
-            .authorize_url(CsrfToken::new_random)
-            .add_scope(Scope::new("user:email".to_string()))
-            .url();
-
-        Ok(auth_url.to_string())
+        ))
     }
 
-    /// 获取Google授权URL
-    pub fn get_google_auth_url(config: &OAuth2Config) -> Result<String, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.google_client_id.clone()),
-            Some(ClientSecret::new(config.google_client_secret.clone())),
-            AuthUrl::new("https://accounts.google.com/o/oauth2/v2/auth".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://oauth2.googleapis.com/token".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
-        )
-        .set_redirect_uri(
-            RedirectUrl::new(config.google_redirect_uri.clone())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
-
-        let (auth_url, _csrf_token) = client
+    /// 生成授权URL，并将 state、PKCE verifier（OIDC 另含 nonce）写入缓存
+    pub async fn authorize_url(
+        registry: &ProviderRegistry,
+        provider: &ProviderConfig,
+        store: &StateStore,
+        action: OAuthAction,
+        user_id: Option<i64>,
+    ) -> Result<String, OAuth2Error> {
+        let endpoints = registry.endpoints(provider).await?;
+        let client = Self::client(provider, &endpoints.auth_url, &endpoints.token_url)?;
+        let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+
+        let mut request = client
             .authorize_url(CsrfToken::new_random)
-            .add_scope(Scope::new("openid".to_string()))
-            .add_scope(Scope::new("email".to_string()))
-            .add_scope(Scope::new("profile".to_string()))
-            .url();
+            .set_pkce_challenge(pkce_challenge);
+        for scope in &provider.scopes {
+            request = request.add_scope(Scope::new(scope.clone()));
+        }
+        let nonce = (provider.kind == ProviderKind::Oidc)
+            .then(|| CsrfToken::new_random().secret().clone());
+        if let Some(nonce) = &nonce {
+            request = request.add_extra_param("nonce", nonce.clone());
+        }
+        let (auth_url, csrf_token) = request.url();
+
+        let state = AuthorizationState {
+            provider: provider.name.clone(),
+            pkce_verifier: pkce_verifier.secret().clone(),
+            nonce,
+            action,
+            user_id,
+        };
+        store.save_state(csrf_token.secret(), &state).await?;
 
         Ok(auth_url.to_string())
     }
 
-    /// GitHub回调处理
-    pub async fn github_callback(
-        config: &OAuth2Config,
-        code: String,
-    ) -> Result<OAuthCallbackResponse, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.github_client_id.clone()),
-            Some(ClientSecret::new(config.github_client_secret.clone())),
-            AuthUrl::new("https://github.com/login/oauth/authorize".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://github.com/login/oauth/access_token".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
-        )
-        .set_redirect_uri(
-            RedirectUrl::new(config.github_redirect_uri.clone())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
-
-        // 交换授权码获取访问令牌
-        let token_result = client
-            .exchange_code(AuthorizationCode::new(code))
-            .request_async(async_http_client)
-            .await
-            .map_err(|e| OAuth2Error::TokenExchangeError(e.to_string()))?;
-
-        let access_token = token_result.access_token().secret().to_string();
-
-        // 获取用户信息
-        let user_info = Self::get_github_user_info(&access_token).await?;
+    /// 校验回调中的 state（一次性）
+    pub async fn verify_state(
+        provider: &ProviderConfig,
+        store: &StateStore,
+        csrf_state: &str,
+    ) -> Result<AuthorizationState, OAuth2Error> {
+        let state = store
+            .take_state(csrf_state)
+            .await?
+            .ok_or(OAuth2Error::InvalidState("state 不存在或已过期".to_string()))?;
+
+        if state.provider != provider.name {
+            return Err(OAuth2Error::InvalidState("state 与提供商不匹配".to_string()));
+        }
 
-        Ok(OAuthCallbackResponse {
-            access_token,
-            user_info,
-        })
+        Ok(state)
     }
 
-    /// Google回调处理
-    pub async fn google_callback(
-        config: &OAuth2Config,
+    /// 使用授权码和 PKCE verifier 换取令牌并获取用户信息
+    ///
+    /// OIDC 提供商校验 ID Token 后再合并 userinfo 声明，最后按声明映射生成用户信息
+    pub async fn exchange_code(
+        registry: &ProviderRegistry,
+        provider: &ProviderConfig,
         code: String,
-    ) -> Result<OAuthCallbackResponse, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.google_client_id.clone()),
-            Some(ClientSecret::new(config.google_client_secret.clone())),
-            AuthUrl::new("https://accounts.google.com/o/oauth2/v2/auth".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://oauth2.googleapis.com/token".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
-        )
-        .set_redirect_uri(
-            RedirectUrl::new(config.google_redirect_uri.clone())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
+        state: &AuthorizationState,
+    ) -> Result<(ExchangedToken, OAuthUserInfo), OAuth2Error> {
+        let endpoints = registry.endpoints(provider).await?;
+        let client = Self::client(provider, &endpoints.auth_url, &endpoints.token_url)?;
 
         // 交换授权码获取访问令牌
         let token_result = client
             .exchange_code(AuthorizationCode::new(code))
+            .set_pkce_verifier(PkceCodeVerifier::new(state.pkce_verifier.clone()))
             .request_async(async_http_client)
             .await
             .map_err(|e| OAuth2Error::TokenExchangeError(e.to_string()))?;
 
-        let access_token = token_result.access_token().secret().to_string();
+        let token = ExchangedToken {
+            access_token: token_result.access_token().secret().to_string(),
+            refresh_token: token_result.refresh_token().map(|t| t.secret().to_string()),
+            expires_at: token_result
+                .expires_in()
+                .and_then(|d| chrono::Duration::from_std(d).ok())
+                .map(|d| chrono::Utc::now().naive_utc() + d),
+        };
+
+        let mut claims = serde_json::Map::new();
+        if let Some(metadata) = &endpoints.oidc {
+            let id_token = token_result
+                .extra_fields()
+                .id_token
+                .as_deref()
+                .ok_or(OAuth2Error::InvalidToken("令牌响应缺少 id_token".to_string()))?;
+            let id_claims = registry
+                .oidc()
+                .validate_id_token(id_token, metadata, &provider.client_id, state.nonce.as_deref())
+                .await?;
+            if let Value::Object(map) = id_claims {
+                claims.extend(map);
+            }
+        }
 
         // 获取用户信息
-        let user_info = Self::get_google_user_info(&access_token).await?;
+        if let Some(userinfo_url) = &endpoints.userinfo_url {
+            if let Value::Object(map) = Self::fetch_user_info(userinfo_url, &token.access_token).await? {
+                // OIDC 要求 userinfo 的 sub 与 ID Token 一致
+                if let (Some(sub), Some(info_sub)) = (claims.get("sub"), map.get("sub")) {
+                    if endpoints.oidc.is_some() && sub != info_sub {
+                        return Err(OAuth2Error::InvalidToken("userinfo 与 ID Token 的 sub 不一致".to_string()));
+                    }
+                }
+                claims.extend(map);
+            }
+        }
 
-        Ok(OAuthCallbackResponse {
-            access_token,
-            user_info,
-        })
+        let user_info = provider
+            .claims
+            .to_user_info(&provider.name, &Value::Object(claims))?;
+
+        Ok((token, user_info))
     }
 
-    /// 获取GitHub用户信息
-    async fn get_github_user_info(access_token: &str) -> Result<OAuthUserInfo, OAuth2Error> {
+    /// 获取用户信息
+    async fn fetch_user_info(userinfo_url: &str, access_token: &str) -> Result<Value, OAuth2Error> {
         let client = reqwest::Client::new();
         let response = client
-            .get("https://api.github.com/user")
+            .get(userinfo_url)
             .header("Authorization", format!("Bearer {}", access_token))
             .header("User-Agent", "FastAPI-Best-Architecture-Rust")
             .send()
             .await
+            .and_then(|r| r.error_for_status())
             .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
 
-        let user_data: Value = response
+        response
             .json()
             .await
-            .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
-
-        Ok(OAuthUserInfo {
-            provider: "github".to_string(),
-            provider_user_id: user_data["id"].as_i64().unwrap_or(0).to_string(),
-            username: user_data["login"].as_str().unwrap_or("").to_string(),
-            email: user_data["email"].as_str().map(|s| s.to_string()),
-            avatar_url: user_data["avatar_url"].as_str().map(|s| s.to_string()),
-        })
+            .map_err(|e| OAuth2Error::ApiError(e.to_string()))
     }
 
-    /// 获取Google用户信息
-    async fn get_google_user_info(access_token: &str) -> Result<OAuthUserInfo, OAuth2Error> {
-        let client = reqwest::Client::new();
-        let response = client
-            .get("https://www.googleapis.com/oauth2/v2/userinfo")
-            .header("Authorization", format!("Bearer {}", access_token))
-            .send()
-            .await
-            .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
-
-        let user_data: Value = response
-            .json()
+    /// 按提供商的分组映射同步用户角色（未配置分组声明时跳过）
+    async fn sync_roles(
+        provider: &ProviderConfig,
+        accounts: &dyn OAuth2AccountBackend,
+        user_id: i64,
+        user_info: &OAuthUserInfo,
+    ) -> Result<(), OAuth2Error> {
+        if provider.claims.groups.is_none() {
+            return Ok(());
+        }
+        accounts
+            .sync_roles(user_id, &provider.managed_roles(), &provider.map_roles(&user_info.groups))
             .await
-            .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
-
-        Ok(OAuthUserInfo {
-            provider: "google".to_string(),
-            provider_user_id: user_data["id"].as_str().unwrap_or("").to_string(),
-            username: user_data["name"].as_str().unwrap_or("").to_string(),
-            email: user_data["email"].as_str().map(|s| s.to_string()),
-            avatar_url: user_data["picture"].as_str().map(|s| s.to_string()),
-        })
-    }
-
-    /// 获取LinuxDo授权URL
-    pub fn get_linux_do_auth_url(config: &OAuth2Config) -> Result<String, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.linux_do_client_id.clone()),
-            Some(ClientSecret::new(config.linux_do_client_secret.clone())),
-            AuthUrl::new("https://connect.linux.do/oauth2/authorize".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://connect.linux.do/oauth2/token".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
-        )
-        .set_redirect_uri(
-            RedirectUrl::new(config.linux_do_redirect_uri.clone())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
-
-        let (auth_url, _csrf_token) = client
-            .authorize_url(CsrfToken::new_random)
-            .add_scope(Scope::new("read".to_string()))
-            .url();
-
-        Ok(auth_url.to_string())
     }
 
-    /// LinuxDo回调处理
-    pub async fn linux_do_callback(
+    /// 第三方登录：已绑定则签发会话，否则自动创建用户或返回关联凭证
+    pub async fn login_or_link(
+        db: &DatabaseConnection,
         config: &OAuth2Config,
-        code: String,
-    ) -> Result<OAuthCallbackResponse, OAuth2Error> {
-        let client = BasicClient::new(
-            ClientId::new(config.linux_do_client_id.clone()),
-            Some(ClientSecret::new(config.linux_do_client_secret.clone())),
-            AuthUrl::new("https://connect.linux.do/oauth2/authorize".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-            Some(TokenUrl::new("https://connect.linux.do/oauth2/token".to_string())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?),
-        )
-        .set_redirect_uri(
-            RedirectUrl::new(config.linux_do_redirect_uri.clone())
-                .map_err(|e| OAuth2Error::ConfigError(e.to_string()))?,
-        );
-
-        // 交换授权码获取访问令牌
-        let token_result = client
-            .exchange_code(AuthorizationCode::new(code))
-            .request_async(async_http_client)
-            .await
-            .map_err(|e| OAuth2Error::TokenExchangeError(e.to_string()))?;
+        store: &StateStore,
+        accounts: &dyn OAuth2AccountBackend,
+        provider: &ProviderConfig,
+        user_info: OAuthUserInfo,
+        token: ExchangedToken,
+    ) -> Result<OAuthLoginResult, OAuth2Error> {
+        let token = token.encrypt(&config.token_cipher()?)?;
+
+        let bind = oauth_bind::Entity::find_by_provider_user_id(&user_info.provider, &user_info.provider_user_id)
+            .one(db)
+            .await?;
+
+        if let Some(bind) = bind {
+            let user_id = bind.user_id;
+            Self::save_token(db, bind, &user_info, token).await?;
+            Self::sync_roles(provider, accounts, user_id, &user_info).await?;
+            let session = accounts.issue_session(user_id).await?;
+            return Ok(OAuthLoginResult::LoggedIn { session, provisioned: false });
+        }
 
-        let access_token = token_result.access_token().secret().to_string();
+        if config.auto_provision {
+            let user_id = accounts.provision_user(&user_info).await?;
+            Self::bind_oauth(db, user_id, &user_info, token).await?;
+            Self::sync_roles(provider, accounts, user_id, &user_info).await?;
+            let session = accounts.issue_session(user_id).await?;
+            return Ok(OAuthLoginResult::LoggedIn { session, provisioned: true });
+        }
 
-        // 获取用户信息
-        let user_info = Self::get_linux_do_user_info(&access_token).await?;
+        let link_ticket = CsrfToken::new_random().secret().clone();
+        store
+            .save_link(&link_ticket, &PendingLink { user_info: user_info.clone(), token })
+            .await?;
 
-        Ok(OAuthCallbackResponse {
-            access_token,
+        Ok(OAuthLoginResult::LinkRequired {
+            link_ticket,
+            expires_in: store.ttl_seconds(),
             user_info,
         })
     }
 
-    /// 获取LinuxDo用户信息
-    async fn get_linux_do_user_info(access_token: &str) -> Result<OAuthUserInfo, OAuth2Error> {
-        let client = reqwest::Client::new();
-        let response = client
-            .get("https://connect.linux.do/api/user")
-            .header("Authorization", format!("Bearer {}", access_token))
-            .send()
-            .await
-            .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
-
-        let user_data: Value = response
-            .json()
-            .await
-            .map_err(|e| OAuth2Error::ApiError(e.to_string()))?;
-
-        Ok(OAuthUserInfo {
-            provider: "linux_do".to_string(),
-            provider_user_id: user_data["id"].as_i64().unwrap_or(0).to_string(),
-            username: user_data["username"].as_str().unwrap_or("").to_string(),
-            email: user_data["email"].as_str().map(|s| s.to_string()),
-            avatar_url: user_data["avatar_url"].as_str().map(|s| s.to_string()),
-        })
+    /// 使用关联凭证和系统账号密码完成绑定并登录
+    ///
+    /// 关联凭证一次性有效，校验失败需重新发起授权
+    pub async fn link_account(
+        db: &DatabaseConnection,
+        registry: &ProviderRegistry,
+        store: &StateStore,
+        accounts: &dyn OAuth2AccountBackend,
+        param: &LinkOAuthParam,
+    ) -> Result<OAuthLoginResult, OAuth2Error> {
+        let link = store
+            .take_link(&param.link_ticket)
+            .await?
+            .ok_or(OAuth2Error::InvalidState("关联凭证不存在或已过期".to_string()))?;
+
+        let user_id = accounts
+            .verify_credentials(&param.username, &param.password)
+            .await?;
+        Self::bind_oauth(db, user_id, &link.user_info, link.token).await?;
+
+        if let Ok(provider) = registry.get(&link.user_info.provider) {
+            Self::sync_roles(&provider, accounts, user_id, &link.user_info).await?;
+        }
+        let session = accounts.issue_session(user_id).await?;
+        Ok(OAuthLoginResult::LoggedIn { session, provisioned: false })
     }
-
-    /// 绑定OAuth账号
+    /// 绑定OAuth账号（令牌需已加密）
     pub async fn bind_oauth(
         db: &DatabaseConnection,
         user_id: i64,
-        provider: &str,
-        provider_user_id: &str,
-        access_token: &str,
+        user_info: &OAuthUserInfo,
+        token: ProviderToken,
     ) -> Result<OAuthBindInfo, OAuth2Error> {
-        let now = chrono::Utc::now().naive_utc();
-        
-        let bind = oauth_bind::ActiveModel {
-            user_id: Set(user_id),
-            provider: Set(provider.to_string()),
-            provider_user_id: Set(provider_user_id.to_string()),
-            access_token: Set(Some(access_token.to_string())),
-            created_time: Set(now),
-            ..Default::default()
+        let existing = oauth_bind::Entity::find_by_provider_user_id(&user_info.provider, &user_info.provider_user_id)
+            .one(db)
+            .await?;
+
+        let result = match existing {
+            Some(bind) if bind.user_id != user_id => {
+                return Err(OAuth2Error::ValidationError("该第三方账号已绑定其他用户".to_string()));
+            }
+            Some(bind) => Self::save_token(db, bind, user_info, token).await?,
+            None => {
+                if Self::get_bind(db, user_id, &user_info.provider).await?.is_some() {
+                    return Err(OAuth2Error::ValidationError("已绑定该平台的其他账号，请先解绑".to_string()));
+                }
+
+                let bind = oauth_bind::ActiveModel {
+                    user_id: Set(user_id),
+                    provider: Set(user_info.provider.clone()),
+                    provider_user_id: Set(user_info.provider_user_id.clone()),
+                    access_token: Set(token.access_token),
+                    refresh_token: Set(token.refresh_token),
+                    expires_at: Set(token.expires_at),
+                    user_info: Set(serde_json::to_string(user_info).ok()),
+                    created_time: Set(chrono::Utc::now().naive_utc()),
+                    ..Default::default()
+                };
+                bind.insert(db).await?
+            }
         };
 
-        let result = bind
-            .insert(db)
-            .await
-            .map_err(|e| OAuth2Error::DatabaseError(e.to_string()))?;
-
         Ok(OAuthBindInfo {
             id: result.id,
             user_id: result.user_id,
@@ -335,6 +399,23 @@ impl OAuth2Service {
         })
     }
 
+    /// 更新已有绑定的令牌和用户信息
+    async fn save_token(
+        db: &DatabaseConnection,
+        bind: oauth_bind::Model,
+        user_info: &OAuthUserInfo,
+        token: ProviderToken,
+    ) -> Result<oauth_bind::Model, OAuth2Error> {
+        let mut bind: oauth_bind::ActiveModel = bind.into();
+        bind.access_token = Set(token.access_token);
+        bind.refresh_token = Set(token.refresh_token);
+        bind.expires_at = Set(token.expires_at);
+        bind.user_info = Set(serde_json::to_string(user_info).ok());
+        bind.updated_time = Set(Some(chrono::Utc::now().naive_utc()));
+
+        Ok(bind.update(db).await?)
+    }
+
     /// 解绑OAuth账号
     pub async fn unbind_oauth(
         db: &DatabaseConnection,
@@ -365,3 +446,101 @@ impl OAuth2Service {
             .map_err(|e| OAuth2Error::DatabaseError(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::{get, post}, Form, Json, Router};
+    use std::collections::HashMap;
+
+    /// 本地模拟 OAuth 服务：校验 PKCE verifier 后下发令牌
+    async fn spawn_mock_provider() -> String {
+        let app = Router::new()
+            .route(
+                "/token",
+                post(|Form(form): Form<HashMap<String, String>>| async move {
+                    if form.get("code").map(String::as_str) != Some("good-code")
+                        || form.get("code_verifier").map(String::as_str) != Some("verifier-123")
+                    {
+                        return (
+                            axum::http::StatusCode::BAD_REQUEST,
+                            Json(serde_json::json!({"error": "invalid_grant"})),
+                        );
+                    }
+                    (
+                        axum::http::StatusCode::OK,
+                        Json(serde_json::json!({
+                            "access_token": "mock-access",
+                            "token_type": "bearer",
+                            "expires_in": 3600,
+                            "refresh_token": "mock-refresh",
+                        })),
+                    )
+                }),
+            )
+            .route(
+                "/user",
+                get(|| async {
+                    Json(serde_json::json!({
+                        "id": 42,
+                        "login": "octocat",
+                        "email": "octo@example.com",
+                        "avatar_url": null,
+                    }))
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn mock_state(verifier: &str) -> AuthorizationState {
+        AuthorizationState {
+            provider: "github".to_string(),
+            pkce_verifier: verifier.to_string(),
+            nonce: None,
+            action: OAuthAction::Login,
+            user_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_with_mock_provider() {
+        let base = spawn_mock_provider().await;
+        let mut provider = ProviderConfig::github();
+        provider.client_id = "client".to_string();
+        provider.client_secret = "secret".to_string();
+        provider.auth_url = Some(format!("{}/authorize", base));
+        provider.token_url = Some(format!("{}/token", base));
+        provider.userinfo_url = Some(format!("{}/user", base));
+        let registry = ProviderRegistry::new(vec![provider.clone()]);
+
+        let (token, user_info) = OAuth2Service::exchange_code(
+            &registry,
+            &provider,
+            "good-code".to_string(),
+            &mock_state("verifier-123"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(token.access_token, "mock-access");
+        assert_eq!(token.refresh_token.as_deref(), Some("mock-refresh"));
+        assert!(token.expires_at.is_some());
+        assert_eq!(user_info.provider, "github");
+        assert_eq!(user_info.provider_user_id, "42");
+        assert_eq!(user_info.username, "octocat");
+        assert_eq!(user_info.avatar_url, None);
+
+        let result = OAuth2Service::exchange_code(
+            &registry,
+            &provider,
+            "good-code".to_string(),
+            &mock_state("wrong-verifier"),
+        )
+        .await;
+        assert!(matches!(result, Err(OAuth2Error::TokenExchangeError(_))));
+    }
+}
@@ -0,0 +1,435 @@
+//! 提供商配置与注册表
+//!
+//! 内置 GitHub / Google / LinuxDo（环境变量配置），其余提供商存放在
+//! `sys_oauth2_provider` 表中，增删改后重新加载即可生效，无需重启。
+
+use sea_orm::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+use crate::dto::OAuthUserInfo;
+use crate::entity::oauth_provider;
+use crate::error::OAuth2Error;
+use crate::service::oidc::OidcCache;
+use crate::service::token_cipher::TokenCipher;
+
+/// 提供商类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    /// 普通 OAuth2，用户信息来自 userinfo 接口
+    OAuth2,
+    /// OpenID Connect，端点来自发现文档，身份来自 ID Token
+    Oidc,
+}
+
+impl ProviderKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProviderKind::OAuth2 => "oauth2",
+            ProviderKind::Oidc => "oidc",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Result<Self, OAuth2Error> {
+        match kind {
+            "oauth2" => Ok(ProviderKind::OAuth2),
+            "oidc" => Ok(ProviderKind::Oidc),
+            _ => Err(OAuth2Error::ValidationError(format!("不支持的提供商类型: {}", kind))),
+        }
+    }
+}
+
+/// 声明映射
+///
+/// 值为声明路径，支持 `.` 访问嵌套字段（如 Keycloak 的 `realm_access.roles`）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct ClaimMapping {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub avatar: String,
+    /// 分组声明，为空时不同步角色
+    pub groups: Option<String>,
+}
+
+impl Default for ClaimMapping {
+    /// OIDC 标准声明
+    fn default() -> Self {
+        Self {
+            id: "sub".to_string(),
+            username: "preferred_username".to_string(),
+            email: "email".to_string(),
+            avatar: "picture".to_string(),
+            groups: None,
+        }
+    }
+}
+
+impl ClaimMapping {
+    fn new(id: &str, username: &str, email: &str, avatar: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            username: username.to_string(),
+            email: email.to_string(),
+            avatar: avatar.to_string(),
+            groups: None,
+        }
+    }
+
+    /// 按路径读取声明
+    pub fn lookup<'a>(claims: &'a Value, path: &str) -> Option<&'a Value> {
+        path.split('.').try_fold(claims, |value, key| value.get(key))
+    }
+
+    fn string(claims: &Value, path: &str) -> Option<String> {
+        match Self::lookup(claims, path)? {
+            Value::String(s) if !s.is_empty() => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    /// 映射为第三方用户信息
+    pub fn to_user_info(&self, provider: &str, claims: &Value) -> Result<OAuthUserInfo, OAuth2Error> {
+        let provider_user_id = Self::string(claims, &self.id)
+            .ok_or(OAuth2Error::ApiError("用户信息缺少唯一标识".to_string()))?;
+
+        let groups = match self.groups.as_deref().and_then(|path| Self::lookup(claims, path)) {
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            Some(Value::String(s)) => vec![s.clone()],
+            _ => Vec::new(),
+        };
+
+        Ok(OAuthUserInfo {
+            provider: provider.to_string(),
+            username: Self::string(claims, &self.username).unwrap_or_else(|| provider_user_id.clone()),
+            provider_user_id,
+            email: Self::string(claims, &self.email),
+            avatar_url: Self::string(claims, &self.avatar),
+            groups,
+        })
+    }
+}
+
+/// 单个提供商配置
+///
+/// 端点均可覆盖，便于对接私有部署或本地模拟服务；OIDC 提供商未填写的端点取自发现文档
+#[derive(Debug, Clone)]
+pub struct ProviderConfig {
+    /// 提供商标识（落库和路由使用）
+    pub name: String,
+    pub kind: ProviderKind,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub issuer: Option<String>,
+    pub auth_url: Option<String>,
+    pub token_url: Option<String>,
+    pub userinfo_url: Option<String>,
+    pub scopes: Vec<String>,
+    pub claims: ClaimMapping,
+    /// 分组 -> 角色名称；为空时分组名即角色名
+    pub role_mapping: BTreeMap<String, String>,
+}
+
+impl ProviderConfig {
+    /// GitHub
+    pub fn github() -> Self {
+        Self::from_env(
+            "OAUTH2_GITHUB",
+            "github",
+            "github",
+            [
+                "https://github.com/login/oauth/authorize",
+                "https://github.com/login/oauth/access_token",
+                "https://api.github.com/user",
+            ],
+            "user:email",
+            ClaimMapping::new("id", "login", "email", "avatar_url"),
+        )
+    }
+
+    /// Google
+    pub fn google() -> Self {
+        Self::from_env(
+            "OAUTH2_GOOGLE",
+            "google",
+            "google",
+            [
+                "https://accounts.google.com/o/oauth2/v2/auth",
+                "https://oauth2.googleapis.com/token",
+                "https://www.googleapis.com/oauth2/v2/userinfo",
+            ],
+            "openid,email,profile",
+            ClaimMapping::new("id", "name", "email", "picture"),
+        )
+    }
+
+    /// LinuxDo
+    pub fn linux_do() -> Self {
+        Self::from_env(
+            "OAUTH2_LINUX_DO",
+            "linux_do",
+            "linux-do",
+            [
+                "https://connect.linux.do/oauth2/authorize",
+                "https://connect.linux.do/oauth2/token",
+                "https://connect.linux.do/api/user",
+            ],
+            "read",
+            ClaimMapping::new("id", "username", "email", "avatar_url"),
+        )
+    }
+
+    /// 内置提供商
+    pub fn builtin() -> Vec<Self> {
+        vec![Self::github(), Self::google(), Self::linux_do()]
+    }
+
+    /// 从环境变量读取，未设置时使用默认端点
+    fn from_env(
+        env_prefix: &str,
+        name: &str,
+        path: &str,
+        [auth_url, token_url, userinfo_url]: [&str; 3],
+        scopes: &str,
+        claims: ClaimMapping,
+    ) -> Self {
+        let env = |key: &str, default: &str| {
+            std::env::var(format!("{}_{}", env_prefix, key)).unwrap_or_else(|_| default.to_string())
+        };
+
+        Self {
+            name: name.to_string(),
+            kind: ProviderKind::OAuth2,
+            client_id: env("CLIENT_ID", ""),
+            client_secret: env("CLIENT_SECRET", ""),
+            redirect_uri: env("REDIRECT_URI", &default_redirect_uri(path)),
+            issuer: None,
+            auth_url: Some(env("AUTH_URL", auth_url)),
+            token_url: Some(env("TOKEN_URL", token_url)),
+            userinfo_url: Some(env("USERINFO_URL", userinfo_url)),
+            scopes: split_scopes(&env("SCOPES", scopes)),
+            claims,
+            role_mapping: BTreeMap::new(),
+        }
+    }
+
+    /// 从数据库记录构建
+    pub fn from_model(model: &oauth_provider::Model, cipher: &TokenCipher) -> Result<Self, OAuth2Error> {
+        let kind = ProviderKind::parse(&model.kind)?;
+        let scopes = match model.scopes.as_deref() {
+            Some(scopes) => split_scopes(scopes),
+            None if kind == ProviderKind::Oidc => vec!["openid".into(), "profile".into(), "email".into()],
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            name: model.name.clone(),
+            kind,
+            client_id: model.client_id.clone(),
+            client_secret: model
+                .client_secret
+                .as_deref()
+                .map(|s| cipher.decrypt(s))
+                .transpose()?
+                .unwrap_or_default(),
+            redirect_uri: model
+                .redirect_uri
+                .clone()
+                .unwrap_or_else(|| default_redirect_uri(&model.name)),
+            issuer: model.issuer.clone(),
+            auth_url: model.auth_url.clone(),
+            token_url: model.token_url.clone(),
+            userinfo_url: model.userinfo_url.clone(),
+            scopes,
+            claims: parse_json(model.claim_mapping.as_deref())?,
+            role_mapping: parse_json(model.role_mapping.as_deref())?,
+        })
+    }
+
+    /// 将第三方分组映射为系统角色名称，只有映射中列出的分组才会授予角色（未配置映射时不授予任何角色）
+    pub fn map_roles(&self, groups: &[String]) -> Vec<String> {
+        let mut roles: Vec<String> = groups
+            .iter()
+            .filter_map(|g| self.role_mapping.get(g).cloned())
+            .collect();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+
+    /// 角色映射涉及的全部系统角色，角色同步只增删这些角色
+    pub fn managed_roles(&self) -> Vec<String> {
+        let mut roles: Vec<String> = self.role_mapping.values().cloned().collect();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+}
+
+fn default_redirect_uri(path: &str) -> String {
+    format!("http://localhost:8000/api/v1/oauth2/{}/callback", path)
+}
+
+fn split_scopes(scopes: &str) -> Vec<String> {
+    scopes
+        .split([',', ' '])
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn parse_json<T: Default + serde::de::DeserializeOwned>(json: Option<&str>) -> Result<T, OAuth2Error> {
+    match json.filter(|s| !s.trim().is_empty()) {
+        Some(json) => serde_json::from_str(json).map_err(|e| OAuth2Error::ConfigError(e.to_string())),
+        None => Ok(T::default()),
+    }
+}
+
+/// 提供商解析后的端点
+#[derive(Debug, Clone)]
+pub struct ProviderEndpoints {
+    pub auth_url: String,
+    pub token_url: String,
+    pub userinfo_url: Option<String>,
+    /// OIDC 发现文档
+    pub oidc: Option<crate::service::oidc::OidcMetadata>,
+}
+
+/// 提供商注册表
+#[derive(Clone)]
+pub struct ProviderRegistry {
+    builtin: Arc<Vec<ProviderConfig>>,
+    providers: Arc<RwLock<HashMap<String, ProviderConfig>>>,
+    oidc: Arc<OidcCache>,
+}
+
+impl ProviderRegistry {
+    pub fn new(builtin: Vec<ProviderConfig>) -> Self {
+        let providers = builtin.iter().map(|p| (p.name.clone(), p.clone())).collect();
+        Self {
+            builtin: Arc::new(builtin),
+            providers: Arc::new(RwLock::new(providers)),
+            oidc: Arc::new(OidcCache::new()),
+        }
+    }
+
+    /// 重新加载数据库中启用的提供商（同名覆盖内置提供商）
+    pub async fn reload(&self, db: &DatabaseConnection, cipher: &TokenCipher) -> Result<usize, OAuth2Error> {
+        let models = oauth_provider::Entity::find()
+            .filter(oauth_provider::Column::Status.eq(1))
+            .all(db)
+            .await?;
+
+        let mut providers: HashMap<String, ProviderConfig> = self
+            .builtin
+            .iter()
+            .map(|p| (p.name.clone(), p.clone()))
+            .collect();
+        for model in &models {
+            match ProviderConfig::from_model(model, cipher) {
+                Ok(provider) => {
+                    providers.insert(provider.name.clone(), provider);
+                }
+                Err(e) => tracing::warn!("OAuth2 提供商 {} 配置无效，已跳过: {}", model.name, e),
+            }
+        }
+
+        let count = providers.len();
+        *self.providers.write().unwrap() = providers;
+        Ok(count)
+    }
+
+    /// 根据路由中的提供商名称获取配置（`linux-do` 与 `linux_do` 等价）
+    pub fn get(&self, name: &str) -> Result<ProviderConfig, OAuth2Error> {
+        self.providers
+            .read()
+            .unwrap()
+            .get(&name.replace('-', "_"))
+            .cloned()
+            .ok_or(OAuth2Error::ValidationError("不支持的OAuth提供商".to_string()))
+    }
+
+    /// 已启用的提供商
+    pub fn list(&self) -> Vec<ProviderConfig> {
+        let mut providers: Vec<ProviderConfig> = self.providers.read().unwrap().values().cloned().collect();
+        providers.sort_by(|a, b| a.name.cmp(&b.name));
+        providers
+    }
+
+    /// 解析提供商端点
+    pub async fn endpoints(&self, provider: &ProviderConfig) -> Result<ProviderEndpoints, OAuth2Error> {
+        let missing = |field: &str| OAuth2Error::ConfigError(format!("提供商 {} 缺少 {}", provider.name, field));
+
+        match provider.kind {
+            ProviderKind::OAuth2 => Ok(ProviderEndpoints {
+                auth_url: provider.auth_url.clone().ok_or_else(|| missing("auth_url"))?,
+                token_url: provider.token_url.clone().ok_or_else(|| missing("token_url"))?,
+                userinfo_url: provider.userinfo_url.clone(),
+                oidc: None,
+            }),
+            ProviderKind::Oidc => {
+                let issuer = provider.issuer.as_deref().ok_or_else(|| missing("issuer"))?;
+                let metadata = self.oidc.discover(issuer).await?;
+                Ok(ProviderEndpoints {
+                    auth_url: provider.auth_url.clone().unwrap_or_else(|| metadata.authorization_endpoint.clone()),
+                    token_url: provider.token_url.clone().unwrap_or_else(|| metadata.token_endpoint.clone()),
+                    userinfo_url: provider.userinfo_url.clone().or_else(|| metadata.userinfo_endpoint.clone()),
+                    oidc: Some(metadata),
+                })
+            }
+        }
+    }
+
+    /// OIDC 缓存
+    pub fn oidc(&self) -> &OidcCache {
+        &self.oidc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_mapping_nested_groups() {
+        let mapping = ClaimMapping {
+            groups: Some("realm_access.roles".to_string()),
+            ..Default::default()
+        };
+        let claims = serde_json::json!({
+            "sub": "f3a1",
+            "preferred_username": "alice",
+            "realm_access": {"roles": ["ops", "dev", "ops"]},
+        });
+
+        let info = mapping.to_user_info("keycloak", &claims).unwrap();
+        assert_eq!(info.provider_user_id, "f3a1");
+        assert_eq!(info.username, "alice");
+        assert_eq!(info.groups, vec!["ops", "dev", "ops"]);
+
+        let mut provider = ProviderConfig::github();
+        assert!(provider.map_roles(&info.groups).is_empty());
+        provider.role_mapping.insert("ops".to_string(), "运维".to_string());
+        assert_eq!(provider.map_roles(&info.groups), vec!["运维"]);
+        assert_eq!(provider.managed_roles(), vec!["运维"]);
+
+        assert!(mapping.to_user_info("keycloak", &serde_json::json!({"name": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let registry = ProviderRegistry::new(ProviderConfig::builtin());
+        assert_eq!(registry.get("linux-do").unwrap().name, "linux_do");
+        assert!(registry.get("keycloak").is_err());
+    }
+}
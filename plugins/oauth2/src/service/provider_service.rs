@@ -0,0 +1,178 @@
+//! 提供商配置管理
+
+use sea_orm::*;
+
+use crate::dto::*;
+use crate::entity::oauth_provider;
+use crate::error::OAuth2Error;
+use crate::service::provider::ProviderKind;
+use crate::service::token_cipher::TokenCipher;
+
+/// 提供商配置服务
+pub struct ProviderService;
+
+impl ProviderService {
+    /// 获取所有提供商配置
+    pub async fn get_all(db: &DatabaseConnection) -> Result<Vec<OAuthProviderDetail>, OAuth2Error> {
+        let models = oauth_provider::Entity::find()
+            .order_by_asc(oauth_provider::Column::Id)
+            .all(db)
+            .await?;
+
+        Ok(models.into_iter().map(Self::to_detail).collect())
+    }
+
+    /// 创建提供商
+    pub async fn create(
+        db: &DatabaseConnection,
+        cipher: &TokenCipher,
+        param: OAuthProviderParam,
+    ) -> Result<OAuthProviderDetail, OAuth2Error> {
+        Self::check_param(&param)?;
+
+        let exists = oauth_provider::Entity::find()
+            .filter(oauth_provider::Column::Name.eq(&param.name))
+            .one(db)
+            .await?;
+        if exists.is_some() {
+            return Err(OAuth2Error::ValidationError("提供商已存在".to_string()));
+        }
+
+        let mut model = oauth_provider::ActiveModel {
+            created_time: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        Self::apply_param(&mut model, cipher, param)?;
+
+        let result = model.insert(db).await?;
+        Ok(Self::to_detail(result))
+    }
+
+    /// 更新提供商
+    pub async fn update(
+        db: &DatabaseConnection,
+        cipher: &TokenCipher,
+        id: i64,
+        param: OAuthProviderParam,
+    ) -> Result<OAuthProviderDetail, OAuth2Error> {
+        Self::check_param(&param)?;
+
+        let model = oauth_provider::Entity::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(OAuth2Error::NotFound("提供商不存在".to_string()))?;
+        if model.name != param.name {
+            let duplicated = oauth_provider::Entity::find()
+                .filter(oauth_provider::Column::Name.eq(&param.name))
+                .one(db)
+                .await?;
+            if duplicated.is_some() {
+                return Err(OAuth2Error::ValidationError("提供商已存在".to_string()));
+            }
+        }
+
+        let mut model: oauth_provider::ActiveModel = model.into();
+        Self::apply_param(&mut model, cipher, param)?;
+        model.updated_time = Set(Some(chrono::Utc::now().naive_utc()));
+
+        let result = model.update(db).await?;
+        Ok(Self::to_detail(result))
+    }
+
+    /// 删除提供商
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), OAuth2Error> {
+        let result = oauth_provider::Entity::delete_by_id(id).exec(db).await?;
+        if result.rows_affected == 0 {
+            return Err(OAuth2Error::NotFound("提供商不存在".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 校验必填端点
+    fn check_param(param: &OAuthProviderParam) -> Result<(), OAuth2Error> {
+        if !param
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(OAuth2Error::ValidationError(
+                "提供商标识只能包含小写字母、数字和下划线".to_string(),
+            ));
+        }
+
+        let required: &[(&str, &Option<String>)] = match param.kind {
+            ProviderKind::Oidc => &[("issuer", &param.issuer)],
+            ProviderKind::OAuth2 => &[
+                ("auth_url", &param.auth_url),
+                ("token_url", &param.token_url),
+                ("userinfo_url", &param.userinfo_url),
+            ],
+        };
+        for (field, value) in required {
+            if value.as_deref().is_none_or(str::is_empty) {
+                return Err(OAuth2Error::ValidationError(format!("缺少 {}", field)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_param(
+        model: &mut oauth_provider::ActiveModel,
+        cipher: &TokenCipher,
+        param: OAuthProviderParam,
+    ) -> Result<(), OAuth2Error> {
+        let non_empty = |value: Option<String>| value.filter(|s| !s.is_empty());
+
+        model.name = Set(param.name);
+        model.kind = Set(param.kind.as_str().to_string());
+        model.client_id = Set(param.client_id);
+        if let Some(secret) = param.client_secret.filter(|s| !s.is_empty()) {
+            model.client_secret = Set(Some(cipher.encrypt(&secret)?));
+        }
+        model.redirect_uri = Set(non_empty(param.redirect_uri));
+        model.issuer = Set(non_empty(param.issuer));
+        model.auth_url = Set(non_empty(param.auth_url));
+        model.token_url = Set(non_empty(param.token_url));
+        model.userinfo_url = Set(non_empty(param.userinfo_url));
+        model.scopes = Set(non_empty(param.scopes));
+        model.claim_mapping = Set(param
+            .claim_mapping
+            .map(|m| serde_json::to_string(&m))
+            .transpose()
+            .map_err(|e| OAuth2Error::ValidationError(e.to_string()))?);
+        model.role_mapping = Set(param
+            .role_mapping
+            .map(|m| serde_json::to_string(&m))
+            .transpose()
+            .map_err(|e| OAuth2Error::ValidationError(e.to_string()))?);
+        model.status = Set(param.status.unwrap_or(1));
+
+        Ok(())
+    }
+
+    fn to_detail(model: oauth_provider::Model) -> OAuthProviderDetail {
+        let parse = |json: Option<String>| json.and_then(|s| serde_json::from_str(&s).ok());
+
+        OAuthProviderDetail {
+            id: model.id,
+            name: model.name,
+            kind: model.kind,
+            client_id: model.client_id,
+            has_client_secret: model.client_secret.is_some(),
+            redirect_uri: model.redirect_uri,
+            issuer: model.issuer,
+            auth_url: model.auth_url,
+            token_url: model.token_url,
+            userinfo_url: model.userinfo_url,
+            scopes: model.scopes,
+            claim_mapping: parse(model.claim_mapping),
+            role_mapping: parse(model.role_mapping),
+            status: model.status,
+            created_time: model.created_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            updated_time: model
+                .updated_time
+                .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! 授权状态存储
+//!
+//! 授权跳转时生成的 state 与 PKCE verifier、待关联的第三方身份均存放在 Redis 中，
+//! 读取即删除，保证一次性使用。
+
+use redis::{aio::ConnectionManager, AsyncCommands};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::dto::OAuthUserInfo;
+use crate::error::OAuth2Error;
+
+/// 授权动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthAction {
+    /// 第三方登录
+    Login,
+    /// 已登录用户绑定第三方账号
+    Bind,
+}
+
+/// 授权状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationState {
+    /// 提供商
+    pub provider: String,
+    /// PKCE verifier
+    pub pkce_verifier: String,
+    /// OIDC nonce
+    #[serde(default)]
+    pub nonce: Option<String>,
+    /// 授权动作
+    pub action: OAuthAction,
+    /// 发起绑定的用户ID
+    pub user_id: Option<i64>,
+}
+
+/// 待关联的第三方身份（未绑定且未开启自动创建用户时产生）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingLink {
+    /// 第三方用户信息
+    pub user_info: OAuthUserInfo,
+    /// 第三方令牌
+    pub token: ProviderToken,
+}
+
+/// 提供商令牌
+///
+/// 缓存期间令牌已加密，与落库格式一致
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProviderToken {
+    pub access_token: Option<String>,
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Redis 状态存储
+#[derive(Clone)]
+pub struct StateStore {
+    redis: ConnectionManager,
+    prefix: String,
+    ttl_seconds: u64,
+}
+
+impl StateStore {
+    pub fn new(redis: ConnectionManager, prefix: impl Into<String>, ttl_seconds: u64) -> Self {
+        Self {
+            redis,
+            prefix: prefix.into(),
+            ttl_seconds,
+        }
+    }
+
+    /// 状态有效期（秒）
+    pub fn ttl_seconds(&self) -> u64 {
+        self.ttl_seconds
+    }
+
+    /// 保存授权状态
+    pub async fn save_state(&self, csrf: &str, state: &AuthorizationState) -> Result<(), OAuth2Error> {
+        self.put(&state_key(&self.prefix, csrf), state).await
+    }
+
+    /// 取出授权状态（一次性）
+    pub async fn take_state(&self, csrf: &str) -> Result<Option<AuthorizationState>, OAuth2Error> {
+        self.take(&state_key(&self.prefix, csrf)).await
+    }
+
+    /// 保存待关联身份
+    pub async fn save_link(&self, ticket: &str, link: &PendingLink) -> Result<(), OAuth2Error> {
+        self.put(&link_key(&self.prefix, ticket), link).await
+    }
+
+    /// 取出待关联身份（一次性）
+    pub async fn take_link(&self, ticket: &str) -> Result<Option<PendingLink>, OAuth2Error> {
+        self.take(&link_key(&self.prefix, ticket)).await
+    }
+
+    async fn put<T: Serialize>(&self, key: &str, value: &T) -> Result<(), OAuth2Error> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| OAuth2Error::CacheError(e.to_string()))?;
+        let mut conn = self.redis.clone();
+        conn.set_ex::<_, _, ()>(key, json, self.ttl_seconds).await?;
+        Ok(())
+    }
+
+    async fn take<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, OAuth2Error> {
+        let mut conn = self.redis.clone();
+        let (value,): (Option<String>,) = redis::pipe()
+            .atomic()
+            .get(key)
+            .del(key)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+
+        value
+            .map(|json| serde_json::from_str(&json).map_err(|e| OAuth2Error::CacheError(e.to_string())))
+            .transpose()
+    }
+}
+
+fn state_key(prefix: &str, csrf: &str) -> String {
+    format!("{}:state:{}", prefix, csrf)
+}
+
+fn link_key(prefix: &str, ticket: &str) -> String {
+    format!("{}:link:{}", prefix, ticket)
+}
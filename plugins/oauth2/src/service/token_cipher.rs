@@ -0,0 +1,87 @@
+//! 第三方令牌加密
+//!
+//! 提供商下发的 access_token / refresh_token 使用 AES-256-GCM 加密后落库，
+//! 存储格式为 `base64(nonce || ciphertext)`，密钥由配置字符串经 SHA-256 派生。
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{engine::general_purpose, Engine as _};
+use sha2::{Digest, Sha256};
+
+use crate::error::OAuth2Error;
+
+/// GCM nonce 长度（字节）
+const NONCE_LEN: usize = 12;
+
+/// 令牌加解密器
+#[derive(Clone)]
+pub struct TokenCipher {
+    cipher: Aes256Gcm,
+}
+
+impl TokenCipher {
+    /// 由任意长度的密钥字符串创建
+    pub fn new(secret: &str) -> Self {
+        let key: Key<Aes256Gcm> = Sha256::digest(secret.as_bytes());
+        Self {
+            cipher: Aes256Gcm::new(&key),
+        }
+    }
+
+    /// 加密
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, OAuth2Error> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|e| OAuth2Error::CryptoError(e.to_string()))?;
+
+        let mut data = nonce.to_vec();
+        data.extend_from_slice(&ciphertext);
+        Ok(general_purpose::STANDARD.encode(data))
+    }
+
+    /// 解密
+    pub fn decrypt(&self, encoded: &str) -> Result<String, OAuth2Error> {
+        let data = general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| OAuth2Error::CryptoError(e.to_string()))?;
+        if data.len() <= NONCE_LEN {
+            return Err(OAuth2Error::CryptoError("密文长度不合法".to_string()));
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce: [u8; NONCE_LEN] = nonce.try_into().expect("nonce 长度已校验");
+        let plaintext = self
+            .cipher
+            .decrypt(&Nonce::from(nonce), ciphertext)
+            .map_err(|e| OAuth2Error::CryptoError(e.to_string()))?;
+
+        String::from_utf8(plaintext).map_err(|e| OAuth2Error::CryptoError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_roundtrip() {
+        let cipher = TokenCipher::new("test-secret");
+        let encrypted = cipher.encrypt("gho_abc123").unwrap();
+        assert_ne!(encrypted, "gho_abc123");
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "gho_abc123");
+
+        // 每次加密使用随机 nonce
+        assert_ne!(encrypted, cipher.encrypt("gho_abc123").unwrap());
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key() {
+        let encrypted = TokenCipher::new("key-a").encrypt("token").unwrap();
+        assert!(TokenCipher::new("key-b").decrypt(&encrypted).is_err());
+        assert!(TokenCipher::new("key-a").decrypt("not-base64!").is_err());
+    }
+}
@@ -79,7 +79,9 @@ values
 (2049629108257816585, '修改模型', 'EditGenCodeModel', null, 0, null, 2, null, 'codegen:model:edit', 1, 0, 1, '', null, 2049629108257816580, '2025-06-26 20:29:06', null),
 (2049629108257816586, '删除模型', 'DeleteGenCodeModel', null, 0, null, 2, null, 'codegen:model:del', 1, 0, 1, '', null, 2049629108257816580, '2025-06-26 20:29:06', null),
 (2049629108257816587, '导入', 'ImportGenCode', null, 0, null, 2, null, 'codegen:table:import', 1, 0, 1, '', null, 2049629108257816580, '2025-06-26 20:29:06', null),
-(2049629108257816588, '写入', 'WriteGenCode', null, 0, null, 2, null, 'codegen:local:write', 1, 0, 1, '', null, 2049629108257816580, '2025-06-26 20:29:06', null);
+(2049629108257816588, '写入', 'WriteGenCode', null, 0, null, 2, null, 'codegen:local:write', 1, 0, 1, '', null, 2049629108257816580, '2025-06-26 20:29:06', null),
+(2049629108257816589, '查看', 'ViewOnline', null, 0, null, 2, null, 'sys:monitor:online', 1, 0, 1, '', null, 2049629108253622274, '2025-06-26 20:29:06', null),
+(2049629108257816590, '任务状态', 'ViewTaskStatus', null, 0, null, 2, null, 'sys:monitor:task', 1, 0, 1, '', null, 2049629108249427984, '2025-06-26 20:29:06', null);
 
 insert into sys_role (id, name, status, is_filter_scopes, remark, created_time, updated_time)
 values (2048601269345583104, '测试', 1, true, null, now(), null);
@@ -88,7 +88,9 @@ values
 (74, '修改模型', 'EditGenCodeModel', null, 0, null, 2, null, 'codegen:model:edit', 1, 0, 1, '', null, 69, '2025-06-26 20:29:06', null),
 (75, '删除模型', 'DeleteGenCodeModel', null, 0, null, 2, null, 'codegen:model:del', 1, 0, 1, '', null, 69, '2025-06-26 20:29:06', null),
 (76, '导入', 'ImportGenCode', null, 0, null, 2, null, 'codegen:table:import', 1, 0, 1, '', null, 69, '2025-06-26 20:29:06', null),
-(77, '写入', 'WriteGenCode', null, 0, null, 2, null, 'codegen:local:write', 1, 0, 1, '', null, 69, '2025-06-26 20:29:06', null);
+(77, '写入', 'WriteGenCode', null, 0, null, 2, null, 'codegen:local:write', 1, 0, 1, '', null, 69, '2025-06-26 20:29:06', null),
+(78, '查看', 'ViewOnline', null, 0, null, 2, null, 'sys:monitor:online', 1, 0, 1, '', null, 46, '2025-06-26 20:29:06', null),
+(79, '任务状态', 'ViewTaskStatus', null, 0, null, 2, null, 'sys:monitor:task', 1, 0, 1, '', null, 36, '2025-06-26 20:29:06', null);
 
 -- 3. 插入角色数据
 insert into sys_role (id, name, status, is_filter_scopes, remark, created_time, updated_time)
@@ -1,9 +1,10 @@
 /// 访问日志查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct AccessLogPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -48,7 +49,7 @@ pub struct AccessLogPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum AccessLogSortField {
     /// 按ID排序
     Id,
@@ -87,7 +88,7 @@ impl std::fmt::Display for AccessLogSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum SortOrder {
     /// 升序
     Asc,
@@ -111,7 +112,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 访问日志分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AccessLogPaginationResponse {
     /// 访问日志列表
     pub list: Vec<AccessLogListItem>,
@@ -1,9 +1,10 @@
 /// 访问日志响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 访问日志详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct AccessLogDetailResponse {
     /// 日志ID
     pub id: i64,
@@ -54,7 +55,7 @@ pub struct AccessLogDetailResponse {
 }
 
 /// 访问日志列表项响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AccessLogListItem {
     /// 日志ID
     pub id: i64,
@@ -77,7 +78,7 @@ pub struct AccessLogListItem {
 }
 
 /// 访问日志统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AccessLogStatistics {
     /// 总访问次数
     pub total_count: usize,
@@ -104,7 +105,7 @@ pub struct AccessLogStatistics {
 }
 
 /// URL统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UrlStat {
     /// URL
     pub url: String,
@@ -115,7 +116,7 @@ pub struct UrlStat {
 }
 
 /// IP统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct IpStat {
     /// IP地址
     pub ip: String,
@@ -124,7 +125,7 @@ pub struct IpStat {
 }
 
 /// 方法统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MethodStat {
     /// HTTP方法
     pub method: String,
@@ -1,8 +1,9 @@
 /// 创建访问日志 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateAccessLogRequest {
     /// 用户ID
     pub user_id: Option<i64>,
@@ -49,7 +50,7 @@ pub struct CreateAccessLogRequest {
 }
 
 /// 访问日志创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateAccessLogResponse {
     /// 日志ID
     pub id: i64,
@@ -0,0 +1,118 @@
+/// 告警规则、事件和静默管理API
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+use crate::app::alert::dto::{AlertEventQuery, CreateAlertSilenceRequest, SaveAlertRuleRequest, TestAlertRuleRequest};
+use crate::app::alert::service::AlertService;
+use crate::common::exception::AppError;
+use crate::common::response::{api_response, ApiResult};
+use crate::database::DatabaseManager;
+use crate::middleware::jwt_auth_middleware::AuthContext;
+
+async fn alert_service() -> AlertService {
+    AlertService::new(DatabaseManager::get_connection().await.clone())
+}
+
+fn validate<T: Validate>(request: &T) -> Result<(), AppError> {
+    request
+        .validate()
+        .map_err(AppError::from)
+}
+
+/// 获取规则列表
+/// GET /api/v1/sys/alerts/rules
+pub async fn get_alert_rules() -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.get_rules().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取规则详情
+/// GET /api/v1/sys/alerts/rules/{id}
+pub async fn get_alert_rule(Path(id): Path<i64>) -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.get_rule(id).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 创建规则
+/// POST /api/v1/sys/alerts/rules
+pub async fn create_alert_rule(Json(request): Json<SaveAlertRuleRequest>) -> ApiResult<impl IntoResponse> {
+    validate(&request)?;
+    let result = alert_service().await.create_rule(&request).await?;
+    Ok((StatusCode::CREATED, Json(api_response(result))))
+}
+
+/// 更新规则
+/// PUT /api/v1/sys/alerts/rules/{id}
+pub async fn update_alert_rule(
+    Path(id): Path<i64>,
+    Json(request): Json<SaveAlertRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    validate(&request)?;
+    let result = alert_service().await.update_rule(id, &request).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 删除规则
+/// DELETE /api/v1/sys/alerts/rules/{id}
+pub async fn delete_alert_rule(Path(id): Path<i64>) -> ApiResult<impl IntoResponse> {
+    alert_service().await.delete_rule(id).await?;
+    Ok((StatusCode::OK, Json(api_response("规则删除成功".to_string()))))
+}
+
+/// 使用合成数据试运行规则（不写入事件、不发送通知）
+/// POST /api/v1/sys/alerts/rules/{id}/test
+pub async fn test_alert_rule(
+    Path(id): Path<i64>,
+    Json(request): Json<TestAlertRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.test_rule(id, &request).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 分页查询告警事件
+/// GET /api/v1/sys/alerts/events
+pub async fn get_alert_events(Query(query): Query<AlertEventQuery>) -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.get_events(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 确认告警
+/// POST /api/v1/sys/alerts/events/{id}/ack
+pub async fn ack_alert_event(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.ack_event(id, &auth.username).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取未过期的静默
+/// GET /api/v1/sys/alerts/silences
+pub async fn get_alert_silences() -> ApiResult<impl IntoResponse> {
+    let result = alert_service().await.get_silences().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 创建静默
+/// POST /api/v1/sys/alerts/silences
+pub async fn create_alert_silence(
+    Extension(auth): Extension<AuthContext>,
+    Json(request): Json<CreateAlertSilenceRequest>,
+) -> ApiResult<impl IntoResponse> {
+    validate(&request)?;
+    let result = alert_service().await.create_silence(&request, &auth.username).await?;
+    Ok((StatusCode::CREATED, Json(api_response(result))))
+}
+
+/// 删除静默（提前结束）
+/// DELETE /api/v1/sys/alerts/silences/{id}
+pub async fn delete_alert_silence(Path(id): Path<i64>) -> ApiResult<impl IntoResponse> {
+    alert_service().await.delete_silence(id).await?;
+    Ok((StatusCode::OK, Json(api_response("静默已删除".to_string()))))
+}
@@ -0,0 +1,5 @@
+/// 告警API模块
+
+pub mod alert;
+
+pub use alert::*;
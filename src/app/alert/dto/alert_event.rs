@@ -0,0 +1,69 @@
+/// 告警事件和静默 DTO
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 告警事件查询参数
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AlertEventQuery {
+    /// 状态（firing / resolved）
+    pub status: Option<String>,
+    /// 规则ID
+    pub rule_id: Option<i64>,
+    /// 页码
+    pub page: Option<u64>,
+    /// 每页数量
+    pub size: Option<u64>,
+}
+
+/// 告警事件详情
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertEventDetail {
+    pub id: i64,
+    pub rule_id: i64,
+    pub rule_name: String,
+    pub severity: String,
+    pub host_name: Option<String>,
+    pub metric_name: Option<String>,
+    pub status: String,
+    pub value: f64,
+    pub threshold: f64,
+    pub started_time: DateTime<Utc>,
+    pub resolved_time: Option<DateTime<Utc>>,
+    pub acked_by: Option<String>,
+    pub acked_time: Option<DateTime<Utc>>,
+    /// 是否处于静默期
+    pub silenced: bool,
+}
+
+/// 创建静默请求
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateAlertSilenceRequest {
+    /// 限定规则（为空表示所有规则）
+    pub rule_id: Option<i64>,
+    /// 限定主机（为空表示所有主机）
+    #[validate(length(min = 1, max = 100))]
+    pub host_name: Option<String>,
+    /// 开始时间（为空表示立即生效）
+    pub starts_time: Option<DateTime<Utc>>,
+    /// 结束时间
+    pub ends_time: DateTime<Utc>,
+    /// 原因
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+/// 静默详情
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertSilenceDetail {
+    pub id: i64,
+    pub rule_id: Option<i64>,
+    pub host_name: Option<String>,
+    pub starts_time: DateTime<Utc>,
+    pub ends_time: DateTime<Utc>,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
+    pub created_time: DateTime<Utc>,
+}
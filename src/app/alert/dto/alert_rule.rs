@@ -0,0 +1,145 @@
+/// 告警规则 DTO
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 创建或更新告警规则请求
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SaveAlertRuleRequest {
+    /// 规则名称
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    /// 数据来源（metric / login_failure_rate / opera_error_rate）
+    pub source: String,
+
+    /// 指标类型（1:CPU 2:内存 3:磁盘 4:网络，仅 metric 来源）
+    #[validate(range(min = 1, max = 4))]
+    pub metric_type: Option<i32>,
+
+    /// 指标名称，以 * 结尾表示前缀匹配，如 disk_usage:*（仅 metric 来源）
+    #[validate(length(min = 1, max = 100))]
+    pub metric_name: Option<String>,
+
+    /// 限定主机（仅 metric 来源，为空表示所有主机）
+    #[validate(length(min = 1, max = 100))]
+    pub host_name: Option<String>,
+
+    /// 比较符（> >= < <= == !=）
+    pub comparator: String,
+
+    /// 阈值（比率类来源为百分比）
+    pub threshold: f64,
+
+    /// 持续时间窗口（秒）：metric 来源要求窗口内所有样本均满足条件，比率类来源按窗口内日志计算比率
+    #[validate(range(min = 1, max = 604800))]
+    pub duration_seconds: i32,
+
+    /// 窗口内最少样本数（比率类来源为最少日志条数）
+    #[serde(default = "default_min_samples")]
+    #[validate(range(min = 1))]
+    pub min_samples: i32,
+
+    /// 严重级别（info / warning / critical）
+    pub severity: String,
+
+    /// 未确认时重复通知间隔（分钟，0 表示不重复）
+    #[serde(default)]
+    #[validate(range(min = 0))]
+    pub repeat_minutes: i32,
+
+    /// 邮件收件人
+    #[serde(default)]
+    pub notify_emails: Vec<String>,
+
+    /// 站内通知投放的角色ID
+    #[serde(default)]
+    pub notice_role_ids: Vec<i64>,
+
+    /// Webhook 地址（POST JSON）
+    #[validate(url)]
+    pub webhook_url: Option<String>,
+
+    /// 状态（0: 禁用, 1: 启用）
+    pub status: Option<i32>,
+
+    /// 备注
+    pub remark: Option<String>,
+}
+
+fn default_min_samples() -> i32 {
+    1
+}
+
+/// 告警规则详情
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertRuleDetail {
+    pub id: i64,
+    pub name: String,
+    pub source: String,
+    pub metric_type: Option<i32>,
+    pub metric_name: Option<String>,
+    pub host_name: Option<String>,
+    pub comparator: String,
+    pub threshold: f64,
+    pub duration_seconds: i32,
+    pub min_samples: i32,
+    pub severity: String,
+    pub repeat_minutes: i32,
+    pub notify_emails: Vec<String>,
+    pub notice_role_ids: Vec<i64>,
+    pub webhook_url: Option<String>,
+    pub status: i32,
+    pub remark: Option<String>,
+    pub created_time: DateTime<Utc>,
+    pub updated_time: Option<DateTime<Utc>>,
+}
+
+/// 合成样本（用于规则试运行）
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SyntheticSample {
+    /// 主机名
+    pub host_name: String,
+    /// 指标名称
+    pub metric_name: String,
+    /// 指标值
+    pub value: f64,
+    /// 采样时间（为空表示当前时间）
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// 规则试运行请求
+///
+/// metric 来源使用 samples；比率类来源使用 failures / total
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TestAlertRuleRequest {
+    #[serde(default)]
+    pub samples: Vec<SyntheticSample>,
+    pub failures: Option<u64>,
+    pub total: Option<u64>,
+}
+
+/// 单个序列的评估结果
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AlertObservation {
+    /// 规则内的序列标识
+    pub fingerprint: String,
+    pub host_name: Option<String>,
+    pub metric_name: Option<String>,
+    /// 最近样本值（比率类来源为百分比）
+    pub value: f64,
+    /// 窗口内样本数
+    pub samples: u64,
+    /// 是否满足触发条件
+    pub breached: bool,
+}
+
+/// 规则试运行结果
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TestAlertRuleResponse {
+    pub observations: Vec<AlertObservation>,
+    /// 按当前告警状态将执行的动作（fire / resolve / repeat / none）
+    pub actions: Vec<String>,
+}
@@ -0,0 +1,7 @@
+/// 告警相关 DTO
+
+pub mod alert_event;
+pub mod alert_rule;
+
+pub use alert_event::*;
+pub use alert_rule::*;
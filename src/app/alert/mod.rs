@@ -0,0 +1,11 @@
+/// 告警模块
+/// 基于系统指标和登录/操作日志比率的阈值告警：规则管理、定时评估、触发/恢复状态、
+/// 邮件/站内通知/Webhook 通知、静默和确认
+
+pub mod api;
+pub mod dto;
+pub mod router;
+pub mod service;
+
+pub use dto::*;
+pub use router::*;
@@ -0,0 +1,21 @@
+/// 组装告警相关路由
+
+use axum::{routing::{delete, get, post, put}, Router};
+
+use crate::app::alert::api;
+
+/// 告警管理，挂载到 `/api/v1/sys/alerts`
+pub fn alert_routes() -> Router {
+    Router::new()
+        .route("/rules", get(api::get_alert_rules))  // GET /api/v1/sys/alerts/rules
+        .route("/rules", post(api::create_alert_rule))  // POST /api/v1/sys/alerts/rules
+        .route("/rules/{id}", get(api::get_alert_rule))  // GET /api/v1/sys/alerts/rules/{id}
+        .route("/rules/{id}", put(api::update_alert_rule))  // PUT /api/v1/sys/alerts/rules/{id}
+        .route("/rules/{id}", delete(api::delete_alert_rule))  // DELETE /api/v1/sys/alerts/rules/{id}
+        .route("/rules/{id}/test", post(api::test_alert_rule))  // POST /api/v1/sys/alerts/rules/{id}/test
+        .route("/events", get(api::get_alert_events))  // GET /api/v1/sys/alerts/events
+        .route("/events/{id}/ack", post(api::ack_alert_event))  // POST /api/v1/sys/alerts/events/{id}/ack
+        .route("/silences", get(api::get_alert_silences))  // GET /api/v1/sys/alerts/silences
+        .route("/silences", post(api::create_alert_silence))  // POST /api/v1/sys/alerts/silences
+        .route("/silences/{id}", delete(api::delete_alert_silence))  // DELETE /api/v1/sys/alerts/silences/{id}
+}
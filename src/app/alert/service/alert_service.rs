@@ -0,0 +1,555 @@
+/// 告警服务
+/// 规则管理、定时评估、事件状态维护、静默和确认
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use tracing::{error, info};
+
+use crate::app::alert::dto::{
+    AlertEventDetail, AlertEventQuery, AlertObservation, AlertRuleDetail, AlertSilenceDetail,
+    CreateAlertSilenceRequest, SaveAlertRuleRequest, SyntheticSample, TestAlertRuleRequest,
+    TestAlertRuleResponse,
+};
+use crate::app::alert::service::evaluator::{
+    evaluate_metric, evaluate_rate, metric_name_matches, transition, Comparator, Transition, SEVERITIES, SOURCES,
+    SOURCE_LOGIN_FAILURE_RATE, SOURCE_METRIC, SOURCE_OPERA_ERROR_RATE,
+};
+use crate::app::alert::service::notifier::AlertNotifier;
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::pagination::PageData;
+use crate::core::SETTINGS;
+use crate::database::entity::alert_event::{STATUS_FIRING, STATUS_RESOLVED};
+use crate::database::entity::{alert_event, alert_rule, alert_silence, login_log, opera_log, system_metric};
+use crate::database::DatabaseManager;
+
+pub struct AlertService {
+    db: DatabaseConnection,
+}
+
+impl AlertService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 启动定时评估任务（ALERT_ENABLED=false 时不启动）
+    pub fn spawn_evaluator() {
+        if !SETTINGS.alert_enabled {
+            info!("告警评估已禁用");
+            return;
+        }
+
+        tokio::spawn(async {
+            let period = StdDuration::from_secs(SETTINGS.alert_evaluate_interval_seconds.max(1));
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                crate::core::health::beat("alert_evaluator", period);
+                let service = Self::new(DatabaseManager::get_connection().await.clone());
+                if let Err(e) = service.evaluate_all().await {
+                    error!("告警评估失败: {}", e.message);
+                }
+            }
+        });
+    }
+
+    /// 获取所有规则
+    pub async fn get_rules(&self) -> Result<Vec<AlertRuleDetail>, AppError> {
+        let models = alert_rule::Entity::find()
+            .order_by_asc(alert_rule::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(to_rule_detail).collect())
+    }
+
+    /// 获取规则详情
+    pub async fn get_rule(&self, id: i64) -> Result<AlertRuleDetail, AppError> {
+        Ok(to_rule_detail(self.find_rule(id).await?))
+    }
+
+    /// 创建规则
+    pub async fn create_rule(&self, request: &SaveAlertRuleRequest) -> Result<AlertRuleDetail, AppError> {
+        check_rule(request)?;
+
+        let model = alert_rule::ActiveModel {
+            created_time: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        let model = apply_request(model, request)?.insert(&self.db).await?;
+
+        Ok(to_rule_detail(model))
+    }
+
+    /// 更新规则
+    pub async fn update_rule(&self, id: i64, request: &SaveAlertRuleRequest) -> Result<AlertRuleDetail, AppError> {
+        check_rule(request)?;
+
+        let mut model: alert_rule::ActiveModel = self.find_rule(id).await?.into();
+        model.updated_time = Set(Some(Utc::now().naive_utc()));
+        let model = apply_request(model, request)?.update(&self.db).await?;
+
+        // 禁用后不再评估，触发中的告警直接恢复
+        if model.status != 1 {
+            self.resolve_rule_events(id).await?;
+        }
+        Ok(to_rule_detail(model))
+    }
+
+    /// 删除规则，触发中的告警直接恢复，历史事件保留
+    pub async fn delete_rule(&self, id: i64) -> Result<(), AppError> {
+        self.find_rule(id).await?;
+        alert_rule::Entity::delete_by_id(id).exec(&self.db).await?;
+        self.resolve_rule_events(id).await
+    }
+
+    /// 分页查询告警事件
+    pub async fn get_events(&self, query: &AlertEventQuery) -> Result<PageData<AlertEventDetail>, AppError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let size = query.size.unwrap_or(20).clamp(1, 100);
+
+        let mut select = alert_event::Entity::find();
+        if let Some(status) = &query.status {
+            select = select.filter(alert_event::Column::Status.eq(status.as_str()));
+        }
+        if let Some(rule_id) = query.rule_id {
+            select = select.filter(alert_event::Column::RuleId.eq(rule_id));
+        }
+
+        let paginator = select
+            .order_by_desc(alert_event::Column::StartedTime)
+            .paginate(&self.db, size);
+        let total = paginator.num_items().await?;
+        let models = paginator.fetch_page(page - 1).await?;
+
+        let now = Utc::now().naive_utc();
+        let silences = self.active_silences(now).await?;
+        let items = models
+            .into_iter()
+            .map(|m| {
+                let silenced = m.status == STATUS_FIRING && is_silenced(&silences, m.rule_id, m.host_name.as_deref(), now);
+                to_event_detail(m, silenced)
+            })
+            .collect();
+
+        Ok(PageData::new(items, total as i64, page as i64, size as i64))
+    }
+
+    /// 确认告警，确认后不再重复通知
+    pub async fn ack_event(&self, id: i64, username: &str) -> Result<AlertEventDetail, AppError> {
+        let event = alert_event::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "告警事件不存在"))?;
+        if event.status != STATUS_FIRING {
+            return Err(AppError::with_message(ErrorCode::BadRequest, "只能确认触发中的告警"));
+        }
+
+        let mut model: alert_event::ActiveModel = event.into();
+        model.acked_by = Set(Some(username.to_string()));
+        model.acked_time = Set(Some(Utc::now().naive_utc()));
+        let model = model.update(&self.db).await?;
+
+        Ok(to_event_detail(model, false))
+    }
+
+    /// 获取未过期的静默
+    pub async fn get_silences(&self) -> Result<Vec<AlertSilenceDetail>, AppError> {
+        let models = alert_silence::Entity::find()
+            .filter(alert_silence::Column::EndsTime.gt(Utc::now().naive_utc()))
+            .order_by_asc(alert_silence::Column::StartsTime)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(to_silence_detail).collect())
+    }
+
+    /// 创建静默
+    pub async fn create_silence(
+        &self,
+        request: &CreateAlertSilenceRequest,
+        username: &str,
+    ) -> Result<AlertSilenceDetail, AppError> {
+        let now = Utc::now();
+        let starts_time = request.starts_time.unwrap_or(now);
+        if request.ends_time <= starts_time || request.ends_time <= now {
+            return Err(AppError::with_message(ErrorCode::ValidationError, "结束时间必须晚于开始时间和当前时间"));
+        }
+        if let Some(rule_id) = request.rule_id {
+            self.find_rule(rule_id).await?;
+        }
+
+        let model = alert_silence::ActiveModel {
+            rule_id: Set(request.rule_id),
+            host_name: Set(request.host_name.clone()),
+            starts_time: Set(starts_time.naive_utc()),
+            ends_time: Set(request.ends_time.naive_utc()),
+            reason: Set(request.reason.clone()),
+            created_by: Set(Some(username.to_string())),
+            created_time: Set(now.naive_utc()),
+            ..Default::default()
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(to_silence_detail(model))
+    }
+
+    /// 删除静默（提前结束）
+    pub async fn delete_silence(&self, id: i64) -> Result<(), AppError> {
+        let result = alert_silence::Entity::delete_by_id(id).exec(&self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(AppError::with_message(ErrorCode::NotFound, "静默规则不存在"));
+        }
+        Ok(())
+    }
+
+    /// 使用合成数据试运行规则，只返回评估结果和将执行的动作，不写入事件也不发送通知
+    pub async fn test_rule(&self, id: i64, request: &TestAlertRuleRequest) -> Result<TestAlertRuleResponse, AppError> {
+        let rule = self.find_rule(id).await?;
+        let comparator = Comparator::parse(&rule.comparator)?;
+        let min_samples = rule.min_samples.max(1) as u64;
+
+        let observations = if rule.source == SOURCE_METRIC {
+            // 与实际评估一致，只保留规则限定的主机和指标
+            let samples: Vec<SyntheticSample> = request
+                .samples
+                .iter()
+                .filter(|s| rule.host_name.as_deref().is_none_or(|host| host == s.host_name))
+                .filter(|s| metric_name_matches(rule.metric_name.as_deref(), &s.metric_name))
+                .cloned()
+                .collect();
+            evaluate_metric(rule.id, comparator, rule.threshold, min_samples, &samples)
+        } else {
+            let (Some(failures), Some(total)) = (request.failures, request.total) else {
+                return Err(AppError::with_message(ErrorCode::ValidationError, "比率类规则需要提供 failures 和 total"));
+            };
+            if failures > total {
+                return Err(AppError::with_message(ErrorCode::ValidationError, "failures 不能大于 total"));
+            }
+            vec![evaluate_rate(rule.id, &rule.source, comparator, rule.threshold, min_samples, failures, total)]
+        };
+
+        let firing = self.firing_events(rule.id).await?;
+        let now = Utc::now().naive_utc();
+        let actions = observations
+            .iter()
+            .map(|obs| {
+                transition(firing.get(&obs.fingerprint), obs.breached, rule.repeat_minutes, now)
+                    .as_str()
+                    .to_string()
+            })
+            .collect();
+
+        Ok(TestAlertRuleResponse { observations, actions })
+    }
+
+    /// 评估所有启用的规则，返回发生状态变化的告警数
+    pub async fn evaluate_all(&self) -> Result<usize, AppError> {
+        let rules = alert_rule::Entity::find()
+            .filter(alert_rule::Column::Status.eq(1))
+            .all(&self.db)
+            .await?;
+        let now = Utc::now().naive_utc();
+        let silences = self.active_silences(now).await?;
+        let notifier = AlertNotifier::new(self.db.clone());
+
+        let mut changed = 0;
+        for rule in rules {
+            let observations = match self.observe(&rule, now).await {
+                Ok(observations) => observations,
+                Err(e) => {
+                    error!("告警规则 {} 评估失败: {}", rule.id, e.message);
+                    continue;
+                }
+            };
+            changed += self.apply(&rule, observations, &silences, &notifier, now).await?;
+        }
+        Ok(changed)
+    }
+
+    /// 查询规则窗口内的数据并评估
+    async fn observe(&self, rule: &alert_rule::Model, now: NaiveDateTime) -> Result<Vec<AlertObservation>, AppError> {
+        let comparator = Comparator::parse(&rule.comparator)?;
+        let min_samples = rule.min_samples.max(1) as u64;
+        let since = now - Duration::seconds(rule.duration_seconds as i64);
+
+        match rule.source.as_str() {
+            SOURCE_METRIC => {
+                let mut select = system_metric::Entity::find()
+                    .filter(system_metric::Column::CollectionTime.gte(since.and_utc()));
+                if let Some(metric_type) = rule.metric_type {
+                    select = select.filter(system_metric::Column::MetricType.eq(metric_type));
+                }
+                if let Some(host_name) = &rule.host_name {
+                    select = select.filter(system_metric::Column::HostName.eq(host_name.as_str()));
+                }
+                if let Some(metric_name) = &rule.metric_name {
+                    select = match metric_name.strip_suffix('*') {
+                        Some(prefix) => select.filter(system_metric::Column::MetricName.starts_with(prefix)),
+                        None => select.filter(system_metric::Column::MetricName.eq(metric_name.as_str())),
+                    };
+                }
+
+                let samples: Vec<SyntheticSample> = select
+                    .all(&self.db)
+                    .await?
+                    .into_iter()
+                    .map(|m| SyntheticSample {
+                        host_name: m.host_name,
+                        metric_name: m.metric_name,
+                        value: m.metric_value,
+                        time: Some(m.collection_time),
+                    })
+                    .collect();
+                Ok(evaluate_metric(rule.id, comparator, rule.threshold, min_samples, &samples))
+            }
+            SOURCE_LOGIN_FAILURE_RATE => {
+                let window = login_log::Entity::find().filter(login_log::Column::LoginTime.gte(since));
+                let total = window.clone().count(&self.db).await?;
+                let failures = window.filter(login_log::Column::Status.eq(0)).count(&self.db).await?;
+                Ok(vec![evaluate_rate(rule.id, &rule.source, comparator, rule.threshold, min_samples, failures, total)])
+            }
+            SOURCE_OPERA_ERROR_RATE => {
+                let window = opera_log::Entity::find().filter(opera_log::Column::OperaTime.gte(since));
+                let total = window.clone().count(&self.db).await?;
+                let failures = window.filter(opera_log::Column::Status.eq(0)).count(&self.db).await?;
+                Ok(vec![evaluate_rate(rule.id, &rule.source, comparator, rule.threshold, min_samples, failures, total)])
+            }
+            other => Err(AppError::with_message(ErrorCode::ValidationError, format!("未知的数据来源: {}", other))),
+        }
+    }
+
+    /// 根据评估结果更新事件并发送通知，窗口内已无数据的触发中序列视为恢复
+    async fn apply(
+        &self,
+        rule: &alert_rule::Model,
+        observations: Vec<AlertObservation>,
+        silences: &[alert_silence::Model],
+        notifier: &AlertNotifier,
+        now: NaiveDateTime,
+    ) -> Result<usize, AppError> {
+        let firing = self.firing_events(rule.id).await?;
+        let seen: HashSet<String> = observations.iter().map(|obs| obs.fingerprint.clone()).collect();
+
+        let mut changes: Vec<(Option<&alert_event::Model>, AlertObservation, Transition)> = observations
+            .into_iter()
+            .map(|obs| {
+                let current = firing.get(&obs.fingerprint);
+                let action = transition(current, obs.breached, rule.repeat_minutes, now);
+                (current, obs, action)
+            })
+            .collect();
+        for event in firing.values().filter(|e| !seen.contains(&e.fingerprint)) {
+            let obs = AlertObservation {
+                fingerprint: event.fingerprint.clone(),
+                host_name: event.host_name.clone(),
+                metric_name: event.metric_name.clone(),
+                value: event.value,
+                samples: 0,
+                breached: false,
+            };
+            changes.push((Some(event), obs, Transition::Resolve));
+        }
+
+        let mut changed = 0;
+        for (current, obs, action) in changes {
+            let silenced = is_silenced(silences, rule.id, obs.host_name.as_deref(), now);
+            let event = match (action, current) {
+                (Transition::Fire, _) => {
+                    alert_event::ActiveModel {
+                        rule_id: Set(rule.id),
+                        rule_name: Set(rule.name.clone()),
+                        severity: Set(rule.severity.clone()),
+                        fingerprint: Set(obs.fingerprint),
+                        host_name: Set(obs.host_name),
+                        metric_name: Set(obs.metric_name),
+                        status: Set(STATUS_FIRING.to_string()),
+                        value: Set(obs.value),
+                        threshold: Set(rule.threshold),
+                        started_time: Set(now),
+                        last_notified_time: Set((!silenced).then_some(now)),
+                        ..Default::default()
+                    }
+                    .insert(&self.db)
+                    .await?
+                }
+                (Transition::Repeat, Some(current)) if !silenced => {
+                    let mut model: alert_event::ActiveModel = current.clone().into();
+                    model.value = Set(obs.value);
+                    model.last_notified_time = Set(Some(now));
+                    model.update(&self.db).await?
+                }
+                (Transition::Resolve, Some(current)) => {
+                    let mut model: alert_event::ActiveModel = current.clone().into();
+                    model.status = Set(STATUS_RESOLVED.to_string());
+                    model.value = Set(obs.value);
+                    model.resolved_time = Set(Some(now));
+                    let model = model.update(&self.db).await?;
+                    // 触发时未通知过（一直处于静默）的告警，恢复时也不通知
+                    if current.last_notified_time.is_none() {
+                        changed += 1;
+                        continue;
+                    }
+                    model
+                }
+                _ => continue,
+            };
+
+            changed += 1;
+            if silenced {
+                info!("告警 {} 处于静默期，跳过通知", event.fingerprint);
+            } else {
+                notifier.notify(rule, &event, action).await;
+            }
+        }
+        Ok(changed)
+    }
+
+    async fn find_rule(&self, id: i64) -> Result<alert_rule::Model, AppError> {
+        alert_rule::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "告警规则不存在"))
+    }
+
+    /// 规则下触发中的事件，按序列标识索引
+    async fn firing_events(&self, rule_id: i64) -> Result<HashMap<String, alert_event::Model>, AppError> {
+        let events = alert_event::Entity::find()
+            .filter(alert_event::Column::RuleId.eq(rule_id))
+            .filter(alert_event::Column::Status.eq(STATUS_FIRING))
+            .all(&self.db)
+            .await?;
+
+        Ok(events.into_iter().map(|e| (e.fingerprint.clone(), e)).collect())
+    }
+
+    async fn active_silences(&self, now: NaiveDateTime) -> Result<Vec<alert_silence::Model>, AppError> {
+        Ok(alert_silence::Entity::find()
+            .filter(alert_silence::Column::StartsTime.lte(now))
+            .filter(alert_silence::Column::EndsTime.gt(now))
+            .all(&self.db)
+            .await?)
+    }
+
+    async fn resolve_rule_events(&self, rule_id: i64) -> Result<(), AppError> {
+        alert_event::Entity::update_many()
+            .col_expr(alert_event::Column::Status, STATUS_RESOLVED.into())
+            .col_expr(alert_event::Column::ResolvedTime, Some(Utc::now().naive_utc()).into())
+            .filter(alert_event::Column::RuleId.eq(rule_id))
+            .filter(alert_event::Column::Status.eq(STATUS_FIRING))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+fn is_silenced(silences: &[alert_silence::Model], rule_id: i64, host_name: Option<&str>, now: NaiveDateTime) -> bool {
+    silences.iter().any(|s| s.matches(rule_id, host_name, now))
+}
+
+fn check_rule(request: &SaveAlertRuleRequest) -> Result<(), AppError> {
+    let invalid = |message: String| Err(AppError::with_message(ErrorCode::ValidationError, message));
+
+    if !SOURCES.contains(&request.source.as_str()) {
+        return invalid(format!("不支持的数据来源: {}", request.source));
+    }
+    if !SEVERITIES.contains(&request.severity.as_str()) {
+        return invalid(format!("不支持的严重级别: {}", request.severity));
+    }
+    Comparator::parse(&request.comparator)?;
+    if request.source == SOURCE_METRIC && request.metric_type.is_none() && request.metric_name.is_none() {
+        return invalid("指标类规则需要指定指标类型或指标名称".to_string());
+    }
+    if let Some(email) = request.notify_emails.iter().find(|e| !e.contains('@')) {
+        return invalid(format!("邮箱格式不正确: {}", email));
+    }
+    Ok(())
+}
+
+fn apply_request(
+    mut model: alert_rule::ActiveModel,
+    request: &SaveAlertRuleRequest,
+) -> Result<alert_rule::ActiveModel, AppError> {
+    // 比率类规则不按主机和指标过滤
+    let is_metric = request.source == SOURCE_METRIC;
+
+    model.name = Set(request.name.clone());
+    model.source = Set(request.source.clone());
+    model.metric_type = Set(request.metric_type.filter(|_| is_metric));
+    model.metric_name = Set(request.metric_name.clone().filter(|_| is_metric));
+    model.host_name = Set(request.host_name.clone().filter(|_| is_metric));
+    model.comparator = Set(request.comparator.clone());
+    model.threshold = Set(request.threshold);
+    model.duration_seconds = Set(request.duration_seconds);
+    model.min_samples = Set(request.min_samples);
+    model.severity = Set(request.severity.clone());
+    model.repeat_minutes = Set(request.repeat_minutes);
+    model.notify_emails = Set(Some(serde_json::to_string(&request.notify_emails)?));
+    model.notice_role_ids = Set(Some(serde_json::to_string(&request.notice_role_ids)?));
+    model.webhook_url = Set(request.webhook_url.clone().filter(|url| !url.is_empty()));
+    model.status = Set(request.status.unwrap_or(1));
+    model.remark = Set(request.remark.clone());
+    Ok(model)
+}
+
+fn to_rule_detail(model: alert_rule::Model) -> AlertRuleDetail {
+    AlertRuleDetail {
+        id: model.id,
+        name: model.name,
+        source: model.source,
+        metric_type: model.metric_type,
+        metric_name: model.metric_name,
+        host_name: model.host_name,
+        comparator: model.comparator,
+        threshold: model.threshold,
+        duration_seconds: model.duration_seconds,
+        min_samples: model.min_samples,
+        severity: model.severity,
+        repeat_minutes: model.repeat_minutes,
+        notify_emails: model.notify_emails.as_deref().and_then(|s| serde_json::from_str(s).ok()).unwrap_or_default(),
+        notice_role_ids: model.notice_role_ids.as_deref().and_then(|s| serde_json::from_str(s).ok()).unwrap_or_default(),
+        webhook_url: model.webhook_url,
+        status: model.status,
+        remark: model.remark,
+        created_time: model.created_time.and_utc(),
+        updated_time: model.updated_time.map(|t| t.and_utc()),
+    }
+}
+
+fn to_event_detail(model: alert_event::Model, silenced: bool) -> AlertEventDetail {
+    AlertEventDetail {
+        id: model.id,
+        rule_id: model.rule_id,
+        rule_name: model.rule_name,
+        severity: model.severity,
+        host_name: model.host_name,
+        metric_name: model.metric_name,
+        status: model.status,
+        value: model.value,
+        threshold: model.threshold,
+        started_time: model.started_time.and_utc(),
+        resolved_time: model.resolved_time.map(|t| t.and_utc()),
+        acked_by: model.acked_by,
+        acked_time: model.acked_time.map(|t| t.and_utc()),
+        silenced,
+    }
+}
+
+fn to_silence_detail(model: alert_silence::Model) -> AlertSilenceDetail {
+    AlertSilenceDetail {
+        id: model.id,
+        rule_id: model.rule_id,
+        host_name: model.host_name,
+        starts_time: model.starts_time.and_utc(),
+        ends_time: model.ends_time.and_utc(),
+        reason: model.reason,
+        created_by: model.created_by,
+        created_time: model.created_time.and_utc(),
+    }
+}
@@ -0,0 +1,305 @@
+/// 告警规则评估
+/// 只依赖输入样本和当前告警状态，不访问数据库，便于用合成数据测试
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::app::alert::dto::{AlertObservation, SyntheticSample};
+use crate::common::exception::{AppError, ErrorCode};
+use crate::database::entity::alert_event;
+
+/// 系统指标
+pub const SOURCE_METRIC: &str = "metric";
+/// 登录失败率（sys_login_log）
+pub const SOURCE_LOGIN_FAILURE_RATE: &str = "login_failure_rate";
+/// 操作失败率（sys_opera_log）
+pub const SOURCE_OPERA_ERROR_RATE: &str = "opera_error_rate";
+
+pub const SOURCES: &[&str] = &[SOURCE_METRIC, SOURCE_LOGIN_FAILURE_RATE, SOURCE_OPERA_ERROR_RATE];
+pub const SEVERITIES: &[&str] = &["info", "warning", "critical"];
+
+/// 比较符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Eq,
+    Ne,
+}
+
+impl Comparator {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value {
+            ">" => Ok(Self::Gt),
+            ">=" => Ok(Self::Gte),
+            "<" => Ok(Self::Lt),
+            "<=" => Ok(Self::Lte),
+            "==" => Ok(Self::Eq),
+            "!=" => Ok(Self::Ne),
+            other => Err(AppError::with_message(
+                ErrorCode::ValidationError,
+                format!("不支持的比较符: {}", other),
+            )),
+        }
+    }
+
+    pub fn matches(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Self::Gt => value > threshold,
+            Self::Gte => value >= threshold,
+            Self::Lt => value < threshold,
+            Self::Lte => value <= threshold,
+            Self::Eq => (value - threshold).abs() < f64::EPSILON,
+            Self::Ne => (value - threshold).abs() >= f64::EPSILON,
+        }
+    }
+}
+
+/// 告警状态迁移
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// 新触发
+    Fire,
+    /// 已恢复
+    Resolve,
+    /// 持续触发且未确认，到达重复通知间隔
+    Repeat,
+    /// 持续触发（未到重复间隔）或持续正常
+    None,
+}
+
+impl Transition {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Fire => "fire",
+            Self::Resolve => "resolve",
+            Self::Repeat => "repeat",
+            Self::None => "none",
+        }
+    }
+}
+
+/// 规则内的序列标识
+pub fn fingerprint(rule_id: i64, host_name: Option<&str>, metric_name: Option<&str>) -> String {
+    format!("{}|{}|{}", rule_id, host_name.unwrap_or_default(), metric_name.unwrap_or_default())
+}
+
+/// 指标名称匹配，以 * 结尾表示前缀匹配
+pub fn metric_name_matches(pattern: Option<&str>, metric_name: &str) -> bool {
+    match pattern {
+        None => true,
+        Some(pattern) => match pattern.strip_suffix('*') {
+            Some(prefix) => metric_name.starts_with(prefix),
+            None => pattern == metric_name,
+        },
+    }
+}
+
+/// 评估指标类规则：按主机和指标分组，窗口内样本数不少于 `min_samples`
+/// 且所有样本都满足条件时视为触发，结果值取最近样本
+pub fn evaluate_metric(
+    rule_id: i64,
+    comparator: Comparator,
+    threshold: f64,
+    min_samples: u64,
+    samples: &[SyntheticSample],
+) -> Vec<AlertObservation> {
+    let mut series: BTreeMap<(&str, &str), Vec<&SyntheticSample>> = BTreeMap::new();
+    for sample in samples {
+        series
+            .entry((sample.host_name.as_str(), sample.metric_name.as_str()))
+            .or_default()
+            .push(sample);
+    }
+
+    series
+        .into_iter()
+        .map(|((host_name, metric_name), mut points)| {
+            points.sort_by_key(|p| p.time);
+            let latest = points.last().map(|p| p.value).unwrap_or_default();
+            let count = points.len() as u64;
+            AlertObservation {
+                fingerprint: fingerprint(rule_id, Some(host_name), Some(metric_name)),
+                host_name: Some(host_name.to_string()),
+                metric_name: Some(metric_name.to_string()),
+                value: latest,
+                samples: count,
+                breached: count >= min_samples && points.iter().all(|p| comparator.matches(p.value, threshold)),
+            }
+        })
+        .collect()
+}
+
+/// 评估比率类规则：失败数占总数的百分比，总数不少于 `min_samples` 时才可能触发
+pub fn evaluate_rate(
+    rule_id: i64,
+    source: &str,
+    comparator: Comparator,
+    threshold: f64,
+    min_samples: u64,
+    failures: u64,
+    total: u64,
+) -> AlertObservation {
+    let rate = if total > 0 {
+        (failures as f64 / total as f64 * 10000.0).round() / 100.0
+    } else {
+        0.0
+    };
+
+    AlertObservation {
+        fingerprint: fingerprint(rule_id, None, Some(source)),
+        host_name: None,
+        metric_name: Some(source.to_string()),
+        value: rate,
+        samples: total,
+        breached: total >= min_samples && comparator.matches(rate, threshold),
+    }
+}
+
+/// 根据当前触发中的事件和本次评估结果决定状态迁移
+///
+/// 未确认的触发中告警按 `repeat_minutes` 重复通知，已确认的不再通知
+pub fn transition(
+    firing: Option<&alert_event::Model>,
+    breached: bool,
+    repeat_minutes: i32,
+    now: NaiveDateTime,
+) -> Transition {
+    match (firing, breached) {
+        (None, true) => Transition::Fire,
+        (None, false) => Transition::None,
+        (Some(_), false) => Transition::Resolve,
+        (Some(event), true) => {
+            if event.acked_time.is_some() {
+                return Transition::None;
+            }
+            // 触发时处于静默期未通知，静默结束后补发
+            let Some(last) = event.last_notified_time else {
+                return Transition::Repeat;
+            };
+            if repeat_minutes <= 0 {
+                return Transition::None;
+            }
+            if now - last >= Duration::minutes(repeat_minutes as i64) {
+                Transition::Repeat
+            } else {
+                Transition::None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use crate::database::entity::alert_event::STATUS_FIRING;
+
+    fn sample(host: &str, name: &str, value: f64, minute: i64) -> SyntheticSample {
+        SyntheticSample {
+            host_name: host.to_string(),
+            metric_name: name.to_string(),
+            value,
+            time: Some(DateTime::from_timestamp(minute * 60, 0).unwrap()),
+        }
+    }
+
+    fn firing_event(started_minute: i64, acked: bool) -> alert_event::Model {
+        let started = DateTime::from_timestamp(started_minute * 60, 0).unwrap().naive_utc();
+        alert_event::Model {
+            id: 1,
+            rule_id: 1,
+            rule_name: "disk".to_string(),
+            severity: "critical".to_string(),
+            fingerprint: fingerprint(1, Some("web-1"), Some("disk_usage:/")),
+            host_name: Some("web-1".to_string()),
+            metric_name: Some("disk_usage:/".to_string()),
+            status: STATUS_FIRING.to_string(),
+            value: 95.0,
+            threshold: 90.0,
+            started_time: started,
+            resolved_time: None,
+            last_notified_time: Some(started),
+            acked_by: acked.then(|| "admin".to_string()),
+            acked_time: acked.then_some(started),
+        }
+    }
+
+    #[test]
+    fn test_metric_rule_requires_every_sample_in_window() {
+        let samples = vec![
+            sample("web-1", "disk_usage:/", 91.0, 0),
+            sample("web-1", "disk_usage:/", 93.0, 1),
+            sample("web-2", "disk_usage:/", 95.0, 0),
+            sample("web-2", "disk_usage:/", 85.0, 1),
+        ];
+        let result = evaluate_metric(1, Comparator::Gte, 90.0, 2, &samples);
+
+        assert_eq!(result.len(), 2);
+        assert!(result[0].breached);
+        assert_eq!(result[0].value, 93.0);
+        assert!(!result[1].breached);
+        assert_eq!(result[1].value, 85.0);
+    }
+
+    #[test]
+    fn test_metric_rule_respects_min_samples() {
+        let samples = vec![sample("web-1", "cpu_usage", 99.0, 0)];
+        assert!(!evaluate_metric(1, Comparator::Gt, 80.0, 3, &samples)[0].breached);
+        assert!(evaluate_metric(1, Comparator::Gt, 80.0, 1, &samples)[0].breached);
+    }
+
+    #[test]
+    fn test_rate_rule() {
+        let spike = evaluate_rate(2, SOURCE_LOGIN_FAILURE_RATE, Comparator::Gt, 30.0, 10, 8, 20);
+        assert_eq!(spike.value, 40.0);
+        assert!(spike.breached);
+
+        // 样本太少时不触发
+        let quiet = evaluate_rate(2, SOURCE_LOGIN_FAILURE_RATE, Comparator::Gt, 30.0, 10, 3, 4);
+        assert!(!quiet.breached);
+
+        let empty = evaluate_rate(2, SOURCE_OPERA_ERROR_RATE, Comparator::Gte, 0.0, 1, 0, 0);
+        assert_eq!(empty.value, 0.0);
+        assert!(!empty.breached);
+    }
+
+    #[test]
+    fn test_metric_name_pattern() {
+        assert!(metric_name_matches(Some("disk_usage:*"), "disk_usage:/data"));
+        assert!(!metric_name_matches(Some("disk_usage:*"), "cpu_usage"));
+        assert!(metric_name_matches(Some("cpu_usage"), "cpu_usage"));
+        assert!(metric_name_matches(None, "anything"));
+    }
+
+    #[test]
+    fn test_transitions() {
+        let now = DateTime::from_timestamp(30 * 60, 0).unwrap().naive_utc();
+        assert_eq!(transition(None, true, 0, now), Transition::Fire);
+        assert_eq!(transition(None, false, 0, now), Transition::None);
+
+        let event = firing_event(0, false);
+        assert_eq!(transition(Some(&event), false, 0, now), Transition::Resolve);
+        assert_eq!(transition(Some(&event), true, 0, now), Transition::None);
+        assert_eq!(transition(Some(&event), true, 15, now), Transition::Repeat);
+        assert_eq!(transition(Some(&event), true, 60, now), Transition::None);
+
+        // 已确认的告警不再重复通知
+        let acked = firing_event(0, true);
+        assert_eq!(transition(Some(&acked), true, 15, now), Transition::None);
+
+        // 触发时处于静默期，静默结束后补发
+        let silenced = alert_event::Model { last_notified_time: None, ..firing_event(0, false) };
+        assert_eq!(transition(Some(&silenced), true, 0, now), Transition::Repeat);
+    }
+
+    #[test]
+    fn test_comparator_parse() {
+        assert_eq!(Comparator::parse(">=").unwrap(), Comparator::Gte);
+        assert!(Comparator::parse("=>").is_err());
+        assert!(Comparator::Ne.matches(1.0, 2.0));
+    }
+}
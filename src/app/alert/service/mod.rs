@@ -0,0 +1,8 @@
+/// 告警服务模块
+
+pub mod alert_service;
+pub mod evaluator;
+pub mod notifier;
+
+pub use alert_service::AlertService;
+pub use notifier::AlertNotifier;
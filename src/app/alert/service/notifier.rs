@@ -0,0 +1,154 @@
+/// 告警通知
+/// 按规则配置发送邮件、站内通知和 Webhook，单个渠道失败只记录日志，不影响其他渠道
+
+use std::time::Duration;
+
+use email_plugin::{EmailService, SendEmailParam};
+use notice_plugin::{CreateNoticeParam, NoticeService};
+use once_cell::sync::Lazy;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tracing::{error, warn};
+
+use crate::app::alert::service::evaluator::Transition;
+use crate::database::entity::{alert_event, alert_rule};
+
+/// 站内通知投放范围：角色
+const NOTICE_TARGET_ROLE: i16 = 1;
+/// 站内通知标题最大长度
+const NOTICE_TITLE_MAX_CHARS: usize = 64;
+
+static WEBHOOK_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to build webhook client")
+});
+
+/// 告警通知器
+pub struct AlertNotifier {
+    db: DatabaseConnection,
+}
+
+impl AlertNotifier {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 发送触发、重复或恢复通知
+    pub async fn notify(&self, rule: &alert_rule::Model, event: &alert_event::Model, transition: Transition) {
+        let title = title(rule, event, transition);
+        let content = content(rule, event, transition);
+
+        let emails: Vec<String> = rule
+            .notify_emails
+            .as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default();
+        for to in emails {
+            let param = SendEmailParam {
+                to: to.clone(),
+                subject: title.clone(),
+                content: content.clone(),
+                is_html: false,
+                text_content: None,
+            };
+            if let Err(e) = EmailService::send_email(&self.db, param).await {
+                error!("告警邮件发送失败 {}: {}", to, e);
+            }
+        }
+
+        let role_ids: Vec<i64> = rule
+            .notice_role_ids
+            .as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default();
+        if !role_ids.is_empty() {
+            let param = CreateNoticeParam {
+                title: title.chars().take(NOTICE_TITLE_MAX_CHARS).collect(),
+                type_: 0,
+                status: 1,
+                content: content.clone(),
+                level: notice_level(&rule.severity, transition),
+                target_type: NOTICE_TARGET_ROLE,
+                target_ids: role_ids,
+                publish_time: None,
+                expire_time: None,
+                email_fallback: false,
+            };
+            if let Err(e) = NoticeService::create(&self.db, param).await {
+                error!("告警站内通知发送失败: {}", e);
+            }
+        }
+
+        if let Some(url) = rule.webhook_url.as_deref().filter(|url| !url.is_empty()) {
+            let payload = json!({
+                "action": transition.as_str(),
+                "status": event.status,
+                "rule_id": rule.id,
+                "rule_name": rule.name,
+                "severity": rule.severity,
+                "source": rule.source,
+                "host_name": event.host_name,
+                "metric_name": event.metric_name,
+                "value": event.value,
+                "comparator": rule.comparator,
+                "threshold": event.threshold,
+                "started_time": event.started_time.and_utc(),
+                "resolved_time": event.resolved_time.map(|t| t.and_utc()),
+                "event_id": event.id,
+            });
+            match WEBHOOK_CLIENT.post(url).json(&payload).send().await {
+                Ok(response) if !response.status().is_success() => {
+                    warn!("告警 Webhook 返回 {}: {}", response.status(), url);
+                }
+                Ok(_) => {}
+                Err(e) => error!("告警 Webhook 调用失败 {}: {}", url, e),
+            }
+        }
+    }
+}
+
+fn title(rule: &alert_rule::Model, event: &alert_event::Model, transition: Transition) -> String {
+    let state = if transition == Transition::Resolve { "已恢复" } else { "告警" };
+    match event.host_name.as_deref() {
+        Some(host) => format!("[{}][{}] {} - {}", state, rule.severity, rule.name, host),
+        None => format!("[{}][{}] {}", state, rule.severity, rule.name),
+    }
+}
+
+fn content(rule: &alert_rule::Model, event: &alert_event::Model, transition: Transition) -> String {
+    let mut lines = vec![
+        format!("规则: {}", rule.name),
+        format!("级别: {}", rule.severity),
+        format!("状态: {}", event.status),
+    ];
+    if let Some(host) = &event.host_name {
+        lines.push(format!("主机: {}", host));
+    }
+    if let Some(metric) = &event.metric_name {
+        lines.push(format!("指标: {}", metric));
+    }
+    lines.push(format!("当前值: {} {} {}", event.value, rule.comparator, event.threshold));
+    lines.push(format!("持续窗口: {} 秒", rule.duration_seconds));
+    lines.push(format!("开始时间: {} UTC", event.started_time.format("%Y-%m-%d %H:%M:%S")));
+    if let Some(resolved) = event.resolved_time {
+        lines.push(format!("恢复时间: {} UTC", resolved.format("%Y-%m-%d %H:%M:%S")));
+    }
+    if transition == Transition::Repeat {
+        lines.push("告警持续未确认，重复通知".to_string());
+    }
+    lines.join("\n")
+}
+
+/// 站内通知重要程度（1普通/2重要/3紧急），恢复通知一律为普通
+fn notice_level(severity: &str, transition: Transition) -> i16 {
+    if transition == Transition::Resolve {
+        return 1;
+    }
+    match severity {
+        "critical" => 3,
+        "warning" => 2,
+        _ => 1,
+    }
+}
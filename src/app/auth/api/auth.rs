@@ -9,6 +9,7 @@ use axum::{
 use uuid::Uuid;
 
 use crate::app::auth::dto::{LoginRequest, LoginResponse, RefreshTokenRequest, RefreshTokenResponse, LogoutRequest};
+use crate::app::auth::service::PasswordService;
 use crate::common::exception::{AppError, ErrorCode};
 use crate::database::entity::user;
 use crate::database::DatabaseConnection;
@@ -53,6 +54,8 @@ pub async fn login(
             "用户名或密码错误",
         ));
     }
+    PasswordService::upgrade_hash_if_needed(&db, &user, &request.password).await;
+    let password_expired = PasswordService::is_expired(&user);
 
     // 生成session UUID - 与Python版本一致
     let session_uuid = Uuid::new_v4().to_string();
@@ -62,7 +65,8 @@ pub async fn login(
         user.id.to_string(),      // sub: 用户ID（字符串）
         session_uuid.clone(),     // session_uuid: 会话UUID
         ACCESS_TOKEN_EXPIRE_SECONDS, // exp: 过期时间
-    );
+    )
+    .with_password_expired(password_expired);
     let access_token = CryptoUtils::generate_jwt(&access_payload, &crate::core::SETTINGS.token_secret_key)?;
 
     // 计算access token过期时间
@@ -120,12 +124,8 @@ pub async fn login(
                 .arg(serde_json::to_string(&extra_info).unwrap_or_default())
                 .query(&mut conn);
 
-            // 3. 添加到在线用户集合（如果需要）
-            let _: Result<(), _> = redis::cmd("SADD")
-                .arg(&SETTINGS.token_online_redis_prefix)
-                .arg(&session_uuid)
-                .query(&mut conn);
-            
+            // 在线状态由 WebSocket 连接维护（见 websocket::presence）
+
             tracing::info!("Token 已存储到 Redis: {}", token_key);
         }
     }
@@ -134,6 +134,7 @@ pub async fn login(
         access_token,
         access_token_expire_time,
         session_uuid,
+        password_expired,
         user: user_info,
     };
     
@@ -168,7 +169,8 @@ pub async fn refresh_token(
         payload.sub.clone(),           // 保持用户ID
         payload.session_uuid.clone(),  // 使用原有session_uuid
         ACCESS_TOKEN_EXPIRE_SECONDS,
-    );
+    )
+    .with_password_expired(payload.password_expired);
     let access_token = CryptoUtils::generate_jwt(&new_payload, &crate::core::SETTINGS.token_secret_key)?;
 
     // 计算access token过期时间
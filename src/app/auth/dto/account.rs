@@ -0,0 +1,76 @@
+/// 账号自助服务 DTO（注册、找回密码）
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 注册请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RegisterRequest {
+    /// 用户名
+    #[validate(length(min = 3, max = 50))]
+    pub username: String,
+
+    /// 昵称（为空时使用用户名）
+    #[validate(length(min = 1, max = 50))]
+    pub nickname: Option<String>,
+
+    /// 密码（按密码策略校验）
+    pub password: String,
+
+    /// 邮箱，用于接收验证码
+    #[validate(email)]
+    pub email: String,
+}
+
+/// 注册申请响应 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RegisterResponse {
+    /// 验证邮件发送到的邮箱
+    pub email: String,
+    /// 验证码有效期（秒）
+    pub expire_seconds: u64,
+}
+
+/// 注册验证请求 DTO：邮件链接中的 token，或邮箱 + 验证码
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyRegisterRequest {
+    /// 邮件链接中的令牌
+    pub token: Option<String>,
+
+    /// 注册邮箱
+    #[validate(email)]
+    pub email: Option<String>,
+
+    /// 邮件中的验证码
+    #[validate(length(equal = 6))]
+    pub code: Option<String>,
+}
+
+/// 注册完成响应 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VerifyRegisterResponse {
+    /// 用户ID
+    pub id: i64,
+    /// 用户名
+    pub username: String,
+}
+
+/// 找回密码请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordRequest {
+    /// 账号绑定的邮箱
+    #[validate(email)]
+    pub email: String,
+}
+
+/// 通过重置令牌设置新密码请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResetForgottenPasswordRequest {
+    /// 邮件中的重置令牌
+    #[validate(length(min = 1))]
+    pub token: String,
+
+    /// 新密码（按密码策略校验）
+    pub new_password: String,
+}
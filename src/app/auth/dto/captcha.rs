@@ -1,9 +1,10 @@
 /// 验证码相关DTO
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// 获取验证码响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CaptchaResponse {
     pub uuid: String,
     pub img_type: String,
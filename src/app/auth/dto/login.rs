@@ -1,9 +1,10 @@
 /// 登录请求 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct LoginRequest {
     /// 用户名
     #[validate(length(min = 3, max = 50))]
@@ -25,7 +26,7 @@ pub struct LoginRequest {
 }
 
 /// 登录响应 DTO - 匹配Python后端的GetLoginToken
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct LoginResponse {
     /// 访问令牌
     pub access_token: String,
@@ -33,12 +34,14 @@ pub struct LoginResponse {
     pub access_token_expire_time: chrono::NaiveDateTime,
     /// 会话 UUID
     pub session_uuid: String,
+    /// 密码已过期，需先修改密码
+    pub password_expired: bool,
     /// 用户信息
     pub user: UserInfo,
 }
 
 /// 用户信息 DTO - 匹配Python后端的GetUserInfoDetail
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UserInfo {
     /// 用户ID
     pub id: i64,
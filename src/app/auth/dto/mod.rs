@@ -1,9 +1,11 @@
 /// 认证相关的数据传输对象
 
+pub mod account;
 pub mod login;
 pub mod token;
 pub mod captcha;
 
+pub use account::*;
 pub use login::*;
 pub use token::*;
 pub use captcha::*;
@@ -1,16 +1,17 @@
 /// Token相关 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 刷新Token请求 DTO
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RefreshTokenRequest {
     /// 刷新令牌
     pub refresh_token: String,
 }
 
 /// 刷新Token响应 DTO - 匹配Python后端的GetNewToken
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct RefreshTokenResponse {
     /// 访问令牌
     pub access_token: String,
@@ -21,7 +22,7 @@ pub struct RefreshTokenResponse {
 }
 
 /// 登出请求 DTO
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LogoutRequest {
     /// 访问令牌（可选，后端也可从请求头获取）
     pub access_token: Option<String>,
@@ -11,10 +11,12 @@ use crate::app::auth::dto::{
     LoginRequest,
     RefreshTokenRequest, LogoutRequest,
 };
+use crate::app::user::api::auth as account_api;
 use crate::common::exception::AppError;
 use crate::common::response::api_response;
+use utoipa::ToSchema;
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, ToSchema)]
 pub struct CaptchaQuery {
     pub key: Option<String>,
 }
@@ -25,6 +27,10 @@ pub fn auth_routes() -> Router {
         .route("/login", post(login_handler))
         .route("/refresh", post(refresh_token_handler))
         .route("/logout", post(logout_handler))
+        .route("/register", post(account_api::register))
+        .route("/register/verify", post(account_api::verify_register))
+        .route("/password/forgot", post(account_api::forgot_password))
+        .route("/password/reset", post(account_api::reset_forgotten_password))
         .route("/codes", get(get_codes_handler))
 }
 
@@ -66,6 +72,12 @@ async fn logout_handler(
     Ok(response)
 }
 
+/// GET /.well-known/jwks.json
+/// 令牌签名公钥（RFC 7517），其他服务据此验证本系统签发的令牌
+pub async fn jwks_handler() -> impl IntoResponse {
+    Json(crate::utils::encrypt::KEYSET.jwks())
+}
+
 /// GET /api/v1/auth/codes
 async fn get_codes_handler(
     _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
@@ -0,0 +1,552 @@
+/// 账号自助服务
+/// 注册（邮箱验证通过后才创建用户）、找回密码和更换邮箱。
+/// 验证码、验证链接和重置令牌保存在 Redis 中，均为一次性使用；令牌只保存哈希。
+
+use config_plugin::service::ConfigService;
+use email_plugin::{EmailService, SendEmailParam};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use redis::aio::ConnectionManager;
+use sea_orm::{ActiveValue, DbErr};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::info;
+
+use crate::app::auth::dto::{
+    ForgotPasswordRequest, RegisterRequest, RegisterResponse, ResetForgottenPasswordRequest,
+    VerifyRegisterRequest, VerifyRegisterResponse,
+};
+use crate::app::auth::service::{revoke_user_sessions, PasswordService};
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::i18n;
+use crate::core::SETTINGS;
+use crate::database::redis::RedisManager;
+use crate::database::{entity::user, user_repo::UserRepository as UserRepo, DatabaseConnection};
+use crate::utils::encrypt::CryptoUtils;
+
+/// 是否开放注册的系统参数
+const REGISTER_CONFIG_KEY: &str = "user.register";
+
+/// 验证用途，区分 Redis 键
+#[derive(Debug, Clone, Copy)]
+enum Purpose {
+    Register,
+    ChangeEmail,
+    PasswordReset,
+}
+
+impl Purpose {
+    fn as_str(self) -> &'static str {
+        match self {
+            Purpose::Register => "register",
+            Purpose::ChangeEmail => "change_email",
+            Purpose::PasswordReset => "password_reset",
+        }
+    }
+}
+
+/// 待验证的申请
+#[derive(Debug, Serialize, Deserialize)]
+struct Ticket {
+    code: String,
+    /// 验证链接令牌的哈希
+    token_hash: String,
+    /// 验证通过后使用的数据
+    payload: String,
+}
+
+/// 待验证的注册信息，密码已按策略校验并哈希
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingRegistration {
+    username: String,
+    nickname: String,
+    email: String,
+    password: String,
+}
+
+pub struct AccountService {
+    db: DatabaseConnection,
+}
+
+impl AccountService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 是否开放注册；参数不存在或读取失败时视为关闭
+    pub async fn registration_enabled(&self) -> bool {
+        let Ok(mut conn) = RedisManager::get_connection().await else {
+            return false;
+        };
+        match ConfigService::get_by_key(&self.db, &mut conn, REGISTER_CONFIG_KEY).await {
+            Ok(config) => is_enabled_value(&config.value),
+            Err(e) => {
+                tracing::debug!("读取注册开关失败，按关闭处理: {}", e);
+                false
+            }
+        }
+    }
+
+    /// 提交注册申请，发送验证邮件
+    pub async fn register(&self, request: &RegisterRequest) -> Result<RegisterResponse, AppError> {
+        if !self.registration_enabled().await {
+            return Err(AppError::with_message(ErrorCode::Forbidden, "系统未开放注册"));
+        }
+
+        let email = normalize_email(&request.email);
+        self.ensure_available(&request.username, &email).await?;
+
+        let pending = PendingRegistration {
+            username: request.username.clone(),
+            nickname: request
+                .nickname
+                .clone()
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| request.username.clone()),
+            email: email.clone(),
+            password: PasswordService::hash_new_password(&request.password, &request.username).await?,
+        };
+
+        let mut conn = RedisManager::get_connection().await?;
+        let (code, token) = issue(&mut conn, Purpose::Register, &email, serde_json::to_string(&pending)?).await?;
+
+        let mut content = i18n::t_args(
+            "email.register_body",
+            &[
+                ("username", &pending.username),
+                ("code", &code),
+                ("minutes", &(SETTINGS.account_code_expire_seconds / 60)),
+            ],
+        );
+        if let Some(url) = SETTINGS.account_verify_url.as_deref() {
+            content.push('\n');
+            content.push_str(&i18n::t_args("email.register_link", &[("link", &link_with_token(url, &token))]));
+        }
+        content.push('\n');
+        content.push_str(&i18n::t("email.ignore"));
+        self.send(&email, &i18n::t("email.register_subject"), content).await?;
+
+        Ok(RegisterResponse {
+            email,
+            expire_seconds: SETTINGS.account_code_expire_seconds,
+        })
+    }
+
+    /// 验证注册邮箱并创建用户
+    pub async fn verify_registration(
+        &self,
+        request: &VerifyRegisterRequest,
+    ) -> Result<VerifyRegisterResponse, AppError> {
+        if !self.registration_enabled().await {
+            return Err(AppError::with_message(ErrorCode::Forbidden, "系统未开放注册"));
+        }
+
+        let mut conn = RedisManager::get_connection().await?;
+        let payload = match (&request.token, &request.email, &request.code) {
+            (Some(token), _, _) => consume_token(&mut conn, Purpose::Register, token).await?,
+            (None, Some(email), Some(code)) => {
+                consume_code(&mut conn, Purpose::Register, &normalize_email(email), code).await?
+            }
+            _ => {
+                return Err(AppError::with_message(ErrorCode::ValidationError, "请提供验证链接令牌或邮箱验证码"));
+            }
+        };
+        let pending: PendingRegistration = serde_json::from_str(&payload)?;
+
+        // 等待验证期间用户名或邮箱可能已被占用
+        self.ensure_available(&pending.username, &pending.email).await?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let user_model = user::ActiveModel {
+            id: ActiveValue::NotSet,
+            uuid: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            username: ActiveValue::Set(pending.username),
+            nickname: ActiveValue::Set(pending.nickname),
+            password: ActiveValue::Set(Some(pending.password.clone())),
+            salt: ActiveValue::Set(None),
+            email: ActiveValue::Set(Some(pending.email)),
+            phone: ActiveValue::Set(None),
+            avatar: ActiveValue::Set(None),
+            status: ActiveValue::Set(1),
+            is_superuser: ActiveValue::Set(false),
+            is_staff: ActiveValue::Set(false),
+            is_multi_login: ActiveValue::Set(false),
+            join_time: ActiveValue::Set(now),
+            last_login_time: ActiveValue::Set(None),
+            password_changed_time: ActiveValue::Set(Some(now)),
+            locale: ActiveValue::Set(None),
+            dept_id: ActiveValue::Set(None),
+            created_time: ActiveValue::NotSet,
+            updated_time: ActiveValue::NotSet,
+            del_flag: ActiveValue::Set(0),
+        };
+        let created = UserRepo::create(user_model, &self.db).await?;
+        PasswordService::record_history(&self.db, created.id, pending.password).await?;
+        info!("用户自助注册完成: user_id={}, username={}", created.id, created.username);
+
+        Ok(VerifyRegisterResponse {
+            id: created.id,
+            username: created.username,
+        })
+    }
+
+    /// 发送密码重置邮件
+    ///
+    /// 邮箱未注册、用户已停用或处于重发间隔内时同样返回成功，避免通过响应探测邮箱
+    pub async fn forgot_password(&self, request: &ForgotPasswordRequest) -> Result<(), AppError> {
+        let email = normalize_email(&request.email);
+        let user_model = match UserRepo::find_by_email(&email, &self.db).await {
+            Ok(user_model) if user_model.status == 1 => user_model,
+            Ok(_) | Err(DbErr::RecordNotFound(_)) => {
+                info!("找回密码的邮箱不存在或用户已停用: {}", email);
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut conn = RedisManager::get_connection().await?;
+        if !try_cooldown(&mut conn, Purpose::PasswordReset, &email).await? {
+            return Ok(());
+        }
+
+        let token = CryptoUtils::random_string(48);
+        let _: () = redis::cmd("SETEX")
+            .arg(redis_key(Purpose::PasswordReset, "token", &CryptoUtils::sha256(&token)))
+            .arg(SETTINGS.account_reset_token_expire_seconds)
+            .arg(user_model.id)
+            .query_async(&mut conn)
+            .await?;
+
+        let action = match SETTINGS.account_reset_url.as_deref() {
+            Some(url) => i18n::t_args("email.reset_link", &[("link", &link_with_token(url, &token))]),
+            None => i18n::t_args("email.reset_token", &[("token", &token)]),
+        };
+        let content = format!(
+            "{}\n{}",
+            i18n::t_args(
+                "email.reset_body",
+                &[
+                    ("username", &user_model.username),
+                    ("action", &action),
+                    ("minutes", &(SETTINGS.account_reset_token_expire_seconds / 60)),
+                ],
+            ),
+            i18n::t("email.ignore")
+        );
+        self.send(&email, &i18n::t("email.reset_subject"), content).await
+    }
+
+    /// 使用重置令牌设置新密码
+    pub async fn reset_forgotten_password(&self, request: &ResetForgottenPasswordRequest) -> Result<(), AppError> {
+        let invalid = || AppError::with_message(ErrorCode::ValidationError, "重置链接无效或已过期");
+        let key = redis_key(Purpose::PasswordReset, "token", &CryptoUtils::sha256(&request.token));
+
+        let mut conn = RedisManager::get_connection().await?;
+        let user_id: Option<i64> = redis::cmd("GET").arg(&key).query_async(&mut conn).await?;
+        let user_model = match user_id {
+            Some(user_id) => UserRepo::find_by_id(user_id, &self.db).await.map_err(|_| invalid())?,
+            None => return Err(invalid()),
+        };
+
+        // 先校验新密码，不满足策略时令牌仍可继续使用
+        PasswordService::check_new_password(&self.db, &user_model, &request.new_password).await?;
+
+        let deleted: i64 = redis::cmd("DEL").arg(&key).query_async(&mut conn).await?;
+        if deleted == 0 {
+            return Err(invalid());
+        }
+        PasswordService::replace_password(&self.db, &user_model, &request.new_password).await?;
+        // 密码可能已泄露，旧会话全部失效
+        revoke_user_sessions(user_model.id).await?;
+        info!("用户通过邮件重置密码: user_id={}", user_model.id);
+        Ok(())
+    }
+
+    /// 申请更换邮箱：确认当前密码后向新邮箱发送验证码
+    pub async fn request_email_change(&self, user_id: i64, email: &str, password: &str) -> Result<(), AppError> {
+        let user_model = UserRepo::find_by_id(user_id, &self.db)
+            .await
+            .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
+        PasswordService::verify_current_password(&user_model, password).await?;
+
+        let email = normalize_email(email);
+        if user_model.email.as_deref().map(normalize_email).as_deref() == Some(email.as_str()) {
+            return Err(AppError::with_message(ErrorCode::ValidationError, "新邮箱与当前邮箱相同"));
+        }
+        if UserRepo::exists_by_email(&email, &self.db).await? {
+            return Err(AppError::with_message(ErrorCode::Conflict, "邮箱已被使用"));
+        }
+
+        let mut conn = RedisManager::get_connection().await?;
+        let (code, _) = issue(&mut conn, Purpose::ChangeEmail, &user_id.to_string(), email.clone()).await?;
+        let content = format!(
+            "{}\n{}",
+            i18n::t_args(
+                "email.change_email_body",
+                &[
+                    ("username", &user_model.username),
+                    ("code", &code),
+                    ("minutes", &(SETTINGS.account_code_expire_seconds / 60)),
+                ],
+            ),
+            i18n::t("email.ignore")
+        );
+        self.send(&email, &i18n::t("email.change_email_subject"), content).await
+    }
+
+    /// 验证新邮箱并更新
+    pub async fn confirm_email_change(&self, user_id: i64, code: &str) -> Result<String, AppError> {
+        let mut conn = RedisManager::get_connection().await?;
+        let email = consume_code(&mut conn, Purpose::ChangeEmail, &user_id.to_string(), code).await?;
+
+        if let Ok(existing) = UserRepo::find_by_email(&email, &self.db).await {
+            if existing.id != user_id {
+                return Err(AppError::with_message(ErrorCode::Conflict, "邮箱已被使用"));
+            }
+        }
+
+        let mut update_data = user::ActiveModel::default();
+        update_data.email = ActiveValue::Set(Some(email.clone()));
+        UserRepo::update(user_id, update_data, &self.db).await?;
+        info!("用户邮箱已更换: user_id={}", user_id);
+        Ok(email)
+    }
+
+    /// 用户名和邮箱均未被占用
+    async fn ensure_available(&self, username: &str, email: &str) -> Result<(), AppError> {
+        if UserRepo::exists_by_username(username, &self.db).await? {
+            return Err(AppError::with_message(ErrorCode::Conflict, "用户名已存在"));
+        }
+        if UserRepo::exists_by_email(email, &self.db).await? {
+            return Err(AppError::with_message(ErrorCode::Conflict, "邮箱已被使用"));
+        }
+        Ok(())
+    }
+
+    /// 通过 email 插件发送纯文本邮件，内容按当前请求语言生成
+    async fn send(&self, to: &str, subject: &str, content: String) -> Result<(), AppError> {
+        let param = SendEmailParam {
+            to: to.to_string(),
+            subject: subject.to_string(),
+            content,
+            is_html: false,
+            text_content: None,
+        };
+        EmailService::send_email(&self.db, param)
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::with_details(ErrorCode::OperationFailed, "邮件发送失败", e.to_string()))
+    }
+}
+
+fn redis_key(purpose: Purpose, kind: &str, id: &str) -> String {
+    format!("{}:{}:{}:{}", SETTINGS.account_redis_prefix, purpose.as_str(), kind, id)
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// 系统参数中的开关值
+fn is_enabled_value(value: &str) -> bool {
+    matches!(value.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes" | "on")
+}
+
+/// 在前端页面地址上附加 token 参数
+fn link_with_token(url: &str, token: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(mut url) => {
+            url.query_pairs_mut().append_pair("token", token);
+            url.to_string()
+        }
+        Err(_) => format!("{}?token={}", url, token),
+    }
+}
+
+/// 占用重发间隔，间隔内已发送过时返回 false
+async fn try_cooldown(conn: &mut ConnectionManager, purpose: Purpose, subject: &str) -> Result<bool, AppError> {
+    if SETTINGS.account_code_resend_seconds == 0 {
+        return Ok(true);
+    }
+    let set: Option<String> = redis::cmd("SET")
+        .arg(redis_key(purpose, "cooldown", subject))
+        .arg(1)
+        .arg("NX")
+        .arg("EX")
+        .arg(SETTINGS.account_code_resend_seconds)
+        .query_async(conn)
+        .await?;
+    Ok(set.is_some())
+}
+
+async fn get_json<T: DeserializeOwned>(conn: &mut ConnectionManager, key: &str) -> Result<Option<T>, AppError> {
+    let value: Option<String> = redis::cmd("GET").arg(key).query_async(conn).await?;
+    Ok(value.and_then(|v| serde_json::from_str(&v).ok()))
+}
+
+/// 生成验证码和验证链接令牌，替换同一对象之前的申请
+async fn issue(
+    conn: &mut ConnectionManager,
+    purpose: Purpose,
+    subject: &str,
+    payload: String,
+) -> Result<(String, String), AppError> {
+    if !try_cooldown(conn, purpose, subject).await? {
+        return Err(AppError::with_message(ErrorCode::BusinessError, "发送过于频繁，请稍后再试"));
+    }
+
+    let ticket_key = redis_key(purpose, "ticket", subject);
+    if let Some(previous) = get_json::<Ticket>(conn, &ticket_key).await? {
+        let _: () = redis::cmd("DEL")
+            .arg(redis_key(purpose, "token", &previous.token_hash))
+            .query_async(conn)
+            .await?;
+    }
+
+    let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+    let token = CryptoUtils::random_string(48);
+    let ticket = Ticket {
+        code: code.clone(),
+        token_hash: CryptoUtils::sha256(&token),
+        payload,
+    };
+
+    let expire = SETTINGS.account_code_expire_seconds;
+    let _: () = redis::pipe()
+        .atomic()
+        .cmd("SETEX")
+        .arg(&ticket_key)
+        .arg(expire)
+        .arg(serde_json::to_string(&ticket)?)
+        .ignore()
+        .cmd("SETEX")
+        .arg(redis_key(purpose, "token", &ticket.token_hash))
+        .arg(expire)
+        .arg(subject)
+        .ignore()
+        .cmd("DEL")
+        .arg(redis_key(purpose, "attempts", subject))
+        .ignore()
+        .query_async(conn)
+        .await?;
+
+    Ok((code, token))
+}
+
+/// 删除申请，返回是否由本次调用删除（并发验证时只有一次成功）
+async fn take_ticket(conn: &mut ConnectionManager, purpose: Purpose, subject: &str, ticket: &Ticket) -> Result<bool, AppError> {
+    let (deleted,): (i64,) = redis::pipe()
+        .atomic()
+        .cmd("DEL")
+        .arg(redis_key(purpose, "ticket", subject))
+        .cmd("DEL")
+        .arg(redis_key(purpose, "token", &ticket.token_hash))
+        .arg(redis_key(purpose, "attempts", subject))
+        .ignore()
+        .query_async(conn)
+        .await?;
+    Ok(deleted > 0)
+}
+
+/// 记录一次验证码错误，返回累计错误次数；申请不存在时返回 -1。
+/// 计数键首次递增时与申请同时过期，达到上限时在同一脚本中删除申请、链接令牌和计数，
+/// 并发提交时不会丢失计数
+const RECORD_FAILURE_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 0 then
+  return -1
+end
+local attempts = redis.call('INCR', KEYS[2])
+if attempts == 1 then
+  local ttl = redis.call('PTTL', KEYS[1])
+  if ttl > 0 then
+    redis.call('PEXPIRE', KEYS[2], ttl)
+  end
+end
+if attempts >= tonumber(ARGV[1]) then
+  redis.call('DEL', KEYS[1], KEYS[2], KEYS[3])
+end
+return attempts
+"#;
+
+static RECORD_FAILURE: Lazy<redis::Script> = Lazy::new(|| redis::Script::new(RECORD_FAILURE_SCRIPT));
+
+/// 校验验证码，返回申请数据；错误次数达到上限后申请作废
+async fn consume_code(
+    conn: &mut ConnectionManager,
+    purpose: Purpose,
+    subject: &str,
+    code: &str,
+) -> Result<String, AppError> {
+    let invalid = || AppError::with_message(ErrorCode::ValidationError, "验证码无效或已过期");
+    let ticket_key = redis_key(purpose, "ticket", subject);
+    let Some(ticket) = get_json::<Ticket>(conn, &ticket_key).await? else {
+        return Err(invalid());
+    };
+
+    if ticket.code != code.trim() {
+        let attempts: i64 = RECORD_FAILURE
+            .key(&ticket_key)
+            .key(redis_key(purpose, "attempts", subject))
+            .key(redis_key(purpose, "token", &ticket.token_hash))
+            .arg(SETTINGS.account_code_max_attempts)
+            .invoke_async(conn)
+            .await?;
+        if attempts < 0 {
+            return Err(invalid());
+        }
+        if attempts >= i64::from(SETTINGS.account_code_max_attempts) {
+            return Err(AppError::with_message(ErrorCode::ValidationError, "验证码错误次数过多，请重新获取"));
+        }
+        return Err(AppError::with_message(ErrorCode::ValidationError, "验证码错误"));
+    }
+
+    if !take_ticket(conn, purpose, subject, &ticket).await? {
+        return Err(invalid());
+    }
+    Ok(ticket.payload)
+}
+
+/// 校验验证链接令牌，返回申请数据
+async fn consume_token(conn: &mut ConnectionManager, purpose: Purpose, token: &str) -> Result<String, AppError> {
+    let invalid = || AppError::with_message(ErrorCode::ValidationError, "验证链接无效或已过期");
+    let token_hash = CryptoUtils::sha256(token);
+
+    let subject: Option<String> = redis::cmd("GET")
+        .arg(redis_key(purpose, "token", &token_hash))
+        .query_async(conn)
+        .await?;
+    let Some(subject) = subject else {
+        return Err(invalid());
+    };
+    let ticket = match get_json::<Ticket>(conn, &redis_key(purpose, "ticket", &subject)).await? {
+        Some(ticket) if ticket.token_hash == token_hash => ticket,
+        _ => return Err(invalid()),
+    };
+
+    if !take_ticket(conn, purpose, &subject, &ticket).await? {
+        return Err(invalid());
+    }
+    Ok(ticket.payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_enabled_value() {
+        assert!(is_enabled_value("true"));
+        assert!(is_enabled_value(" 1 "));
+        assert!(is_enabled_value("ON"));
+        assert!(!is_enabled_value("false"));
+        assert!(!is_enabled_value(""));
+    }
+
+    #[test]
+    fn test_link_with_token() {
+        assert_eq!(
+            link_with_token("http://localhost:5173/reset?lang=zh", "abc"),
+            "http://localhost:5173/reset?lang=zh&token=abc"
+        );
+        assert_eq!(link_with_token("/reset", "abc"), "/reset?token=abc");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::app::auth::dto::{LoginRequest, LoginResponse, RefreshTokenRequest, RefreshTokenResponse, UserInfo};
+use crate::app::auth::service::PasswordService;
 use crate::common::exception::{AppError, ErrorCode};
-use crate::database::{DatabaseConnection, user_repo::UserRepository as UserRepo};
+use crate::core::SETTINGS;
+use crate::database::{DatabaseConnection, entity::user, redis::RedisManager, user_repo::UserRepository as UserRepo};
 use crate::utils::encrypt::{CryptoUtils, JwtPayload};
 use sea_orm::DbErr;
 use uuid::Uuid;
@@ -28,7 +30,39 @@ impl AuthService {
             .cloned()
             .unwrap_or_else(|| request.username.clone());
 
-        let user_model = match UserRepo::find_by_username(&login_account, db).await {
+        let user_model = self.authenticate(&login_account, &request.password, db).await?;
+        let password_expired = PasswordService::is_expired(&user_model);
+
+        self.issue_session(user_model, password_expired)
+    }
+
+    /// 根据用户ID签发会话（第三方登录等无需密码的场景）
+    pub async fn login_by_user_id(
+        &self,
+        user_id: i64,
+        db: &DatabaseConnection,
+    ) -> Result<LoginResponse, AppError> {
+        let user_model = UserRepo::find_by_id(user_id, db).await.map_err(|e| match e {
+            DbErr::RecordNotFound(_) => {
+                AppError::with_message(ErrorCode::AuthenticationFailed, "用户不存在")
+            }
+            e => AppError::with_details(ErrorCode::DatabaseError, "数据库查询失败", e.to_string()),
+        })?;
+
+        Self::check_user_status(&user_model)?;
+
+        // 第三方登录不校验本地密码，不受密码过期限制
+        self.issue_session(user_model, false)
+    }
+
+    /// 校验账号密码，返回用户
+    pub async fn authenticate(
+        &self,
+        login_account: &str,
+        password: &str,
+        db: &DatabaseConnection,
+    ) -> Result<user::Model, AppError> {
+        let user_model = match UserRepo::find_by_username(login_account, db).await {
             Ok(user) => user,
             Err(DbErr::RecordNotFound(_)) => {
                 return Err(AppError::with_message(
@@ -46,25 +80,17 @@ impl AuthService {
             }
         };
         
-        // 检查用户是否被禁用
-        if user_model.status == 0 {
-            return Err(AppError::new(ErrorCode::AuthenticationFailed));
-        }
-
-        // 检查用户是否被删除
-        if user_model.del_flag == 1 {
-            return Err(AppError::new(ErrorCode::AuthenticationFailed));
-        }
+        Self::check_user_status(&user_model)?;
 
         // 2. 验证密码
-        let password = user_model.password.as_ref().ok_or_else(|| {
+        let hashed = user_model.password.as_ref().ok_or_else(|| {
             AppError::with_details(
                 ErrorCode::AuthenticationFailed,
                 "密码验证失败",
                 "用户密码不存在".to_string(),
             )
         })?;
-        let is_valid = CryptoUtils::verify_password(&request.password, password)
+        let is_valid = CryptoUtils::verify_password(password, hashed)
             .await
             .map_err(|e| AppError::with_details(
                 ErrorCode::AuthenticationFailed,
@@ -75,7 +101,28 @@ impl AuthService {
         if !is_valid {
             return Err(AppError::new(ErrorCode::AuthenticationFailed));
         }
+        PasswordService::upgrade_hash_if_needed(db, &user_model, password).await;
+
+        Ok(user_model)
+    }
+
+    /// 检查用户是否可登录
+    pub fn check_user_status(user_model: &user::Model) -> Result<(), AppError> {
+        // 检查用户是否被禁用
+        if user_model.status == 0 {
+            return Err(AppError::new(ErrorCode::AuthenticationFailed));
+        }
+
+        // 检查用户是否被删除
+        if user_model.del_flag == 1 {
+            return Err(AppError::new(ErrorCode::AuthenticationFailed));
+        }
+
+        Ok(())
+    }
 
+    /// 为已校验的用户签发JWT会话
+    fn issue_session(&self, user_model: user::Model, password_expired: bool) -> Result<LoginResponse, AppError> {
         // 3. 生成session UUID
         let session_uuid = Uuid::new_v4().to_string();
 
@@ -84,7 +131,8 @@ impl AuthService {
             user_model.id.to_string(), // sub: 用户ID（字符串）
             session_uuid.clone(),       // session_uuid: 会话UUID
             ACCESS_TOKEN_EXPIRE_SECONDS, // exp: 过期时间
-        );
+        )
+        .with_password_expired(password_expired);
 
         let access_token = CryptoUtils::generate_jwt(&payload, &self.jwt_secret)?;
 
@@ -116,6 +164,7 @@ impl AuthService {
             access_token,
             access_token_expire_time,
             session_uuid,
+            password_expired,
             user: user_info,
         })
     }
@@ -139,7 +188,8 @@ impl AuthService {
             jwt_payload.sub.clone(),           // 保持用户ID
             jwt_payload.session_uuid.clone(),  // 使用原有session_uuid
             ACCESS_TOKEN_EXPIRE_SECONDS,
-        );
+        )
+        .with_password_expired(jwt_payload.password_expired);
 
         let access_token = CryptoUtils::generate_jwt(&new_payload, &self.jwt_secret)?;
 
@@ -160,3 +210,72 @@ impl AuthService {
         Ok(())
     }
 }
+
+/// 校验访问令牌，并确认其会话仍在 Redis 中有效（未退出、未被踢下线、未因改密等原因被撤销）
+pub async fn verify_session_token(token: &str) -> Result<JwtPayload, AppError> {
+    let payload = CryptoUtils::verify_jwt(token, &SETTINGS.token_secret_key)?;
+    let mut conn = RedisManager::get_connection().await?;
+    let stored: Option<String> = redis::cmd("GET")
+        .arg(format!("{}:{}:{}", SETTINGS.token_redis_prefix, payload.sub, payload.session_uuid))
+        .query_async(&mut conn)
+        .await?;
+    if stored.as_deref() != Some(token) {
+        return Err(AppError::new(ErrorCode::TokenInvalid));
+    }
+    Ok(payload)
+}
+
+/// 撤销用户的全部会话：删除令牌、附加信息和刷新令牌，移出在线集合并断开 WebSocket 连接
+pub async fn revoke_user_sessions(user_id: i64) -> Result<(), AppError> {
+    let token_prefix = format!("{}:{}:", SETTINGS.token_redis_prefix, user_id);
+    let mut keys = RedisManager::scan_keys(&format!("{}*", token_prefix)).await?;
+    let session_uuids: Vec<String> = keys
+        .iter()
+        .filter_map(|key| key.strip_prefix(&token_prefix).map(str::to_string))
+        .collect();
+    for prefix in [&SETTINGS.token_extra_info_redis_prefix, &SETTINGS.token_refresh_redis_prefix] {
+        keys.extend(RedisManager::scan_keys(&format!("{}:{}:*", prefix, user_id)).await?);
+    }
+
+    if !keys.is_empty() {
+        let mut conn = RedisManager::get_connection().await?;
+        let mut pipe = redis::pipe();
+        pipe.del(&keys).ignore();
+        if !session_uuids.is_empty() {
+            pipe.srem(&SETTINGS.token_online_redis_prefix, &session_uuids).ignore();
+        }
+        let _: () = pipe.query_async(&mut conn).await?;
+    }
+    crate::websocket::disconnect_user(user_id).await;
+    Ok(())
+}
+
+/// 将签发的会话写入 Redis（与账号密码登录一致），会话校验、在线用户和踢下线都依赖该记录
+pub async fn store_session(session: &LoginResponse) -> Result<(), AppError> {
+    let user_id = session.user.id;
+    let extra_info = serde_json::json!({
+        "username": session.user.username,
+        "nickname": session.user.nickname,
+        "ip": "",
+        "os": "",
+        "browser": "",
+        "device": "",
+        "last_login_time": chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    });
+
+    let mut conn = RedisManager::get_connection().await?;
+    let _: () = redis::pipe()
+        .cmd("SETEX")
+        .arg(format!("{}:{}:{}", SETTINGS.token_redis_prefix, user_id, session.session_uuid))
+        .arg(SETTINGS.token_expire_seconds)
+        .arg(&session.access_token)
+        .ignore()
+        .cmd("SETEX")
+        .arg(format!("{}:{}:{}", SETTINGS.token_extra_info_redis_prefix, user_id, session.session_uuid))
+        .arg(SETTINGS.token_expire_seconds)
+        .arg(extra_info.to_string())
+        .ignore()
+        .query_async(&mut conn)
+        .await?;
+    Ok(())
+}
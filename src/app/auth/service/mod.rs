@@ -1,7 +1,13 @@
 /// 认证服务模块
 
+pub mod account_service;
 pub mod auth_service;
+pub mod oauth2_account;
+pub mod password_service;
 pub mod rbac_service;
 
+pub use account_service::AccountService;
 pub use auth_service::*;
+pub use oauth2_account::OAuth2AccountService;
+pub use password_service::PasswordService;
 pub use rbac_service::*;
@@ -0,0 +1,198 @@
+/// OAuth2 插件账号后端
+/// 为 oauth2 插件提供会话签发、用户自动创建、账号密码校验和角色同步
+
+use async_trait::async_trait;
+use oauth2_plugin::{OAuth2AccountBackend, OAuth2Error, OAuthUserInfo};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QuerySelect, TransactionTrait};
+use serde_json::Value;
+
+use crate::app::auth::service::{rbac_service, store_session, verify_session_token, AuthService};
+use crate::common::exception::AppError;
+use crate::core::SETTINGS;
+use crate::database::{
+    DatabaseConnection,
+    entity::{role, user, user_role},
+    user_repo::UserRepository as UserRepo,
+};
+
+/// 系统账号后端
+pub struct OAuth2AccountService {
+    db: DatabaseConnection,
+}
+
+impl OAuth2AccountService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn auth_service() -> AuthService {
+        AuthService::new(SETTINGS.token_secret_key.clone())
+    }
+
+    /// 生成不冲突的用户名：优先使用第三方用户名，冲突时追加提供商和用户ID
+    async fn unique_username(&self, user_info: &OAuthUserInfo) -> Result<String, OAuth2Error> {
+        let base: String = user_info
+            .username
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+            .take(32)
+            .collect();
+
+        let candidates = [
+            base.clone(),
+            format!("{}_{}", user_info.provider, base),
+            format!("{}_{}", user_info.provider, user_info.provider_user_id),
+        ];
+        for candidate in candidates.into_iter().filter(|c| !c.is_empty()) {
+            if !UserRepo::exists_by_username(&candidate, &self.db).await? {
+                return Ok(candidate);
+            }
+        }
+
+        Ok(format!("{}_{}", user_info.provider, uuid::Uuid::new_v4().simple()))
+    }
+}
+
+fn to_oauth2_error(e: AppError) -> OAuth2Error {
+    OAuth2Error::Unauthorized(e.message)
+}
+
+#[async_trait]
+impl OAuth2AccountBackend for OAuth2AccountService {
+    async fn authenticate(&self, token: &str) -> Result<i64, OAuth2Error> {
+        let payload = verify_session_token(token).await.map_err(to_oauth2_error)?;
+
+        payload
+            .sub
+            .parse()
+            .map_err(|_| OAuth2Error::Unauthorized("Token无效".to_string()))
+    }
+
+    async fn issue_session(&self, user_id: i64) -> Result<Value, OAuth2Error> {
+        let session = Self::auth_service()
+            .login_by_user_id(user_id, &self.db)
+            .await
+            .map_err(to_oauth2_error)?;
+        store_session(&session).await.map_err(to_oauth2_error)?;
+
+        serde_json::to_value(session).map_err(|e| OAuth2Error::ApiError(e.to_string()))
+    }
+
+    async fn provision_user(&self, user_info: &OAuthUserInfo) -> Result<i64, OAuth2Error> {
+        // 邮箱已被占用时不写入，避免与已有账号冲突
+        let email = match &user_info.email {
+            Some(email) if !UserRepo::exists_by_email(email, &self.db).await? => Some(email.clone()),
+            _ => None,
+        };
+
+        let user_model = user::ActiveModel {
+            id: ActiveValue::NotSet,
+            uuid: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            username: ActiveValue::Set(self.unique_username(user_info).await?),
+            nickname: ActiveValue::Set(user_info.username.clone()),
+            password: ActiveValue::Set(None),
+            salt: ActiveValue::Set(None),
+            email: ActiveValue::Set(email),
+            phone: ActiveValue::Set(None),
+            avatar: ActiveValue::Set(user_info.avatar_url.clone()),
+            status: ActiveValue::Set(1),
+            is_superuser: ActiveValue::Set(false),
+            is_staff: ActiveValue::Set(false),
+            is_multi_login: ActiveValue::Set(false),
+            join_time: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+            last_login_time: ActiveValue::Set(None),
+            password_changed_time: ActiveValue::Set(None),
+            locale: ActiveValue::Set(None),
+            dept_id: ActiveValue::Set(None),
+            created_time: ActiveValue::NotSet,
+            updated_time: ActiveValue::NotSet,
+            del_flag: ActiveValue::Set(0),
+        };
+
+        let created = UserRepo::create(user_model, &self.db).await?;
+        tracing::info!(
+            "OAuth2 自动创建用户: provider={}, user_id={}",
+            user_info.provider,
+            created.id
+        );
+
+        Ok(created.id)
+    }
+
+    async fn verify_credentials(&self, username: &str, password: &str) -> Result<i64, OAuth2Error> {
+        Self::auth_service()
+            .authenticate(username, password, &self.db)
+            .await
+            .map(|user| user.id)
+            .map_err(to_oauth2_error)
+    }
+
+    async fn is_superuser(&self, user_id: i64) -> Result<bool, OAuth2Error> {
+        rbac_service::user_is_superuser(user_id, &self.db)
+            .await
+            .map_err(to_oauth2_error)
+    }
+
+    async fn sync_roles(&self, user_id: i64, managed: &[String], granted: &[String]) -> Result<(), OAuth2Error> {
+        if managed.is_empty() {
+            return Ok(());
+        }
+
+        let managed_ids: Vec<i64> = role::Entity::find()
+            .select_only()
+            .column(role::Column::Id)
+            .filter(role::Column::Name.is_in(managed.iter().cloned()))
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+        let granted_ids: Vec<i64> = role::Entity::find()
+            .select_only()
+            .column(role::Column::Id)
+            .filter(role::Column::Name.is_in(granted.iter().filter(|r| managed.contains(r)).cloned()))
+            .filter(role::Column::Status.eq(1))
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        if granted_ids.len() < granted.len() {
+            tracing::warn!(
+                "OAuth2 角色同步存在未匹配的角色: user_id={}, roles={:?}",
+                user_id,
+                granted
+            );
+        }
+
+        // 只增删映射中出现的角色，管理员在系统内分配的其他角色保持不变
+        let revoked: Vec<i64> = managed_ids.into_iter().filter(|id| !granted_ids.contains(id)).collect();
+        let txn = self.db.begin().await?;
+        let current: Vec<i64> = user_role::Entity::find()
+            .select_only()
+            .column(user_role::Column::RoleId)
+            .filter(user_role::Column::UserId.eq(user_id))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if !revoked.is_empty() {
+            user_role::Entity::delete_many()
+                .filter(user_role::Column::UserId.eq(user_id))
+                .filter(user_role::Column::RoleId.is_in(revoked))
+                .exec(&txn)
+                .await?;
+        }
+        let added: Vec<user_role::ActiveModel> = granted_ids
+            .into_iter()
+            .filter(|id| !current.contains(id))
+            .map(|role_id| user_role::ActiveModel {
+                id: ActiveValue::NotSet,
+                user_id: ActiveValue::Set(user_id),
+                role_id: ActiveValue::Set(role_id),
+            })
+            .collect();
+        if !added.is_empty() {
+            user_role::Entity::insert_many(added).exec(&txn).await?;
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
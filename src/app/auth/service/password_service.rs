@@ -0,0 +1,175 @@
+/// 密码服务
+/// 统一处理密码设置（策略校验、历史复用检查、哈希、记录历史）、登录后的哈希升级和过期判断
+
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::security::password::PasswordPolicy;
+use crate::core::SETTINGS;
+use crate::database::{
+    DatabaseConnection,
+    entity::{user, user_password_history},
+    user_repo::UserRepository as UserRepo,
+};
+use crate::utils::encrypt::CryptoUtils;
+
+pub struct PasswordService;
+
+impl PasswordService {
+    /// 校验密码策略并生成哈希，用于新建用户
+    pub async fn hash_new_password(password: &str, username: &str) -> Result<String, AppError> {
+        PasswordPolicy::from_settings().check(password, Some(username))?;
+        CryptoUtils::hash_password(password).await
+    }
+
+    /// 校验用户的新密码：密码策略及最近密码复用
+    pub async fn check_new_password(
+        db: &DatabaseConnection,
+        user_model: &user::Model,
+        new_password: &str,
+    ) -> Result<(), AppError> {
+        PasswordPolicy::from_settings().check(new_password, Some(&user_model.username))?;
+
+        if Self::is_reused(db, user_model, new_password).await? {
+            return Err(AppError::with_message(
+                ErrorCode::ValidationError,
+                crate::common::i18n::t_args("password.reused", &[("count", &SETTINGS.password_history_count)]),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 校验当前密码，用于修改敏感信息前的身份确认
+    pub async fn verify_current_password(user_model: &user::Model, password: &str) -> Result<(), AppError> {
+        let valid = match user_model.password.as_deref() {
+            Some(hashed) => CryptoUtils::verify_password(password, hashed).await?,
+            None => false,
+        };
+        if !valid {
+            return Err(AppError::with_message(ErrorCode::PasswordError, "当前密码错误"));
+        }
+        Ok(())
+    }
+
+    /// 为已有用户设置新密码
+    pub async fn set_password(
+        db: &DatabaseConnection,
+        user_model: &user::Model,
+        new_password: &str,
+    ) -> Result<(), AppError> {
+        Self::check_new_password(db, user_model, new_password).await?;
+        Self::replace_password(db, user_model, new_password).await
+    }
+
+    /// 写入新密码并记录历史，调用方需先通过 `check_new_password` 校验
+    pub async fn replace_password(
+        db: &DatabaseConnection,
+        user_model: &user::Model,
+        new_password: &str,
+    ) -> Result<(), AppError> {
+        let hashed = CryptoUtils::hash_password(new_password).await?;
+        let now = chrono::Utc::now().naive_utc();
+
+        let mut update_data = user::ActiveModel::default();
+        update_data.password = ActiveValue::Set(Some(hashed.clone()));
+        update_data.password_changed_time = ActiveValue::Set(Some(now));
+        UserRepo::update(user_model.id, update_data, db).await?;
+
+        Self::record_history(db, user_model.id, hashed).await
+    }
+
+    /// 新建用户后记录初始密码
+    pub async fn record_history(db: &DatabaseConnection, user_id: i64, hashed: String) -> Result<(), AppError> {
+        let keep = SETTINGS.password_history_count;
+        if keep == 0 {
+            return Ok(());
+        }
+
+        user_password_history::Entity::insert(user_password_history::ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user_id),
+            password: ActiveValue::Set(hashed),
+            created_time: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        })
+        .exec(db)
+        .await?;
+
+        // 只保留最近 keep 条
+        let stale: Vec<i64> = user_password_history::Entity::find()
+            .select_only()
+            .column(user_password_history::Column::Id)
+            .filter(user_password_history::Column::UserId.eq(user_id))
+            .order_by_desc(user_password_history::Column::Id)
+            .offset(keep)
+            .into_tuple()
+            .all(db)
+            .await?;
+        if !stale.is_empty() {
+            user_password_history::Entity::delete_many()
+                .filter(user_password_history::Column::Id.is_in(stale))
+                .exec(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 新密码是否与当前密码或最近的历史密码相同
+    async fn is_reused(
+        db: &DatabaseConnection,
+        user_model: &user::Model,
+        new_password: &str,
+    ) -> Result<bool, AppError> {
+        let keep = SETTINGS.password_history_count;
+        if keep == 0 {
+            return Ok(false);
+        }
+
+        let mut hashes: Vec<String> = user_password_history::Entity::find()
+            .filter(user_password_history::Column::UserId.eq(user_model.id))
+            .order_by_desc(user_password_history::Column::Id)
+            .limit(keep)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|h| h.password)
+            .collect();
+        hashes.extend(user_model.password.clone());
+
+        for hashed in hashes {
+            // 历史数据格式异常时跳过，不阻止修改
+            if CryptoUtils::verify_password(new_password, &hashed).await.unwrap_or(false) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 登录成功后将旧格式或旧参数的哈希升级为当前算法，失败只记录日志
+    pub async fn upgrade_hash_if_needed(db: &DatabaseConnection, user_model: &user::Model, password: &str) {
+        let Some(current) = user_model.password.as_deref() else {
+            return;
+        };
+        if !CryptoUtils::password_needs_rehash(current) {
+            return;
+        }
+
+        let result = async {
+            let hashed = CryptoUtils::hash_password(password).await?;
+            let mut update_data = user::ActiveModel::default();
+            update_data.password = ActiveValue::Set(Some(hashed));
+            UserRepo::update(user_model.id, update_data, db).await?;
+            Ok::<_, AppError>(())
+        }
+        .await;
+
+        match result {
+            Ok(()) => tracing::info!("用户密码哈希已升级: user_id={}", user_model.id),
+            Err(e) => tracing::warn!("用户密码哈希升级失败: user_id={}, error={}", user_model.id, e),
+        }
+    }
+
+    /// 用户密码是否已过期
+    pub fn is_expired(user_model: &user::Model) -> bool {
+        PasswordPolicy::from_settings().is_expired(user_model.password_changed_time, chrono::Utc::now().naive_utc())
+    }
+}
@@ -2,6 +2,19 @@
 /// RBAC 权限控制服务
 /// 提供角色和权限检查功能
 
+use sea_orm::sea_query::Query;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+
+use crate::common::exception::{AppError, ErrorCode};
+use crate::core::SETTINGS;
+use crate::database::{
+    entity::{menu, role, role_menu},
+    user_repo::UserRepository as UserRepo,
+    user_role_repo::UserRoleRepository as UserRoleRepo,
+    DatabaseManager,
+};
+use crate::middleware::jwt_auth_middleware::AuthContext;
+
 /// 检查用户是否有指定权限
 pub fn check_permission(_user_id: i64, _permission: &str) -> bool {
     // TODO: 实现权限检查逻辑
@@ -25,3 +38,75 @@ pub fn has_all_roles(_user_id: i64, _roles: &[&str]) -> bool {
     // TODO: 实现角色检查逻辑
     true
 }
+
+/// 检查用户是否为超级用户
+pub async fn user_is_superuser(user_id: i64, db: &DatabaseConnection) -> Result<bool, AppError> {
+    let user = UserRepo::find_by_id(user_id, db)
+        .await
+        .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
+    Ok(user.is_superuser)
+}
+
+/// 要求当前登录用户为超级用户，否则返回带指定消息的 Forbidden
+pub async fn require_superuser(auth: &AuthContext, message: &str) -> Result<(), AppError> {
+    let user_id = auth
+        .user_id
+        .parse::<i64>()
+        .map_err(|_| AppError::new(ErrorCode::TokenInvalid))?;
+    if !user_is_superuser(user_id, DatabaseManager::get_connection().await).await? {
+        return Err(AppError::with_message(ErrorCode::Forbidden, message));
+    }
+    Ok(())
+}
+
+/// 检查用户是否拥有指定权限码
+///
+/// 超级用户拥有全部权限；`rbac_role_menu_exclude` 中的权限码不做角色菜单校验；
+/// 其余权限码来自用户启用角色所关联的启用菜单（`sys_role_menu`）。
+pub async fn user_has_permission(
+    user_id: i64,
+    permission: &str,
+    db: &DatabaseConnection,
+) -> Result<bool, AppError> {
+    let user = UserRepo::find_by_id(user_id, db)
+        .await
+        .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
+    if user.is_superuser || SETTINGS.rbac_role_menu_exclude.iter().any(|p| p == permission) {
+        return Ok(true);
+    }
+
+    let role_ids: Vec<i64> = role::Entity::find()
+        .select_only()
+        .column(role::Column::Id)
+        .filter(role::Column::Status.eq(1))
+        .filter(role::Column::Id.is_in(UserRoleRepo::find_roles_by_user(user_id, db).await?))
+        .into_tuple()
+        .all(db)
+        .await?;
+    if role_ids.is_empty() {
+        return Ok(false);
+    }
+
+    let perms: Vec<Option<String>> = menu::Entity::find()
+        .select_only()
+        .column(menu::Column::Perms)
+        .filter(menu::Column::Status.eq(1))
+        .filter(
+            menu::Column::Id.in_subquery(
+                Query::select()
+                    .column(role_menu::Column::MenuId)
+                    .from(role_menu::Entity)
+                    .and_where(role_menu::Column::RoleId.is_in(role_ids))
+                    .to_owned(),
+            ),
+        )
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    Ok(perms
+        .iter()
+        .flatten()
+        .flat_map(|p| p.split(','))
+        .any(|p| p.trim() == permission))
+}
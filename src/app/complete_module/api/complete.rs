@@ -7,6 +7,7 @@ use axum::{
     http::StatusCode,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use tracing::info;
 
 use crate::app::complete_module::service::CompleteService;
@@ -18,7 +19,7 @@ use crate::common::exception::AppError;
 use crate::database::DatabaseManager;
 
 /// 系统状态查询参数
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SystemStatusQuery {
     /// 是否包含详细信息
     pub include_details: Option<bool>,
@@ -96,7 +97,7 @@ pub async fn get_system_summary(
 }
 
 /// 系统摘要 DTO
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemSummary {
     pub system_name: String,
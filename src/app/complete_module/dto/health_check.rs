@@ -1,8 +1,10 @@
 /// 健康检查响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = complete::HealthCheckResponse)]
 #[serde(rename_all = "camelCase")]
 pub struct HealthCheckResponse {
     /// 健康状态
@@ -17,7 +19,8 @@ pub struct HealthCheckResponse {
     pub details: HealthStatus,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = complete::HealthStatus)]
 #[serde(rename_all = "camelCase")]
 pub struct HealthStatus {
     /// 数据库健康状态
@@ -1,8 +1,9 @@
 /// 模块信息响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModuleInfoResponse {
     /// 系统名称
@@ -21,7 +22,7 @@ pub struct ModuleInfoResponse {
     pub fetch_time: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModuleInfo {
     /// 模块名称
@@ -1,8 +1,9 @@
 /// 系统状态响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemStatusResponse {
     /// 系统状态
@@ -21,7 +22,7 @@ pub struct SystemStatusResponse {
     pub uptime: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemHealth {
     /// 整体健康度百分比
@@ -36,7 +37,7 @@ pub struct SystemHealth {
     pub redis_connection: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModuleStatus {
     /// 模块名称
@@ -55,7 +56,8 @@ pub struct ModuleStatus {
     pub check_time: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = complete::DatabaseStatus)]
 #[serde(rename_all = "camelCase")]
 pub struct DatabaseStatus {
     /// 数据库类型
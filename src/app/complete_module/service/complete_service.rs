@@ -8,6 +8,7 @@ use crate::app::complete_module::dto::{
     ModuleInfoResponse, ModuleInfo,
     HealthCheckResponse, HealthStatus,
 };
+use crate::app::monitor::service::ProbeService;
 use crate::common::exception::AppError;
 use sea_orm::{DatabaseConnection, DbErr};
 use chrono::Utc;
@@ -43,11 +44,7 @@ impl CompleteService {
         let healthy_modules = modules.iter().filter(|m| m.status == "healthy").count() as u32;
         let unhealthy_modules = modules.iter().filter(|m| m.status != "healthy").count() as u32;
         let total_modules = modules.len() as u32;
-        let health_percentage = if total_modules > 0 {
-            (healthy_modules * 100 / total_modules) as u8
-        } else {
-            0
-        };
+        let health_percentage = (healthy_modules * 100).checked_div(total_modules).unwrap_or(0) as u8;
 
         let health = SystemHealth {
             health_percentage,
@@ -108,7 +105,7 @@ impl CompleteService {
 
         // 检查各个组件
         let database_healthy = self.is_database_healthy().await;
-        let redis_healthy = false; // TODO: 实现Redis检查
+        let redis_healthy = ProbeService::redis().await.is_up();
         let system_load_healthy = self.is_system_load_healthy().await;
         let memory_healthy = self.is_memory_usage_healthy().await;
         let disk_space_healthy = self.is_disk_space_healthy().await;
@@ -258,9 +255,7 @@ impl CompleteService {
 
     /// 检查数据库是否健康
     async fn is_database_healthy(&self) -> bool {
-        // 简单的数据库连接测试
-        // 直接返回true，因为连接已经建立
-        true
+        ProbeService::database().await.is_up()
     }
 
     /// 统计数据库表数量
@@ -2,10 +2,11 @@
 /// 包括创建、更新、删除等操作的数据结构
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建数据规则请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateDataRuleRequest {
     /// 规则名称
     #[validate(length(min = 1, max = 512, message = "规则名称长度必须在1-512个字符之间"))]
@@ -31,7 +32,7 @@ pub struct CreateDataRuleRequest {
 }
 
 /// 更新数据规则请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDataRuleRequest {
     /// 规则名称
     #[validate(length(min = 1, max = 512, message = "规则名称长度必须在1-512个字符之间"))]
@@ -57,7 +58,7 @@ pub struct UpdateDataRuleRequest {
 }
 
 /// 数据规则模型信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleModelInfo {
     pub model: String,
     pub name: String,
@@ -65,7 +66,7 @@ pub struct DataRuleModelInfo {
 }
 
 /// 数据规则字段信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleColumnInfo {
     pub name: String,
     pub type_: String,
@@ -74,7 +75,7 @@ pub struct DataRuleColumnInfo {
 }
 
 /// 数据规则树节点
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleTreeNode {
     pub id: i64,
     pub name: String,
@@ -89,5 +90,6 @@ pub struct DataRuleTreeNode {
     pub updated_time: chrono::DateTime<chrono::Utc>,
     pub create_by: Option<String>,
     pub update_by: Option<String>,
+    #[schema(no_recursion)]
     pub children: Vec<DataRuleTreeNode>,
 }
@@ -2,10 +2,11 @@
 /// 包括分页查询参数等
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 数据规则分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DataRulePaginationQuery {
     /// 页码（从1开始）
     pub page: Option<u64>,
@@ -33,7 +34,7 @@ pub struct DataRulePaginationQuery {
 }
 
 /// 数据规则查询参数（用于简单的列表查询）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleQueryParams {
     /// 规则名称（模糊搜索）
     pub name: Option<String>,
@@ -49,14 +50,14 @@ pub struct DataRuleQueryParams {
 }
 
 /// 获取模型列表查询参数
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleModelQuery {
     /// 关键词（用于模糊搜索模型名称）
     pub keyword: Option<String>,
 }
 
 /// 获取模型列信息查询参数
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleColumnQuery {
     /// 是否包含系统字段
     pub include_system: Option<bool>,
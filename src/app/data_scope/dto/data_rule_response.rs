@@ -2,11 +2,12 @@
 /// 包括API返回的数据结构
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 // 注意：DataRuleTreeNode 已在 data_rule_config.rs 中定义，避免重复导出
 
 /// 数据规则简单响应（用于列表查询，不包含详细信息）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleSimpleResponse {
     pub id: i64,
     pub name: String,
@@ -15,7 +16,7 @@ pub struct DataRuleSimpleResponse {
 }
 
 /// 数据规则详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleDetailResponse {
     pub id: i64,
     pub name: String,
@@ -27,7 +28,7 @@ pub struct DataRuleDetailResponse {
 }
 
 /// 数据规则列表响应（用于分页查询）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleListResponse {
     pub list: Vec<DataRuleDetailResponse>,
     pub total: u64,
@@ -36,13 +37,13 @@ pub struct DataRuleListResponse {
 }
 
 /// 数据规则模型列表响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleModelListResponse {
     pub models: Vec<DataRuleModelResponse>,
 }
 
 /// 数据规则模型响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleModelResponse {
     pub name: String,
     pub display_name: String,
@@ -51,13 +52,13 @@ pub struct DataRuleModelResponse {
 }
 
 /// 数据规则字段列表响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleColumnListResponse {
     pub columns: Vec<DataRuleColumnResponse>,
 }
 
 /// 数据规则字段响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleColumnResponse {
     pub name: String,
     pub display_name: String,
@@ -69,7 +70,7 @@ pub struct DataRuleColumnResponse {
 }
 
 /// 数据规则批量操作响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DataRuleBatchOperationResponse {
     pub success_ids: Vec<i64>,
     pub failed_ids: Vec<i64>,
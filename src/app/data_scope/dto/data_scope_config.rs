@@ -1,10 +1,11 @@
 /// 数据权限配置 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建数据范围请求（匹配 Python 的 CreateDataScopeParam）
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateDataScopeRequest {
     /// 名称
     #[validate(length(min = 1, max = 64, message = "名称长度必须在1-64个字符之间"))]
@@ -14,7 +15,7 @@ pub struct CreateDataScopeRequest {
 }
 
 /// 更新数据范围请求（匹配 Python 的 UpdateDataScopeParam）
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateDataScopeRequest {
     /// 名称
     #[validate(length(min = 1, max = 64, message = "名称长度必须在1-64个字符之间"))]
@@ -24,14 +25,14 @@ pub struct UpdateDataScopeRequest {
 }
 
 /// 更新数据范围规则请求（匹配 Python 的 UpdateDataScopeRuleParam）
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct UpdateDataScopeRuleRequest {
     /// 数据规则 ID 列表
     pub rules: Vec<i64>,
 }
 
 /// 批量删除数据范围请求（匹配 Python 的 DeleteDataScopeParam）
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct DeleteDataScopeRequest {
     /// 数据范围 ID 列表
     pub pks: Vec<i64>,
@@ -40,7 +41,7 @@ pub struct DeleteDataScopeRequest {
 // ===== 旧的 DTO（保留用于角色数据权限配置，后续实现） =====
 
 /// 数据权限配置请求（角色维度）
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct DataScopeConfigRequest {
     /// 角色ID
     pub role_id: i64,
@@ -51,7 +52,7 @@ pub struct DataScopeConfigRequest {
 }
 
 /// 数据权限配置响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DataScopeConfigResponse {
     /// 配置ID
     pub id: i64,
@@ -72,7 +73,7 @@ pub struct DataScopeConfigResponse {
 }
 
 /// 批量配置数据权限请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct BatchDataScopeConfigRequest {
     /// 角色ID列表
     pub role_ids: Vec<i64>,
@@ -83,7 +84,7 @@ pub struct BatchDataScopeConfigRequest {
 }
 
 /// 批量配置数据权限响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct BatchDataScopeConfigResponse {
     /// 成功配置的角色ID列表
     pub success_role_ids: Vec<i64>,
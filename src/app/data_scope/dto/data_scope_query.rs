@@ -1,9 +1,10 @@
 /// 数据权限查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 用户数据权限查询结果
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct UserDataScope {
     /// 用户ID
     pub user_id: i64,
@@ -18,7 +19,7 @@ pub struct UserDataScope {
 }
 
 /// 单个角色的数据权限
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct UserDataScopeItem {
     /// 角色ID
     pub role_id: i64,
@@ -33,7 +34,7 @@ pub struct UserDataScopeItem {
 }
 
 /// 数据范围过滤条件
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DataScopeFilter {
     /// 允许访问的部门ID列表
     pub allowed_dept_ids: Vec<i64>,
@@ -46,7 +47,7 @@ pub struct DataScopeFilter {
 }
 
 /// 数据权限查询参数
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema)]
 pub struct DataScopeQueryParams {
     /// 用户ID
     pub user_id: Option<i64>,
@@ -61,7 +62,7 @@ pub struct DataScopeQueryParams {
 }
 
 /// 数据权限树节点
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DataScopeTreeNode {
     /// 部门ID
     pub dept_id: i64,
@@ -72,5 +73,6 @@ pub struct DataScopeTreeNode {
     /// 是否可选
     pub selectable: bool,
     /// 子部门
+    #[schema(no_recursion)]
     pub children: Vec<DataScopeTreeNode>,
 }
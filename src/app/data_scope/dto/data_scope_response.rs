@@ -1,9 +1,10 @@
 /// 数据权限响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 数据权限列表响应（匹配 Python 的 PageData）
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DataScopeListResponse {
     /// 数据权限配置列表（Python 中是 items）
     pub items: Vec<DataScopeDetailResponse>,
@@ -18,7 +19,7 @@ pub struct DataScopeListResponse {
 }
 
 /// 数据权限详情响应（匹配 Python 的 GetDataScopeDetail）
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DataScopeDetailResponse {
     /// ID
     pub id: i64,
@@ -35,7 +36,7 @@ pub struct DataScopeDetailResponse {
 }
 
 /// 数据权限检查结果
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DataScopeCheckResult {
     /// 是否有权限查看
     pub can_view: bool,
@@ -48,7 +49,7 @@ pub struct DataScopeCheckResult {
 }
 
 /// 数据权限检查过滤条件
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DataScopeCheckFilter {
     /// 允许访问的部门ID列表
     pub dept_ids: Option<Vec<i64>>,
@@ -16,7 +16,7 @@ use crate::app::dept::dto::{
 use crate::app::dept::service::DeptService;
 
 /// 状态更新请求 DTO
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StatusChangeRequest {
     pub status: i32,
@@ -1,9 +1,10 @@
 /// 创建部门 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateDeptRequest {
     /// 部门名称
     #[validate(length(min = 1, max = 64))]
@@ -29,7 +30,7 @@ pub struct CreateDeptRequest {
 }
 
 /// 部门创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateDeptResponse {
     /// 部门ID
     pub id: i64,
@@ -42,7 +43,7 @@ pub struct CreateDeptResponse {
 }
 
 /// 部门更新请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(default)]
 pub struct UpdateDeptRequest {
     /// 部门名称
@@ -83,7 +84,7 @@ impl Default for UpdateDeptRequest {
 }
 
 /// 部门更新响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UpdateDeptResponse {
     /// 部门ID
     pub id: i64,
@@ -1,16 +1,17 @@
 /// 部门查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 部门树查询参数
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema)]
 pub struct DeptTreeQuery {
     /// 父部门ID
     pub parent_id: Option<i64>,
 }
 
 /// 部门列表查询参数
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeptListQuery {
     /// 部门名称关键词搜索
@@ -30,7 +31,7 @@ pub struct DeptListQuery {
 }
 
 /// 部门状态查询参数（用于状态变更）
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeptStatusQuery {
     /// 部门状态（0:正常 1:停用）
@@ -38,7 +39,7 @@ pub struct DeptStatusQuery {
 }
 
 /// 部门树节点
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeptTreeNode {
     /// 部门ID
     pub id: i64,
@@ -59,11 +60,12 @@ pub struct DeptTreeNode {
     /// 部门状态名称
     pub status_name: String,
     /// 子部门
+    #[schema(no_recursion)]
     pub children: Vec<DeptTreeNode>,
 }
 
 /// 部门列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeptListItem {
     /// 部门ID
     pub id: i64,
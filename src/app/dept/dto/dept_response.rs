@@ -1,9 +1,10 @@
 /// 部门响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 部门详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DeptDetailResponse {
     /// 部门ID
     pub id: i64,
@@ -1,10 +1,11 @@
 /// 数据字典创建和更新 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建数据字典请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateDictDataRequest {
     /// 字典排序
     #[validate(range(min = 0, message = "字典排序必须大于等于0"))]
@@ -40,7 +41,7 @@ pub struct CreateDictDataRequest {
 }
 
 /// 更新数据字典请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDictDataRequest {
     /// 字典编码
     #[validate(range(min = 1, message = "字典编码必须大于0"))]
@@ -80,14 +81,14 @@ pub struct UpdateDictDataRequest {
 }
 
 /// 删除数据字典请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DeleteDictDataRequest {
     /// 字典编码列表
     pub dict_codes: Vec<i64>,
 }
 
 /// 创建数据字典响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateDictDataResponse {
     /// 字典编码
     pub dict_code: i64,
@@ -102,7 +103,7 @@ pub struct CreateDictDataResponse {
 }
 
 /// 更新数据字典响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateDictDataResponse {
     /// 字典编码
     pub dict_code: i64,
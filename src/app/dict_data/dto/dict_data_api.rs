@@ -2,16 +2,17 @@
 /// 匹配Python后端的接口规范
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use chrono::{DateTime, Utc};
 
 /// 字典数据类型编码查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DictDataByTypeCodePath {
     pub code: String,
 }
 
 /// 字典数据分页查询参数
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema)]
 pub struct DictDataListQuery {
     pub page: Option<i32>,
     pub size: Option<i32>,
@@ -23,7 +24,7 @@ pub struct DictDataListQuery {
 }
 
 /// 创建字典数据请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateDictDataRequest {
     pub type_id: i64,
     pub label: String,
@@ -35,7 +36,7 @@ pub struct CreateDictDataRequest {
 }
 
 /// 更新字典数据请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateDictDataRequest {
     pub type_id: i64,
     pub label: String,
@@ -47,13 +48,13 @@ pub struct UpdateDictDataRequest {
 }
 
 /// 批量删除字典数据请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DeleteDictDataRequest {
     pub pks: Vec<i64>,
 }
 
 /// 字典数据详情响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DictDataDetailResponse {
     pub id: i64,
     pub type_code: String,
@@ -68,7 +69,7 @@ pub struct DictDataDetailResponse {
 }
 
 /// 字典数据列表项响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DictDataListItem {
     pub id: i64,
     pub type_code: String,
@@ -82,7 +83,7 @@ pub struct DictDataListItem {
 }
 
 /// 字典数据分页响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DictDataPaginationResponse {
     pub list: Vec<DictDataListItem>,
     pub total: usize,
@@ -1,10 +1,11 @@
 /// 数据字典查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DictDataQuery {
     /// 页码
     #[validate(range(min = 1, message = "页码必须大于0"))]
@@ -28,14 +29,14 @@ pub struct DictDataQuery {
 }
 
 /// 字典类型查询
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictTypeQuery {
     /// 字典类型编码
     pub dict_type: String,
 }
 
 /// 字典项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictItem {
     /// 字典标签
     pub label: String,
@@ -46,7 +47,7 @@ pub struct DictItem {
 }
 
 /// 字典数据列表
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictDataList {
     /// 字典类型编码
     pub dict_type: String,
@@ -55,7 +56,7 @@ pub struct DictDataList {
 }
 
 /// 分页响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictDataListResponse {
     /// 字典列表
     pub list: Vec<DictDataListItem>,
@@ -68,7 +69,7 @@ pub struct DictDataListResponse {
 }
 
 /// 字典列表项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictDataListItem {
     /// 字典编码
     pub dict_code: i64,
@@ -1,9 +1,10 @@
 /// 数据字典响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 字典详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictDataDetailResponse {
     /// 字典编码
     pub dict_code: i64,
@@ -42,7 +43,7 @@ pub struct DictDataDetailResponse {
 }
 
 /// 字典类型统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictTypeStatistics {
     /// 字典类型编码
     pub dict_type: String,
@@ -57,7 +58,7 @@ pub struct DictTypeStatistics {
 }
 
 /// 字典分组统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DictDataGroupStatistics {
     /// 默认字典数量
     pub default_count: usize,
@@ -1,8 +1,9 @@
 /// 字典类型查询条件 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictTypeQuery {
     pub page: Option<u64>,
@@ -12,10 +12,11 @@ use crate::app::file_info::dto::{
     GenerateThumbnailRequest, GenerateThumbnailResponse,
 };
 use serde::Serialize;
+use utoipa::ToSchema;
 use tokio::fs;
 use std::io::Cursor;
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct FileInfoItem {
     id: i64,
     file_name: String,
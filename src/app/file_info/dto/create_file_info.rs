@@ -1,10 +1,11 @@
 /// 文件信息创建和更新 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建文件信息请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateFileInfoRequest {
     /// 文件名
     #[validate(length(min = 1, max = 255, message = "文件名长度必须在1-255个字符之间"))]
@@ -50,7 +51,7 @@ pub struct CreateFileInfoRequest {
 }
 
 /// 更新文件信息请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateFileInfoRequest {
     /// 文件ID
     #[validate(range(min = 1, message = "文件ID必须大于0"))]
@@ -69,14 +70,14 @@ pub struct UpdateFileInfoRequest {
 }
 
 /// 删除文件信息请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DeleteFileInfoRequest {
     /// 文件ID列表
     pub file_ids: Vec<i64>,
 }
 
 /// 创建文件信息响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateFileInfoResponse {
     /// 文件ID
     pub file_id: i64,
@@ -89,7 +90,7 @@ pub struct CreateFileInfoResponse {
 }
 
 /// 更新文件信息响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateFileInfoResponse {
     /// 文件ID
     pub file_id: i64,
@@ -100,7 +101,7 @@ pub struct UpdateFileInfoResponse {
 }
 
 /// 文件上传请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileUploadRequest {
     /// 是否覆盖已存在文件
     pub overwrite: bool,
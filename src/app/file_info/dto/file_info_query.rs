@@ -1,10 +1,11 @@
 /// 文件信息查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct FileInfoQuery {
     /// 页码
     #[validate(range(min = 1, message = "页码必须大于0"))]
@@ -34,7 +35,7 @@ pub struct FileInfoQuery {
 }
 
 /// 下载文件请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DownloadFileRequest {
     /// 文件ID
     pub file_id: i64,
@@ -43,7 +44,7 @@ pub struct DownloadFileRequest {
 }
 
 /// 文件统计查询
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct FileStatisticsQuery {
     /// 上传者
     pub uploader: Option<String>,
@@ -54,7 +55,7 @@ pub struct FileStatisticsQuery {
 }
 
 /// 分页响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileInfoListResponse {
     /// 文件列表
     pub list: Vec<FileInfoListItem>,
@@ -67,7 +68,7 @@ pub struct FileInfoListResponse {
 }
 
 /// 文件列表项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileInfoListItem {
     /// 文件ID
     pub file_id: i64,
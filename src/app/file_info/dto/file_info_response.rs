@@ -1,11 +1,12 @@
 /// 文件信息响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::file_info_query::FileInfoListItem;
 
 /// 文件详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileInfoDetailResponse {
     /// 文件ID
     pub file_id: i64,
@@ -46,7 +47,7 @@ pub struct FileInfoDetailResponse {
 }
 
 /// 下载文件响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DownloadFileResponse {
     /// 文件ID
     pub file_id: i64,
@@ -61,7 +62,7 @@ pub struct DownloadFileResponse {
 }
 
 /// 存储类型统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct StorageTypeStatistics {
     /// 存储类型
     pub storage_type: i32,
@@ -76,7 +77,7 @@ pub struct StorageTypeStatistics {
 }
 
 /// 访问权限统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AccessPermissionStatistics {
     /// 访问权限
     pub access_permission: i32,
@@ -87,7 +88,7 @@ pub struct AccessPermissionStatistics {
 }
 
 /// 文件统计响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileStatisticsResponse {
     /// 总文件数
     pub total_files: usize,
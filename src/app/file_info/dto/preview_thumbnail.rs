@@ -1,10 +1,11 @@
 /// 文件预览和缩略图 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 文件预览请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct PreviewFileRequest {
     /// 文件ID
     #[validate(range(min = 1))]
@@ -15,7 +16,7 @@ pub struct PreviewFileRequest {
 }
 
 /// 预览选项
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct PreviewOptions {
     /// 图片预览宽度
     pub width: Option<u32>,
@@ -43,7 +44,7 @@ pub struct PreviewOptions {
 }
 
 /// 文件预览响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PreviewFileResponse {
     /// 文件ID
     pub file_id: i64,
@@ -83,7 +84,7 @@ pub struct PreviewFileResponse {
 }
 
 /// 缩略图生成请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct GenerateThumbnailRequest {
     /// 文件ID
     #[validate(range(min = 1))]
@@ -103,7 +104,7 @@ pub struct GenerateThumbnailRequest {
 }
 
 /// 缩略图尺寸
-#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, ToSchema)]
 pub enum ThumbnailSize {
     /// 小尺寸 (100x100)
     Small,
@@ -133,7 +134,7 @@ impl ThumbnailSize {
 }
 
 /// 缩略图生成响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GenerateThumbnailResponse {
     /// 文件ID
     pub file_id: i64,
@@ -167,7 +168,7 @@ pub struct GenerateThumbnailResponse {
 }
 
 /// 批量缩略图生成请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct BatchGenerateThumbnailsRequest {
     /// 文件ID列表
     pub file_ids: Vec<i64>,
@@ -183,7 +184,7 @@ pub struct BatchGenerateThumbnailsRequest {
 }
 
 /// 批量缩略图生成响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct BatchGenerateThumbnailsResponse {
     /// 成功生成缩略图的文件数
     pub success_count: usize,
@@ -199,7 +200,7 @@ pub struct BatchGenerateThumbnailsResponse {
 }
 
 /// 缩略图生成失败详情
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ThumbnailGenerationFailure {
     /// 文件ID
     pub file_id: i64,
@@ -212,7 +213,7 @@ pub struct ThumbnailGenerationFailure {
 }
 
 /// 获取文件预览URL请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct GetPreviewUrlRequest {
     /// 文件ID
     pub file_id: i64,
@@ -222,7 +223,7 @@ pub struct GetPreviewUrlRequest {
 }
 
 /// 获取文件预览URL响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GetPreviewUrlResponse {
     /// 文件ID
     pub file_id: i64,
@@ -235,7 +236,7 @@ pub struct GetPreviewUrlResponse {
 }
 
 /// 文件类型检测结果
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct FileTypeInfo {
     /// MIME类型
     pub mime_type: String,
@@ -254,7 +255,7 @@ pub struct FileTypeInfo {
 }
 
 /// 文件类型分类
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema)]
 pub enum FileCategory {
     /// 图片
     Image = 1,
@@ -288,7 +289,7 @@ impl FileCategory {
 }
 
 /// 文件内容解析请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ParseFileContentRequest {
     /// 文件ID
     pub file_id: i64,
@@ -298,7 +299,7 @@ pub struct ParseFileContentRequest {
 }
 
 /// 解析选项
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct ParseOptions {
     /// 文本编码格式
     pub encoding: Option<String>,
@@ -314,7 +315,7 @@ pub struct ParseOptions {
 }
 
 /// 文件内容解析响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ParseFileContentResponse {
     /// 文件ID
     pub file_id: i64,
@@ -336,7 +337,7 @@ pub struct ParseFileContentResponse {
 }
 
 /// 文件元数据
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct FileMetadata {
     /// 文件大小
     pub size: i64,
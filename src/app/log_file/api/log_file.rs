@@ -0,0 +1,52 @@
+/// 日志文件API（仅超级用户）
+
+use axum::{
+    body::Body,
+    extract::{Extension, Path},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use tokio_util::io::ReaderStream;
+
+use crate::app::auth::service::rbac_service;
+use crate::app::log_file::service::LogFileService;
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::response::{api_response, ApiResult};
+use crate::middleware::jwt_auth_middleware::AuthContext;
+
+const SUPERUSER_ONLY: &str = "仅超级用户可访问日志文件";
+
+/// 获取日志文件列表
+/// GET /api/v1/sys/log-files
+pub async fn get_log_files(Extension(auth): Extension<AuthContext>) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let result = LogFileService::list().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 下载日志文件
+/// GET /api/v1/sys/log-files/{name}
+pub async fn download_log_file(
+    Extension(auth): Extension<AuthContext>,
+    Path(name): Path<String>,
+) -> Result<Response, AppError> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let path = LogFileService::resolve(&name).await?;
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| AppError::with_message(ErrorCode::IOError, format!("文件读取失败: {}", e)))?;
+    let size = file.metadata().await.map(|m| m.len()).ok();
+
+    let content_type = if name.ends_with(".gz") { "application/gzip" } else { "text/plain; charset=utf-8" };
+    let mut response = Response::new(Body::from_stream(ReaderStream::new(file)));
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, header::HeaderValue::from_static(content_type));
+    if let Ok(value) = format!("attachment; filename=\"{}\"", name).parse() {
+        headers.insert(header::CONTENT_DISPOSITION, value);
+    }
+    if let Some(size) = size {
+        headers.insert(header::CONTENT_LENGTH, size.into());
+    }
+    Ok(response)
+}
@@ -0,0 +1,5 @@
+/// 日志文件API模块
+
+pub mod log_file;
+
+pub use log_file::*;
@@ -0,0 +1,22 @@
+/// 日志文件 DTO
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 日志文件信息
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LogFileInfo {
+    /// 文件名
+    pub name: String,
+    /// 内容范围（all:全部级别 error:仅 ERROR）
+    pub level: String,
+    /// 是否为正在写入的文件（否则为归档）
+    pub current: bool,
+    /// 是否已压缩
+    pub compressed: bool,
+    /// 文件大小（字节）
+    pub size: u64,
+    /// 最后修改时间
+    pub modified_time: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,5 @@
+/// 日志文件相关 DTO
+
+pub mod log_file;
+
+pub use log_file::*;
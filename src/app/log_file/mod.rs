@@ -0,0 +1,10 @@
+/// 日志文件模块
+/// 查看和下载 LOG_FILE 所在目录中的当前日志及滚动归档，仅超级用户可访问
+
+pub mod api;
+pub mod dto;
+pub mod router;
+pub mod service;
+
+pub use dto::*;
+pub use router::*;
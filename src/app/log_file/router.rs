@@ -0,0 +1,12 @@
+/// 组装日志文件相关路由
+
+use axum::{routing::get, Router};
+
+use crate::app::log_file::api;
+
+/// 日志文件，挂载到 `/api/v1/sys/log-files`
+pub fn log_file_routes() -> Router {
+    Router::new()
+        .route("/", get(api::get_log_files))  // GET /api/v1/sys/log-files
+        .route("/{name}", get(api::download_log_file))  // GET /api/v1/sys/log-files/{name}
+}
@@ -0,0 +1,94 @@
+/// 日志文件服务
+/// 只列出 LOG_FILE 对应的当前文件和归档文件，下载时按文件名重新匹配，不接受任意路径
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::log_file::dto::LogFileInfo;
+use crate::common::exception::{AppError, ErrorCode};
+use crate::core::log_file::{archived_date, error_stem, log_location};
+use crate::core::SETTINGS;
+
+pub struct LogFileService;
+
+impl LogFileService {
+    /// 列出日志文件，当前文件在前，归档按修改时间倒序
+    pub async fn list() -> Result<Vec<LogFileInfo>, AppError> {
+        let Some((dir, stem)) = location() else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = tokio::fs::read_dir(&dir).await.map_err(io_error)?;
+        let mut files = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(io_error)? {
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let Some((level, current)) = classify(&stem, &name) else {
+                continue;
+            };
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+
+            files.push(LogFileInfo {
+                compressed: name.ends_with(".gz"),
+                name,
+                level: level.to_string(),
+                current,
+                size: metadata.len(),
+                modified_time: metadata.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+
+        files.sort_by(|a, b| b.current.cmp(&a.current).then(b.modified_time.cmp(&a.modified_time)));
+        Ok(files)
+    }
+
+    /// 解析可下载的日志文件路径
+    pub async fn resolve(name: &str) -> Result<PathBuf, AppError> {
+        let not_found = || AppError::with_message(ErrorCode::NotFound, format!("日志文件不存在: {}", name));
+        let (dir, stem) = location().ok_or_else(not_found)?;
+        if name.contains(['/', '\\']) || classify(&stem, name).is_none() {
+            return Err(not_found());
+        }
+
+        let path = dir.join(name);
+        match tokio::fs::metadata(&path).await {
+            Ok(metadata) if metadata.is_file() => Ok(path),
+            _ => Err(not_found()),
+        }
+    }
+}
+
+fn location() -> Option<(PathBuf, String)> {
+    SETTINGS
+        .log_file
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+        .map(log_location)
+}
+
+/// 判断文件是否属于本应用的日志，返回（内容范围，是否为当前文件）
+fn classify(stem: &str, name: &str) -> Option<(&'static str, bool)> {
+    let error_stem = error_stem(stem);
+    if name == format!("{}.log", stem) {
+        Some(("all", true))
+    } else if name == format!("{}.log", error_stem) {
+        Some(("error", true))
+    } else if archived_date(stem, name).is_some() {
+        Some(("all", false))
+    } else if archived_date(&error_stem, name).is_some() {
+        Some(("error", false))
+    } else {
+        None
+    }
+}
+
+fn io_error(e: std::io::Error) -> AppError {
+    AppError::with_message(ErrorCode::IOError, format!("读取日志目录失败: {}", e))
+}
@@ -0,0 +1,5 @@
+/// 日志文件服务模块
+
+pub mod log_file_service;
+
+pub use log_file_service::LogFileService;
@@ -1,2 +1,100 @@
-use axum::{http::StatusCode, response::IntoResponse, Json}; use crate::common::response::api_response; pub async fn get_log_levels() -> Result<impl IntoResponse, AppError> { Ok((StatusCode::OK, Json(api_response("日志级别列表")))) }
+/// 日志级别管理API
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+use crate::app::log_level::dto::{DeleteLogLevelRequest, EnableLogLevelRequest, LogLevelQuery, SaveLogLevelRequest};
+use crate::app::log_level::service::LogLevelService;
 use crate::common::exception::AppError;
+use crate::common::response::{api_response, ApiResult};
+use crate::database::DatabaseManager;
+use crate::middleware::jwt_auth_middleware::AuthContext;
+
+async fn log_level_service() -> LogLevelService {
+    LogLevelService::new(DatabaseManager::get_connection().await.clone())
+}
+
+fn validate<T: Validate>(request: &T) -> Result<(), AppError> {
+    request
+        .validate()
+        .map_err(AppError::from)
+}
+
+/// 分页查询日志级别
+/// GET /api/v1/sys/log-levels
+pub async fn get_log_levels(Query(query): Query<LogLevelQuery>) -> ApiResult<impl IntoResponse> {
+    let result = log_level_service().await.get_log_levels(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 当前实例生效的日志过滤规则
+/// GET /api/v1/sys/log-levels/runtime
+pub async fn get_runtime_filter() -> ApiResult<impl IntoResponse> {
+    let result = log_level_service().await.runtime_filter();
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取日志级别详情
+/// GET /api/v1/sys/log-levels/{id}
+pub async fn get_log_level(Path(id): Path<i64>) -> ApiResult<impl IntoResponse> {
+    let result = log_level_service().await.get_log_level(id).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 创建日志级别
+/// POST /api/v1/sys/log-levels
+pub async fn create_log_level(
+    Extension(auth): Extension<AuthContext>,
+    Json(request): Json<SaveLogLevelRequest>,
+) -> ApiResult<impl IntoResponse> {
+    validate(&request)?;
+    let result = log_level_service().await.create_log_level(&request, &auth.username).await?;
+    Ok((StatusCode::CREATED, Json(api_response(result))))
+}
+
+/// 更新日志级别
+/// PUT /api/v1/sys/log-levels/{id}
+pub async fn update_log_level(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+    Json(request): Json<SaveLogLevelRequest>,
+) -> ApiResult<impl IntoResponse> {
+    validate(&request)?;
+    let result = log_level_service().await.update_log_level(id, &request, &auth.username).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 批量删除日志级别
+/// DELETE /api/v1/sys/log-levels
+pub async fn delete_log_levels(Json(request): Json<DeleteLogLevelRequest>) -> ApiResult<impl IntoResponse> {
+    let count = log_level_service().await.delete_log_levels(&request.level_ids).await?;
+    Ok((StatusCode::OK, Json(api_response(format!("已删除 {} 个日志级别", count)))))
+}
+
+/// 启用日志级别，可选若干分钟后自动禁用
+/// PUT /api/v1/sys/log-levels/{id}/enable
+pub async fn enable_log_level(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+    request: Option<Json<EnableLogLevelRequest>>,
+) -> ApiResult<impl IntoResponse> {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+    validate(&request)?;
+    let result = log_level_service().await.enable_log_level(id, &request, &auth.username).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 禁用日志级别
+/// PUT /api/v1/sys/log-levels/{id}/disable
+pub async fn disable_log_level(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    let result = log_level_service().await.disable_log_level(id, &auth.username).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
@@ -1 +1,5 @@
-pub mod log_level; pub use log_level::*;
+/// 日志级别API模块
+
+pub mod log_level;
+
+pub use log_level::*;
@@ -1,80 +1,48 @@
 /// 日志级别创建和更新 DTO
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use utoipa::ToSchema;
 use validator::Validate;
 
-/// 创建日志级别请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
-pub struct CreateLogLevelRequest {
-    /// 日志级别
-    #[validate(length(min = 1, max = 50, message = "日志级别长度必须在1-50个字符之间"))]
-    pub level_name: String,
+/// 创建或更新日志级别请求
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SaveLogLevelRequest {
+    /// 作用的模块（tracing target，如 sea_orm、tower_http::trace），为空表示应用自身
+    #[validate(length(min = 1, max = 255, message = "模块长度必须在1-255个字符之间"))]
+    pub target: Option<String>,
 
-    /// 级别值
-    #[validate(range(min = 0, message = "级别值必须大于等于0"))]
-    pub level_value: i32,
+    /// 日志级别（trace / debug / info / warn / error / off）
+    pub level_name: String,
 
     /// 级别描述
+    #[validate(length(max = 500, message = "描述不能超过500个字符"))]
     pub description: Option<String>,
 
-    /// 是否系统内置（0:否 1:是）
-    pub is_system: Option<i32>,
-
     /// 状态（0:启用 1:禁用）
     #[validate(range(min = 0, max = 1, message = "状态必须是0或1"))]
+    #[serde(default = "default_status")]
     pub status: i32,
-}
-
-/// 更新日志级别请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
-pub struct UpdateLogLevelRequest {
-    /// 级别ID
-    #[validate(range(min = 1, message = "级别ID必须大于0"))]
-    pub level_id: i64,
 
-    /// 日志级别
-    #[validate(length(min = 1, max = 50, message = "日志级别长度必须在1-50个字符之间"))]
-    pub level_name: String,
-
-    /// 级别值
-    #[validate(range(min = 0, message = "级别值必须大于等于0"))]
-    pub level_value: i32,
+    /// 启用后自动恢复（禁用）的分钟数，为空表示不自动恢复
+    #[validate(range(min = 1, max = 10080, message = "自动恢复时间必须在1-10080分钟之间"))]
+    pub revert_after_minutes: Option<i64>,
+}
 
-    /// 级别描述
-    pub description: Option<String>,
+fn default_status() -> i32 {
+    1
+}
 
-    /// 状态（0:启用 1:禁用）
-    #[validate(range(min = 0, max = 1, message = "状态必须是0或1"))]
-    pub status: i32,
+/// 启用日志级别请求
+#[derive(Debug, Clone, Default, Deserialize, Validate, ToSchema)]
+pub struct EnableLogLevelRequest {
+    /// 启用后自动恢复（禁用）的分钟数，为空表示不自动恢复
+    #[validate(range(min = 1, max = 10080, message = "自动恢复时间必须在1-10080分钟之间"))]
+    pub revert_after_minutes: Option<i64>,
 }
 
 /// 删除日志级别请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct DeleteLogLevelRequest {
     /// 级别ID列表
     pub level_ids: Vec<i64>,
 }
-
-/// 创建日志级别响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateLogLevelResponse {
-    /// 级别ID
-    pub level_id: i64,
-    /// 日志级别
-    pub level_name: String,
-    /// 级别值
-    pub level_value: i32,
-    /// 创建时间
-    pub created_time: chrono::DateTime<chrono::Utc>,
-}
-
-/// 更新日志级别响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UpdateLogLevelResponse {
-    /// 级别ID
-    pub level_id: i64,
-    /// 日志级别
-    pub level_name: String,
-    /// 更新时间
-    pub updated_time: chrono::DateTime<chrono::Utc>,
-}
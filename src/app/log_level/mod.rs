@@ -1 +1,11 @@
-pub mod api; pub mod router; pub use api::*; pub use router::*;
+/// 日志级别模块
+/// 通过 sys_log_level 在运行时调整日志过滤规则：全局级别、按模块的级别、定时自动恢复，
+/// 变更经 Redis 同步到所有实例
+
+pub mod api;
+pub mod dto;
+pub mod router;
+pub mod service;
+
+pub use api::*;
+pub use router::*;
@@ -1 +1,18 @@
-use axum::Router; use crate::common::response::api_response; pub fn log_level_routes() -> Router { Router::new().route("/log-levels", axum::routing::get(|| async { api_response("日志级别列表") })) }
+/// 组装日志级别相关路由
+
+use axum::{routing::{delete, get, post, put}, Router};
+
+use crate::app::log_level::api;
+
+/// 日志级别管理，挂载到 `/api/v1/sys/log-levels`
+pub fn log_level_routes() -> Router {
+    Router::new()
+        .route("/", get(api::get_log_levels))  // GET /api/v1/sys/log-levels
+        .route("/", post(api::create_log_level))  // POST /api/v1/sys/log-levels
+        .route("/", delete(api::delete_log_levels))  // DELETE /api/v1/sys/log-levels
+        .route("/runtime", get(api::get_runtime_filter))  // GET /api/v1/sys/log-levels/runtime
+        .route("/{id}", get(api::get_log_level))  // GET /api/v1/sys/log-levels/{id}
+        .route("/{id}", put(api::update_log_level))  // PUT /api/v1/sys/log-levels/{id}
+        .route("/{id}/enable", put(api::enable_log_level))  // PUT /api/v1/sys/log-levels/{id}/enable
+        .route("/{id}/disable", put(api::disable_log_level))  // PUT /api/v1/sys/log-levels/{id}/disable
+}
@@ -1 +1,5 @@
-pub mod log_level_router; pub use log_level_router::*;
+/// 日志级别路由模块
+
+pub mod log_level_router;
+
+pub use log_level_router::*;
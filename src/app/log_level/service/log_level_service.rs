@@ -1,575 +1,441 @@
-use tracing::{info, warn, error, debug};
-
 /// 日志级别服务实现
-/// 提供日志级别的增删改查、批量操作、统计等功能
+/// 提供日志级别的增删改查、启用/禁用，并把启用的级别应用到当前进程的日志过滤器；
+/// 变更通过 Redis 发布到其他实例，到期的临时级别由后台任务自动禁用
+
+use std::collections::BTreeMap;
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use futures::StreamExt;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use tracing::{error, info, warn};
 
 use crate::app::log_level::dto::{
-    CreateLogLevelRequest, CreateLogLevelResponse, UpdateLogLevelRequest,
-    UpdateLogLevelResponse, LogLevelQuery, LogLevelListResponse, LogLevelListItem,
-    LogLevelDetailResponse, EnabledLogLevelQuery, EnabledLogLevelList, LogLevelItem,
-    SystemLevelStatistics, StatusLevelStatistics,
+    EnableLogLevelRequest, LogLevelDetail, LogLevelQuery, RuntimeLogFilter, SaveLogLevelRequest,
 };
 use crate::common::exception::{AppError, ErrorCode};
-use crate::database::DatabaseManager;
+use crate::common::pagination::PageData;
+use crate::core::{health, log_filter};
 use crate::database::entity::log_level::{self, LogLevelStatus};
-use sea_orm::{DatabaseConnection, DbErr, EntityTrait, QueryFilter, ColumnTrait, Select, Order};
-use std::collections::HashMap;
-use std::sync::Arc;
+use crate::database::redis::RedisManager;
+use crate::database::DatabaseManager;
+
+/// 级别变更通知频道
+pub const CHANNEL: &str = "fba:log_level:changed";
+
+/// 到期检查及与数据库对账的间隔
+const SYNC_INTERVAL: StdDuration = StdDuration::from_secs(30);
 
 /// 日志级别服务
 pub struct LogLevelService {
     db: DatabaseConnection,
-    /// 级别缓存（级别值 -> 级别名称）
-    level_cache: Arc<std::sync::Mutex<HashMap<i32, String>>>,
 }
 
 impl LogLevelService {
     /// 创建新的日志级别服务
     pub fn new(db: DatabaseConnection) -> Self {
-        Self {
-            db,
-            level_cache: Arc::new(std::sync::Mutex::new(HashMap::new())),
-        }
+        Self { db }
     }
 
-    /// 创建日志级别
-    pub async fn create_log_level(
-        &self,
-        request: &CreateLogLevelRequest,
-        create_by: &str,
-    ) -> Result<CreateLogLevelResponse, AppError> {
-        // 检查级别值是否已存在
-        let existing = log_level::Entity::find_by_level_value(request.level_value)
-            .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to check level value existence: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to check level value")
-            })?;
-
-        if existing.is_some() {
-            return Err(AppError::new(
-                ErrorCode::Conflict,
-                "Level value already exists",
-            ));
-        }
-
-        let active_model = log_level::ActiveModel {
-            level_id: Default::default(),
-            level_name: sea_orm::Set(request.level_name.clone()),
-            level_value: sea_orm::Set(request.level_value),
-            description: sea_orm::Set(request.description.clone()),
-            is_system: sea_orm::Set(request.is_system.unwrap_or(0)),
-            status: sea_orm::Set(LogLevelStatus::from(request.status)),
-            create_by: sea_orm::Set(create_by.to_string()),
-            created_time: Default::default(),
-            update_by: sea_orm::Set(None),
-            updated_time: Default::default(),
-        };
-
-        let saved_level = active_model.insert(&self.db).await.map_err(|e| {
-            error!("Failed to create log level: {:?}", e);
-            AppError::new(ErrorCode::DatabaseError, "Failed to create log level")
-        })?;
-
-        // 更新缓存
-        {
-            let mut cache = self.level_cache.lock().unwrap();
-            if saved_level.status == 0 {
-                cache.insert(saved_level.level_value, saved_level.level_name);
+    /// 启动后台任务：订阅其他实例的变更通知，并定时禁用到期的级别、与数据库对账
+    pub fn spawn_sync() {
+        tokio::spawn(async {
+            loop {
+                if let Err(e) = subscribe().await {
+                    warn!("日志级别变更订阅中断: {}", e);
+                }
+                tokio::time::sleep(StdDuration::from_secs(3)).await;
             }
-        }
-
-        Ok(CreateLogLevelResponse {
-            level_id: saved_level.level_id,
-            level_name: saved_level.level_name,
-            level_value: saved_level.level_value,
-            created_time: saved_level.created_time,
-        })
+        });
+
+        tokio::spawn(async {
+            let mut interval = tokio::time::interval(SYNC_INTERVAL);
+            loop {
+                interval.tick().await;
+                health::beat("log_level_sync", SYNC_INTERVAL);
+                let service = Self::new(DatabaseManager::get_connection().await.clone());
+                if let Err(e) = service.sync().await {
+                    error!("日志级别同步失败: {}", e.message);
+                }
+            }
+        });
     }
 
-    /// 更新日志级别
-    pub async fn update_log_level(
-        &self,
-        request: &UpdateLogLevelRequest,
-        update_by: Option<&str>,
-    ) -> Result<UpdateLogLevelResponse, AppError> {
-        let existing_level = log_level::Entity::find_by_id(request.level_id)
-            .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log level")
-            })?
-            .ok_or_else(|| AppError::new(ErrorCode::NotFound, "Log level not found"))?;
-
-        // 检查是否为系统内置
-        if existing_level.is_system == 1 {
-            return Err(AppError::new(
-                ErrorCode::Forbidden,
-                "Cannot update system log level",
-            ));
-        }
-
-        // 检查级别值是否已被其他级别使用
-        if request.level_value != existing_level.level_value {
-            let existing = log_level::Entity::find_by_level_value(request.level_value)
-                .one(&self.db)
-                .await
-                .map_err(|e| {
-                    error!("Failed to check level value existence: {:?}", e);
-                    AppError::new(ErrorCode::DatabaseError, "Failed to check level value")
-                })?;
-
-            if existing.is_some() {
-                return Err(AppError::new(
-                    ErrorCode::Conflict,
-                    "Level value already exists",
-                ));
-            }
-        }
+    /// 获取级别列表（分页）
+    pub async fn get_log_levels(&self, query: &LogLevelQuery) -> Result<PageData<LogLevelDetail>, AppError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let size = query.size.unwrap_or(20).clamp(1, 100);
 
-        let mut active_model = existing_level.into_active_model();
-        active_model.level_name = sea_orm::Set(request.level_name.clone());
-        active_model.level_value = sea_orm::Set(request.level_value);
-        active_model.description = sea_orm::Set(request.description.clone());
-        active_model.status = sea_orm::Set(LogLevelStatus::from(request.status));
-        active_model.update_by = sea_orm::Set(update_by.map(|s| s.to_string()));
-
-        let updated_level = active_model.update(&self.db).await.map_err(|e| {
-            error!("Failed to update log level: {:?}", e);
-            AppError::new(ErrorCode::DatabaseError, "Failed to update log level")
-        })?;
-
-        // 更新缓存
-        {
-            let mut cache = self.level_cache.lock().unwrap();
-            // 删除旧级别值
-            cache.remove(&existing_level.level_value);
-            // 如果新状态为启用，添加新级别值
-            if updated_level.status == 0 {
-                cache.insert(updated_level.level_value, updated_level.level_name);
-            }
+        let mut select = log_level::Entity::find();
+        if let Some(target) = &query.target {
+            select = select.filter(log_level::Column::Target.contains(target.as_str()));
         }
-
-        Ok(UpdateLogLevelResponse {
-            level_id: updated_level.level_id,
-            level_name: updated_level.level_name,
-            updated_time: updated_level.updated_time,
-        })
-    }
-
-    /// 删除日志级别（批量）
-    pub async fn delete_log_levels(&self, level_ids: &[i64]) -> Result<(), AppError> {
-        if level_ids.is_empty() {
-            return Ok(());
+        if let Some(level_name) = &query.level_name {
+            select = select.filter(log_level::Column::LevelName.eq(level_name.to_lowercase()));
         }
-
-        // 批量查询级别信息
-        let levels = log_level::Entity::find()
-            .filter(log_level::Column::LevelId.is_in(level_ids.to_vec()))
-            .all(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log levels for deletion: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log levels")
-            })?;
-
-        if levels.is_empty() {
-            return Err(AppError::new(ErrorCode::NotFound, "Log levels not found"));
+        if let Some(is_system) = query.is_system {
+            select = select.filter(log_level::Column::IsSystem.eq(is_system));
         }
-
-        // 检查是否有系统内置级别
-        let system_levels: Vec<_> = levels.iter().filter(|l| l.is_system == 1).collect();
-
-        if !system_levels.is_empty() {
-            return Err(AppError::new(
-                ErrorCode::Forbidden,
-                "Cannot delete system log levels",
-            ));
+        if let Some(status) = query.status {
+            select = select.filter(log_level::Column::Status.eq(status));
         }
 
-        // 批量删除
-        for level in levels {
-            level.delete(&self.db).await.map_err(|e| {
-                error!("Failed to delete log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to delete log level")
-            })?;
-
-            // 从缓存中删除
-            {
-                let mut cache = self.level_cache.lock().unwrap();
-                cache.remove(&level.level_value);
-            }
-        }
+        let paginator = select
+            .order_by_asc(log_level::Column::Target)
+            .order_by_asc(log_level::Column::LevelId)
+            .paginate(&self.db, size);
+        let total = paginator.num_items().await?;
+        let now = Utc::now().naive_utc();
+        let items = paginator
+            .fetch_page(page - 1)
+            .await?
+            .into_iter()
+            .map(|m| to_detail(m, now))
+            .collect();
 
-        Ok(())
+        Ok(PageData::new(items, total as i64, page as i64, size as i64))
     }
 
-    /// 获取级别列表（分页）
-    pub async fn get_log_level_list(
-        &self,
-        query: &LogLevelQuery,
-    ) -> Result<LogLevelListResponse, AppError> {
-        let mut select = log_level::Entity::find();
+    /// 获取级别详情
+    pub async fn get_log_level(&self, level_id: i64) -> Result<LogLevelDetail, AppError> {
+        let model = self.find(level_id).await?;
+        Ok(to_detail(model, Utc::now().naive_utc()))
+    }
 
-        // 添加查询条件
-        if let Some(level_name) = &query.level_name {
-            select = select.filter(log_level::Column::LevelName.like(format!("%{}%", level_name)));
+    /// 创建日志级别
+    pub async fn create_log_level(&self, request: &SaveLogLevelRequest, create_by: &str) -> Result<LogLevelDetail, AppError> {
+        let (target, level_name) = normalize(request)?;
+        self.ensure_unique(None, target.as_deref(), &level_name).await?;
+
+        let now = Utc::now().naive_utc();
+        let model = log_level::ActiveModel {
+            target: Set(target),
+            level_name: Set(level_name),
+            description: Set(request.description.clone()),
+            is_system: Set(0),
+            status: Set(request.status),
+            expire_time: Set(expire_time(request.status, request.revert_after_minutes, now)),
+            create_by: Set(create_by.to_string()),
+            created_time: Set(now),
+            ..Default::default()
         }
+        .insert(&self.db)
+        .await?;
 
-        if let Some(level_value) = query.level_value {
-            select = select.filter(log_level::Column::LevelValue.eq(level_value));
-        }
+        self.changed().await;
+        Ok(to_detail(model, now))
+    }
 
-        if let Some(is_system) = query.is_system {
-            select = select.filter(log_level::Column::IsSystem.eq(is_system));
+    /// 更新日志级别（系统内置级别只能启用或禁用）
+    pub async fn update_log_level(
+        &self,
+        level_id: i64,
+        request: &SaveLogLevelRequest,
+        update_by: &str,
+    ) -> Result<LogLevelDetail, AppError> {
+        let existing = self.find(level_id).await?;
+        if existing.is_system() {
+            return Err(AppError::with_message(ErrorCode::Forbidden, "系统内置级别不能修改，只能启用或禁用"));
         }
+        let (target, level_name) = normalize(request)?;
+        self.ensure_unique(Some(level_id), target.as_deref(), &level_name).await?;
+
+        let now = Utc::now().naive_utc();
+        let mut model: log_level::ActiveModel = existing.into();
+        model.target = Set(target);
+        model.level_name = Set(level_name);
+        model.description = Set(request.description.clone());
+        model.status = Set(request.status);
+        model.expire_time = Set(expire_time(request.status, request.revert_after_minutes, now));
+        model.update_by = Set(Some(update_by.to_string()));
+        model.updated_time = Set(Some(now));
+        let model = model.update(&self.db).await?;
+
+        self.changed().await;
+        Ok(to_detail(model, now))
+    }
 
-        if let Some(status) = query.status {
-            select = select.filter(log_level::Column::Status.eq(status));
+    /// 删除日志级别（批量，系统内置级别不能删除）
+    pub async fn delete_log_levels(&self, level_ids: &[i64]) -> Result<u64, AppError> {
+        if level_ids.is_empty() {
+            return Ok(0);
         }
 
-        if let Some(create_by) = &query.create_by {
-            select = select.filter(log_level::Column::CreateBy.like(format!("%{}%", create_by)));
+        let system_count = log_level::Entity::find()
+            .filter(log_level::Column::LevelId.is_in(level_ids.to_vec()))
+            .filter(log_level::Column::IsSystem.eq(1))
+            .count(&self.db)
+            .await?;
+        if system_count > 0 {
+            return Err(AppError::with_message(ErrorCode::Forbidden, "系统内置级别不能删除"));
         }
 
-        // 按级别值升序
-        select = select.order_by(log_level::Column::LevelValue, Order::Asc);
-
-        // 分页
-        let page_size = query.page_size.unwrap_or(20);
-        let page_num = query.page_num.unwrap_or(1);
-        let offset = (page_num - 1) * page_size;
+        let result = log_level::Entity::delete_many()
+            .filter(log_level::Column::LevelId.is_in(level_ids.to_vec()))
+            .exec(&self.db)
+            .await?;
 
-        let levels = select
-            .offset(offset as u64)
-            .limit(page_size as u64)
-            .all(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to query log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query log levels")
-            })?;
+        self.changed().await;
+        Ok(result.rows_affected)
+    }
 
-        let total = log_level::Entity::find()
-            .count(&self.db)
+    /// 启用级别，可指定若干分钟后自动禁用
+    pub async fn enable_log_level(
+        &self,
+        level_id: i64,
+        request: &EnableLogLevelRequest,
+        update_by: &str,
+    ) -> Result<LogLevelDetail, AppError> {
+        self.set_status(level_id, LogLevelStatus::Enabled, request.revert_after_minutes, update_by)
             .await
-            .map_err(|e| {
-                error!("Failed to count log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count log levels")
-            })?;
+    }
 
-        let list = levels
-            .into_iter()
-            .map(|l| {
-                let is_system_name = if l.is_system == 1 { "是" } else { "否" };
-                let status_name = match LogLevelStatus::from(l.status) {
-                    LogLevelStatus::Enabled => "启用",
-                    LogLevelStatus::Disabled => "禁用",
-                };
-
-                LogLevelListItem {
-                    level_id: l.level_id,
-                    level_name: l.level_name,
-                    level_value: l.level_value,
-                    description: l.description,
-                    is_system: l.is_system,
-                    is_system_name: is_system_name.to_string(),
-                    status: l.status,
-                    status_name: status_name.to_string(),
-                    create_by: l.create_by,
-                    created_time: l.created_time,
-                    updated_time: l.updated_time,
-                }
-            })
-            .collect();
+    /// 禁用级别
+    pub async fn disable_log_level(&self, level_id: i64, update_by: &str) -> Result<LogLevelDetail, AppError> {
+        self.set_status(level_id, LogLevelStatus::Disabled, None, update_by).await
+    }
 
-        Ok(LogLevelListResponse {
-            list,
-            total: total as usize,
-            page_num,
-            page_size,
-        })
+    /// 当前进程的日志过滤规则
+    pub fn runtime_filter(&self) -> RuntimeLogFilter {
+        RuntimeLogFilter {
+            base: log_filter::base(),
+            current: log_filter::current(),
+        }
     }
 
-    /// 获取级别详情
-    pub async fn get_log_level_detail(&self, level_id: i64) -> Result<LogLevelDetailResponse, AppError> {
-        let l = log_level::Entity::find_by_id(level_id)
-            .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log level")
-            })?
-            .ok_or_else(|| AppError::new(ErrorCode::NotFound, "Log level not found"))?;
-
-        let is_system_name = if l.is_system == 1 { "是" } else { "否" };
-        let status_name = match LogLevelStatus::from(l.status) {
-            LogLevelStatus::Enabled => "启用",
-            LogLevelStatus::Disabled => "禁用",
-        };
+    /// 按数据库中生效的级别重新计算并应用过滤规则；返回规则是否发生变化
+    pub async fn refresh(&self) -> Result<bool, AppError> {
+        let now = Utc::now().naive_utc();
+        let models = log_level::Entity::find()
+            .filter(log_level::Column::Status.eq(i32::from(LogLevelStatus::Enabled)))
+            .all(&self.db)
+            .await?;
 
-        Ok(LogLevelDetailResponse {
-            level_id: l.level_id,
-            level_name: l.level_name,
-            level_value: l.level_value,
-            description: l.description,
-            is_system: l.is_system,
-            is_system_name: is_system_name.to_string(),
-            status: l.status,
-            status_name: status_name.to_string(),
-            create_by: l.create_by,
-            created_time: l.created_time,
-            update_by: l.update_by,
-            updated_time: l.updated_time,
-        })
+        let directives = log_filter::merge(&log_filter::base(), &overrides(&models, now));
+        log_filter::apply(&directives)
     }
 
-    /// 获取启用的级别列表
-    pub async fn get_enabled_log_levels(
+    async fn set_status(
         &self,
-        query: &EnabledLogLevelQuery,
-    ) -> Result<EnabledLogLevelList, AppError> {
-        // 先从缓存获取
-        if query.enabled_only {
-            let cache = self.level_cache.lock().unwrap();
-            if !cache.is_empty() {
-                let mut levels: Vec<LogLevelItem> = cache
-                    .iter()
-                    .map(|(value, name)| LogLevelItem {
-                        level_id: 0, // 缓存中不包含ID
-                        level_name: name.clone(),
-                        level_value: *value,
-                        description: None,
-                    })
-                    .collect();
-
-                // 按级别值排序
-                levels.sort_by_key(|l| l.level_value);
-
-                return Ok(EnabledLogLevelList { levels });
-            }
-        }
-
-        // 从数据库获取
-        let levels_query = if query.enabled_only {
-            log_level::Entity::find_enabled()
-        } else {
-            log_level::Entity::find()
-        };
+        level_id: i64,
+        status: LogLevelStatus,
+        revert_after_minutes: Option<i64>,
+        update_by: &str,
+    ) -> Result<LogLevelDetail, AppError> {
+        let existing = self.find(level_id).await?;
+
+        let now = Utc::now().naive_utc();
+        let status = i32::from(status);
+        let mut model: log_level::ActiveModel = existing.into();
+        model.status = Set(status);
+        model.expire_time = Set(expire_time(status, revert_after_minutes, now));
+        model.update_by = Set(Some(update_by.to_string()));
+        model.updated_time = Set(Some(now));
+        let model = model.update(&self.db).await?;
+
+        self.changed().await;
+        Ok(to_detail(model, now))
+    }
 
-        let levels = levels_query
-            .order_by(log_level::Column::LevelValue, Order::Asc)
-            .all(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to query log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query log levels")
-            })?;
-
-        let mut result_levels = Vec::new();
-        let mut cache = self.level_cache.lock().unwrap();
-
-        for level in levels {
-            result_levels.push(LogLevelItem {
-                level_id: level.level_id,
-                level_name: level.level_name.clone(),
-                level_value: level.level_value,
-                description: level.description,
-            });
-
-            // 更新缓存
-            if level.status == 0 {
-                cache.insert(level.level_value, level.level_name);
-            }
+    /// 禁用到期的级别，重新应用过滤规则
+    async fn sync(&self) -> Result<(), AppError> {
+        let now = Utc::now().naive_utc();
+        let expired = log_level::Entity::update_many()
+            .col_expr(log_level::Column::Status, Expr::value(i32::from(LogLevelStatus::Disabled)))
+            .col_expr(log_level::Column::UpdateBy, Expr::value("system"))
+            .col_expr(log_level::Column::UpdatedTime, Expr::value(now))
+            .filter(log_level::Column::Status.eq(i32::from(LogLevelStatus::Enabled)))
+            .filter(log_level::Column::ExpireTime.lte(now))
+            .exec(&self.db)
+            .await?
+            .rows_affected;
+
+        self.refresh().await?;
+        if expired > 0 {
+            info!("{} 个临时日志级别已到期自动禁用", expired);
+            publish().await;
         }
-
-        Ok(EnabledLogLevelList {
-            levels: result_levels,
-        })
+        Ok(())
     }
 
-    /// 根据级别值获取级别名称
-    pub async fn get_level_name(&self, level_value: i32) -> Result<Option<String>, AppError> {
-        // 先从缓存获取
-        {
-            let cache = self.level_cache.lock().unwrap();
-            if let Some(name) = cache.get(&level_value) {
-                return Ok(Some(name.clone()));
-            }
+    /// 本实例立即生效并通知其他实例
+    async fn changed(&self) {
+        if let Err(e) = self.refresh().await {
+            error!("日志过滤规则应用失败: {}", e.message);
         }
+        publish().await;
+    }
 
-        // 从数据库获取
-        let level = log_level::Entity::find_by_level_value(level_value)
+    async fn find(&self, level_id: i64) -> Result<log_level::Model, AppError> {
+        log_level::Entity::find_by_id(level_id)
             .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log level")
-            })?;
-
-        if let Some(l) = level {
-            // 更新缓存
-            {
-                let mut cache = self.level_cache.lock().unwrap();
-                cache.insert(level_value, l.level_name.clone());
-            }
-            Ok(Some(l.level_name))
-        } else {
-            Ok(None)
-        }
+            .await?
+            .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "日志级别不存在"))
     }
 
-    /// 启用级别
-    pub async fn enable_level(&self, level_id: i64, update_by: Option<&str>) -> Result<(), AppError> {
-        let existing_level = log_level::Entity::find_by_id(level_id)
-            .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log level")
-            })?
-            .ok_or_else(|| AppError::new(ErrorCode::NotFound, "Log level not found"))?;
-
-        if existing_level.status == 0 {
-            return Err(AppError::new(
-                ErrorCode::Conflict,
-                "Log level is already enabled",
-            ));
+    async fn ensure_unique(&self, level_id: Option<i64>, target: Option<&str>, level_name: &str) -> Result<(), AppError> {
+        let mut select = log_level::Entity::find().filter(log_level::Column::LevelName.eq(level_name));
+        select = match target {
+            Some(target) => select.filter(log_level::Column::Target.eq(target)),
+            None => select.filter(log_level::Column::Target.is_null()),
+        };
+        if let Some(level_id) = level_id {
+            select = select.filter(log_level::Column::LevelId.ne(level_id));
         }
-
-        let mut active_model = existing_level.into_active_model();
-        active_model.status = sea_orm::Set(LogLevelStatus::Enabled);
-        active_model.update_by = sea_orm::Set(update_by.map(|s| s.to_string()));
-
-        let updated_level = active_model.update(&self.db).await.map_err(|e| {
-            error!("Failed to enable log level: {:?}", e);
-            AppError::new(ErrorCode::DatabaseError, "Failed to enable log level")
-        })?;
-
-        // 更新缓存
-        {
-            let mut cache = self.level_cache.lock().unwrap();
-            cache.insert(updated_level.level_value, updated_level.level_name);
+        if select.count(&self.db).await? > 0 {
+            return Err(AppError::with_message(ErrorCode::Conflict, "该模块已存在相同的日志级别"));
         }
-
         Ok(())
     }
+}
+
+/// 校验并规范化模块和级别
+fn normalize(request: &SaveLogLevelRequest) -> Result<(Option<String>, String), AppError> {
+    let level_name = request.level_name.trim().to_lowercase();
+    if log_filter::verbosity(&level_name).is_none() {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationError,
+            format!("不支持的日志级别: {}，可选值: {}", request.level_name, log_filter::LEVELS.join(" / ")),
+        ));
+    }
 
-    /// 禁用级别
-    pub async fn disable_level(&self, level_id: i64, update_by: Option<&str>) -> Result<(), AppError> {
-        let existing_level = log_level::Entity::find_by_id(level_id)
-            .one(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to find log level: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find log level")
-            })?
-            .ok_or_else(|| AppError::new(ErrorCode::NotFound, "Log level not found"))?;
-
-        if existing_level.status == 1 {
-            return Err(AppError::new(
-                ErrorCode::Conflict,
-                "Log level is already disabled",
+    let target = request.target.as_deref().map(str::trim).filter(|t| !t.is_empty());
+    if let Some(target) = target {
+        let valid = target.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+            && !target.starts_with(':')
+            && !target.ends_with(':');
+        if !valid {
+            return Err(AppError::with_message(
+                ErrorCode::ValidationError,
+                format!("模块名称无效: {}，应为模块路径，如 sea_orm 或 tower_http::trace", target),
             ));
         }
+        log_filter::parse(&format!("{}={}", target, level_name))?;
+    }
 
-        let mut active_model = existing_level.into_active_model();
-        active_model.status = sea_orm::Set(LogLevelStatus::Disabled);
-        active_model.update_by = sea_orm::Set(update_by.map(|s| s.to_string()));
+    Ok((target.map(str::to_string), level_name))
+}
 
-        let updated_level = active_model.update(&self.db).await.map_err(|e| {
-            error!("Failed to disable log level: {:?}", e);
-            AppError::new(ErrorCode::DatabaseError, "Failed to disable log level")
-        })?;
+/// 启用且指定了自动恢复时间时计算到期时间
+fn expire_time(status: i32, revert_after_minutes: Option<i64>, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    if LogLevelStatus::from(status) != LogLevelStatus::Enabled {
+        return None;
+    }
+    revert_after_minutes.map(|minutes| now + Duration::minutes(minutes))
+}
 
-        // 从缓存中删除
-        {
-            let mut cache = self.level_cache.lock().unwrap();
-            cache.remove(&updated_level.level_value);
+/// 生效的级别按模块分组，同一模块启用多个级别时取最详细的
+fn overrides(models: &[log_level::Model], now: NaiveDateTime) -> Vec<(Option<String>, String)> {
+    let mut by_target: BTreeMap<Option<String>, &str> = BTreeMap::new();
+    for model in models.iter().filter(|m| m.is_active(now)) {
+        let Some(verbosity) = log_filter::verbosity(&model.level_name) else {
+            continue;
+        };
+        let entry = by_target.entry(model.target.clone()).or_insert(model.level_name.as_str());
+        if log_filter::verbosity(entry).is_none_or(|current| verbosity < current) {
+            *entry = model.level_name.as_str();
         }
-
-        Ok(())
     }
+    by_target
+        .into_iter()
+        .map(|(target, level)| (target, level.to_string()))
+        .collect()
+}
 
-    /// 获取系统内置级别统计
-    pub async fn get_system_level_statistics(&self) -> Result<SystemLevelStatistics, AppError> {
-        let system_count = log_level::Entity::find_system()
-            .count(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to count system log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count log levels")
-            })?;
-
-        let custom_count = log_level::Entity::find_non_system()
-            .count(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to count custom log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count log levels")
-            })?;
-
-        Ok(SystemLevelStatistics {
-            system_count: system_count as usize,
-            custom_count: custom_count as usize,
-            total_count: (system_count + custom_count) as usize,
-        })
+fn to_detail(m: log_level::Model, now: NaiveDateTime) -> LogLevelDetail {
+    LogLevelDetail {
+        active: m.is_active(now),
+        level_id: m.level_id,
+        target: m.target,
+        level_name: m.level_name,
+        description: m.description,
+        is_system: m.is_system,
+        status: m.status,
+        status_name: LogLevelStatus::from(m.status).get_name().to_string(),
+        expire_time: m.expire_time.map(|t| t.and_utc()),
+        create_by: m.create_by,
+        created_time: m.created_time.and_utc(),
+        update_by: m.update_by,
+        updated_time: m.updated_time.map(|t| t.and_utc()),
     }
+}
 
-    /// 获取状态级别统计
-    pub async fn get_status_level_statistics(&self) -> Result<StatusLevelStatistics, AppError> {
-        let enabled_count = log_level::Entity::find_enabled()
-            .count(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to count enabled log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count log levels")
-            })?;
-
-        let disabled_count = log_level::Entity::find_by_status(LogLevelStatus::Disabled)
-            .count(&self.db)
+/// 通知其他实例重新加载
+async fn publish() {
+    let result = match RedisManager::get_connection().await {
+        Ok(mut conn) => redis::cmd("PUBLISH")
+            .arg(CHANNEL)
+            .arg(Utc::now().timestamp_millis())
+            .query_async::<_, ()>(&mut conn)
             .await
-            .map_err(|e| {
-                error!("Failed to count disabled log levels: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count log levels")
-            })?;
-
-        Ok(StatusLevelStatistics {
-            enabled_count: enabled_count as usize,
-            disabled_count: disabled_count as usize,
-            total_count: (enabled_count + disabled_count) as usize,
-        })
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.message),
+    };
+    if let Err(e) = result {
+        warn!("日志级别变更通知发送失败: {}", e);
     }
+}
 
-    /// 初始化缓存
-    pub async fn init_cache(&self) -> Result<(), AppError> {
-        let levels = log_level::Entity::find_enabled()
-            .order_by(log_level::Column::LevelValue, Order::Asc)
-            .all(&self.db)
-            .await
-            .map_err(|e| {
-                error!("Failed to query log levels for cache: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query log levels")
-            })?;
-
-        let mut cache = self.level_cache.lock().unwrap();
-        cache.clear();
-
-        for level in levels {
-            cache.insert(level.level_value, level.level_name);
+/// 收到变更通知后从数据库重新加载
+async fn subscribe() -> Result<(), String> {
+    let client = RedisManager::get_client().map_err(|e| e.message)?;
+    let mut pubsub = client
+        .get_async_connection()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_pubsub();
+    pubsub.subscribe(CHANNEL).await.map_err(|e| e.to_string())?;
+
+    let mut messages = pubsub.on_message();
+    while messages.next().await.is_some() {
+        let service = LogLevelService::new(DatabaseManager::get_connection().await.clone());
+        if let Err(e) = service.refresh().await {
+            error!("日志过滤规则重新加载失败: {}", e.message);
         }
+    }
 
-        info!("Initialized log level cache with {} items", cache.len());
+    Err("订阅连接已关闭".to_string())
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(target: Option<&str>, level_name: &str, expire_minute: Option<i64>) -> log_level::Model {
+        let created = chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc();
+        log_level::Model {
+            level_id: 1,
+            target: target.map(str::to_string),
+            level_name: level_name.to_string(),
+            description: None,
+            is_system: 0,
+            status: 0,
+            expire_time: expire_minute.map(|m| created + Duration::minutes(m)),
+            create_by: "admin".to_string(),
+            created_time: created,
+            update_by: None,
+            updated_time: None,
+        }
     }
 
-    /// 清空缓存
-    pub fn clear_cache(&self) {
-        let mut cache = self.level_cache.lock().unwrap();
-        cache.clear();
-        info!("Cleared log level cache");
+    #[test]
+    fn test_overrides_pick_most_verbose_active_level() {
+        let now = chrono::DateTime::from_timestamp(10 * 60, 0).unwrap().naive_utc();
+        let models = vec![
+            level(None, "warn", None),
+            level(None, "debug", None),
+            level(Some("sea_orm"), "trace", Some(5)),
+            level(Some("sea_orm"), "info", Some(30)),
+        ];
+
+        // sea_orm=trace 已到期，只剩 info
+        assert_eq!(
+            overrides(&models, now),
+            vec![(None, "debug".to_string()), (Some("sea_orm".to_string()), "info".to_string())]
+        );
     }
 }
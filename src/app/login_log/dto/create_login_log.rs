@@ -1,8 +1,9 @@
 /// 创建登录日志 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateLoginLogRequest {
     /// 用户ID
     pub user_id: Option<i64>,
@@ -31,7 +32,7 @@ pub struct CreateLoginLogRequest {
 }
 
 /// 登录日志创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateLoginLogResponse {
     /// 日志ID
     pub id: i64,
@@ -44,7 +45,7 @@ pub struct CreateLoginLogResponse {
 }
 
 /// 创建注销日志请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateLogoutLogRequest {
     /// 用户ID
     pub user_id: Option<i64>,
@@ -57,7 +58,7 @@ pub struct CreateLogoutLogRequest {
 }
 
 /// 注销日志创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateLogoutLogResponse {
     /// 日志ID
     pub id: i64,
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LoginLogQuery {
     pub username: Option<String>,
     pub ip: Option<String>,
@@ -10,7 +11,7 @@ pub struct LoginLogQuery {
 }
 
 /// 登录日志列表项（旧版结构，保留以兼容历史，但目前未在新服务中使用）
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct LoginLogListItem {
     pub id: i64,
     pub user_id: Option<i64>,
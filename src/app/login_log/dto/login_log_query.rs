@@ -1,10 +1,11 @@
 /// 登录日志查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use crate::app::login_log::dto::LoginLogListItem;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct LoginLogPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -42,7 +43,7 @@ pub struct LoginLogPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum LoginLogSortField {
     /// 按ID排序
@@ -75,7 +76,7 @@ impl std::fmt::Display for LoginLogSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -96,7 +97,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 登录日志分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct LoginLogPaginationResponse {
     /// 登录日志列表
     pub items: Vec<LoginLogListItem>,
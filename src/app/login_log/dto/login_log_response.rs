@@ -1,9 +1,10 @@
 /// 登录日志响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 登录日志详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct LoginLogDetailResponse {
     /// 日志ID
     pub id: i64,
@@ -43,7 +44,7 @@ pub struct LoginLogDetailResponse {
 
 /// 登录日志列表项响应
 /// 与前端 LoginLogResult 对齐：id、username、status、ip、country、region、os、browser、device、msg、login_time、created_time
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LoginLogListItem {
     /// 日志ID
     pub id: i64,
@@ -72,7 +73,7 @@ pub struct LoginLogListItem {
 }
 
 /// 登录日志统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LoginLogStatistics {
     /// 总登录次数
     pub total_count: usize,
@@ -95,7 +96,7 @@ pub struct LoginLogStatistics {
 }
 
 /// 登录IP统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LoginIpStat {
     /// IP地址
     pub ip: String,
@@ -104,7 +105,7 @@ pub struct LoginIpStat {
 }
 
 /// 失败原因统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct FailureReasonStat {
     /// 失败原因
     pub reason: String,
@@ -469,7 +469,7 @@ impl LoginLogService {
             .map(|(ip, count)| LoginIpStat { ip, count })
             .collect();
 
-        top_ips.sort_by(|a, b| b.count.cmp(&a.count));
+        top_ips.sort_by_key(|s| std::cmp::Reverse(s.count));
         top_ips.truncate(10);
 
         let mut failure_reasons: Vec<FailureReasonStat> = failure_reason_map
@@ -477,7 +477,7 @@ impl LoginLogService {
             .map(|(reason, count)| FailureReasonStat { reason, count })
             .collect();
 
-        failure_reasons.sort_by(|a, b| b.count.cmp(&a.count));
+        failure_reasons.sort_by_key(|s| std::cmp::Reverse(s.count));
         failure_reasons.truncate(10);
 
         Ok(LoginLogStatistics {
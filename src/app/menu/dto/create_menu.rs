@@ -1,9 +1,10 @@
 /// 创建菜单请求 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateMenuRequest {
     /// 菜单标题（多语言 key）
     #[validate(length(min = 1, max = 100))]
@@ -51,7 +52,7 @@ pub struct CreateMenuRequest {
 }
 
 /// 菜单创建响应 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateMenuResponse {
     /// 菜单ID
     pub id: i64,
@@ -1,9 +1,10 @@
 /// 菜单响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 菜单详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct MenuDetailResponse {
     /// 菜单ID
     pub id: i64,
@@ -43,7 +44,7 @@ pub struct MenuDetailResponse {
 }
 
 /// 菜单列表项响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MenuListItem {
     /// 菜单ID
     pub id: i64,
@@ -81,7 +82,7 @@ pub struct MenuListItem {
 }
 
 /// 菜单树节点
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct MenuTreeNode {
     /// 菜单ID
     pub id: i64,
@@ -114,5 +115,6 @@ pub struct MenuTreeNode {
     /// 备注
     pub remark: Option<String>,
     /// 子菜单
+    #[schema(no_recursion)]
     pub children: Vec<MenuTreeNode>,
 }
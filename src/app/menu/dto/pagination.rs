@@ -2,9 +2,10 @@
 
 use super::menu_response::MenuListItem;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct MenuPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -40,7 +41,7 @@ pub struct MenuPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum MenuSortField {
     /// 按ID排序
@@ -76,7 +77,7 @@ impl std::fmt::Display for MenuSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -97,7 +98,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 菜单分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct MenuPaginationResponse {
     /// 菜单列表
     pub list: Vec<MenuListItem>,
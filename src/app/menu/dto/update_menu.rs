@@ -1,9 +1,10 @@
 /// 更新菜单请求 DTO
 
 use serde::Deserialize;
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct UpdateMenuRequest {
     /// 菜单标题（多语言 key）
     #[validate(length(min = 1, max = 100))]
@@ -18,7 +18,7 @@ pub struct MenuService {
     db: DatabaseConnection,
 }
 
-#[derive(serde::Serialize, Clone, Debug)]
+#[derive(serde::Serialize, Clone, Debug, utoipa::ToSchema)]
 pub struct VbenMenuMeta {
     title: String,
     icon: Option<String>,
@@ -33,7 +33,7 @@ pub struct VbenMenuMeta {
     menu_visible_with_forbidden: bool,
 }
 
-#[derive(serde::Serialize, Clone, Debug)]
+#[derive(serde::Serialize, Clone, Debug, utoipa::ToSchema)]
 pub struct VbenMenuNode {
     id: i64,
     parent_id: Option<i64>,
@@ -41,6 +41,7 @@ pub struct VbenMenuNode {
     path: Option<String>,
     component: Option<String>,
     meta: VbenMenuMeta,
+    #[schema(no_recursion)]
     children: Vec<VbenMenuNode>,
 }
 
@@ -411,7 +412,7 @@ impl MenuService {
             .filter(|m| matches!(m.menu_type, 0 | 1 | 3 | 4) && m.display)
             .collect();
 
-        filtered_menus.sort_by(|a, b| a.sort.cmp(&b.sort));
+        filtered_menus.sort_by_key(|m| m.sort);
 
         let all_nodes: Vec<VbenMenuNode> = filtered_menus
             .into_iter()
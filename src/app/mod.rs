@@ -2,6 +2,7 @@
 /// 包含所有业务功能模块
 
 pub mod admin;
+pub mod alert;
 pub mod auth;
 pub mod user;
 pub mod dept;
@@ -14,10 +15,14 @@ pub mod data_scope;
 pub mod dict_data;
 pub mod dict_type;
 pub mod file_info;
+pub mod log_file;
 pub mod log_level;
 pub mod monitor;
 pub mod notice;
+pub mod oidc;
 pub mod plugin;
+pub mod rate_limit;
+pub mod system_metric;
 pub mod task;
 pub mod complete_module;
 pub mod login_log;
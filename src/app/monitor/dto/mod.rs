@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use chrono::{DateTime, Utc};
 
 /// 系统状态信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SystemStatus {
     pub status: String,
     pub uptime_seconds: i64,
@@ -10,7 +11,7 @@ pub struct SystemStatus {
 }
 
 /// 服务器性能指标
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServerMetrics {
     pub cpu: CpuInfo,
     pub mem: MemoryInfo,
@@ -20,7 +21,7 @@ pub struct ServerMetrics {
 }
 
 /// CPU 信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CpuInfo {
     pub usage: f64,
     pub logical_num: usize,
@@ -31,7 +32,7 @@ pub struct CpuInfo {
 }
 
 /// 内存信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MemoryInfo {
     pub total: f64,
     pub used: f64,
@@ -40,7 +41,7 @@ pub struct MemoryInfo {
 }
 
 /// 系统信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SystemInfo {
     pub name: String,
     pub ip: String,
@@ -49,7 +50,7 @@ pub struct SystemInfo {
 }
 
 /// 磁盘信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DiskInfo {
     pub dir: String,
     #[serde(rename = "type")]
@@ -62,7 +63,7 @@ pub struct DiskInfo {
 }
 
 /// 服务信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServiceInfo {
     pub name: String,
     pub version: String,
@@ -76,7 +77,7 @@ pub struct ServiceInfo {
 }
 
 /// 系统性能指标
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SystemMetrics {
     pub cpu_usage: f64,
     pub memory_usage: f64,
@@ -86,7 +87,7 @@ pub struct SystemMetrics {
 }
 
 /// 网络IO统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct NetworkIO {
     pub bytes_sent: u64,
     pub bytes_recv: u64,
@@ -95,7 +96,7 @@ pub struct NetworkIO {
 }
 
 /// 数据库连接池状态
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DatabaseStatus {
     pub active_connections: u32,
     pub idle_connections: u32,
@@ -104,7 +105,7 @@ pub struct DatabaseStatus {
 }
 
 /// API请求统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ApiMetrics {
     pub total_requests: u64,
     pub success_requests: u64,
@@ -115,7 +116,7 @@ pub struct ApiMetrics {
 }
 
 /// 监控查询参数
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema)]
 pub struct MonitorQuery {
     pub metric_type: Option<String>,
     pub start_time: Option<DateTime<Utc>>,
@@ -123,7 +124,7 @@ pub struct MonitorQuery {
 }
 
 /// 监控数据点
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MetricDataPoint {
     pub timestamp: DateTime<Utc>,
     pub value: f64,
@@ -131,14 +132,14 @@ pub struct MetricDataPoint {
 }
 
 /// 监控数据响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MonitorResponse {
     pub metric_type: String,
     pub data_points: Vec<MetricDataPoint>,
 }
 
 /// 健康检查响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthStatus {
     pub status: String,
     pub version: String,
@@ -148,7 +149,7 @@ pub struct HealthStatus {
 }
 
 /// 健康检查响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthCheckResponse {
     pub status: String,
     pub timestamp: DateTime<Utc>,
@@ -156,7 +157,7 @@ pub struct HealthCheckResponse {
 }
 
 /// 服务健康状态
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServiceHealth {
     pub service_name: String,
     pub status: String,
@@ -165,7 +166,7 @@ pub struct ServiceHealth {
 }
 
 /// Redis监控信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RedisMetrics {
     /// Redis 服务器信息（格式化为字符串的字典）
     pub info: std::collections::HashMap<String, String>,
@@ -174,14 +175,14 @@ pub struct RedisMetrics {
 }
 
 /// Redis 命令统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RedisCommandStat {
     pub name: String,
     pub value: String,
 }
 
 /// Redis服务器信息（内部解析使用）
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RedisServerInfo {
     pub version: String,
     pub connected_clients: u64,
@@ -192,7 +193,7 @@ pub struct RedisServerInfo {
 }
 
 /// 在线会话信息（令牌详情）
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct OnlineSession {
     /// 用户 ID
     pub id: i64,
@@ -219,7 +220,7 @@ pub struct OnlineSession {
 }
 
 /// Token 额外信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TokenExtraInfo {
     pub username: Option<String>,
     pub nickname: Option<String>,
@@ -232,7 +233,7 @@ pub struct TokenExtraInfo {
 }
 
 /// 任务信息
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TaskInfo {
     pub id: i64,
     pub name: String,
@@ -241,3 +242,69 @@ pub struct TaskInfo {
     pub status: String,
     pub next_run: Option<DateTime<Utc>>,
 }
+
+/// 任务 Worker（应用实例）信息
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkerInfo {
+    pub node_id: String,
+    pub hostname: String,
+    pub ok: bool,
+    pub started_at: DateTime<Utc>,
+    /// 该实例持有的在线会话数
+    pub sessions: usize,
+}
+
+/// 后台队列状态
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueueStatus {
+    pub name: String,
+    /// 等待处理
+    pub pending: u64,
+    /// 处理中
+    pub running: u64,
+    /// 处理失败
+    pub failed: u64,
+}
+
+/// 任务 Worker 与队列状态
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskWorkerStatus {
+    pub workers: Vec<WorkerInfo>,
+    pub queues: Vec<QueueStatus>,
+}
+
+/// 探针中单个依赖的检查结果
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProbeCheck {
+    /// up / down
+    pub status: String,
+    /// 检查耗时（毫秒）
+    pub latency_ms: f64,
+    /// 附加信息（如磁盘剩余比例、心跳间隔）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 存活/就绪探针结果
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProbeReport {
+    /// ok / fail / shutting_down
+    pub status: String,
+    pub checks: std::collections::BTreeMap<String, ProbeCheck>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ProbeCheck {
+    pub fn is_up(&self) -> bool {
+        self.status == "up"
+    }
+}
+
+impl ProbeReport {
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
@@ -1,13 +1,17 @@
 use axum::{
     routing::*,
     Router,
-    extract::{Path, Query, State},
+    extract::{ConnectInfo, Path, Query, Request, State},
+    http::{header, StatusCode},
+    Json,
     response::IntoResponse,
 };
+use std::net::{IpAddr, SocketAddr};
 
 use crate::common::response::ResponseModel;
-use crate::common::exception::AppError;
-use crate::app::monitor::service::MonitorService;
+use crate::common::exception::{AppError, ErrorCode};
+use crate::app::monitor::service::{MonitorService, ProbeService, PrometheusService};
+use crate::core::SETTINGS;
 use redis::Client as RedisClient;
 
 pub fn monitor_routes(redis_client: Option<RedisClient>) -> Router {
@@ -31,6 +35,82 @@ pub fn monitor_routes(redis_client: Option<RedisClient>) -> Router {
         .with_state(redis_client)
 }
 
+/// Prometheus 指标路由，挂载在根路径，不经过 JWT 认证，由令牌或 IP 白名单保护
+pub fn metrics_routes() -> Router {
+    Router::new().route("/metrics", get(export_metrics))
+}
+
+/// 存活/就绪探针路由，挂载在根路径，不经过 JWT 认证
+pub fn probe_routes() -> Router {
+    Router::new()
+        .route("/livez", get(livez))
+        .route("/readyz", get(readyz))
+}
+
+/// 存活探针：后台任务卡死时返回 503
+async fn livez() -> impl IntoResponse {
+    probe_response(ProbeService::liveness().await)
+}
+
+/// 就绪探针：依赖不可用或正在停机时返回 503
+async fn readyz() -> impl IntoResponse {
+    probe_response(ProbeService::readiness().await)
+}
+
+fn probe_response(report: crate::app::monitor::dto::ProbeReport) -> impl IntoResponse {
+    let status = if report.is_ok() { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, [(header::CACHE_CONTROL, "no-store")], Json(report))
+}
+
+/// 导出 Prometheus 指标
+async fn export_metrics(request: Request) -> Result<impl IntoResponse, AppError> {
+    if !metrics_access_allowed(&request) {
+        return Err(AppError::new(ErrorCode::Forbidden));
+    }
+
+    let body = PrometheusService::render().await;
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")], body))
+}
+
+/// 校验 /metrics 访问权限：携带配置的令牌，或来源 IP 在白名单内
+///
+/// 只信任 TCP 连接的对端地址，不读取 X-Forwarded-For，避免伪造请求头绕过白名单
+fn metrics_access_allowed(request: &Request) -> bool {
+    if let Some(expected) = SETTINGS.metrics_token.as_deref().filter(|t| !t.is_empty()) {
+        let provided = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        if let Some(provided) = provided {
+            // 固定时间比较，避免通过响应耗时猜测令牌
+            let matched = provided.len() == expected.len()
+                && provided
+                    .bytes()
+                    .zip(expected.bytes())
+                    .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+                    == 0;
+            if matched {
+                return true;
+            }
+        }
+    }
+
+    let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>() else {
+        return false;
+    };
+    // IPv4 映射的 IPv6 地址按 IPv4 比较
+    let ip = match addr.ip() {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(IpAddr::V6(v6)),
+        ip => ip,
+    };
+    SETTINGS
+        .metrics_allow_ips
+        .iter()
+        .filter_map(|allowed| allowed.trim().parse::<IpAddr>().ok())
+        .any(|allowed| allowed == ip)
+}
+
 /// 获取服务器监控信息
 async fn get_server_metrics(
     State(redis_client): State<Option<RedisClient>>,
@@ -97,7 +177,7 @@ async fn get_system_status(
     Ok(ResponseModel::success(result))
 }
 
-/// 获取API指标
+/// 获取API指标（自进程启动以来的 HTTP 请求统计）
 async fn get_api_metrics(
     State(redis_client): State<Option<RedisClient>>,
 ) -> Result<impl IntoResponse, AppError> {
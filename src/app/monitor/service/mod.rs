@@ -1,4 +1,7 @@
 pub use monitor_service::*;
+pub use probe_service::ProbeService;
+pub use prometheus_service::{PrometheusService, HTTP_METRICS};
 
 pub mod monitor_service;
-
+pub mod probe_service;
+pub mod prometheus_service;
@@ -1,11 +1,13 @@
 use tracing::{info, error};
 use crate::app::monitor::dto::{*, TokenExtraInfo};
 use crate::common::exception::{AppError, ErrorCode};
+use crate::database::redis::RedisManager;
 use chrono::Utc;
 use std::time::SystemTime;
 use redis::Client as RedisClient;
 use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, PaginatorTrait};
-use crate::database::entity::{opera_log, login_log};
+use crate::app::monitor::service::prometheus_service::HTTP_METRICS;
+use crate::app::monitor::service::ProbeService;
 use sysinfo::{System, Disks, Pid};
 
 /// 监控服务实现
@@ -199,7 +201,7 @@ impl MonitorService {
     }
 
     /// 获取主机名
-    fn get_hostname() -> String {
+    pub(crate) fn get_hostname() -> String {
         #[cfg(target_os = "windows")]
         {
             use std::process::Command;
@@ -247,7 +249,7 @@ impl MonitorService {
     }
 
     /// 获取本机 IP 地址（非阻塞）
-    fn get_local_ip() -> Option<String> {
+    pub(crate) fn get_local_ip() -> Option<String> {
         use std::net::{TcpStream, SocketAddr};
         use std::time::Duration;
         
@@ -388,20 +390,10 @@ impl MonitorService {
         let token_pattern = format!("{}:*", SETTINGS.token_redis_prefix);
         info!("正在查询 Redis keys，模式: {}", token_pattern);
         
-        let token_keys: Vec<String> = redis::cmd("KEYS")
-            .arg(&token_pattern)
-            .query(&mut conn)
-            .unwrap_or_default();
+        // 监控频道会定时调用，使用 SCAN 避免 KEYS 阻塞 Redis
+        let token_keys = RedisManager::scan_keys(&token_pattern).await.unwrap_or_default();
         
         info!("找到 {} 个 token keys", token_keys.len());
-        if token_keys.is_empty() {
-            // 尝试查看 Redis 中实际存在的所有 keys（仅用于调试）
-            let all_keys: Vec<String> = redis::cmd("KEYS")
-                .arg("*token*")
-                .query(&mut conn)
-                .unwrap_or_default();
-            info!("Redis 中包含 'token' 的所有 keys: {:?}", all_keys.iter().take(10).collect::<Vec<_>>());
-        }
 
         // 2. 获取在线客户端列表
         let online_clients: Vec<String> = redis::cmd("SMEMBERS")
@@ -412,6 +404,8 @@ impl MonitorService {
         info!("找到 {} 个在线客户端", online_clients.len());
 
         let mut sessions = Vec::new();
+        let mut oidc_validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::EdDSA);
+        oidc_validation.validate_aud = false;
 
         // 3. 遍历所有 token
         for key in token_keys {
@@ -421,8 +415,10 @@ impl MonitorService {
                 Err(_) => continue,
             };
 
-            // 解码 token 获取 payload
-            let token_payload = match CryptoUtils::verify_jwt(&token, &SETTINGS.token_secret_key) {
+            // 解码 token 获取 payload（OIDC 授权服务签发的令牌使用签名密钥集验证）
+            let token_payload = match CryptoUtils::verify_jwt(&token, &SETTINGS.token_secret_key)
+                .or_else(|_| crate::utils::encrypt::KEYSET.verify::<crate::utils::encrypt::JwtPayload>(&token, &oidc_validation))
+            {
                 Ok(payload) => payload,
                 Err(_) => continue,
             };
@@ -501,33 +497,98 @@ impl MonitorService {
 
     /// 踢出指定在线用户
     pub async fn kick_out_session(&self, session_id: &str) -> Result<String, AppError> {
-        let Some(client) = &self.redis_client else {
-            return Err(AppError::with_message(ErrorCode::BadRequest, "Redis客户端未配置"));
-        };
+        use crate::core::conf::SETTINGS;
 
-        let mut conn = client.get_connection().map_err(|e| {
-            error!("连接Redis失败: {:?}", e);
-            AppError::with_message(ErrorCode::DatabaseError, "连接Redis失败")
-        })?;
+        // 删除会话令牌及附加信息，并移出在线集合
+        let mut keys: Vec<String> = Vec::new();
+        for prefix in [&SETTINGS.token_redis_prefix, &SETTINGS.token_extra_info_redis_prefix] {
+            keys.extend(RedisManager::scan_keys(&format!("{}:*:{}", prefix, session_id)).await?);
+        }
+        if keys.is_empty() {
+            return Err(AppError::with_message(ErrorCode::NotFound, "用户不在线或已退出"));
+        }
 
-        // 删除会话
-        let result: Result<i64, redis::RedisError> = redis::cmd("DEL")
-            .arg(format!("online_sessions:{}", session_id))
-            .query(&mut conn);
+        let mut conn = RedisManager::get_connection().await?;
+        let result: Result<(), redis::RedisError> = redis::pipe()
+            .del(&keys)
+            .srem(&SETTINGS.token_online_redis_prefix, session_id)
+            .query_async(&mut conn)
+            .await;
+        if let Err(e) = result {
+            error!("踢出用户失败: {:?}", e);
+            return Err(AppError::with_message(ErrorCode::DatabaseError, "踢出用户失败"));
+        }
 
-        match result {
-            Ok(deleted_count) if deleted_count > 0 => {
-                info!("踢出在线用户成功: {}", session_id);
-                Ok(format!("成功踢出用户: {}", session_id))
-            }
-            Ok(_) => {
-                Err(AppError::with_message(ErrorCode::NotFound, "用户不在线或已退出"))
-            }
-            Err(e) => {
-                error!("踢出用户失败: {:?}", e);
-                Err(AppError::with_message(ErrorCode::DatabaseError, "踢出用户失败"))
-            }
+        // 关闭该会话在所有实例上的 WebSocket 连接
+        crate::websocket::disconnect_session(session_id).await;
+
+        info!("踢出在线用户成功: {}", session_id);
+        Ok(format!("成功踢出用户: {}", session_id))
+    }
+
+    /// 获取任务 Worker 与队列状态
+    ///
+    /// Worker 为心跳有效的应用实例；队列包括邮件发送队列、通知推送队列和启用的定时任务
+    pub async fn get_task_worker_status(&self) -> Result<TaskWorkerStatus, AppError> {
+        use email_plugin::entity::email_record;
+        use notice_plugin::entity::notice;
+        use crate::database::entity::task_scheduler;
+
+        let db = Self::get_db().await;
+
+        let workers = crate::websocket::presence::nodes()
+            .await?
+            .into_iter()
+            .map(|node| WorkerInfo {
+                node_id: node.node_id,
+                hostname: node.hostname,
+                ok: true,
+                started_at: node.started_at,
+                sessions: node.sessions,
+            })
+            .collect();
+
+        // 插件未安装时对应的表不存在，跳过该队列
+        let mut queues = Vec::new();
+        let email_count = |status: i16| {
+            email_record::Entity::find()
+                .filter(email_record::Column::Status.eq(status))
+                .count(&db)
+        };
+        let email: Result<QueueStatus, sea_orm::DbErr> = async {
+            Ok(QueueStatus {
+                name: "email".to_string(),
+                pending: email_count(email_record::STATUS_PENDING).await?,
+                running: email_count(email_record::STATUS_SENDING).await?,
+                failed: email_count(email_record::STATUS_FAILED).await?,
+            })
         }
+        .await;
+        queues.extend(email.ok());
+
+        let notice = notice::Entity::find()
+            .filter(notice::Column::Status.eq(1))
+            .filter(notice::Column::Dispatched.eq(0))
+            .count(&db)
+            .await;
+        queues.extend(notice.ok().map(|pending| QueueStatus {
+            name: "notice".to_string(),
+            pending,
+            running: 0,
+            failed: 0,
+        }));
+
+        queues.push(QueueStatus {
+            name: "scheduler".to_string(),
+            pending: task_scheduler::Entity::find()
+                .filter(task_scheduler::Column::Enabled.eq(true))
+                .count(&db)
+                .await?,
+            running: 0,
+            failed: 0,
+        });
+
+        Ok(TaskWorkerStatus { workers, queues })
     }
 
     /// 获取已注册任务列表
@@ -557,83 +618,31 @@ impl MonitorService {
     }
 
     /// 获取API指标
+    ///
+    /// 来自请求指标中间件的内存统计（自进程启动以来），与 /metrics 导出的数据一致
     pub async fn get_api_metrics(&self) -> Result<ApiMetrics, AppError> {
-        let db = Self::get_db().await;
-        let base_query = opera_log::Entity::find();
-
-        let total_requests = base_query.clone().count(&db).await.map_err(|e| {
-            error!("获取总请求数失败: {:?}", e);
-            AppError::with_message(ErrorCode::DatabaseError, "获取总请求数失败")
-        })?;
-
-        let success_requests = base_query
-            .clone()
-            .filter(opera_log::Column::Status.eq(0))
-            .count(&db)
-            .await
-            .map_err(|e| {
-                error!("获取成功请求数失败: {:?}", e);
-                AppError::with_message(ErrorCode::DatabaseError, "获取成功请求数失败")
-            })?;
-
-        let error_requests = total_requests - success_requests;
-
-        let avg_response_time = {
-            let logs = base_query
-                .all(&db)
-                .await
-                .map_err(|e| {
-                    error!("获取操作日志失败: {:?}", e);
-                    AppError::with_message(ErrorCode::DatabaseError, "获取操作日志失败")
-                })?;
-
-            if logs.is_empty() {
-                0.0
-            } else {
-                let total_time: f32 = logs
-                    .iter()
-                    .map(|log| log.cost_time)
-                    .sum();
-                total_time as f64 / logs.len() as f64
-            }
-        };
-
-        info!("获取API指标成功");
+        let summary = HTTP_METRICS.summary();
 
         Ok(ApiMetrics {
-            total_requests,
-            success_requests,
-            error_requests,
-            avg_response_time,
-            requests_per_minute: 500, // 这个值需要从实际统计中获取
+            total_requests: summary.total_requests,
+            success_requests: summary.success_requests,
+            error_requests: summary.error_requests,
+            avg_response_time: (summary.avg_response_time_ms * 100.0).round() / 100.0,
+            requests_per_minute: summary.requests_per_minute,
             timestamp: Utc::now(),
         })
     }
 
     /// 健康检查
     pub async fn health_check(&self) -> Result<HealthStatus, AppError> {
-        let db = Self::get_db().await;
-        // 检查数据库连接
-        let db_healthy = match login_log::Entity::find()
-            .count(&db)
-            .await
-        {
-            Ok(_) => "connected",
-            Err(_) => "disconnected",
-        };
-
-        // 检查Redis连接
-        let redis_healthy = if let Some(client) = &self.redis_client {
-            match client.get_connection() {
-                Ok(_) => "connected",
-                Err(_) => "disconnected",
-            }
-        } else {
-            "not configured"
-        };
+        let (database, redis) = tokio::join!(ProbeService::database(), ProbeService::redis());
+        let status = if database.is_up() && redis.is_up() { "healthy" } else { "unhealthy" };
+        let connection_state = |check: &ProbeCheck| if check.is_up() { "connected" } else { "disconnected" };
+        let db_healthy = connection_state(&database);
+        let redis_healthy = connection_state(&redis);
 
         Ok(HealthStatus {
-            status: "healthy".to_string(),
+            status: status.to_string(),
             version: "0.1.0".to_string(),
             database: db_healthy.to_string(),
             redis: redis_healthy.to_string(),
@@ -0,0 +1,160 @@
+/// 存活/就绪探针
+///
+/// 存活探针只检查进程内状态（后台任务心跳），依赖故障时不会触发重启；
+/// 就绪探针并行检查数据库、Redis、后台任务心跳和磁盘空间，每项单独限时，停机期间直接返回失败
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, Statement};
+use sysinfo::Disks;
+
+use crate::app::monitor::dto::{ProbeCheck, ProbeReport};
+use crate::core::health;
+use crate::core::SETTINGS;
+use crate::database::redis::RedisManager;
+use crate::database::DatabaseManager;
+
+pub struct ProbeService;
+
+impl ProbeService {
+    /// 存活探针
+    pub async fn liveness() -> ProbeReport {
+        let mut checks = BTreeMap::new();
+        checks.insert("heartbeats".to_string(), Self::timed(async { Self::check_heartbeats() }).await);
+        Self::report(checks, false)
+    }
+
+    /// 就绪探针
+    pub async fn readiness() -> ProbeReport {
+        let (database, redis, heartbeats, disk) = tokio::join!(
+            Self::database(),
+            Self::redis(),
+            Self::timed(async { Self::check_heartbeats() }),
+            Self::timed(Self::check_disk()),
+        );
+
+        let mut checks = BTreeMap::new();
+        checks.insert("database".to_string(), database);
+        checks.insert("redis".to_string(), redis);
+        checks.insert("heartbeats".to_string(), heartbeats);
+        checks.insert("disk".to_string(), disk);
+        Self::report(checks, health::is_shutting_down())
+    }
+
+    /// 数据库连通性（`SELECT 1`）
+    pub async fn database() -> ProbeCheck {
+        Self::timed(Self::check_database()).await
+    }
+
+    /// Redis 连通性（`PING`）
+    pub async fn redis() -> ProbeCheck {
+        Self::timed(Self::check_redis()).await
+    }
+
+    fn report(checks: BTreeMap<String, ProbeCheck>, shutting_down: bool) -> ProbeReport {
+        let status = if shutting_down {
+            "shutting_down"
+        } else if checks.values().all(ProbeCheck::is_up) {
+            "ok"
+        } else {
+            "fail"
+        };
+
+        ProbeReport {
+            status: status.to_string(),
+            checks,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// 执行单项检查并计时，超时视为失败
+    async fn timed<F>(check: F) -> ProbeCheck
+    where
+        F: Future<Output = Result<Option<String>, String>>,
+    {
+        let start = Instant::now();
+        let result = tokio::time::timeout(Duration::from_millis(SETTINGS.health_check_timeout_ms), check).await;
+        let latency_ms = (start.elapsed().as_secs_f64() * 1000.0 * 100.0).round() / 100.0;
+
+        let (status, detail, error) = match result {
+            Ok(Ok(detail)) => ("up", detail, None),
+            Ok(Err(e)) => ("down", None, Some(e)),
+            Err(_) => ("down", None, Some(format!("超时（{} ms）", SETTINGS.health_check_timeout_ms))),
+        };
+        ProbeCheck {
+            status: status.to_string(),
+            latency_ms,
+            detail,
+            error,
+        }
+    }
+
+    async fn check_database() -> Result<Option<String>, String> {
+        let db = DatabaseManager::get_connection().await;
+        db.execute(Statement::from_string(db.get_database_backend(), "SELECT 1"))
+            .await
+            .map(|_| None)
+            .map_err(|e| e.to_string())
+    }
+
+    async fn check_redis() -> Result<Option<String>, String> {
+        let mut conn = RedisManager::get_connection().await.map_err(|e| e.message)?;
+        let pong: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| e.to_string())?;
+        if pong == "PONG" {
+            Ok(None)
+        } else {
+            Err(format!("意外的响应: {}", pong))
+        }
+    }
+
+    /// 后台任务（在线状态心跳、密钥轮换、指标采集、告警评估等）是否按时运行
+    fn check_heartbeats() -> Result<Option<String>, String> {
+        let statuses = health::heartbeats();
+        let stale: Vec<String> = statuses
+            .iter()
+            .filter(|s| !s.is_alive())
+            .map(|s| format!("{} {}s 未运行", s.name, s.age.as_secs()))
+            .collect();
+        if !stale.is_empty() {
+            return Err(stale.join(", "));
+        }
+
+        let names: Vec<&str> = statuses.iter().map(|s| s.name).collect();
+        Ok(Some(names.join(",")))
+    }
+
+    /// 工作目录所在磁盘的剩余空间比例不低于 `health_disk_min_free_percent`
+    async fn check_disk() -> Result<Option<String>, String> {
+        tokio::task::spawn_blocking(|| {
+            let cwd = std::env::current_dir()
+                .and_then(|p| p.canonicalize())
+                .map_err(|e| e.to_string())?;
+            let disks = Disks::new_with_refreshed_list();
+            let disk = disks
+                .iter()
+                .filter(|d| cwd.starts_with(d.mount_point()))
+                .max_by_key(|d| d.mount_point().components().count())
+                .ok_or_else(|| format!("未找到 {} 所在磁盘", cwd.display()))?;
+
+            let total = disk.total_space();
+            if total == 0 {
+                return Ok(None);
+            }
+            let free_percent = disk.available_space() as f64 / total as f64 * 100.0;
+            let detail = format!("{} 剩余 {:.1}%", disk.mount_point().display(), free_percent);
+            if free_percent < SETTINGS.health_disk_min_free_percent {
+                Err(detail)
+            } else {
+                Ok(Some(detail))
+            }
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+}
@@ -0,0 +1,449 @@
+/// Prometheus 指标导出服务
+/// 维护 HTTP 请求计数与延迟直方图，并汇总连接池、Redis、定时任务、插件和进程指标为文本格式
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::Lazy;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QuerySelect};
+use sysinfo::{Pid, ProcessRefreshKind, System};
+
+use crate::app::plugin::metrics::PLUGIN_METRICS;
+use crate::core::conf::{DatabaseType, SETTINGS};
+
+/// 未匹配任何路由的请求使用的路由标签，避免按原始路径产生无限多的时间序列
+pub const UNMATCHED_ROUTE: &str = "<unmatched>";
+
+/// 请求延迟直方图桶上限（秒）
+const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// 全局 HTTP 指标
+pub static HTTP_METRICS: Lazy<HttpMetrics> = Lazy::new(HttpMetrics::new);
+
+/// 进程信息采样器（CPU 使用率需要两次采样之间的差值，因此复用同一个实例）
+static PROCESS_SAMPLER: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new()));
+
+/// 单个 路由/方法/状态码 组合的统计
+#[derive(Debug, Clone, Default)]
+struct RequestSeries {
+    count: u64,
+    sum_seconds: f64,
+    /// 各桶内的请求数（非累计），输出时再累加
+    buckets: [u64; LATENCY_BUCKETS.len()],
+}
+
+/// 最近一分钟的请求数，按秒分槽
+#[derive(Debug)]
+struct MinuteWindow {
+    slots: [(u64, u64); 60],
+}
+
+impl MinuteWindow {
+    fn hit(&mut self, now: u64) {
+        let slot = &mut self.slots[(now % 60) as usize];
+        if slot.0 != now {
+            *slot = (now, 0);
+        }
+        slot.1 += 1;
+    }
+
+    fn count(&self, now: u64) -> u64 {
+        self.slots
+            .iter()
+            .filter(|(second, _)| *second <= now && now - *second < 60)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+/// HTTP 请求汇总
+#[derive(Debug, Clone, Default)]
+pub struct HttpSummary {
+    pub total_requests: u64,
+    pub success_requests: u64,
+    pub error_requests: u64,
+    /// 平均响应时间（毫秒）
+    pub avg_response_time_ms: f64,
+    pub requests_per_minute: u64,
+}
+
+/// HTTP 指标注册表
+pub struct HttpMetrics {
+    series: Mutex<HashMap<(String, String, u16), RequestSeries>>,
+    window: Mutex<MinuteWindow>,
+    in_flight: AtomicI64,
+}
+
+impl HttpMetrics {
+    pub fn new() -> Self {
+        Self {
+            series: Mutex::new(HashMap::new()),
+            window: Mutex::new(MinuteWindow { slots: [(0, 0); 60] }),
+            in_flight: AtomicI64::new(0),
+        }
+    }
+
+    /// 请求开始处理
+    pub fn start_request(&self) {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 请求处理结束，记录路由、方法、状态码和耗时
+    pub fn finish_request(&self, route: &str, method: &str, status: u16, elapsed: Duration) {
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+
+        let seconds = elapsed.as_secs_f64();
+        {
+            let mut series = self.series.lock().unwrap();
+            let entry = series
+                .entry((route.to_string(), method.to_string(), status))
+                .or_default();
+            entry.count += 1;
+            entry.sum_seconds += seconds;
+            if let Some(index) = LATENCY_BUCKETS.iter().position(|bound| seconds <= *bound) {
+                entry.buckets[index] += 1;
+            }
+        }
+        self.window.lock().unwrap().hit(unix_seconds());
+    }
+
+    /// 自进程启动以来的请求汇总，状态码 < 400 视为成功
+    pub fn summary(&self) -> HttpSummary {
+        let series = self.series.lock().unwrap();
+        let mut summary = HttpSummary::default();
+        let mut sum_seconds = 0.0;
+        for ((_, _, status), entry) in series.iter() {
+            summary.total_requests += entry.count;
+            if *status < 400 {
+                summary.success_requests += entry.count;
+            }
+            sum_seconds += entry.sum_seconds;
+        }
+        summary.error_requests = summary.total_requests - summary.success_requests;
+        if summary.total_requests > 0 {
+            summary.avg_response_time_ms = sum_seconds * 1000.0 / summary.total_requests as f64;
+        }
+        summary.requests_per_minute = self.window.lock().unwrap().count(unix_seconds());
+        summary
+    }
+
+    /// 以 Prometheus 文本格式输出
+    fn encode(&self, out: &mut PrometheusWriter) {
+        let mut series: Vec<_> = self
+            .series
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        series.sort_by(|a, b| a.0.cmp(&b.0));
+
+        out.header("http_requests_total", "counter", "HTTP 请求总数");
+        for ((route, method, status), entry) in &series {
+            let status = status.to_string();
+            out.sample(
+                "http_requests_total",
+                &[("route", route.as_str()), ("method", method.as_str()), ("status", status.as_str())],
+                entry.count as f64,
+            );
+        }
+
+        out.header("http_request_duration_seconds", "histogram", "HTTP 请求处理耗时（秒）");
+        for ((route, method, status), entry) in &series {
+            let status = status.to_string();
+            let labels = [("route", route.as_str()), ("method", method.as_str()), ("status", status.as_str())];
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(entry.buckets.iter()) {
+                cumulative += count;
+                let le = bound.to_string();
+                out.sample(
+                    "http_request_duration_seconds_bucket",
+                    &[labels[0], labels[1], labels[2], ("le", le.as_str())],
+                    cumulative as f64,
+                );
+            }
+            out.sample(
+                "http_request_duration_seconds_bucket",
+                &[labels[0], labels[1], labels[2], ("le", "+Inf")],
+                entry.count as f64,
+            );
+            out.sample("http_request_duration_seconds_sum", &labels, entry.sum_seconds);
+            out.sample("http_request_duration_seconds_count", &labels, entry.count as f64);
+        }
+
+        out.header("http_requests_in_flight", "gauge", "正在处理的 HTTP 请求数");
+        out.sample("http_requests_in_flight", &[], self.in_flight.load(Ordering::Relaxed) as f64);
+    }
+}
+
+impl Default for HttpMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prometheus 文本格式写入器
+#[derive(Default)]
+pub struct PrometheusWriter {
+    buf: String,
+}
+
+impl PrometheusWriter {
+    /// 写入指标的 HELP 和 TYPE 行
+    pub fn header(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.buf, "# HELP {} {}", name, help.replace('\\', "\\\\").replace('\n', "\\n"));
+        let _ = writeln!(self.buf, "# TYPE {} {}", name, kind);
+    }
+
+    /// 写入一个样本
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.buf.push_str(name);
+        if !labels.is_empty() {
+            self.buf.push('{');
+            for (i, (key, value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.buf.push(',');
+                }
+                let escaped = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+                let _ = write!(self.buf, "{}=\"{}\"", key, escaped);
+            }
+            self.buf.push('}');
+        }
+        let _ = writeln!(self.buf, " {}", format_value(value));
+    }
+
+    pub fn finish(self) -> String {
+        self.buf
+    }
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf".to_string() } else { "-Inf".to_string() }
+    } else {
+        value.to_string()
+    }
+}
+
+fn unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Prometheus 指标导出服务
+pub struct PrometheusService;
+
+impl PrometheusService {
+    /// 采集全部指标并输出为 Prometheus 文本格式
+    ///
+    /// 依赖（数据库、Redis）不可用时对应的 `*_up` 指标为 0，其余指标照常输出
+    pub async fn render() -> String {
+        let mut out = PrometheusWriter::default();
+
+        HTTP_METRICS.encode(&mut out);
+
+        let db = crate::database::DatabaseManager::get_connection().await;
+        Self::encode_db_pool(db, &mut out);
+        Self::encode_redis(&mut out).await;
+        Self::encode_scheduler(db, &mut out).await;
+        Self::encode_plugins(&mut out).await;
+        Self::encode_process(&mut out);
+
+        out.finish()
+    }
+
+    /// 数据库连接池状态
+    fn encode_db_pool(db: &DatabaseConnection, out: &mut PrometheusWriter) {
+        let (size, idle, max) = match SETTINGS.database_type {
+            DatabaseType::MySQL => {
+                let pool = db.get_mysql_connection_pool();
+                (pool.size(), pool.num_idle(), pool.options().get_max_connections())
+            }
+            DatabaseType::PostgreSQL => {
+                let pool = db.get_postgres_connection_pool();
+                (pool.size(), pool.num_idle(), pool.options().get_max_connections())
+            }
+            DatabaseType::SQLite => {
+                let pool = db.get_sqlite_connection_pool();
+                (pool.size(), pool.num_idle(), pool.options().get_max_connections())
+            }
+        };
+
+        out.header("db_pool_connections", "gauge", "数据库连接池当前连接数");
+        out.sample("db_pool_connections", &[], size as f64);
+        out.header("db_pool_idle_connections", "gauge", "数据库连接池空闲连接数");
+        out.sample("db_pool_idle_connections", &[], idle as f64);
+        out.header("db_pool_max_connections", "gauge", "数据库连接池最大连接数");
+        out.sample("db_pool_max_connections", &[], max as f64);
+    }
+
+    /// Redis 连接状态
+    async fn encode_redis(out: &mut PrometheusWriter) {
+        let info: Option<String> = match crate::database::redis::RedisManager::get_connection().await {
+            Ok(mut conn) => redis::cmd("INFO").query_async(&mut conn).await.ok(),
+            Err(_) => None,
+        };
+
+        out.header("redis_up", "gauge", "Redis 是否可用");
+        out.sample("redis_up", &[], if info.is_some() { 1.0 } else { 0.0 });
+
+        let Some(info) = info else {
+            return;
+        };
+        let fields: HashMap<&str, &str> = info
+            .lines()
+            .filter_map(|line| line.trim().split_once(':'))
+            .collect();
+        let exported = [
+            ("connected_clients", "redis_connected_clients", "gauge", "Redis 已连接客户端数"),
+            ("blocked_clients", "redis_blocked_clients", "gauge", "Redis 阻塞中的客户端数"),
+            ("used_memory", "redis_used_memory_bytes", "gauge", "Redis 已使用内存（字节）"),
+            ("total_connections_received", "redis_connections_received_total", "counter", "Redis 累计接受的连接数"),
+            ("rejected_connections", "redis_rejected_connections_total", "counter", "Redis 累计拒绝的连接数"),
+            ("total_commands_processed", "redis_commands_processed_total", "counter", "Redis 累计处理的命令数"),
+        ];
+        for (field, name, kind, help) in exported {
+            if let Some(value) = fields.get(field).and_then(|v| v.parse::<f64>().ok()) {
+                out.header(name, kind, help);
+                out.sample(name, &[], value);
+            }
+        }
+    }
+
+    /// 定时任务执行结果，按任务名称和状态汇总 task_result
+    async fn encode_scheduler(db: &DatabaseConnection, out: &mut PrometheusWriter) {
+        use crate::database::entity::task_result;
+
+        let rows: Vec<(Option<String>, String, i64)> = match task_result::Entity::find()
+            .select_only()
+            .column(task_result::Column::Name)
+            .column(task_result::Column::Status)
+            .column_as(task_result::Column::Id.count(), "count")
+            .group_by(task_result::Column::Name)
+            .group_by(task_result::Column::Status)
+            .into_tuple()
+            .all(db)
+            .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::warn!("统计定时任务执行结果失败: {}", err);
+                return;
+            }
+        };
+
+        out.header("scheduler_job_results_total", "counter", "定时任务执行结果数");
+        for (name, status, count) in rows {
+            let name = name.unwrap_or_default();
+            out.sample(
+                "scheduler_job_results_total",
+                &[("task", name.as_str()), ("status", status.as_str())],
+                count as f64,
+            );
+        }
+    }
+
+    /// 插件请求指标
+    async fn encode_plugins(out: &mut PrometheusWriter) {
+        let mut metrics = PLUGIN_METRICS.get_all_metrics().await;
+        metrics.sort_by(|a, b| a.plugin_name.cmp(&b.plugin_name));
+
+        out.header("plugin_requests_total", "counter", "插件请求总数");
+        for m in &metrics {
+            let successful = m.successful_requests as f64;
+            let failed = m.failed_requests as f64;
+            out.sample("plugin_requests_total", &[("plugin", m.plugin_name.as_str()), ("result", "success")], successful);
+            out.sample("plugin_requests_total", &[("plugin", m.plugin_name.as_str()), ("result", "failure")], failed);
+        }
+
+        out.header("plugin_response_time_milliseconds", "gauge", "插件最近请求的响应时间分位数（毫秒）");
+        for m in &metrics {
+            for (quantile, value) in [
+                ("0.5", m.p50_response_time_ms),
+                ("0.95", m.p95_response_time_ms),
+                ("0.99", m.p99_response_time_ms),
+            ] {
+                out.sample(
+                    "plugin_response_time_milliseconds",
+                    &[("plugin", m.plugin_name.as_str()), ("quantile", quantile)],
+                    value,
+                );
+            }
+        }
+    }
+
+    /// 当前进程 CPU 与内存
+    fn encode_process(out: &mut PrometheusWriter) {
+        let pid = Pid::from_u32(std::process::id());
+        let mut sys = PROCESS_SAMPLER.lock().unwrap();
+        sys.refresh_process_specifics(pid, ProcessRefreshKind::new().with_cpu().with_memory());
+        let Some(process) = sys.process(pid) else {
+            return;
+        };
+
+        out.header("process_cpu_usage_percent", "gauge", "进程 CPU 使用率（自上次采集以来，百分比）");
+        out.sample("process_cpu_usage_percent", &[], process.cpu_usage() as f64);
+        out.header("process_resident_memory_bytes", "gauge", "进程常驻内存（字节）");
+        out.sample("process_resident_memory_bytes", &[], process.memory() as f64);
+        out.header("process_virtual_memory_bytes", "gauge", "进程虚拟内存（字节）");
+        out.sample("process_virtual_memory_bytes", &[], process.virtual_memory() as f64);
+        out.header("process_start_time_seconds", "gauge", "进程启动时间（Unix 时间戳）");
+        out.sample("process_start_time_seconds", &[], process.start_time() as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let metrics = HttpMetrics::new();
+        for millis in [3, 40, 40, 20_000] {
+            metrics.start_request();
+            metrics.finish_request("/api/v1/sys/users", "GET", 200, Duration::from_millis(millis));
+        }
+
+        let mut out = PrometheusWriter::default();
+        metrics.encode(&mut out);
+        let text = out.finish();
+
+        let labels = "route=\"/api/v1/sys/users\",method=\"GET\",status=\"200\"";
+        assert!(text.contains(&format!("http_requests_total{{{}}} 4", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"0.005\"}} 1", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"0.05\"}} 3", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"10\"}} 3", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} 4", labels)));
+        assert!(text.contains("http_requests_in_flight 0"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let metrics = HttpMetrics::new();
+        metrics.start_request();
+        metrics.finish_request("/a", "GET", 200, Duration::from_millis(10));
+        metrics.start_request();
+        metrics.finish_request("/a", "POST", 500, Duration::from_millis(30));
+
+        let summary = metrics.summary();
+        assert_eq!(summary.total_requests, 2);
+        assert_eq!(summary.success_requests, 1);
+        assert_eq!(summary.error_requests, 1);
+        assert!((summary.avg_response_time_ms - 20.0).abs() < 1e-6);
+        assert_eq!(summary.requests_per_minute, 2);
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        let mut out = PrometheusWriter::default();
+        out.sample("m", &[("k", "a\"b\\c\nd")], 1.0);
+        assert_eq!(out.finish(), "m{k=\"a\\\"b\\\\c\\nd\"} 1\n");
+    }
+}
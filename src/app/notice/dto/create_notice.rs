@@ -1,10 +1,11 @@
 /// 通知公告创建和更新 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建通知公告请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateNoticeRequest {
     /// 公告标题
     #[validate(length(min = 1, max = 100, message = "公告标题长度必须在1-100个字符之间"))]
@@ -27,7 +28,7 @@ pub struct CreateNoticeRequest {
 }
 
 /// 更新通知公告请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateNoticeRequest {
     /// 公告ID
     #[validate(range(min = 1, message = "公告ID必须大于0"))]
@@ -54,14 +55,14 @@ pub struct UpdateNoticeRequest {
 }
 
 /// 删除通知公告请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DeleteNoticeRequest {
     /// 公告ID列表
     pub notice_ids: Vec<i64>,
 }
 
 /// 创建通知公告响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateNoticeResponse {
     /// 公告ID
     pub notice_id: i64,
@@ -74,7 +75,7 @@ pub struct CreateNoticeResponse {
 }
 
 /// 更新通知公告响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateNoticeResponse {
     /// 公告ID
     pub notice_id: i64,
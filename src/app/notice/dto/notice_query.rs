@@ -1,10 +1,11 @@
 /// 通知公告查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct NoticeQuery {
     /// 页码
     #[validate(range(min = 1, message = "页码必须大于0"))]
@@ -28,7 +29,7 @@ pub struct NoticeQuery {
 }
 
 /// 分页响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NoticeListResponse {
     /// 公告列表
     pub list: Vec<NoticeListItem>,
@@ -41,7 +42,7 @@ pub struct NoticeListResponse {
 }
 
 /// 公告列表项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NoticeListItem {
     /// 公告ID
     pub notice_id: i64,
@@ -1,9 +1,10 @@
 /// 通知公告响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 公告详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NoticeDetailResponse {
     /// 公告ID
     pub notice_id: i64,
@@ -32,7 +33,7 @@ pub struct NoticeDetailResponse {
 }
 
 /// 公告类型统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NoticeTypeStatistics {
     /// 公告类型
     pub notice_type: i32,
@@ -43,7 +44,7 @@ pub struct NoticeTypeStatistics {
 }
 
 /// 公告分组统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NoticeGroupStatistics {
     /// 正常状态数量
     pub normal_count: usize,
@@ -0,0 +1,7 @@
+/// OIDC 授权服务API模块
+
+pub mod oidc;
+pub mod oidc_client;
+
+pub use oidc::*;
+pub use oidc_client::*;
@@ -0,0 +1,138 @@
+/// OIDC 协议端点
+/// 令牌、用户信息、内省和撤销端点供内部应用直接调用，不经过系统 JWT 中间件
+
+use axum::{
+    extract::{OriginalUri, Query},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Redirect, Response},
+    Form, Json,
+};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use crate::app::auth::service::verify_session_token;
+use crate::app::oidc::dto::{AuthorizeQuery, AuthorizeResponse, TokenParam, TokenRequest};
+use crate::app::oidc::error::OidcError;
+use crate::app::oidc::service::{discovery, issuer, OidcService};
+use crate::common::response::api_response;
+use crate::core::SETTINGS;
+use crate::database::DatabaseManager;
+
+async fn oidc_service() -> OidcService {
+    OidcService::new(DatabaseManager::get_connection().await.clone())
+}
+
+/// 解析 HTTP Basic 客户端认证
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let encoded = value.strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (client_id, client_secret) = decoded.split_once(':')?;
+    Some((client_id.to_string(), client_secret.to_string()))
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+}
+
+/// 授权端点
+/// GET /api/v1/oidc/authorize
+///
+/// 浏览器未携带系统 token 时跳转到前端登录页；前端登录后携带 token 请求本端点，
+/// 再跳转到返回的 redirect_to（内部应用回调地址，附带 code 和 state）
+pub async fn authorize(
+    headers: HeaderMap,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<AuthorizeQuery>,
+) -> Result<Response, OidcError> {
+    // 令牌必须对应仍然有效的会话，已退出或被踢下线的会话按未登录处理
+    let user_id = match bearer_token(&headers) {
+        Some(token) => verify_session_token(token).await.ok().and_then(|payload| payload.sub.parse::<i64>().ok()),
+        None => None,
+    };
+
+    let Some(user_id) = user_id else {
+        let mut login_url = reqwest::Url::parse(&SETTINGS.oidc_login_url)
+            .map_err(|_| OidcError::server_error("OIDC 登录页地址配置无效"))?;
+        login_url
+            .query_pairs_mut()
+            .append_pair("redirect", &format!("{}{}", issuer(), uri));
+        return Ok(Redirect::to(login_url.as_str()).into_response());
+    };
+
+    let redirect_to = oidc_service().await.authorize(user_id, &query).await?;
+    Ok(Json(api_response(AuthorizeResponse { redirect_to })).into_response())
+}
+
+/// 令牌端点
+/// POST /api/v1/oidc/token
+pub async fn token(
+    headers: HeaderMap,
+    Form(request): Form<TokenRequest>,
+) -> Result<impl IntoResponse, OidcError> {
+    let response = oidc_service()
+        .await
+        .token(basic_credentials(&headers), &request)
+        .await?;
+
+    Ok((
+        [(header::CACHE_CONTROL, "no-store"), (header::PRAGMA, "no-cache")],
+        Json(response),
+    ))
+}
+
+/// 用户信息端点
+/// GET/POST /api/v1/oidc/userinfo
+pub async fn userinfo(headers: HeaderMap) -> Result<impl IntoResponse, OidcError> {
+    let token = bearer_token(&headers).ok_or_else(|| OidcError::invalid_token("缺少访问令牌"))?;
+    let claims = oidc_service().await.userinfo(token).await?;
+    Ok(Json(claims))
+}
+
+/// 令牌内省端点
+/// POST /api/v1/oidc/introspect
+pub async fn introspect(
+    headers: HeaderMap,
+    Form(param): Form<TokenParam>,
+) -> Result<impl IntoResponse, OidcError> {
+    let service = oidc_service().await;
+    service
+        .authenticate_client(
+            basic_credentials(&headers),
+            param.client_id.as_deref(),
+            param.client_secret.as_deref(),
+        )
+        .await?;
+
+    Ok(Json(service.introspect(&param.token).await?))
+}
+
+/// 令牌撤销端点
+/// POST /api/v1/oidc/revoke
+pub async fn revoke(
+    headers: HeaderMap,
+    Form(param): Form<TokenParam>,
+) -> Result<impl IntoResponse, OidcError> {
+    let service = oidc_service().await;
+    let client = service
+        .authenticate_client(
+            basic_credentials(&headers),
+            param.client_id.as_deref(),
+            param.client_secret.as_deref(),
+        )
+        .await?;
+    service.revoke(&client, &param.token).await?;
+
+    Ok(Json(serde_json::json!({})))
+}
+
+/// 发现文档
+/// GET /.well-known/openid-configuration
+pub async fn openid_configuration() -> impl IntoResponse {
+    Json(discovery())
+}
@@ -0,0 +1,98 @@
+/// OIDC 客户端和签名密钥管理API
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+use crate::app::auth::service::rbac_service;
+use crate::app::oidc::dto::{CreateOidcClientRequest, RotateKeyResponse, UpdateOidcClientRequest};
+use crate::app::oidc::service::{OidcClientService, OidcService};
+use crate::common::exception::AppError;
+use crate::common::response::{api_response, ApiResult};
+use crate::database::DatabaseManager;
+use crate::middleware::jwt_auth_middleware::AuthContext;
+use crate::utils::encrypt::KEYSET;
+
+const SUPERUSER_ONLY: &str = "仅超级用户可管理OIDC客户端和签名密钥";
+
+async fn client_service() -> OidcClientService {
+    OidcClientService::new(DatabaseManager::get_connection().await.clone())
+}
+
+fn validate<T: Validate>(request: &T) -> Result<(), AppError> {
+    request
+        .validate()
+        .map_err(AppError::from)
+}
+
+/// 获取客户端列表
+/// GET /api/v1/sys/oidc/clients
+pub async fn get_clients(Extension(auth): Extension<AuthContext>) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let result = client_service().await.get_all().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 注册客户端（返回的客户端密钥仅展示一次）
+/// POST /api/v1/sys/oidc/clients
+pub async fn create_client(
+    Extension(auth): Extension<AuthContext>,
+    Json(request): Json<CreateOidcClientRequest>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    validate(&request)?;
+    let result = client_service().await.create(&request).await?;
+    Ok((StatusCode::CREATED, Json(api_response(result))))
+}
+
+/// 更新客户端
+/// PUT /api/v1/sys/oidc/clients/{id}
+pub async fn update_client(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+    Json(request): Json<UpdateOidcClientRequest>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    validate(&request)?;
+    let result = client_service().await.update(id, &request).await?;
+    // 禁用客户端时撤销其客户端凭证令牌，用户令牌在校验时因客户端禁用而失效
+    if result.status != 1 {
+        OidcService::revoke_client_tokens(&result.client_id).await?;
+    }
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 删除客户端
+/// DELETE /api/v1/sys/oidc/clients/{id}
+pub async fn delete_client(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let model = client_service().await.delete(id).await?;
+    OidcService::revoke_client_tokens(&model.client_id).await?;
+    Ok((StatusCode::OK, Json(api_response("客户端删除成功".to_string()))))
+}
+
+/// 重置客户端密钥
+/// POST /api/v1/sys/oidc/clients/{id}/secret
+pub async fn reset_client_secret(
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let result = client_service().await.reset_secret(id).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 立即轮换签名密钥（旧密钥继续用于验签直至保留期结束）
+/// POST /api/v1/sys/oidc/keys/rotate
+pub async fn rotate_key(Extension(auth): Extension<AuthContext>) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let kid = KEYSET.rotate(DatabaseManager::get_connection().await).await?;
+    Ok((StatusCode::OK, Json(api_response(RotateKeyResponse { kid }))))
+}
@@ -0,0 +1,92 @@
+/// OIDC 客户端管理 DTO
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 创建客户端请求
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateOidcClientRequest {
+    /// 客户端名称
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    /// 允许的回调地址（需完全匹配）
+    pub redirect_uris: Vec<String>,
+
+    /// 允许的授权类型（authorization_code / client_credentials / refresh_token）
+    #[validate(length(min = 1))]
+    pub grant_types: Vec<String>,
+
+    /// 允许的 scope
+    #[validate(length(min = 1))]
+    pub scopes: Vec<String>,
+
+    /// 是否为机密客户端（签发客户端密钥）；公共客户端必须使用 PKCE
+    #[serde(default = "default_confidential")]
+    pub confidential: bool,
+
+    /// 状态（0: 禁用, 1: 启用）
+    pub status: Option<i32>,
+
+    /// 备注
+    pub remark: Option<String>,
+}
+
+fn default_confidential() -> bool {
+    true
+}
+
+/// 更新客户端请求
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateOidcClientRequest {
+    /// 客户端名称
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    /// 允许的回调地址（需完全匹配）
+    pub redirect_uris: Vec<String>,
+
+    /// 允许的授权类型
+    #[validate(length(min = 1))]
+    pub grant_types: Vec<String>,
+
+    /// 允许的 scope
+    #[validate(length(min = 1))]
+    pub scopes: Vec<String>,
+
+    /// 状态（0: 禁用, 1: 启用）
+    pub status: Option<i32>,
+
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 客户端详情（不含密钥）
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcClientDetail {
+    pub id: i64,
+    pub client_id: String,
+    pub name: String,
+    pub confidential: bool,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub status: i32,
+    pub remark: Option<String>,
+    pub created_time: chrono::NaiveDateTime,
+    pub updated_time: Option<chrono::NaiveDateTime>,
+}
+
+/// 客户端密钥响应（仅在创建和重置时返回一次）
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcClientSecretResponse {
+    pub client: OidcClientDetail,
+    pub client_secret: Option<String>,
+}
+
+/// 密钥轮换响应
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotateKeyResponse {
+    pub kid: String,
+}
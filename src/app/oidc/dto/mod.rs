@@ -0,0 +1,7 @@
+/// OIDC 授权服务相关 DTO
+
+pub mod client;
+pub mod protocol;
+
+pub use client::*;
+pub use protocol::*;
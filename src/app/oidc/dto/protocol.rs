@@ -0,0 +1,96 @@
+/// OAuth2 / OIDC 协议 DTO
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::encrypt::JwtPayload;
+
+/// 授权请求参数
+/// GET /api/v1/oidc/authorize
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AuthorizeQuery {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: Option<String>,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+}
+
+/// 授权结果（前端跳转到该地址，携带 code 和 state）
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorizeResponse {
+    pub redirect_to: String,
+}
+
+/// 令牌请求（application/x-www-form-urlencoded）
+/// POST /api/v1/oidc/token
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TokenRequest {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// 令牌内省 / 撤销请求（RFC 7662 / RFC 7009）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TokenParam {
+    pub token: String,
+    pub token_type_hint: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// 令牌响应（RFC 6749 5.1）
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: &'static str,
+    pub expires_in: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+    pub scope: String,
+}
+
+/// 访问令牌载荷：在系统 JwtPayload（sub/session_uuid/exp）基础上增加协议字段
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccessTokenClaims {
+    #[serde(flatten)]
+    pub payload: JwtPayload,
+    pub iss: String,
+    pub aud: String,
+    pub iat: i64,
+    pub client_id: String,
+    pub scope: String,
+}
+
+/// 授权码（存储在 Redis，一次性使用）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuthorizationCode {
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub user_id: i64,
+    pub scope: String,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub auth_time: i64,
+}
+
+/// 刷新令牌授权（存储在 Redis，使用后轮换）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RefreshGrant {
+    pub client_id: String,
+    pub user_id: i64,
+    pub session_uuid: String,
+    pub scope: String,
+    pub auth_time: i64,
+}
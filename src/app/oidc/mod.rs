@@ -0,0 +1,12 @@
+/// OIDC 授权服务模块
+/// 内部应用通过本系统的用户、角色和部门登录：客户端注册、授权码 + PKCE、客户端凭证、
+/// 刷新令牌、用户信息、JWKS 和发现文档，令牌使用轮换的非对称密钥签名
+
+pub mod api;
+pub mod dto;
+pub mod error;
+pub mod router;
+pub mod service;
+
+pub use dto::*;
+pub use router::*;
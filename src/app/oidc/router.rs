@@ -0,0 +1,32 @@
+/// 组装 OIDC 授权服务相关路由
+
+use axum::{routing::{delete, get, post, put}, Router};
+
+use crate::app::oidc::api;
+
+/// 协议端点，挂载到 `/api/v1/oidc`
+pub fn oidc_routes() -> Router {
+    Router::new()
+        .route("/authorize", get(api::authorize))  // GET /api/v1/oidc/authorize
+        .route("/token", post(api::token))  // POST /api/v1/oidc/token
+        .route("/userinfo", get(api::userinfo).post(api::userinfo))  // GET/POST /api/v1/oidc/userinfo
+        .route("/introspect", post(api::introspect))  // POST /api/v1/oidc/introspect
+        .route("/revoke", post(api::revoke))  // POST /api/v1/oidc/revoke
+}
+
+/// 发现文档，挂载到根路径
+pub fn well_known_routes() -> Router {
+    Router::new()
+        .route("/.well-known/openid-configuration", get(api::openid_configuration))
+}
+
+/// 客户端与密钥管理，挂载到 `/api/v1/sys/oidc`
+pub fn oidc_admin_routes() -> Router {
+    Router::new()
+        .route("/clients", get(api::get_clients))  // GET /api/v1/sys/oidc/clients
+        .route("/clients", post(api::create_client))  // POST /api/v1/sys/oidc/clients
+        .route("/clients/{id}", put(api::update_client))  // PUT /api/v1/sys/oidc/clients/{id}
+        .route("/clients/{id}", delete(api::delete_client))  // DELETE /api/v1/sys/oidc/clients/{id}
+        .route("/clients/{id}/secret", post(api::reset_client_secret))  // POST /api/v1/sys/oidc/clients/{id}/secret
+        .route("/keys/rotate", post(api::rotate_key))  // POST /api/v1/sys/oidc/keys/rotate
+}
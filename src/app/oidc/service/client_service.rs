@@ -0,0 +1,202 @@
+/// OIDC 客户端管理服务
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+use crate::app::oidc::dto::{
+    CreateOidcClientRequest, OidcClientDetail, OidcClientSecretResponse, UpdateOidcClientRequest,
+};
+use crate::common::exception::{AppError, ErrorCode};
+use crate::database::entity::oauth2_client;
+use crate::utils::encrypt::CryptoUtils;
+
+/// 支持的授权类型
+pub const GRANT_TYPES: &[&str] = &["authorization_code", "client_credentials", "refresh_token"];
+
+/// 支持的 scope
+pub const SCOPES: &[&str] = &["openid", "profile", "email", "phone"];
+
+pub struct OidcClientService {
+    db: DatabaseConnection,
+}
+
+impl OidcClientService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 获取所有客户端
+    pub async fn get_all(&self) -> Result<Vec<OidcClientDetail>, AppError> {
+        let models = oauth2_client::Entity::find()
+            .order_by_asc(oauth2_client::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(to_detail).collect())
+    }
+
+    /// 注册客户端，机密客户端的密钥仅在此返回一次
+    pub async fn create(&self, request: &CreateOidcClientRequest) -> Result<OidcClientSecretResponse, AppError> {
+        check_client(request.confidential, &request.redirect_uris, &request.grant_types, &request.scopes)?;
+
+        let client_secret = request.confidential.then(|| CryptoUtils::random_string(48));
+        let model = oauth2_client::ActiveModel {
+            client_id: Set(CryptoUtils::random_string(24)),
+            client_secret_hash: Set(client_secret.as_deref().map(CryptoUtils::sha256)),
+            name: Set(request.name.clone()),
+            redirect_uris: Set(serde_json::to_string(&request.redirect_uris)?),
+            grant_types: Set(request.grant_types.join(" ")),
+            scopes: Set(request.scopes.join(" ")),
+            status: Set(request.status.unwrap_or(1)),
+            remark: Set(request.remark.clone()),
+            created_time: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(OidcClientSecretResponse {
+            client: to_detail(model),
+            client_secret,
+        })
+    }
+
+    /// 更新客户端
+    pub async fn update(&self, id: i64, request: &UpdateOidcClientRequest) -> Result<OidcClientDetail, AppError> {
+        let model = self.find_by_id(id).await?;
+        check_client(
+            model.client_secret_hash.is_some(),
+            &request.redirect_uris,
+            &request.grant_types,
+            &request.scopes,
+        )?;
+
+        let mut model: oauth2_client::ActiveModel = model.into();
+        model.name = Set(request.name.clone());
+        model.redirect_uris = Set(serde_json::to_string(&request.redirect_uris)?);
+        model.grant_types = Set(request.grant_types.join(" "));
+        model.scopes = Set(request.scopes.join(" "));
+        model.status = Set(request.status.unwrap_or(1));
+        model.remark = Set(request.remark.clone());
+        model.updated_time = Set(Some(chrono::Utc::now().naive_utc()));
+
+        Ok(to_detail(model.update(&self.db).await?))
+    }
+
+    /// 删除客户端
+    pub async fn delete(&self, id: i64) -> Result<oauth2_client::Model, AppError> {
+        let model = self.find_by_id(id).await?;
+        oauth2_client::Entity::delete_by_id(id).exec(&self.db).await?;
+        Ok(model)
+    }
+
+    /// 重置机密客户端的密钥
+    pub async fn reset_secret(&self, id: i64) -> Result<OidcClientSecretResponse, AppError> {
+        let model = self.find_by_id(id).await?;
+        if model.client_secret_hash.is_none() {
+            return Err(AppError::with_message(ErrorCode::BadRequest, "公共客户端没有密钥"));
+        }
+
+        let client_secret = CryptoUtils::random_string(48);
+        let mut model: oauth2_client::ActiveModel = model.into();
+        model.client_secret_hash = Set(Some(CryptoUtils::sha256(&client_secret)));
+        model.updated_time = Set(Some(chrono::Utc::now().naive_utc()));
+
+        Ok(OidcClientSecretResponse {
+            client: to_detail(model.update(&self.db).await?),
+            client_secret: Some(client_secret),
+        })
+    }
+
+    /// 按 client_id 查找启用的客户端
+    pub async fn find_active(&self, client_id: &str) -> Result<Option<oauth2_client::Model>, AppError> {
+        Ok(oauth2_client::Entity::find()
+            .filter(oauth2_client::Column::ClientId.eq(client_id))
+            .filter(oauth2_client::Column::Status.eq(1))
+            .one(&self.db)
+            .await?)
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<oauth2_client::Model, AppError> {
+        oauth2_client::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "客户端不存在"))
+    }
+}
+
+/// 客户端允许的回调地址
+pub fn redirect_uris(model: &oauth2_client::Model) -> Vec<String> {
+    serde_json::from_str(&model.redirect_uris).unwrap_or_default()
+}
+
+/// 客户端是否允许该授权类型
+pub fn allows_grant(model: &oauth2_client::Model, grant_type: &str) -> bool {
+    model.grant_types.split_whitespace().any(|g| g == grant_type)
+}
+
+/// 校验请求的 scope 均在客户端允许范围内，未指定时使用客户端全部 scope
+pub fn resolve_scope(model: &oauth2_client::Model, requested: Option<&str>) -> Option<String> {
+    let allowed: Vec<&str> = model.scopes.split_whitespace().collect();
+    match requested.map(str::trim).filter(|s| !s.is_empty()) {
+        None => Some(allowed.join(" ")),
+        Some(requested) => requested
+            .split_whitespace()
+            .all(|s| allowed.contains(&s))
+            .then(|| requested.split_whitespace().collect::<Vec<_>>().join(" ")),
+    }
+}
+
+/// 校验客户端密钥
+pub fn verify_secret(model: &oauth2_client::Model, secret: Option<&str>) -> bool {
+    match (&model.client_secret_hash, secret) {
+        (Some(hash), Some(secret)) => &CryptoUtils::sha256(secret) == hash,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn check_client(
+    confidential: bool,
+    redirect_uris: &[String],
+    grant_types: &[String],
+    scopes: &[String],
+) -> Result<(), AppError> {
+    let invalid = |msg: String| AppError::with_message(ErrorCode::ValidationError, msg);
+
+    if let Some(grant) = grant_types.iter().find(|g| !GRANT_TYPES.contains(&g.as_str())) {
+        return Err(invalid(format!("不支持的授权类型: {}", grant)));
+    }
+    if let Some(scope) = scopes.iter().find(|s| !SCOPES.contains(&s.as_str())) {
+        return Err(invalid(format!("不支持的 scope: {}", scope)));
+    }
+    if !confidential && grant_types.iter().any(|g| g == "client_credentials") {
+        return Err(invalid("公共客户端不能使用 client_credentials".to_string()));
+    }
+    if grant_types.iter().any(|g| g == "authorization_code") && redirect_uris.is_empty() {
+        return Err(invalid("授权码模式至少需要一个回调地址".to_string()));
+    }
+    for uri in redirect_uris {
+        let url = reqwest::Url::parse(uri).map_err(|_| invalid(format!("回调地址无效: {}", uri)))?;
+        if url.fragment().is_some() {
+            return Err(invalid(format!("回调地址不能包含片段: {}", uri)));
+        }
+    }
+
+    Ok(())
+}
+
+fn to_detail(model: oauth2_client::Model) -> OidcClientDetail {
+    OidcClientDetail {
+        id: model.id,
+        redirect_uris: redirect_uris(&model),
+        confidential: model.client_secret_hash.is_some(),
+        grant_types: model.grant_types.split_whitespace().map(String::from).collect(),
+        scopes: model.scopes.split_whitespace().map(String::from).collect(),
+        client_id: model.client_id,
+        name: model.name,
+        status: model.status,
+        remark: model.remark,
+        created_time: model.created_time,
+        updated_time: model.updated_time,
+    }
+}
@@ -0,0 +1,7 @@
+/// OIDC 授权服务模块
+
+pub mod client_service;
+pub mod oidc_service;
+
+pub use client_service::OidcClientService;
+pub use oidc_service::*;
@@ -0,0 +1,637 @@
+/// OIDC 授权服务
+/// 授权码 + PKCE、客户端凭证、刷新令牌、用户信息、令牌内省与撤销；
+/// 用户令牌与账号密码登录共用 {token_redis_prefix}:{user_id}:{session_uuid} 会话存储，
+/// 退出登录或踢下线删除会话后，访问令牌内省、用户信息和刷新令牌同时失效
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use jsonwebtoken::{Algorithm, Validation};
+use redis::aio::ConnectionManager;
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::app::auth::service::AuthService;
+use crate::app::oidc::dto::{
+    AccessTokenClaims, AuthorizationCode, AuthorizeQuery, RefreshGrant, TokenRequest, TokenResponse,
+};
+use crate::app::oidc::error::OidcError;
+use crate::app::oidc::service::client_service::{self, OidcClientService, GRANT_TYPES, SCOPES};
+use crate::common::exception::{AppError, ErrorCode};
+use crate::core::SETTINGS;
+use crate::database::entity::{dept, oauth2_client, role, user};
+use crate::database::redis::RedisManager;
+use crate::database::user_repo::UserRepository as UserRepo;
+use crate::database::user_role_repo::UserRoleRepository as UserRoleRepo;
+use crate::utils::encrypt::{CryptoUtils, JwtPayload, KEYSET};
+
+pub struct OidcService {
+    db: DatabaseConnection,
+}
+
+impl OidcService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn clients(&self) -> OidcClientService {
+        OidcClientService::new(self.db.clone())
+    }
+
+    /// 授权：校验客户端和请求参数，为已登录用户签发授权码，返回携带 code/state 的回调地址
+    pub async fn authorize(&self, user_id: i64, query: &AuthorizeQuery) -> Result<String, OidcError> {
+        let client = self
+            .clients()
+            .find_active(&query.client_id)
+            .await?
+            .ok_or_else(|| OidcError::invalid_client("客户端不存在或已禁用"))?;
+
+        let registered = client_service::redirect_uris(&client);
+        let redirect_uri = match &query.redirect_uri {
+            Some(uri) if registered.contains(uri) => uri.clone(),
+            Some(_) => return Err(OidcError::invalid_request("redirect_uri 未注册")),
+            None if registered.len() == 1 => registered[0].clone(),
+            None => return Err(OidcError::invalid_request("缺少 redirect_uri")),
+        };
+
+        if query.response_type != "code" {
+            return Err(OidcError::unsupported_response_type("仅支持 response_type=code"));
+        }
+        if !client_service::allows_grant(&client, "authorization_code") {
+            return Err(OidcError::unauthorized_client("客户端不允许授权码模式"));
+        }
+        let scope = client_service::resolve_scope(&client, query.scope.as_deref())
+            .ok_or_else(|| OidcError::invalid_scope("请求的 scope 超出客户端允许范围"))?;
+
+        // 仅支持 S256；公共客户端没有密钥，必须使用 PKCE
+        let code_challenge = match (&query.code_challenge, query.code_challenge_method.as_deref()) {
+            (Some(challenge), Some("S256")) => Some(challenge.clone()),
+            (Some(_), _) => return Err(OidcError::invalid_request("code_challenge_method 仅支持 S256")),
+            (None, _) if client.client_secret_hash.is_none() => {
+                return Err(OidcError::invalid_request("公共客户端必须使用 PKCE"))
+            }
+            (None, _) => None,
+        };
+
+        let user = self
+            .active_user(user_id)
+            .await
+            .map_err(|e| OidcError::access_denied(e.message))?;
+
+        let code = CryptoUtils::random_string(48);
+        let grant = AuthorizationCode {
+            client_id: client.client_id,
+            redirect_uri: redirect_uri.clone(),
+            user_id: user.id,
+            scope,
+            nonce: query.nonce.clone(),
+            code_challenge,
+            auth_time: chrono::Utc::now().timestamp(),
+        };
+        let mut conn = RedisManager::get_connection().await?;
+        let _: () = redis::cmd("SETEX")
+            .arg(redis_key("code", &code))
+            .arg(SETTINGS.oidc_code_expire_seconds)
+            .arg(serde_json::to_string(&grant)?)
+            .query_async(&mut conn)
+            .await?;
+
+        let mut url = reqwest::Url::parse(&redirect_uri)
+            .map_err(|_| OidcError::invalid_request("redirect_uri 无效"))?;
+        url.query_pairs_mut().append_pair("code", &code);
+        if let Some(state) = &query.state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+
+        Ok(url.to_string())
+    }
+
+    /// 令牌端点
+    pub async fn token(
+        &self,
+        basic: Option<(String, String)>,
+        request: &TokenRequest,
+    ) -> Result<TokenResponse, OidcError> {
+        if !GRANT_TYPES.contains(&request.grant_type.as_str()) {
+            return Err(OidcError::unsupported_grant_type(format!("不支持的授权类型: {}", request.grant_type)));
+        }
+
+        let client = self
+            .authenticate_client(basic, request.client_id.as_deref(), request.client_secret.as_deref())
+            .await?;
+        if !client_service::allows_grant(&client, &request.grant_type) {
+            return Err(OidcError::unauthorized_client("客户端不允许该授权类型"));
+        }
+
+        let mut conn = RedisManager::get_connection().await?;
+        match request.grant_type.as_str() {
+            "authorization_code" => self.exchange_code(&client, request, &mut conn).await,
+            "refresh_token" => self.refresh(&client, request, &mut conn).await,
+            _ => self.client_credentials(&client, request, &mut conn).await,
+        }
+    }
+
+    /// 客户端认证：client_secret_basic、client_secret_post，公共客户端只提交 client_id
+    pub async fn authenticate_client(
+        &self,
+        basic: Option<(String, String)>,
+        client_id: Option<&str>,
+        client_secret: Option<&str>,
+    ) -> Result<oauth2_client::Model, OidcError> {
+        let (client_id, secret) = match basic {
+            Some((client_id, secret)) => (client_id, Some(secret)),
+            None => (
+                client_id
+                    .map(str::to_string)
+                    .ok_or_else(|| OidcError::invalid_client("缺少客户端认证信息"))?,
+                client_secret.map(str::to_string),
+            ),
+        };
+        let secret = secret.filter(|s| !s.is_empty());
+
+        let client = self
+            .clients()
+            .find_active(&client_id)
+            .await?
+            .filter(|client| client_service::verify_secret(client, secret.as_deref()))
+            .ok_or_else(|| OidcError::invalid_client("客户端认证失败"))?;
+
+        Ok(client)
+    }
+
+    /// 授权码换取令牌
+    async fn exchange_code(
+        &self,
+        client: &oauth2_client::Model,
+        request: &TokenRequest,
+        conn: &mut ConnectionManager,
+    ) -> Result<TokenResponse, OidcError> {
+        let code = request
+            .code
+            .as_deref()
+            .ok_or_else(|| OidcError::invalid_request("缺少 code"))?;
+        let grant: AuthorizationCode = take_json(conn, &redis_key("code", code))
+            .await?
+            .ok_or_else(|| OidcError::invalid_grant("授权码无效或已过期"))?;
+
+        if grant.client_id != client.client_id {
+            return Err(OidcError::invalid_grant("授权码不属于该客户端"));
+        }
+        if request.redirect_uri.as_ref().is_some_and(|uri| *uri != grant.redirect_uri) {
+            return Err(OidcError::invalid_grant("redirect_uri 不匹配"));
+        }
+        match (&grant.code_challenge, request.code_verifier.as_deref()) {
+            (Some(challenge), Some(verifier)) if pkce_challenge(verifier) == *challenge => {}
+            (None, None) => {}
+            _ => return Err(OidcError::invalid_grant("code_verifier 校验失败")),
+        }
+
+        let user = self
+            .active_user(grant.user_id)
+            .await
+            .map_err(|e| OidcError::invalid_grant(e.message))?;
+        let session_uuid = Uuid::new_v4().to_string();
+
+        self.issue_user_tokens(client, &user, &grant.scope, &session_uuid, grant.nonce.as_deref(), grant.auth_time, conn)
+            .await
+    }
+
+    /// 刷新令牌：使用后轮换，会话被撤销后失效
+    async fn refresh(
+        &self,
+        client: &oauth2_client::Model,
+        request: &TokenRequest,
+        conn: &mut ConnectionManager,
+    ) -> Result<TokenResponse, OidcError> {
+        let token = request
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| OidcError::invalid_request("缺少 refresh_token"))?;
+        let token_hash = CryptoUtils::sha256(token);
+        let grant: RefreshGrant = take_json(conn, &redis_key("refresh", &token_hash))
+            .await?
+            .ok_or_else(|| OidcError::invalid_grant("refresh_token 无效或已过期"))?;
+
+        if grant.client_id != client.client_id {
+            return Err(OidcError::invalid_grant("refresh_token 不属于该客户端"));
+        }
+        let current: Option<String> = redis::cmd("GET")
+            .arg(refresh_session_key(grant.user_id, &grant.session_uuid))
+            .query_async(conn)
+            .await?;
+        if current.as_deref() != Some(token_hash.as_str()) {
+            return Err(OidcError::invalid_grant("会话已失效"));
+        }
+
+        // 刷新时 scope 只能缩小
+        let scope = match request.scope.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+            Some(scope) if scope.split_whitespace().all(|s| grant.scope.split_whitespace().any(|g| g == s)) => {
+                scope.to_string()
+            }
+            Some(_) => return Err(OidcError::invalid_scope("请求的 scope 超出原授权范围")),
+            None => grant.scope.clone(),
+        };
+
+        let user = self
+            .active_user(grant.user_id)
+            .await
+            .map_err(|e| OidcError::invalid_grant(e.message))?;
+
+        self.issue_user_tokens(client, &user, &scope, &grant.session_uuid, None, grant.auth_time, conn)
+            .await
+    }
+
+    /// 客户端凭证：服务间调用，令牌主体为客户端本身
+    async fn client_credentials(
+        &self,
+        client: &oauth2_client::Model,
+        request: &TokenRequest,
+        conn: &mut ConnectionManager,
+    ) -> Result<TokenResponse, OidcError> {
+        if client.client_secret_hash.is_none() {
+            return Err(OidcError::unauthorized_client("公共客户端不能使用 client_credentials"));
+        }
+        let scope = client_service::resolve_scope(client, request.scope.as_deref())
+            .ok_or_else(|| OidcError::invalid_scope("请求的 scope 超出客户端允许范围"))?;
+
+        let session_uuid = Uuid::new_v4().to_string();
+        let claims = access_claims(client, &client.client_id, &session_uuid, &scope);
+        let access_token = KEYSET.sign(&claims)?;
+
+        let _: () = redis::cmd("SETEX")
+            .arg(client_token_key(&client.client_id, &session_uuid))
+            .arg(SETTINGS.token_expire_seconds)
+            .arg(&access_token)
+            .query_async(conn)
+            .await?;
+
+        Ok(TokenResponse {
+            access_token,
+            token_type: "Bearer",
+            expires_in: SETTINGS.token_expire_seconds,
+            refresh_token: None,
+            id_token: None,
+            scope,
+        })
+    }
+
+    /// 为用户签发访问令牌、刷新令牌和 ID Token，并写入会话存储
+    #[allow(clippy::too_many_arguments)]
+    async fn issue_user_tokens(
+        &self,
+        client: &oauth2_client::Model,
+        user: &user::Model,
+        scope: &str,
+        session_uuid: &str,
+        nonce: Option<&str>,
+        auth_time: i64,
+        conn: &mut ConnectionManager,
+    ) -> Result<TokenResponse, OidcError> {
+        let claims = access_claims(client, &user.id.to_string(), session_uuid, scope);
+        let access_token = KEYSET.sign(&claims)?;
+
+        // 会话存储与账号密码登录一致，在线用户列表可见并可统一撤销
+        let extra_info = json!({
+            "username": user.username,
+            "nickname": user.nickname,
+            "ip": "",
+            "os": "",
+            "browser": "",
+            "device": "",
+            "client": client.name,
+            "last_login_time": chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        });
+        let _: () = redis::pipe()
+            .cmd("SETEX")
+            .arg(format!("{}:{}:{}", SETTINGS.token_redis_prefix, user.id, session_uuid))
+            .arg(SETTINGS.token_expire_seconds)
+            .arg(&access_token)
+            .ignore()
+            .cmd("SETEX")
+            .arg(format!("{}:{}:{}", SETTINGS.token_extra_info_redis_prefix, user.id, session_uuid))
+            .arg(SETTINGS.token_expire_seconds)
+            .arg(extra_info.to_string())
+            .ignore()
+            .query_async(conn)
+            .await?;
+
+        let refresh_token = if client_service::allows_grant(client, "refresh_token") {
+            let token = CryptoUtils::random_string(64);
+            let token_hash = CryptoUtils::sha256(&token);
+            let grant = RefreshGrant {
+                client_id: client.client_id.clone(),
+                user_id: user.id,
+                session_uuid: session_uuid.to_string(),
+                scope: scope.to_string(),
+                auth_time,
+            };
+            let _: () = redis::pipe()
+                .cmd("SETEX")
+                .arg(redis_key("refresh", &token_hash))
+                .arg(SETTINGS.token_refresh_expire_seconds)
+                .arg(serde_json::to_string(&grant)?)
+                .ignore()
+                .cmd("SETEX")
+                .arg(refresh_session_key(user.id, session_uuid))
+                .arg(SETTINGS.token_refresh_expire_seconds)
+                .arg(&token_hash)
+                .ignore()
+                .query_async(conn)
+                .await?;
+            Some(token)
+        } else {
+            None
+        };
+
+        let id_token = if scope.split_whitespace().any(|s| s == "openid") {
+            let now = chrono::Utc::now().timestamp();
+            let mut claims = self.user_claims(user, scope).await?;
+            claims.insert("iss".to_string(), json!(issuer()));
+            claims.insert("aud".to_string(), json!(client.client_id));
+            claims.insert("iat".to_string(), json!(now));
+            claims.insert("exp".to_string(), json!(now + SETTINGS.token_expire_seconds));
+            claims.insert("auth_time".to_string(), json!(auth_time));
+            claims.insert("sid".to_string(), json!(session_uuid));
+            if let Some(nonce) = nonce {
+                claims.insert("nonce".to_string(), json!(nonce));
+            }
+            Some(KEYSET.sign(&Value::Object(claims))?)
+        } else {
+            None
+        };
+
+        Ok(TokenResponse {
+            access_token,
+            token_type: "Bearer",
+            expires_in: SETTINGS.token_expire_seconds,
+            refresh_token,
+            id_token,
+            scope: scope.to_string(),
+        })
+    }
+
+    /// 校验访问令牌：签名、签发者、客户端状态和会话是否仍然有效
+    async fn verify_access_token(&self, token: &str) -> Result<AccessTokenClaims, OidcError> {
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_issuer(&[issuer()]);
+        validation.set_required_spec_claims(&["exp", "iss", "sub"]);
+        validation.validate_aud = false;
+        let claims: AccessTokenClaims = KEYSET
+            .verify(token, &validation)
+            .map_err(|e| OidcError::invalid_token(e.message))?;
+
+        if self.clients().find_active(&claims.client_id).await?.is_none() {
+            return Err(OidcError::invalid_token("客户端已禁用"));
+        }
+
+        let session_key = if claims.payload.sub == claims.client_id {
+            client_token_key(&claims.client_id, &claims.payload.session_uuid)
+        } else {
+            format!("{}:{}:{}", SETTINGS.token_redis_prefix, claims.payload.sub, claims.payload.session_uuid)
+        };
+        let mut conn = RedisManager::get_connection().await?;
+        let exists: bool = redis::cmd("EXISTS").arg(session_key).query_async(&mut conn).await?;
+        if !exists {
+            return Err(OidcError::invalid_token("会话已失效"));
+        }
+
+        Ok(claims)
+    }
+
+    /// 用户信息端点
+    pub async fn userinfo(&self, token: &str) -> Result<Value, OidcError> {
+        let claims = self.verify_access_token(token).await?;
+        let user_id: i64 = claims
+            .payload
+            .sub
+            .parse()
+            .map_err(|_| OidcError::invalid_token("令牌不属于用户"))?;
+        let user = self
+            .active_user(user_id)
+            .await
+            .map_err(|e| OidcError::invalid_token(e.message))?;
+
+        Ok(Value::Object(self.user_claims(&user, &claims.scope).await?))
+    }
+
+    /// 令牌内省（RFC 7662），资源服务据此感知会话撤销
+    pub async fn introspect(&self, token: &str) -> Result<Value, OidcError> {
+        let claims = match self.verify_access_token(token).await {
+            Ok(claims) => claims,
+            Err(_) => return Ok(json!({ "active": false })),
+        };
+
+        Ok(json!({
+            "active": true,
+            "iss": claims.iss,
+            "sub": claims.payload.sub,
+            "aud": claims.aud,
+            "client_id": claims.client_id,
+            "scope": claims.scope,
+            "exp": claims.payload.exp,
+            "iat": claims.iat,
+            "token_type": "Bearer",
+        }))
+    }
+
+    /// 撤销令牌（RFC 7009）：删除令牌所属会话，访问令牌和刷新令牌均可
+    pub async fn revoke(&self, client: &oauth2_client::Model, token: &str) -> Result<(), OidcError> {
+        let mut conn = RedisManager::get_connection().await?;
+
+        if let Some(grant) = take_json::<RefreshGrant>(&mut conn, &redis_key("refresh", &CryptoUtils::sha256(token))).await? {
+            if grant.client_id == client.client_id {
+                revoke_session(&mut conn, grant.user_id, &grant.session_uuid).await?;
+            }
+            return Ok(());
+        }
+
+        // 无效或过期的令牌按协议视为撤销成功
+        if let Ok(claims) = self.verify_access_token(token).await {
+            if claims.client_id != client.client_id {
+                return Ok(());
+            }
+            match claims.payload.sub.parse::<i64>() {
+                Ok(user_id) => revoke_session(&mut conn, user_id, &claims.payload.session_uuid).await?,
+                Err(_) => {
+                    let _: () = redis::cmd("DEL")
+                        .arg(client_token_key(&claims.client_id, &claims.payload.session_uuid))
+                        .query_async(&mut conn)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 撤销客户端凭证签发的全部令牌（客户端删除或禁用时调用）
+    pub async fn revoke_client_tokens(client_id: &str) -> Result<(), AppError> {
+        let keys = RedisManager::scan_keys(&client_token_key(client_id, "*")).await?;
+        if !keys.is_empty() {
+            let mut conn = RedisManager::get_connection().await?;
+            let _: () = redis::cmd("DEL").arg(keys).query_async(&mut conn).await?;
+        }
+        Ok(())
+    }
+
+    /// 按 scope 返回用户声明
+    async fn user_claims(&self, user: &user::Model, scope: &str) -> Result<Map<String, Value>, OidcError> {
+        let scopes: Vec<&str> = scope.split_whitespace().collect();
+        let mut claims = Map::new();
+        claims.insert("sub".to_string(), json!(user.id.to_string()));
+
+        if scopes.contains(&"profile") {
+            claims.insert("name".to_string(), json!(user.nickname));
+            claims.insert("preferred_username".to_string(), json!(user.username));
+            claims.insert("picture".to_string(), json!(user.avatar));
+            claims.insert("roles".to_string(), json!(self.role_names(user.id).await?));
+            let dept = match user.dept_id {
+                Some(dept_id) => dept::Entity::find_by_id(dept_id)
+                    .filter(dept::Column::DelFlag.eq(0))
+                    .one(&self.db)
+                    .await?
+                    .map(|d| d.name),
+                None => None,
+            };
+            claims.insert("dept".to_string(), json!(dept));
+        }
+        if scopes.contains(&"email") {
+            claims.insert("email".to_string(), json!(user.email));
+        }
+        if scopes.contains(&"phone") {
+            claims.insert("phone_number".to_string(), json!(user.phone));
+        }
+
+        Ok(claims)
+    }
+
+    async fn role_names(&self, user_id: i64) -> Result<Vec<String>, DbErr> {
+        let role_ids = UserRoleRepo::find_roles_by_user(user_id, &self.db).await?;
+        if role_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let roles = role::Entity::find()
+            .filter(role::Column::Id.is_in(role_ids))
+            .filter(role::Column::Status.eq(1))
+            .all(&self.db)
+            .await?;
+        Ok(roles.into_iter().map(|r| r.name).collect())
+    }
+
+    async fn active_user(&self, user_id: i64) -> Result<user::Model, AppError> {
+        let user = UserRepo::find_by_id(user_id, &self.db).await.map_err(|e| match e {
+            DbErr::RecordNotFound(_) => AppError::with_message(ErrorCode::UserNotFound, "用户不存在"),
+            e => AppError::from(e),
+        })?;
+        AuthService::check_user_status(&user)?;
+        Ok(user)
+    }
+}
+
+/// 签发者标识
+pub fn issuer() -> String {
+    SETTINGS.oidc_issuer.trim_end_matches('/').to_string()
+}
+
+/// 发现文档
+pub fn discovery() -> Value {
+    let issuer = issuer();
+    let endpoint = |path: &str| format!("{}{}/oidc/{}", issuer, SETTINGS.api_v1_path, path);
+
+    json!({
+        "issuer": issuer,
+        "authorization_endpoint": endpoint("authorize"),
+        "token_endpoint": endpoint("token"),
+        "userinfo_endpoint": endpoint("userinfo"),
+        "jwks_uri": format!("{}/.well-known/jwks.json", issuer),
+        "introspection_endpoint": endpoint("introspect"),
+        "revocation_endpoint": endpoint("revoke"),
+        "response_types_supported": ["code"],
+        "grant_types_supported": GRANT_TYPES,
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": KEYSET.algorithms(),
+        "scopes_supported": SCOPES,
+        "token_endpoint_auth_methods_supported": ["client_secret_basic", "client_secret_post", "none"],
+        "code_challenge_methods_supported": ["S256"],
+        "claims_supported": [
+            "sub", "iss", "aud", "exp", "iat", "auth_time", "nonce", "sid",
+            "name", "preferred_username", "picture", "roles", "dept", "email", "phone_number",
+        ],
+    })
+}
+
+fn access_claims(client: &oauth2_client::Model, sub: &str, session_uuid: &str, scope: &str) -> AccessTokenClaims {
+    AccessTokenClaims {
+        payload: JwtPayload::new(sub, session_uuid, SETTINGS.token_expire_seconds),
+        iss: issuer(),
+        aud: client.client_id.clone(),
+        iat: chrono::Utc::now().timestamp(),
+        client_id: client.client_id.clone(),
+        scope: scope.to_string(),
+    }
+}
+
+/// PKCE S256：BASE64URL(SHA256(code_verifier))
+fn pkce_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+fn redis_key(kind: &str, id: &str) -> String {
+    format!("{}:{}:{}", SETTINGS.oidc_redis_prefix, kind, id)
+}
+
+fn client_token_key(client_id: &str, session_uuid: &str) -> String {
+    format!("{}:client_token:{}:{}", SETTINGS.oidc_redis_prefix, client_id, session_uuid)
+}
+
+fn refresh_session_key(user_id: i64, session_uuid: &str) -> String {
+    format!("{}:{}:{}", SETTINGS.token_refresh_redis_prefix, user_id, session_uuid)
+}
+
+/// 原子地读取并删除一次性凭证
+async fn take_json<T: serde::de::DeserializeOwned>(
+    conn: &mut ConnectionManager,
+    key: &str,
+) -> Result<Option<T>, OidcError> {
+    let (value,): (Option<String>,) = redis::pipe()
+        .atomic()
+        .get(key)
+        .del(key)
+        .ignore()
+        .query_async(conn)
+        .await?;
+
+    Ok(value.and_then(|v| serde_json::from_str(&v).ok()))
+}
+
+/// 删除用户会话（与退出登录、踢下线一致）
+async fn revoke_session(conn: &mut ConnectionManager, user_id: i64, session_uuid: &str) -> Result<(), OidcError> {
+    let _: () = redis::cmd("DEL")
+        .arg(format!("{}:{}:{}", SETTINGS.token_redis_prefix, user_id, session_uuid))
+        .arg(format!("{}:{}:{}", SETTINGS.token_extra_info_redis_prefix, user_id, session_uuid))
+        .arg(refresh_session_key(user_id, session_uuid))
+        .query_async(conn)
+        .await?;
+    let _: () = redis::cmd("SREM")
+        .arg(&SETTINGS.token_online_redis_prefix)
+        .arg(session_uuid)
+        .query_async(conn)
+        .await?;
+    crate::websocket::disconnect_session(session_uuid).await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_challenge() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K9uhUlZy8AqNKIDCEEF1wdAIFI"),
+            "phSCTUCs7jAw_cSEM2OuwedPluObwSKImgIFOHmOkHI"
+        );
+        assert_ne!(pkce_challenge("other-verifier"), "phSCTUCs7jAw_cSEM2OuwedPluObwSKImgIFOHmOkHI");
+    }
+}
@@ -1,8 +1,9 @@
 /// 创建操作日志 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateOperaLogRequest {
     /// 操作模块
     pub title: String,
@@ -41,7 +42,7 @@ pub struct CreateOperaLogRequest {
 }
 
 /// 操作日志创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateOperaLogResponse {
     /// 日志ID
     pub id: i64,
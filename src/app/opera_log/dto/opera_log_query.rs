@@ -1,10 +1,11 @@
 /// 操作日志查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use crate::app::opera_log::dto::OperaLogListItem;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct OperaLogPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -46,7 +47,7 @@ pub struct OperaLogPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum OperaLogSortField {
     /// 按ID排序
@@ -79,7 +80,7 @@ impl std::fmt::Display for OperaLogSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -100,7 +101,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 操作日志分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct OperaLogPaginationResponse {
     /// 操作日志列表
     pub items: Vec<OperaLogListItem>,
@@ -1,9 +1,10 @@
 /// 操作日志响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 操作日志详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct OperaLogDetailResponse {
     /// 日志ID
     pub id: i64,
@@ -51,7 +52,7 @@ pub struct OperaLogDetailResponse {
 
 /// 操作日志列表项响应
 /// 对齐前端 OperaLogResult 接口
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OperaLogListItem {
     /// 日志ID
     pub id: i64,
@@ -94,7 +95,7 @@ pub struct OperaLogListItem {
 }
 
 /// 操作日志统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OperaLogStatistics {
     /// 总操作次数
     pub total_count: usize,
@@ -115,7 +116,7 @@ pub struct OperaLogStatistics {
 }
 
 /// 业务类型统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct BusinessTypeStat {
     /// 业务类型
     pub business_type: i32,
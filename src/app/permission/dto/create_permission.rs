@@ -1,9 +1,10 @@
 /// 创建权限请求 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreatePermissionRequest {
     /// 权限名称
     #[validate(length(min = 1, max = 50))]
@@ -30,7 +31,7 @@ pub struct CreatePermissionRequest {
 }
 
 /// 权限创建响应 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreatePermissionResponse {
     /// 权限ID
     pub id: i64,
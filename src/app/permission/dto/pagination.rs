@@ -2,9 +2,10 @@
 
 use super::permission_response::PermissionListItem;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct PermissionPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -40,7 +41,7 @@ pub struct PermissionPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum PermissionSortField {
     /// 按ID排序
@@ -76,7 +77,7 @@ impl std::fmt::Display for PermissionSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -97,7 +98,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 权限分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PermissionPaginationResponse {
     /// 权限列表
     pub list: Vec<PermissionListItem>,
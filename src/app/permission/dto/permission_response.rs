@@ -1,9 +1,10 @@
 /// 权限响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 权限详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct PermissionDetailResponse {
     /// 权限ID
     pub id: i64,
@@ -28,7 +29,7 @@ pub struct PermissionDetailResponse {
 }
 
 /// 权限列表项响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PermissionListItem {
     /// 权限ID
     pub id: i64,
@@ -51,7 +52,7 @@ pub struct PermissionListItem {
 }
 
 /// 权限树节点
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct PermissionTreeNode {
     /// 权限ID
     pub id: i64,
@@ -68,5 +69,6 @@ pub struct PermissionTreeNode {
     /// 状态
     pub status: i32,
     /// 子权限
+    #[schema(no_recursion)]
     pub children: Vec<PermissionTreeNode>,
 }
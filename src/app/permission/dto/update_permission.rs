@@ -1,9 +1,10 @@
 /// 更新权限请求 DTO
 
 use serde::Deserialize;
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct UpdatePermissionRequest {
     /// 权限名称
     #[validate(length(min = 1, max = 50))]
@@ -6,18 +6,19 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use crate::common::exception::{AppError, ErrorCode};
 use crate::common::response::ResponseModel;
 
 /// 插件类型枚举
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PluginType {
     Zip,
     Git,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct InstallPluginQuery {
     #[serde(rename = "type")]
     pub plugin_type: PluginType,
@@ -25,16 +26,27 @@ pub struct InstallPluginQuery {
 }
 
 /// 插件信息响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PluginResponse {
     pub plugin: PluginConfigResponse,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app: Option<AppConfigResponse>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub api: Option<serde_json::Value>,  // 使用Value因为api结构是动态的
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<PluginSignatureResponse>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 插件签名校验信息
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PluginSignatureResponse {
+    pub status: crate::app::plugin::signature::SignatureStatus,
+    pub status_name: String,
+    pub key_id: Option<String>,
+    pub manifest_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PluginConfigResponse {
     pub summary: String,
     pub version: String,
@@ -44,7 +56,7 @@ pub struct PluginConfigResponse {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AppConfigResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extend: Option<String>,
@@ -127,6 +139,7 @@ pub async fn install_plugin(
             
             // 调用ZIP安装函数
             let result = crate::app::plugin::install_zip_plugin(multipart).await?;
+            crate::app::plugin::PLUGIN_ROUTES.rebuild("./plugins").await?;
             
             Ok(Json(ResponseModel {
                 code: 200,
@@ -141,6 +154,7 @@ pub async fn install_plugin(
             
             // 调用Git安装函数
             let result = crate::app::plugin::install_git_plugin(repo_url).await?;
+            crate::app::plugin::PLUGIN_ROUTES.rebuild("./plugins").await?;
             
             Ok(Json(ResponseModel {
                 code: 200,
@@ -183,10 +197,13 @@ pub async fn uninstall_plugin(
     
     // 标记变更
     PluginCacheManager::mark_plugin_changed().await?;
+
+    // 卸载插件路由
+    crate::app::plugin::PLUGIN_ROUTES.rebuild("./plugins").await?;
     
     Ok(Json(ResponseModel {
         code: 200,
-        msg: format!("插件 {} 卸载成功，请根据插件说明（README.md）移除相关配置并重启服务", plugin_name),
+        msg: format!("插件 {} 卸载成功，请根据插件说明（README.md）移除相关配置", plugin_name),
         data: Some(()),
     }))
 }
@@ -204,6 +221,14 @@ pub async fn update_plugin_status(
     // 切换状态
     let new_status = !current_enabled;
     PluginCacheManager::update_plugin_status(&plugin_name, new_status).await?;
+
+    // 重新挂载插件路由，状态变更即时生效；重建失败时恢复原状态，保持状态与已挂载的路由一致
+    if let Err(err) = crate::app::plugin::PLUGIN_ROUTES.rebuild("./plugins").await {
+        if let Err(restore_err) = PluginCacheManager::update_plugin_status(&plugin_name, current_enabled).await {
+            tracing::error!("Failed to restore status of plugin {}: {:?}", plugin_name, restore_err);
+        }
+        return Err(err);
+    }
     
     Ok(Json(ResponseModel {
         code: 200,
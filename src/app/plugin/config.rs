@@ -76,13 +76,7 @@ impl PluginConfig {
             )
         })?;
 
-        let mut config: PluginConfig = toml::from_str(&content).map_err(|e| {
-            tracing::error!("Failed to parse plugin config: {:?}", e);
-            AppError::with_message(
-                ErrorCode::InvalidInput,
-                format!("Invalid plugin config format: {}", e)
-            )
-        })?;
+        let mut config = Self::from_toml_str(&content)?;
 
         // 从文件路径提取插件名称
         if let Some(parent) = path.parent() {
@@ -96,6 +90,17 @@ impl PluginConfig {
         Ok(config)
     }
 
+    /// 从toml文本解析配置
+    pub fn from_toml_str(content: &str) -> Result<Self, AppError> {
+        toml::from_str(content).map_err(|e| {
+            tracing::error!("Failed to parse plugin config: {:?}", e);
+            AppError::with_message(
+                ErrorCode::InvalidInput,
+                format!("Invalid plugin config format: {}", e)
+            )
+        })
+    }
+
     /// 验证配置有效性
     pub fn validate(&self) -> Result<(), AppError> {
         // 验证必填字段
@@ -1,9 +1,10 @@
 /// 插件查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct PluginPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -48,7 +49,7 @@ pub struct PluginPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum PluginSortField {
     /// 按ID排序
@@ -87,7 +88,7 @@ impl std::fmt::Display for PluginSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -108,7 +109,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 插件分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PluginPaginationResponse {
     /// 插件列表
     pub list: Vec<PluginListItem>,
@@ -127,7 +128,7 @@ pub struct PluginPaginationResponse {
 }
 
 /// 插件列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PluginListItem {
     /// 插件ID
     pub id: i64,
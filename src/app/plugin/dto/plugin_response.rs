@@ -1,10 +1,11 @@
 /// 插件响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 插件详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct PluginDetailResponse {
     /// 插件ID
     pub id: i64,
@@ -51,14 +52,14 @@ pub struct PluginDetailResponse {
 }
 
 /// 插件安装请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct InstallPluginRequest {
     /// 插件文件路径
     pub file_path: String,
 }
 
 /// 插件安装响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct InstallPluginResponse {
     /// 插件ID
     pub id: i64,
@@ -75,14 +76,14 @@ pub struct InstallPluginResponse {
 }
 
 /// 插件卸载请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UninstallPluginRequest {
     /// 插件ID
     pub id: i64,
 }
 
 /// 插件卸载响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UninstallPluginResponse {
     /// 插件ID
     pub id: i64,
@@ -97,14 +98,14 @@ pub struct UninstallPluginResponse {
 }
 
 /// 插件启用请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct EnablePluginRequest {
     /// 插件ID
     pub id: i64,
 }
 
 /// 插件启用响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EnablePluginResponse {
     /// 插件ID
     pub id: i64,
@@ -119,14 +120,14 @@ pub struct EnablePluginResponse {
 }
 
 /// 插件禁用请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DisablePluginRequest {
     /// 插件ID
     pub id: i64,
 }
 
 /// 插件禁用响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DisablePluginResponse {
     /// 插件ID
     pub id: i64,
@@ -141,7 +142,7 @@ pub struct DisablePluginResponse {
 }
 
 /// 插件更新请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdatePluginRequest {
     /// 插件ID
     pub id: i64,
@@ -154,7 +155,7 @@ pub struct UpdatePluginRequest {
 }
 
 /// 插件更新响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UpdatePluginResponse {
     /// 插件ID
     pub id: i64,
@@ -165,7 +166,7 @@ pub struct UpdatePluginResponse {
 }
 
 /// 插件统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PluginStatistics {
     /// 插件总数
     pub total_count: usize,
@@ -184,7 +185,7 @@ pub struct PluginStatistics {
 }
 
 /// 插件类型统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PluginTypeStat {
     /// 插件类型
     pub plugin_type: i32,
@@ -195,7 +196,7 @@ pub struct PluginTypeStat {
 }
 
 /// 插件配置查询 DTO
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct PluginConfigPaginationQuery {
     /// 插件ID
     pub plugin_id: Option<i64>,
@@ -210,7 +211,7 @@ pub struct PluginConfigPaginationQuery {
 }
 
 /// 插件配置分页响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PluginConfigPaginationResponse {
     /// 配置列表
     pub list: Vec<PluginConfigItem>,
@@ -229,7 +230,7 @@ pub struct PluginConfigPaginationResponse {
 }
 
 /// 插件配置项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PluginConfigItem {
     /// 配置ID
     pub id: i64,
@@ -256,7 +257,7 @@ pub struct PluginConfigItem {
 }
 
 /// 插件配置更新请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdatePluginConfigRequest {
     /// 配置ID
     pub id: i64,
@@ -265,7 +266,7 @@ pub struct UpdatePluginConfigRequest {
 }
 
 /// 插件配置更新响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UpdatePluginConfigResponse {
     /// 配置ID
     pub id: i64,
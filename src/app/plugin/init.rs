@@ -10,6 +10,8 @@ use tracing::{info, warn, error};
 use crate::common::exception::{AppError, ErrorCode};
 use crate::database::redis::RedisManager;
 use crate::core::SETTINGS;
+use crate::app::plugin::registry::{builtin_manifest, BUILTIN_MANIFESTS};
+use crate::app::plugin::signature::{enforce_signature_policy, record_verification, SignatureVerifier};
 
 /// 插件配置结构（用于解析plugin.toml）
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -27,6 +29,13 @@ pub struct PluginInfo {
     pub version: String,
     pub description: String,
     pub author: String,
+    /// plugin.toml 中的默认启用状态 (1=启用, 0=禁用)
+    #[serde(default = "default_enable")]
+    pub enable: i32,
+}
+
+fn default_enable() -> i32 {
+    1
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -44,14 +53,12 @@ pub async fn init_plugins() -> Result<(), AppError> {
     
     let plugin_dir = Path::new("./plugins");
     
-    // 检查plugins目录是否存在
+    // 检查plugins目录是否存在，内置插件使用嵌入的 plugin.toml，不依赖该目录
     if !plugin_dir.exists() {
         warn!("插件目录不存在，正在创建: {:?}", plugin_dir);
         fs::create_dir_all(plugin_dir).map_err(|e| {
             AppError::with_message(ErrorCode::IOError, format!("Failed to create plugins directory: {}", e))
         })?;
-        info!("插件系统已初始化（未找到插件）");
-        return Ok(());
     }
     
     // 获取Redis连接
@@ -98,8 +105,10 @@ pub async fn init_plugins() -> Result<(), AppError> {
             None => continue,
         };
         
-        // 跳过备份目录和隐藏目录
-        if plugin_name.ends_with(".backup") || plugin_name.starts_with('.') || plugin_name == "target" {
+        // 跳过备份目录和隐藏目录；内置插件统一按嵌入的 plugin.toml 加载
+        if plugin_name.ends_with(".backup") || plugin_name.starts_with('.') || plugin_name == "target"
+            || builtin_manifest(plugin_name).is_some()
+        {
             continue;
         }
         
@@ -116,6 +125,20 @@ pub async fn init_plugins() -> Result<(), AppError> {
         }
     }
     
+    // 内置插件编译进应用，按嵌入的 plugin.toml 加载，视为可信，不执行签名策略
+    for (plugin_name, manifest) in BUILTIN_MANIFESTS {
+        match cache_plugin_manifest(None, plugin_name, manifest, &mut redis_conn, prefix).await {
+            Ok(_) => {
+                loaded_count += 1;
+                valid_plugin_keys.push(format!("{}:{}", prefix, plugin_name));
+            }
+            Err(e) => {
+                error!("加载内置插件 '{}' 失败: {}", plugin_name, e);
+                error_count += 1;
+            }
+        }
+    }
+    
     // 清理Redis中不存在的插件
     for key in existing_keys {
         if key.ends_with(":changed") {
@@ -168,8 +191,20 @@ async fn load_plugin(
         AppError::with_message(ErrorCode::IOError, format!("Failed to read plugin.toml: {}", e))
     })?;
     
+    cache_plugin_manifest(Some(plugin_path), plugin_name, &toml_content, redis_conn, prefix).await
+}
+
+/// 解析 plugin.toml 内容写入Redis缓存
+/// plugin_path 为已安装插件的目录，写入前校验签名；内置插件传 None
+async fn cache_plugin_manifest(
+    plugin_path: Option<&Path>,
+    plugin_name: &str,
+    toml_content: &str,
+    redis_conn: &mut redis::aio::ConnectionManager,
+    prefix: &str,
+) -> Result<(), AppError> {
     // 解析TOML配置
-    let config: PluginTomlConfig = toml::from_str(&toml_content).map_err(|e| {
+    let config: PluginTomlConfig = toml::from_str(toml_content).map_err(|e| {
         AppError::with_message(ErrorCode::InvalidInput, format!("Failed to parse plugin.toml: {}", e))
     })?;
     
@@ -191,7 +226,8 @@ async fn load_plugin(
             Err(_) => "1".to_string(),
         }
     } else {
-        "1".to_string() // 默认启用
+        // 首次加载时使用 plugin.toml 中的 enable 配置
+        if config.plugin.enable == 1 { "1" } else { "0" }.to_string()
     };
     
     // 构建插件数据
@@ -206,6 +242,21 @@ async fn load_plugin(
         }
     });
     
+    // 校验已安装插件的签名并记录结果，未通过策略检查的插件不写入缓存
+    if let Some(plugin_path) = plugin_path {
+        let verification = SignatureVerifier::from_settings()?.verify_plugin(plugin_path)?;
+        plugin_data["signature"] = json!({
+            "status": verification.status,
+            "status_name": verification.status.get_name(),
+            "key_id": verification.key_id,
+            "manifest_hash": verification.manifest_hash,
+        });
+        if let Err(e) = record_verification(plugin_name, &config.plugin.version, &verification).await {
+            warn!("记录插件 {} 签名状态失败: {}", plugin_name, e);
+        }
+        enforce_signature_policy(plugin_name, &verification)?;
+    }
+    
     // 添加app配置（如果存在）
     if let Some(app_config) = config.app {
         let mut app_json = json!({});
@@ -241,6 +292,7 @@ async fn load_plugin(
 pub async fn reload_plugins() -> Result<(), AppError> {
     info!("正在重新加载插件系统...");
     init_plugins().await?;
+    crate::app::plugin::PLUGIN_ROUTES.rebuild("./plugins").await?;
     info!("插件系统重新加载完成");
     Ok(())
 }
@@ -1,15 +1,28 @@
 /// 插件路由注入器
 /// 负责将插件路由动态注入到主应用路由中
 
-use axum::Router;
-use std::collections::HashMap;
+use axum::{extract::Request, response::Response, Router};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tower::ServiceExt;
 use crate::common::exception::{AppError, ErrorCode};
-use crate::app::plugin::discovery::DiscoveredPlugin;
+use crate::app::plugin::cache::PluginCacheManager;
+use crate::app::plugin::discovery::{DiscoveredPlugin, PluginDiscovery};
+use crate::app::plugin::metrics::instrument;
+use crate::app::plugin::registry::{app_prefix, builtin_plugins, extend_prefix, PluginContext, PluginRegistry};
+
+/// 全局插件路由注入器
+/// 主路由通过 fallback 将未匹配的请求交给当前挂载的插件路由处理
+pub static PLUGIN_ROUTES: Lazy<RouteInjector> = Lazy::new(RouteInjector::new);
 
 /// 路由注入器
 pub struct RouteInjector {
+    /// 插件路由注册表
+    registry: PluginRegistry,
+    /// 当前挂载的插件路由
+    router: Arc<RwLock<Router>>,
     /// 已注入的扩展级插件路由
     extend_plugins: Arc<RwLock<HashMap<String, Vec<String>>>>,
     /// 已注入的应用级插件路由
@@ -19,7 +32,14 @@ pub struct RouteInjector {
 impl RouteInjector {
     /// 创建新的路由注入器
     pub fn new() -> Self {
+        Self::with_registry(PluginRegistry::builtin())
+    }
+
+    /// 使用指定注册表创建路由注入器
+    pub fn with_registry(registry: PluginRegistry) -> Self {
         Self {
+            registry,
+            router: Arc::new(RwLock::new(Router::new())),
             extend_plugins: Arc::new(RwLock::new(HashMap::new())),
             app_plugins: Arc::new(RwLock::new(Vec::new())),
         }
@@ -31,6 +51,7 @@ impl RouteInjector {
         &self,
         target_router: Router,
         plugin: &DiscoveredPlugin,
+        ctx: &PluginContext,
     ) -> Result<Router, AppError> {
         let plugin_name = &plugin.name;
         
@@ -53,25 +74,29 @@ impl RouteInjector {
             })?;
 
         let mut router = target_router;
+        let target_prefix = extend_prefix(extend_target);
 
         // 遍历所有API配置
         for (api_name, api_config) in api_configs {
+            let Some(factory) = self.registry.get(plugin_name, api_name) else {
+                tracing::warn!(
+                    "Plugin {} api {} has no registered router, skipped",
+                    plugin_name,
+                    api_name
+                );
+                continue;
+            };
+
+            let prefix = format!("{}{}", target_prefix, api_config.prefix);
             tracing::debug!(
                 "Injecting API {} with prefix {} for plugin {}",
                 api_name,
-                api_config.prefix,
+                prefix,
                 plugin_name
             );
 
-            // TODO: 这里需要实际加载插件的路由器
-            // 目前返回空路由作为占位符
-            // 实际实现需要动态加载插件模块并获取其router
-            
-            // 创建插件路由（暂时为空，实际使用时需要加载真实路由）
-            let plugin_router = Router::new();
-
             // 将插件路由添加到主路由
-            router = router.nest(&api_config.prefix, plugin_router);
+            router = router.nest(&prefix, instrument(plugin_name, factory(ctx)));
         }
 
         // 记录已注入的插件
@@ -96,6 +121,7 @@ impl RouteInjector {
         &self,
         main_router: Router,
         plugin: &DiscoveredPlugin,
+        ctx: &PluginContext,
     ) -> Result<Router, AppError> {
         let plugin_name = &plugin.name;
         
@@ -113,22 +139,23 @@ impl RouteInjector {
 
         // 遍历所有路由器
         for router_name in routers {
+            let Some(factory) = self.registry.get(plugin_name, router_name) else {
+                tracing::warn!(
+                    "Plugin {} router {} is not registered, skipped",
+                    plugin_name,
+                    router_name
+                );
+                continue;
+            };
+
             tracing::debug!(
                 "Injecting router {} for plugin {}",
                 router_name,
                 plugin_name
             );
 
-            // TODO: 这里需要实际加载插件的路由器
-            // 目前返回空路由作为占位符
-            
-            // 创建插件路由（暂时为空）
-            let plugin_router = Router::new();
-
-            // 将插件路由添加到主路由
-            // 使用插件名作为路径前缀
-            let route_prefix = format!("/plugin/{}", plugin_name);
-            router = router.nest(&route_prefix, plugin_router);
+            // 路由名即API版本，如 v1 挂载到 /api/v1
+            router = router.nest(&app_prefix(router_name), instrument(plugin_name, factory(ctx)));
         }
 
         // 记录已注入的插件
@@ -148,11 +175,12 @@ impl RouteInjector {
         &self,
         target_router: Router,
         plugins: Vec<DiscoveredPlugin>,
+        ctx: &PluginContext,
     ) -> Result<Router, AppError> {
         let mut router = target_router;
 
         for plugin in plugins {
-            router = self.inject_extend_plugin(router, &plugin).await?;
+            router = self.inject_extend_plugin(router, &plugin, ctx).await?;
         }
 
         Ok(router)
@@ -163,16 +191,78 @@ impl RouteInjector {
         &self,
         main_router: Router,
         plugins: Vec<DiscoveredPlugin>,
+        ctx: &PluginContext,
     ) -> Result<Router, AppError> {
         let mut router = main_router;
 
         for plugin in plugins {
-            router = self.inject_app_plugin(router, &plugin).await?;
+            router = self.inject_app_plugin(router, &plugin, ctx).await?;
         }
 
         Ok(router)
     }
 
+    /// 重新构建插件路由
+    /// 按内置插件嵌入的 plugin.toml 和启用状态挂载已注册的插件，替换当前路由；
+    /// 插件目录中未编译进应用的插件只记录警告
+    pub async fn rebuild(&self, plugin_dir: &str) -> Result<(), AppError> {
+        let discovery = PluginDiscovery::new(plugin_dir);
+        for plugin in discovery.discover_all()? {
+            if !self.registry.contains(&plugin.name) {
+                tracing::warn!("Plugin {} is not compiled into the application, skipped", plugin.name);
+            }
+        }
+
+        let builtins = builtin_plugins(plugin_dir)?;
+        let mut enabled = HashSet::new();
+        for plugin in &builtins {
+            if PluginCacheManager::is_plugin_enabled(&plugin.name).await? {
+                enabled.insert(plugin.name.clone());
+            } else {
+                tracing::info!("Skipping disabled plugin: {}", plugin.name);
+            }
+        }
+        let (enabled_extend, enabled_app) =
+            discovery.classify_plugins(self.select_mountable(builtins, &enabled));
+        let ctx = PluginContext::current().await?;
+
+        self.clear().await;
+        let router = self.inject_extend_plugins(Router::new(), enabled_extend, &ctx).await?;
+        let router = self.inject_app_plugins(router, enabled_app, &ctx).await?;
+        *self.router.write().await = router;
+
+        tracing::info!("Plugin routes rebuilt");
+        Ok(())
+    }
+
+    /// 筛选可挂载的插件：已注册路由工厂且处于启用状态
+    /// 已注册的插件均编译进应用，视为可信，不执行签名策略；签名策略只作用于安装的插件
+    fn select_mountable(
+        &self,
+        plugins: Vec<DiscoveredPlugin>,
+        enabled: &HashSet<String>,
+    ) -> Vec<DiscoveredPlugin> {
+        plugins
+            .into_iter()
+            .filter(|plugin| {
+                let registered = self.registry.contains(&plugin.name);
+                if !registered {
+                    tracing::warn!("Plugin {} has no registered router factory, skipped", plugin.name);
+                }
+                registered && enabled.contains(&plugin.name)
+            })
+            .collect()
+    }
+
+    /// 将请求交给当前挂载的插件路由处理
+    pub async fn handle(&self, req: Request) -> Response {
+        let router = self.router.read().await.clone();
+        match router.oneshot(req).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        }
+    }
+
     /// 获取已注入的扩展级插件列表
     pub async fn get_injected_extend_plugins(&self) -> HashMap<String, Vec<String>> {
         self.extend_plugins.read().await.clone()
@@ -209,4 +299,40 @@ mod tests {
         assert!(injector.get_injected_app_plugins().await.is_empty());
         assert!(injector.get_injected_extend_plugins().await.is_empty());
     }
+
+    #[test]
+    fn test_builtin_plugins_mount_under_prod_policy() {
+        use crate::app::plugin::signature::{check_signature_policy, SignatureStatus, VerificationResult};
+
+        let injector = RouteInjector::new();
+        let builtins = builtin_plugins("./plugins").unwrap();
+        let enabled: HashSet<String> = builtins.iter().map(|p| p.name.clone()).collect();
+
+        // 内置插件未签名，作为安装的插件在生产环境会被拒绝
+        let unsigned = VerificationResult {
+            status: SignatureStatus::Unsigned,
+            key_id: None,
+            manifest_hash: String::new(),
+        };
+        for plugin in &builtins {
+            assert!(check_signature_policy(&plugin.name, &unsigned, true).is_err());
+        }
+
+        // 编译进应用的内置插件不经过签名策略，仍全部挂载
+        let mounted = injector.select_mountable(builtins, &enabled);
+        assert_eq!(mounted.len(), enabled.len());
+
+        // 未启用或未注册的插件不挂载
+        let mut unknown = builtin_plugins("./plugins").unwrap().remove(0);
+        unknown.name = "unknown".to_string();
+        let mut partial = enabled.clone();
+        partial.remove("dict");
+        partial.insert("unknown".to_string());
+        let mounted = injector.select_mountable(
+            builtin_plugins("./plugins").unwrap().into_iter().chain([unknown]).collect(),
+            &partial,
+        );
+        assert_eq!(mounted.len(), enabled.len() - 1);
+        assert!(mounted.iter().all(|p| p.name != "dict" && p.name != "unknown"));
+    }
 }
@@ -3,7 +3,7 @@
 
 use std::fs;
 use std::io::Cursor;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use axum::extract::Multipart;
 use tracing::{info, error};
 use zip::ZipArchive;
@@ -11,6 +11,7 @@ use regex::Regex;
 
 use crate::common::exception::{AppError, ErrorCode};
 use crate::app::plugin::cache::PluginCacheManager;
+use crate::app::plugin::signature::{enforce_signature_policy, SignatureVerifier};
 
 /// 插件安装结果
 #[derive(Debug, Clone)]
@@ -27,7 +28,8 @@ pub struct InstallResult {
 /// 2. 检查必要文件（plugin.toml, README.md）
 /// 3. 验证插件名称和是否已安装
 /// 4. 解压到plugins目录
-/// 5. 标记插件变更
+/// 5. 校验插件签名
+/// 6. 标记插件变更
 pub async fn install_zip_plugin(mut multipart: Multipart) -> Result<InstallResult, AppError> {
     info!("Starting ZIP plugin installation...");
     
@@ -190,6 +192,9 @@ pub async fn install_zip_plugin(mut multipart: Multipart) -> Result<InstallResul
     
     info!("插件文件解压成功");
     
+    // 校验插件签名，生产环境拒绝未签名或被篡改的插件
+    verify_plugin_signature(&plugin_name, &plugin_path)?;
+    
     // 立即加载插件配置到Redis（这样前端可以立即看到新插件）
     info!("正在加载插件配置到 Redis...");
     match crate::app::plugin::init::load_single_plugin(&plugin_name).await {
@@ -221,12 +226,13 @@ pub async fn install_zip_plugin(mut multipart: Multipart) -> Result<InstallResul
 /// 2. 提取仓库名称
 /// 3. 检查是否已安装
 /// 4. 克隆仓库到plugins目录
-/// 5. 标记插件变更
+/// 5. 校验插件签名
+/// 6. 标记插件变更
 pub async fn install_git_plugin(repo_url: String) -> Result<InstallResult, AppError> {
     info!("Starting Git plugin installation from: {}", repo_url);
     
     // 验证Git URL格式
-    let re = Regex::new(r"(?:https?://|git@)[\w\.\-]+(?:/|:)(?P<repo>[\w\-]+)(?:\.git)?$").unwrap();
+    let re = Regex::new(r"(?:https?://|git@)[\w\.\-]+(?:/|:)(?:[\w\.\-]+/)*(?P<repo>[\w\-]+)(?:\.git)?$").unwrap();
     let captures = re.captures(&repo_url).ok_or_else(|| {
         AppError::with_message(ErrorCode::InvalidInput, "Git 仓库地址格式非法")
     })?;
@@ -267,6 +273,9 @@ pub async fn install_git_plugin(repo_url: String) -> Result<InstallResult, AppEr
         ));
     }
     
+    // 校验插件签名，生产环境拒绝未签名或被篡改的插件
+    verify_plugin_signature(&repo_name, &plugin_path)?;
+    
     // 立即加载插件配置到Redis
     info!("正在加载插件配置到 Redis...");
     match crate::app::plugin::init::load_single_plugin(&repo_name).await {
@@ -294,6 +303,19 @@ pub async fn install_git_plugin(repo_url: String) -> Result<InstallResult, AppEr
     })
 }
 
+/// 校验插件签名，未通过策略检查时删除已安装的文件
+fn verify_plugin_signature(plugin_name: &str, plugin_path: &Path) -> Result<(), AppError> {
+    let result = SignatureVerifier::from_settings()?.verify_plugin(plugin_path)?;
+    info!("插件 {} 签名校验结果: {}", plugin_name, result.status.get_name());
+    
+    if let Err(e) = enforce_signature_policy(plugin_name, &result) {
+        error!("插件 {} 签名校验失败，已拒绝安装", plugin_name);
+        let _ = fs::remove_dir_all(plugin_path);
+        return Err(e);
+    }
+    Ok(())
+}
+
 /// 清理文件名，移除特殊字符
 /// 
 /// 规则：
@@ -327,7 +349,7 @@ mod tests {
 
     #[test]
     fn test_git_url_regex() {
-        let re = Regex::new(r"(?:https?://|git@)[\w\.\-]+(?:/|:)(?P<repo>[\w\-]+)(?:\.git)?$").unwrap();
+        let re = Regex::new(r"(?:https?://|git@)[\w\.\-]+(?:/|:)(?:[\w\.\-]+/)*(?P<repo>[\w\-]+)(?:\.git)?$").unwrap();
         
         let urls = vec![
             ("https://github.com/user/my-plugin.git", Some("my-plugin")),
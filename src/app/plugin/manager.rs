@@ -11,6 +11,7 @@ use super::{
     RouteInjector,
     DiscoveredPlugin,
 };
+use super::registry::PluginContext;
 
 /// 统一插件管理器
 pub struct UnifiedPluginManager {
@@ -78,11 +79,13 @@ impl UnifiedPluginManager {
             app_plugins.len()
         );
 
+        let ctx = PluginContext::current().await?;
+
         // 1. 注入扩展级插件（需要在主路由构建前）
         let mut router = base_router;
         for plugin in &extend_plugins {
             if PluginCacheManager::is_plugin_enabled(&plugin.name).await? {
-                router = self.injector.inject_extend_plugin(router, plugin).await?;
+                router = self.injector.inject_extend_plugin(router, plugin, &ctx).await?;
             } else {
                 tracing::warn!("Skipping disabled extend plugin: {}", plugin.name);
             }
@@ -91,7 +94,7 @@ impl UnifiedPluginManager {
         // 2. 注入应用级插件
         for plugin in &app_plugins {
             if PluginCacheManager::is_plugin_enabled(&plugin.name).await? {
-                router = self.injector.inject_app_plugin(router, plugin).await?;
+                router = self.injector.inject_app_plugin(router, plugin, &ctx).await?;
             } else {
                 tracing::warn!("Skipping disabled app plugin: {}", plugin.name);
             }
@@ -5,14 +5,19 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
+use axum::{extract::{MatchedPath, Request}, middleware::Next, Router};
+use once_cell::sync::Lazy;
 use serde::{Serialize, Deserialize};
 
+/// 全局插件性能监控管理器，插件路由挂载时自动接入，指标由 /metrics 导出
+pub static PLUGIN_METRICS: Lazy<MetricsManager> = Lazy::new(MetricsManager::new);
+
 /// 性能指标
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginMetrics {
     /// 插件名称
     pub plugin_name: String,
-    /// 总请求数
+    /// 总请求数（累计）
     pub total_requests: u64,
     /// 成功请求数
     pub successful_requests: u64,
@@ -99,6 +104,17 @@ impl PluginPerformanceMonitor {
             success,
         };
 
+        {
+            // 请求数自启动以来累计，不受样本窗口影响
+            let mut metrics = self.metrics.write().await;
+            metrics.total_requests += 1;
+            if success {
+                metrics.successful_requests += 1;
+            } else {
+                metrics.failed_requests += 1;
+            }
+        }
+
         {
             let mut samples = self.samples.write().await;
             samples.push(sample);
@@ -123,12 +139,7 @@ impl PluginPerformanceMonitor {
             return;
         }
 
-        // 统计请求数
-        metrics.total_requests = samples.len() as u64;
-        metrics.successful_requests = samples.iter().filter(|s| s.success).count() as u64;
-        metrics.failed_requests = samples.iter().filter(|s| !s.success).count() as u64;
-
-        // 计算响应时间统计
+        // 计算响应时间统计（最近1000个样本）
         let mut response_times: Vec<f64> = samples
             .iter()
             .map(|s| s.response_time.as_secs_f64() * 1000.0)
@@ -233,6 +244,19 @@ impl MetricsManager {
         monitor
     }
 
+    /// 获取插件监控器，不存在时创建
+    pub async fn monitor(&self, plugin_name: &str) -> Arc<PluginPerformanceMonitor> {
+        if let Some(monitor) = self.get_monitor(plugin_name).await {
+            return monitor;
+        }
+
+        let mut monitors = self.monitors.write().await;
+        monitors
+            .entry(plugin_name.to_string())
+            .or_insert_with(|| Arc::new(PluginPerformanceMonitor::new(plugin_name.to_string())))
+            .clone()
+    }
+
     /// 获取插件监控器
     pub async fn get_monitor(&self, plugin_name: &str) -> Option<Arc<PluginPerformanceMonitor>> {
         let monitors = self.monitors.read().await;
@@ -271,6 +295,28 @@ impl Default for MetricsManager {
     }
 }
 
+/// 为插件路由接入性能监控
+///
+/// 5xx 响应计为失败；匹配到的路由模板写入响应扩展，供主路由的请求指标使用
+pub fn instrument(plugin_name: &str, router: Router) -> Router {
+    let plugin_name = plugin_name.to_string();
+    router.layer(axum::middleware::from_fn(move |request: Request, next: Next| {
+        let plugin_name = plugin_name.clone();
+        async move {
+            let matched = request.extensions().get::<MatchedPath>().cloned();
+            let timer = RequestTimer::start(PLUGIN_METRICS.monitor(&plugin_name).await);
+
+            let mut response = next.run(request).await;
+            timer.finish(!response.status().is_server_error()).await;
+
+            if let Some(matched) = matched {
+                response.extensions_mut().insert(matched);
+            }
+            response
+        }
+    }))
+}
+
 /// 性能计时器
 /// 用于简化请求时间测量
 pub struct RequestTimer {
@@ -313,6 +359,19 @@ mod tests {
         assert_eq!(metrics.failed_requests, 1);
     }
 
+    #[tokio::test]
+    async fn test_request_counts_are_cumulative() {
+        let monitor = PluginPerformanceMonitor::new("test_plugin".to_string());
+
+        for _ in 0..1005 {
+            monitor.record_request(Duration::from_millis(1), true).await;
+        }
+
+        let metrics = monitor.get_metrics().await;
+        assert_eq!(metrics.total_requests, 1005);
+        assert_eq!(metrics.successful_requests, 1005);
+    }
+
     #[tokio::test]
     async fn test_metrics_manager() {
         let manager = MetricsManager::new();
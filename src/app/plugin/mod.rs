@@ -4,6 +4,7 @@ pub mod discovery;
 pub mod cache;
 pub mod middleware;
 pub mod injector;
+pub mod registry;
 pub mod manager;
 pub mod dynamic_loader;
 pub mod signature;
@@ -11,6 +12,7 @@ pub mod sandbox;
 pub mod metrics;
 pub mod init;  // 插件初始化模块
 pub mod install;  // 插件安装模块
+pub mod notice_backend;  // 通知插件后端
 
 // API和路由模块
 pub mod api;
@@ -25,7 +27,8 @@ pub use config::{PluginConfig, PluginInfo, AppConfig, ApiConfig};
 pub use discovery::{PluginDiscovery, DiscoveredPlugin};
 pub use cache::{PluginCacheManager, PluginCacheInfo};
 pub use middleware::check_plugin_status;
-pub use injector::RouteInjector;
+pub use injector::{RouteInjector, PLUGIN_ROUTES};
+pub use registry::{PluginContext, PluginRegistry};
 pub use manager::UnifiedPluginManager;
 pub use init::{init_plugins, reload_plugins};  // 导出初始化函数
 pub use install::{install_zip_plugin, install_git_plugin, InstallResult};  // 导出安装函数
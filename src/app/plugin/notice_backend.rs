@@ -0,0 +1,157 @@
+/// 通知插件后端
+/// 为 notice 插件提供用户认证、接收人解析、WebSocket 推送和邮件兜底
+
+use async_trait::async_trait;
+use notice_plugin::{NoticeBackend, NoticeError, Recipient};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use serde_json::Value;
+
+use crate::app::auth::service::verify_session_token;
+use crate::common::exception::AppError;
+use crate::core::SETTINGS;
+use crate::database::{
+    DatabaseConnection,
+    entity::{user, user_role},
+    redis::RedisManager,
+    user_repo::UserRepository as UserRepo,
+    user_role_repo::UserRoleRepository as UserRoleRepo,
+};
+use crate::websocket::{emit_to_user, presence};
+
+/// 投放范围（与 notice 插件一致）
+const TARGET_ALL: i16 = 0;
+const TARGET_ROLE: i16 = 1;
+const TARGET_DEPT: i16 = 2;
+const TARGET_USER: i16 = 3;
+
+/// 通知中心后端
+pub struct NoticeBackendService {
+    db: DatabaseConnection,
+}
+
+impl NoticeBackendService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 用户当前的登录会话
+    async fn sessions(user_id: i64) -> Result<Vec<String>, AppError> {
+        let prefix = format!("{}:{}:", SETTINGS.token_redis_prefix, user_id);
+        let keys = RedisManager::scan_keys(&format!("{}*", prefix)).await?;
+
+        Ok(keys
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&prefix).map(str::to_string))
+            .collect())
+    }
+}
+
+fn to_notice_error(e: impl std::fmt::Display) -> NoticeError {
+    NoticeError::DatabaseError(e.to_string())
+}
+
+#[async_trait]
+impl NoticeBackend for NoticeBackendService {
+    async fn authenticate(&self, token: &str) -> Result<i64, NoticeError> {
+        let payload = verify_session_token(token)
+            .await
+            .map_err(|e| NoticeError::Unauthorized(e.message))?;
+
+        payload
+            .sub
+            .parse()
+            .map_err(|_| NoticeError::Unauthorized("Token无效".to_string()))
+    }
+
+    async fn recipient(&self, user_id: i64) -> Result<Recipient, NoticeError> {
+        let user = UserRepo::find_by_id(user_id, &self.db)
+            .await
+            .map_err(|_| NoticeError::Unauthorized("用户不存在".to_string()))?;
+        let role_ids = UserRoleRepo::find_roles_by_user(user_id, &self.db)
+            .await
+            .map_err(to_notice_error)?;
+
+        Ok(Recipient {
+            user_id: user.id,
+            dept_id: user.dept_id,
+            role_ids,
+            email: user.email,
+        })
+    }
+
+    async fn resolve_recipients(
+        &self,
+        target_type: i16,
+        target_ids: &[i64],
+    ) -> Result<Vec<Recipient>, NoticeError> {
+        let mut select = user::Entity::find()
+            .filter(user::Column::DelFlag.eq(0))
+            .filter(user::Column::Status.eq(1));
+        select = match target_type {
+            TARGET_ALL => select,
+            TARGET_ROLE => {
+                let user_ids: Vec<i64> = user_role::Entity::find()
+                    .select_only()
+                    .column(user_role::Column::UserId)
+                    .filter(user_role::Column::RoleId.is_in(target_ids.to_vec()))
+                    .into_tuple()
+                    .all(&self.db)
+                    .await
+                    .map_err(to_notice_error)?;
+                select.filter(user::Column::Id.is_in(user_ids))
+            }
+            TARGET_DEPT => select.filter(user::Column::DeptId.is_in(target_ids.to_vec())),
+            TARGET_USER => select.filter(user::Column::Id.is_in(target_ids.to_vec())),
+            other => {
+                return Err(NoticeError::ValidationError(format!("未知的投放范围: {}", other)));
+            }
+        };
+
+        let users = select.all(&self.db).await.map_err(to_notice_error)?;
+        Ok(users
+            .into_iter()
+            .map(|u| Recipient {
+                user_id: u.id,
+                dept_id: u.dept_id,
+                role_ids: Vec::new(),
+                email: u.email.filter(|e| !e.is_empty()),
+            })
+            .collect())
+    }
+
+    async fn push(&self, user_id: i64, event: &str, payload: &Value) -> bool {
+        let sessions = match Self::sessions(user_id).await {
+            Ok(sessions) => sessions,
+            Err(e) => {
+                tracing::warn!("获取用户 {} 的会话失败: {}", user_id, e.message);
+                return false;
+            }
+        };
+
+        let mut online = false;
+        for session_uuid in &sessions {
+            if presence::is_online(session_uuid).await.unwrap_or(false) {
+                online = true;
+                break;
+            }
+        }
+        if online {
+            emit_to_user(user_id, event.to_string(), payload).await;
+        }
+        online
+    }
+
+    async fn send_email(&self, to: &str, subject: &str, content: &str) -> Result<(), NoticeError> {
+        let param = email_plugin::SendEmailParam {
+            to: to.to_string(),
+            subject: subject.to_string(),
+            content: content.to_string(),
+            is_html: true,
+            text_content: None,
+        };
+        email_plugin::EmailService::send_email(&self.db, param)
+            .await
+            .map(|_| ())
+            .map_err(|e| NoticeError::OperationFailed(e.to_string()))
+    }
+}
@@ -0,0 +1,200 @@
+/// 插件路由注册表
+/// 记录已编译进应用的插件及其路由工厂，按 plugin.toml 中的 [app]/[api] 配置挂载
+
+use axum::Router;
+use redis::aio::ConnectionManager;
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use code_generator_plugin::CodeGeneratorPlugin;
+use config_plugin::ConfigPlugin;
+use email_plugin::EmailPlugin;
+use notice_plugin::NoticePlugin;
+use oauth2_plugin::{OAuth2Config, OAuth2Plugin};
+
+use crate::app::auth::service::OAuth2AccountService;
+use crate::app::plugin::config::PluginConfig;
+use crate::app::plugin::discovery::DiscoveredPlugin;
+use crate::app::plugin::notice_backend::NoticeBackendService;
+use crate::common::exception::AppError;
+use crate::database::redis::RedisManager;
+use crate::database::DatabaseManager;
+
+/// 插件路由所需的共享状态
+#[derive(Clone)]
+pub struct PluginContext {
+    pub db: DatabaseConnection,
+    pub redis: ConnectionManager,
+}
+
+impl PluginContext {
+    /// 从全局数据库和Redis连接构建
+    pub async fn current() -> Result<Self, AppError> {
+        Ok(Self {
+            db: DatabaseManager::get_connection().await.clone(),
+            redis: RedisManager::get_connection().await?,
+        })
+    }
+}
+
+/// 内置插件的 plugin.toml，编译期嵌入，运行时无需 plugins 目录即可挂载
+pub const BUILTIN_MANIFESTS: &[(&str, &str)] = &[
+    ("notice", include_str!("../../../plugins/notice/plugin.toml")),
+    ("config", include_str!("../../../plugins/config/plugin.toml")),
+    ("dict", include_str!("../../../plugins/dict/plugin.toml")),
+    ("code_generator", include_str!("../../../plugins/code_generator/plugin.toml")),
+    ("email", include_str!("../../../plugins/email/plugin.toml")),
+    ("oauth2", include_str!("../../../plugins/oauth2/plugin.toml")),
+];
+
+/// 获取内置插件的 plugin.toml 内容
+pub fn builtin_manifest(plugin: &str) -> Option<&'static str> {
+    BUILTIN_MANIFESTS
+        .iter()
+        .find(|(name, _)| *name == plugin)
+        .map(|(_, manifest)| *manifest)
+}
+
+/// 按嵌入的 plugin.toml 构建内置插件列表，path 指向插件目录下的同名子目录（可能不存在）
+pub fn builtin_plugins(plugin_dir: &str) -> Result<Vec<DiscoveredPlugin>, AppError> {
+    BUILTIN_MANIFESTS
+        .iter()
+        .map(|(name, manifest)| {
+            let mut config = PluginConfig::from_toml_str(manifest)?;
+            config.plugin.name = Some(name.to_string());
+            config.validate()?;
+            Ok(DiscoveredPlugin {
+                name: name.to_string(),
+                config,
+                path: Path::new(plugin_dir).join(name),
+            })
+        })
+        .collect()
+}
+
+/// 路由工厂
+pub type RouterFactory = fn(&PluginContext) -> Router;
+
+/// 插件路由注册表
+///
+/// key 为插件名，value 为 路由名 -> 工厂 的映射：
+/// - 扩展级插件（extend）：路由名对应 plugin.toml 中的 `[api.<name>]`
+/// - 应用级插件（router）：路由名对应 `[app] router` 中的版本（如 `v1`）
+#[derive(Default)]
+pub struct PluginRegistry {
+    factories: HashMap<String, HashMap<String, RouterFactory>>,
+}
+
+impl PluginRegistry {
+    /// 创建空注册表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册内置插件
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        registry.register("notice", "notice", |ctx| {
+            NoticePlugin::create_router(
+                ctx.db.clone(),
+                Arc::new(NoticeBackendService::new(ctx.db.clone())),
+            )
+        });
+        registry.register("config", "config", |ctx| {
+            ConfigPlugin::create_router(ctx.db.clone(), ctx.redis.clone())
+        });
+        registry.register("dict", "dict_type", |ctx| {
+            dict_plugin::api::dict_type_routes().with_state(ctx.db.clone())
+        });
+        registry.register("dict", "dict_data", |ctx| {
+            dict_plugin::api::dict_data_routes().with_state(ctx.db.clone())
+        });
+        registry.register("code_generator", "v1", |ctx| {
+            Router::new().nest("/generates", CodeGeneratorPlugin::create_router(ctx.db.clone()))
+        });
+        registry.register("email", "v1", |ctx| {
+            EmailPlugin::create_router(ctx.db.clone())
+        });
+        registry.register("oauth2", "v1", |ctx| {
+            OAuth2Plugin::create_router(
+                ctx.db.clone(),
+                ctx.redis.clone(),
+                OAuth2Config::default(),
+                Arc::new(OAuth2AccountService::new(ctx.db.clone())),
+            )
+        });
+
+        registry
+    }
+
+    /// 注册插件路由工厂
+    pub fn register(&mut self, plugin: &str, router: &str, factory: RouterFactory) {
+        self.factories
+            .entry(plugin.to_string())
+            .or_default()
+            .insert(router.to_string(), factory);
+    }
+
+    /// 获取插件路由工厂
+    pub fn get(&self, plugin: &str, router: &str) -> Option<RouterFactory> {
+        self.factories.get(plugin)?.get(router).copied()
+    }
+
+    /// 插件是否已注册
+    pub fn contains(&self, plugin: &str) -> bool {
+        self.factories.contains_key(plugin)
+    }
+}
+
+/// 扩展级插件的挂载前缀
+/// extend = 'admin' 对应系统管理模块 /api/v1/sys
+pub fn extend_prefix(target: &str) -> String {
+    match target {
+        "admin" => "/api/v1/sys".to_string(),
+        other => format!("/api/v1/{}", other),
+    }
+}
+
+/// 应用级插件的挂载前缀
+pub fn app_prefix(router: &str) -> String {
+    format!("/api/{}", router)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_registry() {
+        let registry = PluginRegistry::builtin();
+        assert!(registry.get("dict", "dict_type").is_some());
+        assert!(registry.get("email", "v1").is_some());
+        assert!(registry.get("dict", "v1").is_none());
+        assert!(!registry.contains("unknown"));
+        assert_eq!(extend_prefix("admin"), "/api/v1/sys");
+        assert_eq!(app_prefix("v1"), "/api/v1");
+    }
+
+    #[test]
+    fn test_builtin_manifests_cover_registry() {
+        let registry = PluginRegistry::builtin();
+        let plugins = builtin_plugins("./not-exists").unwrap();
+        assert_eq!(plugins.len(), registry.factories.len());
+
+        for plugin in &plugins {
+            assert!(registry.contains(&plugin.name), "{} 未注册路由工厂", plugin.name);
+            let routers: Vec<&String> = match plugin.config.get_routers() {
+                Some(routers) => routers.iter().collect(),
+                None => plugin.config.api.as_ref().unwrap().keys().collect(),
+            };
+            for router in routers {
+                assert!(registry.get(&plugin.name, router).is_some(), "{}:{} 未注册", plugin.name, router);
+            }
+        }
+        assert!(builtin_manifest("dict").unwrap().contains("/dict-datas"));
+        assert!(builtin_manifest("unknown").is_none());
+    }
+}
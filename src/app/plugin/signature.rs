@@ -1,94 +1,351 @@
 /// 插件签名验证模块
-/// 使用RSA+SHA256确保插件完整性和来源可信
+/// 对插件目录内全部文件生成规范化清单，使用受信任公钥（Ed25519 / RSA-SHA256）校验 plugin.sig
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ed25519_dalek::pkcs8::DecodePublicKey as _; // Ed25519 与 RSA 共用 pkcs8 的公钥解析 trait
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use sha2::{Sha256, Digest};
+use std::collections::HashMap;
 use std::path::Path;
 use std::fs;
 use crate::common::exception::{AppError, ErrorCode};
 
+/// 插件签名文件名（位于插件根目录，不参与清单计算）
+pub const SIGNATURE_FILE: &str = "plugin.sig";
+
+/// 签名算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SignatureAlgorithm {
+    Ed25519,
+    RsaSha256,
+}
+
+/// 插件签名文件内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginSignatureFile {
+    /// 签名算法
+    pub algorithm: SignatureAlgorithm,
+    /// 公钥ID（对应受信任公钥目录中的 {key_id}.pem）
+    pub key_id: String,
+    /// Base64 编码的签名
+    pub signature: String,
+}
+
+/// 签名校验状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureStatus {
+    /// 未签名
+    Unsigned = 0,
+    /// 校验通过
+    Verified = 1,
+    /// 签名无效（文件被篡改或签名格式错误）
+    Invalid = 2,
+    /// 签名公钥不受信任
+    UntrustedKey = 3,
+}
+
+impl SignatureStatus {
+    /// 从i32值创建SignatureStatus
+    pub fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(SignatureStatus::Unsigned),
+            1 => Some(SignatureStatus::Verified),
+            2 => Some(SignatureStatus::Invalid),
+            3 => Some(SignatureStatus::UntrustedKey),
+            _ => None,
+        }
+    }
+
+    /// 获取状态名称
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SignatureStatus::Unsigned => "未签名",
+            SignatureStatus::Verified => "已验证",
+            SignatureStatus::Invalid => "签名无效",
+            SignatureStatus::UntrustedKey => "不受信任的公钥",
+        }
+    }
+}
+
+/// 签名校验结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationResult {
+    pub status: SignatureStatus,
+    pub key_id: Option<String>,
+    /// 规范化清单的SHA256
+    pub manifest_hash: String,
+}
+
+impl VerificationResult {
+    pub fn is_verified(&self) -> bool {
+        self.status == SignatureStatus::Verified
+    }
+}
+
+/// 受信任公钥
+enum TrustedKey {
+    Ed25519(ed25519_dalek::VerifyingKey),
+    Rsa(rsa::RsaPublicKey),
+}
+
 /// 签名验证器
+#[derive(Default)]
 pub struct SignatureVerifier {
-    /// 公钥PEM
-    #[allow(dead_code)]
-    public_key_pem: String,
+    /// 受信任公钥，key 为公钥ID
+    keys: HashMap<String, TrustedKey>,
 }
 
 impl SignatureVerifier {
-    /// 创建新的验证器
-    pub fn new(public_key_pem: String) -> Self {
-        Self { public_key_pem }
+    /// 创建不含任何公钥的验证器
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// 从文件加载公钥
-    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, AppError> {
-        let public_key_pem = fs::read_to_string(path).map_err(|e| {
-            tracing::error!("Failed to read public key: {:?}", e);
-            AppError::with_message(ErrorCode::IOError, "Failed to read public key file")
+    /// 从受信任公钥目录加载（目录中每个 {key_id}.pem 为一个 SPKI 公钥）
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self, AppError> {
+        let mut verifier = Self::new();
+        let dir = dir.as_ref();
+        if !dir.exists() {
+            tracing::warn!("Trusted plugin keys directory does not exist: {:?}", dir);
+            return Ok(verifier);
+        }
+
+        let entries = fs::read_dir(dir).map_err(|e| {
+            AppError::with_message(ErrorCode::IOError, format!("Failed to read trusted keys directory: {}", e))
         })?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("pem") {
+                continue;
+            }
+            let Some(key_id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let pem = fs::read_to_string(&path).map_err(|e| {
+                AppError::with_message(ErrorCode::IOError, format!("Failed to read public key: {}", e))
+            })?;
+            if let Err(e) = verifier.add_key_pem(key_id, &pem) {
+                tracing::error!("Failed to load trusted key {}: {}", key_id, e);
+            }
+        }
 
-        Ok(Self::new(public_key_pem))
+        Ok(verifier)
     }
 
-    /// 验证插件签名
-    pub fn verify_plugin<P: AsRef<Path>>(
-        &self,
-        plugin_path: P,
-        signature: &[u8],
-    ) -> Result<bool, AppError> {
-        tracing::info!("Verifying plugin signature for: {:?}", plugin_path.as_ref());
+    /// 从当前配置的受信任公钥目录加载
+    pub fn from_settings() -> Result<Self, AppError> {
+        Self::from_dir(&crate::core::SETTINGS.plugin_trusted_keys_dir)
+    }
 
-        // 计算插件文件的SHA256哈希
-        let plugin_hash = self.calculate_file_hash(plugin_path)?;
+    /// 添加 PEM 格式公钥（自动识别 Ed25519 / RSA）
+    pub fn add_key_pem(&mut self, key_id: &str, pem: &str) -> Result<(), AppError> {
+        let key = if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_pem(pem) {
+            TrustedKey::Ed25519(key)
+        } else if let Ok(key) = rsa::RsaPublicKey::from_public_key_pem(pem) {
+            TrustedKey::Rsa(key)
+        } else {
+            return Err(AppError::with_message(
+                ErrorCode::InvalidInput,
+                "Unsupported public key, expected Ed25519 or RSA SPKI PEM",
+            ));
+        };
+        self.keys.insert(key_id.to_string(), key);
+        Ok(())
+    }
 
-        // TODO: 使用RSA公钥验证签名
-        // 这里需要使用 rsa/ring 等crate
-        // 目前返回基础哈希验证
-        
-        tracing::info!("Plugin hash: {}", hex::encode(&plugin_hash));
-        
-        // 简化验证：检查签名长度是否合理
-        if signature.len() < 64 {
-            tracing::warn!("Invalid signature length");
-            return Ok(false);
+    /// 验证插件目录签名
+    pub fn verify_plugin<P: AsRef<Path>>(&self, plugin_path: P) -> Result<VerificationResult, AppError> {
+        let plugin_path = plugin_path.as_ref();
+        tracing::info!("Verifying plugin signature for: {:?}", plugin_path);
+
+        let manifest = canonical_manifest(plugin_path)?;
+        let manifest_hash = hex::encode(Sha256::digest(manifest.as_bytes()));
+        let result = |status, key_id: Option<&str>| VerificationResult {
+            status,
+            key_id: key_id.map(str::to_string),
+            manifest_hash: manifest_hash.clone(),
+        };
+
+        let sig_path = plugin_path.join(SIGNATURE_FILE);
+        if !sig_path.exists() {
+            return Ok(result(SignatureStatus::Unsigned, None));
         }
 
-        Ok(true)
+        let sig_file: PluginSignatureFile = match fs::read_to_string(&sig_path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(sig_file) => sig_file,
+            Err(e) => {
+                tracing::warn!("Invalid plugin signature file: {}", e);
+                return Ok(result(SignatureStatus::Invalid, None));
+            }
+        };
+
+        let Some(key) = self.keys.get(&sig_file.key_id) else {
+            tracing::warn!("Plugin signed by untrusted key: {}", sig_file.key_id);
+            return Ok(result(SignatureStatus::UntrustedKey, Some(&sig_file.key_id)));
+        };
+
+        let valid = BASE64
+            .decode(sig_file.signature.trim())
+            .map(|signature| verify_signature(key, sig_file.algorithm, manifest.as_bytes(), &signature))
+            .unwrap_or(false);
+        let status = if valid { SignatureStatus::Verified } else { SignatureStatus::Invalid };
+
+        Ok(result(status, Some(&sig_file.key_id)))
     }
+}
 
-    /// 计算文件的SHA256哈希
-    fn calculate_file_hash<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, AppError> {
-        let content = fs::read(path).map_err(|e| {
-            tracing::error!("Failed to read file for hashing: {:?}", e);
-            AppError::with_message(ErrorCode::IOError, "Failed to read file")
+/// 使用公钥校验签名，算法与公钥类型不匹配时视为无效
+fn verify_signature(key: &TrustedKey, algorithm: SignatureAlgorithm, message: &[u8], signature: &[u8]) -> bool {
+    match (key, algorithm) {
+        (TrustedKey::Ed25519(key), SignatureAlgorithm::Ed25519) => {
+            ed25519_dalek::Signature::from_slice(signature)
+                .map(|sig| key.verify_strict(message, &sig).is_ok())
+                .unwrap_or(false)
+        }
+        (TrustedKey::Rsa(key), SignatureAlgorithm::RsaSha256) => {
+            let digest = Sha256::digest(message);
+            key.verify(rsa::Pkcs1v15Sign::new::<Sha256>(), &digest, signature).is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// 生成插件目录的规范化清单
+///
+/// 每行格式为 `{sha256}  {相对路径}`，路径统一使用正斜杠并按字典序排列，
+/// 排除 `.git` 目录和根目录下的 plugin.sig
+pub fn canonical_manifest<P: AsRef<Path>>(plugin_path: P) -> Result<String, AppError> {
+    let plugin_path = plugin_path.as_ref();
+    let mut entries = Vec::new();
+
+    for entry in walkdir::WalkDir::new(plugin_path)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+    {
+        let entry = entry.map_err(|e| {
+            AppError::with_message(ErrorCode::IOError, format!("Failed to walk plugin directory: {}", e))
         })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
 
-        let mut hasher = Sha256::new();
-        hasher.update(&content);
-        Ok(hasher.finalize().to_vec())
+        let relative = entry
+            .path()
+            .strip_prefix(plugin_path)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+        if relative == SIGNATURE_FILE {
+            continue;
+        }
+
+        let content = fs::read(entry.path()).map_err(|e| {
+            AppError::with_message(ErrorCode::IOError, format!("Failed to read file: {}", e))
+        })?;
+        entries.push((relative, hex::encode(Sha256::digest(&content))));
     }
 
-    /// 生成插件的签名文件（用于开发）
-    pub fn generate_signature_file<P: AsRef<Path>>(
-        plugin_path: P,
-        output_path: P,
-    ) -> Result<(), AppError> {
-        let hash = {
-            let content = fs::read(&plugin_path).map_err(|e| {
-                AppError::with_message(ErrorCode::IOError, format!("Failed to read plugin: {}", e))
-            })?;
+    entries.sort();
+    Ok(entries
+        .into_iter()
+        .map(|(path, hash)| format!("{}  {}\n", hash, path))
+        .collect())
+}
 
-            let mut hasher = Sha256::new();
-            hasher.update(&content);
-            hasher.finalize().to_vec()
-        };
+/// 校验插件签名并按运行环境执行策略
+/// 生产环境拒绝未签名、签名无效或公钥不受信任的插件，开发环境仅记录警告；
+/// 仅用于已安装的插件，编译进应用的内置插件不经过该策略
+pub fn enforce_signature_policy(plugin_name: &str, result: &VerificationResult) -> Result<(), AppError> {
+    check_signature_policy(plugin_name, result, crate::core::SETTINGS.is_prod())
+}
 
-        // 写入签名文件（简化版，实际应该用私钥签名）
-        fs::write(output_path, hex::encode(hash)).map_err(|e| {
-            AppError::with_message(ErrorCode::IOError, format!("Failed to write signature: {}", e))
-        })?;
+/// 按指定运行环境执行签名策略
+pub fn check_signature_policy(plugin_name: &str, result: &VerificationResult, is_prod: bool) -> Result<(), AppError> {
+    if result.is_verified() {
+        return Ok(());
+    }
 
-        Ok(())
+    if is_prod {
+        return Err(AppError::with_message(
+            ErrorCode::Forbidden,
+            format!("插件 {} 签名校验失败: {}", plugin_name, result.status.get_name()),
+        ));
     }
+
+    tracing::warn!(
+        "Plugin {} signature check failed ({}), allowed in dev environment",
+        plugin_name,
+        result.status.get_name()
+    );
+    Ok(())
+}
+
+/// 将签名校验结果记录到 sys_plugin 表（按插件编码更新，不存在时新建）
+pub async fn record_verification(
+    plugin_name: &str,
+    version: &str,
+    result: &VerificationResult,
+) -> Result<(), AppError> {
+    use crate::database::entity::plugin;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, Set, NotSet};
+
+    let db = crate::database::DatabaseManager::get_connection().await;
+    let now = chrono::Local::now().naive_local();
+
+    let existing = plugin::Entity::find()
+        .filter(plugin::Column::Code.eq(plugin_name))
+        .one(db)
+        .await?;
+
+    match existing {
+        Some(model) => {
+            let mut active = model.into_active_model();
+            active.version = Set(version.to_string());
+            active.signature_status = Set(result.status as i32);
+            active.signature_key_id = Set(result.key_id.clone());
+            active.manifest_hash = Set(Some(result.manifest_hash.clone()));
+            active.verified_time = Set(Some(now));
+            active.updated_time = Set(now);
+            active.update(db).await?;
+        }
+        None => {
+            plugin::ActiveModel {
+                id: NotSet,
+                name: Set(plugin_name.to_string()),
+                code: Set(plugin_name.to_string()),
+                version: Set(version.to_string()),
+                plugin_type: Set(plugin::PluginType::Feature as i32),
+                description: Set(None),
+                author: Set(None),
+                homepage: Set(None),
+                file_path: Set(format!("./plugins/{}", plugin_name)),
+                class_name: Set(plugin_name.to_string()),
+                config: Set(None),
+                status: Set(plugin::PluginStatus::Enabled as i32),
+                sort_order: Set(0),
+                is_system: Set(0),
+                dependencies: Set(None),
+                install_time: Set(Some(now)),
+                uninstall_time: Set(None),
+                signature_status: Set(result.status as i32),
+                signature_key_id: Set(result.key_id.clone()),
+                manifest_hash: Set(Some(result.manifest_hash.clone())),
+                verified_time: Set(Some(now)),
+                created_time: Set(now),
+                updated_time: Set(now),
+            }
+            .insert(db)
+            .await?;
+        }
+    }
+
+    Ok(())
 }
 
 /// 快速验证插件哈希
@@ -195,4 +452,40 @@ mod tests {
         
         assert!(checker.trusted_hashes.contains_key("test_plugin"));
     }
+
+    #[test]
+    fn test_verify_ed25519_plugin() {
+        use ed25519_dalek::pkcs8::{spki::der::pem::LineEnding, EncodePublicKey};
+        use ed25519_dalek::Signer;
+
+        let dir = std::env::temp_dir().join(format!("plugin_sig_{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("plugin.toml"), "[plugin]\nsummary = 'test'\n").unwrap();
+        fs::write(dir.join("src/lib.rs"), "pub fn f() {}\n").unwrap();
+
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let pem = signing_key.verifying_key().to_public_key_pem(LineEnding::LF).unwrap();
+        let mut verifier = SignatureVerifier::new();
+        verifier.add_key_pem("dev", &pem).unwrap();
+
+        assert_eq!(verifier.verify_plugin(&dir).unwrap().status, SignatureStatus::Unsigned);
+
+        let manifest = canonical_manifest(&dir).unwrap();
+        let sig_file = PluginSignatureFile {
+            algorithm: SignatureAlgorithm::Ed25519,
+            key_id: "dev".to_string(),
+            signature: BASE64.encode(signing_key.sign(manifest.as_bytes()).to_bytes()),
+        };
+        fs::write(dir.join(SIGNATURE_FILE), serde_json::to_string(&sig_file).unwrap()).unwrap();
+        assert_eq!(verifier.verify_plugin(&dir).unwrap().status, SignatureStatus::Verified);
+
+        fs::write(dir.join("src/lib.rs"), "pub fn evil() {}\n").unwrap();
+        assert_eq!(verifier.verify_plugin(&dir).unwrap().status, SignatureStatus::Invalid);
+
+        assert_eq!(
+            SignatureVerifier::new().verify_plugin(&dir).unwrap().status,
+            SignatureStatus::UntrustedKey
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
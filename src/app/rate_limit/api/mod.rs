@@ -0,0 +1,5 @@
+/// 限流管理API模块
+
+pub mod rate_limit;
+
+pub use rate_limit::*;
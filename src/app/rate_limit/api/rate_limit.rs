@@ -0,0 +1,56 @@
+/// 限流管理API（仅超级用户）
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::app::auth::service::rbac_service;
+use crate::app::rate_limit::dto::{RateLimitBucketQuery, ResetRateLimitBucketsRequest};
+use crate::app::rate_limit::service::RateLimitService;
+use crate::common::response::{api_response, ApiResult};
+use crate::middleware::jwt_auth_middleware::AuthContext;
+
+const SUPERUSER_ONLY: &str = "仅超级用户可管理限流";
+
+/// 获取当前生效的限流规则
+/// GET /api/v1/sys/rate-limits/rules
+pub async fn get_rate_limit_rules(Extension(auth): Extension<AuthContext>) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    Ok((StatusCode::OK, Json(api_response(RateLimitService::rules()))))
+}
+
+/// 分页查询限流桶
+/// GET /api/v1/sys/rate-limits/buckets
+pub async fn get_rate_limit_buckets(
+    Extension(auth): Extension<AuthContext>,
+    Query(query): Query<RateLimitBucketQuery>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let result = RateLimitService::get_buckets(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 重置指定的限流桶
+/// DELETE /api/v1/sys/rate-limits/buckets
+pub async fn reset_rate_limit_buckets(
+    Extension(auth): Extension<AuthContext>,
+    Json(request): Json<ResetRateLimitBucketsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let count = RateLimitService::reset_buckets(&request.bucket_keys).await?;
+    Ok((StatusCode::OK, Json(api_response(format!("已重置 {} 个限流桶", count)))))
+}
+
+/// 重置某条规则的全部限流桶
+/// DELETE /api/v1/sys/rate-limits/rules/{name}/buckets
+pub async fn reset_rate_limit_rule(
+    Extension(auth): Extension<AuthContext>,
+    Path(name): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    rbac_service::require_superuser(&auth, SUPERUSER_ONLY).await?;
+    let count = RateLimitService::reset_rule(&name).await?;
+    Ok((StatusCode::OK, Json(api_response(format!("已重置 {} 个限流桶", count)))))
+}
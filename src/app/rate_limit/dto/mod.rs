@@ -0,0 +1,5 @@
+/// 限流 DTO 模块
+
+pub mod rate_limit;
+
+pub use rate_limit::*;
@@ -0,0 +1,64 @@
+/// 限流 DTO
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::app::rate_limit::service::policy::KeyKind;
+
+/// 限流规则
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RateLimitRuleDetail {
+    /// 规则名
+    pub name: String,
+    /// 路径模式
+    pub path: String,
+    /// 请求方法，为空表示全部
+    pub methods: Vec<String>,
+    /// 生效的角色名，为空表示所有请求
+    pub roles: Vec<String>,
+    /// 计数维度
+    pub key: KeyKind,
+    /// 每个周期允许的请求数，0 表示不限流
+    pub limit: u32,
+    /// 周期（秒）
+    pub period: u64,
+}
+
+/// 限流桶查询参数
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RateLimitBucketQuery {
+    /// 规则名
+    pub rule: Option<String>,
+    /// 计数对象（IP、用户ID或 API Key 摘要，模糊匹配）
+    pub subject: Option<String>,
+    /// 页码
+    pub page: Option<u64>,
+    /// 每页数量
+    pub size: Option<u64>,
+}
+
+/// 限流桶
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RateLimitBucketDetail {
+    /// Redis 键
+    pub bucket_key: String,
+    /// 规则名
+    pub rule: String,
+    /// 计数维度
+    pub kind: KeyKind,
+    /// 计数对象
+    pub subject: String,
+    /// 每个周期允许的请求数，规则已删除时为空
+    pub limit: Option<u32>,
+    /// 剩余次数，规则已删除时为空
+    pub remaining: Option<u32>,
+    /// 恢复满额的秒数
+    pub reset_seconds: u64,
+}
+
+/// 重置限流桶请求
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ResetRateLimitBucketsRequest {
+    /// 要重置的 Redis 键
+    pub bucket_keys: Vec<String>,
+}
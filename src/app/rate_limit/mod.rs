@@ -0,0 +1,11 @@
+/// 限流模块
+/// 按路由规则对请求限流（GCRA，计数保存在 Redis 中，多实例共享），
+/// 规则可按 IP、用户或 API Key 计数并区分角色；超级用户可查看和重置限流桶
+
+pub mod api;
+pub mod dto;
+pub mod router;
+pub mod service;
+
+pub use dto::*;
+pub use router::*;
@@ -0,0 +1,14 @@
+/// 组装限流管理相关路由
+
+use axum::{routing::{delete, get}, Router};
+
+use crate::app::rate_limit::api;
+
+/// 限流管理，挂载到 `/api/v1/sys/rate-limits`
+pub fn rate_limit_routes() -> Router {
+    Router::new()
+        .route("/rules", get(api::get_rate_limit_rules))  // GET /api/v1/sys/rate-limits/rules
+        .route("/rules/{name}/buckets", delete(api::reset_rate_limit_rule))  // DELETE /api/v1/sys/rate-limits/rules/{name}/buckets
+        .route("/buckets", get(api::get_rate_limit_buckets))  // GET /api/v1/sys/rate-limits/buckets
+        .route("/buckets", delete(api::reset_rate_limit_buckets))  // DELETE /api/v1/sys/rate-limits/buckets
+}
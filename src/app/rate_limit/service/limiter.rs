@@ -0,0 +1,187 @@
+/// GCRA 限流器
+/// 每个桶只保存理论到达时间（TAT，毫秒），由 Lua 脚本在 Redis 中原子地判断和更新，
+/// 时间取 Redis 服务器时间，多个实例共享同一份计数；
+/// Redis 不可用时退回到进程内计数，此时各实例分别限流
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::Lazy;
+use tracing::{info, warn};
+
+use crate::common::exception::AppError;
+use crate::database::redis::RedisManager;
+
+/// 判断并更新一个桶，返回 [是否放行, 剩余次数, 恢复满额的毫秒数, 需等待的毫秒数]
+const GCRA_SCRIPT: &str = r#"
+local limit = tonumber(ARGV[1])
+local period = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local interval = period / limit
+local stored = redis.call('GET', KEYS[1])
+local tat = stored and tonumber(stored) or now
+if tat < now then tat = now end
+local allow_at = tat + interval - period
+if allow_at > now then
+  return {0, 0, math.ceil(tat - now), math.ceil(allow_at - now)}
+end
+local new_tat = tat + interval
+redis.call('SET', KEYS[1], tostring(new_tat), 'PX', math.ceil(new_tat - now))
+return {1, math.floor((now - allow_at) / interval), math.ceil(new_tat - now), 0}
+"#;
+
+static SCRIPT: Lazy<redis::Script> = Lazy::new(|| redis::Script::new(GCRA_SCRIPT));
+
+/// Redis 不可用时的进程内桶（键 -> TAT）
+static LOCAL_BUCKETS: Lazy<Mutex<HashMap<String, f64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 进程内桶超过该数量时清理已恢复满额的桶
+const LOCAL_PRUNE_THRESHOLD: usize = 10_000;
+
+/// 是否已退回到进程内计数，只在状态切换时记录日志
+static DEGRADED: AtomicBool = AtomicBool::new(false);
+
+/// 一次请求的限流结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    /// 是否放行
+    pub allowed: bool,
+    /// 本周期剩余次数
+    pub remaining: u32,
+    /// 恢复满额的毫秒数
+    pub reset_ms: u64,
+    /// 被拒绝时需等待的毫秒数
+    pub retry_after_ms: u64,
+}
+
+/// 消耗桶中的一次额度
+pub async fn acquire(key: &str, limit: u32, period_ms: u64) -> Decision {
+    match acquire_redis(key, limit, period_ms).await {
+        Ok(decision) => {
+            if DEGRADED.swap(false, Ordering::Relaxed) {
+                info!("Redis 已恢复，限流改回跨实例计数");
+            }
+            decision
+        }
+        Err(e) => {
+            if !DEGRADED.swap(true, Ordering::Relaxed) {
+                warn!("限流 Redis 不可用，退回到进程内计数: {}", e);
+            }
+            acquire_local(key, limit, period_ms)
+        }
+    }
+}
+
+async fn acquire_redis(key: &str, limit: u32, period_ms: u64) -> Result<Decision, AppError> {
+    let mut conn = RedisManager::get_connection().await?;
+    let result: Vec<i64> = SCRIPT.key(key).arg(limit).arg(period_ms).invoke_async(&mut conn).await?;
+    let value = |i: usize| result.get(i).copied().unwrap_or(0).max(0) as u64;
+    Ok(Decision {
+        allowed: value(0) == 1,
+        remaining: value(1) as u32,
+        reset_ms: value(2),
+        retry_after_ms: value(3),
+    })
+}
+
+fn acquire_local(key: &str, limit: u32, period_ms: u64) -> Decision {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as f64)
+        .unwrap_or_default();
+    let mut buckets = LOCAL_BUCKETS.lock().unwrap_or_else(|e| e.into_inner());
+    if buckets.len() > LOCAL_PRUNE_THRESHOLD {
+        buckets.retain(|_, tat| *tat > now);
+    }
+    let (decision, tat) = gcra(buckets.get(key).copied(), now, limit, period_ms);
+    buckets.insert(key.to_string(), tat);
+    decision
+}
+
+/// 与 Lua 脚本相同的 GCRA 计算，返回结果和新的 TAT（被拒绝时 TAT 不变）
+pub fn gcra(tat: Option<f64>, now: f64, limit: u32, period_ms: u64) -> (Decision, f64) {
+    let period = period_ms as f64;
+    let interval = period / limit.max(1) as f64;
+    let tat = tat.unwrap_or(now).max(now);
+    let allow_at = tat + interval - period;
+    if allow_at > now {
+        let decision = Decision {
+            allowed: false,
+            remaining: 0,
+            reset_ms: (tat - now).ceil() as u64,
+            retry_after_ms: (allow_at - now).ceil() as u64,
+        };
+        return (decision, tat);
+    }
+    let new_tat = tat + interval;
+    let decision = Decision {
+        allowed: true,
+        remaining: ((now - allow_at) / interval).floor() as u32,
+        reset_ms: (new_tat - now).ceil() as u64,
+        retry_after_ms: 0,
+    };
+    (decision, new_tat)
+}
+
+/// 不消耗额度，计算桶当前的剩余次数
+pub fn remaining(tat: f64, now: f64, limit: u32, period_ms: u64) -> u32 {
+    let interval = period_ms as f64 / limit.max(1) as f64;
+    let used = ((tat - now).max(0.0) / interval).ceil() as u32;
+    limit.saturating_sub(used)
+}
+
+/// 清空进程内的桶，键为空时清空全部
+pub fn reset_local(keys: &[String]) {
+    let mut buckets = LOCAL_BUCKETS.lock().unwrap_or_else(|e| e.into_inner());
+    if keys.is_empty() {
+        buckets.clear();
+    } else {
+        for key in keys {
+            buckets.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcra_allows_burst_then_spaces_requests() {
+        // 每 60 秒 3 次：可连续 3 次，之后每 20 秒恢复一次
+        let mut tat = None;
+        for expected in [2, 1, 0] {
+            let (decision, next) = gcra(tat, 0.0, 3, 60_000);
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, expected);
+            tat = Some(next);
+        }
+        assert_eq!(remaining(tat.unwrap(), 0.0, 3, 60_000), 0);
+
+        let (decision, next) = gcra(tat, 5_000.0, 3, 60_000);
+        assert!(!decision.allowed);
+        assert_eq!(decision.retry_after_ms, 15_000);
+        assert_eq!(decision.reset_ms, 55_000);
+        assert_eq!(Some(next), tat);
+
+        let (decision, _) = gcra(tat, 20_000.0, 3, 60_000);
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 0);
+        assert_eq!(remaining(tat.unwrap(), 20_000.0, 3, 60_000), 1);
+        assert_eq!(remaining(tat.unwrap(), 60_000.0, 3, 60_000), 3);
+    }
+
+    #[test]
+    fn test_local_fallback() {
+        let key = "test:local:ip:127.0.0.1".to_string();
+        assert!(acquire_local(&key, 1, 60_000).allowed);
+        let denied = acquire_local(&key, 1, 60_000);
+        assert!(!denied.allowed);
+        assert!(denied.retry_after_ms > 0);
+        reset_local(std::slice::from_ref(&key));
+        assert!(acquire_local(&key, 1, 60_000).allowed);
+    }
+}
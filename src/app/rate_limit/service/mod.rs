@@ -0,0 +1,8 @@
+/// 限流服务模块
+
+pub mod limiter;
+pub mod policy;
+pub mod rate_limit_service;
+
+pub use policy::{KeyKind, RateLimitPolicy, RateLimitRule, RATE_LIMIT_POLICY};
+pub use rate_limit_service::RateLimitService;
@@ -0,0 +1,286 @@
+/// 限流规则
+/// 规则按文件中的顺序匹配，命中第一条后不再继续，未命中任何规则的请求不限流；
+/// 规则文件不存在或格式错误时使用内置规则
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+use utoipa::ToSchema;
+
+use crate::core::SETTINGS;
+
+/// 内置规则，也是规则文件的格式示例
+pub const DEFAULT_RULES: &str = r#"
+# name:    规则名，出现在 Redis 键中，只能包含字母、数字、下划线和连字符
+# path:    路径模式，* 匹配一段路径（也可写成 {id}），** 匹配剩余所有路径
+# methods: 请求方法，省略表示全部
+# roles:   仅对拥有其中任一角色（角色名）的登录用户生效，省略表示所有请求
+# key:     计数维度 ip / user / api_key；user 未登录时按 IP，api_key 未携带或未在 RATE_LIMIT_API_KEYS 登记时按用户或 IP
+# limit:   每个周期允许的请求数，0 表示不限流（可用于给特定角色放行）
+# period:  周期（秒）
+
+[[rules]]
+name = "login"
+path = "/api/v1/auth/login"
+methods = ["POST"]
+key = "ip"
+limit = 10
+period = 60
+
+[[rules]]
+name = "captcha"
+path = "/api/v1/auth/captcha"
+methods = ["GET"]
+key = "ip"
+limit = 30
+period = 60
+
+[[rules]]
+name = "register"
+path = "/api/v1/auth/register/**"
+methods = ["POST"]
+key = "ip"
+limit = 10
+period = 3600
+
+[[rules]]
+name = "password_reset"
+path = "/api/v1/auth/password/**"
+methods = ["POST"]
+key = "ip"
+limit = 10
+period = 3600
+
+[[rules]]
+name = "file_upload"
+path = "/api/v1/sys/files/file-infos/upload"
+methods = ["POST"]
+key = "user"
+limit = 30
+period = 60
+"#;
+
+/// 当前生效的限流规则，启动时从 `rate_limit_rules_file` 加载
+pub static RATE_LIMIT_POLICY: Lazy<RateLimitPolicy> =
+    Lazy::new(|| RateLimitPolicy::load(Path::new(&SETTINGS.rate_limit_rules_file)));
+
+/// 计数维度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyKind {
+    /// 客户端 IP
+    Ip,
+    /// 登录用户
+    User,
+    /// API Key 请求头
+    ApiKey,
+}
+
+impl KeyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyKind::Ip => "ip",
+            KeyKind::User => "user",
+            KeyKind::ApiKey => "api_key",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ip" => Some(KeyKind::Ip),
+            "user" => Some(KeyKind::User),
+            "api_key" => Some(KeyKind::ApiKey),
+            _ => None,
+        }
+    }
+}
+
+/// 限流规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitRule {
+    /// 规则名
+    pub name: String,
+    /// 路径模式
+    pub path: String,
+    /// 请求方法（大写），为空表示全部
+    #[serde(default)]
+    pub methods: Vec<String>,
+    /// 生效的角色名，为空表示所有请求
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// 计数维度
+    #[serde(default = "default_key")]
+    pub key: KeyKind,
+    /// 每个周期允许的请求数，0 表示不限流
+    pub limit: u32,
+    /// 周期（秒）
+    pub period: u64,
+}
+
+fn default_key() -> KeyKind {
+    KeyKind::Ip
+}
+
+impl RateLimitRule {
+    /// 请求方法和路径是否匹配（不含角色条件）
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        (self.methods.is_empty() || self.methods.iter().any(|m| m.eq_ignore_ascii_case(method)))
+            && path_matches(&self.path, path)
+    }
+
+    /// 周期（毫秒）
+    pub fn period_ms(&self) -> u64 {
+        self.period * 1000
+    }
+}
+
+#[derive(Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    rules: Vec<RateLimitRule>,
+}
+
+/// 限流规则集
+#[derive(Debug, Clone)]
+pub struct RateLimitPolicy {
+    rules: Vec<RateLimitRule>,
+}
+
+impl RateLimitPolicy {
+    /// 解析 TOML 格式的规则
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let file: RuleFile = toml::from_str(content).map_err(|e| e.to_string())?;
+        let mut names = HashSet::new();
+        for rule in &file.rules {
+            if rule.name.is_empty()
+                || !rule.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(format!("规则名非法: {:?}", rule.name));
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(format!("规则名重复: {}", rule.name));
+            }
+            if !rule.path.starts_with('/') {
+                return Err(format!("规则 {} 的路径必须以 / 开头", rule.name));
+            }
+            if rule.period == 0 {
+                return Err(format!("规则 {} 的周期必须大于 0", rule.name));
+            }
+        }
+        Ok(Self { rules: file.rules })
+    }
+
+    /// 读取规则文件，文件不存在或格式错误时使用内置规则
+    pub fn load(path: &Path) -> Self {
+        let builtin = || Self::parse(DEFAULT_RULES).expect("内置限流规则格式错误");
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                info!("限流规则文件 {} 不存在，使用内置规则", path.display());
+                return builtin();
+            }
+            Err(e) => {
+                error!("限流规则文件 {} 读取失败，使用内置规则: {}", path.display(), e);
+                return builtin();
+            }
+        };
+        match Self::parse(&content) {
+            Ok(policy) => {
+                info!("已加载 {} 条限流规则: {}", policy.rules.len(), path.display());
+                policy
+            }
+            Err(e) => {
+                error!("限流规则文件 {} 格式错误，使用内置规则: {}", path.display(), e);
+                builtin()
+            }
+        }
+    }
+
+    /// 全部规则
+    pub fn rules(&self) -> &[RateLimitRule] {
+        &self.rules
+    }
+
+    /// 按名称查找规则
+    pub fn get(&self, name: &str) -> Option<&RateLimitRule> {
+        self.rules.iter().find(|rule| rule.name == name)
+    }
+
+    /// 按顺序返回方法和路径匹配的规则，角色条件由调用方判断
+    pub fn candidates<'p: 'a, 'a>(&'p self, method: &'a str, path: &'a str) -> impl Iterator<Item = &'p RateLimitRule> + 'a {
+        self.rules.iter().filter(move |rule| rule.matches(method, path))
+    }
+}
+
+/// 路径模式匹配：`*` 或 `{name}` 匹配一段非空路径，`**` 匹配剩余的零段或多段
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let mut pattern = pattern.trim_end_matches('/').split('/');
+    let mut path = path.trim_end_matches('/').split('/');
+    loop {
+        match (pattern.next(), path.next()) {
+            (Some("**"), _) => return true,
+            (Some(p), Some(s)) if p == "*" || (p.starts_with('{') && p.ends_with('}')) => {
+                if s.is_empty() {
+                    return false;
+                }
+            }
+            (Some(p), Some(s)) if p == s => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_matches() {
+        assert!(path_matches("/api/v1/auth/login", "/api/v1/auth/login"));
+        assert!(path_matches("/api/v1/auth/login", "/api/v1/auth/login/"));
+        assert!(!path_matches("/api/v1/auth/login", "/api/v1/auth/login/x"));
+        assert!(path_matches("/api/v1/sys/users/{id}", "/api/v1/sys/users/5"));
+        assert!(path_matches("/api/v1/sys/*/5", "/api/v1/sys/users/5"));
+        assert!(!path_matches("/api/v1/sys/users/*", "/api/v1/sys/users"));
+        assert!(path_matches("/api/v1/auth/password/**", "/api/v1/auth/password/reset"));
+        assert!(path_matches("/api/**", "/api"));
+        assert!(!path_matches("/api/**", "/apis/x"));
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let policy = RateLimitPolicy::parse(DEFAULT_RULES).unwrap();
+        let names: Vec<&str> = policy.candidates("POST", "/api/v1/auth/login").map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["login"]);
+        assert_eq!(policy.candidates("GET", "/api/v1/auth/login").count(), 0);
+        assert_eq!(policy.get("file_upload").unwrap().key, KeyKind::User);
+        assert_eq!(policy.get("password_reset").unwrap().period_ms(), 3_600_000);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_rules() {
+        let rule = |name: &str, path: &str, period: u64| {
+            format!("[[rules]]\nname = \"{}\"\npath = \"{}\"\nlimit = 1\nperiod = {}\n", name, path, period)
+        };
+        assert!(RateLimitPolicy::parse(&rule("a:b", "/x", 1)).is_err());
+        assert!(RateLimitPolicy::parse(&rule("a", "x", 1)).is_err());
+        assert!(RateLimitPolicy::parse(&rule("a", "/x", 0)).is_err());
+        assert!(RateLimitPolicy::parse(&format!("{}{}", rule("a", "/x", 1), rule("a", "/y", 1))).is_err());
+
+        let policy = RateLimitPolicy::parse(&format!(
+            "{}roles = [\"admin\"]\nkey = \"api_key\"\n{}",
+            rule("admin", "/api/**", 60),
+            rule("default", "/api/**", 60)
+        ))
+        .unwrap();
+        let matched: Vec<&RateLimitRule> = policy.candidates("DELETE", "/api/v1/x").collect();
+        assert_eq!(matched.len(), 2);
+        assert_eq!(matched[0].roles, ["admin"]);
+        assert_eq!(matched[0].key, KeyKind::ApiKey);
+        assert_eq!(matched[1].key, KeyKind::Ip);
+    }
+}
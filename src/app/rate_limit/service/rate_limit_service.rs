@@ -0,0 +1,183 @@
+/// 限流管理服务
+/// 查看当前规则、按规则和计数对象浏览 Redis 中的限流桶，以及重置指定的桶
+
+use redis::aio::ConnectionManager;
+
+use crate::app::rate_limit::dto::{RateLimitBucketDetail, RateLimitBucketQuery, RateLimitRuleDetail};
+use crate::app::rate_limit::service::limiter;
+use crate::app::rate_limit::service::policy::{KeyKind, RATE_LIMIT_POLICY};
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::pagination::PageData;
+use crate::core::SETTINGS;
+use crate::database::redis::RedisManager;
+
+/// 浏览限流桶时最多扫描的键数
+const MAX_SCAN_KEYS: usize = 10_000;
+
+/// 每批删除的键数
+const DELETE_BATCH: usize = 500;
+
+/// 限流桶的 Redis 键：`{prefix}:{rule}:{kind}:{subject}`
+pub fn bucket_key(rule: &str, kind: KeyKind, subject: &str) -> String {
+    format!("{}:{}:{}:{}", SETTINGS.rate_limit_redis_prefix, rule, kind.as_str(), subject)
+}
+
+/// 解析限流桶的 Redis 键，返回规则名、计数维度和计数对象
+pub fn parse_bucket_key(key: &str) -> Option<(&str, KeyKind, &str)> {
+    let rest = key.strip_prefix(SETTINGS.rate_limit_redis_prefix.as_str())?.strip_prefix(':')?;
+    let mut parts = rest.splitn(3, ':');
+    let rule = parts.next().filter(|rule| !rule.is_empty())?;
+    let kind = KeyKind::parse(parts.next()?)?;
+    let subject = parts.next().filter(|subject| !subject.is_empty())?;
+    Some((rule, kind, subject))
+}
+
+fn is_rule_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+pub struct RateLimitService;
+
+impl RateLimitService {
+    /// 当前生效的规则，按匹配顺序排列
+    pub fn rules() -> Vec<RateLimitRuleDetail> {
+        RATE_LIMIT_POLICY
+            .rules()
+            .iter()
+            .map(|rule| RateLimitRuleDetail {
+                name: rule.name.clone(),
+                path: rule.path.clone(),
+                methods: rule.methods.clone(),
+                roles: rule.roles.clone(),
+                key: rule.key,
+                limit: rule.limit,
+                period: rule.period,
+            })
+            .collect()
+    }
+
+    /// 分页浏览限流桶，最多扫描 MAX_SCAN_KEYS 个键
+    pub async fn get_buckets(query: &RateLimitBucketQuery) -> Result<PageData<RateLimitBucketDetail>, AppError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let size = query.size.unwrap_or(20).clamp(1, 100);
+
+        let rule = match query.rule.as_deref().filter(|rule| !rule.is_empty()) {
+            Some(rule) if !is_rule_name(rule) => return Ok(PageData::new(Vec::new(), 0, page as i64, size as i64)),
+            Some(rule) => rule,
+            None => "*",
+        };
+        let mut conn = RedisManager::get_connection().await?;
+        let keys: Vec<String> = scan(&mut conn, &format!("{}:{}:*", SETTINGS.rate_limit_redis_prefix, rule), MAX_SCAN_KEYS)
+            .await?
+            .into_iter()
+            .filter(|key| match (parse_bucket_key(key), query.subject.as_deref()) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some((_, _, subject)), Some(filter)) => subject.contains(filter),
+            })
+            .collect();
+        let total = keys.len();
+
+        let page_keys: Vec<String> = keys.into_iter().skip(((page - 1) * size) as usize).take(size as usize).collect();
+        if page_keys.is_empty() {
+            return Ok(PageData::new(Vec::new(), total as i64, page as i64, size as i64));
+        }
+        let (seconds, micros): (u64, u64) = redis::cmd("TIME").query_async(&mut conn).await?;
+        let now = (seconds * 1000 + micros / 1000) as f64;
+        let values: Vec<Option<String>> = redis::cmd("MGET").arg(&page_keys).query_async(&mut conn).await?;
+
+        let items = page_keys
+            .iter()
+            .zip(values)
+            .filter_map(|(key, value)| {
+                let tat: f64 = value?.parse().ok()?;
+                let (rule_name, kind, subject) = parse_bucket_key(key)?;
+                let rule = RATE_LIMIT_POLICY.get(rule_name).filter(|rule| rule.limit > 0);
+                Some(RateLimitBucketDetail {
+                    bucket_key: key.clone(),
+                    rule: rule_name.to_string(),
+                    kind,
+                    subject: subject.to_string(),
+                    limit: rule.map(|rule| rule.limit),
+                    remaining: rule.map(|rule| limiter::remaining(tat, now, rule.limit, rule.period_ms())),
+                    reset_seconds: ((tat - now).max(0.0) / 1000.0).ceil() as u64,
+                })
+            })
+            .collect();
+
+        Ok(PageData::new(items, total as i64, page as i64, size as i64))
+    }
+
+    /// 重置指定的限流桶，返回删除的数量
+    pub async fn reset_buckets(bucket_keys: &[String]) -> Result<u64, AppError> {
+        if let Some(key) = bucket_keys.iter().find(|key| parse_bucket_key(key).is_none()) {
+            return Err(AppError::with_message(ErrorCode::BadRequest, format!("不是限流桶: {}", key)));
+        }
+        limiter::reset_local(bucket_keys);
+        if bucket_keys.is_empty() {
+            return Ok(0);
+        }
+        let mut conn = RedisManager::get_connection().await?;
+        delete(&mut conn, bucket_keys).await
+    }
+
+    /// 重置某条规则的全部限流桶，返回删除的数量
+    pub async fn reset_rule(rule: &str) -> Result<u64, AppError> {
+        // 规则已从配置中删除时仍允许清理其遗留的桶
+        if !is_rule_name(rule) {
+            return Err(AppError::with_message(ErrorCode::BadRequest, format!("规则名非法: {}", rule)));
+        }
+        let mut conn = RedisManager::get_connection().await?;
+        let keys = scan(&mut conn, &format!("{}:{}:*", SETTINGS.rate_limit_redis_prefix, rule), usize::MAX).await?;
+        limiter::reset_local(&keys);
+        delete(&mut conn, &keys).await
+    }
+}
+
+async fn scan(conn: &mut ConnectionManager, pattern: &str, max: usize) -> Result<Vec<String>, AppError> {
+    let mut keys = Vec::new();
+    let mut cursor: u64 = 0;
+    loop {
+        let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(pattern)
+            .arg("COUNT")
+            .arg(1000)
+            .query_async(conn)
+            .await?;
+        keys.extend(batch);
+        if next == 0 || keys.len() >= max {
+            break;
+        }
+        cursor = next;
+    }
+    keys.sort();
+    keys.dedup();
+    keys.truncate(max);
+    Ok(keys)
+}
+
+async fn delete(conn: &mut ConnectionManager, keys: &[String]) -> Result<u64, AppError> {
+    let mut deleted = 0;
+    for chunk in keys.chunks(DELETE_BATCH) {
+        let count: u64 = redis::cmd("DEL").arg(chunk).query_async(conn).await?;
+        deleted += count;
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_key_round_trip() {
+        let key = bucket_key("login", KeyKind::Ip, "::ffff:10.0.0.1");
+        assert_eq!(parse_bucket_key(&key), Some(("login", KeyKind::Ip, "::ffff:10.0.0.1")));
+        let prefix = &SETTINGS.rate_limit_redis_prefix;
+        assert_eq!(parse_bucket_key(&format!("{}:login:ip:", prefix)), None);
+        assert_eq!(parse_bucket_key(&format!("{}:login:device:1", prefix)), None);
+        assert_eq!(parse_bucket_key("other:login:ip:1"), None);
+    }
+}
@@ -1,9 +1,10 @@
 /// 创建角色请求 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateRoleRequest {
     /// 角色名称
     #[validate(length(min = 1, max = 50))]
@@ -27,7 +28,7 @@ pub struct CreateRoleRequest {
 }
 
 /// 角色创建响应 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateRoleResponse {
     /// 角色ID
     pub id: i64,
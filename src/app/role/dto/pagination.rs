@@ -1,11 +1,12 @@
 /// 角色分页查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 use super::role_response::RoleListItem;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct RolePaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -35,7 +36,7 @@ pub struct RolePaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum RoleSortField {
     /// 按ID排序
@@ -65,7 +66,7 @@ impl std::fmt::Display for RoleSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -86,7 +87,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 角色分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct RolePaginationResponse {
     /// 角色列表
     pub list: Vec<RoleListItem>,
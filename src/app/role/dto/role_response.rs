@@ -1,9 +1,10 @@
 /// 角色响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 角色详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct RoleDetailResponse {
     /// 角色ID
     pub id: i64,
@@ -22,7 +23,7 @@ pub struct RoleDetailResponse {
 }
 
 /// 角色列表项响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RoleListItem {
     /// 角色ID
     pub id: i64,
@@ -39,7 +40,7 @@ pub struct RoleListItem {
 }
 
 /// 角色权限树
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct RolePermissionTree {
     /// 权限ID
     pub id: i64,
@@ -56,5 +57,6 @@ pub struct RolePermissionTree {
     /// 是否已分配
     pub is_assigned: bool,
     /// 子权限
+    #[schema(no_recursion)]
     pub children: Vec<RolePermissionTree>,
 }
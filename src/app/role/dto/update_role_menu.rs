@@ -1,15 +1,16 @@
 /// 更新角色菜单 DTO
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 更新角色菜单请求
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct UpdateRoleMenuRequest {
     /// 菜单 ID 列表
     pub menus: Vec<i64>,
 }
 
 /// 更新角色数据权限请求
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct UpdateRoleScopeRequest {
     /// 数据范围 ID 列表
     pub scopes: Vec<i64>,
@@ -1,9 +1,10 @@
 /// 分配角色权限 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct AssignRolePermissionsRequest {
     /// 角色ID
     pub role_id: i64,
@@ -13,7 +14,7 @@ pub struct AssignRolePermissionsRequest {
 }
 
 /// 分配角色权限响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AssignRolePermissionsResponse {
     /// 角色ID
     pub role_id: i64,
@@ -1,9 +1,10 @@
 /// 获取角色权限 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 获取角色权限响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GetRolePermissionsResponse {
     /// 角色ID
     pub role_id: i64,
@@ -12,7 +13,7 @@ pub struct GetRolePermissionsResponse {
 }
 
 /// 角色权限信息
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct RolePermissionInfo {
     /// 权限ID
     pub id: i64,
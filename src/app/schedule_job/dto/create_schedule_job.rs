@@ -1,9 +1,10 @@
 /// 创建任务调度 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateScheduleJobRequest {
     /// 任务名称
     #[validate(length(min = 1, max = 64))]
@@ -54,7 +55,7 @@ pub struct CreateScheduleJobRequest {
 }
 
 /// 任务调度创建响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateScheduleJobResponse {
     /// 任务ID
     pub id: i64,
@@ -69,7 +70,7 @@ pub struct CreateScheduleJobResponse {
 }
 
 /// 任务调度更新请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateScheduleJobRequest {
     /// 任务ID
     pub id: i64,
@@ -123,7 +124,7 @@ pub struct UpdateScheduleJobRequest {
 }
 
 /// 任务调度更新响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UpdateScheduleJobResponse {
     /// 任务ID
     pub id: i64,
@@ -136,14 +137,14 @@ pub struct UpdateScheduleJobResponse {
 }
 
 /// 任务调度立即执行请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ExecuteScheduleJobRequest {
     /// 任务ID
     pub id: i64,
 }
 
 /// 任务调度立即执行响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ExecuteScheduleJobResponse {
     /// 执行ID
     pub execute_id: String,
@@ -1,9 +1,10 @@
 /// 任务调度查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct ScheduleJobPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -42,7 +43,7 @@ pub struct ScheduleJobPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum ScheduleJobSortField {
     /// 按ID排序
     Id,
@@ -81,7 +82,7 @@ impl std::fmt::Display for ScheduleJobSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum SortOrder {
     /// 升序
     Asc,
@@ -105,7 +106,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 任务调度分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ScheduleJobPaginationResponse {
     /// 任务调度列表
     pub list: Vec<ScheduleJobListItem>,
@@ -124,7 +125,7 @@ pub struct ScheduleJobPaginationResponse {
 }
 
 /// 任务调度列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ScheduleJobListItem {
     /// 任务ID
     pub id: i64,
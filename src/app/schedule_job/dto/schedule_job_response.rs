@@ -1,9 +1,10 @@
 /// 任务调度响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 任务调度详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ScheduleJobDetailResponse {
     /// 任务ID
     pub id: i64,
@@ -50,7 +51,7 @@ pub struct ScheduleJobDetailResponse {
 }
 
 /// 任务调度统计
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ScheduleJobStatistics {
     /// 总任务数
     pub total_count: usize,
@@ -71,7 +72,7 @@ pub struct ScheduleJobStatistics {
 }
 
 /// 任务执行日志查询 DTO
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct ScheduleJobLogPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -104,7 +105,7 @@ pub struct ScheduleJobLogPaginationQuery {
 }
 
 /// 任务执行日志排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum ScheduleJobLogSortField {
     /// 按ID排序
     Id,
@@ -125,7 +126,7 @@ impl Default for ScheduleJobLogSortField {
 }
 
 /// 任务执行日志分页响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ScheduleJobLogPaginationResponse {
     /// 任务执行日志列表
     pub list: Vec<ScheduleJobLogListItem>,
@@ -144,7 +145,7 @@ pub struct ScheduleJobLogPaginationResponse {
 }
 
 /// 任务执行日志列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ScheduleJobLogListItem {
     /// 日志ID
     pub id: i64,
@@ -177,7 +178,7 @@ pub struct ScheduleJobLogListItem {
 }
 
 /// 任务执行日志详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ScheduleJobLogDetailResponse {
     /// 日志ID
     pub id: i64,
@@ -1,10 +1,11 @@
 /// 系统配置创建和更新 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建系统配置请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateSysConfigRequest {
     /// 配置名称
     #[validate(length(min = 1, max = 100, message = "配置名称长度必须在1-100个字符之间"))]
@@ -26,7 +27,7 @@ pub struct CreateSysConfigRequest {
 }
 
 /// 更新系统配置请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateSysConfigRequest {
     /// 配置ID
     #[validate(range(min = 1, message = "配置ID必须大于0"))]
@@ -52,14 +53,14 @@ pub struct UpdateSysConfigRequest {
 }
 
 /// 删除系统配置请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DeleteSysConfigRequest {
     /// 配置ID列表
     pub ids: Vec<i64>,
 }
 
 /// 创建系统配置响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateSysConfigResponse {
     /// 配置ID
     pub id: i64,
@@ -72,7 +73,7 @@ pub struct CreateSysConfigResponse {
 }
 
 /// 更新系统配置响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateSysConfigResponse {
     /// 配置ID
     pub id: i64,
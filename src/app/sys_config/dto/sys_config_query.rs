@@ -1,10 +1,11 @@
 /// 系统配置查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct SysConfigQuery {
     /// 页码
     #[validate(range(min = 1, message = "页码必须大于0"))]
@@ -28,14 +29,14 @@ pub struct SysConfigQuery {
 }
 
 /// 配置键名查询
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigKeyQuery {
     /// 配置键名列表
     pub config_keys: Vec<String>,
 }
 
 /// 配置键值响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigKeyValue {
     /// 配置键名
     pub config_key: String,
@@ -48,7 +49,7 @@ pub struct SysConfigKeyValue {
 }
 
 /// 分页响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigListResponse {
     /// 配置列表
     pub list: Vec<SysConfigListItem>,
@@ -61,7 +62,7 @@ pub struct SysConfigListResponse {
 }
 
 /// 配置列表项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigListItem {
     /// 配置ID
     pub id: i64,
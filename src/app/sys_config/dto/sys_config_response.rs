@@ -1,9 +1,10 @@
 /// 系统配置响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 配置详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigDetailResponse {
     /// 配置ID
     pub id: i64,
@@ -34,7 +35,7 @@ pub struct SysConfigDetailResponse {
 }
 
 /// 配置类型统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigTypeStatistics {
     /// 配置类型
     pub config_type: i32,
@@ -45,7 +46,7 @@ pub struct SysConfigTypeStatistics {
 }
 
 /// 配置分组统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SysConfigGroupStatistics {
     /// 系统内置配置数量
     pub system_count: usize,
@@ -1 +1,5 @@
-pub mod system_metric; pub use system_metric::*;
+// SystemMetric API 模块
+
+pub mod system_metric;
+
+pub use system_metric::*;
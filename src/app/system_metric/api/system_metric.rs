@@ -1,2 +1,100 @@
-use axum::{http::StatusCode, response::IntoResponse, Json}; use crate::common::response::api_response; pub async fn get_system_metrics() -> Result<impl IntoResponse, AppError> { Ok((StatusCode::OK, Json(api_response("指标列表")))) }
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use crate::common::response::api_response;
 use crate::common::exception::AppError;
+use crate::database::DatabaseManager;
+use crate::app::system_metric::dto::{
+    SystemMetricQuery, CreateSystemMetricRequest, BatchCreateSystemMetricRequest,
+    RealTimeMetricQuery, MetricHistoryQuery, MetricStatisticsQuery,
+};
+use crate::app::system_metric::service::SystemMetricService;
+
+/// 获取系统指标列表
+pub async fn get_system_metrics_paginated(
+    Query(query): Query<SystemMetricQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_system_metric_list(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取系统指标详情
+pub async fn get_system_metric_detail(
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_system_metric_detail(id).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 上报单个系统指标
+pub async fn create_system_metric(
+    Json(request): Json<CreateSystemMetricRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    service.create_system_metric(&request).await?;
+    Ok((StatusCode::CREATED, Json(api_response("创建成功".to_string()))))
+}
+
+/// 批量上报系统指标
+pub async fn batch_create_system_metrics(
+    Json(request): Json<BatchCreateSystemMetricRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.batch_create_system_metrics(&request).await?;
+    Ok((StatusCode::CREATED, Json(api_response(result))))
+}
+
+/// 获取各主机最新指标
+pub async fn get_real_time_metrics(
+    Query(query): Query<RealTimeMetricQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_real_time_metrics(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取单个指标历史数据
+pub async fn get_metric_history(
+    Query(query): Query<MetricHistoryQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_metric_history(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取指标统计
+pub async fn get_metric_statistics(
+    Query(query): Query<MetricStatisticsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_metric_statistics(&query).await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取指标类型统计
+pub async fn get_metric_type_statistics() -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_metric_type_statistics().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 获取主机概览
+pub async fn get_host_overview() -> Result<impl IntoResponse, AppError> {
+    let db_conn = DatabaseManager::get_connection().await;
+    let service = SystemMetricService::new(db_conn.clone());
+    let result = service.get_host_overview().await?;
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
@@ -0,0 +1,271 @@
+/// 系统指标采集任务
+/// 定时采样本机 CPU、内存、磁盘、网络并批量写入，同时负责降采样汇总和过期数据清理
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use sysinfo::{Disks, Networks, System};
+use tracing::{error, info, warn};
+
+use crate::app::monitor::service::MonitorService;
+use crate::app::system_metric::dto::{BatchCreateSystemMetricRequest, CreateSystemMetricRequest};
+use crate::app::system_metric::service::SystemMetricService;
+use crate::core::conf::SETTINGS;
+use crate::database::entity::system_metric::MetricType;
+use crate::database::entity::system_metric_rollup::{PERIOD_DAY, PERIOD_HOUR};
+use crate::database::DatabaseManager;
+
+/// 汇总与清理的执行间隔
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// 单次最多补齐的汇总周期数，避免长时间停机后首次汇总占用过久
+const MAX_ROLLUP_BUCKETS: usize = 48;
+/// 指标名称最大长度（与 sys_system_metric.metric_name 一致）
+const MAX_METRIC_NAME_LEN: usize = 100;
+
+/// 系统指标采集器
+pub struct SystemMetricCollector {
+    host_name: String,
+    ip_address: String,
+    sys: System,
+    disks: Disks,
+    networks: Networks,
+    /// 上次采样时各网卡的累计收发字节数，用于计算速率
+    last_network: HashMap<String, (u64, u64)>,
+    last_sample: Instant,
+}
+
+impl SystemMetricCollector {
+    /// 创建采集器并做一次基线采样（CPU 使用率和网络速率需要两次采样之差）
+    pub fn new(host_name: String, ip_address: String) -> Self {
+        let mut sys = System::new();
+        sys.refresh_cpu_usage();
+        sys.refresh_memory();
+        let networks = Networks::new_with_refreshed_list();
+        let last_network = networks
+            .iter()
+            .map(|(name, data)| (name.clone(), (data.total_received(), data.total_transmitted())))
+            .collect();
+
+        Self {
+            host_name,
+            ip_address,
+            sys,
+            disks: Disks::new_with_refreshed_list(),
+            networks,
+            last_network,
+            last_sample: Instant::now(),
+        }
+    }
+
+    /// 采样一次，返回待写入的指标
+    pub fn sample(&mut self) -> Vec<CreateSystemMetricRequest> {
+        let mut metrics = Vec::new();
+
+        self.sys.refresh_cpu_usage();
+        let cpu_usage = self.sys.global_cpu_info().cpu_usage() as f64;
+        metrics.push(self.metric(MetricType::Cpu, "cpu_usage".to_string(), cpu_usage, "%"));
+
+        self.sys.refresh_memory();
+        let total_memory = self.sys.total_memory();
+        if total_memory > 0 {
+            let usage = self.sys.used_memory() as f64 / total_memory as f64 * 100.0;
+            metrics.push(self.metric(MetricType::Memory, "memory_usage".to_string(), usage, "%"));
+        }
+
+        self.disks.refresh_list();
+        for disk in self.disks.iter() {
+            let total = disk.total_space();
+            if total == 0 {
+                continue;
+            }
+            // 使用 saturating_sub 避免下溢（某些虚拟文件系统可能 available > total）
+            let used = total.saturating_sub(disk.available_space());
+            let name = format!("disk_usage:{}", disk.mount_point().to_string_lossy());
+            metrics.push(self.metric(MetricType::Disk, name, used as f64 / total as f64 * 100.0, "%"));
+        }
+
+        self.networks.refresh_list();
+        let elapsed = self.last_sample.elapsed().as_secs_f64();
+        self.last_sample = Instant::now();
+        let mut current = HashMap::new();
+        for (interface, data) in self.networks.iter() {
+            let totals = (data.total_received(), data.total_transmitted());
+            current.insert(interface.clone(), totals);
+            if interface == "lo" || elapsed <= 0.0 {
+                continue;
+            }
+            // 新出现的网卡没有基线，下一轮再计算
+            let Some(&(last_rx, last_tx)) = self.last_network.get(interface) else {
+                continue;
+            };
+            let rx_rate = rate(last_rx, totals.0, elapsed);
+            let tx_rate = rate(last_tx, totals.1, elapsed);
+            metrics.push(self.metric(MetricType::Network, format!("network_rx:{}", interface), rx_rate, "B/s"));
+            metrics.push(self.metric(MetricType::Network, format!("network_tx:{}", interface), tx_rate, "B/s"));
+        }
+        self.last_network = current;
+
+        metrics
+    }
+
+    fn metric(&self, metric_type: MetricType, mut metric_name: String, value: f64, unit: &str) -> CreateSystemMetricRequest {
+        truncate_name(&mut metric_name);
+
+        CreateSystemMetricRequest {
+            metric_type: metric_type.into(),
+            metric_name,
+            metric_value: round2(value),
+            unit: unit.to_string(),
+            host_name: self.host_name.clone(),
+            ip_address: self.ip_address.clone(),
+            remark: None,
+        }
+    }
+
+    /// 启动采集任务和汇总清理任务（SYSTEM_METRIC_ENABLED=false 时不启动）
+    pub fn spawn() {
+        if !SETTINGS.system_metric_enabled {
+            info!("系统指标采集已禁用");
+            return;
+        }
+
+        tokio::spawn(async {
+            // 主机名和 IP 只解析一次；获取 IP 会建立 TCP 连接，放到阻塞线程中执行
+            let host_name = MonitorService::get_hostname();
+            let ip_address = tokio::task::spawn_blocking(MonitorService::get_local_ip)
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| "127.0.0.1".to_string());
+            let mut collector = Self::new(host_name, ip_address);
+
+            let period = Duration::from_secs(SETTINGS.system_metric_interval_seconds.max(1));
+            let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                crate::core::health::beat("system_metric_collector", period);
+                let metrics = collector.sample();
+                if metrics.is_empty() {
+                    continue;
+                }
+
+                let service = SystemMetricService::new(DatabaseManager::get_connection().await.clone());
+                // 单次批量写入上限与接口校验一致
+                for chunk in metrics.chunks(100) {
+                    let request = BatchCreateSystemMetricRequest { metrics: chunk.to_vec() };
+                    match service.batch_create_system_metrics(&request).await {
+                        Ok(response) if !response.failed_metrics.is_empty() => {
+                            warn!("系统指标写入失败: {} 条", response.failed_metrics.len());
+                        }
+                        Ok(_) => {}
+                        Err(e) => error!("系统指标写入失败: {}", e.message),
+                    }
+                }
+            }
+        });
+
+        tokio::spawn(async {
+            let mut interval = tokio::time::interval(MAINTENANCE_INTERVAL);
+            loop {
+                interval.tick().await;
+                let service = SystemMetricService::new(DatabaseManager::get_connection().await.clone());
+                Self::maintain(&service).await;
+            }
+        });
+    }
+
+    /// 先汇总再清理，保证原始数据在删除前已计入小时汇总
+    async fn maintain(service: &SystemMetricService) {
+        if let Err(e) = service.rollup_hourly(MAX_ROLLUP_BUCKETS).await {
+            error!("系统指标小时汇总失败: {}", e.message);
+            return;
+        }
+        if let Err(e) = service.rollup_daily(MAX_ROLLUP_BUCKETS).await {
+            error!("系统指标天汇总失败: {}", e.message);
+            return;
+        }
+
+        let cleanups = [
+            service.cleanup_old_metrics(SETTINGS.system_metric_raw_retention_days).await,
+            service.cleanup_old_rollups(PERIOD_HOUR, SETTINGS.system_metric_hourly_retention_days).await,
+            service.cleanup_old_rollups(PERIOD_DAY, SETTINGS.system_metric_daily_retention_days).await,
+        ];
+        for result in cleanups {
+            if let Err(e) = result {
+                error!("系统指标过期数据清理失败: {}", e.message);
+            }
+        }
+    }
+}
+
+/// 两次采样间累计计数的每秒速率，计数器回绕或重置时记为 0
+fn rate(last: u64, current: u64, elapsed: f64) -> f64 {
+    current.saturating_sub(last) as f64 / elapsed
+}
+
+/// 保留两位小数
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// 按字符边界截断超长的指标名称
+fn truncate_name(metric_name: &mut String) {
+    if metric_name.len() > MAX_METRIC_NAME_LEN {
+        let mut end = MAX_METRIC_NAME_LEN;
+        while !metric_name.is_char_boundary(end) {
+            end -= 1;
+        }
+        metric_name.truncate(end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate() {
+        assert_eq!(rate(1_000, 3_000, 2.0), 1_000.0);
+        // 网卡计数器重置后不产生负速率
+        assert_eq!(rate(3_000, 1_000, 2.0), 0.0);
+        assert_eq!(rate(0, 0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_round2() {
+        assert_eq!(round2(12.345_6), 12.35);
+        assert_eq!(round2(0.004), 0.0);
+        assert_eq!(round2(100.0), 100.0);
+    }
+
+    #[test]
+    fn test_truncate_name() {
+        let mut short = "disk_usage:/".to_string();
+        truncate_name(&mut short);
+        assert_eq!(short, "disk_usage:/");
+
+        // 挂载点可能包含多字节字符，截断时不能落在字符中间
+        let mut long = format!("disk_usage:/{}", "数据".repeat(40));
+        truncate_name(&mut long);
+        assert!(long.len() <= MAX_METRIC_NAME_LEN);
+        assert!(long.len() > MAX_METRIC_NAME_LEN - 3);
+        assert!(long.starts_with("disk_usage:/数据"));
+    }
+
+    #[test]
+    fn test_sample_metrics() {
+        let mut collector = SystemMetricCollector::new("node-1".to_string(), "10.0.0.1".to_string());
+        let metrics = collector.sample();
+
+        let cpu = metrics.iter().find(|m| m.metric_name == "cpu_usage").unwrap();
+        assert_eq!(cpu.metric_type, i32::from(MetricType::Cpu));
+        assert!((0.0..=100.0).contains(&cpu.metric_value));
+        for metric in &metrics {
+            assert_eq!((metric.host_name.as_str(), metric.ip_address.as_str()), ("node-1", "10.0.0.1"));
+            assert!(metric.metric_name.len() <= MAX_METRIC_NAME_LEN);
+            if metric.unit == "%" {
+                assert!((0.0..=100.0).contains(&metric.metric_value), "{}", metric.metric_name);
+            }
+        }
+    }
+}
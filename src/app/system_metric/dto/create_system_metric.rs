@@ -1,10 +1,11 @@
 /// 系统监控指标创建和更新 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 创建系统指标请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateSystemMetricRequest {
     /// 指标类型（1:CPU 2:内存 3:磁盘 4:网络）
     #[validate(range(min = 1, max = 4, message = "指标类型必须是1-4之间的值"))]
@@ -34,7 +35,7 @@ pub struct CreateSystemMetricRequest {
 }
 
 /// 批量创建系统指标请求
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct BatchCreateSystemMetricRequest {
     /// 指标列表
     #[validate(length(min = 1, max = 100, message = "指标列表数量必须在1-100之间"))]
@@ -42,7 +43,7 @@ pub struct BatchCreateSystemMetricRequest {
 }
 
 /// 批量创建系统指标响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BatchCreateSystemMetricResponse {
     /// 成功创建的数量
     pub success_count: usize,
@@ -1,10 +1,11 @@
 /// 系统监控指标查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 分页查询参数
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct SystemMetricQuery {
     /// 页码
     #[validate(range(min = 1, message = "页码必须大于0"))]
@@ -34,7 +35,7 @@ pub struct SystemMetricQuery {
 }
 
 /// 实时指标查询
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RealTimeMetricQuery {
     /// 主机名
     pub host_name: Option<String>,
@@ -43,7 +44,7 @@ pub struct RealTimeMetricQuery {
 }
 
 /// 单个指标历史查询
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct MetricHistoryQuery {
     /// 主机名
     #[validate(length(min = 1, message = "主机名不能为空"))]
@@ -64,7 +65,7 @@ pub struct MetricHistoryQuery {
 }
 
 /// 指标统计查询
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct MetricStatisticsQuery {
     /// 主机名
     pub host_name: Option<String>,
@@ -79,7 +80,7 @@ pub struct MetricStatisticsQuery {
 }
 
 /// 分页响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SystemMetricListResponse {
     /// 指标列表
     pub list: Vec<SystemMetricListItem>,
@@ -92,7 +93,7 @@ pub struct SystemMetricListResponse {
 }
 
 /// 指标列表项
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SystemMetricListItem {
     /// 指标ID
     pub metric_id: i64,
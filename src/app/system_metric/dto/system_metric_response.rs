@@ -1,9 +1,12 @@
 /// 系统监控指标响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::SystemMetricListItem;
 
 /// 指标详情响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SystemMetricDetailResponse {
     /// 指标ID
     pub metric_id: i64,
@@ -30,7 +33,7 @@ pub struct SystemMetricDetailResponse {
 }
 
 /// 实时指标响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RealTimeMetricResponse {
     /// 主机名
     pub host_name: String,
@@ -41,7 +44,7 @@ pub struct RealTimeMetricResponse {
 }
 
 /// 指标历史数据点
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MetricHistoryPoint {
     /// 时间
     pub time: chrono::DateTime<chrono::Utc>,
@@ -50,7 +53,7 @@ pub struct MetricHistoryPoint {
 }
 
 /// 指标历史响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MetricHistoryResponse {
     /// 主机名
     pub host_name: String,
@@ -65,7 +68,7 @@ pub struct MetricHistoryResponse {
 }
 
 /// 指标统计数据
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MetricStatisticsData {
     /// 最小值
     pub min_value: f64,
@@ -80,7 +83,7 @@ pub struct MetricStatisticsData {
 }
 
 /// 指标统计响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MetricStatisticsResponse {
     /// 主机名
     pub host_name: String,
@@ -95,7 +98,7 @@ pub struct MetricStatisticsResponse {
 }
 
 /// 指标类型统计
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MetricTypeStatistics {
     /// 指标类型
     pub metric_type: i32,
@@ -108,7 +111,7 @@ pub struct MetricTypeStatistics {
 }
 
 /// 主机概览
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HostOverview {
     /// 主机名
     pub host_name: String,
@@ -1 +1,14 @@
-pub mod api; pub mod router; pub use api::*; pub use router::*;
+/// 系统监控指标模块
+/// 提供系统指标的采集、查询、统计、降采样汇总和过期清理
+
+pub mod api;
+pub mod collector;
+pub mod dto;
+pub mod router;
+pub mod service;
+
+pub use api::*;
+pub use collector::*;
+pub use dto::*;
+pub use router::*;
+pub use service::*;
@@ -1 +1,3 @@
-pub mod system_metric_router; pub use system_metric_router::*;
+pub mod system_metric_router;
+
+pub use system_metric_router::*;
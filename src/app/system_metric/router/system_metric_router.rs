@@ -1 +1,20 @@
-use axum::Router; use crate::common::response::api_response; pub fn system_metric_routes() -> Router { Router::new().route("/system-metrics", axum::routing::get(|| async { api_response("指标列表") })) }
+use axum::{routing::{get, post}, Router};
+use crate::app::system_metric::api::{
+    get_system_metrics_paginated, get_system_metric_detail,
+    create_system_metric, batch_create_system_metrics,
+    get_real_time_metrics, get_metric_history,
+    get_metric_statistics, get_metric_type_statistics, get_host_overview,
+};
+
+pub fn system_metric_routes() -> Router {
+    Router::new()
+        .route("/", get(get_system_metrics_paginated))
+        .route("/{id}", get(get_system_metric_detail))
+        .route("/", post(create_system_metric))
+        .route("/batch", post(batch_create_system_metrics))
+        .route("/real-time", get(get_real_time_metrics))
+        .route("/history", get(get_metric_history))
+        .route("/statistics", get(get_metric_statistics))
+        .route("/type-statistics", get(get_metric_type_statistics))
+        .route("/hosts", get(get_host_overview))
+}
@@ -1,7 +1,7 @@
-use tracing::{info, warn, error, debug};
-
 /// 系统监控指标服务实现
-/// 提供系统指标的收集、查询、统计等功能
+/// 提供系统指标的收集、查询、统计、汇总和清理等功能
+
+use tracing::{info, error};
 
 use crate::app::system_metric::dto::{
     CreateSystemMetricRequest, BatchCreateSystemMetricRequest,
@@ -12,9 +12,13 @@ use crate::app::system_metric::dto::{
     MetricStatisticsData, MetricTypeStatistics, HostOverview,
 };
 use crate::common::exception::{AppError, ErrorCode};
-use crate::database::DatabaseManager;
 use crate::database::entity::system_metric::{self, MetricType};
-use sea_orm::{DatabaseConnection, DbErr, EntityTrait, QueryFilter, ColumnTrait, Select, Order};
+use crate::database::entity::system_metric_rollup::{self, PERIOD_DAY, PERIOD_HOUR};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
 use std::collections::HashMap;
 
 /// 系统监控指标服务
@@ -47,46 +51,52 @@ impl SystemMetricService {
 
         active_model.insert(&self.db).await.map_err(|e| {
             error!("Failed to create system metric: {:?}", e);
-            AppError::new(ErrorCode::DatabaseError, "Failed to create system metric")
+            AppError::with_message(ErrorCode::DatabaseError, "Failed to create system metric")
         })?;
 
         Ok(())
     }
 
     /// 批量创建系统指标
+    ///
+    /// 同一批指标使用同一采集时间，一条语句写入
     pub async fn batch_create_system_metrics(
         &self,
         request: &BatchCreateSystemMetricRequest,
     ) -> Result<BatchCreateSystemMetricResponse, AppError> {
-        let mut success_count = 0;
-        let mut failed_metrics = Vec::new();
-
-        for metric_request in &request.metrics {
-            let active_model = system_metric::ActiveModel {
-                metric_id: Default::default(),
-                metric_type: sea_orm::Set(metric_request.metric_type),
-                metric_name: sea_orm::Set(metric_request.metric_name.clone()),
-                metric_value: sea_orm::Set(metric_request.metric_value),
-                unit: sea_orm::Set(metric_request.unit.clone()),
-                host_name: sea_orm::Set(metric_request.host_name.clone()),
-                ip_address: sea_orm::Set(metric_request.ip_address.clone()),
-                collection_time: Default::default(),
-                remark: sea_orm::Set(metric_request.remark.clone()),
-            };
+        if request.metrics.is_empty() {
+            return Ok(BatchCreateSystemMetricResponse {
+                success_count: 0,
+                failed_metrics: Vec::new(),
+            });
+        }
 
-            match active_model.insert(&self.db).await {
-                Ok(_) => success_count += 1,
-                Err(e) => {
-                    error!("Failed to create system metric: {:?}", e);
-                    failed_metrics.push(metric_request.metric_name.clone());
-                }
+        let collection_time = Utc::now();
+        let models = request.metrics.iter().map(|metric_request| system_metric::ActiveModel {
+            metric_id: Default::default(),
+            metric_type: Set(metric_request.metric_type),
+            metric_name: Set(metric_request.metric_name.clone()),
+            metric_value: Set(metric_request.metric_value),
+            unit: Set(metric_request.unit.clone()),
+            host_name: Set(metric_request.host_name.clone()),
+            ip_address: Set(metric_request.ip_address.clone()),
+            collection_time: Set(collection_time),
+            remark: Set(metric_request.remark.clone()),
+        });
+
+        match system_metric::Entity::insert_many(models).exec(&self.db).await {
+            Ok(_) => Ok(BatchCreateSystemMetricResponse {
+                success_count: request.metrics.len(),
+                failed_metrics: Vec::new(),
+            }),
+            Err(e) => {
+                error!("Failed to batch create system metrics: {:?}", e);
+                Ok(BatchCreateSystemMetricResponse {
+                    success_count: 0,
+                    failed_metrics: request.metrics.iter().map(|m| m.metric_name.clone()).collect(),
+                })
             }
         }
-
-        Ok(BatchCreateSystemMetricResponse {
-            success_count,
-            failed_metrics,
-        })
     }
 
     /// 获取指标列表（分页）
@@ -145,7 +155,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query system metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query system metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query system metrics")
             })?;
 
         let total = system_metric::Entity::find()
@@ -153,7 +163,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to count system metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to count system metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to count system metrics")
             })?;
 
         let list = metrics
@@ -202,9 +212,9 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to find system metric: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to find system metric")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to find system metric")
             })?
-            .ok_or_else(|| AppError::new(ErrorCode::NotFound, "System metric not found"))?;
+            .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "System metric not found"))?;
 
         let metric_type_name = match MetricType::from(m.metric_type) {
             MetricType::Cpu => "CPU",
@@ -255,7 +265,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query real time metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query real time metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query real time metrics")
             })?;
 
         let mut host_name = "unknown".to_string();
@@ -304,12 +314,12 @@ impl SystemMetricService {
         &self,
         query: &MetricHistoryQuery,
     ) -> Result<MetricHistoryResponse, AppError> {
-        let end_time = query.end_time.unwrap_or_else(|| chrono::Utc::now());
+        let end_time = query.end_time.unwrap_or_else(chrono::Utc::now);
         let start_time = query
             .start_time
             .unwrap_or(end_time - chrono::Duration::hours(24));
 
-        let mut select = system_metric::Entity::find()
+        let select = system_metric::Entity::find()
             .filter(system_metric::Column::HostName.eq(&query.host_name))
             .filter(system_metric::Column::MetricName.eq(&query.metric_name))
             .filter(system_metric::Column::CollectionTime.gte(start_time))
@@ -321,7 +331,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query metric history: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query metric history")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query metric history")
             })?;
 
         let history = metrics
@@ -341,13 +351,13 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query latest metric: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query latest metric")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query latest metric")
             })?;
 
         let (metric_type, unit) = if let Some(m) = latest_metric {
             (m.metric_type, m.unit)
         } else {
-            return Err(AppError::new(ErrorCode::NotFound, "Metric not found"));
+            return Err(AppError::with_message(ErrorCode::NotFound, "Metric not found"));
         };
 
         Ok(MetricHistoryResponse {
@@ -384,14 +394,11 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query metric statistics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query metric statistics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query metric statistics")
             })?;
 
         if metrics.is_empty() {
-            return Err(AppError::new(
-                ErrorCode::NotFound,
-                "No metrics found for statistics",
-            ));
+            return Err(AppError::with_message(ErrorCode::NotFound, "No metrics found for statistics"));
         }
 
         let mut min_value = f64::MAX;
@@ -438,7 +445,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query system metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query system metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query system metrics")
             })?;
 
         let mut stats_map: HashMap<i32, (usize, usize)> = HashMap::new();
@@ -481,7 +488,7 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to query system metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to query system metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to query system metrics")
             })?;
 
         let mut host_map: HashMap<String, HostOverview> = HashMap::new();
@@ -514,7 +521,7 @@ impl SystemMetricService {
         }
 
         let mut result: Vec<HostOverview> = host_map.into_values().collect();
-        result.sort_by(|a, b| b.last_collection_time.cmp(&a.last_collection_time));
+        result.sort_by_key(|h| std::cmp::Reverse(h.last_collection_time));
 
         Ok(result)
     }
@@ -530,11 +537,488 @@ impl SystemMetricService {
             .await
             .map_err(|e| {
                 error!("Failed to cleanup old metrics: {:?}", e);
-                AppError::new(ErrorCode::DatabaseError, "Failed to cleanup old metrics")
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to cleanup old metrics")
             })?;
 
         info!("Cleaned up {} old metrics", delete_result.rows_affected);
 
         Ok(delete_result.rows_affected as usize)
     }
+
+    /// 清理过期的汇总数据
+    pub async fn cleanup_old_rollups(&self, period: &str, days: i32) -> Result<usize, AppError> {
+        let cutoff_time = Utc::now() - Duration::days(days as i64);
+
+        let delete_result = system_metric_rollup::Entity::delete_many()
+            .filter(system_metric_rollup::Column::Period.eq(period))
+            .filter(system_metric_rollup::Column::BucketTime.lt(cutoff_time))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to cleanup old metric rollups: {:?}", e);
+                AppError::with_message(ErrorCode::DatabaseError, "Failed to cleanup old metric rollups")
+            })?;
+
+        info!("Cleaned up {} old {} metric rollups", delete_result.rows_affected, period);
+
+        Ok(delete_result.rows_affected as usize)
+    }
+
+    /// 将原始数据按小时汇总
+    ///
+    /// 从上次汇总的下一个小时开始补齐到上一个完整小时，跳过没有数据的时段，
+    /// 每次最多处理 `max_buckets` 个小时，返回写入的汇总条数
+    pub async fn rollup_hourly(&self, max_buckets: usize) -> Result<usize, AppError> {
+        let limit = Utc::now().duration_trunc(Duration::hours(1)).map_err(Self::rollup_error)?;
+        self.rollup(PERIOD_HOUR, Duration::hours(1), limit, max_buckets).await
+    }
+
+    /// 将小时汇总按天汇总（UTC 零点为界）
+    ///
+    /// 某天的 24 个小时都已完成小时汇总后才生成该天的汇总
+    pub async fn rollup_daily(&self, max_buckets: usize) -> Result<usize, AppError> {
+        let latest_hour = system_metric_rollup::Entity::find()
+            .filter(system_metric_rollup::Column::Period.eq(PERIOD_HOUR))
+            .order_by(system_metric_rollup::Column::BucketTime, Order::Desc)
+            .one(&self.db)
+            .await
+            .map_err(Self::db_error)?;
+        let Some(latest_hour) = latest_hour else {
+            return Ok(0);
+        };
+
+        let limit = latest_hour.bucket_time + Duration::hours(1);
+        self.rollup(PERIOD_DAY, Duration::days(1), limit, max_buckets).await
+    }
+
+    /// 汇总 `limit` 之前已完整结束的周期
+    async fn rollup(
+        &self,
+        period: &str,
+        step: Duration,
+        limit: DateTime<Utc>,
+        max_buckets: usize,
+    ) -> Result<usize, AppError> {
+        let last = system_metric_rollup::Entity::find()
+            .filter(system_metric_rollup::Column::Period.eq(period))
+            .order_by(system_metric_rollup::Column::BucketTime, Order::Desc)
+            .one(&self.db)
+            .await
+            .map_err(Self::db_error)?;
+        let mut next = last.map(|r| r.bucket_time + step);
+
+        let mut written = 0;
+        for _ in 0..max_buckets {
+            // 跳过没有数据的时段，直接定位到下一条源数据所在的周期
+            let Some(first) = self.earliest_source_time(period, next).await? else {
+                break;
+            };
+            let Some(bucket) = rollup_bucket(first, step, limit)? else {
+                break;
+            };
+
+            let rows = if period == PERIOD_DAY {
+                self.aggregate_hourly(bucket, bucket + step).await?
+            } else {
+                self.aggregate_raw(bucket, bucket + step).await?
+            };
+            written += self.save_rollups(period, bucket, rows).await?;
+            next = Some(bucket + step);
+        }
+
+        if written > 0 {
+            info!("Rolled up {} {} metric records", written, period);
+        }
+        Ok(written)
+    }
+
+    /// 汇总源数据中不早于 `from` 的最早时间（小时汇总的源为原始数据，天汇总的源为小时汇总）
+    async fn earliest_source_time(
+        &self,
+        period: &str,
+        from: Option<DateTime<Utc>>,
+    ) -> Result<Option<DateTime<Utc>>, AppError> {
+        if period == PERIOD_DAY {
+            let mut select = system_metric_rollup::Entity::find()
+                .filter(system_metric_rollup::Column::Period.eq(PERIOD_HOUR));
+            if let Some(from) = from {
+                select = select.filter(system_metric_rollup::Column::BucketTime.gte(from));
+            }
+            let row = select
+                .order_by(system_metric_rollup::Column::BucketTime, Order::Asc)
+                .one(&self.db)
+                .await
+                .map_err(Self::db_error)?;
+            Ok(row.map(|r| r.bucket_time))
+        } else {
+            let mut select = system_metric::Entity::find();
+            if let Some(from) = from {
+                select = select.filter(system_metric::Column::CollectionTime.gte(from));
+            }
+            let row = select
+                .order_by(system_metric::Column::CollectionTime, Order::Asc)
+                .one(&self.db)
+                .await
+                .map_err(Self::db_error)?;
+            Ok(row.map(|m| m.collection_time))
+        }
+    }
+
+    /// 按主机和指标聚合 [start, end) 内的原始数据
+    async fn aggregate_raw(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<system_metric_rollup::Model>, AppError> {
+        type Row = (String, i32, String, String, String, f64, f64, f64, i64);
+
+        let rows: Vec<Row> = system_metric::Entity::find()
+            .select_only()
+            .column(system_metric::Column::HostName)
+            .column(system_metric::Column::MetricType)
+            .column(system_metric::Column::MetricName)
+            .column(system_metric::Column::Unit)
+            .column_as(system_metric::Column::IpAddress.max(), "ip_address")
+            .column_as(system_metric::Column::MetricValue.sum(), "sum_value")
+            .column_as(system_metric::Column::MetricValue.min(), "min_value")
+            .column_as(system_metric::Column::MetricValue.max(), "max_value")
+            .column_as(system_metric::Column::MetricId.count(), "sample_count")
+            .filter(system_metric::Column::CollectionTime.gte(start))
+            .filter(system_metric::Column::CollectionTime.lt(end))
+            .group_by(system_metric::Column::HostName)
+            .group_by(system_metric::Column::MetricType)
+            .group_by(system_metric::Column::MetricName)
+            .group_by(system_metric::Column::Unit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(Self::db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|row| row.8 > 0)
+            .map(|(host_name, metric_type, metric_name, unit, ip_address, sum, min, max, count)| {
+                system_metric_rollup::Model {
+                    id: 0,
+                    period: PERIOD_HOUR.to_string(),
+                    bucket_time: start,
+                    metric_type,
+                    metric_name,
+                    unit,
+                    host_name,
+                    ip_address,
+                    avg_value: sum / count as f64,
+                    min_value: min,
+                    max_value: max,
+                    sample_count: count,
+                }
+            })
+            .collect())
+    }
+
+    /// 按主机和指标合并 [start, end) 内的小时汇总，平均值按采样数加权
+    async fn aggregate_hourly(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<system_metric_rollup::Model>, AppError> {
+        let hourly = system_metric_rollup::Entity::find()
+            .filter(system_metric_rollup::Column::Period.eq(PERIOD_HOUR))
+            .filter(system_metric_rollup::Column::BucketTime.gte(start))
+            .filter(system_metric_rollup::Column::BucketTime.lt(end))
+            .all(&self.db)
+            .await
+            .map_err(Self::db_error)?;
+
+        Ok(merge_hourly(start, hourly))
+    }
+
+    /// 写入一个周期的汇总，已存在的同周期汇总先删除，重复执行结果一致
+    async fn save_rollups(
+        &self,
+        period: &str,
+        bucket: DateTime<Utc>,
+        rows: Vec<system_metric_rollup::Model>,
+    ) -> Result<usize, AppError> {
+        let count = rows.len();
+        let txn = self.db.begin().await.map_err(Self::db_error)?;
+
+        system_metric_rollup::Entity::delete_many()
+            .filter(system_metric_rollup::Column::Period.eq(period))
+            .filter(system_metric_rollup::Column::BucketTime.eq(bucket))
+            .exec(&txn)
+            .await
+            .map_err(Self::db_error)?;
+
+        if !rows.is_empty() {
+            let models = rows.into_iter().map(|row| system_metric_rollup::ActiveModel {
+                id: Default::default(),
+                period: Set(row.period),
+                bucket_time: Set(row.bucket_time),
+                metric_type: Set(row.metric_type),
+                metric_name: Set(row.metric_name),
+                unit: Set(row.unit),
+                host_name: Set(row.host_name),
+                ip_address: Set(row.ip_address),
+                avg_value: Set(row.avg_value),
+                min_value: Set(row.min_value),
+                max_value: Set(row.max_value),
+                sample_count: Set(row.sample_count),
+            });
+            system_metric_rollup::Entity::insert_many(models)
+                .exec(&txn)
+                .await
+                .map_err(Self::db_error)?;
+        }
+
+        txn.commit().await.map_err(Self::db_error)?;
+        Ok(count)
+    }
+
+    fn db_error(e: sea_orm::DbErr) -> AppError {
+        error!("System metric rollup failed: {:?}", e);
+        AppError::with_message(ErrorCode::DatabaseError, "System metric rollup failed")
+    }
+
+    fn rollup_error(e: chrono::RoundingError) -> AppError {
+        AppError::with_message(ErrorCode::InternalServerError, format!("Invalid rollup bucket: {}", e))
+    }
+}
+
+/// `first` 所在的汇总周期，周期在 `limit` 之前尚未结束时返回 None
+fn rollup_bucket(
+    first: DateTime<Utc>,
+    step: Duration,
+    limit: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, AppError> {
+    let bucket = first.duration_trunc(step).map_err(SystemMetricService::rollup_error)?;
+    Ok((bucket + step <= limit).then_some(bucket))
+}
+
+/// 按主机和指标合并小时汇总为 `start` 开始的天汇总，平均值按采样数加权
+fn merge_hourly(
+    start: DateTime<Utc>,
+    hourly: Vec<system_metric_rollup::Model>,
+) -> Vec<system_metric_rollup::Model> {
+    let mut merged: HashMap<(String, String), (system_metric_rollup::Model, f64)> = HashMap::new();
+    for hour in hourly {
+        let weighted = hour.avg_value * hour.sample_count as f64;
+        match merged.get_mut(&(hour.host_name.clone(), hour.metric_name.clone())) {
+            Some((day, sum)) => {
+                *sum += weighted;
+                day.min_value = day.min_value.min(hour.min_value);
+                day.max_value = day.max_value.max(hour.max_value);
+                day.sample_count += hour.sample_count;
+            }
+            None => {
+                let key = (hour.host_name.clone(), hour.metric_name.clone());
+                let day = system_metric_rollup::Model {
+                    period: PERIOD_DAY.to_string(),
+                    bucket_time: start,
+                    ..hour
+                };
+                merged.insert(key, (day, weighted));
+            }
+        }
+    }
+
+    merged
+        .into_values()
+        .filter(|(day, _)| day.sample_count > 0)
+        .map(|(mut day, sum)| {
+            day.avg_value = sum / day.sample_count as f64;
+            day
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use sea_orm::{ConnectOptions, ConnectionTrait, Database, Schema};
+
+    /// 建好指标表和汇总表的内存 SQLite 数据库
+    async fn sqlite() -> SystemMetricService {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1);
+        let db = Database::connect(options).await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(system_metric::Entity),
+            schema.create_table_from_entity(system_metric_rollup::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await.unwrap();
+        }
+        SystemMetricService::new(db)
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, minute, 0).unwrap()
+    }
+
+    async fn sample(service: &SystemMetricService, time: DateTime<Utc>, value: f64) {
+        system_metric::ActiveModel {
+            metric_id: Default::default(),
+            metric_type: Set(MetricType::Cpu.into()),
+            metric_name: Set("cpu_usage".to_string()),
+            metric_value: Set(value),
+            unit: Set("%".to_string()),
+            host_name: Set("node-1".to_string()),
+            ip_address: Set("10.0.0.1".to_string()),
+            collection_time: Set(time),
+            remark: Set(None),
+        }
+        .insert(&service.db)
+        .await
+        .unwrap();
+    }
+
+    async fn rollups(service: &SystemMetricService, period: &str) -> Vec<system_metric_rollup::Model> {
+        system_metric_rollup::Entity::find()
+            .filter(system_metric_rollup::Column::Period.eq(period))
+            .order_by(system_metric_rollup::Column::BucketTime, Order::Asc)
+            .all(&service.db)
+            .await
+            .unwrap()
+    }
+
+    fn hour_rollup(bucket: DateTime<Utc>, host: &str, avg: f64, min: f64, max: f64, count: i64) -> system_metric_rollup::Model {
+        system_metric_rollup::Model {
+            id: 0,
+            period: PERIOD_HOUR.to_string(),
+            bucket_time: bucket,
+            metric_type: MetricType::Cpu.into(),
+            metric_name: "cpu_usage".to_string(),
+            unit: "%".to_string(),
+            host_name: host.to_string(),
+            ip_address: "10.0.0.1".to_string(),
+            avg_value: avg,
+            min_value: min,
+            max_value: max,
+            sample_count: count,
+        }
+    }
+
+    #[test]
+    fn test_rollup_bucket_boundary() {
+        let hour = Duration::hours(1);
+        // 周期恰好在 limit 结束时可以汇总
+        assert_eq!(rollup_bucket(at(1, 10, 59), hour, at(1, 11, 0)).unwrap(), Some(at(1, 10, 0)));
+        assert_eq!(rollup_bucket(at(1, 10, 0), hour, at(1, 11, 0)).unwrap(), Some(at(1, 10, 0)));
+        // 尚未结束的周期不汇总
+        assert_eq!(rollup_bucket(at(1, 11, 0), hour, at(1, 11, 0)).unwrap(), None);
+        assert_eq!(rollup_bucket(at(1, 11, 30), hour, at(1, 11, 45)).unwrap(), None);
+        // 天汇总以 UTC 零点为界
+        let day = Duration::days(1);
+        assert_eq!(rollup_bucket(at(1, 23, 0), day, at(2, 0, 0)).unwrap(), Some(at(1, 0, 0)));
+        assert_eq!(rollup_bucket(at(2, 0, 0), day, at(2, 23, 0)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_merge_hourly_weights_by_sample_count() {
+        let merged = merge_hourly(
+            at(1, 0, 0),
+            vec![
+                hour_rollup(at(1, 1, 0), "node-1", 10.0, 5.0, 20.0, 1),
+                hour_rollup(at(1, 2, 0), "node-1", 40.0, 30.0, 50.0, 3),
+                hour_rollup(at(1, 2, 0), "node-2", 7.0, 7.0, 7.0, 2),
+                hour_rollup(at(1, 3, 0), "node-3", 1.0, 1.0, 1.0, 0),
+            ],
+        );
+
+        let mut merged: HashMap<String, system_metric_rollup::Model> =
+            merged.into_iter().map(|m| (m.host_name.clone(), m)).collect();
+        assert_eq!(merged.len(), 2);
+
+        let node1 = merged.remove("node-1").unwrap();
+        assert_eq!(node1.period, PERIOD_DAY);
+        assert_eq!(node1.bucket_time, at(1, 0, 0));
+        assert_eq!(node1.sample_count, 4);
+        assert_eq!(node1.avg_value, (10.0 + 40.0 * 3.0) / 4.0);
+        assert_eq!((node1.min_value, node1.max_value), (5.0, 50.0));
+
+        let node2 = merged.remove("node-2").unwrap();
+        assert_eq!((node2.avg_value, node2.sample_count), (7.0, 2));
+    }
+
+    #[tokio::test]
+    async fn test_rollup_skips_partial_hour() {
+        let service = sqlite().await;
+        sample(&service, at(1, 10, 5), 10.0).await;
+        sample(&service, at(1, 10, 35), 30.0).await;
+        sample(&service, at(1, 11, 10), 99.0).await;
+
+        let written = service.rollup(PERIOD_HOUR, Duration::hours(1), at(1, 11, 30), 48).await.unwrap();
+        assert_eq!(written, 1);
+
+        let hourly = rollups(&service, PERIOD_HOUR).await;
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].bucket_time, at(1, 10, 0));
+        assert_eq!(hourly[0].sample_count, 2);
+        assert_eq!(hourly[0].avg_value, 20.0);
+        assert_eq!((hourly[0].min_value, hourly[0].max_value), (10.0, 30.0));
+
+        // 小时结束后补齐
+        let written = service.rollup(PERIOD_HOUR, Duration::hours(1), at(1, 12, 0), 48).await.unwrap();
+        assert_eq!(written, 1);
+        let hourly = rollups(&service, PERIOD_HOUR).await;
+        assert_eq!(hourly.len(), 2);
+        assert_eq!((hourly[1].bucket_time, hourly[1].avg_value), (at(1, 11, 0), 99.0));
+    }
+
+    #[tokio::test]
+    async fn test_rollup_skips_gap_between_buckets() {
+        let service = sqlite().await;
+        sample(&service, at(1, 8, 15), 10.0).await;
+        sample(&service, at(1, 11, 45), 20.0).await;
+
+        let written = service.rollup(PERIOD_HOUR, Duration::hours(1), at(1, 12, 0), 48).await.unwrap();
+        assert_eq!(written, 2);
+
+        let buckets: Vec<DateTime<Utc>> =
+            rollups(&service, PERIOD_HOUR).await.into_iter().map(|r| r.bucket_time).collect();
+        assert_eq!(buckets, vec![at(1, 8, 0), at(1, 11, 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_rollup_rerun_does_not_duplicate() {
+        let service = sqlite().await;
+        sample(&service, at(1, 9, 0), 10.0).await;
+        sample(&service, at(1, 10, 0), 20.0).await;
+
+        let limit = at(1, 11, 0);
+        assert_eq!(service.rollup(PERIOD_HOUR, Duration::hours(1), limit, 48).await.unwrap(), 2);
+        assert_eq!(service.rollup(PERIOD_HOUR, Duration::hours(1), limit, 48).await.unwrap(), 0);
+        assert_eq!(rollups(&service, PERIOD_HOUR).await.len(), 2);
+
+        // 同一周期重复写入时替换而不是追加
+        let rows = service.aggregate_raw(at(1, 10, 0), limit).await.unwrap();
+        service.save_rollups(PERIOD_HOUR, at(1, 10, 0), rows).await.unwrap();
+        assert_eq!(rollups(&service, PERIOD_HOUR).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rollup_daily_waits_for_complete_day() {
+        let service = sqlite().await;
+        for hour in 0..24 {
+            sample(&service, at(1, hour, 30), hour as f64).await;
+        }
+        sample(&service, at(2, 0, 30), 100.0).await;
+        service.rollup(PERIOD_HOUR, Duration::hours(1), at(2, 0, 0), 48).await.unwrap();
+
+        // 最新的小时汇总为 1 日 23 点，当天刚好完整
+        assert_eq!(service.rollup_daily(48).await.unwrap(), 1);
+        let daily = rollups(&service, PERIOD_DAY).await;
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].bucket_time, at(1, 0, 0));
+        assert_eq!(daily[0].sample_count, 24);
+        assert_eq!(daily[0].avg_value, 11.5);
+
+        // 2 日只有 0 点的数据，不生成天汇总
+        service.rollup(PERIOD_HOUR, Duration::hours(1), at(2, 1, 0), 48).await.unwrap();
+        assert_eq!(service.rollup_daily(48).await.unwrap(), 0);
+        assert_eq!(rollups(&service, PERIOD_DAY).await.len(), 1);
+    }
 }
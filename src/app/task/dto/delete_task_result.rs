@@ -1,9 +1,10 @@
 /// 删除任务结果 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteTaskRequest {
     /// 任务结果 ID 列表
@@ -11,7 +12,7 @@ pub struct DeleteTaskRequest {
     pub ids: Vec<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DeleteTaskResponse {
     pub message: String,
 }
@@ -1,9 +1,10 @@
 /// 任务控制相关 DTO
 
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 
 /// 已注册任务详情
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RegisteredTaskDetail {
     /// 任务名称
@@ -1,10 +1,11 @@
 /// 任务结果响应 DTO
 
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 use chrono::{DateTime, Utc};
 
 /// 任务结果详情响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskResultDetailResponse {
     /// 任务结果 ID
@@ -62,7 +63,7 @@ impl TaskResultDetailResponse {
 }
 
 /// 任务结果列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskResultListItem {
     /// 任务结果 ID
@@ -1,9 +1,10 @@
 /// 任务调度器查询参数 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 任务调度器列表查询参数
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskSchedulerListQuery {
     /// 任务调度名称
@@ -1,10 +1,11 @@
 /// 任务调度器响应 DTO
 
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 use chrono::{DateTime, Utc};
 
 /// 任务调度器详情响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskSchedulerDetailResponse {
     /// 任务调度 ID
@@ -93,7 +94,7 @@ impl TaskSchedulerDetailResponse {
 }
 
 /// 任务调度器列表项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskSchedulerListItem {
     /// 任务调度 ID
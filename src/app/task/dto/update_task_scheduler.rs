@@ -1,10 +1,11 @@
 /// 更新任务调度器 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 use chrono::{DateTime, Utc};
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTaskSchedulerRequest {
     /// 任务名称
@@ -60,7 +61,7 @@ pub struct UpdateTaskSchedulerRequest {
     pub remark: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UpdateTaskSchedulerResponse {
     pub id: i64,
     pub name: String,
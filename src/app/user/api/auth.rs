@@ -1,5 +1,5 @@
 /// 用户认证相关API
-/// 提供登录、注册、Token刷新、登出等接口
+/// 提供登录、注册、找回密码、Token刷新、登出等接口
 
 use axum::{
     extract::Json,
@@ -7,14 +7,17 @@ use axum::{
     response::IntoResponse,
 };
 use crate::common::response::api_response;
-use crate::app::auth::dto::{LoginRequest, RefreshTokenRequest, LogoutRequest, CaptchaResponse};
-use crate::app::auth::service::AuthService;
-use crate::app::user::dto::CreateUserRequest;
+use crate::app::auth::dto::{
+    LoginRequest, RefreshTokenRequest, LogoutRequest, CaptchaResponse,
+    RegisterRequest, VerifyRegisterRequest, ForgotPasswordRequest, ResetForgottenPasswordRequest,
+};
+use crate::app::auth::service::{AccountService, AuthService};
 use crate::common::exception::AppError;
 use crate::core::SETTINGS;
 use crate::database::DatabaseManager;
 use captcha::{Captcha, filters::{Noise, Wave}};
 use uuid::Uuid;
+use validator::Validate;
 use base64::{Engine as _, engine::general_purpose};
 
 /// 获取登录验证码（内部版本，用于无State的情况）
@@ -85,19 +88,58 @@ pub async fn get_captcha_internal() -> Result<impl IntoResponse, AppError> {
     Ok((StatusCode::OK, Json(api_response(data))))
 }
 
-/// 用户注册
+fn validate<T: Validate>(request: &T) -> Result<(), AppError> {
+    request
+        .validate()
+        .map_err(AppError::from)
+}
+
+async fn account_service() -> AccountService {
+    AccountService::new(DatabaseManager::get_connection().await.clone())
+}
+
+/// 用户注册：校验后发送邮箱验证码，验证通过才创建用户
 /// POST /api/v1/auth/register
 pub async fn register(
-    Json(_request): Json<CreateUserRequest>,
+    Json(request): Json<RegisterRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate(&request)?;
+    let result = account_service().await.register(&request).await?;
+
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 验证注册邮箱（邮件链接令牌或邮箱 + 验证码）
+/// POST /api/v1/auth/register/verify
+pub async fn verify_register(
+    Json(request): Json<VerifyRegisterRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // TODO: 实现用户注册逻辑
-    // 1. 验证用户数据
-    // 2. 检查用户名、邮箱唯一性
-    // 3. 加密密码
-    // 4. 创建用户
-    // 5. 返回成功响应
-
-    Ok((StatusCode::NOT_IMPLEMENTED, Json(api_response("未实现".to_string()))))
+    validate(&request)?;
+    let result = account_service().await.verify_registration(&request).await?;
+
+    Ok((StatusCode::OK, Json(api_response(result))))
+}
+
+/// 找回密码：向绑定邮箱发送重置链接
+/// POST /api/v1/auth/password/forgot
+pub async fn forgot_password(
+    Json(request): Json<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate(&request)?;
+    account_service().await.forgot_password(&request).await?;
+
+    Ok((StatusCode::OK, Json(api_response("如果邮箱已注册，重置邮件将很快送达".to_string()))))
+}
+
+/// 使用重置令牌设置新密码
+/// POST /api/v1/auth/password/reset
+pub async fn reset_forgotten_password(
+    Json(request): Json<ResetForgottenPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate(&request)?;
+    account_service().await.reset_forgotten_password(&request).await?;
+
+    Ok((StatusCode::OK, Json(api_response("密码重置成功".to_string()))))
 }
 
 /// 用户登录
@@ -1,11 +1,13 @@
 /// 修改密码请求 DTO
 
 use serde::Deserialize;
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ChangePasswordRequest {
-    /// 用户ID
+    /// 用户ID（修改本人密码时由认证上下文填充）
+    #[serde(default)]
     pub user_id: i64,
 
     /// 旧密码
@@ -18,7 +20,7 @@ pub struct ChangePasswordRequest {
 }
 
 /// 重置密码请求 DTO
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ResetPasswordRequest {
     /// 用户ID
     pub user_id: i64,
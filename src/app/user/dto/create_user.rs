@@ -1,9 +1,10 @@
 /// 创建用户请求 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateUserRequest {
     /// 用户名
     #[validate(length(min = 3, max = 50))]
@@ -47,7 +48,7 @@ pub struct CreateUserRequest {
 }
 
 /// 用户创建响应 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateUserResponse {
     /// 用户ID
     pub id: i64,
@@ -1,10 +1,11 @@
 /// 用户导入导出 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// 用户导入请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ImportUsersRequest {
     /// 文件内容（CSV或Excel格式的Base64编码）
     pub file_data: String,
@@ -24,7 +25,7 @@ pub struct ImportUsersRequest {
 }
 
 /// 用户导入响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ImportUsersResponse {
     /// 导入结果
     pub result: ImportResult,
@@ -40,7 +41,7 @@ pub struct ImportUsersResponse {
 }
 
 /// 导入结果
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ImportResult {
     /// 是否成功
     pub success: bool,
@@ -50,7 +51,7 @@ pub struct ImportResult {
 }
 
 /// 导入错误详情
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ImportError {
     /// 错误行号（CSV中的行号）
     pub row_number: usize,
@@ -69,7 +70,7 @@ pub struct ImportError {
 }
 
 /// 用户导出请求
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ExportUsersRequest {
     /// 导出格式（csv, xlsx, json）
     #[validate(length(min = 1))]
@@ -98,7 +99,7 @@ pub struct ExportUsersRequest {
 }
 
 /// 用户导出响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ExportUsersResponse {
     /// 导出的文件ID
     pub file_id: i64,
@@ -126,14 +127,14 @@ pub struct ExportUsersResponse {
 }
 
 /// 用户模板下载请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DownloadTemplateRequest {
     /// 模板格式（csv, xlsx）
     pub template_format: String,
 }
 
 /// 用户模板下载响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DownloadTemplateResponse {
     /// 模板文件ID
     pub file_id: i64,
@@ -158,7 +159,7 @@ pub struct DownloadTemplateResponse {
 }
 
 /// 用户导入模板数据项（用于生成模板）
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserImportTemplateItem {
     /// 用户名（必填）
     pub username: String,
@@ -198,7 +199,7 @@ pub struct UserImportTemplateItem {
 }
 
 /// 用户导出数据项
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserExportItem {
     /// 用户ID
     pub id: i64,
@@ -247,7 +248,7 @@ pub struct UserExportItem {
 }
 
 /// 导入验证错误
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ImportValidationError {
     /// 字段名
     pub field: String,
@@ -260,7 +261,7 @@ pub struct ImportValidationError {
 }
 
 /// 批量导入用户请求（用于API）
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct BatchImportUsersRequest {
     /// 用户数据列表
     pub users: Vec<UserImportTemplateItem>,
@@ -276,7 +277,7 @@ pub struct BatchImportUsersRequest {
 }
 
 /// 批量导入用户响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct BatchImportUsersResponse {
     /// 成功导入的用户数
     pub success_count: usize,
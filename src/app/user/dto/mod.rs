@@ -4,6 +4,7 @@ pub mod create_user;
 pub mod update_user;
 pub mod user_response;
 pub mod change_password;
+pub mod profile;
 pub mod pagination;
 pub mod import_export_user;
 
@@ -17,6 +18,10 @@ pub use create_user::CreateUserResponse;
 pub use update_user::UpdateUserRequest;
 pub use change_password::ChangePasswordRequest;
 pub use change_password::ResetPasswordRequest;
+pub use profile::{
+    UpdateAvatarRequest, UpdateEmailRequest, UpdateLocaleRequest, UpdateNicknameRequest, UpdatePhoneRequest,
+    VerifyEmailRequest,
+};
 pub use pagination::{
     UserPaginationQuery, UserPaginationResponse,
     UserSortField, SortOrder,
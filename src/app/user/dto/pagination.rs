@@ -1,12 +1,13 @@
 /// 用户分页查询 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 use super::user_response::UserListItem;
 
 /// 用户分页查询请求
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct UserPaginationQuery {
     /// 页码（从1开始）
     #[validate(range(min = 1))]
@@ -57,7 +58,7 @@ pub struct UserPaginationQuery {
 }
 
 /// 排序字段
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum UserSortField {
     /// 按ID排序
@@ -93,7 +94,7 @@ impl std::fmt::Display for UserSortField {
 }
 
 /// 排序方向
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[derive(Default)]
 pub enum SortOrder {
     /// 升序
@@ -114,7 +115,7 @@ impl std::fmt::Display for SortOrder {
 }
 
 /// 用户分页查询响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UserPaginationResponse {
     /// 用户列表
     pub list: Vec<UserListItem>,
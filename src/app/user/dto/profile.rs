@@ -0,0 +1,59 @@
+/// 当前用户资料修改 DTO
+
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 修改昵称请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateNicknameRequest {
+    /// 昵称
+    #[validate(length(min = 1, max = 50))]
+    pub nickname: String,
+}
+
+/// 修改偏好语言请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateLocaleRequest {
+    /// 语言标签（如 zh-CN、en-US），为空时清除偏好
+    #[validate(length(max = 20))]
+    pub locale: Option<String>,
+}
+
+/// 修改头像请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateAvatarRequest {
+    /// 头像URL（可为上传接口返回的相对路径）
+    #[validate(length(min = 1, max = 255))]
+    pub avatar: String,
+}
+
+/// 修改手机号请求 DTO（需确认当前密码；未接入短信通道，新号码不做归属验证）
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdatePhoneRequest {
+    /// 新手机号
+    #[validate(length(min = 5, max = 20))]
+    pub phone: String,
+
+    /// 当前密码
+    pub password: String,
+}
+
+/// 修改邮箱请求 DTO（需确认当前密码，验证码发送到新邮箱）
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateEmailRequest {
+    /// 新邮箱
+    #[validate(email)]
+    pub email: String,
+
+    /// 当前密码
+    pub password: String,
+}
+
+/// 验证新邮箱请求 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyEmailRequest {
+    /// 新邮箱收到的验证码
+    #[validate(length(equal = 6))]
+    pub code: String,
+}
@@ -1,9 +1,10 @@
 /// 更新用户请求 DTO
 
 use serde::Deserialize;
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate, Default)]
+#[derive(Debug, Deserialize, Validate, Default, ToSchema)]
 pub struct UpdateUserRequest {
     /// 昵称
     #[validate(length(min = 1, max = 50))]
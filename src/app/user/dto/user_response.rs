@@ -1,9 +1,10 @@
 /// 用户响应 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 用户详情响应
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct UserDetailResponse {
     /// 用户ID
     pub id: i64,
@@ -44,7 +45,7 @@ pub struct UserDetailResponse {
 }
 
 /// 用户列表项响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserListItem {
     /// 用户ID
     pub id: i64,
@@ -70,7 +71,7 @@ pub struct UserListItem {
     pub created_time: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct CurrentUserResponse {
     pub id: i64,
     pub uuid: String,
@@ -88,4 +89,6 @@ pub struct CurrentUserResponse {
     pub dept_id: Option<i64>,
     pub dept: Option<String>,
     pub roles: Vec<String>,
+    /// 偏好语言，为空时按 Accept-Language 协商
+    pub locale: Option<String>,
 }
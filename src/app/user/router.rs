@@ -9,6 +9,8 @@ use crate::database::DatabaseManager;
 use crate::app::user::dto::{
     UserPaginationQuery, CreateUserRequest, UpdateUserRequest,
     ChangePasswordRequest, ResetPasswordRequest,
+    UpdateNicknameRequest, UpdateAvatarRequest, UpdateEmailRequest, VerifyEmailRequest, UpdatePhoneRequest,
+    UpdateLocaleRequest,
     ImportUsersRequest, ExportUsersRequest, DownloadTemplateRequest, BatchImportUsersRequest,
 };
 use crate::app::auth::dto::{
@@ -27,7 +29,9 @@ pub fn user_routes() -> Router {
         .route("/me/nickname", put(update_current_user_nickname_handler))  // PUT /api/v1/sys/users/me/nickname
         .route("/me/phone", put(update_current_user_phone_handler))  // PUT /api/v1/sys/users/me/phone
         .route("/me/avatar", put(update_current_user_avatar_handler))  // PUT /api/v1/sys/users/me/avatar
+        .route("/me/locale", put(update_current_user_locale_handler))  // PUT /api/v1/sys/users/me/locale
         .route("/me/email", put(update_current_user_email_handler))  // PUT /api/v1/sys/users/me/email
+        .route("/me/email/verify", put(verify_current_user_email_handler))  // PUT /api/v1/sys/users/me/email/verify
 
         // ===== 用户管理路由 =====
         .route("/", get(get_users_handler))  // GET /api/v1/sys/users
@@ -434,59 +438,124 @@ async fn batch_import_users_handler(
 /// 更新当前用户密码
 /// PUT /api/v1/sys/users/me/password
 async fn update_current_user_password_handler(
-    _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
-    Json(request): Json<ChangePasswordRequest>,
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(mut request): Json<ChangePasswordRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let db_conn = DatabaseManager::get_connection().await;
     let user_service = crate::app::user::service::UserService::new(db_conn.clone());
 
-    // TODO: 从认证上下文中获取当前用户ID
+    // 只能修改本人密码；密码过期的令牌修改成功后需重新登录
+    request.user_id = current_user_id(&auth_context)?;
     user_service.change_password(&request).await?;
 
     Ok((StatusCode::OK, Json(api_response("密码修改成功".to_string()))))
 }
 
+/// 从认证上下文中获取当前用户ID
+fn current_user_id(auth_context: &crate::middleware::jwt_auth_middleware::AuthContext) -> Result<i64, AppError> {
+    auth_context.user_id.parse()
+        .map_err(|_| AppError::new(crate::common::exception::ErrorCode::TokenInvalid))
+}
+
+fn validate<T: validator::Validate>(request: &T) -> Result<(), AppError> {
+    request.validate().map_err(AppError::from)
+}
+
 /// 更新当前用户昵称
 /// PUT /api/v1/sys/users/me/nickname
 async fn update_current_user_nickname_handler(
-    _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
-    Json(_request): Json<serde_json::Value>,
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<UpdateNicknameRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // TODO: 从认证上下文中获取当前用户ID
-    // TODO: 实现昵称更新逻辑
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let user_service = crate::app::user::service::UserService::new(db_conn.clone());
+
+    user_service.update_nickname(current_user_id(&auth_context)?, &request.nickname).await?;
+
     Ok((StatusCode::OK, Json(api_response("昵称更新成功".to_string()))))
 }
 
+/// 更新当前用户偏好语言
+/// PUT /api/v1/sys/users/me/locale
+async fn update_current_user_locale_handler(
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<UpdateLocaleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let user_service = crate::app::user::service::UserService::new(db_conn.clone());
+
+    let locale = user_service
+        .update_locale(current_user_id(&auth_context)?, request.locale.as_deref())
+        .await?;
+
+    Ok((StatusCode::OK, Json(api_response(serde_json::json!({ "locale": locale })))))
+}
+
 /// 更新当前用户头像
 /// PUT /api/v1/sys/users/me/avatar
 async fn update_current_user_avatar_handler(
-    _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
-    Json(_request): Json<serde_json::Value>,
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<UpdateAvatarRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // TODO: 从认证上下文中获取当前用户ID
-    // TODO: 实现头像更新逻辑
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let user_service = crate::app::user::service::UserService::new(db_conn.clone());
+
+    user_service.update_avatar(current_user_id(&auth_context)?, &request.avatar).await?;
+
     Ok((StatusCode::OK, Json(api_response("头像更新成功".to_string()))))
 }
 
-/// 更新当前用户邮箱
+/// 申请更换当前用户邮箱，验证码发送到新邮箱
 /// PUT /api/v1/sys/users/me/email
 async fn update_current_user_email_handler(
-    _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
-    Json(_request): Json<serde_json::Value>,
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<UpdateEmailRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // TODO: 从认证上下文中获取当前用户ID
-    // TODO: 实现邮箱更新逻辑
-    Ok((StatusCode::OK, Json(api_response("邮箱更新成功".to_string()))))
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let account_service = crate::app::auth::service::AccountService::new(db_conn.clone());
+
+    account_service
+        .request_email_change(current_user_id(&auth_context)?, &request.email, &request.password)
+        .await?;
+
+    Ok((StatusCode::OK, Json(api_response("验证码已发送到新邮箱".to_string()))))
 }
 
-/// 更新当前用户手机号
+/// 验证新邮箱，通过后更新
+/// PUT /api/v1/sys/users/me/email/verify
+async fn verify_current_user_email_handler(
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let account_service = crate::app::auth::service::AccountService::new(db_conn.clone());
+
+    let email = account_service
+        .confirm_email_change(current_user_id(&auth_context)?, &request.code)
+        .await?;
+
+    Ok((StatusCode::OK, Json(api_response(email))))
+}
+
+/// 更新当前用户手机号，需确认当前密码；新号码不做短信验证
 /// PUT /api/v1/sys/users/me/phone
 async fn update_current_user_phone_handler(
-    _auth_context: axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
-    Json(_request): Json<serde_json::Value>,
+    axum::extract::Extension(auth_context): axum::extract::Extension<crate::middleware::jwt_auth_middleware::AuthContext>,
+    Json(request): Json<UpdatePhoneRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // TODO: 从认证上下文中获取当前用户ID
-    // TODO: 实现手机号更新逻辑
+    validate(&request)?;
+    let db_conn = DatabaseManager::get_connection().await;
+    let user_service = crate::app::user::service::UserService::new(db_conn.clone());
+
+    user_service
+        .update_phone(current_user_id(&auth_context)?, &request.phone, &request.password)
+        .await?;
+
     Ok((StatusCode::OK, Json(api_response("手机号更新成功".to_string()))))
 }
 
@@ -8,6 +8,7 @@ use crate::app::user::dto::{
     ExportUsersRequest, ExportUsersResponse, DownloadTemplateRequest, DownloadTemplateResponse,
     UserImportTemplateItem, UserExportItem, BatchImportUsersRequest, BatchImportUsersResponse,
 };
+use crate::app::auth::service::PasswordService;
 use crate::common::exception::{AppError, ErrorCode};
 use crate::database::entity::user;
 use crate::database::user_repo::UserRepository as UserRepo;
@@ -49,20 +50,14 @@ impl UserService {
             }
         }
 
-        let hashed_password = CryptoUtils::hash_password(&request.password, None)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
+        let hashed_password = PasswordService::hash_new_password(&request.password, &request.username).await?;
 
         let user_model = user::ActiveModel {
             id: ActiveValue::NotSet,
             uuid: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
             username: ActiveValue::Set(request.username.clone()),
             nickname: ActiveValue::Set(request.nickname.clone()),
-            password: ActiveValue::Set(Some(hashed_password)),
+            password: ActiveValue::Set(Some(hashed_password.clone())),
             salt: ActiveValue::Set(None),
             email: ActiveValue::Set(request.email.clone()),
             phone: ActiveValue::Set(request.phone.clone()),
@@ -73,6 +68,8 @@ impl UserService {
             is_multi_login: ActiveValue::Set(request.is_multi_login.unwrap_or(false)),
             join_time: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             last_login_time: ActiveValue::Set(None),
+            password_changed_time: ActiveValue::Set(Some(chrono::Utc::now().naive_utc())),
+            locale: ActiveValue::Set(None),
             dept_id: ActiveValue::Set(request.dept_id),
             created_time: ActiveValue::NotSet,
             updated_time: ActiveValue::NotSet,
@@ -86,6 +83,7 @@ impl UserService {
                 "用户创建失败",
                 e.to_string(),
             ))?;
+        PasswordService::record_history(&self.db, created_user.id, hashed_password).await?;
 
         // TODO: 分配角色（关联用户-角色表）
 
@@ -258,6 +256,7 @@ impl UserService {
             dept_id: user.dept_id,
             dept: dept_name,
             roles: role_names,
+            locale: user.locale,
         })
     }
 
@@ -327,29 +326,8 @@ impl UserService {
             return Err(AppError::new(ErrorCode::PasswordError));
         }
 
-        // 3. 加密新密码
-        let hashed_new_password = CryptoUtils::hash_password(&request.new_password, None)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
-
-        // 4. 更新密码
-        let mut update_data = user::ActiveModel::default();
-        update_data.id = ActiveValue::Set(request.user_id);
-        update_data.password = ActiveValue::Set(Some(hashed_new_password));
-
-        UserRepo::update(request.user_id, update_data, &self.db)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::DatabaseError,
-                "密码更新失败",
-                e.to_string(),
-            ))?;
-
-        Ok(())
+        // 3. 校验密码策略并更新密码
+        PasswordService::set_password(&self.db, &user, &request.new_password).await
     }
 
     pub async fn reset_password(
@@ -357,32 +335,68 @@ impl UserService {
         request: &ResetPasswordRequest,
     ) -> Result<(), AppError> {
         // 1. 检查用户是否存在
-        let _ = UserRepo::find_by_id(request.user_id, &self.db)
+        let user = UserRepo::find_by_id(request.user_id, &self.db)
             .await
             .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
 
-        // 2. 加密新密码
-        let hashed_new_password = CryptoUtils::hash_password(&request.new_password, None)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
+        // 2. 校验密码策略并更新密码
+        PasswordService::set_password(&self.db, &user, &request.new_password).await
+    }
+
+    /// 修改本人昵称
+    pub async fn update_nickname(&self, user_id: i64, nickname: &str) -> Result<(), AppError> {
+        let mut update_data = user::ActiveModel::default();
+        update_data.nickname = ActiveValue::Set(nickname.trim().to_string());
+        UserRepo::update(user_id, update_data, &self.db).await?;
+        Ok(())
+    }
+
+    /// 修改本人偏好语言，传空时清除偏好并恢复按 Accept-Language 协商
+    pub async fn update_locale(&self, user_id: i64, locale: Option<&str>) -> Result<Option<String>, AppError> {
+        let locale = match locale.map(str::trim).filter(|l| !l.is_empty()) {
+            Some(tag) => Some(
+                crate::common::i18n::resolve(tag)
+                    .ok_or_else(|| AppError::with_message(ErrorCode::ValidationError, "不支持的语言"))?
+                    .to_string(),
+            ),
+            None => None,
+        };
 
-        // 3. 更新密码
         let mut update_data = user::ActiveModel::default();
-        update_data.id = ActiveValue::Set(request.user_id);
-        update_data.password = ActiveValue::Set(Some(hashed_new_password));
+        update_data.locale = ActiveValue::Set(locale.clone());
+        UserRepo::update(user_id, update_data, &self.db).await?;
+        crate::middleware::i18n_middleware::forget_user_locale(user_id);
+        Ok(locale)
+    }
 
-        UserRepo::update(request.user_id, update_data, &self.db)
+    /// 修改本人头像
+    pub async fn update_avatar(&self, user_id: i64, avatar: &str) -> Result<(), AppError> {
+        let mut update_data = user::ActiveModel::default();
+        update_data.avatar = ActiveValue::Set(Some(avatar.trim().to_string()));
+        UserRepo::update(user_id, update_data, &self.db).await?;
+        Ok(())
+    }
+
+    /// 修改本人手机号，需确认当前密码
+    ///
+    /// 系统未接入短信通道，无法像更换邮箱那样向新号码发送验证码，手机号只作为未经验证的联系信息保存，
+    /// 不得用于登录、找回密码等依赖号码归属的功能
+    pub async fn update_phone(&self, user_id: i64, phone: &str, password: &str) -> Result<(), AppError> {
+        let user = UserRepo::find_by_id(user_id, &self.db)
             .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::DatabaseError,
-                "密码重置失败",
-                e.to_string(),
-            ))?;
+            .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
+        PasswordService::verify_current_password(&user, password).await?;
+
+        let phone = phone.trim();
+        if let Ok(existing) = UserRepo::find_by_phone(phone, &self.db).await {
+            if existing.id != user_id {
+                return Err(AppError::with_message(ErrorCode::Conflict, "手机号已被使用"));
+            }
+        }
 
+        let mut update_data = user::ActiveModel::default();
+        update_data.phone = ActiveValue::Set(Some(phone.to_string()));
+        UserRepo::update(user_id, update_data, &self.db).await?;
         Ok(())
     }
 
@@ -891,13 +905,7 @@ impl UserService {
         }
 
         // 加密密码
-        let hashed_password = CryptoUtils::hash_password(&user_data.password, None)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
+        let hashed_password = PasswordService::hash_new_password(&user_data.password, &user_data.username).await?;
 
         // 创建用户数据
         let user_model = user::ActiveModel {
@@ -905,7 +913,7 @@ impl UserService {
             uuid: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
             username: ActiveValue::Set(user_data.username.clone()),
             nickname: ActiveValue::Set(user_data.nickname.clone()),
-            password: ActiveValue::Set(Some(hashed_password)),
+            password: ActiveValue::Set(Some(hashed_password.clone())),
             salt: ActiveValue::Set(None),
             email: ActiveValue::Set(user_data.email.clone()),
             phone: ActiveValue::Set(user_data.phone.clone()),
@@ -931,6 +939,8 @@ impl UserService {
             ),
             join_time: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             last_login_time: ActiveValue::Set(None),
+            password_changed_time: ActiveValue::Set(Some(chrono::Utc::now().naive_utc())),
+            locale: ActiveValue::Set(None),
             dept_id: ActiveValue::Set(None), // TODO: 根据部门名称查找部门ID
             created_time: ActiveValue::NotSet,
             updated_time: ActiveValue::NotSet,
@@ -945,6 +955,7 @@ impl UserService {
                 "用户创建失败",
                 e.to_string(),
             ))?;
+        PasswordService::record_history(&self.db, created_user.id, hashed_password).await?;
 
         // TODO: 分配角色
 
@@ -1062,32 +1073,11 @@ impl UserService {
         new_password: &str,
     ) -> Result<(), AppError> {
         // 1. 检查用户是否存在
-        let _ = UserRepo::find_by_id(user_id, &self.db)
+        let user = UserRepo::find_by_id(user_id, &self.db)
             .await
             .map_err(|_| AppError::new(ErrorCode::UserNotFound))?;
 
-        // 2. 加密新密码
-        let hashed_new_password = crate::utils::encrypt::CryptoUtils::hash_password(new_password, None)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
-
-        // 3. 更新密码
-        let mut update_data = user::ActiveModel::default();
-        update_data.id = ActiveValue::Set(user_id);
-        update_data.password = ActiveValue::Set(Some(hashed_new_password));
-
-        UserRepo::update(user_id, update_data, &self.db)
-            .await
-            .map_err(|e| AppError::with_details(
-                ErrorCode::DatabaseError,
-                "密码重置失败",
-                e.to_string(),
-            ))?;
-
-        Ok(())
+        // 2. 校验密码策略并更新密码
+        PasswordService::set_password(&self.db, &user, new_password).await
     }
 }
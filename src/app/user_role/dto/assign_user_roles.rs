@@ -1,9 +1,10 @@
 /// 分配用户角色 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct AssignUserRolesRequest {
     /// 用户ID
     pub user_id: i64,
@@ -13,7 +14,7 @@ pub struct AssignUserRolesRequest {
 }
 
 /// 分配用户角色响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AssignUserRolesResponse {
     /// 用户ID
     pub user_id: i64,
@@ -1,9 +1,10 @@
 /// 获取用户角色 DTO
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 获取用户角色响应
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GetUserRolesResponse {
     /// 用户ID
     pub user_id: i64,
@@ -12,7 +13,7 @@ pub struct GetUserRolesResponse {
 }
 
 /// 用户角色信息
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct UserRoleInfo {
     /// 角色ID
     pub id: i64,
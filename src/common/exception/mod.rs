@@ -24,6 +24,8 @@ pub enum ErrorCode {
     NotFound = 404,
     /// 资源冲突
     Conflict = 409,
+    /// 请求过于频繁
+    TooManyRequests = 429,
     /// 参数验证失败
     ValidationError = 422,
     /// 服务器内部错误
@@ -97,6 +99,7 @@ impl ErrorCode {
             ErrorCode::Forbidden => "禁止访问",
             ErrorCode::NotFound => "资源未找到",
             ErrorCode::Conflict => "资源冲突",
+            ErrorCode::TooManyRequests => "请求过于频繁，请稍后再试",
             ErrorCode::ValidationError => "参数验证失败",
             ErrorCode::InternalServerError => "服务器内部错误",
 
@@ -128,6 +131,48 @@ impl ErrorCode {
             ErrorCode::RedisError => "Redis操作失败",
         }
     }
+
+    /// 消息目录中的键名
+    pub fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::Success => "success",
+            ErrorCode::BadRequest => "bad_request",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::Forbidden => "forbidden",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::TooManyRequests => "too_many_requests",
+            ErrorCode::ValidationError => "validation_error",
+            ErrorCode::InternalServerError => "internal_server_error",
+
+            ErrorCode::AuthenticationFailed => "authentication_failed",
+            ErrorCode::LoginFailed => "login_failed",
+            ErrorCode::TokenExpired => "token_expired",
+            ErrorCode::TokenInvalid => "token_invalid",
+            ErrorCode::UserNotFound => "user_not_found",
+            ErrorCode::UserDisabled => "user_disabled",
+            ErrorCode::PasswordError => "password_error",
+
+            ErrorCode::PermissionDenied => "permission_denied",
+            ErrorCode::RoleNotFound => "role_not_found",
+            ErrorCode::PermissionNotFound => "permission_not_found",
+
+            ErrorCode::ResourceExists => "resource_exists",
+            ErrorCode::ResourceNotFound => "resource_not_found",
+
+            ErrorCode::DatabaseError => "database_error",
+            ErrorCode::TransactionError => "transaction_error",
+
+            ErrorCode::BusinessError => "business_error",
+            ErrorCode::OperationFailed => "operation_failed",
+
+            ErrorCode::IOError => "io_error",
+            ErrorCode::InvalidInput => "invalid_input",
+            ErrorCode::SerializationError => "serialization_error",
+
+            ErrorCode::RedisError => "redis_error",
+        }
+    }
 }
 
 impl fmt::Display for ErrorCode {
@@ -181,6 +226,7 @@ impl AppError {
             ErrorCode::Forbidden => StatusCode::FORBIDDEN,
             ErrorCode::NotFound => StatusCode::NOT_FOUND,
             ErrorCode::Conflict => StatusCode::CONFLICT,
+            ErrorCode::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
             ErrorCode::InternalServerError | ErrorCode::DatabaseError | ErrorCode::TransactionError | ErrorCode::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
             
             // IO和系统错误返回 400
@@ -210,7 +256,7 @@ impl IntoResponse for AppError {
 
         let response: crate::common::response::ResponseModel<()> = crate::common::response::ResponseModel {
             code: self.code.code(),
-            msg: self.message,
+            msg: crate::common::i18n::localize_error(self.code, &self.message),
             data: None,
         };
 
@@ -268,6 +314,16 @@ impl From<serde_json::Error> for AppError {
     }
 }
 
+impl From<validator::ValidationErrors> for AppError {
+    fn from(err: validator::ValidationErrors) -> Self {
+        Self::with_details(
+            ErrorCode::ValidationError,
+            crate::common::i18n::localize_validation(&err),
+            err.to_string(),
+        )
+    }
+}
+
 impl From<jsonwebtoken::errors::Error> for AppError {
     fn from(err: jsonwebtoken::errors::Error) -> Self {
         Self::with_details(
@@ -0,0 +1,286 @@
+/// 国际化模块
+/// 提供消息目录、Accept-Language 协商和请求级语言上下文
+///
+/// 目录按 `分组.键` 扁平化：`errors.<错误码>`、`validation.*`、`password.*`、`email.*`，
+/// `messages.<中文原文>` 用于翻译业务代码中的自定义错误消息。
+/// 内置 zh-CN 和 en-US，`locale/` 目录下的 `<语言>.json` 会覆盖或新增条目；
+/// 当前语言缺失的键回退到默认语言，默认语言也缺失时使用原文。
+
+use std::{collections::HashMap, fmt::Display, future::Future};
+
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::common::exception::ErrorCode;
+use crate::core::{path_conf::LOCALE_DIR, SETTINGS};
+
+/// 内置消息目录
+const BUILTIN_CATALOGS: &[(&str, &str)] = &[
+    ("zh-CN", include_str!("locales/zh-CN.json")),
+    ("en-US", include_str!("locales/en-US.json")),
+];
+
+tokio::task_local! {
+    /// 当前请求协商出的语言
+    static LOCALE: String;
+}
+
+static CATALOGS: Lazy<HashMap<String, HashMap<String, String>>> = Lazy::new(load_catalogs);
+
+fn load_catalogs() -> HashMap<String, HashMap<String, String>> {
+    let mut catalogs: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for (locale, content) in BUILTIN_CATALOGS {
+        match serde_json::from_str::<Value>(content) {
+            Ok(value) => flatten("", &value, catalogs.entry(locale.to_string()).or_default()),
+            Err(e) => warn!("内置语言目录解析失败 {}: {}", locale, e),
+        }
+    }
+
+    if let Ok(entries) = std::fs::read_dir(&*LOCALE_DIR) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()));
+            match parsed {
+                Ok(value) => flatten("", &value, catalogs.entry(locale.to_string()).or_default()),
+                Err(e) => warn!("语言目录加载失败 {}: {}", path.display(), e),
+            }
+        }
+    }
+    catalogs
+}
+
+fn flatten(prefix: &str, value: &Value, out: &mut HashMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if prefix.is_empty() {
+                    flatten(key, value, out);
+                } else {
+                    flatten(&format!("{}.{}", prefix, key), value, out);
+                }
+            }
+        }
+        Value::String(text) => {
+            out.insert(prefix.to_string(), text.clone());
+        }
+        _ => {}
+    }
+}
+
+/// 默认语言
+pub fn default_locale() -> &'static str {
+    &SETTINGS.i18n_default_locale
+}
+
+/// 已加载的语言列表
+pub fn supported_locales() -> Vec<&'static str> {
+    let mut locales: Vec<&'static str> = CATALOGS.keys().map(String::as_str).collect();
+    locales.sort_unstable();
+    locales
+}
+
+/// 当前请求的语言，不在请求上下文中时为默认语言
+pub fn current_locale() -> String {
+    LOCALE
+        .try_with(|locale| locale.clone())
+        .unwrap_or_else(|_| default_locale().to_string())
+}
+
+/// 在指定语言上下文中执行
+pub async fn scope<F: Future>(locale: String, future: F) -> F::Output {
+    LOCALE.scope(locale, future).await
+}
+
+/// 将语言标签映射为已加载的语言：先精确匹配（忽略大小写），再按主语言匹配
+pub fn resolve(tag: &str) -> Option<&'static str> {
+    let tag = tag.trim().replace('_', "-");
+    if tag.is_empty() || tag == "*" {
+        return None;
+    }
+    let locales = supported_locales();
+    if let Some(locale) = locales.iter().find(|locale| locale.eq_ignore_ascii_case(&tag)) {
+        return Some(*locale);
+    }
+
+    let primary = tag.split('-').next().unwrap_or_default();
+    let same_language = |locale: &&&str| {
+        locale.split('-').next().is_some_and(|lang| lang.eq_ignore_ascii_case(primary))
+    };
+    // 同一主语言有多个目录时优先默认语言
+    locales
+        .iter()
+        .filter(same_language)
+        .find(|locale| **locale == default_locale())
+        .or_else(|| locales.iter().find(same_language))
+        .copied()
+}
+
+/// 按 Accept-Language（含 q 权重）协商语言，无法匹配时返回默认语言
+pub fn negotiate(accept_language: Option<&str>) -> String {
+    let mut candidates: Vec<(&str, f32)> = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let tag = pieces.next()?.trim();
+            let quality = pieces
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(1.0, |q| q.trim().parse::<f32>().unwrap_or(0.0));
+            (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+    // 稳定排序，同权重时保持客户端给出的顺序
+    candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    candidates
+        .into_iter()
+        .find_map(|(tag, _)| resolve(tag))
+        .unwrap_or_else(default_locale)
+        .to_string()
+}
+
+/// 在指定语言中查找，缺失时回退到默认语言
+pub fn lookup(locale: &str, key: &str) -> Option<&'static str> {
+    let find = |locale: &str| CATALOGS.get(locale).and_then(|catalog| catalog.get(key)).map(String::as_str);
+    find(locale).or_else(|| find(default_locale()))
+}
+
+/// 按当前语言翻译，找不到时返回键本身
+pub fn t(key: &str) -> String {
+    lookup(&current_locale(), key).unwrap_or(key).to_string()
+}
+
+/// 按当前语言翻译并替换 `{name}` 占位符
+pub fn t_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    format_args_into(&t(key), args)
+}
+
+fn format_args_into(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+/// 本地化错误消息
+///
+/// 使用错误码默认消息时按 `errors.<错误码>` 翻译，自定义消息按 `messages.<原文>` 翻译，
+/// 都找不到时返回原文
+pub fn localize_error(code: ErrorCode, message: &str) -> String {
+    let locale = current_locale();
+    let key = if message == code.message() {
+        format!("errors.{}", code.key())
+    } else {
+        format!("messages.{}", message)
+    };
+    lookup(&locale, &key).unwrap_or(message).to_string()
+}
+
+/// 将 validator 的校验错误渲染为当前语言的字段消息
+pub fn localize_validation(errors: &validator::ValidationErrors) -> String {
+    let locale = current_locale();
+    let mut fields: Vec<_> = errors.field_errors().into_iter().collect();
+    fields.sort_by_key(|(field, _)| *field);
+
+    let rendered: Vec<String> = fields
+        .into_iter()
+        .flat_map(|(field, field_errors)| {
+            let locale = locale.as_str();
+            field_errors.iter().map(move |error| {
+                let message = validation_message(locale, error);
+                format_args_into(
+                    lookup(locale, "validation.field").unwrap_or("{field}: {message}"),
+                    &[("field", &field), ("message", &message)],
+                )
+            })
+        })
+        .collect();
+    rendered.join(lookup(&locale, "validation.separator").unwrap_or("; "))
+}
+
+fn validation_message(locale: &str, error: &validator::ValidationError) -> String {
+    // DTO 上声明的自定义消息（中文原文）：当前语言有译文时使用译文，默认语言直接使用原文
+    if let Some(message) = &error.message {
+        let translated = CATALOGS
+            .get(locale)
+            .and_then(|catalog| catalog.get(&format!("messages.{}", message)));
+        if let Some(translated) = translated {
+            return translated.clone();
+        }
+        if locale == default_locale() {
+            return message.to_string();
+        }
+    }
+
+    let param = |name: &str| error.params.get(name).map(|value| match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    });
+    let (min, max, equal) = (param("min"), param("max"), param("equal"));
+    let key = match (error.code.as_ref(), &min, &max, &equal) {
+        ("length", _, _, Some(_)) => "validation.length_equal",
+        ("length", Some(_), Some(_), _) => "validation.length_between",
+        ("length", Some(_), None, _) => "validation.length_min",
+        ("length", None, Some(_), _) => "validation.length_max",
+        ("range", Some(_), Some(_), _) => "validation.range_between",
+        ("range", Some(_), None, _) => "validation.range_min",
+        ("range", None, Some(_), _) => "validation.range_max",
+        ("email", ..) => "validation.email",
+        ("url", ..) => "validation.url",
+        ("required", ..) => "validation.required",
+        ("must_match", ..) => "validation.must_match",
+        ("regex", ..) => "validation.regex",
+        _ => "validation.invalid",
+    };
+    let (min, max, equal, other) = (
+        min.unwrap_or_default(),
+        max.unwrap_or_default(),
+        equal.unwrap_or_default(),
+        param("other").unwrap_or_default(),
+    );
+    format_args_into(
+        lookup(locale, key).unwrap_or(key),
+        &[("min", &min), ("max", &max), ("equal", &equal), ("other", &other)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate(Some("en-US,en;q=0.9")), "en-US");
+        assert_eq!(negotiate(Some("fr;q=1.0, en-GB;q=0.8, zh;q=0.5")), "en-US");
+        assert_eq!(negotiate(Some("zh-cn, en;q=0")), "zh-CN");
+        assert_eq!(negotiate(Some("fr")), default_locale());
+        assert_eq!(negotiate(None), default_locale());
+    }
+
+    #[test]
+    fn test_catalogs_complete() {
+        let zh = &CATALOGS["zh-CN"];
+        let en = &CATALOGS["en-US"];
+        let missing: Vec<&String> = zh.keys().filter(|key| !en.contains_key(*key)).collect();
+        assert!(missing.is_empty(), "en-US 缺少键: {:?}", missing);
+    }
+
+    #[tokio::test]
+    async fn test_localize_with_fallback() {
+        scope("en-US".to_string(), async {
+            assert_eq!(localize_error(ErrorCode::TokenInvalid, ErrorCode::TokenInvalid.message()), "Invalid token");
+            assert_eq!(localize_error(ErrorCode::Conflict, "邮箱已被使用"), "Email is already in use");
+            assert_eq!(localize_error(ErrorCode::NotFound, "Menu not found"), "Menu not found");
+            assert_eq!(t("no.such.key"), "no.such.key");
+        })
+        .await;
+        assert_eq!(current_locale(), default_locale());
+    }
+}
@@ -6,3 +6,4 @@ pub mod exception;
 pub mod security;
 pub mod enums;
 pub mod pagination;
+pub mod i18n;
@@ -1,9 +1,10 @@
 /// 分页相关结构体和工具函数
 
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 
 /// 分页链接
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PaginationLinks {
     /// 首页链接
     pub first: String,
@@ -19,9 +20,10 @@ pub struct PaginationLinks {
 }
 
 /// 分页数据 - 与 Python 版本完全一致
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PageData<T> {
     /// 当前页数据列表
+    #[schema(inline)]
     pub items: Vec<T>,
     /// 数据总条数
     pub total: i64,
@@ -0,0 +1,164 @@
+/// 密码策略
+///
+/// 按配置校验密码长度、字符类型，并拒绝常见弱密码和包含用户名的密码。
+/// 弱密码列表内置一份常见密码，可通过 `PASSWORD_BLOCKLIST_FILE` 追加。
+
+use chrono::{Duration, NaiveDateTime};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use tracing::warn;
+
+use crate::common::exception::{AppError, ErrorCode};
+use crate::common::i18n;
+use crate::core::SETTINGS;
+
+/// 内置常见弱密码列表
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
+
+/// 弱密码集合（小写），首次使用时加载
+static BLOCKLIST: Lazy<HashSet<String>> = Lazy::new(|| {
+    let mut set = parse_blocklist(COMMON_PASSWORDS);
+    if let Some(path) = SETTINGS.password_blocklist_file.as_deref() {
+        match std::fs::read_to_string(path) {
+            Ok(content) => set.extend(parse_blocklist(&content)),
+            Err(e) => warn!("弱密码列表文件读取失败 {}: {}", path, e),
+        }
+    }
+    set
+});
+
+fn parse_blocklist(content: &str) -> HashSet<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// 密码策略
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    /// 密码有效天数，0 表示永不过期
+    pub expire_days: i64,
+}
+
+impl PasswordPolicy {
+    /// 从配置读取策略
+    pub fn from_settings() -> Self {
+        Self {
+            min_length: SETTINGS.password_min_length,
+            max_length: SETTINGS.password_max_length,
+            require_lowercase: SETTINGS.password_require_lowercase,
+            require_uppercase: SETTINGS.password_require_uppercase,
+            require_digit: SETTINGS.password_require_digit,
+            require_special: SETTINGS.password_require_special,
+            expire_days: SETTINGS.password_expire_days,
+        }
+    }
+
+    /// 校验密码，返回所有不满足的规则
+    pub fn violations(&self, password: &str, username: Option<&str>) -> Vec<String> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            violations.push(i18n::t_args("password.min_length", &[("min", &self.min_length)]));
+        }
+        if self.max_length > 0 && length > self.max_length {
+            violations.push(i18n::t_args("password.max_length", &[("max", &self.max_length)]));
+        }
+        if self.require_lowercase && !password.chars().any(|c| c.is_ascii_lowercase()) {
+            violations.push(i18n::t("password.require_lowercase"));
+        }
+        if self.require_uppercase && !password.chars().any(|c| c.is_ascii_uppercase()) {
+            violations.push(i18n::t("password.require_uppercase"));
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            violations.push(i18n::t("password.require_digit"));
+        }
+        if self.require_special && !password.chars().any(|c| !c.is_ascii_alphanumeric() && !c.is_whitespace()) {
+            violations.push(i18n::t("password.require_special"));
+        }
+
+        let lower = password.to_lowercase();
+        if BLOCKLIST.contains(&lower) {
+            violations.push(i18n::t("password.too_common"));
+        }
+        if let Some(username) = username.filter(|u| u.chars().count() >= 3) {
+            if lower.contains(&username.to_lowercase()) {
+                violations.push(i18n::t("password.contains_username"));
+            }
+        }
+        violations
+    }
+
+    /// 校验密码，不满足策略时返回参数验证错误
+    pub fn check(&self, password: &str, username: Option<&str>) -> Result<(), AppError> {
+        let violations = self.violations(password, username);
+        if violations.is_empty() {
+            return Ok(());
+        }
+        Err(AppError::with_details(
+            ErrorCode::ValidationError,
+            violations[0].clone(),
+            violations.join(&i18n::t("password.separator")),
+        ))
+    }
+
+    /// 密码是否已过期；未记录修改时间时视为未过期
+    pub fn is_expired(&self, changed_time: Option<NaiveDateTime>, now: NaiveDateTime) -> bool {
+        if self.expire_days <= 0 {
+            return false;
+        }
+        changed_time.is_some_and(|changed| now - changed >= Duration::days(self.expire_days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PasswordPolicy {
+        PasswordPolicy {
+            min_length: 8,
+            max_length: 16,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_special: false,
+            expire_days: 90,
+        }
+    }
+
+    #[test]
+    fn test_violations() {
+        let policy = policy();
+        assert!(policy.violations("Tr0ub4dor", Some("alice")).is_empty());
+
+        assert_eq!(policy.violations("Ab1", None).len(), 1);
+        assert_eq!(policy.violations("abcdefgh1", None), vec!["密码必须包含大写字母"]);
+        assert_eq!(policy.violations("Abcdefghijklmnop1", None), vec!["密码长度不能超过 16 位"]);
+        // 常见密码不区分大小写
+        assert_eq!(policy.violations("Password123", None), vec!["密码过于常见，请更换"]);
+        assert_eq!(policy.violations("xAlice2026", Some("alice")), vec!["密码不能包含用户名"]);
+        assert!(policy.check("abc", None).is_err());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let policy = policy();
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 10, 18).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        assert!(policy.is_expired(Some(now - Duration::days(90)), now));
+        assert!(!policy.is_expired(Some(now - Duration::days(89)), now));
+        assert!(!policy.is_expired(None, now));
+        assert!(!PasswordPolicy { expire_days: 0, ..policy }.is_expired(Some(now - Duration::days(999)), now));
+    }
+}
@@ -96,6 +96,10 @@ pub struct Settings {
     #[serde(default = "default_openapi_url")]
     #[serde(alias = "FASTAPI_OPENAPI_URL", alias = "FBA_OPENAPI_URL")]
     pub openapi_url: Option<String>,
+    /// 是否开放 API 文档（Swagger UI、ReDoc 和 OpenAPI JSON），未配置时生产环境关闭
+    #[serde(default = "default_docs_enabled")]
+    #[serde(alias = "FASTAPI_DOCS_ENABLED", alias = "FBA_DOCS_ENABLED")]
+    pub docs_enabled: Option<bool>,
     /// 是否启用静态文件
     #[serde(default = "default_static_files")]
     #[serde(alias = "FASTAPI_STATIC_FILES", alias = "FBA_STATIC_FILES")]
@@ -182,10 +186,14 @@ pub struct Settings {
     #[serde(default = "default_token_secret_key")]
     #[serde(alias = "TOKEN_SECRET_KEY", alias = "FBA_TOKEN_SECRET_KEY")]
     pub token_secret_key: String,
-    /// JWT 算法
+    /// JWT 算法（RS256 / ES256 / EdDSA 使用轮换的非对称密钥签名，HS256 使用 token_secret_key）
     #[serde(default = "default_token_algorithm")]
     #[serde(alias = "TOKEN_ALGORITHM", alias = "FBA_TOKEN_ALGORITHM")]
     pub token_algorithm: String,
+    /// 签名密钥轮换周期（秒）
+    #[serde(default = "default_token_key_rotation_seconds")]
+    #[serde(alias = "TOKEN_KEY_ROTATION_SECONDS", alias = "FBA_TOKEN_KEY_ROTATION_SECONDS")]
+    pub token_key_rotation_seconds: i64,
     /// Access Token 过期时间（秒）
     #[serde(default = "default_token_expire_seconds")]
     #[serde(alias = "TOKEN_EXPIRE_SECONDS", alias = "FBA_TOKEN_EXPIRE_SECONDS")]
@@ -239,6 +247,74 @@ pub struct Settings {
     #[serde(alias = "CAPTCHA_LOGIN_EXPIRE_SECONDS", alias = "FBA_CAPTCHA_EXPIRE_SECONDS")]
     pub captcha_expire_seconds: i64,
 
+    // ===== 密码策略配置 =====
+    /// 密码最小长度
+    #[serde(default = "default_password_min_length")]
+    #[serde(alias = "PASSWORD_MIN_LENGTH", alias = "FBA_PASSWORD_MIN_LENGTH")]
+    pub password_min_length: usize,
+    /// 密码最大长度
+    #[serde(default = "default_password_max_length")]
+    #[serde(alias = "PASSWORD_MAX_LENGTH", alias = "FBA_PASSWORD_MAX_LENGTH")]
+    pub password_max_length: usize,
+    /// 是否要求包含小写字母
+    #[serde(default = "default_password_require_lowercase")]
+    #[serde(alias = "PASSWORD_REQUIRE_LOWERCASE", alias = "FBA_PASSWORD_REQUIRE_LOWERCASE")]
+    pub password_require_lowercase: bool,
+    /// 是否要求包含大写字母
+    #[serde(default = "default_password_require_uppercase")]
+    #[serde(alias = "PASSWORD_REQUIRE_UPPERCASE", alias = "FBA_PASSWORD_REQUIRE_UPPERCASE")]
+    pub password_require_uppercase: bool,
+    /// 是否要求包含数字
+    #[serde(default = "default_password_require_digit")]
+    #[serde(alias = "PASSWORD_REQUIRE_DIGIT", alias = "FBA_PASSWORD_REQUIRE_DIGIT")]
+    pub password_require_digit: bool,
+    /// 是否要求包含特殊字符
+    #[serde(default = "default_password_require_special")]
+    #[serde(alias = "PASSWORD_REQUIRE_SPECIAL", alias = "FBA_PASSWORD_REQUIRE_SPECIAL")]
+    pub password_require_special: bool,
+    /// 额外的弱密码列表文件（每行一个），与内置列表合并
+    #[serde(default)]
+    #[serde(alias = "PASSWORD_BLOCKLIST_FILE", alias = "FBA_PASSWORD_BLOCKLIST_FILE")]
+    pub password_blocklist_file: Option<String>,
+    /// 禁止重复使用最近几次的密码（0 不限制）
+    #[serde(default = "default_password_history_count")]
+    #[serde(alias = "PASSWORD_HISTORY_COUNT", alias = "FBA_PASSWORD_HISTORY_COUNT")]
+    pub password_history_count: u64,
+    /// 密码有效天数，过期后登录需先修改密码（0 永不过期）
+    #[serde(default = "default_password_expire_days")]
+    #[serde(alias = "PASSWORD_EXPIRE_DAYS", alias = "FBA_PASSWORD_EXPIRE_DAYS")]
+    pub password_expire_days: i64,
+
+    // ===== 账号自助服务配置 =====
+    /// 注册验证、邮箱验证和密码重置的 Redis 前缀
+    #[serde(default = "default_account_redis_prefix")]
+    #[serde(alias = "ACCOUNT_REDIS_PREFIX", alias = "FBA_ACCOUNT_REDIS_PREFIX")]
+    pub account_redis_prefix: String,
+    /// 验证码有效期（秒）
+    #[serde(default = "default_account_code_expire_seconds")]
+    #[serde(alias = "ACCOUNT_CODE_EXPIRE_SECONDS", alias = "FBA_ACCOUNT_CODE_EXPIRE_SECONDS")]
+    pub account_code_expire_seconds: u64,
+    /// 同一地址重新发送的最小间隔（秒）
+    #[serde(default = "default_account_code_resend_seconds")]
+    #[serde(alias = "ACCOUNT_CODE_RESEND_SECONDS", alias = "FBA_ACCOUNT_CODE_RESEND_SECONDS")]
+    pub account_code_resend_seconds: u64,
+    /// 验证码最多尝试次数，超过后作废
+    #[serde(default = "default_account_code_max_attempts")]
+    #[serde(alias = "ACCOUNT_CODE_MAX_ATTEMPTS", alias = "FBA_ACCOUNT_CODE_MAX_ATTEMPTS")]
+    pub account_code_max_attempts: u32,
+    /// 密码重置令牌有效期（秒）
+    #[serde(default = "default_account_reset_token_expire_seconds")]
+    #[serde(alias = "ACCOUNT_RESET_TOKEN_EXPIRE_SECONDS", alias = "FBA_ACCOUNT_RESET_TOKEN_EXPIRE_SECONDS")]
+    pub account_reset_token_expire_seconds: u64,
+    /// 前端注册验证页面地址，邮件中的链接附加 `?token=`
+    #[serde(default)]
+    #[serde(alias = "ACCOUNT_VERIFY_URL", alias = "FBA_ACCOUNT_VERIFY_URL")]
+    pub account_verify_url: Option<String>,
+    /// 前端密码重置页面地址，邮件中的链接附加 `?token=`
+    #[serde(default)]
+    #[serde(alias = "ACCOUNT_RESET_URL", alias = "FBA_ACCOUNT_RESET_URL")]
+    pub account_reset_url: Option<String>,
+
     // ===== RBAC 配置 =====
     /// 是否启用角色菜单模式
     #[serde(default = "default_rbac_role_menu_mode")]
@@ -265,6 +341,72 @@ pub struct Settings {
     #[serde(alias = "WS_NO_AUTH_MARKER", alias = "FBA_WS_NO_AUTH_MARKER")]
     pub ws_no_auth_marker: Option<String>,
 
+    /// WebSocket 跨实例广播的 Redis 频道
+    #[serde(default = "default_ws_redis_channel")]
+    #[serde(alias = "WS_REDIS_CHANNEL", alias = "FBA_WS_REDIS_CHANNEL")]
+    pub ws_redis_channel: String,
+
+    /// WebSocket 监控频道推送间隔（秒）
+    #[serde(default = "default_ws_monitor_interval_seconds")]
+    #[serde(alias = "WS_MONITOR_INTERVAL_SECONDS", alias = "FBA_WS_MONITOR_INTERVAL_SECONDS")]
+    pub ws_monitor_interval_seconds: u64,
+
+    // ===== Prometheus 指标配置 =====
+    /// /metrics 访问令牌（Authorization: Bearer <token>）
+    #[serde(default)]
+    #[serde(alias = "METRICS_TOKEN", alias = "FBA_METRICS_TOKEN")]
+    pub metrics_token: Option<String>,
+    /// 无需令牌即可访问 /metrics 的客户端 IP
+    #[serde(default = "default_metrics_allow_ips")]
+    #[serde(alias = "METRICS_ALLOW_IPS", alias = "FBA_METRICS_ALLOW_IPS")]
+    pub metrics_allow_ips: Vec<String>,
+
+    // ===== 系统指标采集配置 =====
+    /// 是否启用内置系统指标采集（CPU、内存、磁盘、网络）
+    #[serde(default = "default_system_metric_enabled")]
+    #[serde(alias = "SYSTEM_METRIC_ENABLED", alias = "FBA_SYSTEM_METRIC_ENABLED")]
+    pub system_metric_enabled: bool,
+    /// 采集间隔（秒）
+    #[serde(default = "default_system_metric_interval_seconds")]
+    #[serde(alias = "SYSTEM_METRIC_INTERVAL_SECONDS", alias = "FBA_SYSTEM_METRIC_INTERVAL_SECONDS")]
+    pub system_metric_interval_seconds: u64,
+    /// 原始采样保留天数
+    #[serde(default = "default_system_metric_raw_retention_days")]
+    #[serde(alias = "SYSTEM_METRIC_RAW_RETENTION_DAYS", alias = "FBA_SYSTEM_METRIC_RAW_RETENTION_DAYS")]
+    pub system_metric_raw_retention_days: i32,
+    /// 小时汇总保留天数
+    #[serde(default = "default_system_metric_hourly_retention_days")]
+    #[serde(alias = "SYSTEM_METRIC_HOURLY_RETENTION_DAYS", alias = "FBA_SYSTEM_METRIC_HOURLY_RETENTION_DAYS")]
+    pub system_metric_hourly_retention_days: i32,
+    /// 天汇总保留天数
+    #[serde(default = "default_system_metric_daily_retention_days")]
+    #[serde(alias = "SYSTEM_METRIC_DAILY_RETENTION_DAYS", alias = "FBA_SYSTEM_METRIC_DAILY_RETENTION_DAYS")]
+    pub system_metric_daily_retention_days: i32,
+
+    // ===== 告警配置 =====
+    /// 是否启用告警规则定时评估
+    #[serde(default = "default_alert_enabled")]
+    #[serde(alias = "ALERT_ENABLED", alias = "FBA_ALERT_ENABLED")]
+    pub alert_enabled: bool,
+    /// 评估间隔（秒）
+    #[serde(default = "default_alert_evaluate_interval_seconds")]
+    #[serde(alias = "ALERT_EVALUATE_INTERVAL_SECONDS", alias = "FBA_ALERT_EVALUATE_INTERVAL_SECONDS")]
+    pub alert_evaluate_interval_seconds: u64,
+
+    // ===== 健康检查配置 =====
+    /// 探针中每项依赖检查的超时时间（毫秒）
+    #[serde(default = "default_health_check_timeout_ms")]
+    #[serde(alias = "HEALTH_CHECK_TIMEOUT_MS", alias = "FBA_HEALTH_CHECK_TIMEOUT_MS")]
+    pub health_check_timeout_ms: u64,
+    /// 工作目录所在磁盘的最低剩余空间比例（%），低于该值时就绪探针失败
+    #[serde(default = "default_health_disk_min_free_percent")]
+    #[serde(alias = "HEALTH_DISK_MIN_FREE_PERCENT", alias = "FBA_HEALTH_DISK_MIN_FREE_PERCENT")]
+    pub health_disk_min_free_percent: f64,
+    /// 收到停机信号后、停止接收新连接前的等待时间（秒），期间就绪探针返回 503
+    #[serde(default = "default_shutdown_drain_seconds")]
+    #[serde(alias = "SHUTDOWN_DRAIN_SECONDS", alias = "FBA_SHUTDOWN_DRAIN_SECONDS")]
+    pub shutdown_drain_seconds: u64,
+
     // ===== 日志配置 =====
     /// 调试模式（开启后显示详细日志：路由、SQL、响应时间等）
     #[serde(default = "default_debug_mode")]
@@ -321,12 +463,54 @@ pub struct Settings {
     #[serde(default = "default_rate_limit_redis_prefix")]
     #[serde(alias = "REQUEST_LIMITER_REDIS_PREFIX", alias = "FBA_RATE_LIMIT_REDIS_PREFIX")]
     pub rate_limit_redis_prefix: String,
+    /// 是否启用请求限流
+    #[serde(default = "default_rate_limit_enabled")]
+    #[serde(alias = "RATE_LIMIT_ENABLED", alias = "FBA_RATE_LIMIT_ENABLED")]
+    pub rate_limit_enabled: bool,
+    /// 限流规则文件（TOML），不存在时使用内置规则
+    #[serde(default = "default_rate_limit_rules_file")]
+    #[serde(alias = "RATE_LIMIT_RULES_FILE", alias = "FBA_RATE_LIMIT_RULES_FILE")]
+    pub rate_limit_rules_file: String,
+    /// 按 API Key 限流时读取的请求头
+    #[serde(default = "default_rate_limit_api_key_header")]
+    #[serde(alias = "RATE_LIMIT_API_KEY_HEADER", alias = "FBA_RATE_LIMIT_API_KEY_HEADER")]
+    pub rate_limit_api_key_header: String,
+    /// 已签发 API Key 的 SHA256 摘要（十六进制），只有匹配的 API Key 才单独计数
+    #[serde(default)]
+    #[serde(alias = "RATE_LIMIT_API_KEYS", alias = "FBA_RATE_LIMIT_API_KEYS")]
+    pub rate_limit_api_keys: Vec<String>,
+    /// 受信任的反向代理 IP，仅来自这些地址的请求才读取 X-Forwarded-For / X-Real-IP
+    #[serde(default)]
+    #[serde(alias = "RATE_LIMIT_TRUSTED_PROXIES", alias = "FBA_RATE_LIMIT_TRUSTED_PROXIES")]
+    pub rate_limit_trusted_proxies: Vec<String>,
     
     // ===== 插件配置 =====
     /// 插件 Redis 前缀
     #[serde(default = "default_plugin_redis_prefix")]
     #[serde(alias = "PLUGIN_REDIS_PREFIX", alias = "FBA_PLUGIN_REDIS_PREFIX")]
     pub plugin_redis_prefix: String,
+    /// 插件受信任公钥目录（{key_id}.pem）
+    #[serde(default = "default_plugin_trusted_keys_dir")]
+    #[serde(alias = "PLUGIN_TRUSTED_KEYS_DIR", alias = "FBA_PLUGIN_TRUSTED_KEYS_DIR")]
+    pub plugin_trusted_keys_dir: String,
+
+    // ===== OIDC 授权服务配置 =====
+    /// 签发者标识（对外可访问的服务根地址）
+    #[serde(default = "default_oidc_issuer")]
+    #[serde(alias = "OIDC_ISSUER", alias = "FBA_OIDC_ISSUER")]
+    pub oidc_issuer: String,
+    /// 前端登录页（未登录用户访问授权端点时跳转，登录后携带 token 重新请求授权端点）
+    #[serde(default = "default_oidc_login_url")]
+    #[serde(alias = "OIDC_LOGIN_URL", alias = "FBA_OIDC_LOGIN_URL")]
+    pub oidc_login_url: String,
+    /// OIDC Redis 前缀（授权码、刷新令牌索引）
+    #[serde(default = "default_oidc_redis_prefix")]
+    #[serde(alias = "OIDC_REDIS_PREFIX", alias = "FBA_OIDC_REDIS_PREFIX")]
+    pub oidc_redis_prefix: String,
+    /// 授权码有效期（秒）
+    #[serde(default = "default_oidc_code_expire_seconds")]
+    #[serde(alias = "OIDC_CODE_EXPIRE_SECONDS", alias = "FBA_OIDC_CODE_EXPIRE_SECONDS")]
+    pub oidc_code_expire_seconds: i64,
 
     // ===== 中间件配置 =====
     /// 是否启用 CORS
@@ -345,6 +529,10 @@ pub struct Settings {
     #[serde(default = "default_middleware_i18n")]
     #[serde(alias = "MIDDLEWARE_I18N", alias = "FBA_MIDDLEWARE_I18N")]
     pub middleware_i18n: bool,
+    /// 默认语言，请求未指定语言或语言不受支持时使用
+    #[serde(default = "default_i18n_default_locale")]
+    #[serde(alias = "I18N_DEFAULT_LOCALE", alias = "FBA_I18N_DEFAULT_LOCALE")]
+    pub i18n_default_locale: String,
 
     // ===== 操作日志配置 =====
     /// 操作日志加密密钥
@@ -397,6 +585,7 @@ impl Default for Settings {
             docs_url: default_docs_url(),
             redoc_url: default_redoc_url(),
             openapi_url: default_openapi_url(),
+            docs_enabled: default_docs_enabled(),
             static_files: default_static_files(),
 
             database_type: default_database_type(),
@@ -421,6 +610,7 @@ impl Default for Settings {
 
             token_secret_key: default_token_secret_key(),
             token_algorithm: default_token_algorithm(),
+            token_key_rotation_seconds: default_token_key_rotation_seconds(),
             token_expire_seconds: default_token_expire_seconds(),
             token_refresh_expire_seconds: default_token_refresh_expire_seconds(),
             token_redis_prefix: default_token_redis_prefix(),
@@ -443,6 +633,42 @@ impl Default for Settings {
             data_permission_column_exclude: default_data_permission_column_exclude(),
 
             ws_no_auth_marker: None,
+            ws_redis_channel: default_ws_redis_channel(),
+            ws_monitor_interval_seconds: default_ws_monitor_interval_seconds(),
+
+            metrics_token: None,
+            metrics_allow_ips: default_metrics_allow_ips(),
+
+            system_metric_enabled: default_system_metric_enabled(),
+            system_metric_interval_seconds: default_system_metric_interval_seconds(),
+            system_metric_raw_retention_days: default_system_metric_raw_retention_days(),
+            system_metric_hourly_retention_days: default_system_metric_hourly_retention_days(),
+            system_metric_daily_retention_days: default_system_metric_daily_retention_days(),
+
+            alert_enabled: default_alert_enabled(),
+            alert_evaluate_interval_seconds: default_alert_evaluate_interval_seconds(),
+
+            health_check_timeout_ms: default_health_check_timeout_ms(),
+            health_disk_min_free_percent: default_health_disk_min_free_percent(),
+            shutdown_drain_seconds: default_shutdown_drain_seconds(),
+
+            password_min_length: default_password_min_length(),
+            password_max_length: default_password_max_length(),
+            password_require_lowercase: default_password_require_lowercase(),
+            password_require_uppercase: default_password_require_uppercase(),
+            password_require_digit: default_password_require_digit(),
+            password_require_special: default_password_require_special(),
+            password_blocklist_file: None,
+            password_history_count: default_password_history_count(),
+            password_expire_days: default_password_expire_days(),
+
+            account_redis_prefix: default_account_redis_prefix(),
+            account_code_expire_seconds: default_account_code_expire_seconds(),
+            account_code_resend_seconds: default_account_code_resend_seconds(),
+            account_code_max_attempts: default_account_code_max_attempts(),
+            account_reset_token_expire_seconds: default_account_reset_token_expire_seconds(),
+            account_verify_url: None,
+            account_reset_url: None,
 
             debug_mode: default_debug_mode(),
             log_level: default_log_level(),
@@ -459,12 +685,24 @@ impl Default for Settings {
             ip_location_mode: default_ip_location_mode(),
 
             rate_limit_redis_prefix: default_rate_limit_redis_prefix(),
+            rate_limit_enabled: default_rate_limit_enabled(),
+            rate_limit_rules_file: default_rate_limit_rules_file(),
+            rate_limit_api_key_header: default_rate_limit_api_key_header(),
+            rate_limit_api_keys: Vec::new(),
+            rate_limit_trusted_proxies: Vec::new(),
             plugin_redis_prefix: default_plugin_redis_prefix(),
+            plugin_trusted_keys_dir: default_plugin_trusted_keys_dir(),
+
+            oidc_issuer: default_oidc_issuer(),
+            oidc_login_url: default_oidc_login_url(),
+            oidc_redis_prefix: default_oidc_redis_prefix(),
+            oidc_code_expire_seconds: default_oidc_code_expire_seconds(),
 
             middleware_cors: default_middleware_cors(),
             middleware_opera_log: default_middleware_opera_log(),
             middleware_access_log: default_middleware_access_log(),
             middleware_i18n: default_middleware_i18n(),
+            i18n_default_locale: default_i18n_default_locale(),
 
             opera_log_encrypt_secret_key: default_opera_log_encrypt_secret_key(),
             opera_log_path_exclude: default_opera_log_path_exclude(),
@@ -489,6 +727,7 @@ fn default_app_description() -> String { "FastAPI Best Architecture".to_string()
 fn default_docs_url() -> String { "/docs".to_string() }
 fn default_redoc_url() -> String { "/redoc".to_string() }
 fn default_openapi_url() -> Option<String> { Some("/openapi".to_string()) }
+fn default_docs_enabled() -> Option<bool> { None }
 fn default_static_files() -> bool { true }
 
 fn default_database_type() -> DatabaseType { DatabaseType::MySQL }
@@ -522,12 +761,39 @@ fn default_token_secret_key() -> String {
     // 如果没有配置环境变量，使用默认值
     "1VkVF75nsNABBjK_7-qz7GtzNy3AMvktc9TCPwKczCk".to_string()
 }
-fn default_token_algorithm() -> String { "HS256".to_string() }
+fn default_token_algorithm() -> String { "EdDSA".to_string() }
+fn default_token_key_rotation_seconds() -> i64 { 60 * 60 * 24 * 30 }
 fn default_token_expire_seconds() -> i64 { 60 * 60 * 24 }
 fn default_token_refresh_expire_seconds() -> i64 { 60 * 60 * 24 * 7 }
 fn default_token_redis_prefix() -> String { "fba:token".to_string() }
 fn default_token_extra_info_redis_prefix() -> String { "fba:token_extra_info".to_string() }
 fn default_token_online_redis_prefix() -> String { "fba:token_online".to_string() }
+fn default_ws_redis_channel() -> String { "fba:socketio".to_string() }
+fn default_ws_monitor_interval_seconds() -> u64 { 5 }
+fn default_metrics_allow_ips() -> Vec<String> { vec!["127.0.0.1".to_string(), "::1".to_string()] }
+fn default_system_metric_enabled() -> bool { true }
+fn default_system_metric_interval_seconds() -> u64 { 60 }
+fn default_system_metric_raw_retention_days() -> i32 { 7 }
+fn default_system_metric_hourly_retention_days() -> i32 { 90 }
+fn default_system_metric_daily_retention_days() -> i32 { 730 }
+fn default_alert_enabled() -> bool { true }
+fn default_alert_evaluate_interval_seconds() -> u64 { 60 }
+fn default_health_check_timeout_ms() -> u64 { 1000 }
+fn default_health_disk_min_free_percent() -> f64 { 5.0 }
+fn default_shutdown_drain_seconds() -> u64 { 5 }
+fn default_password_min_length() -> usize { 8 }
+fn default_password_max_length() -> usize { 128 }
+fn default_password_require_lowercase() -> bool { true }
+fn default_password_require_uppercase() -> bool { false }
+fn default_password_require_digit() -> bool { true }
+fn default_password_require_special() -> bool { false }
+fn default_password_history_count() -> u64 { 5 }
+fn default_password_expire_days() -> i64 { 0 }
+fn default_account_redis_prefix() -> String { "fba:account".to_string() }
+fn default_account_code_expire_seconds() -> u64 { 600 }
+fn default_account_code_resend_seconds() -> u64 { 60 }
+fn default_account_code_max_attempts() -> u32 { 5 }
+fn default_account_reset_token_expire_seconds() -> u64 { 1800 }
 fn default_token_refresh_redis_prefix() -> String { "fba:refresh_token".to_string() }
 fn default_jwt_user_redis_prefix() -> String { "fba:user".to_string() }
 fn default_token_exclude_paths() -> Vec<String> { vec!["/api/v1/auth/login".to_string()] }
@@ -564,12 +830,21 @@ fn default_upload_video_extensions() -> Vec<String> {
 
 fn default_ip_location_mode() -> String { "offline".to_string() }
 fn default_rate_limit_redis_prefix() -> String { "fba:limiter".to_string() }
+fn default_rate_limit_enabled() -> bool { true }
+fn default_rate_limit_rules_file() -> String { "./rate_limit.toml".to_string() }
+fn default_rate_limit_api_key_header() -> String { "X-API-Key".to_string() }
 fn default_plugin_redis_prefix() -> String { "fba:plugin".to_string() }
+fn default_plugin_trusted_keys_dir() -> String { "./keys/plugins".to_string() }
+fn default_oidc_issuer() -> String { "http://127.0.0.1:8000".to_string() }
+fn default_oidc_login_url() -> String { "http://localhost:5173/auth/login".to_string() }
+fn default_oidc_redis_prefix() -> String { "fba:oidc".to_string() }
+fn default_oidc_code_expire_seconds() -> i64 { 300 }
 
 fn default_middleware_cors() -> bool { true }
 fn default_middleware_opera_log() -> bool { true }
 fn default_middleware_access_log() -> bool { true }
 fn default_middleware_i18n() -> bool { true }
+fn default_i18n_default_locale() -> String { "zh-CN".to_string() }
 
 fn default_opera_log_encrypt_secret_key() -> String {
     "d77b25790a804c2b4a339dd0207941e4cefa5751935a33735bc73bb7071a005b".to_string()
@@ -664,6 +939,11 @@ impl Settings {
         matches!(self.environment, EnvironmentType::Prod)
     }
 
+    /// 检查是否开放 API 文档：未显式配置时生产环境关闭，未配置 OpenAPI URL 时始终关闭
+    pub fn is_docs_enabled(&self) -> bool {
+        self.openapi_url.is_some() && self.docs_enabled.unwrap_or(!self.is_prod())
+    }
+
     /// 检查是否启用操作日志
     pub fn is_opera_log_enabled(&self) -> bool {
         self.middleware_opera_log || self.is_dev()
@@ -0,0 +1,127 @@
+/// 进程健康状态
+///
+/// 记录优雅停机状态和后台任务心跳，供 /livez、/readyz 探针使用。
+/// 收到停机信号后先将就绪探针置为失败，等待负载均衡摘除流量后再停止接收新连接。
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+use crate::core::SETTINGS;
+
+/// 心跳超过间隔的倍数未更新视为卡死
+const HEARTBEAT_TOLERANCE: u32 = 3;
+
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+static HEARTBEATS: Lazy<Mutex<HashMap<&'static str, Heartbeat>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct Heartbeat {
+    last: Instant,
+    interval: Duration,
+}
+
+/// 后台任务心跳状态
+#[derive(Debug, Clone)]
+pub struct HeartbeatStatus {
+    pub name: &'static str,
+    /// 距上次心跳的时间
+    pub age: Duration,
+    /// 允许的最长间隔
+    pub max_age: Duration,
+}
+
+impl HeartbeatStatus {
+    pub fn is_alive(&self) -> bool {
+        self.age <= self.max_age
+    }
+}
+
+/// 后台任务每轮循环调用，`interval` 为循环间隔
+pub fn beat(name: &'static str, interval: Duration) {
+    HEARTBEATS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name, Heartbeat { last: Instant::now(), interval });
+}
+
+/// 所有已登记后台任务的心跳状态
+pub fn heartbeats() -> Vec<HeartbeatStatus> {
+    let heartbeats = HEARTBEATS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut statuses: Vec<HeartbeatStatus> = heartbeats
+        .iter()
+        .map(|(&name, heartbeat)| HeartbeatStatus {
+            name,
+            age: heartbeat.last.elapsed(),
+            max_age: heartbeat.interval * HEARTBEAT_TOLERANCE,
+        })
+        .collect();
+    statuses.sort_by_key(|s| s.name);
+    statuses
+}
+
+/// 是否已开始优雅停机
+pub fn is_shutting_down() -> bool {
+    SHUTTING_DOWN.load(Ordering::Relaxed)
+}
+
+/// 标记开始停机，此后就绪探针返回 503
+pub fn begin_shutdown() {
+    SHUTTING_DOWN.store(true, Ordering::Relaxed);
+}
+
+/// 等待停机信号（Ctrl+C / SIGTERM），标记停机后等待 `shutdown_drain_seconds` 再返回
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    begin_shutdown();
+    info!("收到停机信号，就绪探针已置为失败，{} 秒后停止接收新连接", SETTINGS.shutdown_drain_seconds);
+    tokio::time::sleep(Duration::from_secs(SETTINGS.shutdown_drain_seconds)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_staleness() {
+        beat("test_fresh", Duration::from_secs(60));
+        HEARTBEATS.lock().unwrap().insert(
+            "test_stale",
+            Heartbeat {
+                last: Instant::now() - Duration::from_secs(10),
+                interval: Duration::from_secs(1),
+            },
+        );
+
+        let statuses = heartbeats();
+        let fresh = statuses.iter().find(|s| s.name == "test_fresh").unwrap();
+        let stale = statuses.iter().find(|s| s.name == "test_stale").unwrap();
+        assert!(fresh.is_alive());
+        assert!(!stale.is_alive());
+        assert_eq!(stale.max_age, Duration::from_secs(3));
+    }
+}
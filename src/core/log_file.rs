@@ -0,0 +1,212 @@
+/// 滚动日志文件
+///
+/// 当前日志写入 `<name>.log`，跨天或超过 `log_file_max_size` 时归档为
+/// `<name>.<日期>.<序号>.log` 并在后台压缩为 `.gz`，归档日期早于 `log_file_keep_days` 的文件自动删除。
+/// ERROR 级别日志另写一份到 `<name>.error.log`，按同样规则滚动。
+
+use chrono::{Duration, Local, NaiveDate};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 错误日志文件名后缀
+pub const ERROR_SUFFIX: &str = "error";
+
+/// 日志文件名（不含 `.log` 扩展名）及所在目录，由 `LOG_FILE` 配置推导
+pub fn log_location(log_file: &str) -> (PathBuf, String) {
+    let path = Path::new(log_file);
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| !s.is_empty())
+        .unwrap_or("app")
+        .to_string();
+    (dir, stem)
+}
+
+/// 错误日志文件名（不含扩展名）
+pub fn error_stem(stem: &str) -> String {
+    format!("{}.{}", stem, ERROR_SUFFIX)
+}
+
+/// 解析归档文件名 `<stem>.<YYYY-MM-DD>.<n>.log[.gz]`，返回归档日期
+pub fn archived_date(stem: &str, file_name: &str) -> Option<NaiveDate> {
+    let rest = file_name.strip_prefix(stem)?.strip_prefix('.')?;
+    let rest = rest
+        .strip_suffix(".log.gz")
+        .or_else(|| rest.strip_suffix(".log"))?;
+    let (date, index) = rest.split_once('.')?;
+    index.parse::<u32>().ok()?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// 按大小和日期滚动的日志写入器
+pub struct RollingFileWriter {
+    dir: PathBuf,
+    stem: String,
+    max_bytes: u64,
+    keep_days: u64,
+    file: File,
+    size: u64,
+    date: NaiveDate,
+}
+
+impl RollingFileWriter {
+    /// `max_size_mb` 为 0 时只按天滚动，`keep_days` 为 0 时不删除归档
+    pub fn new(dir: &Path, stem: &str, max_size_mb: u64, keep_days: u64) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.log", stem));
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+        // 沿用已有文件时按其最后修改日期判断是否需要滚动
+        let date = metadata
+            .modified()
+            .map(|t| chrono::DateTime::<Local>::from(t).date_naive())
+            .unwrap_or_else(|_| Local::now().date_naive());
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            stem: stem.to_string(),
+            max_bytes: max_size_mb.saturating_mul(1024 * 1024),
+            keep_days,
+            file,
+            size: metadata.len(),
+            date,
+        })
+    }
+
+    fn active_path(&self) -> PathBuf {
+        self.dir.join(format!("{}.log", self.stem))
+    }
+
+    /// 归档当前文件并重新打开，压缩和清理在独立线程中进行
+    fn rotate(&mut self, today: NaiveDate) -> io::Result<()> {
+        self.file.flush()?;
+        let archived = (1..)
+            .map(|n| self.dir.join(format!("{}.{}.{}.log", self.stem, self.date.format("%Y-%m-%d"), n)))
+            .find(|p| !p.exists() && !gz_path(p).exists())
+            .expect("unbounded range");
+        fs::rename(self.active_path(), &archived)?;
+
+        self.file = OpenOptions::new().create(true).append(true).open(self.active_path())?;
+        self.size = 0;
+        self.date = today;
+
+        let (dir, stem, keep_days) = (self.dir.clone(), self.stem.clone(), self.keep_days);
+        std::thread::spawn(move || {
+            if let Err(e) = compress(&archived) {
+                eprintln!("日志归档压缩失败 {}: {}", archived.display(), e);
+            }
+            if keep_days > 0 {
+                remove_expired(&dir, &stem, today - Duration::days(keep_days as i64));
+            }
+        });
+        Ok(())
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let today = Local::now().date_naive();
+        let oversized = self.max_bytes > 0 && self.size > 0 && self.size + buf.len() as u64 > self.max_bytes;
+        if today != self.date || oversized {
+            // 滚动失败时继续写入当前文件，避免丢日志
+            if let Err(e) = self.rotate(today) {
+                eprintln!("日志文件滚动失败 {}: {}", self.active_path().display(), e);
+            }
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn gz_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// 压缩为 `.gz` 后删除原文件
+fn compress(path: &Path) -> io::Result<()> {
+    let target = gz_path(path);
+    let mut encoder = GzEncoder::new(File::create(&target)?, Compression::default());
+    io::copy(&mut File::open(path)?, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    fs::remove_file(path)
+}
+
+/// 删除归档日期早于 `before` 的文件
+fn remove_expired(dir: &Path, stem: &str, before: NaiveDate) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some(date) = name.to_str().and_then(|n| archived_date(stem, n)) else {
+            continue;
+        };
+        if date < before {
+            if let Err(e) = fs::remove_file(entry.path()) {
+                eprintln!("过期日志删除失败 {}: {}", entry.path().display(), e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archived_date() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 18);
+        assert_eq!(archived_date("fba", "fba.2026-10-18.1.log.gz"), date);
+        assert_eq!(archived_date("fba", "fba.2026-10-18.12.log"), date);
+        assert_eq!(archived_date("fba.error", "fba.error.2026-10-18.1.log.gz"), date);
+
+        // 当前文件和其他前缀的归档不匹配
+        assert_eq!(archived_date("fba", "fba.log"), None);
+        assert_eq!(archived_date("fba", "fba.error.2026-10-18.1.log.gz"), None);
+        assert_eq!(archived_date("fba", "fba.2026-10-18.log"), None);
+    }
+
+    #[test]
+    fn test_rotate_by_size() {
+        let dir = std::env::temp_dir().join(format!("fba-log-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let mut writer = RollingFileWriter::new(&dir, "fba", 0, 0).unwrap();
+        writer.max_bytes = 16;
+
+        writer.write_all(b"0123456789\n").unwrap();
+        writer.write_all(b"0123456789\n").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(fs::read(dir.join("fba.log")).unwrap(), b"0123456789\n");
+        // 归档可能正在后台压缩，.log 和 .log.gz 都算
+        let archived = fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .any(|e| archived_date("fba", e.file_name().to_str().unwrap()).is_some());
+        assert!(archived);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_log_location() {
+        assert_eq!(log_location("./logs/fba.log"), (PathBuf::from("./logs"), "fba".to_string()));
+        assert_eq!(log_location("fba.log"), (PathBuf::from("."), "fba".to_string()));
+        assert_eq!(error_stem("fba"), "fba.error");
+    }
+}
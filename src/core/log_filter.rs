@@ -0,0 +1,131 @@
+/// 运行时日志过滤器
+///
+/// 启动时的过滤规则（RUST_LOG 或按配置生成）作为基础，sys_log_level 中启用的规则覆盖在其上，
+/// 通过 tracing-subscriber 的 reload 句柄立即生效，无需重启。
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::sync::RwLock;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+use crate::common::exception::{AppError, ErrorCode};
+
+/// 支持的级别，按详细程度从高到低
+pub const LEVELS: &[&str] = &["trace", "debug", "info", "warn", "error", "off"];
+
+type Reloader = Box<dyn Fn(EnvFilter) -> Result<(), String> + Send + Sync>;
+
+static RELOADER: OnceCell<Reloader> = OnceCell::new();
+static BASE: OnceCell<String> = OnceCell::new();
+static CURRENT: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new(String::new()));
+
+/// 应用自身的 target，未指定模块的规则作用于此
+pub fn app_target() -> String {
+    env!("CARGO_PKG_NAME").replace('-', "_")
+}
+
+/// 级别的详细程度，数值越小越详细；不支持的级别返回 None
+pub fn verbosity(level: &str) -> Option<usize> {
+    LEVELS.iter().position(|l| l.eq_ignore_ascii_case(level))
+}
+
+/// 登记基础过滤规则和 reload 句柄，由日志初始化调用一次
+pub fn install(base: &str, reloader: Reloader) {
+    let _ = BASE.set(base.to_string());
+    let _ = RELOADER.set(reloader);
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = base.to_string();
+}
+
+/// 基础过滤规则
+pub fn base() -> String {
+    BASE.get().cloned().unwrap_or_default()
+}
+
+/// 当前生效的过滤规则
+pub fn current() -> String {
+    CURRENT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// 在基础规则上叠加覆盖规则：同一 target 的规则被替换，新 target 追加在末尾
+///
+/// 覆盖规则中 target 为 None 表示应用自身
+pub fn merge(base: &str, overrides: &[(Option<String>, String)]) -> String {
+    let mut directives: Vec<(String, String)> = base
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| match d.rsplit_once('=') {
+            Some((target, level)) => (target.to_string(), level.to_string()),
+            None => (String::new(), d.to_string()),
+        })
+        .collect();
+
+    for (target, level) in overrides {
+        let target = target.clone().unwrap_or_else(app_target);
+        match directives.iter_mut().find(|(t, _)| *t == target) {
+            Some(existing) => existing.1 = level.to_lowercase(),
+            None => directives.push((target, level.to_lowercase())),
+        }
+    }
+
+    directives
+        .into_iter()
+        .map(|(target, level)| if target.is_empty() { level } else { format!("{}={}", target, level) })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 校验过滤规则语法
+pub fn parse(directives: &str) -> Result<EnvFilter, AppError> {
+    EnvFilter::try_new(directives).map_err(|e| {
+        AppError::with_details(ErrorCode::ValidationError, "日志过滤规则无效", e.to_string())
+    })
+}
+
+/// 应用过滤规则，与当前规则相同时不重复加载；返回是否发生变化
+pub fn apply(directives: &str) -> Result<bool, AppError> {
+    if current() == directives {
+        return Ok(false);
+    }
+    let filter = parse(directives)?;
+    let Some(reload) = RELOADER.get() else {
+        return Err(AppError::with_message(ErrorCode::InternalServerError, "日志过滤器不支持运行时重载"));
+    };
+    reload(filter).map_err(|e| AppError::with_details(ErrorCode::InternalServerError, "日志过滤器重载失败", e))?;
+
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = directives.to_string();
+    info!("日志过滤规则已更新: {}", directives);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_overrides() {
+        let app = app_target();
+        let base = format!("{}=info,sea_orm=warn,axum=info", app);
+
+        let merged = merge(
+            &base,
+            &[
+                (None, "debug".to_string()),
+                (Some("sea_orm".to_string()), "DEBUG".to_string()),
+                (Some("tower_http::trace".to_string()), "trace".to_string()),
+            ],
+        );
+        assert_eq!(merged, format!("{}=debug,sea_orm=debug,axum=info,tower_http::trace=trace", app));
+
+        // 无覆盖时保持基础规则，裸级别原样保留
+        assert_eq!(merge("warn, sqlx=error", &[]), "warn,sqlx=error");
+    }
+
+    #[test]
+    fn test_verbosity() {
+        assert!(verbosity("trace") < verbosity("INFO"));
+        assert_eq!(verbosity("verbose"), None);
+        assert!(parse("sea_orm=debug,info").is_ok());
+        assert!(parse("sea_orm=loud").is_err());
+    }
+}
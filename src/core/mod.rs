@@ -1,4 +1,8 @@
 pub mod conf;
+pub mod health;
+pub mod log_file;
+pub mod log_filter;
+pub mod openapi;
 pub mod path_conf;
 pub mod registrar;
 
@@ -0,0 +1,434 @@
+/// 接口描述构建器
+///
+/// 请求和响应结构来自 DTO 的 `ToSchema` 派生：顶层结构内联展开，其引用的子结构登记到 components。
+/// 成功响应统一包装为 `ResponseModel` 的 `{code, msg, data}` 结构，插件的 `ApiResponse` 与之一致。
+
+use utoipa::openapi::{
+    path::{HttpMethod, Operation, OperationBuilder, Parameter, ParameterBuilder, ParameterIn},
+    request_body::RequestBodyBuilder,
+    schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, SchemaType, Type},
+    security::SecurityRequirement,
+    ContentBuilder, RefOr, Required, Response, ResponseBuilder, ResponsesBuilder,
+};
+use utoipa::ToSchema;
+
+/// 组件结构列表（名称, 结构）
+pub type Schemas = Vec<(String, RefOr<Schema>)>;
+
+/// 成功响应内容
+enum Body {
+    /// 统一响应结构，`None` 表示 data 为 null
+    Envelope(Option<RefOr<Schema>>),
+    /// 不包装的 JSON（OIDC 协议端点、探针等）
+    Plain(RefOr<Schema>),
+    /// 文件或文本，值为 Content-Type
+    Raw(&'static str),
+}
+
+/// 单个接口描述
+pub struct Op {
+    method: HttpMethod,
+    path: &'static str,
+    summary: &'static str,
+    status: u16,
+    params: Vec<Parameter>,
+    string_path_params: Vec<&'static str>,
+    request: Option<(&'static str, RefOr<Schema>, bool)>,
+    body: Option<Body>,
+    redirect: Option<&'static str>,
+    security: Option<Vec<SecurityRequirement>>,
+    schemas: Schemas,
+}
+
+pub fn get(path: &'static str, summary: &'static str) -> Op {
+    Op::new(HttpMethod::Get, path, summary)
+}
+
+pub fn post(path: &'static str, summary: &'static str) -> Op {
+    Op::new(HttpMethod::Post, path, summary)
+}
+
+pub fn put(path: &'static str, summary: &'static str) -> Op {
+    Op::new(HttpMethod::Put, path, summary)
+}
+
+pub fn delete(path: &'static str, summary: &'static str) -> Op {
+    Op::new(HttpMethod::Delete, path, summary)
+}
+
+pub fn patch(path: &'static str, summary: &'static str) -> Op {
+    Op::new(HttpMethod::Patch, path, summary)
+}
+
+impl Op {
+    fn new(method: HttpMethod, path: &'static str, summary: &'static str) -> Self {
+        Self {
+            method,
+            path,
+            summary,
+            status: 200,
+            params: Vec::new(),
+            string_path_params: Vec::new(),
+            request: None,
+            body: None,
+            redirect: None,
+            security: None,
+            schemas: Vec::new(),
+        }
+    }
+
+    /// JSON 请求体
+    pub fn json<T: ToSchema>(self) -> Self {
+        self.request_body::<T>("application/json")
+    }
+
+    /// 表单请求体（application/x-www-form-urlencoded）
+    pub fn form<T: ToSchema>(self) -> Self {
+        self.request_body::<T>("application/x-www-form-urlencoded")
+    }
+
+    /// 文件上传请求体（multipart/form-data）
+    pub fn multipart(mut self, field: &'static str, description: &'static str) -> Self {
+        let schema = object(ObjectBuilder::new().property(
+            field,
+            object(ObjectBuilder::new().schema_type(Type::String).description(Some(description))),
+        ));
+        self.request = Some(("multipart/form-data", schema, true));
+        self
+    }
+
+    /// 请求体可省略
+    pub fn optional_body(mut self) -> Self {
+        if let Some(request) = self.request.as_mut() {
+            request.2 = false;
+        }
+        self
+    }
+
+    /// 查询参数，按结构字段展开
+    pub fn query<T: ToSchema>(mut self) -> Self {
+        let mut schemas = Vec::new();
+        T::schemas(&mut schemas);
+        collect_query(&T::schema(), &schemas, &mut self.params);
+        self.schemas.extend(schemas);
+        self
+    }
+
+    /// 单个可选的字符串查询参数（处理器以 HashMap 接收查询时使用）
+    pub fn param(mut self, name: &'static str, description: &'static str) -> Self {
+        self.params.push(
+            ParameterBuilder::new()
+                .name(name)
+                .parameter_in(ParameterIn::Query)
+                .required(Required::False)
+                .description(Some(description))
+                .schema(Some(object(ObjectBuilder::new().schema_type(Type::String))))
+                .build(),
+        );
+        self
+    }
+
+    /// 指定字符串类型的路径参数（默认 id、pk 和 *_id 为整数，其余为字符串）
+    pub fn path_str(mut self, name: &'static str) -> Self {
+        self.string_path_params.push(name);
+        self
+    }
+
+    /// 成功响应，data 为指定结构
+    pub fn ok<T: ToSchema>(mut self) -> Self {
+        let schema = self.schema_of::<T>();
+        self.body = Some(Body::Envelope(Some(schema)));
+        self
+    }
+
+    /// 成功响应，data 为 null
+    pub fn ok_empty(mut self) -> Self {
+        self.body = Some(Body::Envelope(None));
+        self
+    }
+
+    /// 登记组件结构：树形节点的 children 以 `no_recursion` 引用自身，需显式登记节点本身
+    pub fn component<T: ToSchema>(mut self) -> Self {
+        T::schemas(&mut self.schemas);
+        self.schemas.push((T::name().into_owned(), T::schema()));
+        self
+    }
+
+    /// 成功响应，data 为结构不固定的 JSON
+    pub fn ok_any(mut self, description: &'static str) -> Self {
+        self.body = Some(Body::Envelope(Some(any(description))));
+        self
+    }
+
+    /// 不包装统一响应结构的 JSON 响应
+    pub fn plain<T: ToSchema>(mut self) -> Self {
+        let schema = self.schema_of::<T>();
+        self.body = Some(Body::Plain(schema));
+        self
+    }
+
+    /// 不包装统一响应结构、结构不固定的 JSON 响应
+    pub fn plain_any(mut self, description: &'static str) -> Self {
+        self.body = Some(Body::Plain(any(description)));
+        self
+    }
+
+    /// 文件或文本响应
+    pub fn raw(mut self, content_type: &'static str) -> Self {
+        self.body = Some(Body::Raw(content_type));
+        self
+    }
+
+    /// 成功状态码为 201
+    pub fn created(self) -> Self {
+        self.status(201)
+    }
+
+    /// 成功状态码
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// 303 跳转响应
+    pub fn redirect(mut self, description: &'static str) -> Self {
+        self.redirect = Some(description);
+        self
+    }
+
+    /// 无需认证
+    pub fn public(mut self) -> Self {
+        self.security = Some(Vec::new());
+        self
+    }
+
+    /// 使用指定的认证方式代替全局的 Bearer 令牌
+    pub fn auth(mut self, scheme: &'static str) -> Self {
+        self.security = Some(vec![SecurityRequirement::new(scheme, Vec::<&str>::new())]);
+        self
+    }
+
+    pub(super) fn method(&self) -> &HttpMethod {
+        &self.method
+    }
+
+    pub(super) fn path(&self) -> &'static str {
+        self.path
+    }
+
+    fn request_body<T: ToSchema>(mut self, content_type: &'static str) -> Self {
+        let schema = self.schema_of::<T>();
+        self.request = Some((content_type, schema, true));
+        self
+    }
+
+    fn schema_of<T: ToSchema>(&mut self) -> RefOr<Schema> {
+        T::schemas(&mut self.schemas);
+        T::schema()
+    }
+
+    /// 生成 OpenAPI 操作，返回操作及其引用的组件结构
+    pub(super) fn build(self, tag: &str, full_path: &str) -> (Operation, Schemas) {
+        let mut builder = OperationBuilder::new()
+            .tag(tag)
+            .summary(Some(self.summary))
+            .operation_id(Some(operation_id(&self.method, full_path)));
+
+        for name in path_params(full_path) {
+            let is_string = self.string_path_params.contains(&name)
+                || !(name == "id" || name == "pk" || name.ends_with("_id"));
+            let schema = if is_string {
+                ObjectBuilder::new().schema_type(Type::String)
+            } else {
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            };
+            builder = builder.parameter(
+                ParameterBuilder::new()
+                    .name(name)
+                    .parameter_in(ParameterIn::Path)
+                    .required(Required::True)
+                    .schema(Some(object(schema)))
+                    .build(),
+            );
+        }
+        for param in self.params {
+            builder = builder.parameter(param);
+        }
+
+        if let Some((content_type, schema, required)) = self.request {
+            let required = if required { Required::True } else { Required::False };
+            builder = builder.request_body(Some(
+                RequestBodyBuilder::new()
+                    .content(content_type, ContentBuilder::new().schema(Some(schema)).build())
+                    .required(Some(required))
+                    .build(),
+            ));
+        }
+
+        let mut responses = ResponsesBuilder::new();
+        if let Some(body) = self.body {
+            let response = if self.status == 204 {
+                // 204 响应不带响应体
+                ResponseBuilder::new().description("成功").build()
+            } else {
+                success_response(body)
+            };
+            responses = responses.response(self.status.to_string(), response);
+        }
+        if let Some(description) = self.redirect {
+            responses = responses.response("303", ResponseBuilder::new().description(description).build());
+        }
+        responses = responses.response("default", error_response());
+        builder = builder.responses(responses.build());
+
+        if let Some(security) = self.security {
+            builder = builder.securities(Some(security));
+        }
+        (builder.build(), self.schemas)
+    }
+}
+
+fn object(builder: ObjectBuilder) -> RefOr<Schema> {
+    RefOr::T(Schema::Object(builder.build()))
+}
+
+/// 统一响应结构 `{code, msg, data}`
+fn envelope(data: RefOr<Schema>) -> RefOr<Schema> {
+    object(
+        ObjectBuilder::new()
+            .property(
+                "code",
+                object(ObjectBuilder::new().schema_type(Type::Integer).description(Some("状态码"))),
+            )
+            .required("code")
+            .property(
+                "msg",
+                object(ObjectBuilder::new().schema_type(Type::String).description(Some("响应消息"))),
+            )
+            .required("msg")
+            .property("data", data),
+    )
+}
+
+/// 任意 JSON
+fn any(description: &'static str) -> RefOr<Schema> {
+    object(ObjectBuilder::new().schema_type(SchemaType::AnyValue).description(Some(description)))
+}
+
+fn null() -> RefOr<Schema> {
+    object(ObjectBuilder::new().schema_type(Type::Null))
+}
+
+fn json_response(description: &str, schema: RefOr<Schema>) -> Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content("application/json", ContentBuilder::new().schema(Some(schema)).build())
+        .build()
+}
+
+fn success_response(body: Body) -> Response {
+    match body {
+        Body::Envelope(data) => json_response("成功", envelope(data.unwrap_or_else(null))),
+        Body::Plain(schema) => json_response("成功", schema),
+        Body::Raw(content_type) => ResponseBuilder::new()
+            .description("成功")
+            .content(
+                content_type,
+                ContentBuilder::new()
+                    .schema(Some(object(ObjectBuilder::new().schema_type(Type::String))))
+                    .build(),
+            )
+            .build(),
+    }
+}
+
+fn error_response() -> Response {
+    json_response("请求失败，code 为错误码，msg 为按请求语言本地化的错误信息", envelope(null()))
+}
+
+/// 将结构的字段展开为查询参数，`#[serde(flatten)]` 产生的 allOf 和引用会继续展开
+fn collect_query(schema: &RefOr<Schema>, schemas: &Schemas, out: &mut Vec<Parameter>) {
+    match schema {
+        RefOr::Ref(reference) => {
+            let name = reference.ref_location.rsplit('/').next().unwrap_or_default();
+            if let Some((_, schema)) = schemas.iter().find(|(n, _)| n == name) {
+                collect_query(schema, schemas, out);
+            }
+        }
+        RefOr::T(Schema::Object(object)) => {
+            for (name, property) in &object.properties {
+                let description = match property {
+                    RefOr::T(Schema::Object(property)) => property.description.clone(),
+                    _ => None,
+                };
+                let required = if object.required.contains(name) { Required::True } else { Required::False };
+                out.push(
+                    ParameterBuilder::new()
+                        .name(name)
+                        .parameter_in(ParameterIn::Query)
+                        .required(required)
+                        .description(description)
+                        .schema(Some(property.clone()))
+                        .build(),
+                );
+            }
+        }
+        RefOr::T(Schema::AllOf(all_of)) => {
+            for item in &all_of.items {
+                collect_query(item, schemas, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 路径中的 `{name}` 参数
+pub(super) fn path_params(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .collect()
+}
+
+pub(super) fn method_name(method: &HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::Get => "get",
+        HttpMethod::Post => "post",
+        HttpMethod::Put => "put",
+        HttpMethod::Delete => "delete",
+        HttpMethod::Patch => "patch",
+        HttpMethod::Head => "head",
+        HttpMethod::Options => "options",
+        HttpMethod::Trace => "trace",
+    }
+}
+
+/// 由方法和路径生成唯一的 operationId，如 `get_api_v1_sys_users_id`
+fn operation_id(method: &HttpMethod, path: &str) -> String {
+    let mut id = method_name(method).to_string();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        id.push('_');
+        id.extend(segment.chars().filter_map(|c| match c {
+            '{' | '}' => None,
+            c if c.is_ascii_alphanumeric() => Some(c),
+            _ => Some('_'),
+        }));
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_id_and_path_params() {
+        assert_eq!(operation_id(&HttpMethod::Get, "/api/v1/sys/users/{id}"), "get_api_v1_sys_users_id");
+        assert_eq!(operation_id(&HttpMethod::Get, "/.well-known/jwks.json"), "get__well_known_jwks_json");
+        assert_eq!(
+            path_params("/api/v1/sys/user-roles/user-roles/{user_id}/check/{role_code}"),
+            vec!["user_id", "role_code"]
+        );
+    }
+}
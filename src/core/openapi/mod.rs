@@ -0,0 +1,384 @@
+/// OpenAPI 文档
+///
+/// 启动时根据 paths 中的接口描述生成 OpenAPI 3.1 规范，在 openapi_url 提供 JSON，
+/// 在 docs_url 和 redoc_url 提供 Swagger UI 与 ReDoc 页面。生产环境默认关闭，见 `Settings::is_docs_enabled`。
+
+mod builder;
+mod paths;
+
+use axum::{
+    http::header,
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use utoipa::openapi::{
+    path::{HttpMethod, Operation, PathItemBuilder},
+    security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme},
+    tag::TagBuilder,
+    ComponentsBuilder, InfoBuilder, OpenApi, OpenApiBuilder, PathsBuilder, RefOr, Schema,
+};
+
+use crate::core::SETTINGS;
+use builder::Op;
+
+/// 全局认证方式：登录获取的访问令牌
+pub const BEARER_AUTH: &str = "BearerAuth";
+/// OIDC 客户端认证（client_id / client_secret）
+pub const CLIENT_BASIC: &str = "ClientBasic";
+/// Prometheus 指标端点的访问令牌
+pub const METRICS_TOKEN: &str = "MetricsToken";
+
+/// 同一标签下的一组接口，接口路径相对于 prefix
+struct ApiGroup {
+    tag: &'static str,
+    description: &'static str,
+    prefix: &'static str,
+    ops: Vec<Op>,
+}
+
+impl ApiGroup {
+    fn new(tag: &'static str, description: &'static str, prefix: &'static str, ops: Vec<Op>) -> Self {
+        Self { tag, description, prefix, ops }
+    }
+}
+
+/// OpenAPI 规范
+pub static OPENAPI: Lazy<OpenApi> = Lazy::new(|| build(paths::groups()));
+
+static OPENAPI_JSON: Lazy<String> =
+    Lazy::new(|| OPENAPI.to_json().expect("OpenAPI 规范序列化失败"));
+
+fn full_path(prefix: &str, path: &str) -> String {
+    let full = format!("{}{}", prefix, path);
+    if full.is_empty() { "/".to_string() } else { full }
+}
+
+fn build(groups: Vec<ApiGroup>) -> OpenApi {
+    let mut paths: Vec<(String, Vec<(HttpMethod, Operation)>)> = Vec::new();
+    let mut schemas: BTreeMap<String, RefOr<Schema>> = BTreeMap::new();
+    let mut tags = Vec::new();
+
+    for group in groups {
+        if !tags.iter().any(|(name, _)| *name == group.tag) {
+            tags.push((group.tag, group.description));
+        }
+        for op in group.ops {
+            let path = full_path(group.prefix, op.path());
+            let method = op.method().clone();
+            let (operation, op_schemas) = op.build(group.tag, &path);
+            // 同名结构以首次出现为准，测试保证同名结构一致
+            for (name, schema) in op_schemas {
+                schemas.entry(name).or_insert(schema);
+            }
+            match paths.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, operations)) => operations.push((method, operation)),
+                None => paths.push((path, vec![(method, operation)])),
+            }
+        }
+    }
+
+    let mut paths_builder = PathsBuilder::new();
+    for (path, operations) in paths {
+        let item = operations
+            .into_iter()
+            .fold(PathItemBuilder::new(), |item, (method, operation)| item.operation(method, operation));
+        paths_builder = paths_builder.path(path, item.build());
+    }
+
+    let components = ComponentsBuilder::new()
+        .schemas_from_iter(schemas)
+        .security_scheme(
+            BEARER_AUTH,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .description(Some("登录接口返回的 access_token"))
+                    .build(),
+            ),
+        )
+        .security_scheme(
+            CLIENT_BASIC,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Basic)
+                    .description(Some("OIDC 客户端的 client_id 和 client_secret，也可放在表单参数中"))
+                    .build(),
+            ),
+        )
+        .security_scheme(
+            METRICS_TOKEN,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some("METRICS_TOKEN 配置的访问令牌，未配置时按 IP 白名单放行"))
+                    .build(),
+            ),
+        )
+        .build();
+
+    OpenApiBuilder::new()
+        .info(
+            InfoBuilder::new()
+                .title(SETTINGS.app_title.clone())
+                .version(env!("CARGO_PKG_VERSION"))
+                .description(Some(SETTINGS.app_description.clone()))
+                .build(),
+        )
+        .paths(paths_builder.build())
+        .components(Some(components))
+        .security(Some(vec![SecurityRequirement::new(BEARER_AUTH, Vec::<&str>::new())]))
+        .tags(Some(
+            tags.into_iter()
+                .map(|(name, description)| TagBuilder::new().name(name).description(Some(description)).build()),
+        ))
+        .build()
+}
+
+/// 文档路由，路径取自配置
+pub fn docs_routes() -> Router {
+    let Some(openapi_url) = SETTINGS.openapi_url.as_deref() else {
+        return Router::new();
+    };
+    Router::new()
+        .route(openapi_url, get(openapi_json))
+        .route(&SETTINGS.docs_url, get(swagger_ui))
+        .route(&SETTINGS.redoc_url, get(redoc))
+}
+
+/// 是否为文档路径（无需认证）
+pub fn is_docs_path(path: &str) -> bool {
+    SETTINGS.is_docs_enabled()
+        && (path == SETTINGS.docs_url
+            || path == SETTINGS.redoc_url
+            || SETTINGS.openapi_url.as_deref() == Some(path))
+}
+
+async fn openapi_json() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], OPENAPI_JSON.as_str())
+}
+
+async fn swagger_ui() -> Html<String> {
+    Html(render(SWAGGER_UI_HTML))
+}
+
+async fn redoc() -> Html<String> {
+    Html(render(REDOC_HTML))
+}
+
+fn render(template: &str) -> String {
+    let title = SETTINGS
+        .app_title
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+    template
+        .replace("{title}", &title)
+        .replace("{spec_url}", SETTINGS.openapi_url.as_deref().unwrap_or_default())
+}
+
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="zh-CN">
+<head>
+  <meta charset="utf-8">
+  <meta name="viewport" content="width=device-width, initial-scale=1">
+  <title>{title} - Swagger UI</title>
+  <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({
+      url: "{spec_url}",
+      dom_id: "#swagger-ui",
+      presets: [SwaggerUIBundle.presets.apis],
+      persistAuthorization: true,
+      deepLinking: true,
+    });
+  </script>
+</body>
+</html>
+"##;
+
+const REDOC_HTML: &str = r##"<!DOCTYPE html>
+<html lang="zh-CN">
+<head>
+  <meta charset="utf-8">
+  <meta name="viewport" content="width=device-width, initial-scale=1">
+  <title>{title} - ReDoc</title>
+</head>
+<body>
+  <redoc spec-url="{spec_url}"></redoc>
+  <script src="https://cdn.jsdelivr.net/npm/redoc@2/bundles/redoc.standalone.js"></script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// 应答所有命令为 +OK 的最小 Redis 服务，仅用于创建插件路由持有的连接，路由构建不会访问 Redis
+    async fn fake_redis() -> redis::Client {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    while let Ok(n) = socket.read(&mut buf).await {
+                        if n == 0 {
+                            break;
+                        }
+                        // 每条命令以行首的 * 开始
+                        let commands = (0..n).filter(|&i| buf[i] == b'*' && (i == 0 || buf[i - 1] == b'\n')).count();
+                        if socket.write_all(&b"+OK\r\n".repeat(commands)).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        redis::Client::open(format!("redis://{}", addr)).unwrap()
+    }
+
+    /// 实际挂载的路由：应用内置路由和全部内置插件路由（生产环境中后者作为兜底路由）
+    async fn mounted_routers() -> Vec<Router> {
+        use crate::app::plugin::discovery::PluginDiscovery;
+        use crate::app::plugin::injector::RouteInjector;
+        use crate::app::plugin::registry::{builtin_plugins, PluginContext};
+
+        let ctx = PluginContext {
+            db: sea_orm::DatabaseConnection::Disconnected,
+            redis: redis::aio::ConnectionManager::new(fake_redis().await).await.unwrap(),
+        };
+        let injector = RouteInjector::new();
+        let (extend, app) = PluginDiscovery::new("./plugins").classify_plugins(builtin_plugins("./plugins").unwrap());
+        let plugins = injector.inject_extend_plugins(Router::new(), extend, &ctx).await.unwrap();
+        let plugins = injector.inject_app_plugins(plugins, app, &ctx).await.unwrap();
+
+        [crate::core::registrar::AppRegistrar::new().routes(), plugins]
+            .into_iter()
+            .map(|router| {
+                // 匹配成功时直接返回匹配到的路由模板，不执行处理函数；方法不匹配时返回 405
+                router
+                    .route_layer(axum::middleware::from_fn(
+                        |path: axum::extract::MatchedPath, _req: axum::extract::Request, _next: axum::middleware::Next| async move {
+                            path.as_str().to_string()
+                        },
+                    ))
+                    .method_not_allowed_fallback(|| async { axum::http::StatusCode::METHOD_NOT_ALLOWED })
+            })
+            .collect()
+    }
+
+    /// 依次交给各路由处理，返回首个匹配的路由模板
+    async fn matched(routers: &[Router], method: &str, template: &str) -> Option<String> {
+        use tower::ServiceExt;
+
+        let uri: String = template
+            .split('/')
+            .map(|segment| if segment.starts_with('{') { "1" } else { segment })
+            .collect::<Vec<_>>()
+            .join("/");
+        for router in routers {
+            let req = axum::extract::Request::builder()
+                .method(method.to_uppercase().as_str())
+                .uri(uri.as_str())
+                .body(axum::body::Body::empty())
+                .unwrap();
+            let res = router.clone().oneshot(req).await.unwrap();
+            if res.status() == axum::http::StatusCode::OK {
+                let body = axum::body::to_bytes(res.into_body(), usize::MAX).await.unwrap();
+                return Some(String::from_utf8(body.to_vec()).unwrap());
+            }
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn test_every_route_is_documented() {
+        let spec: serde_json::Value = serde_json::from_str(&OPENAPI_JSON).unwrap();
+        let routers = mounted_routers().await;
+
+        // axum 不提供路由列表接口，从 Router 的 Debug 输出中读取已注册的路由模板
+        let template = regex::Regex::new(r#"RouteId\(\d+\): "([^"]+)""#).unwrap();
+        let mut routes = std::collections::BTreeSet::new();
+        for router in &routers {
+            let debug = format!("{:?}", router);
+            for caps in template.captures_iter(&debug) {
+                for method in ["get", "post", "put", "patch", "delete"] {
+                    if matched(&routers, method, &caps[1]).await.as_deref() == Some(&caps[1]) {
+                        routes.insert((method.to_string(), caps[1].to_string()));
+                    }
+                }
+            }
+        }
+        assert!(routes.len() > 200, "路由数量异常: {}", routes.len());
+
+        let missing: Vec<String> = routes
+            .iter()
+            .filter(|(method, path)| spec["paths"][path.as_str()][method.as_str()].is_null())
+            .map(|(method, path)| format!("{} {}", method.to_uppercase(), path))
+            .collect();
+        assert!(missing.is_empty(), "以下路由未写入 OpenAPI 文档（src/core/openapi/paths.rs）:\n{}", missing.join("\n"));
+
+        let mut unmatched = Vec::new();
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            for method in item.as_object().unwrap().keys() {
+                if matched(&routers, method, path).await.as_deref() != Some(path.as_str()) {
+                    unmatched.push(format!("{} {}", method.to_uppercase(), path));
+                }
+            }
+        }
+        assert!(unmatched.is_empty(), "以下文档中的路由不存在:\n{}", unmatched.join("\n"));
+    }
+
+    #[test]
+    fn test_schema_names_are_unique() {
+        let mut seen: HashMap<String, String> = HashMap::new();
+        for group in paths::groups() {
+            for op in group.ops {
+                let path = full_path(group.prefix, op.path());
+                let (_, schemas) = op.build(group.tag, &path);
+                for (name, schema) in schemas {
+                    let json = serde_json::to_string(&schema).unwrap();
+                    if let Some(previous) = seen.insert(name.clone(), json.clone()) {
+                        assert_eq!(previous, json, "不同结构使用了相同的组件名 {}，请用 #[schema(as = ...)] 区分", name);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_references_resolve() {
+        let json = OPENAPI_JSON.as_str();
+        let spec: serde_json::Value = serde_json::from_str(json).unwrap();
+        let components = &spec["components"]["schemas"];
+        for part in json.split("\"#/components/schemas/").skip(1) {
+            let name = &part[..part.find('"').unwrap()];
+            assert!(!components[name].is_null(), "引用的组件 {} 未登记", name);
+        }
+    }
+
+    #[test]
+    fn test_operation_ids_are_unique() {
+        let spec: serde_json::Value = serde_json::from_str(&OPENAPI_JSON).unwrap();
+        let mut ids = std::collections::HashSet::new();
+        for item in spec["paths"].as_object().unwrap().values() {
+            for operation in item.as_object().unwrap().values() {
+                let id = operation["operationId"].as_str().unwrap();
+                assert!(ids.insert(id.to_string()), "operationId 重复: {}", id);
+            }
+        }
+    }
+}
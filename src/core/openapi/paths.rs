@@ -0,0 +1,650 @@
+/// 接口清单
+///
+/// 与 `AppRegistrar::build_router` 及内置插件的路由一一对应，新增或调整路由时同步修改，
+/// 遗漏会使 `test_every_route_is_documented` 失败。
+
+use utoipa::ToSchema;
+
+use super::builder::{delete, get, patch, post, put};
+use super::{ApiGroup, CLIENT_BASIC, METRICS_TOKEN};
+use crate::app::alert::dto::{alert_event, alert_rule};
+use crate::app::auth::dto::{account, captcha, login, token};
+use crate::app::auth::router::CaptchaQuery;
+use crate::app::complete_module::api::complete as complete_api;
+use crate::app::complete_module::dto::{health_check, module_info, system_status};
+use crate::app::data_scope::dto::{
+    data_rule_config, data_rule_query, data_rule_response, data_scope_config, data_scope_query, data_scope_response,
+};
+use crate::app::dept::api::dept as dept_api;
+use crate::app::dept::dto::{create_dept, dept_query, dept_response};
+use crate::app::file_info::api::file_info as file_info_api;
+use crate::app::file_info::dto::{file_info_response, preview_thumbnail};
+use crate::app::log_file::dto::log_file;
+use crate::app::log_level::dto::{create_log_level, log_level_query, log_level_response};
+use crate::app::login_log::dto::{create_login_log, delete_login_logs, login_log_query, login_log_response};
+use crate::app::menu::dto::{create_menu, menu_response, pagination as menu_pagination, update_menu};
+use crate::app::menu::service::menu_service::VbenMenuNode;
+use crate::app::monitor::dto as monitor;
+use crate::app::oidc::dto::{client as oidc_client, protocol as oidc};
+use crate::app::opera_log::dto::{delete_opera_logs, opera_log_query, opera_log_response};
+use crate::app::permission::dto::{
+    create_permission, pagination as permission_pagination, permission_response, update_permission,
+};
+use crate::app::plugin::api::plugin_api;
+use crate::app::rate_limit::dto::rate_limit;
+use crate::app::role::dto::{create_role, pagination as role_pagination, role_response, update_role, update_role_menu};
+use crate::app::system_metric::dto::{create_system_metric, system_metric_query, system_metric_response};
+use crate::app::task::dto::{
+    create_task_scheduler, delete_task_result, task_control, task_result_query, task_result_response,
+    task_scheduler_query, task_scheduler_response, update_task_scheduler,
+};
+use crate::app::user::dto::{
+    change_password, create_user, import_export_user, pagination as user_pagination, profile, update_user,
+    user_response,
+};
+use crate::app::user_role::dto::{assign_user_roles, get_user_roles};
+use crate::common::pagination::PageData;
+
+use code_generator_plugin::dto::{codegen_dto as codegen, common as codegen_common};
+use config_plugin::dto::{common as config_common, config_dto};
+use dict_plugin::dto::{common as dict_common, dict_data_dto, dict_type_dto};
+use email_plugin::dto::{common as email_common, email_dto};
+use notice_plugin::dto::{common as notice_common, notice_dto};
+use oauth2_plugin::dto::oauth_dto;
+
+/// 管理员重置用户密码请求（处理器以 JSON 对象接收）
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct ResetUserPasswordBody {
+    /// 新密码
+    password: String,
+}
+
+pub fn groups() -> Vec<ApiGroup> {
+    vec![
+        ApiGroup::new("系统", "服务状态与探针", "", vec![
+            get("/", "欢迎信息").ok::<String>().public(),
+            get("/health", "服务存活检查").ok::<String>().public(),
+            get("/livez", "存活探针：后台任务卡死时返回 503").plain::<monitor::ProbeReport>().public(),
+            get("/readyz", "就绪探针：依赖不可用或正在停机时返回 503").plain::<monitor::ProbeReport>().public(),
+            get("/metrics", "导出 Prometheus 指标").raw("text/plain").auth(METRICS_TOKEN),
+        ]),
+        ApiGroup::new("认证", "登录、令牌、注册与找回密码", "/api/v1/auth", vec![
+            get("/captcha", "获取图形验证码").query::<CaptchaQuery>().ok::<captcha::CaptchaResponse>().public(),
+            get("/codes", "获取当前用户的权限码").ok::<Vec<String>>(),
+            post("/login", "用户登录").json::<login::LoginRequest>().ok::<login::LoginResponse>().public(),
+            post("/refresh", "刷新访问令牌").json::<token::RefreshTokenRequest>().ok::<token::RefreshTokenResponse>(),
+            post("/logout", "退出登录").json::<token::LogoutRequest>().plain_any("{code, message, data}"),
+            post("/register", "用户注册：校验后发送邮箱验证码，验证通过才创建用户")
+                .json::<account::RegisterRequest>()
+                .ok::<account::RegisterResponse>()
+                .public(),
+            post("/register/verify", "验证注册邮箱（邮件链接令牌或邮箱 + 验证码）")
+                .json::<account::VerifyRegisterRequest>()
+                .ok::<account::VerifyRegisterResponse>()
+                .public(),
+            post("/password/forgot", "找回密码：向绑定邮箱发送重置链接")
+                .json::<account::ForgotPasswordRequest>()
+                .ok::<String>()
+                .public(),
+            post("/password/reset", "使用重置令牌设置新密码")
+                .json::<account::ResetForgottenPasswordRequest>()
+                .ok::<String>()
+                .public(),
+        ]),
+        ApiGroup::new("OIDC", "OpenID Connect 授权服务协议端点", "", vec![
+            get("/.well-known/jwks.json", "令牌签名公钥（RFC 7517）").plain_any("JWK Set").public(),
+            get("/.well-known/openid-configuration", "发现文档").plain_any("OpenID Provider Metadata").public(),
+            get("/api/v1/oidc/authorize", "授权端点")
+                .query::<oidc::AuthorizeQuery>()
+                .ok::<oidc::AuthorizeResponse>()
+                .redirect("未登录时跳转登录页，同意后携带授权码跳转 redirect_uri")
+                .public(),
+            post("/api/v1/oidc/token", "令牌端点")
+                .form::<oidc::TokenRequest>()
+                .plain::<oidc::TokenResponse>()
+                .auth(CLIENT_BASIC),
+            post("/api/v1/oidc/introspect", "令牌内省端点（RFC 7662）")
+                .form::<oidc::TokenParam>()
+                .plain_any("令牌状态，active 为 false 时不含其他字段")
+                .auth(CLIENT_BASIC),
+            post("/api/v1/oidc/revoke", "令牌撤销端点（RFC 7009）")
+                .form::<oidc::TokenParam>()
+                .plain_any("空对象")
+                .auth(CLIENT_BASIC),
+            get("/api/v1/oidc/userinfo", "用户信息端点").plain_any("按授权范围返回的用户声明"),
+            post("/api/v1/oidc/userinfo", "用户信息端点").plain_any("按授权范围返回的用户声明"),
+        ]),
+        ApiGroup::new("OIDC 客户端", "OIDC 客户端与签名密钥管理", "/api/v1/sys/oidc", vec![
+            get("/clients", "获取客户端列表").ok::<Vec<oidc_client::OidcClientDetail>>(),
+            post("/clients", "注册客户端（返回的客户端密钥仅展示一次）")
+                .json::<oidc_client::CreateOidcClientRequest>()
+                .ok::<oidc_client::OidcClientSecretResponse>()
+                .created(),
+            put("/clients/{id}", "更新客户端")
+                .json::<oidc_client::UpdateOidcClientRequest>()
+                .ok::<oidc_client::OidcClientDetail>(),
+            delete("/clients/{id}", "删除客户端").ok::<String>(),
+            post("/clients/{id}/secret", "重置客户端密钥").ok::<oidc_client::OidcClientSecretResponse>(),
+            post("/keys/rotate", "立即轮换签名密钥（旧密钥继续用于验签直至保留期结束）")
+                .ok::<oidc_client::RotateKeyResponse>(),
+        ]),
+        ApiGroup::new("用户", "用户管理与当前用户资料", "/api/v1/sys/users", vec![
+            get("", "获取用户列表（分页）")
+                .query::<user_pagination::UserPaginationQuery>()
+                .ok::<PageData<user_response::UserListItem>>(),
+            post("", "创建用户")
+                .json::<create_user::CreateUserRequest>()
+                .ok::<create_user::CreateUserResponse>()
+                .created(),
+            delete("/batch", "批量删除用户").json::<Vec<i64>>().ok::<String>(),
+            post("/batch-import", "批量导入用户")
+                .json::<import_export_user::BatchImportUsersRequest>()
+                .ok::<import_export_user::BatchImportUsersResponse>(),
+            post("/download-template", "下载用户导入模板")
+                .json::<import_export_user::DownloadTemplateRequest>()
+                .ok::<import_export_user::DownloadTemplateResponse>(),
+            post("/export", "导出用户")
+                .json::<import_export_user::ExportUsersRequest>()
+                .ok::<import_export_user::ExportUsersResponse>(),
+            post("/import", "导入用户")
+                .json::<import_export_user::ImportUsersRequest>()
+                .ok::<import_export_user::ImportUsersResponse>(),
+            get("/me", "获取当前用户信息").ok::<user_response::CurrentUserResponse>(),
+            put("/me/avatar", "更新当前用户头像").json::<profile::UpdateAvatarRequest>().ok::<String>(),
+            put("/me/email", "申请更换当前用户邮箱，验证码发送到新邮箱")
+                .json::<profile::UpdateEmailRequest>()
+                .ok::<String>(),
+            put("/me/email/verify", "验证新邮箱，通过后更新").json::<profile::VerifyEmailRequest>().ok::<String>(),
+            put("/me/locale", "更新当前用户偏好语言").json::<profile::UpdateLocaleRequest>().ok_any("{locale}"),
+            put("/me/nickname", "更新当前用户昵称").json::<profile::UpdateNicknameRequest>().ok::<String>(),
+            put("/me/password", "更新当前用户密码").json::<change_password::ChangePasswordRequest>().ok::<String>(),
+            put("/me/phone", "更新当前用户手机号，需确认当前密码").json::<profile::UpdatePhoneRequest>().ok::<String>(),
+            get("/{id}", "获取用户详情").ok::<user_response::UserDetailResponse>(),
+            put("/{id}", "更新用户").json::<update_user::UpdateUserRequest>().ok::<String>(),
+            delete("/{id}", "删除用户").ok::<String>(),
+            put("/{id}/password", "重置用户密码（管理员操作）").json::<ResetUserPasswordBody>().ok::<String>(),
+            put("/{id}/permissions", "更新用户权限（切换状态）")
+                .param("type", "权限类型（必填）：superuser、staff、status、multilogin")
+                .ok::<String>(),
+            get("/{id}/roles", "获取用户角色列表").ok::<Vec<String>>(),
+            patch("/{id}/status", "更新用户状态").json::<i32>().ok::<String>(),
+        ]),
+        ApiGroup::new("角色", "角色及其菜单、权限、数据权限", "/api/v1/sys/roles", vec![
+            get("", "获取角色列表（分页）")
+                .query::<role_pagination::RolePaginationQuery>()
+                .ok::<PageData<role_response::RoleListItem>>(),
+            post("", "创建角色")
+                .json::<create_role::CreateRoleRequest>()
+                .ok::<create_role::CreateRoleResponse>()
+                .created(),
+            delete("", "批量删除角色").json::<Vec<i64>>().ok::<String>(),
+            get("/all", "获取所有角色").ok::<Vec<role_response::RoleListItem>>(),
+            get("/{id}", "获取角色详情").ok::<role_response::RoleDetailResponse>(),
+            put("/{id}", "更新角色").json::<update_role::UpdateRoleRequest>().ok::<String>(),
+            delete("/{id}", "删除角色").ok::<String>(),
+            get("/{id}/menus", "获取角色菜单").ok_any("角色菜单树"),
+            put("/{id}/menus", "更新角色菜单").json::<update_role_menu::UpdateRoleMenuRequest>().ok::<String>(),
+            get("/{id}/permissions", "获取角色权限树")
+                .ok::<Vec<role_response::RolePermissionTree>>()
+                .component::<role_response::RolePermissionTree>(),
+            post("/{id}/permissions", "分配角色权限").json::<Vec<i64>>().ok::<String>(),
+            get("/{id}/scopes", "获取角色数据权限").ok::<Vec<i64>>(),
+            put("/{id}/scopes", "更新角色数据权限").json::<update_role_menu::UpdateRoleScopeRequest>().ok::<String>(),
+        ]),
+        ApiGroup::new("菜单", "菜单管理与侧边栏", "/api/v1/sys/menus", vec![
+            get("", "获取菜单树")
+                .query::<menu_pagination::MenuPaginationQuery>()
+                .ok::<Vec<menu_response::MenuTreeNode>>()
+                .component::<menu_response::MenuTreeNode>(),
+            post("", "创建菜单")
+                .json::<create_menu::CreateMenuRequest>()
+                .ok::<create_menu::CreateMenuResponse>()
+                .created(),
+            get("/list", "获取菜单列表（平铺）").ok::<Vec<menu_response::MenuListItem>>(),
+            get("/sidebar", "获取侧边栏菜单").ok::<Vec<VbenMenuNode>>().component::<VbenMenuNode>(),
+            get("/tree", "获取菜单树")
+                .query::<menu_pagination::MenuPaginationQuery>()
+                .ok::<Vec<menu_response::MenuTreeNode>>()
+                .component::<menu_response::MenuTreeNode>(),
+            get("/{id}", "获取菜单详情").ok::<menu_response::MenuDetailResponse>(),
+            put("/{id}", "更新菜单").json::<update_menu::UpdateMenuRequest>().ok::<String>(),
+            delete("/{id}", "删除菜单").ok::<String>(),
+        ]),
+        ApiGroup::new("部门", "部门管理", "/api/v1/sys/depts", vec![
+            get("", "获取部门列表（扁平列表，支持筛选）")
+                .query::<dept_query::DeptListQuery>()
+                .ok::<Vec<dept_query::DeptListItem>>(),
+            post("", "创建部门")
+                .json::<create_dept::CreateDeptRequest>()
+                .ok::<create_dept::CreateDeptResponse>()
+                .created(),
+            get("/tree", "获取部门树")
+                .query::<dept_query::DeptTreeQuery>()
+                .ok::<Vec<dept_query::DeptTreeNode>>()
+                .component::<dept_query::DeptTreeNode>(),
+            get("/{id}", "获取部门详情").ok::<dept_response::DeptDetailResponse>(),
+            put("/{id}", "更新部门").json::<create_dept::UpdateDeptRequest>().ok::<create_dept::UpdateDeptResponse>(),
+            delete("/{id}", "删除部门").ok::<String>().status(204),
+            put("/{id}/status", "更改部门状态").json::<dept_api::StatusChangeRequest>().ok::<String>(),
+        ]),
+        ApiGroup::new("权限", "权限管理", "/api/v1/sys/permissions", vec![
+            get("", "获取权限列表（分页）")
+                .query::<permission_pagination::PermissionPaginationQuery>()
+                .ok::<permission_pagination::PermissionPaginationResponse>(),
+            post("", "创建权限")
+                .json::<create_permission::CreatePermissionRequest>()
+                .ok::<create_permission::CreatePermissionResponse>()
+                .created(),
+            get("/list", "获取权限列表（平铺）").ok::<Vec<permission_response::PermissionListItem>>(),
+            get("/tree", "获取权限树")
+                .ok::<Vec<permission_response::PermissionTreeNode>>()
+                .component::<permission_response::PermissionTreeNode>(),
+            get("/{id}", "获取权限详情").ok::<permission_response::PermissionDetailResponse>(),
+            put("/{id}", "更新权限").json::<update_permission::UpdatePermissionRequest>().ok::<String>(),
+            delete("/{id}", "删除权限").ok::<String>(),
+        ]),
+        ApiGroup::new("用户角色", "用户与角色的关联", "/api/v1/sys/user-roles/user-roles", vec![
+            post("/assign", "分配用户角色")
+                .json::<assign_user_roles::AssignUserRolesRequest>()
+                .ok::<assign_user_roles::AssignUserRolesResponse>(),
+            get("/{user_id}", "获取用户角色").ok::<get_user_roles::GetUserRolesResponse>(),
+            get("/{user_id}/check/{role_code}", "检查用户是否有指定角色").ok::<bool>(),
+        ]),
+        ApiGroup::new("角色权限", "角色与权限的关联", "/api/v1/sys/role-permissions/role-permissions", vec![
+            post("/assign", "分配角色权限").json::<Vec<i64>>().ok::<String>(),
+            get("/{role_id}", "获取角色权限树")
+                .ok::<Vec<role_response::RolePermissionTree>>()
+                .component::<role_response::RolePermissionTree>(),
+            get("/{role_id}/check/{permission_code}", "检查角色是否有指定权限").ok::<bool>(),
+        ]),
+        ApiGroup::new("数据权限", "数据权限及其规则", "/api/v1/sys/data-scopes", vec![
+            get("", "获取数据权限列表（分页）")
+                .query::<data_scope_query::DataScopeQueryParams>()
+                .ok::<data_scope_response::DataScopeListResponse>(),
+            post("", "创建数据权限")
+                .json::<data_scope_config::CreateDataScopeRequest>()
+                .ok::<data_scope_response::DataScopeDetailResponse>()
+                .created(),
+            delete("", "批量删除数据权限").json::<data_scope_config::DeleteDataScopeRequest>().ok::<String>(),
+            get("/all", "获取所有数据权限").ok::<data_scope_response::DataScopeListResponse>(),
+            get("/{id}", "获取数据权限详情").ok::<data_scope_response::DataScopeDetailResponse>(),
+            put("/{id}", "更新数据权限")
+                .json::<data_scope_config::UpdateDataScopeRequest>()
+                .ok::<data_scope_response::DataScopeDetailResponse>(),
+            get("/{id}/rules", "获取数据权限规则")
+                .ok::<Vec<data_scope_query::DataScopeTreeNode>>()
+                .component::<data_scope_query::DataScopeTreeNode>(),
+            put("/{id}/rules", "更新数据权限规则")
+                .json::<data_scope_config::UpdateDataScopeRuleRequest>()
+                .ok::<String>(),
+        ]),
+        ApiGroup::new("数据规则", "数据过滤规则", "/api/v1/sys/data-rules", vec![
+            get("", "获取数据规则列表（分页）")
+                .query::<data_rule_query::DataRulePaginationQuery>()
+                .ok::<data_rule_response::DataRuleListResponse>(),
+            post("", "创建数据规则")
+                .json::<data_rule_config::CreateDataRuleRequest>()
+                .ok::<data_rule_response::DataRuleDetailResponse>()
+                .created(),
+            get("/all", "获取所有数据规则")
+                .query::<data_rule_query::DataRuleQueryParams>()
+                .ok::<Vec<data_rule_response::DataRuleSimpleResponse>>(),
+            delete("/batch", "批量删除数据规则")
+                .json::<Vec<i64>>()
+                .ok::<data_rule_response::DataRuleBatchOperationResponse>(),
+            get("/models", "获取数据规则模型列表")
+                .param("keyword", "模型名关键字")
+                .ok::<data_rule_response::DataRuleModelListResponse>(),
+            get("/models/{model}/columns", "获取模型列信息")
+                .param("include_system", "是否包含系统字段")
+                .ok::<data_rule_response::DataRuleColumnListResponse>(),
+            get("/{id}", "获取数据规则详情").ok::<data_rule_response::DataRuleDetailResponse>(),
+            put("/{id}", "更新数据规则")
+                .json::<data_rule_config::UpdateDataRuleRequest>()
+                .ok::<data_rule_response::DataRuleDetailResponse>(),
+            delete("/{id}", "删除数据规则").ok::<String>(),
+        ]),
+        ApiGroup::new("文件", "文件上传、下载与预览", "/api/v1/sys/files/file-infos", vec![
+            get("", "获取文件列表").ok::<Vec<file_info_api::FileInfoItem>>(),
+            post("/upload", "上传文件").multipart("file", "上传的文件").ok::<String>().created(),
+            get("/{id}", "获取文件信息").ok::<String>(),
+            delete("/{id}", "删除文件").ok::<String>().status(204),
+            get("/{id}/download", "文件下载").ok::<file_info_response::DownloadFileResponse>(),
+            get("/{id}/preview", "文件预览")
+                .json::<preview_thumbnail::PreviewFileRequest>()
+                .ok::<preview_thumbnail::PreviewFileResponse>(),
+            post("/{id}/thumbnail", "生成缩略图")
+                .json::<preview_thumbnail::GenerateThumbnailRequest>()
+                .ok::<preview_thumbnail::GenerateThumbnailResponse>(),
+        ]),
+        ApiGroup::new("日志级别", "运行时日志过滤规则", "/api/v1/sys/log-levels", vec![
+            get("", "分页查询日志级别")
+                .query::<log_level_query::LogLevelQuery>()
+                .ok::<PageData<log_level_response::LogLevelDetail>>(),
+            post("", "创建日志级别")
+                .json::<create_log_level::SaveLogLevelRequest>()
+                .ok::<log_level_response::LogLevelDetail>()
+                .created(),
+            delete("", "批量删除日志级别").json::<create_log_level::DeleteLogLevelRequest>().ok::<String>(),
+            get("/runtime", "当前实例生效的日志过滤规则").ok::<log_level_response::RuntimeLogFilter>(),
+            get("/{id}", "获取日志级别详情").ok::<log_level_response::LogLevelDetail>(),
+            put("/{id}", "更新日志级别")
+                .json::<create_log_level::SaveLogLevelRequest>()
+                .ok::<log_level_response::LogLevelDetail>(),
+            put("/{id}/disable", "禁用日志级别").ok::<log_level_response::LogLevelDetail>(),
+            put("/{id}/enable", "启用日志级别，可选若干分钟后自动禁用")
+                .json::<create_log_level::EnableLogLevelRequest>()
+                .optional_body()
+                .ok::<log_level_response::LogLevelDetail>(),
+        ]),
+        ApiGroup::new("日志文件", "滚动日志文件下载（仅超级管理员）", "/api/v1/sys/log-files", vec![
+            get("", "获取日志文件列表").ok::<Vec<log_file::LogFileInfo>>(),
+            get("/{name}", "下载日志文件").raw("application/octet-stream"),
+        ]),
+        ApiGroup::new("插件", "插件安装、卸载与启用状态", "/api/v1/sys/plugins", vec![
+            get("", "获取所有插件信息").ok::<Vec<plugin_api::PluginResponse>>(),
+            post("", "安装插件（上传 zip 或指定 git 仓库）")
+                .query::<plugin_api::InstallPluginQuery>()
+                .multipart("file", "插件 zip 包")
+                .optional_body()
+                .ok::<String>(),
+            get("/changed", "检查插件是否变更").ok::<bool>(),
+            get("/{plugin}", "下载插件").raw("application/zip"),
+            delete("/{plugin}", "卸载插件").ok_empty(),
+            put("/{plugin}/status", "切换插件启用状态").ok_empty(),
+        ]),
+        ApiGroup::new("告警", "告警规则、事件与静默", "/api/v1/sys/alerts", vec![
+            get("/events", "分页查询告警事件")
+                .query::<alert_event::AlertEventQuery>()
+                .ok::<PageData<alert_event::AlertEventDetail>>(),
+            post("/events/{id}/ack", "确认告警").ok::<alert_event::AlertEventDetail>(),
+            get("/rules", "获取规则列表").ok::<Vec<alert_rule::AlertRuleDetail>>(),
+            post("/rules", "创建规则")
+                .json::<alert_rule::SaveAlertRuleRequest>()
+                .ok::<alert_rule::AlertRuleDetail>()
+                .created(),
+            get("/rules/{id}", "获取规则详情").ok::<alert_rule::AlertRuleDetail>(),
+            put("/rules/{id}", "更新规则")
+                .json::<alert_rule::SaveAlertRuleRequest>()
+                .ok::<alert_rule::AlertRuleDetail>(),
+            delete("/rules/{id}", "删除规则").ok::<String>(),
+            post("/rules/{id}/test", "使用合成数据试运行规则（不写入事件、不发送通知）")
+                .json::<alert_rule::TestAlertRuleRequest>()
+                .ok::<alert_rule::TestAlertRuleResponse>(),
+            get("/silences", "获取未过期的静默").ok::<Vec<alert_event::AlertSilenceDetail>>(),
+            post("/silences", "创建静默")
+                .json::<alert_event::CreateAlertSilenceRequest>()
+                .ok::<alert_event::AlertSilenceDetail>()
+                .created(),
+            delete("/silences/{id}", "删除静默（提前结束）").ok::<String>(),
+        ]),
+        ApiGroup::new("限流", "限流规则与限流桶（仅超级管理员）", "/api/v1/sys/rate-limits", vec![
+            get("/rules", "获取当前生效的限流规则").ok::<Vec<rate_limit::RateLimitRuleDetail>>(),
+            delete("/rules/{name}/buckets", "重置某条规则的全部限流桶").ok::<String>(),
+            get("/buckets", "分页查询限流桶")
+                .query::<rate_limit::RateLimitBucketQuery>()
+                .ok::<PageData<rate_limit::RateLimitBucketDetail>>(),
+            delete("/buckets", "重置指定的限流桶")
+                .json::<rate_limit::ResetRateLimitBucketsRequest>()
+                .ok::<String>(),
+        ]),
+        ApiGroup::new("系统概览", "模块信息与系统状态", "/api/v1/complete", vec![
+            get("/health", "健康检查").ok::<health_check::HealthCheckResponse>(),
+            get("/modules", "获取模块信息").ok::<module_info::ModuleInfoResponse>(),
+            get("/summary", "获取系统摘要").ok::<complete_api::SystemSummary>(),
+            get("/system-status", "获取系统状态")
+                .query::<complete_api::SystemStatusQuery>()
+                .ok::<system_status::SystemStatusResponse>(),
+        ]),
+        ApiGroup::new("任务", "任务调度与执行结果", "/api/v1/tasks", vec![
+            get("/registered", "获取已注册的任务列表").ok::<Vec<task_control::RegisteredTaskDetail>>(),
+            get("/results", "任务结果列表（分页）")
+                .query::<task_result_query::TaskResultListQuery>()
+                .ok::<PageData<task_result_response::TaskResultListItem>>(),
+            delete("/results", "批量删除任务结果").json::<delete_task_result::DeleteTaskRequest>().ok::<String>(),
+            get("/results/{id}", "获取任务结果详情").ok::<task_result_response::TaskResultDetailResponse>(),
+            get("/schedulers", "任务调度器列表（分页）")
+                .query::<task_scheduler_query::TaskSchedulerListQuery>()
+                .ok::<PageData<task_scheduler_response::TaskSchedulerListItem>>(),
+            post("/schedulers", "创建任务调度器")
+                .json::<create_task_scheduler::CreateTaskSchedulerRequest>()
+                .ok::<create_task_scheduler::CreateTaskSchedulerResponse>()
+                .created(),
+            get("/schedulers/all", "获取所有任务调度器")
+                .ok::<Vec<task_scheduler_response::TaskSchedulerDetailResponse>>(),
+            get("/schedulers/{id}", "获取任务调度器详情").ok::<task_scheduler_response::TaskSchedulerDetailResponse>(),
+            put("/schedulers/{id}", "更新任务调度器")
+                .json::<update_task_scheduler::UpdateTaskSchedulerRequest>()
+                .ok::<update_task_scheduler::UpdateTaskSchedulerResponse>(),
+            delete("/schedulers/{id}", "删除任务调度器").ok::<String>(),
+            post("/schedulers/{id}/executions", "手动执行任务").ok::<String>(),
+            put("/schedulers/{id}/status", "切换任务调度器启用状态").ok::<String>(),
+            delete("/{task_id}/cancel", "撤销任务").path_str("task_id").ok::<String>(),
+        ]),
+        ApiGroup::new("登录日志", "登录与注销记录", "/api/v1/logs/login", vec![
+            get("", "获取登录日志列表")
+                .query::<login_log_query::LoginLogPaginationQuery>()
+                .ok::<login_log_query::LoginLogPaginationResponse>(),
+            post("", "创建登录日志")
+                .json::<create_login_log::CreateLoginLogRequest>()
+                .ok::<create_login_log::CreateLoginLogResponse>()
+                .created(),
+            delete("/batch", "批量删除登录日志").json::<delete_login_logs::DeleteLoginLogsRequest>().ok::<String>(),
+            delete("/clear", "清空登录日志").ok::<String>(),
+            post("/logout", "创建注销日志")
+                .json::<create_login_log::CreateLogoutLogRequest>()
+                .ok::<create_login_log::CreateLogoutLogResponse>()
+                .created(),
+            get("/statistics", "获取登录日志统计").ok::<login_log_response::LoginLogStatistics>(),
+            get("/{id}", "获取登录日志详情").ok::<login_log_response::LoginLogDetailResponse>(),
+            delete("/{id}", "删除登录日志").ok::<String>().status(204),
+        ]),
+        ApiGroup::new("操作日志", "接口操作记录", "/api/v1/logs/opera", vec![
+            get("", "获取操作日志列表")
+                .query::<opera_log_query::OperaLogPaginationQuery>()
+                .ok::<opera_log_query::OperaLogPaginationResponse>(),
+            delete("", "批量删除操作日志").json::<delete_opera_logs::DeleteOperaLogsRequest>().ok::<String>(),
+            delete("/all", "清空操作日志").ok::<String>(),
+            get("/{id}", "获取操作日志详情").ok::<opera_log_response::OperaLogDetailResponse>(),
+            delete("/{id}", "删除操作日志").ok::<String>().status(204),
+        ]),
+        ApiGroup::new("监控", "服务器、Redis、接口与在线用户监控", "/api/v1/monitors", vec![
+            get("/api-metrics", "获取API指标（自进程启动以来的 HTTP 请求统计）").ok::<monitor::ApiMetrics>(),
+            get("/health", "健康检查").ok::<monitor::HealthStatus>(),
+            get("/redis", "获取Redis监控信息").ok::<monitor::RedisMetrics>(),
+            get("/registered", "获取已注册任务列表").ok::<Vec<monitor::TaskInfo>>(),
+            get("/server", "获取服务器监控信息").ok::<monitor::ServerMetrics>(),
+            get("/sessions", "获取在线用户列表")
+                .param("username", "按用户名筛选")
+                .ok::<Vec<monitor::OnlineSession>>(),
+            get("/sessions/{id}", "获取在线用户详情").ok::<String>(),
+            delete("/sessions/{id}", "踢出指定在线用户").path_str("id").ok::<String>(),
+            get("/status", "获取系统状态").ok::<monitor::SystemStatus>(),
+        ]),
+        ApiGroup::new("系统指标", "主机指标上报与查询", "/api/v1/monitors/system-metrics", vec![
+            get("", "获取系统指标列表")
+                .query::<system_metric_query::SystemMetricQuery>()
+                .ok::<system_metric_query::SystemMetricListResponse>(),
+            post("", "上报单个系统指标")
+                .json::<create_system_metric::CreateSystemMetricRequest>()
+                .ok::<String>()
+                .created(),
+            post("/batch", "批量上报系统指标")
+                .json::<create_system_metric::BatchCreateSystemMetricRequest>()
+                .ok::<create_system_metric::BatchCreateSystemMetricResponse>()
+                .created(),
+            get("/history", "获取单个指标历史数据")
+                .query::<system_metric_query::MetricHistoryQuery>()
+                .ok::<system_metric_response::MetricHistoryResponse>(),
+            get("/hosts", "获取主机概览").ok::<Vec<system_metric_response::HostOverview>>(),
+            get("/real-time", "获取各主机最新指标")
+                .query::<system_metric_query::RealTimeMetricQuery>()
+                .ok::<system_metric_response::RealTimeMetricResponse>(),
+            get("/statistics", "获取指标统计")
+                .query::<system_metric_query::MetricStatisticsQuery>()
+                .ok::<system_metric_response::MetricStatisticsResponse>(),
+            get("/type-statistics", "获取指标类型统计").ok::<Vec<system_metric_response::MetricTypeStatistics>>(),
+            get("/{id}", "获取系统指标详情").ok::<system_metric_response::SystemMetricDetailResponse>(),
+        ]),
+        // 以下由插件提供，插件未启用时不挂载
+        ApiGroup::new("通知公告", "通知公告插件", "/api/v1/sys/notices", vec![
+            get("", "分页获取所有通知公告")
+                .query::<notice_dto::NoticeQuery>()
+                .query::<notice_common::PaginationQuery>()
+                .ok::<notice_common::PageData<notice_dto::NoticeDetail>>(),
+            post("", "创建通知公告").json::<notice_dto::CreateNoticeParam>().ok::<notice_dto::NoticeDetail>(),
+            delete("", "批量删除通知公告").json::<notice_common::DeleteBatchParam>().ok_empty(),
+            get("/all", "获取所有通知公告").ok::<Vec<notice_dto::NoticeDetail>>(),
+            put("/read-all", "全部标记为已读").ok_empty(),
+            get("/unread-count", "获取当前用户的未读通知数量").ok::<notice_dto::UnreadCount>(),
+            get("/visible", "获取当前用户可见的通知公告（含已读状态）").ok::<Vec<notice_dto::UserNoticeDetail>>(),
+            get("/{pk}", "获取通知公告详情").ok::<notice_dto::NoticeDetail>(),
+            put("/{pk}", "更新通知公告").json::<notice_dto::UpdateNoticeParam>().ok_empty(),
+            put("/{pk}/read", "标记通知为已读").ok_empty(),
+        ]),
+        ApiGroup::new("参数配置", "参数配置插件", "/api/v1/sys/configs", vec![
+            get("", "分页获取所有配置")
+                .query::<config_dto::ConfigQuery>()
+                .query::<config_common::PaginationQuery>()
+                .ok::<config_common::PageData<config_dto::ConfigDetail>>(),
+            post("", "创建配置").json::<config_dto::CreateConfigParam>().ok::<config_dto::ConfigDetail>(),
+            delete("", "批量删除配置").json::<config_common::DeleteBatchParam>().ok_empty(),
+            get("/all", "获取所有配置")
+                .query::<config_dto::GetAllConfigQuery>()
+                .ok::<Vec<config_dto::ConfigDetail>>(),
+            get("/key/{key}", "根据key获取配置（带缓存）").ok::<config_dto::ConfigDetail>(),
+            post("/refresh", "刷新配置缓存").ok_empty(),
+            get("/{pk}", "获取配置详情").ok::<config_dto::ConfigDetail>(),
+            put("/{pk}", "更新配置").json::<config_dto::UpdateConfigParam>().ok_empty(),
+        ]),
+        ApiGroup::new("字典类型", "数据字典插件", "/api/v1/sys/dict-types", vec![
+            get("", "分页获取所有字典类型")
+                .query::<dict_type_dto::DictTypeQuery>()
+                .query::<dict_common::PaginationQuery>()
+                .ok::<dict_common::PageData<dict_type_dto::DictTypeDetail>>(),
+            post("", "创建字典类型").json::<dict_type_dto::CreateDictTypeParam>().ok::<dict_type_dto::DictTypeDetail>(),
+            delete("", "批量删除字典类型").json::<dict_common::DeleteBatchParam>().ok_empty(),
+            get("/all", "获取所有字典类型").ok::<Vec<dict_type_dto::DictTypeDetail>>(),
+            get("/{pk}", "获取字典类型详情").ok::<dict_type_dto::DictTypeDetail>(),
+            put("/{pk}", "更新字典类型").json::<dict_type_dto::UpdateDictTypeParam>().ok_empty(),
+        ]),
+        ApiGroup::new("字典数据", "数据字典插件", "/api/v1/sys/dict-datas", vec![
+            get("", "分页获取所有字典数据")
+                .query::<dict_data_dto::DictDataQuery>()
+                .query::<dict_common::PaginationQuery>()
+                .ok::<dict_common::PageData<dict_data_dto::DictDataDetail>>(),
+            post("", "创建字典数据").json::<dict_data_dto::CreateDictDataParam>().ok::<dict_data_dto::DictDataDetail>(),
+            delete("", "批量删除字典数据").json::<dict_common::DeleteBatchParam>().ok_empty(),
+            get("/all", "获取所有字典数据").ok::<Vec<dict_data_dto::DictDataDetail>>(),
+            get("/type-codes/{code}", "根据类型编码获取字典数据列表").ok::<Vec<dict_data_dto::DictDataDetail>>(),
+            get("/{pk}", "获取字典数据详情").ok::<dict_data_dto::DictDataDetail>(),
+            put("/{pk}", "更新字典数据").json::<dict_data_dto::UpdateDictDataParam>().ok_empty(),
+        ]),
+        ApiGroup::new("代码生成", "代码生成插件", "/api/v1/generates", vec![
+            get("/businesses", "分页获取业务列表")
+                .param("table_name", "按表名筛选")
+                .query::<codegen_common::PaginationQuery>()
+                .ok::<codegen_common::PageData<codegen::GenBusinessDetail>>(),
+            post("/businesses", "创建业务")
+                .json::<codegen::CreateGenBusinessParam>()
+                .ok::<codegen::GenBusinessDetail>(),
+            get("/businesses/all", "获取所有业务").ok::<Vec<codegen::GenBusinessDetail>>(),
+            post("/businesses/import", "导入表结构")
+                .json::<codegen::ImportTableParam>()
+                .ok::<codegen::GenBusinessDetail>(),
+            get("/businesses/{id}", "获取业务详情").ok::<codegen::GenBusinessDetail>(),
+            put("/businesses/{id}", "更新业务").json::<codegen::UpdateGenBusinessParam>().ok_empty(),
+            delete("/businesses/{id}", "删除业务").ok_empty(),
+            get("/businesses/{id}/columns", "获取业务的所有列").ok::<Vec<codegen::GenColumnDetail>>(),
+            get("/businesses/{id}/download", "下载业务生成代码ZIP").raw("application/zip"),
+            post("/businesses/{id}/generate", "生成代码到工作区，存在手动修改的文件时需 force=true")
+                .query::<codegen::GenerateWorkspaceParam>()
+                .ok::<codegen::GenChangeset>(),
+            get("/businesses/{id}/paths", "获取业务代码生成路径").ok::<Vec<String>>(),
+            get("/businesses/{id}/plan", "预演生成代码到工作区（返回变更集与差异，不写入文件）")
+                .query::<codegen::GenerateWorkspaceParam>()
+                .ok::<codegen::GenChangeset>(),
+            get("/businesses/{id}/preview", "预览业务生成代码").ok::<codegen::CodePreview>(),
+            get("/codes/download", "下载代码ZIP")
+                .param("schema", "数据库 schema")
+                .param("table_name", "表名")
+                .param("module_name", "模块名")
+                .raw("application/zip"),
+            post("/codes/generate", "生成代码").json::<codegen::GenerateCodeParam>().ok::<String>(),
+            get("/codes/preview", "生成代码预览")
+                .param("schema", "数据库 schema")
+                .param("table_name", "表名")
+                .param("module_name", "模块名")
+                .ok::<codegen::CodePreview>(),
+            get("/codes/tables", "获取所有表").param("schema", "数据库 schema").ok::<Vec<codegen::TableInfo>>(),
+            get("/codes/tables/{name}/columns", "获取表字段")
+                .param("schema", "数据库 schema")
+                .ok::<Vec<codegen::ColumnInfo>>(),
+            get("/codes/templates", "获取可用模板").ok::<Vec<String>>(),
+        ]),
+        ApiGroup::new("邮件", "电子邮件插件", "/api/v1/email", vec![
+            get("/records", "查询发送记录（分页）")
+                .query::<email_dto::EmailRecordQuery>()
+                .query::<email_common::PaginationQuery>()
+                .ok::<email_common::PageData<email_dto::EmailRecordDetail>>(),
+            get("/records/{id}", "获取发送记录详情").ok::<email_dto::EmailRecordDetail>(),
+            post("/records/{id}/retry", "重新发送失败的邮件").ok::<email_dto::EmailRecordDetail>(),
+            post("/send", "发送邮件").json::<email_dto::SendEmailParam>().ok::<email_dto::EmailRecordDetail>(),
+            post("/send-template", "发送模板邮件")
+                .json::<email_dto::SendTemplateEmailParam>()
+                .ok::<email_dto::EmailRecordDetail>(),
+            get("/suppressions", "查询抑制名单（分页）")
+                .query::<email_dto::SuppressionQuery>()
+                .query::<email_common::PaginationQuery>()
+                .ok::<email_common::PageData<email_dto::EmailSuppressionDetail>>(),
+            post("/suppressions", "添加抑制名单（如外部退信、投诉回调）")
+                .json::<email_dto::AddSuppressionParam>()
+                .ok::<email_dto::EmailSuppressionDetail>(),
+            delete("/suppressions/{id}", "移出抑制名单").ok_empty(),
+            get("/templates", "查询邮件模板（分页，仅当前版本）")
+                .query::<email_dto::EmailTemplateQuery>()
+                .query::<email_common::PaginationQuery>()
+                .ok::<email_common::PageData<email_dto::EmailTemplateDetail>>(),
+            post("/templates", "创建邮件模板")
+                .json::<email_dto::CreateEmailTemplateParam>()
+                .ok::<email_dto::EmailTemplateDetail>(),
+            get("/templates/{id}", "获取邮件模板详情").ok::<email_dto::EmailTemplateDetail>(),
+            put("/templates/{id}", "修改邮件模板（保存为新版本）")
+                .json::<email_dto::EmailTemplateContent>()
+                .ok::<email_dto::EmailTemplateDetail>(),
+            delete("/templates/{id}", "删除邮件模板（含全部版本）").ok_empty(),
+            post("/templates/{id}/activate", "将指定版本设为当前版本").ok::<email_dto::EmailTemplateDetail>(),
+            post("/templates/{id}/preview", "预览邮件模板")
+                .json::<email_dto::PreviewEmailTemplateParam>()
+                .optional_body()
+                .ok::<email_dto::EmailTemplatePreview>(),
+            get("/templates/{id}/versions", "查询邮件模板的历史版本").ok::<Vec<email_dto::EmailTemplateDetail>>(),
+            post("/test-smtp", "测试SMTP配置").json::<email_dto::TestSmtpParam>().ok_empty(),
+        ]),
+        ApiGroup::new("OAuth2", "第三方登录插件", "/api/v1/oauth2", vec![
+            get("/providers", "已启用的提供商（登录页展示）")
+                .ok::<Vec<oauth_dto::OAuthProviderSummary>>()
+                .public(),
+            get("/admin/providers", "获取所有提供商配置").ok::<Vec<oauth_dto::OAuthProviderDetail>>(),
+            post("/admin/providers", "创建提供商")
+                .json::<oauth_dto::OAuthProviderParam>()
+                .ok::<oauth_dto::OAuthProviderDetail>(),
+            put("/admin/providers/{pk}", "更新提供商")
+                .json::<oauth_dto::OAuthProviderParam>()
+                .ok::<oauth_dto::OAuthProviderDetail>(),
+            delete("/admin/providers/{pk}", "删除提供商").ok_empty(),
+            post("/bind", "绑定OAuth账号").json::<oauth_dto::BindOAuthParam>().ok::<oauth_dto::OAuthAuthorizeUrl>(),
+            post("/link", "关联已有账号")
+                .json::<oauth_dto::LinkOAuthParam>()
+                .ok::<oauth_dto::OAuthLoginResult>()
+                .public(),
+            delete("/unbind", "解绑OAuth账号").json::<oauth_dto::UnbindOAuthParam>().ok_empty(),
+            get("/{provider}/authorize", "第三方授权").redirect("跳转到第三方授权页").public(),
+            get("/{provider}/callback", "第三方回调")
+                .query::<oauth_dto::OAuthCallbackQuery>()
+                .ok::<oauth_dto::OAuthLoginResult>()
+                .public(),
+        ]),
+    ]
+}
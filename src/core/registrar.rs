@@ -21,25 +21,25 @@ use owo_colors::OwoColorize;
 
 use crate::database::DatabaseConnection;
 
-use code_generator_plugin::CodeGeneratorPlugin;
-use config_plugin::ConfigPlugin;
-use notice_plugin::NoticePlugin;
-
 use crate::{
     app::admin::api::v1::router as admin_v1_router,
+    app::alert::{router as alert_router, service::AlertService},
     app::auth::router as auth_router,
     app::complete_module::router as complete_router,
     app::data_scope::router as data_scope_router,
-    app::dict_data::router as dict_data_router,
-    app::dict_type::router as dict_type_router,
     app::file_info::router as file_info_router,
-    app::log_level::router as log_level_router,
+    app::log_file::router as log_file_router,
+    app::log_level::{router as log_level_router, service::LogLevelService},
     app::login_log::router as login_log_router,
     app::opera_log::router as opera_log_router,
     app::menu::router as menu_router,
     app::monitor::router as monitor_router,
+    app::system_metric::{router as system_metric_router, SystemMetricCollector},
+    app::oidc::router as oidc_router,
     app::permission::router as permission_router,
     app::plugin::router as plugin_router,
+    app::plugin::PLUGIN_ROUTES,
+    app::rate_limit::router as rate_limit_router,
     app::role::router as role_router,
     app::role_permission::router as role_permission_router,
     app::task::router as task_router,
@@ -48,6 +48,7 @@ use crate::{
     app::dept::router as dept_router,
     common::response::api_response,
     core::SETTINGS,
+    middleware::rate_limit_middleware,
 };
 
 /// 应用状态
@@ -82,23 +83,16 @@ impl AppRegistrar {
         }
     }
 
-    /// 注册应用路由
-    pub async fn build_router(self) -> Router {
-        // 配置CORS - 允许前端域名访问
-        let allowed_origins = [
-            "http://127.0.0.1:8000".parse::<HeaderValue>().unwrap(),
-            "http://localhost:5173".parse::<HeaderValue>().unwrap(),
-        ];
-        let cors = CorsLayer::new()
-            .allow_origin(allowed_origins)
-            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::PATCH, Method::OPTIONS])
-            .allow_headers([axum::http::header::CONTENT_TYPE, axum::http::header::AUTHORIZATION])
-            .allow_credentials(true);
-
+    /// 构建应用内置路由（根路径和 /api/v1），不含文档、插件路由和中间件，不启动后台任务
+    pub(crate) fn routes(&self) -> Router {
         // 根路径
         let root_router = Router::new()
             .route("/", get(|| async { api_response::success("FastAPI Best Architecture - Rust", "Welcome to the API") }))
-            .route("/health", get(|| async { api_response::success("ok", "Service is healthy") }));
+            .route("/health", get(|| async { api_response::success("ok", "Service is healthy") }))
+            .route("/.well-known/jwks.json", get(auth_router::jwks_handler))
+            .merge(oidc_router::well_known_routes())
+            .merge(monitor_router::metrics_routes())
+            .merge(monitor_router::probe_routes());
 
         // API v1 路由 - 创建无状态的路由器
         let mut api_v1_router = Router::new();
@@ -116,26 +110,20 @@ impl AppRegistrar {
         api_v1_router = api_v1_router.nest("/api/v1/sys/role-permissions", role_permission_router::role_permission_routes());
         // 数据权限和数据规则路由（分别注册，避免嵌套错误）
         api_v1_router = api_v1_router.nest("/api/v1/sys", data_scope_router::data_scope_routes());
-        api_v1_router = api_v1_router.nest("/api/v1/sys/dict-types", dict_type_router::create_dict_type_router());
-        api_v1_router = api_v1_router.nest("/api/v1/sys/dict-datas", dict_data_router::dict_data_routes());
         api_v1_router = api_v1_router.nest("/api/v1/sys/files", file_info_router::file_info_routes());
         api_v1_router = api_v1_router.nest("/api/v1/sys/log-levels", log_level_router::log_level_routes());
-        // Notice插件路由 - 需要数据库连接
-        let db_for_notice = crate::database::DatabaseManager::get_connection().await.clone();
-        let notice_router = NoticePlugin::create_router(db_for_notice);
-        api_v1_router = api_v1_router.nest("/api/v1/sys/notices", notice_router);
+        api_v1_router = api_v1_router.nest("/api/v1/sys/log-files", log_file_router::log_file_routes());
         api_v1_router = api_v1_router.nest("/api/v1/sys/plugins", plugin_router::plugin_routes());
-        // Config插件路由 - 需要数据库和Redis连接
-        let db_for_config = crate::database::DatabaseManager::get_connection().await.clone();
-        let redis_for_config = crate::database::redis::RedisManager::get_connection().await
-            .expect("Failed to get Redis connection for Config plugin");
-        let config_router = ConfigPlugin::create_router(db_for_config, redis_for_config);
-        api_v1_router = api_v1_router.nest("/api/v1/sys/configs", config_router);
+        api_v1_router = api_v1_router.nest("/api/v1/sys/oidc", oidc_router::oidc_admin_routes());
+        api_v1_router = api_v1_router.nest("/api/v1/sys/alerts", alert_router::alert_routes());
+        api_v1_router = api_v1_router.nest("/api/v1/sys/rate-limits", rate_limit_router::rate_limit_routes());
 
         // 其他路由（无/sys前缀）
         api_v1_router = api_v1_router.nest("/api/v1", admin_v1_router());
         api_v1_router = api_v1_router.nest("/api/v1", complete_router::complete_routes());
         api_v1_router = api_v1_router.nest("/api/v1", task_router::task_routes());
+        // OIDC 授权服务协议端点 - /api/v1/oidc/*
+        api_v1_router = api_v1_router.nest("/api/v1/oidc", oidc_router::oidc_routes());
         // 日志相关路由 - /api/v1/logs/login/* 和 /api/v1/logs/opera/*
         api_v1_router = api_v1_router.nest("/api/v1/logs/login", login_log_router::login_log_routes());
         api_v1_router = api_v1_router.nest("/api/v1/logs/opera", opera_log_router::opera_log_routes());
@@ -144,27 +132,108 @@ impl AppRegistrar {
         let redis_client = Some((*self.state.redis_client).clone());
         let monitor_router = monitor_router::monitor_routes(redis_client);
         api_v1_router = api_v1_router.nest("/api/v1", monitor_router);
+        api_v1_router = api_v1_router.nest("/api/v1/monitors/system-metrics", system_metric_router::system_metric_routes());
+
+        // 代码生成模块路由（由代码生成器自动注册，请勿删除下方标记）
+        // @codegen:routes
 
-        // 代码生成器插件路由 - 需要数据库连接
-        let db = crate::database::DatabaseManager::get_connection().await.clone();
-        let codegen_router = CodeGeneratorPlugin::create_router(db);
-        api_v1_router = api_v1_router.nest("/api/v1/generates", codegen_router);
+        root_router.merge(api_v1_router)
+    }
+
+    /// 注册应用路由
+    pub async fn build_router(self) -> Router {
+        // 配置CORS - 允许前端域名访问
+        let allowed_origins = [
+            "http://127.0.0.1:8000".parse::<HeaderValue>().unwrap(),
+            "http://localhost:5173".parse::<HeaderValue>().unwrap(),
+        ];
+        let cors = CorsLayer::new()
+            .allow_origin(allowed_origins)
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::PATCH, Method::OPTIONS])
+            .allow_headers([axum::http::header::CONTENT_TYPE, axum::http::header::AUTHORIZATION])
+            .expose_headers(rate_limit_middleware::exposed_headers())
+            .allow_credentials(true);
+
+        // 应用内置路由
+        let mut routes = self.routes();
+
+        // API 文档 - Swagger UI、ReDoc 和 OpenAPI JSON，生产环境默认关闭
+        if self.state.config.is_docs_enabled() {
+            routes = routes.merge(crate::core::openapi::docs_routes());
+        }
+
+        // 加载令牌签名密钥并启动定时轮换
+        if let Err(err) = crate::utils::encrypt::KEYSET.load(crate::database::DatabaseManager::get_connection().await).await {
+            tracing::error!("签名密钥加载失败: {}", err.message);
+        }
+        crate::utils::encrypt::KEYSET.spawn_rotation();
+
+        // 启动邮件发送队列（插件停用时不再接收新邮件，已入队的邮件继续投递）
+        if let Err(err) = email_plugin::EmailPlugin::start_queue(
+            crate::database::DatabaseManager::get_connection().await.clone(),
+            &email_plugin::SmtpConfig::default(),
+            email_plugin::QueueConfig::default(),
+        ) {
+            tracing::error!("邮件发送队列启动失败: {}", err);
+        }
+
+        // 启动通知推送（定时发布的通知到期后推送给在线用户）
+        {
+            let db = crate::database::DatabaseManager::get_connection().await.clone();
+            notice_plugin::NoticePlugin::start_dispatcher(
+                db.clone(),
+                std::sync::Arc::new(crate::app::plugin::notice_backend::NoticeBackendService::new(db)),
+            );
+        }
+
+        // 启动系统指标采集及汇总清理
+        SystemMetricCollector::spawn();
+
+        // 启动告警规则定时评估
+        AlertService::spawn_evaluator();
+
+        // 应用 sys_log_level 中启用的日志级别，并同步其他实例的变更、到期自动恢复
+        LogLevelService::spawn_sync();
 
         // 初始化 Socket.IO 服务器（使用完整的 WebSocket 实现）
         let (socketio_layer, _io) = create_socketio_server();
 
+        // 插件路由 - 由 plugin.toml 和插件启用状态驱动，状态变更后重新挂载无需重启
+        if let Err(err) = PLUGIN_ROUTES.rebuild("./plugins").await {
+            tracing::error!("插件路由挂载失败: {}", err);
+        }
+
         // 构建完整路由 - 应用CORS和追踪层
+        // 未匹配的请求交给当前挂载的插件路由处理
         let mut app = Router::new()
-            .merge(root_router)
-            .merge(api_v1_router)
+            .merge(routes)
+            .fallback(|req: axum::extract::Request| async move { PLUGIN_ROUTES.handle(req).await })
             .layer(cors);
 
+        // 限流中间件（包在 JWT 之内，按用户和角色限流时可读取认证上下文）
+        if self.state.config.rate_limit_enabled {
+            once_cell::sync::Lazy::force(&crate::app::rate_limit::service::RATE_LIMIT_POLICY);
+            app = app.layer(axum::middleware::from_fn(rate_limit_middleware::middleware));
+        }
+
         // 应用JWT认证中间件（在 Socket.IO 之前，这样 Socket.IO 不会被拦截）
         app = app.layer(axum::middleware::from_fn_with_state(
                 self.state.clone(),
                 crate::middleware::jwt_auth_middleware::middleware
             ));
 
+        // 国际化中间件（包在 JWT 之外，认证失败的错误消息同样按请求语言返回）
+        if self.state.config.middleware_i18n {
+            app = app.layer(axum::middleware::from_fn(
+                crate::middleware::i18n_middleware::middleware
+            ));
+        }
+
+        // 请求指标中间件（包在 JWT 之外，认证失败的请求同样计入）
+        app = app.layer(axum::middleware::from_fn(
+            crate::middleware::metrics_middleware::middleware
+        ));
+
         // Debug 模式下添加请求日志中间件
         if self.state.config.debug_mode {
             app = app.layer(axum::middleware::from_fn(
@@ -261,7 +330,16 @@ impl AppRegistrar {
         // 应用配置
         println!("\n{}", "  应用配置".cyan());
         println!("      CORS:        {}", if self.state.config.middleware_cors { "已启用" } else { "已禁用" });
-        println!("      API 文档:    {}", self.state.config.docs_url);
+        println!("      国际化:      {}", if self.state.config.middleware_i18n {
+            format!("已启用（默认 {}）", self.state.config.i18n_default_locale)
+        } else {
+            "已禁用".to_string()
+        });
+        println!("      API 文档:    {}", if self.state.config.is_docs_enabled() {
+            format!("{}（ReDoc {}）", self.state.config.docs_url, self.state.config.redoc_url)
+        } else {
+            "已禁用".to_string()
+        });
 
         println!("{}", "─".repeat(80));
         println!();
@@ -281,7 +359,10 @@ impl AppRegistrar {
         println!();
 
         // axum 0.8 的启动方式
-        axum::serve(listener, router)
+        // 携带连接对端地址，供访问日志和 /metrics IP 白名单使用
+        // 收到停机信号后就绪探针先返回 503，等待流量摘除后停止接收新连接并处理完进行中的请求
+        axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
+            .with_graceful_shutdown(crate::core::health::shutdown_signal())
             .await
             .expect("Server failed to start");
     }
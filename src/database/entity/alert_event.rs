@@ -0,0 +1,39 @@
+//! 告警事件实体 - sys_alert_event表
+//! 每次触发生成一条事件，恢复时更新为 resolved
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+/// 触发中
+pub const STATUS_FIRING: &str = "firing";
+/// 已恢复
+pub const STATUS_RESOLVED: &str = "resolved";
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_alert_event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub rule_id: i64,
+    pub rule_name: String,
+    pub severity: String,
+    /// 规则内的序列标识（规则ID + 主机 + 指标）
+    pub fingerprint: String,
+    pub host_name: Option<String>,
+    pub metric_name: Option<String>,
+    pub status: String,
+    /// 最近一次评估的值
+    pub value: f64,
+    pub threshold: f64,
+    pub started_time: DateTime,
+    pub resolved_time: Option<DateTime>,
+    pub last_notified_time: Option<DateTime>,
+    pub acked_by: Option<String>,
+    pub acked_time: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
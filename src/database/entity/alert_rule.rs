@@ -0,0 +1,46 @@
+//! 告警规则实体 - sys_alert_rule表
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_alert_rule")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+    /// 数据来源（metric / login_failure_rate / opera_error_rate）
+    pub source: String,
+    /// 指标类型（仅 metric 来源）
+    pub metric_type: Option<i32>,
+    /// 指标名称，以 * 结尾表示前缀匹配（仅 metric 来源）
+    pub metric_name: Option<String>,
+    /// 限定主机（仅 metric 来源，为空表示所有主机）
+    pub host_name: Option<String>,
+    /// 比较符（> >= < <= == !=）
+    pub comparator: String,
+    pub threshold: f64,
+    /// 持续时间窗口（秒）
+    pub duration_seconds: i32,
+    /// 窗口内最少样本数，不足时不触发
+    pub min_samples: i32,
+    /// 严重级别（info / warning / critical）
+    pub severity: String,
+    /// 未确认时重复通知间隔（分钟，0 表示不重复）
+    pub repeat_minutes: i32,
+    /// 邮件收件人（JSON数组）
+    pub notify_emails: Option<String>,
+    /// 站内通知投放的角色ID（JSON数组）
+    pub notice_role_ids: Option<String>,
+    pub webhook_url: Option<String>,
+    pub status: i32,
+    pub remark: Option<String>,
+    pub created_time: DateTime,
+    pub updated_time: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
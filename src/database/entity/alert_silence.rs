@@ -0,0 +1,37 @@
+//! 告警静默实体 - sys_alert_silence表
+//! 生效期间匹配的告警照常记录事件，但不发送通知
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_alert_silence")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    /// 限定规则（为空表示所有规则）
+    pub rule_id: Option<i64>,
+    /// 限定主机（为空表示所有主机）
+    pub host_name: Option<String>,
+    pub starts_time: DateTime,
+    pub ends_time: DateTime,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
+    pub created_time: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 判断静默是否覆盖指定告警
+    pub fn matches(&self, rule_id: i64, host_name: Option<&str>, now: DateTime) -> bool {
+        self.starts_time <= now
+            && now < self.ends_time
+            && self.rule_id.is_none_or(|id| id == rule_id)
+            && self.host_name.as_deref().is_none_or(|host| Some(host) == host_name)
+    }
+}
@@ -0,0 +1,32 @@
+//! OIDC 客户端实体 - sys_oauth2_client表
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_oauth2_client")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub client_id: String,
+    /// 客户端密钥的SHA256（公共客户端为空，必须使用 PKCE）
+    #[serde(skip_serializing)]
+    pub client_secret_hash: Option<String>,
+    pub name: String,
+    /// 允许的回调地址（JSON数组）
+    pub redirect_uris: String,
+    /// 允许的授权类型（空格分隔）
+    pub grant_types: String,
+    /// 允许的 scope（空格分隔）
+    pub scopes: String,
+    pub status: i32,
+    pub remark: Option<String>,
+    pub created_time: DateTime,
+    pub updated_time: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
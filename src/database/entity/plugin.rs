@@ -24,35 +24,22 @@ pub struct Model {
     pub dependencies: Option<String>,
     pub install_time: Option<DateTime>,
     pub uninstall_time: Option<DateTime>,
+    /// 签名校验状态（0=未签名, 1=已验证, 2=签名无效, 3=不受信任的公钥）
+    pub signature_status: i32,
+    /// 签名公钥ID
+    pub signature_key_id: Option<String>,
+    /// 插件文件规范化清单的SHA256
+    pub manifest_hash: Option<String>,
+    /// 签名校验时间
+    pub verified_time: Option<DateTime>,
     pub created_time: DateTime,
     pub updated_time: DateTime,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-#[sea_orm()]
-pub enum Relation {
-    PluginConfig,
-}
-
-impl Related<super::plugin_config::Entity> for Entity {
-    fn to() -> RelationDef {
-        Relation::PluginConfig.def()
-    }
-}
+pub enum Relation {}
 
-impl ActiveModelBehavior for ActiveModel {
-    fn before_save(c: &mut sea_orm::query::Iterable, insert: bool) {
-        let now = chrono::Utc::now().naive_utc();
-        if insert {
-            if c.get("created_time").is_none() {
-                c.set("created_time", now);
-            }
-        }
-        if c.get("updated_time").is_none() {
-            c.set("updated_time", now);
-        }
-    }
-}
+impl ActiveModelBehavior for ActiveModel {}
 
 /// 插件类型
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,29 @@
+//! 签名密钥实体 - sys_signing_key表
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_signing_key")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub kid: String,
+    /// 签名算法（JWS alg）
+    pub algorithm: String,
+    /// PKCS#8 PEM 私钥
+    #[serde(skip_serializing)]
+    pub private_key: String,
+    /// SPKI PEM 公钥
+    pub public_key: String,
+    /// 状态（1=当前签名密钥, 0=已退役，仅用于验签）
+    pub status: i32,
+    pub created_time: DateTime,
+    pub retired_time: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
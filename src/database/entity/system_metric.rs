@@ -1,20 +1,17 @@
 /// 系统监控指标实体
+/// 对应 sys_system_metric 表（原始采样数据）
 
-use chrono::{DateTime, Utc};
-use sea_orm::entity::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, DbErr, DeriveEntityModel,
-    DerivePrimaryKey, DeriveRelationColumn, EntityFilter, EntityModel, EqTrait, ModelTrait,
-    PrimaryKeyTrait, QueryFilter, QuerySelect, Related, RelationDef, RelationTrait, Set,
-};
+use sea_orm::prelude::*;
+use sea_orm::{EnumIter, DeriveRelation, ActiveModelBehavior, QueryOrder, Set};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
 
-#[derive(
-    Debug, Clone, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "sys_system_metric")]
 pub struct Model {
     /// 指标ID
-    #[sea_orm(primary_key, auto_increment = false)]
+    #[sea_orm(primary_key, auto_increment = true)]
     pub metric_id: i64,
     /// 指标类型（1:CPU 2:内存 3:磁盘 4:网络）
     pub metric_type: i32,
@@ -76,31 +73,20 @@ impl From<MetricType> for i32 {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {
-    /// 在创建新记录前触发
-    fn before_insert(model: sea_orm::ActiveModel<Self>) -> Result<Self, DbErr> {
-        let mut model = model;
-        if model.metric_id.as_ref() == &0 {
-            model.metric_id = Set(chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0) as i64);
-        }
-        if model.collection_time.as_ref() == &chrono::DateTime::default() {
-            model.collection_time = Set(Utc::now());
-        }
-        Ok(model)
-    }
-}
-
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-#[sea_orm()]
-pub enum Relation {
-    /// 无关联
-    #[sea_orm(name = "sys_system_metric_metric_id_fk")]
-    SysSystemMetricMetricIdFk,
-}
+pub enum Relation {}
 
-impl Related<sea_orm::Entity> for Entity {
-    fn to() -> RelationDef {
-        Relation::SysSystemMetricMetricIdFk.def()
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 未指定采集时间时使用当前时间
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if insert && self.collection_time.is_not_set() {
+            self.collection_time = Set(Utc::now());
+        }
+        Ok(self)
     }
 }
 
@@ -0,0 +1,46 @@
+/// 系统监控指标汇总实体
+/// 对应 sys_system_metric_rollup 表（按小时、按天降采样的数据）
+
+use sea_orm::prelude::*;
+use sea_orm::{EnumIter, DeriveRelation, ActiveModelBehavior};
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// 小时汇总
+pub const PERIOD_HOUR: &str = "hour";
+/// 天汇总
+pub const PERIOD_DAY: &str = "day";
+
+#[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_system_metric_rollup")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    /// 汇总周期（hour / day）
+    pub period: String,
+    /// 周期起始时间（UTC 整点 / 零点）
+    pub bucket_time: DateTime<Utc>,
+    /// 指标类型（1:CPU 2:内存 3:磁盘 4:网络）
+    pub metric_type: i32,
+    /// 指标名称
+    pub metric_name: String,
+    /// 指标单位
+    pub unit: String,
+    /// 主机名
+    pub host_name: String,
+    /// IP地址
+    pub ip_address: String,
+    /// 平均值
+    pub avg_value: f64,
+    /// 最小值
+    pub min_value: f64,
+    /// 最大值
+    pub max_value: f64,
+    /// 参与汇总的原始采样数
+    pub sample_count: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -21,7 +21,7 @@ pub struct Model {
     pub username: String,
     /// 昵称
     pub nickname: String,
-    /// 密码（Bcrypt 加密）
+    /// 密码（Argon2id PHC 格式，旧数据可能为 Bcrypt）
     pub password: Option<String>,
     /// 加密盐（BLOB 类型，对应数据库中的 BLOB 列）
     pub salt: Option<Vec<u8>>,
@@ -44,6 +44,10 @@ pub struct Model {
     pub join_time: DateTime,
     /// 上次登录时间
     pub last_login_time: Option<DateTime>,
+    /// 密码修改时间
+    pub password_changed_time: Option<DateTime>,
+    /// 偏好语言，为空时按 Accept-Language 协商
+    pub locale: Option<String>,
     /// 部门 ID
     pub dept_id: Option<i64>,
     /// 创建时间
@@ -0,0 +1,26 @@
+//! 用户密码历史实体 - sys_user_password_history表
+//! 记录用户用过的密码哈希，用于禁止重复使用最近的密码
+
+use sea_orm::prelude::*;
+use sea_orm::EnumIter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_user_password_history")]
+pub struct Model {
+    /// 主键ID
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    /// 用户ID
+    pub user_id: i64,
+    /// 密码哈希
+    #[serde(skip_serializing)]
+    pub password: String,
+    /// 创建时间
+    pub created_time: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -12,6 +12,7 @@ pub mod entity {
     pub mod menu;
     pub mod dept;
     pub mod user_role;
+    pub mod role_menu;
     pub mod data_scope;
     pub mod data_rule;
     pub mod role_data_scope;
@@ -21,6 +22,16 @@ pub mod entity {
     pub mod login_log;
     pub mod task_scheduler;
     pub mod task_result;
+    pub mod plugin;
+    pub mod oauth2_client;
+    pub mod signing_key;
+    pub mod system_metric;
+    pub mod system_metric_rollup;
+    pub mod alert_rule;
+    pub mod alert_event;
+    pub mod alert_silence;
+    pub mod log_level;
+    pub mod user_password_history;
 }
 
 // 导出Repository
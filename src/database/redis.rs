@@ -62,6 +62,32 @@ impl RedisManager {
         Ok(conn_guard.as_ref().unwrap().clone())
     }
 
+    /// 以 SCAN 遍历匹配的键，避免 KEYS 在键较多时阻塞 Redis
+    pub async fn scan_keys(pattern: &str) -> Result<Vec<String>, AppError> {
+        let mut conn = Self::get_connection().await?;
+        let mut keys = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(pattern)
+                .arg("COUNT")
+                .arg(1000)
+                .query_async(&mut conn)
+                .await?;
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        // SCAN 可能重复返回同一个键
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
     /// 获取Redis客户端
     pub fn get_client() -> Result<&'static Client, AppError> {
         REDIS_CLIENT.get().ok_or_else(|| {
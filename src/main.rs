@@ -1,13 +1,16 @@
 /// 主应用入口
 /// 启动 FastAPI Best Architecture - Rust 服务器
+use fastapi_best_architecture_rust::core::log_file::{self, RollingFileWriter};
+use fastapi_best_architecture_rust::core::log_filter;
 use fastapi_best_architecture_rust::core::registrar::AppRegistrar;
 use fastapi_best_architecture_rust::core::SETTINGS;
 use tracing::{error, info};
+use tracing_appender::non_blocking::WorkerGuard;
 
 #[tokio::main]
 async fn main() {
     // 初始化日志
-    init_logging();
+    let _log_guards = init_logging();
 
     info!("启动 FastAPI Best Architecture - Rust");
     info!("版本: 0.1.0");
@@ -42,77 +45,119 @@ async fn main() {
 }
 
 /// 初始化日志系统
-fn init_logging() {
+///
+/// 返回文件日志后台写入线程的守卫，需持有到进程退出，否则缓冲中的日志会丢失
+fn init_logging() -> Vec<WorkerGuard> {
     use tracing_subscriber::fmt::time::ChronoLocal;
-    
+    use tracing_subscriber::layer::{Layered, SubscriberExt};
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::{filter::LevelFilter, fmt, reload, EnvFilter, Layer, Registry};
+
+    type Base = Layered<reload::Layer<EnvFilter, Registry>, Registry>;
+
     // 根据 debug_mode 动态调整日志级别
     let log_level = if SETTINGS.debug_mode {
         "debug"  // Debug 模式：显示所有详细信息
     } else {
         SETTINGS.log_level.as_str()  // 普通模式：使用配置的级别
     };
-    
-    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| {
-            // 构建详细的过滤器
+
+    // 基础过滤规则：优先使用 RUST_LOG，运行时可通过 sys_log_level 覆盖
+    let directives = std::env::var("RUST_LOG")
+        .ok()
+        .filter(|d| !d.trim().is_empty() && log_filter::parse(d).is_ok())
+        .unwrap_or_else(|| {
             if SETTINGS.debug_mode {
                 // Debug 模式：显示所有模块的 debug 日志，包括 sea_orm 的 SQL
-                tracing_subscriber::EnvFilter::new(
-                    format!(
-                        "{}=debug,sea_orm=debug,sqlx=debug,tower_http=debug,axum=debug",
-                        env!("CARGO_PKG_NAME").replace('-', "_")
-                    )
+                format!(
+                    "{}=debug,sea_orm=debug,sqlx=debug,tower_http=debug,axum=debug",
+                    log_filter::app_target()
                 )
             } else {
                 // 普通模式：精简日志，隐藏 SQL 和 HTTP 详情
-                tracing_subscriber::EnvFilter::new(
-                    format!(
-                        "{}={},sea_orm=warn,sqlx=warn,tower_http=warn,axum=info",
-                        env!("CARGO_PKG_NAME").replace('-', "_"),
-                        log_level
-                    )
+                format!(
+                    "{}={},sea_orm=warn,sqlx=warn,tower_http=warn,axum=info",
+                    log_filter::app_target(),
+                    log_level
                 )
             }
         });
 
-    // 根据环境和 debug_mode 选择不同的格式
+    // 过滤器在最外层，对控制台和文件输出同时生效，并支持运行时重载
+    let (filter, reload_handle) = reload::Layer::new(EnvFilter::new(&directives));
+    let timer = || ChronoLocal::new("%Y-%m-%d %H:%M:%S".to_string());
+    let mut layers: Vec<Box<dyn Layer<Base> + Send + Sync>> = Vec::new();
+
+    // 控制台：根据环境和 debug_mode 选择不同的格式
     if SETTINGS.is_dev() {
         if SETTINGS.debug_mode {
             // Debug 模式：显示详细信息（文件、行号、模块）
-            tracing_subscriber::fmt()
-                .with_env_filter(env_filter)
-                .with_timer(ChronoLocal::new("%Y-%m-%d %H:%M:%S".to_string()))
-                .with_target(true)      // 显示模块路径
-                .with_thread_ids(false)
-                .with_file(true)        // 显示文件名
-                .with_line_number(true) // 显示行号
-                .with_level(true)
-                .with_ansi(true)
-                .compact()
-                .init();
-            info!("调试模式已启用 - 显示详细日志（路由、SQL、响应时间）");
+            layers.push(
+                fmt::layer()
+                    .with_timer(timer())
+                    .with_target(true)      // 显示模块路径
+                    .with_thread_ids(false)
+                    .with_file(true)        // 显示文件名
+                    .with_line_number(true) // 显示行号
+                    .with_level(true)
+                    .with_ansi(true)
+                    .compact()
+                    .boxed(),
+            );
         } else {
             // 普通模式：精简清晰
-            tracing_subscriber::fmt()
-                .with_env_filter(env_filter)
-                .with_timer(ChronoLocal::new("%Y-%m-%d %H:%M:%S".to_string()))
-                .with_target(false)
-                .with_thread_ids(false)
-                .with_file(false)
-                .with_line_number(false)
-                .with_level(true)
-                .with_ansi(true)
-                .compact()
-                .init();
+            layers.push(
+                fmt::layer()
+                    .with_timer(timer())
+                    .with_target(false)
+                    .with_thread_ids(false)
+                    .with_file(false)
+                    .with_line_number(false)
+                    .with_level(true)
+                    .with_ansi(true)
+                    .compact()
+                    .boxed(),
+            );
         }
     } else {
         // 生产环境：JSON 格式，便于日志聚合
-        tracing_subscriber::fmt()
-            .with_env_filter(env_filter)
-            .with_timer(ChronoLocal::new("%Y-%m-%d %H:%M:%S".to_string()))
-            .json()
-            .init();
+        layers.push(fmt::layer().with_timer(timer()).json().boxed());
+    }
+
+    // 文件：配置 LOG_FILE 后启用，非阻塞写入，ERROR 级别另写一份到 <name>.error.log
+    let mut guards = Vec::new();
+    let mut file_errors = Vec::new();
+    if let Some(path) = SETTINGS.log_file.as_deref().filter(|p| !p.trim().is_empty()) {
+        let (dir, stem) = log_file::log_location(path);
+        for (stem, errors_only) in [(log_file::error_stem(&stem), true), (stem, false)] {
+            let writer = match RollingFileWriter::new(&dir, &stem, SETTINGS.log_file_max_size, SETTINGS.log_file_keep_days) {
+                Ok(writer) => writer,
+                Err(e) => {
+                    file_errors.push(format!("{}: {}", dir.join(format!("{}.log", stem)).display(), e));
+                    continue;
+                }
+            };
+            let (writer, guard) = tracing_appender::non_blocking(writer);
+            guards.push(guard);
+
+            let layer = if SETTINGS.log_json {
+                fmt::layer().with_writer(writer).with_timer(timer()).with_ansi(false).json().boxed()
+            } else {
+                fmt::layer().with_writer(writer).with_timer(timer()).with_ansi(false).boxed()
+            };
+            layers.push(if errors_only { layer.with_filter(LevelFilter::ERROR).boxed() } else { layer });
+        }
     }
 
+    tracing_subscriber::registry().with(filter).with(layers).init();
+    log_filter::install(&directives, Box::new(move |filter| reload_handle.reload(filter).map_err(|e| e.to_string())));
+
+    if SETTINGS.debug_mode && SETTINGS.is_dev() {
+        info!("调试模式已启用 - 显示详细日志（路由、SQL、响应时间）");
+    }
+    for e in file_errors {
+        error!("日志文件打开失败，仅输出到控制台: {}", e);
+    }
     info!("日志系统已初始化 (级别: {}, 调试模式: {})", log_level, SETTINGS.debug_mode);
+    guards
 }
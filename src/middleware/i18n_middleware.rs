@@ -1,11 +1,98 @@
-/// 中间件占位符
-/// TODO: 实现具体功能
+/// 国际化中间件
+/// 协商当前请求语言：已登录用户优先使用其偏好语言，否则按 Accept-Language 协商
+use std::time::Duration;
+
 use axum::{
     extract::Request,
+    http::{header, HeaderMap, HeaderValue},
     middleware::Next,
     response::Response,
 };
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use sea_orm::{EntityTrait, QuerySelect};
+use tracing::debug;
+
+use crate::{
+    common::i18n,
+    core::SETTINGS,
+    database::{entity::user, DatabaseManager},
+    utils::encrypt::CryptoUtils,
+};
+
+/// 用户偏好语言缓存，避免每个请求都查询数据库
+static USER_LOCALES: Lazy<Cache<i64, Option<String>>> = Lazy::new(|| {
+    Cache::builder()
+        .max_capacity(10_000)
+        .time_to_live(Duration::from_secs(300))
+        .build()
+});
+
+/// 用户修改偏好语言后清除缓存（其他实例的缓存在过期后刷新）
+pub fn forget_user_locale(user_id: i64) {
+    USER_LOCALES.invalidate(&user_id);
+}
+
+pub async fn middleware(mut request: Request, next: Next) -> Response {
+    let locale = match user_locale(request.headers()).await {
+        Some(locale) => locale,
+        None => i18n::negotiate(
+            request
+                .headers()
+                .get(header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok()),
+        ),
+    };
+
+    // 改写为协商结果，插件等下游处理器直接读取 Accept-Language 即可
+    let header_value = HeaderValue::from_str(&locale).ok();
+    if let Some(value) = &header_value {
+        request.headers_mut().insert(header::ACCEPT_LANGUAGE, value.clone());
+    }
+
+    let mut response = i18n::scope(locale, next.run(request)).await;
+    if let Some(value) = header_value {
+        response.headers_mut().insert(header::CONTENT_LANGUAGE, value);
+    }
+    response
+}
+
+/// 携带有效访问令牌且设置了偏好语言时返回该语言；令牌无效时交给 JWT 中间件处理
+async fn user_locale(headers: &HeaderMap) -> Option<String> {
+    let token = headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .trim_start_matches("Bearer ")
+        .trim();
+    if token.is_empty() {
+        return None;
+    }
+    let user_id: i64 = CryptoUtils::verify_jwt(token, &SETTINGS.token_secret_key)
+        .ok()?
+        .sub
+        .parse()
+        .ok()?;
+
+    if let Some(locale) = USER_LOCALES.get(&user_id) {
+        return locale;
+    }
 
-pub async fn middleware(request: Request, next: Next) -> Response {
-    next.run(request).await
+    let db = DatabaseManager::get_connection().await;
+    let locale = match user::Entity::find_by_id(user_id)
+        .select_only()
+        .column(user::Column::Locale)
+        .into_tuple::<Option<String>>()
+        .one(db)
+        .await
+    {
+        Ok(locale) => locale.flatten().and_then(|tag| i18n::resolve(&tag)).map(str::to_string),
+        Err(e) => {
+            // 查询失败不缓存，回退到 Accept-Language
+            debug!("读取用户偏好语言失败: {}", e);
+            return None;
+        }
+    };
+    USER_LOCALES.insert(user_id, locale.clone());
+    locale
 }
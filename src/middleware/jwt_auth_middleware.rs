@@ -14,6 +14,14 @@ use crate::{
     core::SETTINGS,
 };
 
+/// 密码过期后仍允许访问的路径
+const PASSWORD_EXPIRED_ALLOW_PATHS: &[&str] = &[
+    "/api/v1/sys/users/me",
+    "/api/v1/sys/users/me/password",
+    "/api/v1/auth/refresh",
+    "/api/v1/auth/logout",
+];
+
 /// 认证上下文
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthContext {
@@ -61,13 +69,39 @@ pub async fn middleware(
         return Ok(next.run(request).await);
     }
 
+    // OIDC 协议端点使用客户端认证（Basic）或自身签发的访问令牌，由端点自行校验；
+    // 授权端点未登录时跳转登录页
+    if path.starts_with("/api/v1/oidc/") || path.starts_with("/.well-known/") {
+        return Ok(next.run(request).await);
+    }
+
+    // Prometheus 指标端点使用独立的访问令牌或 IP 白名单，由端点自行校验
+    if path == "/metrics" {
+        return Ok(next.run(request).await);
+    }
+
+    // Kubernetes 存活/就绪探针
+    if path == "/livez" || path == "/readyz" {
+        return Ok(next.run(request).await);
+    }
+
+    // API 文档页面和 OpenAPI JSON
+    if crate::core::openapi::is_docs_path(path) {
+        return Ok(next.run(request).await);
+    }
+
     let token = match extract_token_from_headers(request.headers()) {
         Ok(token) => token,
         Err(_) => {
             if path.starts_with("/api/v1/auth/login")
                 || path.starts_with("/api/v1/auth/captcha")
+                // 注册和找回密码
+                || path.starts_with("/api/v1/auth/register")
+                || path.starts_with("/api/v1/auth/password/")
                 || path == "/"
                 || path.starts_with("/health")
+                // 第三方登录的授权/回调/关联账号无需系统 token，绑定和解绑由插件自行校验
+                || (path.starts_with("/api/v1/oauth2/") && !path.starts_with("/api/v1/oauth2/admin"))
                 // WebSocket/Socket.IO 握手走的是 auth payload，而不是 HTTP 头，所以这里放行 /ws 下的请求
                 || path.starts_with("/ws") {
                 warn!("路径 {} 在白名单中，无需认证，直接放行", path);
@@ -95,6 +129,12 @@ pub async fn middleware(
         return Err(AppError::new(ErrorCode::TokenInvalid));
     }
 
+    // 密码过期的会话只能查看本人信息、修改密码和退出登录
+    if payload.password_expired && !PASSWORD_EXPIRED_ALLOW_PATHS.contains(&path) {
+        warn!("用户 {} 密码已过期，拒绝访问 {}", user_id, path);
+        return Err(AppError::with_message(ErrorCode::Forbidden, "密码已过期，请先修改密码"));
+    }
+
     // 创建认证上下文
     let auth_context = AuthContext {
         user_id: user_id.clone(),                // 用户ID（字符串格式）
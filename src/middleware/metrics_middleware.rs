@@ -0,0 +1,33 @@
+/// 请求指标中间件
+/// 按匹配到的路由模板、方法和状态码记录请求数与耗时，供 /metrics 导出
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::app::monitor::service::prometheus_service::{HTTP_METRICS, UNMATCHED_ROUTE};
+
+/// 请求指标中间件
+///
+/// 路由标签使用路由模板（如 `/api/v1/sys/users/{id}`）而不是原始路径；
+/// 插件路由由 fallback 转发，其匹配路径由插件层写入响应扩展
+pub async fn middleware(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let matched = request.extensions().get::<MatchedPath>().cloned();
+
+    HTTP_METRICS.start_request();
+    let start = std::time::Instant::now();
+    let response = next.run(request).await;
+    let elapsed = start.elapsed();
+
+    let route = matched
+        .as_ref()
+        .or_else(|| response.extensions().get::<MatchedPath>())
+        .map(|path| path.as_str())
+        .unwrap_or(UNMATCHED_ROUTE);
+    HTTP_METRICS.finish_request(route, &method, response.status().as_u16(), elapsed);
+
+    response
+}
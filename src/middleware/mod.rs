@@ -6,8 +6,10 @@ pub mod context_middleware;
 pub mod cors_middleware;
 pub mod i18n_middleware;
 pub mod jwt_auth_middleware;
+pub mod metrics_middleware;
 pub mod opera_log_middleware;
 pub mod permission_middleware;
+pub mod rate_limit_middleware;
 pub mod request_log_middleware;
 pub mod state_middleware;
 
@@ -0,0 +1,251 @@
+/// 限流中间件
+/// 按 RATE_LIMIT_POLICY 中第一条匹配的规则计数，响应携带 RateLimit-* 头，超出限额返回 429 和 Retry-After；
+/// 放在 JWT 中间件之内，按用户和角色限流时可读取认证上下文
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::{header, HeaderMap, HeaderName, HeaderValue, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use tracing::{debug, warn};
+
+use crate::{
+    app::rate_limit::service::{
+        limiter::{self, Decision},
+        policy::{KeyKind, RateLimitRule, RATE_LIMIT_POLICY},
+        rate_limit_service::bucket_key,
+    },
+    common::exception::{AppError, ErrorCode},
+    core::SETTINGS,
+    database::{entity::role, user_role_repo::UserRoleRepository, DatabaseManager},
+    middleware::AuthContext,
+    utils::encrypt::CryptoUtils,
+};
+
+const RATELIMIT_LIMIT: &str = "ratelimit-limit";
+const RATELIMIT_REMAINING: &str = "ratelimit-remaining";
+const RATELIMIT_RESET: &str = "ratelimit-reset";
+const RATELIMIT_POLICY: &str = "ratelimit-policy";
+
+/// 用户角色名缓存，按角色限流时避免每个请求都查询数据库
+static USER_ROLES: Lazy<Cache<i64, Vec<String>>> = Lazy::new(|| {
+    Cache::builder()
+        .max_capacity(10_000)
+        .time_to_live(Duration::from_secs(60))
+        .build()
+});
+
+/// 受信任的反向代理地址
+static TRUSTED_PROXIES: Lazy<Vec<IpAddr>> = Lazy::new(|| {
+    SETTINGS
+        .rate_limit_trusted_proxies
+        .iter()
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect()
+});
+
+pub async fn middleware(request: Request, next: Next) -> Response {
+    // CORS 预检请求不计数
+    if request.method() == Method::OPTIONS {
+        return next.run(request).await;
+    }
+
+    let method = request.method().as_str().to_string();
+    let path = request.uri().path().to_string();
+    let user_id = request
+        .extensions()
+        .get::<AuthContext>()
+        .and_then(|auth| auth.user_id.parse::<i64>().ok());
+
+    let Some(rule) = select_rule(&method, &path, user_id).await else {
+        return next.run(request).await;
+    };
+    if rule.limit == 0 {
+        return next.run(request).await;
+    }
+
+    let (kind, subject) = subject(rule.key, &request, user_id);
+    let decision = limiter::acquire(&bucket_key(&rule.name, kind, &subject), rule.limit, rule.period_ms()).await;
+    if !decision.allowed {
+        debug!("请求被限流: {} {} 规则={} {}={}", method, path, rule.name, kind.as_str(), subject);
+        let mut response = AppError::new(ErrorCode::TooManyRequests).into_response();
+        set_headers(response.headers_mut(), rule, &decision);
+        return response;
+    }
+
+    let mut response = next.run(request).await;
+    set_headers(response.headers_mut(), rule, &decision);
+    response
+}
+
+/// 按顺序找到第一条方法、路径和角色都匹配的规则，只在遇到限定角色的规则时才查询用户角色
+async fn select_rule(method: &str, path: &str, user_id: Option<i64>) -> Option<&'static RateLimitRule> {
+    let mut roles: Option<Vec<String>> = None;
+    for rule in RATE_LIMIT_POLICY.candidates(method, path) {
+        if rule.roles.is_empty() {
+            return Some(rule);
+        }
+        let Some(user_id) = user_id else {
+            continue;
+        };
+        if roles.is_none() {
+            roles = Some(user_roles(user_id).await);
+        }
+        if roles.as_ref().is_some_and(|roles| rule.roles.iter().any(|r| roles.contains(r))) {
+            return Some(rule);
+        }
+    }
+    None
+}
+
+/// 用户启用角色的名称，查询失败时按无角色处理且不缓存
+async fn user_roles(user_id: i64) -> Vec<String> {
+    if let Some(roles) = USER_ROLES.get(&user_id) {
+        return roles;
+    }
+
+    let db = DatabaseManager::get_connection().await;
+    let role_ids = match UserRoleRepository::find_roles_by_user(user_id, db).await {
+        Ok(role_ids) => role_ids,
+        Err(e) => {
+            warn!("限流读取用户角色失败: {}", e);
+            return Vec::new();
+        }
+    };
+    let roles = if role_ids.is_empty() {
+        Vec::new()
+    } else {
+        match role::Entity::find()
+            .select_only()
+            .column(role::Column::Name)
+            .filter(role::Column::Status.eq(1))
+            .filter(role::Column::Id.is_in(role_ids))
+            .into_tuple::<String>()
+            .all(db)
+            .await
+        {
+            Ok(roles) => roles,
+            Err(e) => {
+                warn!("限流读取用户角色失败: {}", e);
+                return Vec::new();
+            }
+        }
+    };
+    USER_ROLES.insert(user_id, roles.clone());
+    roles
+}
+
+/// 已登记的 API Key 摘要
+static API_KEY_DIGESTS: Lazy<Vec<String>> = Lazy::new(|| {
+    SETTINGS
+        .rate_limit_api_keys
+        .iter()
+        .map(|digest| digest.trim().to_ascii_lowercase())
+        .filter(|digest| !digest.is_empty())
+        .collect()
+});
+
+/// 计数对象：按用户限流时未登录的请求按 IP 计数；
+/// 按 API Key 限流时只有已登记的 API Key 单独计数，未携带或未登记的请求按用户或 IP 计数，
+/// 避免不断更换请求头取得新的计数桶
+fn subject(key: KeyKind, request: &Request, user_id: Option<i64>) -> (KeyKind, String) {
+    if key == KeyKind::ApiKey {
+        let api_key = request
+            .headers()
+            .get(SETTINGS.rate_limit_api_key_header.as_str())
+            .and_then(|value| value.to_str().ok());
+        if let Some(digest) = api_key.and_then(|value| registered_api_key(value, &API_KEY_DIGESTS)) {
+            // 只保存摘要，Redis 中不出现 API Key 原文
+            return (KeyKind::ApiKey, digest[..16].to_string());
+        }
+    }
+    if key != KeyKind::Ip {
+        if let Some(user_id) = user_id {
+            return (KeyKind::User, user_id.to_string());
+        }
+    }
+    (KeyKind::Ip, client_ip(request))
+}
+
+/// 请求头中的 API Key 已登记时返回其摘要
+fn registered_api_key(value: &str, digests: &[String]) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let digest = CryptoUtils::sha256(value);
+    digests.contains(&digest).then_some(digest)
+}
+
+/// 客户端 IP：只有连接对端是受信任的代理时才读取 X-Forwarded-For / X-Real-IP，避免伪造请求头绕过限流
+fn client_ip(request: &Request) -> String {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| match addr.ip() {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(IpAddr::V6(v6)),
+            ip => ip,
+        });
+    let Some(peer) = peer else {
+        return "unknown".to_string();
+    };
+    if TRUSTED_PROXIES.contains(&peer) {
+        let headers = request.headers();
+        let forwarded = headers
+            .get("X-Forwarded-For")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .or_else(|| headers.get("X-Real-IP").and_then(|value| value.to_str().ok()))
+            .and_then(|value| value.trim().parse::<IpAddr>().ok());
+        if let Some(ip) = forwarded {
+            return ip.to_string();
+        }
+    }
+    peer.to_string()
+}
+
+/// 需要通过 CORS 暴露给前端的限流响应头
+pub fn exposed_headers() -> [HeaderName; 5] {
+    [
+        HeaderName::from_static(RATELIMIT_LIMIT),
+        HeaderName::from_static(RATELIMIT_REMAINING),
+        HeaderName::from_static(RATELIMIT_RESET),
+        HeaderName::from_static(RATELIMIT_POLICY),
+        header::RETRY_AFTER,
+    ]
+}
+
+/// 写入 RateLimit-* 头，被拒绝时另加 Retry-After（秒）
+fn set_headers(headers: &mut HeaderMap, rule: &RateLimitRule, decision: &Decision) {
+    let seconds = |ms: u64| ms.div_ceil(1000);
+    headers.insert(RATELIMIT_LIMIT, HeaderValue::from(rule.limit));
+    headers.insert(RATELIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(RATELIMIT_RESET, HeaderValue::from(seconds(decision.reset_ms)));
+    if let Ok(value) = HeaderValue::from_str(&format!("{};w={}", rule.limit, rule.period)) {
+        headers.insert(RATELIMIT_POLICY, value);
+    }
+    if !decision.allowed {
+        headers.insert(header::RETRY_AFTER, HeaderValue::from(seconds(decision.retry_after_ms).max(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registered_api_key() {
+        let digests = vec![CryptoUtils::sha256("issued-key")];
+
+        assert_eq!(registered_api_key(" issued-key ", &digests), Some(digests[0].clone()));
+        // 未登记的 API Key 不单独计数，更换请求头无法取得新的计数桶
+        assert_eq!(registered_api_key("rotated-key", &digests), None);
+        assert_eq!(registered_api_key("  ", &digests), None);
+    }
+}
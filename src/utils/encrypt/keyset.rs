@@ -0,0 +1,423 @@
+/// 非对称签名密钥集
+/// 当前密钥负责签名，已退役的密钥在保留期内继续参与验签；
+/// 密钥持久化在 sys_signing_key 表中，多实例通过定时重新加载保持一致。
+/// 支持 RS256 / ES256 / EdDSA，新密钥的算法由 TOKEN_ALGORITHM 决定
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::NaiveDateTime;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::{spki::der::pem::LineEnding, DecodePublicKey, EncodePrivateKey, EncodePublicKey};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use rsa::traits::PublicKeyParts;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::common::exception::{AppError, ErrorCode};
+use crate::core::SETTINGS;
+use crate::database::entity::signing_key;
+use crate::database::{DatabaseConnection, DatabaseManager};
+
+/// 密钥重新加载与轮换检查间隔
+const RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
+/// RSA 密钥长度
+const RSA_KEY_BITS: usize = 2048;
+
+/// 全局签名密钥集
+pub static KEYSET: Lazy<KeySet> = Lazy::new(KeySet::new);
+
+/// 单个签名密钥
+#[derive(Clone)]
+struct SigningKey {
+    kid: String,
+    algorithm: Algorithm,
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    jwk: Value,
+    created_time: NaiveDateTime,
+    current: bool,
+}
+
+/// 签名密钥集
+pub struct KeySet {
+    keys: RwLock<Arc<Vec<SigningKey>>>,
+}
+
+impl KeySet {
+    pub fn new() -> Self {
+        Self {
+            keys: RwLock::new(Arc::new(Vec::new())),
+        }
+    }
+
+    fn snapshot(&self) -> Arc<Vec<SigningKey>> {
+        self.keys.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn replace(&self, keys: Vec<SigningKey>) {
+        *self.keys.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(keys);
+    }
+
+    /// 退役密钥的保留期：覆盖最长的令牌有效期，保证轮换后已签发的令牌仍可验证
+    fn retention() -> chrono::Duration {
+        chrono::Duration::seconds(SETTINGS.token_expire_seconds.max(SETTINGS.token_refresh_expire_seconds))
+    }
+
+    /// 从数据库加载当前密钥和保留期内的退役密钥，不存在当前密钥时生成
+    pub async fn load(&self, db: &DatabaseConnection) -> Result<(), AppError> {
+        if !self.reload(db).await? {
+            self.rotate(db).await?;
+        }
+        Ok(())
+    }
+
+    /// 重新读取密钥，返回是否存在当前密钥
+    async fn reload(&self, db: &DatabaseConnection) -> Result<bool, AppError> {
+        let retired_after = chrono::Utc::now().naive_utc() - Self::retention();
+        let models = signing_key::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(signing_key::Column::Status.eq(1))
+                    .add(signing_key::Column::RetiredTime.gt(retired_after)),
+            )
+            .order_by_desc(signing_key::Column::CreatedTime)
+            .all(db)
+            .await?;
+
+        let keys = models
+            .into_iter()
+            .map(SigningKey::from_model)
+            .collect::<Result<Vec<_>, _>>()?;
+        let has_current = keys.iter().any(|k| k.current);
+        self.replace(keys);
+        Ok(has_current)
+    }
+
+    /// 轮换密钥：按配置的算法生成新的当前密钥，原当前密钥退役，清理超过保留期的退役密钥
+    pub async fn rotate(&self, db: &DatabaseConnection) -> Result<String, AppError> {
+        let now = chrono::Utc::now().naive_utc();
+        let algorithm = configured_algorithm();
+        let (kid, private_key, public_key) = generate_key(algorithm)?;
+
+        let txn = db.begin().await?;
+        signing_key::Entity::update_many()
+            .col_expr(signing_key::Column::Status, sea_orm::sea_query::Expr::value(0))
+            .col_expr(signing_key::Column::RetiredTime, sea_orm::sea_query::Expr::value(now))
+            .filter(signing_key::Column::Status.eq(1))
+            .exec(&txn)
+            .await?;
+        signing_key::Entity::delete_many()
+            .filter(signing_key::Column::Status.eq(0))
+            .filter(signing_key::Column::RetiredTime.lte(now - Self::retention()))
+            .exec(&txn)
+            .await?;
+        signing_key::ActiveModel {
+            kid: Set(kid.clone()),
+            algorithm: Set(algorithm_name(algorithm)),
+            private_key: Set(private_key),
+            public_key: Set(public_key),
+            status: Set(1),
+            created_time: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        txn.commit().await?;
+
+        tracing::info!("签名密钥已轮换: kid={}, alg={:?}", kid, algorithm);
+        self.reload(db).await?;
+        Ok(kid)
+    }
+
+    /// 当前密钥超过轮换周期或与配置的算法不一致时执行轮换
+    pub async fn rotate_if_due(&self, db: &DatabaseConnection) -> Result<bool, AppError> {
+        let due = match self.snapshot().iter().find(|k| k.current) {
+            Some(key) => {
+                key.algorithm != configured_algorithm()
+                    || chrono::Utc::now().naive_utc() - key.created_time
+                        >= chrono::Duration::seconds(SETTINGS.token_key_rotation_seconds)
+            }
+            None => true,
+        };
+        if due {
+            self.rotate(db).await?;
+        }
+        Ok(due)
+    }
+
+    /// 启动后台任务：定时重新加载密钥（同步其他实例的轮换）并按周期轮换
+    pub fn spawn_rotation(&'static self) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+            loop {
+                interval.tick().await;
+                crate::core::health::beat("keyset_rotation", RELOAD_INTERVAL);
+                let db = DatabaseManager::get_connection().await;
+                let result = match self.load(db).await {
+                    Ok(()) => self.rotate_if_due(db).await.map(|_| ()),
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    tracing::error!("签名密钥加载或轮换失败: {}", e.message);
+                }
+            }
+        });
+    }
+
+    /// 使用当前密钥签名，头部携带 kid
+    pub fn sign<T: Serialize>(&self, claims: &T) -> Result<String, AppError> {
+        let keys = self.snapshot();
+        let key = keys
+            .iter()
+            .find(|k| k.current)
+            .ok_or_else(|| AppError::with_message(ErrorCode::InternalServerError, "签名密钥未初始化"))?;
+
+        let mut header = Header::new(key.algorithm);
+        header.kid = Some(key.kid.clone());
+        encode(&header, claims, &key.encoding).map_err(|e| {
+            AppError::with_details(ErrorCode::TokenInvalid, "JWT Token 生成失败", e.to_string())
+        })
+    }
+
+    /// 按 kid 选择密钥验签，密钥集中任意未过保留期的密钥均可
+    pub fn verify<T: DeserializeOwned>(&self, token: &str, validation: &Validation) -> Result<T, AppError> {
+        let kid = decode_header(token)
+            .ok()
+            .and_then(|header| header.kid)
+            .ok_or_else(|| AppError::new(ErrorCode::TokenInvalid))?;
+        let keys = self.snapshot();
+        let key = keys
+            .iter()
+            .find(|k| k.kid == kid)
+            .ok_or_else(|| AppError::with_message(ErrorCode::TokenInvalid, "未知的签名密钥"))?;
+
+        let mut validation = validation.clone();
+        validation.algorithms = vec![key.algorithm];
+        decode::<T>(token, &key.decoding, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => AppError::new(ErrorCode::TokenExpired),
+                _ => AppError::with_details(ErrorCode::TokenInvalid, "JWT Token 验证失败", e.to_string()),
+            })
+    }
+
+    /// 公开的 JWKS（RFC 7517）
+    pub fn jwks(&self) -> Value {
+        let keys: Vec<Value> = self.snapshot().iter().map(|k| k.jwk.clone()).collect();
+        serde_json::json!({ "keys": keys })
+    }
+
+    /// 当前支持的签名算法（发现文档使用）
+    pub fn algorithms(&self) -> Vec<String> {
+        let mut algorithms: Vec<String> = self
+            .snapshot()
+            .iter()
+            .map(|k| format!("{:?}", k.algorithm))
+            .collect();
+        algorithms.sort();
+        algorithms.dedup();
+        algorithms
+    }
+}
+
+impl Default for KeySet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SigningKey {
+    fn from_model(model: signing_key::Model) -> Result<Self, AppError> {
+        let invalid = |e: String| {
+            AppError::with_details(ErrorCode::InternalServerError, "签名密钥解析失败", format!("{}: {}", model.kid, e))
+        };
+
+        let private_key = model.private_key.as_bytes();
+        let public_key = model.public_key.as_bytes();
+        let (algorithm, encoding, decoding, mut jwk) = match model.algorithm.as_str() {
+            "RS256" => {
+                let public = rsa::RsaPublicKey::from_public_key_pem(&model.public_key)
+                    .map_err(|e| invalid(e.to_string()))?;
+                (
+                    Algorithm::RS256,
+                    EncodingKey::from_rsa_pem(private_key).map_err(|e| invalid(e.to_string()))?,
+                    DecodingKey::from_rsa_pem(public_key).map_err(|e| invalid(e.to_string()))?,
+                    serde_json::json!({
+                        "kty": "RSA",
+                        "n": URL_SAFE_NO_PAD.encode(public.n().to_bytes_be()),
+                        "e": URL_SAFE_NO_PAD.encode(public.e().to_bytes_be()),
+                    }),
+                )
+            }
+            "ES256" => {
+                let point = p256::PublicKey::from_public_key_pem(&model.public_key)
+                    .map_err(|e| invalid(e.to_string()))?
+                    .to_encoded_point(false);
+                let (Some(x), Some(y)) = (point.x(), point.y()) else {
+                    return Err(invalid("无效的 P-256 公钥".to_string()));
+                };
+                (
+                    Algorithm::ES256,
+                    EncodingKey::from_ec_pem(private_key).map_err(|e| invalid(e.to_string()))?,
+                    DecodingKey::from_ec_pem(public_key).map_err(|e| invalid(e.to_string()))?,
+                    serde_json::json!({
+                        "kty": "EC",
+                        "crv": "P-256",
+                        "x": URL_SAFE_NO_PAD.encode(x),
+                        "y": URL_SAFE_NO_PAD.encode(y),
+                    }),
+                )
+            }
+            "EdDSA" => {
+                let public = ed25519_dalek::VerifyingKey::from_public_key_pem(&model.public_key)
+                    .map_err(|e| invalid(e.to_string()))?;
+                (
+                    Algorithm::EdDSA,
+                    EncodingKey::from_ed_pem(private_key).map_err(|e| invalid(e.to_string()))?,
+                    DecodingKey::from_ed_pem(public_key).map_err(|e| invalid(e.to_string()))?,
+                    serde_json::json!({
+                        "kty": "OKP",
+                        "crv": "Ed25519",
+                        "x": URL_SAFE_NO_PAD.encode(public.to_bytes()),
+                    }),
+                )
+            }
+            other => return Err(invalid(format!("不支持的签名算法 {}", other))),
+        };
+        jwk["use"] = "sig".into();
+        jwk["alg"] = model.algorithm.clone().into();
+        jwk["kid"] = model.kid.clone().into();
+
+        Ok(Self {
+            kid: model.kid,
+            algorithm,
+            encoding,
+            decoding,
+            jwk,
+            created_time: model.created_time,
+            current: model.status == 1,
+        })
+    }
+}
+
+/// 配置的签名算法；HS256 等对称算法无法公开验签，此时密钥集使用 EdDSA
+fn configured_algorithm() -> Algorithm {
+    match SETTINGS.token_algorithm.parse::<Algorithm>() {
+        Ok(algorithm @ (Algorithm::RS256 | Algorithm::ES256)) => algorithm,
+        _ => Algorithm::EdDSA,
+    }
+}
+
+fn algorithm_name(algorithm: Algorithm) -> String {
+    format!("{:?}", algorithm)
+}
+
+/// 生成密钥对，返回 (kid, PKCS#8 私钥 PEM, SPKI 公钥 PEM)
+fn generate_key(algorithm: Algorithm) -> Result<(String, String, String), AppError> {
+    let failed = |e: String| AppError::with_details(ErrorCode::InternalServerError, "签名密钥生成失败", e);
+
+    let mut rng = rand::thread_rng();
+    let (private_key, public_key) = match algorithm {
+        Algorithm::RS256 => {
+            let key = rsa::RsaPrivateKey::new(&mut rng, RSA_KEY_BITS).map_err(|e| failed(e.to_string()))?;
+            (
+                key.to_pkcs8_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?.to_string(),
+                key.to_public_key().to_public_key_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?,
+            )
+        }
+        Algorithm::ES256 => {
+            let key = p256::SecretKey::random(&mut rng);
+            (
+                key.to_pkcs8_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?.to_string(),
+                key.public_key().to_public_key_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?,
+            )
+        }
+        Algorithm::EdDSA => {
+            let secret: [u8; 32] = rng.gen();
+            let key = ed25519_dalek::SigningKey::from_bytes(&secret);
+            (
+                key.to_pkcs8_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?.to_string(),
+                key.verifying_key().to_public_key_pem(LineEnding::LF).map_err(|e| failed(e.to_string()))?,
+            )
+        }
+        other => return Err(failed(format!("不支持的签名算法 {:?}", other))),
+    };
+    let kid = format!(
+        "{}-{}",
+        chrono::Utc::now().format("%Y%m%d"),
+        &crate::utils::encrypt::CryptoUtils::sha256(&public_key)[..8]
+    );
+
+    Ok((kid, private_key, public_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(status: i32) -> signing_key::Model {
+        model_with(Algorithm::EdDSA, status)
+    }
+
+    fn model_with(algorithm: Algorithm, status: i32) -> signing_key::Model {
+        let (kid, private_key, public_key) = generate_key(algorithm).unwrap();
+        signing_key::Model {
+            id: 0,
+            kid,
+            algorithm: algorithm_name(algorithm),
+            private_key,
+            public_key,
+            status,
+            created_time: chrono::Utc::now().naive_utc(),
+            retired_time: None,
+        }
+    }
+
+    #[test]
+    fn test_retired_key_still_verifies() {
+        let keyset = KeySet::new();
+        let old = SigningKey::from_model(model(1)).unwrap();
+        keyset.replace(vec![old.clone()]);
+
+        let claims = serde_json::json!({ "sub": "1", "exp": chrono::Utc::now().timestamp() + 60 });
+        let token = keyset.sign(&claims).unwrap();
+
+        // 轮换后旧令牌仍可验证，新令牌使用新 kid
+        let new = SigningKey::from_model(model(1)).unwrap();
+        keyset.replace(vec![new.clone(), SigningKey { current: false, ..old }]);
+        let validation = Validation::new(Algorithm::EdDSA);
+        let verified: Value = keyset.verify(&token, &validation).unwrap();
+        assert_eq!(verified["sub"], "1");
+
+        let token = keyset.sign(&claims).unwrap();
+        assert_eq!(decode_header(&token).unwrap().kid, Some(new.kid));
+        assert_eq!(keyset.jwks()["keys"].as_array().unwrap().len(), 2);
+
+        // 超出保留期被移除的密钥签发的令牌不再被接受
+        keyset.replace(vec![SigningKey::from_model(model(1)).unwrap()]);
+        assert!(keyset.verify::<Value>(&token, &validation).is_err());
+    }
+
+    #[test]
+    fn test_sign_with_each_algorithm() {
+        let claims = serde_json::json!({ "sub": "1", "exp": chrono::Utc::now().timestamp() + 60 });
+        for algorithm in [Algorithm::RS256, Algorithm::ES256, Algorithm::EdDSA] {
+            let keyset = KeySet::new();
+            keyset.replace(vec![SigningKey::from_model(model_with(algorithm, 1)).unwrap()]);
+
+            let token = keyset.sign(&claims).unwrap();
+            assert_eq!(decode_header(&token).unwrap().alg, algorithm);
+            let verified: Value = keyset.verify(&token, &Validation::new(algorithm)).unwrap();
+            assert_eq!(verified["sub"], "1");
+            assert_eq!(keyset.jwks()["keys"][0]["alg"], algorithm_name(algorithm));
+        }
+    }
+}
@@ -1,7 +1,12 @@
 /// 加密工具
 /// 提供密码加密、JWT Token、Base64 等加密功能
 
-use bcrypt::{verify, DEFAULT_COST};
+pub mod keyset;
+
+pub use keyset::{KeySet, KEYSET};
+
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier};
 use jsonwebtoken::{encode, decode, Header, EncodingKey, DecodingKey, Algorithm, Validation};
 use jsonwebtoken::errors::ErrorKind;
 use rand::Rng;
@@ -9,11 +14,13 @@ use base64::{Engine as _, engine::general_purpose};
 use sha2::{Sha256, Digest};
 use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 
 use crate::common::exception::{AppError, ErrorCode};
+use crate::core::SETTINGS;
 
 /// JWT 载荷结构
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct JwtPayload {
     /// 会话UUID
     #[serde(default)]
@@ -22,6 +29,9 @@ pub struct JwtPayload {
     pub exp: u64,
     /// 用户ID（字符串格式）
     pub sub: String,
+    /// 密码已过期，只允许修改密码和退出登录
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub password_expired: bool,
 }
 
 impl JwtPayload {
@@ -40,78 +50,110 @@ impl JwtPayload {
             session_uuid: session_uuid.into(),
             exp: (now + expire_seconds as u64),
             sub: user_id.into(),
+            password_expired: false,
         }
     }
+
+    /// 标记密码是否已过期
+    pub fn with_password_expired(mut self, expired: bool) -> Self {
+        self.password_expired = expired;
+        self
+    }
 }
 
 /// 加密工具
 pub struct CryptoUtils;
 
 impl CryptoUtils {
-    /// 对密码进行 Bcrypt 加密
-    pub async fn hash_password(password: &str, salt: Option<&str>) -> Result<String, AppError> {
-        let salt_value = if let Some(s) = salt {
-            s.to_string()
-        } else {
-            let mut rng = rand::thread_rng();
-            let salt_bytes: [u8; 16] = rng.gen();
-            general_purpose::STANDARD.encode(salt_bytes)
-        };
-
-        let salted_password = format!("{}{}", password, salt_value);
-        let hashed = bcrypt::hash(&salted_password, DEFAULT_COST)
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码加密失败",
-                e.to_string(),
-            ))?;
+    /// 对密码进行 Argon2id 哈希，返回 PHC 格式字符串（`$argon2id$v=19$...`）
+    /// 哈希计算在阻塞线程池中执行，不占用异步运行时
+    pub async fn hash_password(password: &str) -> Result<String, AppError> {
+        let password = password.to_string();
+        let salt_bytes: [u8; 16] = rand::thread_rng().gen();
 
-        Ok(format!("{}:{}", salt_value, hashed))
+        Self::run_blocking(move || {
+            let salt = SaltString::encode_b64(&salt_bytes)
+                .map_err(|e| AppError::with_details(ErrorCode::BusinessError, "密码加密失败", e.to_string()))?;
+            Argon2::default()
+                .hash_password(password.as_bytes(), &salt)
+                .map(|hash| hash.to_string())
+                .map_err(|e| AppError::with_details(ErrorCode::BusinessError, "密码加密失败", e.to_string()))
+        })
+        .await
     }
 
     /// 验证密码
     /// # Arguments
     /// * `password` - 明文密码
     /// * `hashed_password` - 加密后的密码
-    ///   - 支持格式1：salt:hash（自定义加盐）
-    ///   - 支持格式2：$2b$...（纯bcrypt哈希）
+    ///   - 支持格式1：$argon2id$...（PHC 格式）
+    ///   - 支持格式2：salt:hash（旧版自定义加盐 bcrypt）
+    ///   - 支持格式3：$2b$...（纯bcrypt哈希）
     ///
     /// # Returns
     /// 返回验证结果
     pub async fn verify_password(password: &str, hashed_password: &str) -> Result<bool, AppError> {
-        // 如果包含自定义salt（salt:hash格式）
-        if hashed_password.contains(':') {
-            let parts: Vec<&str> = hashed_password.split(':').collect();
-            if parts.len() == 2 {
-                let salt = parts[0];
-                let hash = parts[1];
-                let salted_password = format!("{}{}", password, salt);
-                return verify(&salted_password, hash)
-                    .map_err(|e| AppError::with_details(
-                        ErrorCode::BusinessError,
-                        "密码验证失败",
-                        e.to_string(),
-                    ));
+        let password = password.to_string();
+        let hashed_password = hashed_password.to_string();
+
+        Self::run_blocking(move || {
+            if hashed_password.starts_with("$argon2") {
+                let parsed = PasswordHash::new(&hashed_password)
+                    .map_err(|e| AppError::with_details(ErrorCode::BusinessError, "密码验证失败", e.to_string()))?;
+                return Ok(Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok());
             }
+
+            // 旧版 salt:hash 格式
+            let (password, hash) = match hashed_password.split_once(':') {
+                Some((salt, hash)) => (format!("{}{}", password, salt), hash),
+                None => (password, hashed_password.as_str()),
+            };
+            bcrypt::verify(&password, hash)
+                .map_err(|e| AppError::with_details(ErrorCode::BusinessError, "密码验证失败", e.to_string()))
+        })
+        .await
+    }
+
+    /// 哈希是否需要升级：非 Argon2id 或参数与当前默认参数不一致
+    pub fn password_needs_rehash(hashed_password: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(hashed_password) else {
+            return true;
+        };
+        if parsed.algorithm != argon2::Algorithm::Argon2id.ident() {
+            return true;
         }
+        let current = Params::default();
+        Params::try_from(&parsed).map_or(true, |params| {
+            params.m_cost() != current.m_cost()
+                || params.t_cost() != current.t_cost()
+                || params.p_cost() != current.p_cost()
+        })
+    }
 
-        // 直接验证bcrypt哈希
-        verify(password, hashed_password)
-            .map_err(|e| AppError::with_details(
-                ErrorCode::BusinessError,
-                "密码验证失败",
-                e.to_string(),
-            ))
+    /// 在阻塞线程池中执行计算密集的任务
+    async fn run_blocking<T, F>(f: F) -> Result<T, AppError>
+    where
+        F: FnOnce() -> Result<T, AppError> + Send + 'static,
+        T: Send + 'static,
+    {
+        tokio::task::spawn_blocking(f).await.map_err(|e| {
+            AppError::with_details(ErrorCode::InternalServerError, "密码计算任务失败", e.to_string())
+        })?
     }
 
     /// 生成 JWT Token
+    /// 非对称算法使用密钥集的当前密钥签名（头部携带 kid），HS256 使用共享密钥
     /// # Arguments
     /// * `payload` - JWT 载荷
-    /// * `secret` - 密钥
+    /// * `secret` - HS256 密钥
     ///
     /// # Returns
     /// 返回 JWT Token 字符串
     pub fn generate_jwt(payload: &JwtPayload, secret: &str) -> Result<String, AppError> {
+        if !Self::uses_hmac() {
+            return KEYSET.sign(payload);
+        }
+
         let header = Header::new(Algorithm::HS256);
         let key = EncodingKey::from_secret(secret.as_bytes());
 
@@ -124,13 +166,19 @@ impl CryptoUtils {
     }
 
     /// 验证 JWT Token
+    /// 非对称算法按 kid 选择密钥集中的密钥，轮换后旧密钥签发的令牌在保留期内仍然有效
     /// # Arguments
     /// * `token` - JWT Token 字符串
-    /// * `secret` - 密钥
+    /// * `secret` - HS256 密钥
     ///
     /// # Returns
     /// 返回 JWT 载荷
     pub fn verify_jwt(token: &str, secret: &str) -> Result<JwtPayload, AppError> {
+        if !Self::uses_hmac() {
+            // 算法由 kid 对应的密钥决定；携带 aud 的 OIDC 令牌不能作为系统令牌使用
+            return KEYSET.verify(token, &Validation::new(Algorithm::EdDSA));
+        }
+
         let key = DecodingKey::from_secret(secret.as_bytes());
         let validation = Validation::new(Algorithm::HS256);
 
@@ -147,6 +195,11 @@ impl CryptoUtils {
             })
     }
 
+    /// 是否使用 HS256 共享密钥签名
+    fn uses_hmac() -> bool {
+        SETTINGS.token_algorithm.eq_ignore_ascii_case("HS256")
+    }
+
     /// 生成随机盐值
     /// # Returns
     /// 返回 Base64 编码的 16 字节盐值
@@ -217,3 +270,29 @@ impl CryptoUtils {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hash_and_verify_password() {
+        let hashed = CryptoUtils::hash_password("Secret123").await.unwrap();
+        assert!(hashed.starts_with("$argon2id$"));
+        assert!(!CryptoUtils::password_needs_rehash(&hashed));
+        assert!(CryptoUtils::verify_password("Secret123", &hashed).await.unwrap());
+        assert!(!CryptoUtils::verify_password("secret123", &hashed).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_legacy_bcrypt() {
+        let plain = bcrypt::hash("Secret123", 4).unwrap();
+        assert!(CryptoUtils::verify_password("Secret123", &plain).await.unwrap());
+        assert!(CryptoUtils::password_needs_rehash(&plain));
+
+        let salted = format!("c2FsdA==:{}", bcrypt::hash("Secret123c2FsdA==", 4).unwrap());
+        assert!(CryptoUtils::verify_password("Secret123", &salted).await.unwrap());
+        assert!(!CryptoUtils::verify_password("Secret12", &salted).await.unwrap());
+        assert!(CryptoUtils::password_needs_rehash(&salted));
+    }
+}
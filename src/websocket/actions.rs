@@ -1,5 +1,8 @@
 /// WebSocket 动作模块
 /// 提供主动向客户端推送消息的功能
+///
+/// 所有推送都经过 [`adapter`](crate::websocket::adapter) 同步到其他实例，
+/// 无论目标连接在哪个实例上都能收到。
 
 use socketioxide::SocketIo;
 use serde::{Serialize, Deserialize};
@@ -7,6 +10,8 @@ use tracing::{info, error};
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
 
+use crate::websocket::adapter::{self, Operation};
+
 /// 全局 Socket.IO 实例
 static SOCKETIO_INSTANCE: OnceCell<Arc<SocketIo>> = OnceCell::new();
 
@@ -18,10 +23,50 @@ pub fn init_socketio_instance(io: Arc<SocketIo>) {
 }
 
 /// 获取全局 Socket.IO 实例
-fn get_socketio() -> Option<Arc<SocketIo>> {
+pub(crate) fn get_socketio() -> Option<Arc<SocketIo>> {
     SOCKETIO_INSTANCE.get().cloned()
 }
 
+/// 会话房间
+pub fn session_room(session_uuid: &str) -> String {
+    format!("session:{}", session_uuid)
+}
+
+/// 用户房间（该用户所有会话的连接）
+pub fn user_room(user_id: i64) -> String {
+    format!("user:{}", user_id)
+}
+
+/// 角色房间
+pub fn role_room(role_id: i64) -> String {
+    format!("role:{}", role_id)
+}
+
+/// 部门房间
+pub fn dept_room(dept_id: i64) -> String {
+    format!("dept:{}", dept_id)
+}
+
+/// 在本实例执行并同步到其他实例
+async fn dispatch(operation: Operation) {
+    match get_socketio() {
+        Some(io) => adapter::apply(&io, &operation).await,
+        None => error!("Socket.IO 实例未初始化"),
+    }
+    adapter::publish(&operation).await;
+}
+
+async fn emit<T: Serialize>(room: Option<String>, event: String, data: &T) {
+    let data = match serde_json::to_value(data) {
+        Ok(data) => data,
+        Err(e) => {
+            error!("WebSocket 消息序列化失败: {}", e);
+            return;
+        }
+    };
+    dispatch(Operation::Emit { room, event, data }).await;
+}
+
 /// 任务通知消息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskNotificationMsg {
@@ -29,22 +74,14 @@ pub struct TaskNotificationMsg {
 }
 
 /// 向所有连接的客户端发送任务通知
-/// 
+///
 /// # 示例
-/// ```rust
+/// ```rust,ignore
 /// use crate::websocket::task_notification;
-/// 
+///
 /// task_notification("代码生成任务已完成").await;
 /// ```
 pub async fn task_notification(msg: &str) {
-    let io = match get_socketio() {
-        Some(io) => io,
-        None => {
-            error!("Socket.IO 实例未初始化");
-            return;
-        }
-    };
-
     info!("发送任务通知: {}", msg);
 
     let data = TaskNotificationMsg {
@@ -52,47 +89,56 @@ pub async fn task_notification(msg: &str) {
     };
 
     // 向所有连接的客户端广播
-    std::mem::drop(io.emit("task_notification", &data));
+    emit(None, "task_notification".to_string(), &data).await;
 }
 
 /// 向特定会话发送消息
-/// 
+///
 /// # 参数
 /// * `session_uuid` - 会话 UUID
 /// * `event` - 事件名称
 /// * `data` - 消息数据
-#[allow(dead_code)]
 pub async fn emit_to_session<T: Serialize>(session_uuid: String, event: String, data: &T) {
-    let io = match get_socketio() {
-        Some(io) => io,
-        None => {
-            error!("Socket.IO 实例未初始化");
-            return;
-        }
-    };
-
     info!("向会话 {} 发送事件: {}", session_uuid, event);
+    emit(Some(session_room(&session_uuid)), event, data).await;
+}
+
+/// 向用户的所有会话发送消息
+pub async fn emit_to_user<T: Serialize>(user_id: i64, event: String, data: &T) {
+    info!("向用户 {} 发送事件: {}", user_id, event);
+    emit(Some(user_room(user_id)), event, data).await;
+}
 
-    // 向特定房间（session_uuid）发送消息
-    std::mem::drop(io.to(session_uuid).emit(event, data));
+/// 向角色下的所有用户发送消息
+pub async fn emit_to_role<T: Serialize>(role_id: i64, event: String, data: &T) {
+    info!("向角色 {} 发送事件: {}", role_id, event);
+    emit(Some(role_room(role_id)), event, data).await;
+}
+
+/// 向部门下的所有用户发送消息
+pub async fn emit_to_dept<T: Serialize>(dept_id: i64, event: String, data: &T) {
+    info!("向部门 {} 发送事件: {}", dept_id, event);
+    emit(Some(dept_room(dept_id)), event, data).await;
 }
 
 /// 向所有客户端广播消息
-/// 
+///
 /// # 参数
 /// * `event` - 事件名称
 /// * `data` - 消息数据
-#[allow(dead_code)]
 pub async fn broadcast<T: Serialize>(event: String, data: &T) {
-    let io = match get_socketio() {
-        Some(io) => io,
-        None => {
-            error!("Socket.IO 实例未初始化");
-            return;
-        }
-    };
-
     info!("广播事件: {}", event);
+    emit(None, event, data).await;
+}
+
+/// 断开会话的所有连接（退出登录、踢下线）
+pub async fn disconnect_session(session_uuid: &str) {
+    info!("断开会话 {} 的 WebSocket 连接", session_uuid);
+    dispatch(Operation::Disconnect { room: session_room(session_uuid) }).await;
+}
 
-    std::mem::drop(io.emit(event, data));
+/// 断开用户所有会话的连接
+pub async fn disconnect_user(user_id: i64) {
+    info!("断开用户 {} 的 WebSocket 连接", user_id);
+    dispatch(Operation::Disconnect { room: user_room(user_id) }).await;
 }
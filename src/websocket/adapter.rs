@@ -0,0 +1,145 @@
+/// WebSocket 跨实例广播
+///
+/// Socket.IO 实例只管理本进程的连接。推送和断开操作先在本实例执行，
+/// 再通过 Redis 发布到 `ws_redis_channel`，其他实例订阅后在各自的连接上执行。
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use socketioxide::SocketIo;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::core::SETTINGS;
+use crate::database::redis::RedisManager;
+use crate::websocket::presence::NODE_ID;
+
+/// 广播操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Operation {
+    /// 推送事件，room 为空时推送给所有连接
+    Emit {
+        room: Option<String>,
+        event: String,
+        data: Value,
+    },
+    /// 断开房间内的所有连接
+    Disconnect { room: String },
+}
+
+/// 频道消息
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    node: String,
+    #[serde(flatten)]
+    operation: Operation,
+}
+
+/// 在本实例执行操作
+pub async fn apply(io: &SocketIo, operation: &Operation) {
+    let result = match operation {
+        Operation::Emit { room: Some(room), event, data } => io.to(room.clone()).emit(event, data).await,
+        Operation::Emit { room: None, event, data } => io.emit(event, data).await,
+        Operation::Disconnect { room } => io.to(room.clone()).disconnect().await,
+    };
+    if let Err(e) = result {
+        warn!("WebSocket 操作执行失败: {:?} ({})", operation, e);
+    }
+}
+
+/// 发布操作到其他实例
+pub async fn publish(operation: &Operation) {
+    let envelope = Envelope {
+        node: NODE_ID.clone(),
+        operation: operation.clone(),
+    };
+    let payload = match serde_json::to_string(&envelope) {
+        Ok(payload) => payload,
+        Err(e) => {
+            error!("WebSocket 广播序列化失败: {}", e);
+            return;
+        }
+    };
+
+    let result = match RedisManager::get_connection().await {
+        Ok(mut conn) => redis::cmd("PUBLISH")
+            .arg(&SETTINGS.ws_redis_channel)
+            .arg(payload)
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.message),
+    };
+    if let Err(e) = result {
+        warn!("WebSocket 跨实例广播失败: {}", e);
+    }
+}
+
+/// 订阅其他实例的广播，连接断开后自动重连
+pub fn spawn_subscriber(io: Arc<SocketIo>) {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = subscribe(&io).await {
+                warn!("WebSocket 广播订阅中断: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(3)).await;
+        }
+    });
+}
+
+async fn subscribe(io: &SocketIo) -> Result<(), String> {
+    let client = RedisManager::get_client().map_err(|e| e.message)?;
+    let mut pubsub = client
+        .get_async_connection()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_pubsub();
+    pubsub
+        .subscribe(&SETTINGS.ws_redis_channel)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!("已订阅 WebSocket 广播频道: {}", SETTINGS.ws_redis_channel);
+
+    let mut messages = pubsub.on_message();
+    while let Some(message) = messages.next().await {
+        let payload: String = match message.get_payload() {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("WebSocket 广播消息读取失败: {}", e);
+                continue;
+            }
+        };
+        match serde_json::from_str::<Envelope>(&payload) {
+            Ok(envelope) if envelope.node == *NODE_ID => {}
+            Ok(envelope) => apply(io, &envelope.operation).await,
+            Err(e) => warn!("WebSocket 广播消息格式错误: {}", e),
+        }
+    }
+
+    Err("订阅连接已关闭".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_format() {
+        let envelope = Envelope {
+            node: "n1".to_string(),
+            operation: Operation::Emit {
+                room: Some("user:1".to_string()),
+                event: "notice".to_string(),
+                data: serde_json::json!({ "id": 1 }),
+            },
+        };
+        let json = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(json["op"], "emit");
+        assert_eq!(json["room"], "user:1");
+
+        let parsed: Envelope = serde_json::from_str(r#"{"node":"n2","op":"disconnect","room":"session:abc"}"#).unwrap();
+        assert_eq!(parsed.operation, Operation::Disconnect { room: "session:abc".to_string() });
+    }
+}
@@ -1,10 +1,17 @@
 /// WebSocket 事件处理器
 /// 处理各种 Socket.IO 事件
 
-use socketioxide::extract::{SocketRef, Data};
+use socketioxide::extract::{SocketRef, TryData};
 use serde::{Deserialize, Serialize};
-use tracing::{info, error};
-use crate::websocket::auth::{authenticate_socket, SocketAuth};
+use tracing::{info, warn, error};
+use crate::database::{
+    DatabaseManager,
+    user_repo::UserRepository as UserRepo,
+    user_role_repo::UserRoleRepository as UserRoleRepo,
+};
+use crate::websocket::actions::{dept_room, role_room, session_room, user_room};
+use crate::websocket::auth::{authenticate_socket, SocketAuth, SocketIdentity};
+use crate::websocket::{monitor, presence};
 
 /// Worker 状态数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,56 +26,91 @@ pub struct TaskNotification {
     pub msg: String,
 }
 
+/// 连接需要加入的房间：会话、用户、角色、部门
+async fn rooms_of(identity: &SocketIdentity) -> Vec<String> {
+    let mut rooms = vec![session_room(&identity.session_uuid)];
+    let Some(user_id) = identity.user_id else {
+        return rooms;
+    };
+    rooms.push(user_room(user_id));
+
+    let db = DatabaseManager::get_connection().await;
+    match UserRoleRepo::find_roles_by_user(user_id, db).await {
+        Ok(role_ids) => rooms.extend(role_ids.into_iter().map(role_room)),
+        Err(e) => warn!("获取用户 {} 的角色失败: {}", user_id, e),
+    }
+    match UserRepo::find_by_id(user_id, db).await {
+        Ok(user) => rooms.extend(user.dept_id.map(dept_room)),
+        Err(e) => warn!("获取用户 {} 的部门失败: {}", user_id, e),
+    }
+    rooms
+}
+
 /// Socket.IO 连接事件处理
-pub async fn on_connect(socket: SocketRef, Data(auth): Data<SocketAuth>) {
+pub async fn on_connect(socket: SocketRef, TryData(auth): TryData<SocketAuth>) {
     info!("新的 WebSocket 连接请求: socket_id={}", socket.id);
 
     // 验证认证信息
-    match authenticate_socket(Some(auth.clone())).await {
-        Ok(session_uuid) => {
-            info!("WebSocket 连接认证成功: socket_id={}, session={}", socket.id, session_uuid);
-            
-            // 发送连接成功消息
-            let _ = socket.emit("connected", &serde_json::json!({
-                "status": "ok",
-                "message": "连接成功",
-                "session_uuid": session_uuid
-            }));
-        }
+    let identity = match authenticate_socket(auth.ok()).await {
+        Ok(identity) => identity,
         Err(e) => {
             error!("WebSocket 连接认证失败: {}", e);
             let _ = socket.emit("auth_error", &serde_json::json!({
                 "error": e.to_string()
             }));
             socket.disconnect().ok();
+            return;
         }
+    };
+    info!("WebSocket 连接认证成功: socket_id={}, session={}", socket.id, identity.session_uuid);
+
+    socket.join(rooms_of(&identity).await);
+    if let Err(e) = presence::connect(&identity.session_uuid).await {
+        warn!("记录在线状态失败: {}", e);
     }
+    socket.extensions.insert(identity.clone());
+
+    socket.on("task_worker_status", on_task_worker_status);
+    socket.on("ping", on_ping);
+    socket.on("monitor:subscribe", monitor::on_subscribe);
+    socket.on("monitor:unsubscribe", monitor::on_unsubscribe);
+    socket.on_disconnect(on_disconnect);
+
+    // 发送连接成功消息
+    let _ = socket.emit("connected", &serde_json::json!({
+        "status": "ok",
+        "message": "连接成功",
+        "session_uuid": identity.session_uuid
+    }));
 }
 
 /// Socket.IO 断开连接事件处理
 pub async fn on_disconnect(socket: SocketRef) {
     info!("WebSocket 连接断开: socket_id={}", socket.id);
-    // 注意：由于 socketioxide 限制，无法直接存储 session_uuid
-    // 实际生产中需要使用 Redis 映射 socket_id -> session_uuid
-    // 或者使用 room 机制
+    if let Some(identity) = socket.extensions.get::<SocketIdentity>() {
+        if let Err(e) = presence::disconnect(&identity.session_uuid).await {
+            warn!("清除在线状态失败: {}", e);
+        }
+    }
 }
 
 /// 处理任务 Worker 状态请求
 pub async fn on_task_worker_status(socket: SocketRef) {
     info!("收到任务 Worker 状态请求: socket_id={}", socket.id);
 
-    // TODO: 从实际的任务队列获取 Worker 状态
-    // 这里暂时返回示例数据
-    let workers = vec![
-        WorkerStatus {
-            hostname: "rust-worker-1".to_string(),
-            ok: true,
-        },
-        WorkerStatus {
-            hostname: "rust-worker-2".to_string(),
-            ok: true,
-        },
-    ];
+    let workers: Vec<WorkerStatus> = match presence::nodes().await {
+        Ok(nodes) => nodes
+            .into_iter()
+            .map(|node| WorkerStatus {
+                hostname: node.hostname,
+                ok: true,
+            })
+            .collect(),
+        Err(e) => {
+            warn!("获取 Worker 状态失败: {}", e);
+            Vec::new()
+        }
+    };
 
     let _ = socket.emit("task_worker_status", &workers);
 }
@@ -5,6 +5,9 @@ pub mod server;
 pub mod auth;
 pub mod handlers;
 pub mod actions;
+pub mod adapter;
+pub mod monitor;
+pub mod presence;
 
 pub use server::create_socketio_server;
 pub use actions::*;
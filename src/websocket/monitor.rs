@@ -0,0 +1,191 @@
+/// WebSocket 监控频道
+///
+/// 客户端发送 `monitor:subscribe` / `monitor:unsubscribe`（参数 `{"channel": "server"}`）订阅或取消订阅，
+/// 订阅成功后立即收到一次快照，之后按 `ws_monitor_interval_seconds` 定时收到同名事件（如 `monitor:server`）。
+/// 每个频道需要对应的权限码，频道无人订阅时停止推送。
+///
+/// 推送只发给本实例上的订阅者，服务器指标为该连接所在实例的主机指标。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use socketioxide::extract::{AckSender, SocketRef, TryData};
+use socketioxide::SocketIo;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::app::auth::service::rbac_service::user_has_permission;
+use crate::app::monitor::service::MonitorService;
+use crate::common::exception::{AppError, ErrorCode};
+use crate::core::SETTINGS;
+use crate::database::{redis::RedisManager, DatabaseManager};
+use crate::websocket::actions::get_socketio;
+use crate::websocket::auth::SocketIdentity;
+
+/// 监控频道
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorChannel {
+    /// 服务器指标（CPU、内存、磁盘）
+    Server,
+    /// Redis 状态
+    Redis,
+    /// 在线会话（有变化时推送）
+    Online,
+    /// 任务 Worker 与队列状态
+    Tasks,
+}
+
+impl MonitorChannel {
+    /// 推送事件名，同时作为订阅者所在的房间
+    pub fn event(self) -> &'static str {
+        match self {
+            Self::Server => "monitor:server",
+            Self::Redis => "monitor:redis",
+            Self::Online => "monitor:online",
+            Self::Tasks => "monitor:tasks",
+        }
+    }
+
+    /// 订阅所需的权限码
+    pub fn permission(self) -> &'static str {
+        match self {
+            Self::Server => "sys:monitor:server",
+            Self::Redis => "sys:monitor:redis",
+            Self::Online => "sys:monitor:online",
+            Self::Tasks => "sys:monitor:task",
+        }
+    }
+
+    /// 采集频道数据
+    async fn collect(self) -> Result<Value, AppError> {
+        let service = MonitorService::new(RedisManager::get_client().ok().cloned());
+        let value = match self {
+            Self::Server => serde_json::to_value(service.get_system_metrics().await?),
+            Self::Redis => serde_json::to_value(service.get_redis_metrics().await?),
+            Self::Online => serde_json::to_value(service.get_online_sessions(None).await?),
+            Self::Tasks => serde_json::to_value(service.get_task_worker_status().await?),
+        };
+        value.map_err(|e| AppError::with_details(ErrorCode::InternalServerError, "监控数据序列化失败", e.to_string()))
+    }
+}
+
+/// 订阅参数
+#[derive(Debug, Deserialize)]
+pub struct MonitorSubscribe {
+    pub channel: MonitorChannel,
+}
+
+/// 正在推送的频道
+static RUNNING: Lazy<Mutex<HashSet<MonitorChannel>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// 订阅监控频道
+pub async fn on_subscribe(socket: SocketRef, TryData(param): TryData<MonitorSubscribe>, ack: AckSender) {
+    let result = subscribe(&socket, param.ok()).await;
+    let reply = match &result {
+        Ok(channel) => serde_json::json!({ "ok": true, "channel": channel }),
+        Err(e) => serde_json::json!({ "ok": false, "error": e.message }),
+    };
+    let _ = ack.send(&reply);
+
+    let Ok(channel) = result else {
+        return;
+    };
+    match channel.collect().await {
+        Ok(data) => {
+            let _ = socket.emit(channel.event(), &data);
+        }
+        Err(e) => warn!("监控频道 {} 数据采集失败: {}", channel.event(), e.message),
+    }
+}
+
+async fn subscribe(socket: &SocketRef, param: Option<MonitorSubscribe>) -> Result<MonitorChannel, AppError> {
+    let channel = param
+        .ok_or_else(|| AppError::with_message(ErrorCode::ValidationError, "未知的监控频道"))?
+        .channel;
+    let user_id = socket
+        .extensions
+        .get::<SocketIdentity>()
+        .and_then(|identity| identity.user_id)
+        .ok_or_else(|| AppError::with_message(ErrorCode::Forbidden, "免授权连接不能订阅监控频道"))?;
+
+    let db = DatabaseManager::get_connection().await;
+    if !user_has_permission(user_id, channel.permission(), db).await? {
+        return Err(AppError::with_message(ErrorCode::Forbidden, "没有订阅该监控频道的权限"));
+    }
+
+    socket.join(channel.event());
+    info!("socket_id={} 订阅监控频道 {}", socket.id, channel.event());
+
+    // 先加入房间再检查推送任务，推送任务在同一把锁内判断无人订阅后退出，不会漏掉新订阅者
+    if RUNNING.lock().unwrap_or_else(|e| e.into_inner()).insert(channel) {
+        match get_socketio() {
+            Some(io) => {
+                tokio::spawn(run(io, channel));
+            }
+            None => {
+                RUNNING.lock().unwrap_or_else(|e| e.into_inner()).remove(&channel);
+            }
+        }
+    }
+    Ok(channel)
+}
+
+/// 取消订阅监控频道
+pub async fn on_unsubscribe(socket: SocketRef, TryData(param): TryData<MonitorSubscribe>) {
+    if let Ok(param) = param {
+        socket.leave(param.channel.event());
+    }
+}
+
+/// 频道推送任务，无人订阅时退出
+async fn run(io: Arc<SocketIo>, channel: MonitorChannel) {
+    let mut interval = tokio::time::interval(Duration::from_secs(SETTINGS.ws_monitor_interval_seconds.max(1)));
+    // 第一次 tick 立即返回，订阅时已经发送过快照
+    interval.tick().await;
+    let mut last: Option<Value> = None;
+    info!("监控频道 {} 开始推送", channel.event());
+
+    loop {
+        interval.tick().await;
+        {
+            let mut running = RUNNING.lock().unwrap_or_else(|e| e.into_inner());
+            if io.to(channel.event()).sockets().is_empty() {
+                running.remove(&channel);
+                info!("监控频道 {} 无订阅者，停止推送", channel.event());
+                return;
+            }
+        }
+
+        let data = match channel.collect().await {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("监控频道 {} 数据采集失败: {}", channel.event(), e.message);
+                continue;
+            }
+        };
+        // 在线会话只在变化时推送
+        if channel == MonitorChannel::Online && last.as_ref() == Some(&data) {
+            continue;
+        }
+        if let Err(e) = io.to(channel.event()).emit(channel.event(), &data).await {
+            warn!("监控频道 {} 推送失败: {}", channel.event(), e);
+        }
+        last = Some(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_param() {
+        let param: MonitorSubscribe = serde_json::from_str(r#"{"channel":"online"}"#).unwrap();
+        assert_eq!(param.channel, MonitorChannel::Online);
+        assert_eq!(param.channel.event(), "monitor:online");
+        assert!(serde_json::from_str::<MonitorSubscribe>(r#"{"channel":"unknown"}"#).is_err());
+    }
+}
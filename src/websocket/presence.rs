@@ -0,0 +1,209 @@
+/// WebSocket 在线状态
+///
+/// 在线会话记录在 `token_online_redis_prefix` 集合中，Socket 连接时加入、最后一个连接断开时移除。
+/// 每个实例定时写入心跳并记录本实例持有的会话，实例ID登记在实例索引集合中；实例崩溃后心跳过期，
+/// 其他实例清理时将其会话移出在线集合（仍在其他实例上连接的会话由对应实例的心跳重新加入）。
+
+use once_cell::sync::Lazy;
+use redis::aio::ConnectionManager;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::common::exception::AppError;
+use crate::core::SETTINGS;
+use crate::database::redis::RedisManager;
+
+/// 心跳间隔
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+/// 心跳有效期，超过未续期视为实例已下线
+const HEARTBEAT_TTL_SECONDS: u64 = 30;
+
+/// 当前实例ID
+pub static NODE_ID: Lazy<String> = Lazy::new(|| uuid::Uuid::new_v4().simple().to_string());
+
+/// 当前实例启动时间
+static STARTED_AT: Lazy<chrono::DateTime<chrono::Utc>> = Lazy::new(chrono::Utc::now);
+
+/// 实例信息（随心跳写入）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeInfo {
+    pub node_id: String,
+    pub hostname: String,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// 本实例持有的在线会话数
+    pub sessions: usize,
+}
+
+/// 本实例各会话的 Socket 连接数
+static LOCAL_SESSIONS: Lazy<Mutex<HashMap<String, usize>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn node_key(node: &str) -> String {
+    format!("{}:node:{}", SETTINGS.token_online_redis_prefix, node)
+}
+
+fn node_sessions_key(node: &str) -> String {
+    format!("{}:node:{}:sessions", SETTINGS.token_online_redis_prefix, node)
+}
+
+/// 实例索引集合，避免遍历键空间查找实例
+fn nodes_key() -> String {
+    format!("{}:nodes", SETTINGS.token_online_redis_prefix)
+}
+
+/// 会话建立 Socket 连接
+pub async fn connect(session_uuid: &str) -> Result<(), AppError> {
+    *LOCAL_SESSIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(session_uuid.to_string())
+        .or_default() += 1;
+
+    let mut conn = RedisManager::get_connection().await?;
+    redis::pipe()
+        .sadd(&SETTINGS.token_online_redis_prefix, session_uuid)
+        .sadd(node_sessions_key(&NODE_ID), session_uuid)
+        .query_async::<_, ()>(&mut conn)
+        .await?;
+    Ok(())
+}
+
+/// 会话的 Socket 连接断开，本实例上没有该会话的连接时移出在线集合
+pub async fn disconnect(session_uuid: &str) -> Result<(), AppError> {
+    let last = {
+        let mut sessions = LOCAL_SESSIONS.lock().unwrap_or_else(|e| e.into_inner());
+        match sessions.get_mut(session_uuid) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                false
+            }
+            _ => {
+                sessions.remove(session_uuid);
+                true
+            }
+        }
+    };
+    if !last {
+        return Ok(());
+    }
+
+    let mut conn = RedisManager::get_connection().await?;
+    redis::pipe()
+        .srem(&SETTINGS.token_online_redis_prefix, session_uuid)
+        .srem(node_sessions_key(&NODE_ID), session_uuid)
+        .query_async::<_, ()>(&mut conn)
+        .await?;
+    Ok(())
+}
+
+/// 会话是否在线（任一实例上有 Socket 连接）
+pub async fn is_online(session_uuid: &str) -> Result<bool, AppError> {
+    let mut conn = RedisManager::get_connection().await?;
+    let online: bool = redis::cmd("SISMEMBER")
+        .arg(&SETTINGS.token_online_redis_prefix)
+        .arg(session_uuid)
+        .query_async(&mut conn)
+        .await?;
+    Ok(online)
+}
+
+/// 续期本实例心跳并重新登记本实例持有的会话
+async fn heartbeat(conn: &mut ConnectionManager) -> Result<(), AppError> {
+    let sessions: Vec<String> = LOCAL_SESSIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect();
+
+    let info = NodeInfo {
+        node_id: NODE_ID.clone(),
+        hostname: sysinfo::System::host_name().unwrap_or_default(),
+        started_at: *STARTED_AT,
+        sessions: sessions.len(),
+    };
+
+    let mut pipe = redis::pipe();
+    pipe.set_ex(
+        node_key(&NODE_ID),
+        serde_json::to_string(&info).unwrap_or_default(),
+        HEARTBEAT_TTL_SECONDS,
+    )
+    .sadd(nodes_key(), NODE_ID.as_str());
+    if !sessions.is_empty() {
+        pipe.sadd(&SETTINGS.token_online_redis_prefix, &sessions)
+            .sadd(node_sessions_key(&NODE_ID), &sessions);
+    }
+    pipe.query_async::<_, ()>(conn).await?;
+    Ok(())
+}
+
+/// 心跳有效的实例
+pub async fn nodes() -> Result<Vec<NodeInfo>, AppError> {
+    let mut conn = RedisManager::get_connection().await?;
+    let ids: Vec<String> = redis::cmd("SMEMBERS").arg(nodes_key()).query_async(&mut conn).await?;
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let keys: Vec<String> = ids.iter().map(|id| node_key(id)).collect();
+    let values: Vec<Option<String>> = redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+    let mut nodes: Vec<NodeInfo> = values
+        .into_iter()
+        .flatten()
+        .filter_map(|v| serde_json::from_str(&v).ok())
+        .collect();
+    nodes.sort_by_key(|n| n.started_at);
+    Ok(nodes)
+}
+
+/// 清理心跳已过期实例的会话，并将其移出实例索引
+async fn sweep(conn: &mut ConnectionManager) -> Result<(), AppError> {
+    let ids: Vec<String> = redis::cmd("SMEMBERS").arg(nodes_key()).query_async(conn).await?;
+
+    for node in ids {
+        let alive: bool = redis::cmd("EXISTS").arg(node_key(&node)).query_async(conn).await?;
+        if alive {
+            continue;
+        }
+
+        let key = node_sessions_key(&node);
+        let sessions: Vec<String> = redis::cmd("SMEMBERS").arg(&key).query_async(conn).await?;
+        let mut pipe = redis::pipe();
+        if !sessions.is_empty() {
+            pipe.srem(&SETTINGS.token_online_redis_prefix, &sessions);
+        }
+        pipe.del(&key)
+            .srem(nodes_key(), &node)
+            .query_async::<_, ()>(conn)
+            .await?;
+        warn!("实例 {} 心跳已过期，移除其 {} 个在线会话", node, sessions.len());
+    }
+    Ok(())
+}
+
+/// 启动心跳任务
+pub fn spawn_heartbeat() {
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+        loop {
+            interval.tick().await;
+            crate::core::health::beat("presence_heartbeat", HEARTBEAT_INTERVAL);
+            let mut conn = match RedisManager::get_connection().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    warn!("在线状态心跳失败: {}", e.message);
+                    continue;
+                }
+            };
+            if let Err(e) = heartbeat(&mut conn).await {
+                warn!("在线状态心跳失败: {}", e.message);
+            }
+            if let Err(e) = sweep(&mut conn).await {
+                warn!("清理离线实例会话失败: {}", e.message);
+            }
+        }
+    });
+}
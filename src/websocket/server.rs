@@ -1,14 +1,13 @@
 /// WebSocket 服务器模块
 /// 创建和配置 Socket.IO 服务器
 
-use socketioxide::{SocketIo, extract::SocketRef};
+use socketioxide::SocketIo;
 use std::sync::Arc;
 use tracing::info;
 
-use crate::websocket::handlers::{
-    on_task_worker_status, on_ping
-};
+use crate::websocket::handlers::on_connect;
 use crate::websocket::actions::init_socketio_instance;
+use crate::websocket::{adapter, presence};
 
 /// 创建 Socket.IO 服务器
 /// 
@@ -28,16 +27,12 @@ pub fn create_socketio_server() -> (socketioxide::layer::SocketIoLayer, Arc<Sock
     // 初始化全局实例
     init_socketio_instance(io_arc.clone());
 
-    // 注册事件处理器
-    io.ns("/", |socket: SocketRef| async move {
-        info!("新连接到默认命名空间: socket_id={}", socket.id);
+    // 注册事件处理器（连接时认证并加入会话/用户/角色/部门房间）
+    io.ns("/", on_connect);
 
-        // 任务 Worker 状态事件
-        socket.on("task_worker_status", on_task_worker_status);
-
-        // Ping/Pong 心跳
-        socket.on("ping", on_ping);
-    });
+    // 订阅其他实例的推送，并维护在线状态心跳
+    adapter::spawn_subscriber(io_arc.clone());
+    presence::spawn_heartbeat();
 
     info!("Socket.IO 服务器创建完成");
     info!("  路径: /ws/socket.io");
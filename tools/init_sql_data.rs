@@ -69,18 +69,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // 验证用户数据
-    let users = sqlx::query!("SELECT id, username, nickname, status, is_superuser, del_flag FROM sys_user")
-        .fetch_all(&pool)
-        .await?;
+    let users: Vec<(String, String, i64, i64, i64)> = sqlx::query_as(
+        "SELECT username, nickname, CAST(status AS SIGNED), CAST(is_superuser AS SIGNED), CAST(del_flag AS SIGNED) FROM sys_user",
+    )
+    .fetch_all(&pool)
+    .await?;
 
     info!("\n📋 数据库用户列表:");
     info!("========================================");
-    for user in users {
-        info!("用户名: {}", user.username);
-        info!("昵称: {}", user.nickname);
-        info!("状态: {}", if user.status == 1 { "启用" } else { "禁用" });
-        info!("超级用户: {}", if user.is_superuser == 1 { "是" } else { "否" });
-        info!("删除标志: {}", user.del_flag);
+    for (username, nickname, status, is_superuser, del_flag) in users {
+        info!("用户名: {}", username);
+        info!("昵称: {}", nickname);
+        info!("状态: {}", if status == 1 { "启用" } else { "禁用" });
+        info!("超级用户: {}", if is_superuser == 1 { "是" } else { "否" });
+        info!("删除标志: {}", del_flag);
         info!("----------------------------------------");
     }
 
@@ -96,7 +96,7 @@ fn check_process_windows(process_name: &str) -> bool {
 fn check_process_linux(process_name: &str) -> bool {
     use std::process::Command;
     let output = Command::new("pgrep")
-        .args(&[process_name])
+        .args([process_name])
         .output()
         .ok();
     match output {
@@ -109,7 +109,7 @@ fn check_process_linux(process_name: &str) -> bool {
 fn check_process_macos(process_name: &str) -> bool {
     use std::process::Command;
     let output = Command::new("pgrep")
-        .args(&[process_name])
+        .args([process_name])
         .output()
         .ok();
     match output {